@@ -16,7 +16,9 @@
 
 //! Helpers for implementing various message-related runtime API methods.
 
-use bp_messages::{InboundMessageDetails, MessageNonce, MessagePayload, OutboundMessageDetails};
+use bp_messages::{
+	InboundMessageDetails, MessageNonce, MessagePayload, OutboundLaneStatus, OutboundMessageDetails,
+};
 use sp_std::vec::Vec;
 
 /// Implementation of the `To*OutboundLaneApi::message_details`.
@@ -44,6 +46,17 @@ where
 		.collect()
 }
 
+/// Implementation of the `To*OutboundLaneStatusApi::outbound_lane_status`.
+pub fn outbound_lane_status<Runtime, MessagesPalletInstance>(
+	lane: Runtime::LaneId,
+) -> Option<OutboundLaneStatus>
+where
+	Runtime: pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	pallet_bridge_messages::Pallet::<Runtime, MessagesPalletInstance>::outbound_lane_status(lane)
+}
+
 /// Implementation of the `To*InboundLaneApi::message_details`.
 pub fn inbound_message_details<Runtime, MessagesPalletInstance>(
 	lane: Runtime::LaneId,
@@ -39,15 +39,21 @@
 //!
 //! Note that the worst case scenario here would be a justification where each validator has it's
 //! own fork which is `SESSION_LENGTH` blocks long.
+//!
+//! The `submit_compact_finality_proof` call accepts compact justifications, which have no vote
+//! ancestries. So it only depends on the number of `pre-commits`.
 
 use crate::*;
 
-use bp_header_chain::justification::required_justification_precommits;
+use bp_header_chain::justification::{
+	required_justification_precommits, CompactGrandpaJustification,
+};
 use bp_runtime::BasicOperatingMode;
 use bp_test_utils::{
-	accounts, make_justification_for_header, JustificationGeneratorParams, TEST_GRANDPA_ROUND,
-	TEST_GRANDPA_SET_ID,
+	accounts, make_justification_for_header, make_precommit_equivocation_proof, Account,
+	JustificationGeneratorParams, TEST_GRANDPA_ROUND, TEST_GRANDPA_SET_ID,
 };
+use codec::Encode;
 use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
 use frame_system::RawOrigin;
 use sp_consensus_grandpa::AuthorityId;
@@ -139,6 +145,37 @@ benchmarks_instance_pallet! {
 		assert!(!<ImportedHeaders<T, I>>::contains_key(genesis_header.hash()));
 	}
 
+	// Benchmark `submit_compact_finality_proof` call. Compact justification has no votes
+	// ancestries and is smaller than the regular justification with the same precommits.
+	submit_compact_finality_proof {
+		let p in 1 .. precommits_range_end::<T, I>();
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, justification) = prepare_benchmark_data::<T, I>(p, 0);
+		let authorities = <CurrentAuthoritySet<T, I>>::get()
+			.authorities
+			.into_iter()
+			.map(|(id, _)| id)
+			.collect::<Vec<_>>();
+		let compact_justification =
+			CompactGrandpaJustification::try_compress(&justification, &authorities)
+				.expect("justification has no votes ancestries; qed");
+		assert!(compact_justification.encoded_size() < justification.encoded_size());
+	}: submit_compact_finality_proof(
+		RawOrigin::Signed(caller),
+		Box::new(header),
+		compact_justification,
+		TEST_GRANDPA_SET_ID,
+		false
+	)
+	verify {
+		let header: BridgedHeader<T, I> = bp_test_utils::test_header(One::one());
+		let expected_hash = header.hash();
+
+		// check that the header#1 has been inserted
+		assert_eq!(<BestFinalized<T, I>>::get().unwrap().1, expected_hash);
+		assert!(<ImportedHeaders<T, I>>::contains_key(expected_hash));
+	}
+
 	force_set_pallet_state {
 		let set_id = 100;
 		let authorities = accounts(T::BridgedChain::MAX_AUTHORITIES_COUNT as u16)
@@ -153,5 +190,25 @@ benchmarks_instance_pallet! {
 		assert_eq!(<CurrentAuthoritySet<T, I>>::get().set_id, set_id);
 	}
 
+	// Benchmark `report_equivocation` call. The worst case is when the offender is the last
+	// authority in the largest possible set.
+	report_equivocation {
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, _) = prepare_benchmark_data::<T, I>(precommits_range_end::<T, I>(), 0);
+		let other_header: BridgedHeader<T, I> = bp_test_utils::test_header(2u32.into());
+		let authorities_count = <CurrentAuthoritySet<T, I>>::get().authorities.len();
+		let offender = Account(authorities_count as u16 - 1);
+		let equivocation_proof = make_precommit_equivocation_proof::<BridgedHeader<T, I>>(
+			&offender,
+			(header.hash(), *header.number()),
+			(other_header.hash(), *other_header.number()),
+			TEST_GRANDPA_ROUND,
+			TEST_GRANDPA_SET_ID,
+		);
+	}: report_equivocation(RawOrigin::Signed(caller), Box::new(equivocation_proof))
+	verify {
+		assert_eq!(<CompromisedAuthoritySetId<T, I>>::get(), Some(TEST_GRANDPA_SET_ID));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::TestRuntime)
 }
@@ -15,11 +15,12 @@
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	weights::WeightInfo, BestFinalized, BridgedBlockNumber, BridgedHeader, Config,
-	CurrentAuthoritySet, Error, FreeHeadersRemaining, Pallet,
+	weights::WeightInfo, BestFinalized, BridgedBlockNumber, BridgedHeader,
+	CompromisedAuthoritySetId, Config, CurrentAuthoritySet, Error, FreeHeadersRemaining, Pallet,
 };
 use bp_header_chain::{
-	justification::GrandpaJustification, submit_finality_proof_limits_extras,
+	justification::{CompactGrandpaJustification, GrandpaJustification},
+	submit_compact_finality_proof_limits_extras, submit_finality_proof_limits_extras,
 	SubmitFinalityProofInfo,
 };
 use bp_runtime::{BlockNumberOf, Chain, OwnedBridgeModule};
@@ -159,8 +160,19 @@ impl<T: Config<I>, I: 'static> SubmitFinalityProofHelper<T, I> {
 			},
 		};
 
+		let actual_set_id = <CurrentAuthoritySet<T, I>>::get().set_id;
+		if CompromisedAuthoritySetId::<T, I>::get() == Some(actual_set_id) {
+			log::trace!(
+				target: crate::LOG_TARGET,
+				"Cannot finalize header {:?} because authority set {} is compromised",
+				finality_target,
+				actual_set_id,
+			);
+
+			return Err(Error::<T, I>::AuthoritySetCompromised)
+		}
+
 		if let Some(current_set_id) = current_set_id {
-			let actual_set_id = <CurrentAuthoritySet<T, I>>::get().set_id;
 			if current_set_id != actual_set_id {
 				log::trace!(
 					target: crate::LOG_TARGET,
@@ -215,6 +227,19 @@ pub trait CallSubType<T: Config<I, RuntimeCall = Self>, I: 'static>:
 				Some(*current_set_id),
 				*is_free_execution_expected,
 			))
+		} else if let Some(crate::Call::<T, I>::submit_compact_finality_proof {
+			finality_target,
+			justification,
+			current_set_id,
+			is_free_execution_expected,
+		}) = self.is_sub_type()
+		{
+			return Some(submit_compact_finality_proof_info_from_args::<T, I>(
+				finality_target,
+				justification,
+				*current_set_id,
+				*is_free_execution_expected,
+			))
 		}
 
 		None
@@ -297,6 +322,29 @@ pub(crate) fn submit_finality_proof_info_from_args<T: Config<I>, I: 'static>(
 	}
 }
 
+/// Extract finality proof info from the submitted header and compact justification.
+pub(crate) fn submit_compact_finality_proof_info_from_args<T: Config<I>, I: 'static>(
+	finality_target: &BridgedHeader<T, I>,
+	justification: &CompactGrandpaJustification<BridgedHeader<T, I>>,
+	current_set_id: SetId,
+	is_free_execution_expected: bool,
+) -> SubmitFinalityProofInfo<BridgedBlockNumber<T, I>> {
+	// compact justification has no votes ancestries, so there's no extra weight
+	let extras = submit_compact_finality_proof_limits_extras::<T::BridgedChain>(
+		finality_target,
+		justification,
+	);
+
+	SubmitFinalityProofInfo {
+		block_number: *finality_target.number(),
+		current_set_id: Some(current_set_id),
+		is_mandatory: extras.is_mandatory_finality_target,
+		is_free_execution_expected,
+		extra_weight: Weight::zero(),
+		extra_size: extras.extra_size,
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
@@ -308,11 +356,13 @@ mod tests {
 		BestFinalized, Config, CurrentAuthoritySet, FreeHeadersRemaining, PalletOperatingMode,
 		StoredAuthoritySet, WeightInfo,
 	};
-	use bp_header_chain::{ChainWithGrandpa, SubmitFinalityProofInfo};
+	use bp_header_chain::{
+		justification::CompactGrandpaJustification, ChainWithGrandpa, SubmitFinalityProofInfo,
+	};
 	use bp_runtime::{BasicOperatingMode, HeaderId};
 	use bp_test_utils::{
 		make_default_justification, make_justification_for_header, JustificationGeneratorParams,
-		TEST_GRANDPA_SET_ID,
+		TEST_GRANDPA_ROUND, TEST_GRANDPA_SET_ID,
 	};
 	use codec::Encode;
 	use frame_support::weights::Weight;
@@ -602,6 +652,31 @@ mod tests {
 				is_free_execution_expected: false,
 			})
 		);
+
+		// when `submit_compact_finality_proof` is used, `current_set_id` is set to `Some`
+		let compact_call =
+			RuntimeCall::Grandpa(crate::Call::<TestRuntime, ()>::submit_compact_finality_proof {
+				finality_target: Box::new(test_header(42)),
+				justification: CompactGrandpaJustification {
+					round: TEST_GRANDPA_ROUND,
+					target_hash: test_header(42).hash(),
+					target_number: 42,
+					precommits: vec![],
+				},
+				current_set_id: 777,
+				is_free_execution_expected: true,
+			});
+		assert_eq!(
+			compact_call.submit_finality_proof_info(),
+			Some(SubmitFinalityProofInfo {
+				block_number: 42,
+				current_set_id: Some(777),
+				extra_weight: Weight::zero(),
+				extra_size: 0,
+				is_mandatory: false,
+				is_free_execution_expected: true,
+			})
+		);
 	}
 
 	#[test]
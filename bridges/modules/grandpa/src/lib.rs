@@ -28,9 +28,11 @@
 //!
 //! Since this pallet only tracks finalized headers it does not deal with forks. Forks can only
 //! occur if the GRANDPA validator set on the bridged chain is either colluding or there is a severe
-//! bug causing resulting in an equivocation. Such events are outside the scope of this pallet.
-//! Shall the fork occur on the bridged chain governance intervention will be required to
-//! re-initialize the bridge and track the right fork.
+//! bug causing resulting in an equivocation. Anyone may report such equivocation using the
+//! `report_equivocation` call. The current validator set is then marked as compromised, the pallet
+//! stops importing headers and the configured handler pauses everything that depends on the
+//! bridged chain headers. Governance intervention will be required to re-initialize the bridge
+//! and track the right fork.
 
 #![warn(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -38,13 +40,13 @@
 pub use storage_types::StoredAuthoritySet;
 
 use bp_header_chain::{
-	justification::GrandpaJustification, AuthoritySet, ChainWithGrandpa, GrandpaConsensusLogReader,
-	HeaderChain, InitializationData, StoredHeaderData, StoredHeaderDataBuilder,
-	StoredHeaderGrandpaInfo,
+	justification::{CompactGrandpaJustification, GrandpaJustification},
+	AuthoritySet, ChainWithGrandpa, GrandpaConsensusLogReader, HeaderChain, InitializationData,
+	OnAuthoritySetCompromised, StoredHeaderData, StoredHeaderDataBuilder, StoredHeaderGrandpaInfo,
 };
 use bp_runtime::{BlockNumberOf, HashOf, HasherOf, HeaderId, HeaderOf, OwnedBridgeModule};
 use frame_support::{dispatch::PostDispatchInfo, ensure, DefaultNoBound};
-use sp_consensus_grandpa::{AuthorityList, SetId};
+use sp_consensus_grandpa::{AuthorityId, AuthorityList, EquivocationProof, SetId};
 use sp_runtime::{
 	traits::{Header as HeaderT, Zero},
 	SaturatedConversion,
@@ -140,6 +142,12 @@ pub mod pallet {
 		#[pallet::constant]
 		type HeadersToKeep: Get<u32>;
 
+		/// Handler that is called when the current authorities set is marked as compromised.
+		///
+		/// Everything that relies on headers, finalized by this pallet (e.g. messages lanes),
+		/// shall be paused by this handler, because we can't trust these headers anymore.
+		type OnAuthoritySetCompromised: OnAuthoritySetCompromised;
+
 		/// Weights gathered through benchmarking.
 		type WeightInfo: WeightInfoExt;
 	}
@@ -370,6 +378,8 @@ pub mod pallet {
 		///
 		/// No other checks are made. Previously imported headers stay in the storage and
 		/// are still accessible after the call.
+		///
+		/// If the current authorities set has been marked as compromised, the mark is cleared.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::force_set_pallet_state())]
 		pub fn force_set_pallet_state(
@@ -394,6 +404,124 @@ pub mod pallet {
 			let new_best_header_hash = new_best_header.hash();
 			insert_header::<T, I>(*new_best_header, new_best_header_hash);
 
+			// governance has chosen the new set, so we may trust it again
+			CompromisedAuthoritySetId::<T, I>::kill();
+
+			Ok(())
+		}
+
+		/// Verify a target header is finalized according to the given compact finality proof.
+		///
+		/// This call is an equivalent of the `submit_finality_proof_ex` call. The only difference
+		/// is that it accepts a compact justification, which has no votes ancestries, does not
+		/// repeat the precommit target in every precommit and references authorities by their
+		/// index in the current authorities set. The justification is expanded using the
+		/// current authorities set and then verified exactly as a regular justification.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::submit_compact_finality_proof_weight(
+			justification.precommits.len().saturated_into(),
+		))]
+		pub fn submit_compact_finality_proof(
+			origin: OriginFor<T>,
+			finality_target: Box<BridgedHeader<T, I>>,
+			justification: CompactGrandpaJustification<BridgedHeader<T, I>>,
+			current_set_id: sp_consensus_grandpa::SetId,
+			_is_free_execution_expected: bool,
+		) -> DispatchResultWithPostInfo {
+			let precommits = justification.precommits.len().saturated_into();
+			// authorities are referenced by their index in the current set, so we can't expand
+			// the justification, signed by other set
+			let authority_set = <CurrentAuthoritySet<T, I>>::get();
+			ensure!(authority_set.set_id == current_set_id, <Error<T, I>>::InvalidAuthoritySetId);
+			let authorities =
+				authority_set.authorities.into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+			let justification = justification.try_expand(&authorities).map_err(|e| {
+				log::error!(
+					target: LOG_TARGET,
+					"Received invalid compact justification for {:?}: {:?}",
+					finality_target.hash(),
+					e,
+				);
+				<Error<T, I>>::InvalidJustification
+			})?;
+
+			// the expanded justification is imported as a regular one, refunding the unused
+			// `proof_size` from the weight of `submit_finality_proof_ex`. The same `proof_size`
+			// is refunded from the weight of this call
+			let regular_weight = T::WeightInfo::submit_finality_proof(precommits, 0);
+			let pre_dispatch_weight = T::WeightInfo::submit_compact_finality_proof(precommits);
+			let mut post_info = Self::submit_finality_proof_ex(
+				origin,
+				finality_target,
+				justification,
+				current_set_id,
+				_is_free_execution_expected,
+			)?;
+			let unused_proof_size = post_info.actual_weight.map_or(0, |actual_weight| {
+				regular_weight.proof_size().saturating_sub(actual_weight.proof_size())
+			});
+			post_info.actual_weight = Some(pre_dispatch_weight.set_proof_size(
+				pre_dispatch_weight.proof_size().saturating_sub(unused_proof_size),
+			));
+			Ok(post_info)
+		}
+
+		/// Report equivocation of the bridged chain authority.
+		///
+		/// The equivocation proof must be generated by one of the authorities of the current
+		/// authorities set, known to the pallet. Once the proof is verified, the current set is
+		/// marked as compromised and the pallet stops importing headers until governance calls
+		/// `force_set_pallet_state`. The `T::OnAuthoritySetCompromised` handler is called to pause
+		/// everything that depends on the bridged chain headers.
+		///
+		/// Only the first report for the current set is accepted. The pallet accepts reports
+		/// even when it is halted.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::report_equivocation())]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			equivocation_proof: Box<
+				EquivocationProof<BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>>,
+			>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let authority_set = <CurrentAuthoritySet<T, I>>::get();
+			let set_id = authority_set.set_id;
+			ensure!(
+				CompromisedAuthoritySetId::<T, I>::get() != Some(set_id),
+				<Error<T, I>>::AuthoritySetCompromised,
+			);
+
+			let offender = equivocation_proof.offender().clone();
+			let is_known_offender = equivocation_proof.set_id() == set_id &&
+				authority_set.authorities.iter().any(|(id, _)| *id == offender);
+			if !is_known_offender ||
+				!sp_consensus_grandpa::check_equivocation_proof(*equivocation_proof)
+			{
+				log::trace!(
+					target: LOG_TARGET,
+					"Received invalid equivocation proof of {:?} for authority set {}",
+					offender,
+					set_id,
+				);
+
+				return Err(<Error<T, I>>::InvalidEquivocationProof.into())
+			}
+
+			log::warn!(
+				target: LOG_TARGET,
+				"Authority {:?} of the {:?} authority set {} has equivocated. \
+				Marking set as compromised",
+				offender,
+				T::BridgedChain::ID,
+				set_id,
+			);
+
+			CompromisedAuthoritySetId::<T, I>::put(set_id);
+			T::OnAuthoritySetCompromised::on_authority_set_compromised(set_id);
+			Self::deposit_event(Event::AuthoritySetCompromised { set_id, offender });
+
 			Ok(())
 		}
 	}
@@ -454,6 +582,14 @@ pub mod pallet {
 	pub type CurrentAuthoritySet<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, StoredAuthoritySet<T, I>, ValueQuery>;
 
+	/// Id of the authorities set that has been marked as compromised.
+	///
+	/// If it matches the id of the current authorities set, the pallet rejects all headers. It is
+	/// only reset by the `force_set_pallet_state` call.
+	#[pallet::storage]
+	pub type CompromisedAuthoritySetId<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, SetId, OptionQuery>;
+
 	/// Optional pallet owner.
 	///
 	/// Pallet owner has a right to halt all pallet operations and then resume it. If it is
@@ -509,6 +645,14 @@ pub mod pallet {
 			/// The Grandpa info associated to the new best finalized header.
 			grandpa_info: StoredHeaderGrandpaInfo<BridgedHeader<T, I>>,
 		},
+		/// The current authority set has been marked as compromised, because one of its
+		/// authorities has equivocated.
+		AuthoritySetCompromised {
+			/// Id of the compromised authority set.
+			set_id: SetId,
+			/// Authority that has equivocated.
+			offender: AuthorityId,
+		},
 	}
 
 	#[pallet::error]
@@ -543,6 +687,10 @@ pub mod pallet {
 		/// The header (and its finality) submission overflows hardcoded chain limits: size
 		/// and/or weight are larger than expected.
 		HeaderOverflowLimits,
+		/// The equivocation proof is invalid or is not generated by the current authority set.
+		InvalidEquivocationProof,
+		/// The current authority set has been marked as compromised.
+		AuthoritySetCompromised,
 	}
 
 	/// Called when new free header is imported.
@@ -828,15 +976,16 @@ pub fn initialize_for_benchmarks<T: Config<I>, I: 'static>(header: BridgedHeader
 mod tests {
 	use super::*;
 	use crate::mock::{
-		run_test, test_header, FreeHeadersInterval, RuntimeEvent as TestEvent, RuntimeOrigin,
-		System, TestBridgedChain, TestHeader, TestNumber, TestRuntime, MAX_BRIDGED_AUTHORITIES,
+		run_test, test_header, CompromisedAuthoritySets, FreeHeadersInterval,
+		RuntimeEvent as TestEvent, RuntimeOrigin, System, TestBridgedChain, TestHeader, TestNumber,
+		TestRuntime, MAX_BRIDGED_AUTHORITIES,
 	};
 	use bp_header_chain::BridgeGrandpaCall;
 	use bp_runtime::BasicOperatingMode;
 	use bp_test_utils::{
 		authority_list, generate_owned_bridge_module_tests, make_default_justification,
-		make_justification_for_header, JustificationGeneratorParams, ALICE, BOB,
-		TEST_GRANDPA_SET_ID,
+		make_justification_for_header, make_precommit_equivocation_proof, Account,
+		JustificationGeneratorParams, ALICE, BOB, TEST_GRANDPA_ROUND, TEST_GRANDPA_SET_ID,
 	};
 	use codec::Encode;
 	use frame_support::{
@@ -1128,6 +1277,123 @@ mod tests {
 		})
 	}
 
+	fn compact_justification(header: &TestHeader) -> CompactGrandpaJustification<TestHeader> {
+		let justification = make_justification_for_header(JustificationGeneratorParams {
+			header: header.clone(),
+			ancestors: 0,
+			..Default::default()
+		});
+		let authorities = authority_list().into_iter().map(|(id, _)| id).collect::<Vec<_>>();
+		CompactGrandpaJustification::try_compress(&justification, &authorities).unwrap()
+	}
+
+	#[test]
+	fn successfully_imports_header_with_valid_compact_finality() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(1);
+			let justification = compact_justification(&header);
+			let expanded_justification = justification
+				.clone()
+				.try_expand(&authority_list().into_iter().map(|(id, _)| id).collect::<Vec<_>>())
+				.unwrap();
+			// compact justification is smaller than the regular one
+			assert!(justification.encoded_size() < expanded_justification.encoded_size());
+
+			let pre_dispatch_weight =
+				<TestRuntime as Config>::WeightInfo::submit_compact_finality_proof(
+					justification.precommits.len().try_into().unwrap_or(u32::MAX),
+				);
+			let result = Pallet::<TestRuntime>::submit_compact_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header.clone()),
+				justification,
+				TEST_GRANDPA_SET_ID,
+				false,
+			);
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::Yes);
+			assert_eq!(
+				result.unwrap().actual_weight.unwrap().ref_time(),
+				pre_dispatch_weight.ref_time()
+			);
+
+			assert_eq!(<BestFinalized<TestRuntime>>::get().unwrap().1, header.hash());
+			assert!(<ImportedHeaders<TestRuntime>>::contains_key(header.hash()));
+			assert_eq!(
+				Pallet::<TestRuntime>::synced_headers_grandpa_info(),
+				vec![StoredHeaderGrandpaInfo {
+					finality_proof: expanded_justification,
+					new_verification_context: None
+				}]
+			);
+		})
+	}
+
+	#[test]
+	fn does_not_import_header_with_invalid_compact_finality_proof() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(1);
+
+			// invalid signature
+			let mut justification = compact_justification(&header);
+			justification.round = 42;
+			assert_err!(
+				Pallet::<TestRuntime>::submit_compact_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header.clone()),
+					justification,
+					TEST_GRANDPA_SET_ID,
+					false,
+				),
+				<Error<TestRuntime>>::InvalidJustification
+			);
+
+			// unknown authority
+			let mut justification = compact_justification(&header);
+			justification.precommits[0].authority_index = u32::MAX;
+			assert_err!(
+				Pallet::<TestRuntime>::submit_compact_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header.clone()),
+					justification,
+					TEST_GRANDPA_SET_ID,
+					false,
+				),
+				<Error<TestRuntime>>::InvalidJustification
+			);
+
+			// duplicate precommits
+			let mut justification = compact_justification(&header);
+			justification.precommits.push(justification.precommits[0].clone());
+			assert_err!(
+				Pallet::<TestRuntime>::submit_compact_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header.clone()),
+					justification,
+					TEST_GRANDPA_SET_ID,
+					false,
+				),
+				<Error<TestRuntime>>::InvalidJustification
+			);
+
+			// other authority set
+			assert_err!(
+				Pallet::<TestRuntime>::submit_compact_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header.clone()),
+					compact_justification(&header),
+					TEST_GRANDPA_SET_ID + 1,
+					false,
+				),
+				<Error<TestRuntime>>::InvalidAuthoritySetId
+			);
+		})
+	}
+
 	#[test]
 	fn rejects_justification_that_skips_authority_set_transition() {
 		run_test(|| {
@@ -1440,6 +1706,118 @@ mod tests {
 		});
 	}
 
+	fn equivocation_proof(
+		signer: &Account,
+		set_id: SetId,
+	) -> Box<EquivocationProof<BridgedBlockHash<TestRuntime, ()>, TestNumber>> {
+		let first = test_header(2);
+		let mut second = test_header(2);
+		second.state_root = sp_core::H256::repeat_byte(1);
+		Box::new(make_precommit_equivocation_proof::<TestHeader>(
+			signer,
+			(first.hash(), *first.number()),
+			(second.hash(), *second.number()),
+			TEST_GRANDPA_ROUND,
+			set_id,
+		))
+	}
+
+	#[test]
+	fn reported_equivocation_marks_authority_set_compromised() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			assert_ok!(submit_finality_proof(1));
+
+			assert_ok!(Pallet::<TestRuntime>::report_equivocation(
+				RuntimeOrigin::signed(1),
+				equivocation_proof(&ALICE, TEST_GRANDPA_SET_ID),
+			));
+			assert_eq!(CompromisedAuthoritySetId::<TestRuntime>::get(), Some(TEST_GRANDPA_SET_ID));
+			assert_eq!(CompromisedAuthoritySets::get(), vec![TEST_GRANDPA_SET_ID]);
+			System::assert_last_event(TestEvent::Grandpa(Event::AuthoritySetCompromised {
+				set_id: TEST_GRANDPA_SET_ID,
+				offender: ALICE.into(),
+			}));
+
+			// headers, finalized by the compromised set, are rejected
+			assert_noop!(submit_finality_proof(2), Error::<TestRuntime>::AuthoritySetCompromised);
+
+			// and we don't accept more reports for the same set
+			assert_noop!(
+				Pallet::<TestRuntime>::report_equivocation(
+					RuntimeOrigin::signed(1),
+					equivocation_proof(&BOB, TEST_GRANDPA_SET_ID),
+				),
+				Error::<TestRuntime>::AuthoritySetCompromised,
+			);
+			assert_eq!(CompromisedAuthoritySets::get(), vec![TEST_GRANDPA_SET_ID]);
+		})
+	}
+
+	#[test]
+	fn force_set_pallet_state_clears_compromised_authority_set() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			assert_ok!(Pallet::<TestRuntime>::report_equivocation(
+				RuntimeOrigin::signed(1),
+				equivocation_proof(&ALICE, TEST_GRANDPA_SET_ID),
+			));
+
+			let next_set_id = TEST_GRANDPA_SET_ID + 1;
+			assert_ok!(Pallet::<TestRuntime>::force_set_pallet_state(
+				RuntimeOrigin::root(),
+				next_set_id,
+				authority_list(),
+				Box::new(test_header(1)),
+			));
+			assert_eq!(CompromisedAuthoritySetId::<TestRuntime>::get(), None);
+			assert_ok!(submit_finality_proof_with_set_id(2, next_set_id));
+		})
+	}
+
+	#[test]
+	fn rejects_invalid_equivocation_proofs() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			// proof is generated for other authorities set
+			assert_noop!(
+				Pallet::<TestRuntime>::report_equivocation(
+					RuntimeOrigin::signed(1),
+					equivocation_proof(&ALICE, TEST_GRANDPA_SET_ID + 1),
+				),
+				Error::<TestRuntime>::InvalidEquivocationProof,
+			);
+
+			// offender is not in the current authorities set
+			assert_noop!(
+				Pallet::<TestRuntime>::report_equivocation(
+					RuntimeOrigin::signed(1),
+					equivocation_proof(&Account(42), TEST_GRANDPA_SET_ID),
+				),
+				Error::<TestRuntime>::InvalidEquivocationProof,
+			);
+
+			// both votes have the same target
+			let header = test_header(2);
+			let target = (header.hash(), *header.number());
+			assert_noop!(
+				Pallet::<TestRuntime>::report_equivocation(
+					RuntimeOrigin::signed(1),
+					Box::new(make_precommit_equivocation_proof::<TestHeader>(
+						&ALICE,
+						target,
+						target,
+						TEST_GRANDPA_ROUND,
+						TEST_GRANDPA_SET_ID,
+					)),
+				),
+				Error::<TestRuntime>::InvalidEquivocationProof,
+			);
+			assert!(CompromisedAuthoritySets::get().is_empty());
+		})
+	}
+
 	#[test]
 	fn verify_storage_proof_rejects_unknown_header() {
 		run_test(|| {
@@ -1705,6 +2083,26 @@ mod tests {
 			direct_submit_finality_proof_call.encode(),
 			indirect_submit_finality_proof_call.encode()
 		);
+
+		let compact_justification = compact_justification(&test_header(1));
+		let direct_submit_compact_finality_proof_call =
+			Call::<TestRuntime>::submit_compact_finality_proof {
+				finality_target: Box::new(test_header(1)),
+				justification: compact_justification.clone(),
+				current_set_id: TEST_GRANDPA_SET_ID,
+				is_free_execution_expected: true,
+			};
+		let indirect_submit_compact_finality_proof_call =
+			BridgeGrandpaCall::<TestHeader>::submit_compact_finality_proof {
+				finality_target: Box::new(test_header(1)),
+				justification: compact_justification,
+				current_set_id: TEST_GRANDPA_SET_ID,
+				is_free_execution_expected: true,
+			};
+		assert_eq!(
+			direct_submit_compact_finality_proof_call.encode(),
+			indirect_submit_compact_finality_proof_call.encode()
+		);
 	}
 
 	generate_owned_bridge_module_tests!(BasicOperatingMode::Normal, BasicOperatingMode::Halted);
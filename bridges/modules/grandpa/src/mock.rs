@@ -17,12 +17,13 @@
 // From construct_runtime macro
 #![allow(clippy::from_over_into)]
 
-use bp_header_chain::ChainWithGrandpa;
+use bp_header_chain::{ChainWithGrandpa, OnAuthoritySetCompromised};
 use bp_runtime::{Chain, ChainId};
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types, sp_runtime::StateVersion, traits::Hooks,
 	weights::Weight,
 };
+use sp_consensus_grandpa::SetId;
 use sp_core::sr25519::Signature;
 
 pub type AccountId = u64;
@@ -52,6 +53,16 @@ parameter_types! {
 	pub const MaxFreeHeadersPerBlock: u32 = 2;
 	pub const FreeHeadersInterval: u32 = 32;
 	pub const HeadersToKeep: u32 = 5;
+	pub static CompromisedAuthoritySets: Vec<SetId> = vec![];
+}
+
+/// Authority set compromise handler that remembers all compromised sets.
+pub struct TestOnAuthoritySetCompromised;
+
+impl OnAuthoritySetCompromised for TestOnAuthoritySetCompromised {
+	fn on_authority_set_compromised(set_id: SetId) {
+		CompromisedAuthoritySets::mutate(|sets| sets.push(set_id));
+	}
 }
 
 impl grandpa::Config for TestRuntime {
@@ -60,6 +71,7 @@ impl grandpa::Config for TestRuntime {
 	type MaxFreeHeadersPerBlock = MaxFreeHeadersPerBlock;
 	type FreeHeadersInterval = FreeHeadersInterval;
 	type HeadersToKeep = HeadersToKeep;
+	type OnAuthoritySetCompromised = TestOnAuthoritySetCompromised;
 	type WeightInfo = ();
 }
 
@@ -43,6 +43,12 @@ pub trait WeightInfoExt: WeightInfo {
 		let base_weight = Self::submit_finality_proof(precommits_len, votes_ancestries_len);
 		base_weight.saturating_add(Self::submit_finality_proof_overhead_from_runtime())
 	}
+
+	/// Weight of compact finality proof submission extrinsic.
+	fn submit_compact_finality_proof_weight(precommits_len: u32) -> Weight {
+		let base_weight = Self::submit_compact_finality_proof(precommits_len);
+		base_weight.saturating_add(Self::submit_finality_proof_overhead_from_runtime())
+	}
 }
 
 impl<T: frame_system::Config> WeightInfoExt for BridgeWeight<T> {
@@ -20,7 +20,7 @@
 
 use crate::{
 	active_outbound_lane, weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, BridgedChainOf, Call,
-	InboundLanes, OutboundLanes,
+	InboundLanes, OutboundLanes, PausedOutboundLanes,
 };
 
 use bp_messages::{
@@ -501,6 +501,22 @@ mod benchmarks {
 		assert!(T::is_relayer_rewarded(&relayer2_id));
 	}
 
+	// Benchmark `set_outbound_lane_paused` extrinsic, pausing an opened outbound lane.
+	#[benchmark]
+	fn set_outbound_lane_paused() {
+		// setup code
+		OutboundLanes::<T, I>::insert(
+			T::bench_lane_id(),
+			OutboundLaneData { state: LaneState::Opened, ..Default::default() },
+		);
+
+		#[extrinsic_call]
+		set_outbound_lane_paused(RawOrigin::Root, T::bench_lane_id(), true);
+
+		// verification code
+		assert!(PausedOutboundLanes::<T, I>::contains_key(T::bench_lane_id()));
+	}
+
 	//
 	// Benchmarks that the runtime developers may use for proper pallet configuration.
 	//
@@ -15,9 +15,9 @@
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	BridgedChainOf, Config, InboundLane, InboundLaneStorage, InboundLanes, OutboundLane,
-	OutboundLaneStorage, OutboundLanes, OutboundMessages, StoredInboundLaneData,
-	StoredMessagePayload,
+	BridgedChainOf, Config, InboundLane, InboundLaneRelayers, InboundLaneStorage, InboundLanes,
+	OutboundLane, OutboundLaneStorage, OutboundLanes, OutboundMessages, PausedOutboundLanes,
+	RelayerDeliveries, StoredInboundLaneData, StoredMessagePayload, MAX_LANE_RELAYERS,
 };
 
 use bp_messages::{
@@ -220,7 +220,9 @@ impl<T: Config<I>, I: 'static> InboundLaneStorage for RuntimeInboundLaneStorage<
 	}
 
 	fn purge(self) {
-		InboundLanes::<T, I>::remove(self.lane_id)
+		InboundLanes::<T, I>::remove(self.lane_id);
+		InboundLaneRelayers::<T, I>::remove(self.lane_id);
+		let _ = RelayerDeliveries::<T, I>::clear_prefix(self.lane_id, MAX_LANE_RELAYERS, None);
 	}
 }
 
@@ -280,6 +282,7 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 	}
 
 	fn purge(self) {
-		OutboundLanes::<T, I>::remove(self.lane_id)
+		OutboundLanes::<T, I>::remove(self.lane_id);
+		PausedOutboundLanes::<T, I>::remove(self.lane_id);
 	}
 }
@@ -43,6 +43,7 @@ pub use lanes_manager::{
 pub use outbound_lane::{
 	OutboundLane, OutboundLaneStorage, ReceptionConfirmationError, StoredMessagePayload,
 };
+pub use relayer_set::{RelayerSet, MAX_LANE_RELAYERS};
 pub use weights::WeightInfo;
 pub use weights_ext::{
 	ensure_able_to_receive_confirmation, ensure_able_to_receive_message,
@@ -50,7 +51,7 @@ pub use weights_ext::{
 	EXPECTED_DEFAULT_MESSAGE_LENGTH, EXTRA_STORAGE_PROOF_SIZE,
 };
 
-use bp_header_chain::HeaderChain;
+use bp_header_chain::{HeaderChain, OnAuthoritySetCompromised, SetId};
 use bp_messages::{
 	source_chain::{
 		DeliveryConfirmationPayments, FromBridgedChainMessagesDeliveryProof, OnMessagesDelivered,
@@ -61,8 +62,8 @@ use bp_messages::{
 		ProvedLaneMessages, ProvedMessages,
 	},
 	ChainWithMessages, DeliveredMessages, InboundLaneData, InboundMessageDetails, MessageKey,
-	MessageNonce, MessagePayload, MessagesOperatingMode, OutboundLaneData, OutboundMessageDetails,
-	UnrewardedRelayersState, VerificationError,
+	MessageNonce, MessagePayload, MessagesOperatingMode, OutboundLaneData, OutboundLaneStatus,
+	OutboundMessageDetails, UnrewardedRelayersState, VerificationError,
 };
 use bp_runtime::{
 	AccountIdOf, BasicOperatingMode, HashOf, OwnedBridgeModule, PreComputedSize, RangeInclusiveExt,
@@ -77,6 +78,7 @@ mod inbound_lane;
 mod lanes_manager;
 mod outbound_lane;
 mod proofs;
+mod relayer_set;
 mod tests;
 mod weights_ext;
 
@@ -209,7 +211,10 @@ pub mod pallet {
 		/// The call may succeed, but some messages may not be delivered e.g. if they are not fit
 		/// into the unrewarded relayers vector.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(&**proof, *messages_count, *dispatch_weight))]
+		#[pallet::weight(
+			T::WeightInfo::receive_messages_proof_weight(&**proof, *messages_count, *dispatch_weight)
+				.saturating_add(relayer_set::delivery_weight::<T, I>())
+		)]
 		pub fn receive_messages_proof(
 			origin: OriginFor<T>,
 			relayer_id_at_bridged_chain: AccountIdOf<BridgedChainOf<T, I>>,
@@ -241,7 +246,8 @@ pub mod pallet {
 				&*proof,
 				messages_count,
 				dispatch_weight,
-			);
+			)
+			.saturating_add(relayer_set::delivery_weight::<T, I>());
 			let mut actual_weight = declared_weight;
 
 			// verify messages proof && convert proof into messages
@@ -260,6 +266,13 @@ pub mod pallet {
 			let mut dispatch_weight_left = dispatch_weight;
 			let mut lane = active_inbound_lane::<T, I>(lane_id)?;
 
+			// if the lane has a relayer set, only its active relayers may deliver messages
+			relayer_set::ensure_may_deliver::<T, I>(
+				lane_id,
+				&relayer_id_at_this_chain,
+				lane_data.messages.len() as MessageNonce,
+			)?;
+
 			// subtract extra storage proof bytes from the actual PoV size - there may be
 			// less unrewarded relayers than the maximal configured value
 			let lane_extra_proof_size_bytes = lane.storage().extra_proof_size_bytes();
@@ -330,6 +343,12 @@ pub mod pallet {
 				actual_weight = actual_weight.saturating_sub(unspent_weight);
 			}
 
+			// only deliveries of valid messages postpone the permissionless fallback, otherwise
+			// anyone could keep the lane locked by resubmitting already delivered messages
+			if valid_messages > 0 {
+				relayer_set::note_delivery::<T, I>(lane_id);
+			}
+
 			// let's now deal with relayer payments
 			T::DeliveryPayments::pay_reward(
 				relayer_id_at_this_chain,
@@ -443,6 +462,91 @@ pub mod pallet {
 
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
 		}
+
+		/// Pause or resume the outbound lane.
+		///
+		/// Paused lane rejects all new outbound messages. Messages that are already queued
+		/// are preserved - they are still delivered to the bridged chain and their delivery
+		/// may be confirmed, so the lane may be resumed later without losing in-flight messages.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::WeightInfo::set_outbound_lane_paused(), DispatchClass::Operational))]
+		pub fn set_outbound_lane_paused(
+			origin: OriginFor<T>,
+			lane_id: T::LaneId,
+			paused: bool,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+			ensure!(
+				OutboundLanes::<T, I>::contains_key(lane_id),
+				Error::<T, I>::LanesManager(LanesManagerError::UnknownOutboundLane),
+			);
+
+			if paused {
+				PausedOutboundLanes::<T, I>::insert(lane_id, ());
+				log::info!(target: LOG_TARGET, "Paused outbound lane {:?}", lane_id);
+				Self::deposit_event(Event::OutboundLanePaused { lane_id });
+			} else {
+				PausedOutboundLanes::<T, I>::remove(lane_id);
+				log::info!(target: LOG_TARGET, "Resumed outbound lane {:?}", lane_id);
+				Self::deposit_event(Event::OutboundLaneResumed { lane_id });
+			}
+
+			Ok(())
+		}
+
+		/// Set or remove the relayer set of the inbound lane.
+		///
+		/// When the lane has a relayer set, only the currently active relayers of the set may
+		/// deliver messages to the lane, up to the set quota per rotation period. If no messages
+		/// are delivered for `fallback_timeout` blocks, anyone may deliver messages again.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(5)]
+		#[pallet::weight((
+			T::DbWeight::get().reads_writes(2, MAX_LANE_RELAYERS.into()),
+			DispatchClass::Operational,
+		))]
+		pub fn set_inbound_lane_relayers(
+			origin: OriginFor<T>,
+			lane_id: T::LaneId,
+			relayer_set: Option<RelayerSet<T::AccountId, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+			ensure!(
+				InboundLanes::<T, I>::contains_key(lane_id),
+				Error::<T, I>::LanesManager(LanesManagerError::UnknownInboundLane),
+			);
+
+			ensure!(
+				relayer_set.as_ref().map_or(true, RelayerSet::is_valid),
+				Error::<T, I>::InvalidRelayerSet,
+			);
+
+			let _ = RelayerDeliveries::<T, I>::clear_prefix(lane_id, MAX_LANE_RELAYERS, None);
+			match relayer_set {
+				Some(mut relayer_set) => {
+					relayer_set.last_delivery = frame_system::Pallet::<T>::block_number();
+					let relayers = relayer_set.relayers.len() as u32;
+					InboundLaneRelayers::<T, I>::insert(lane_id, relayer_set);
+					log::info!(
+						target: LOG_TARGET,
+						"Set {} relayers of inbound lane {:?}",
+						relayers,
+						lane_id,
+					);
+					Self::deposit_event(Event::InboundLaneRelayersSet { lane_id, relayers });
+				},
+				None => {
+					InboundLaneRelayers::<T, I>::remove(lane_id);
+					log::info!(target: LOG_TARGET, "Removed relayers of inbound lane {:?}", lane_id);
+					Self::deposit_event(Event::InboundLaneRelayersRemoved { lane_id });
+				},
+			}
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -470,6 +574,28 @@ pub mod pallet {
 			/// Delivered messages.
 			messages: DeliveredMessages,
 		},
+		/// Outbound lane has been paused and rejects new messages.
+		OutboundLanePaused {
+			/// Lane that has been paused.
+			lane_id: T::LaneId,
+		},
+		/// Outbound lane has been resumed and accepts new messages.
+		OutboundLaneResumed {
+			/// Lane that has been resumed.
+			lane_id: T::LaneId,
+		},
+		/// Relayer set of the inbound lane has been set.
+		InboundLaneRelayersSet {
+			/// Lane, which relayer set has been set.
+			lane_id: T::LaneId,
+			/// Number of relayers in the set.
+			relayers: u32,
+		},
+		/// Relayer set of the inbound lane has been removed and anyone may deliver messages.
+		InboundLaneRelayersRemoved {
+			/// Lane, which relayer set has been removed.
+			lane_id: T::LaneId,
+		},
 	}
 
 	#[pallet::error]
@@ -497,6 +623,14 @@ pub mod pallet {
 		ReceptionConfirmation(ReceptionConfirmationError),
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
+		/// The outbound lane is paused and doesn't accept new messages.
+		OutboundLanePaused,
+		/// The relayer set can't allow anyone to deliver messages.
+		InvalidRelayerSet,
+		/// The relayer is not an active relayer of the inbound lane relayer set.
+		RelayerNotAllowed,
+		/// The relayer has already delivered its quota of messages in this rotation period.
+		RelayerQuotaExceeded,
 	}
 
 	/// Optional pallet owner.
@@ -534,6 +668,39 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Set of paused outbound lanes.
+	///
+	/// Paused lanes reject new outbound messages, but messages that have been queued before
+	/// the lane has been paused, are still delivered.
+	#[pallet::storage]
+	pub type PausedOutboundLanes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::LaneId, (), OptionQuery>;
+
+	/// Map of lane id => relayers allowed to deliver messages to the inbound lane.
+	///
+	/// Lanes without relayer set accept messages from any relayer.
+	#[pallet::storage]
+	pub type InboundLaneRelayers<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::LaneId,
+		RelayerSet<T::AccountId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Number of messages delivered by the relayer to the inbound lane in the given rotation
+	/// period of the lane relayer set.
+	#[pallet::storage]
+	pub type RelayerDeliveries<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::LaneId,
+		Blake2_128Concat,
+		T::AccountId,
+		(BlockNumberFor<T>, MessageNonce),
+		OptionQuery,
+	>;
+
 	/// All queued outbound messages.
 	#[pallet::storage]
 	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
@@ -605,6 +772,15 @@ pub mod pallet {
 			OutboundLanes::<T, I>::get(lane)
 		}
 
+		/// Return outbound lane status.
+		pub fn outbound_lane_status(lane: T::LaneId) -> Option<OutboundLaneStatus> {
+			OutboundLanes::<T, I>::get(lane).map(|data| OutboundLaneStatus {
+				state: data.state,
+				is_paused: PausedOutboundLanes::<T, I>::contains_key(lane),
+				queued_messages: data.queued_messages().saturating_len(),
+			})
+		}
+
 		/// Return inbound lane data.
 		pub fn inbound_lane_data(
 			lane: T::LaneId,
@@ -692,6 +868,10 @@ where
 
 		// check lane
 		let lane = active_outbound_lane::<T, I>(lane_id)?;
+		ensure!(
+			!PausedOutboundLanes::<T, I>::contains_key(lane_id),
+			Error::<T, I>::OutboundLanePaused
+		);
 
 		Ok(SendMessageArgs {
 			lane_id,
@@ -728,6 +908,21 @@ where
 	}
 }
 
+impl<T: Config<I>, I: 'static> OnAuthoritySetCompromised for Pallet<T, I> {
+	fn on_authority_set_compromised(set_id: SetId) {
+		// both messages and delivery confirmations are proved using bridged chain headers, which
+		// can't be trusted anymore. So we halt all lanes. Queued messages stay in the storage and
+		// the pallet may be resumed by the governance, once the header chain is fixed
+		log::warn!(
+			target: LOG_TARGET,
+			"Halting all lanes, because bridged chain authority set {} is compromised",
+			set_id,
+		);
+
+		PalletOperatingMode::<T, I>::put(MessagesOperatingMode::Basic(BasicOperatingMode::Halted));
+	}
+}
+
 /// Ensure that the pallet is in normal operational mode.
 fn ensure_normal_operating_mode<T: Config<I>, I: 'static>() -> Result<(), Error<T, I>> {
 	if PalletOperatingMode::<T, I>::get() ==
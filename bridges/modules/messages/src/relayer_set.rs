@@ -0,0 +1,131 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Optional restriction of the relayers that may deliver messages to an inbound lane.
+
+use crate::{Config, Error, InboundLaneRelayers, RelayerDeliveries};
+
+use bp_messages::MessageNonce;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	ensure,
+	traits::{ConstU32, Get},
+	weights::Weight,
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, SaturatedConversion, Zero},
+	RuntimeDebug,
+};
+
+/// Maximal number of relayers in the relayer set of a lane.
+pub const MAX_LANE_RELAYERS: u32 = 32;
+
+/// Relayers allowed to deliver messages to an inbound lane.
+///
+/// The relayers take turns: in every rotation period, the next `active` relayers of the set may
+/// deliver up to `quota` messages each. If nothing has been delivered to the lane for
+/// `fallback_timeout` blocks, anyone may deliver messages again, so that the lane does not stall
+/// when the relayers of the set are gone.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RelayerSet<AccountId, BlockNumber> {
+	/// Relayers of the set, in rotation order.
+	pub relayers: BoundedVec<AccountId, ConstU32<MAX_LANE_RELAYERS>>,
+	/// Number of relayers that may deliver messages in the same rotation period.
+	pub active: u32,
+	/// Number of blocks after which the next relayers take over.
+	pub rotation_period: BlockNumber,
+	/// Maximal number of messages a relayer may deliver in a rotation period.
+	pub quota: MessageNonce,
+	/// Number of blocks without delivery after which anyone may deliver messages.
+	pub fallback_timeout: BlockNumber,
+	/// Block of the last delivery to the lane, or of the relayer set update.
+	pub last_delivery: BlockNumber,
+}
+
+impl<AccountId: PartialEq, BlockNumber: AtLeast32BitUnsigned + Copy>
+	RelayerSet<AccountId, BlockNumber>
+{
+	/// Returns true if the set may ever allow anyone to deliver messages.
+	pub fn is_valid(&self) -> bool {
+		!self.relayers.is_empty() &&
+			self.active > 0 &&
+			!self.rotation_period.is_zero() &&
+			self.quota > 0
+	}
+
+	/// Index of the rotation period at given block.
+	pub fn rotation(&self, at: BlockNumber) -> BlockNumber {
+		at / self.rotation_period.max(1u32.into())
+	}
+
+	/// Returns true if `relayer` is one of the active relayers at given block.
+	pub fn is_active(&self, relayer: &AccountId, at: BlockNumber) -> bool {
+		let len = self.relayers.len() as u64;
+		if len == 0 {
+			return false
+		}
+		let active = u64::from(self.active).min(len);
+		let rotation = self.rotation(at).saturated_into::<u64>() % len;
+		let first = rotation * active % len;
+		(0..active).any(|i| self.relayers[((first + i) % len) as usize] == *relayer)
+	}
+
+	/// Returns true if anyone may deliver messages at given block.
+	pub fn is_permissionless(&self, at: BlockNumber) -> bool {
+		at.saturating_sub(self.last_delivery) >= self.fallback_timeout
+	}
+}
+
+/// Weight of the relayer set checks of a message delivery.
+///
+/// Covers reading the lane relayer set and the relayer deliveries, and updating the relayer
+/// deliveries and the last delivery block of the set.
+pub(crate) fn delivery_weight<T: Config<I>, I: 'static>() -> Weight {
+	T::DbWeight::get().reads_writes(2, 2)
+}
+
+/// Ensure that `relayer` may deliver `messages` messages to the inbound lane at the current block
+/// and account them in its quota.
+pub(crate) fn ensure_may_deliver<T: Config<I>, I: 'static>(
+	lane_id: T::LaneId,
+	relayer: &T::AccountId,
+	messages: MessageNonce,
+) -> Result<(), Error<T, I>> {
+	let Some(set) = InboundLaneRelayers::<T, I>::get(lane_id) else { return Ok(()) };
+	let now = frame_system::Pallet::<T>::block_number();
+	if set.is_permissionless(now) {
+		return Ok(())
+	}
+
+	ensure!(set.is_active(relayer, now), Error::<T, I>::RelayerNotAllowed);
+	let rotation = set.rotation(now);
+	let delivered = RelayerDeliveries::<T, I>::get(lane_id, relayer)
+		.filter(|(at_rotation, _)| *at_rotation == rotation)
+		.map_or(0, |(_, delivered)| delivered)
+		.saturating_add(messages);
+	ensure!(delivered <= set.quota, Error::<T, I>::RelayerQuotaExceeded);
+	RelayerDeliveries::<T, I>::insert(lane_id, relayer, (rotation, delivered));
+	Ok(())
+}
+
+/// Remember that messages have been delivered to the inbound lane at the current block.
+pub(crate) fn note_delivery<T: Config<I>, I: 'static>(lane_id: T::LaneId) {
+	InboundLaneRelayers::<T, I>::mutate_extant(lane_id, |set| {
+		set.last_delivery = frame_system::Pallet::<T>::block_number();
+	});
+}
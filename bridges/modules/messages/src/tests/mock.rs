@@ -181,6 +181,7 @@ impl pallet_bridge_grandpa::Config for TestRuntime {
 	type MaxFreeHeadersPerBlock = ConstU32<4>;
 	type FreeHeadersInterval = ConstU32<1_024>;
 	type HeadersToKeep = ConstU32<8>;
+	type OnAuthoritySetCompromised = Messages;
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<TestRuntime>;
 }
 
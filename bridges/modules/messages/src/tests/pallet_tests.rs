@@ -20,19 +20,22 @@ use crate::{
 	active_outbound_lane,
 	lanes_manager::RuntimeInboundLaneStorage,
 	outbound_lane::ReceptionConfirmationError,
+	relayer_set,
 	tests::mock::{RuntimeEvent as TestEvent, *},
 	weights_ext::WeightInfoExt,
-	Call, Config, Error, Event, InboundLanes, LanesManagerError, OutboundLanes, OutboundMessages,
-	Pallet, PalletOperatingMode, PalletOwner, StoredInboundLaneData,
+	Call, Config, Error, Event, InboundLaneRelayers, InboundLanes, LanesManager, LanesManagerError,
+	OutboundLanes, OutboundMessages, Pallet, PalletOperatingMode, PalletOwner, RelayerDeliveries,
+	RelayerSet, StoredInboundLaneData,
 };
 
+use bp_header_chain::OnAuthoritySetCompromised;
 use bp_messages::{
 	source_chain::{FromBridgedChainMessagesDeliveryProof, MessagesBridge},
 	target_chain::{FromBridgedChainMessagesProof, MessageDispatch},
 	BridgeMessagesCall, ChainWithMessages, DeliveredMessages, InboundLaneData,
 	InboundMessageDetails, LaneIdType, LaneState, MessageKey, MessageNonce, MessagesOperatingMode,
-	OutboundLaneData, OutboundMessageDetails, UnrewardedRelayer, UnrewardedRelayersState,
-	VerificationError,
+	OutboundLaneData, OutboundLaneStatus, OutboundMessageDetails, UnrewardedRelayer,
+	UnrewardedRelayersState, VerificationError,
 };
 use bp_runtime::{BasicOperatingMode, PreComputedSize, RangeInclusiveExt, Size};
 use bp_test_utils::generate_owned_bridge_module_tests;
@@ -44,7 +47,8 @@ use frame_support::{
 	weights::Weight,
 };
 use frame_system::{EventRecord, Pallet as System, Phase};
-use sp_runtime::{BoundedVec, DispatchError};
+use sp_runtime::{BoundedVec, DispatchError, DispatchResult};
+use sp_std::ops::RangeInclusive;
 
 fn get_ready_for_events() {
 	System::<TestRuntime>::set_block_number(1);
@@ -168,6 +172,29 @@ fn pallet_rejects_transactions_if_halted() {
 	});
 }
 
+#[test]
+fn pallet_is_halted_when_bridged_authority_set_is_compromised() {
+	run_test(|| {
+		send_regular_message(test_lane_id());
+
+		Pallet::<TestRuntime>::on_authority_set_compromised(1);
+		assert_eq!(
+			PalletOperatingMode::<TestRuntime, ()>::get(),
+			MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+		);
+		assert_noop!(
+			Pallet::<TestRuntime, ()>::validate_message(test_lane_id(), &REGULAR_PAYLOAD),
+			Error::<TestRuntime, ()>::NotOperatingNormally,
+		);
+
+		// queued message is not lost
+		assert_eq!(
+			Pallet::<TestRuntime>::outbound_message_data(test_lane_id(), 1),
+			Some(REGULAR_PAYLOAD.encode()),
+		);
+	});
+}
+
 #[test]
 fn receive_messages_fails_if_dispatcher_is_inactive() {
 	run_test(|| {
@@ -230,6 +257,263 @@ fn pallet_rejects_new_messages_in_rejecting_outbound_messages_operating_mode() {
 	});
 }
 
+#[test]
+fn paused_outbound_lane_rejects_new_messages_but_delivers_queued_messages() {
+	run_test(|| {
+		// send message first to be able to check that it is delivered after lane is paused
+		send_regular_message(test_lane_id());
+
+		get_ready_for_events();
+		assert_ok!(Pallet::<TestRuntime>::set_outbound_lane_paused(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			true,
+		));
+		assert_eq!(
+			System::<TestRuntime>::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::OutboundLanePaused { lane_id: test_lane_id() }),
+				topics: vec![],
+			}],
+		);
+		assert_eq!(
+			Pallet::<TestRuntime>::outbound_lane_status(test_lane_id()),
+			Some(OutboundLaneStatus {
+				state: LaneState::Opened,
+				is_paused: true,
+				queued_messages: 1
+			}),
+		);
+
+		assert_noop!(
+			Pallet::<TestRuntime, ()>::validate_message(test_lane_id(), &REGULAR_PAYLOAD),
+			Error::<TestRuntime, ()>::OutboundLanePaused,
+		);
+
+		// queued message is still delivered and confirmed
+		assert_eq!(
+			Pallet::<TestRuntime>::outbound_message_data(test_lane_id(), 1),
+			Some(REGULAR_PAYLOAD.encode()),
+		);
+		receive_messages_delivery_proof();
+		assert_eq!(
+			Pallet::<TestRuntime>::outbound_lane_status(test_lane_id()),
+			Some(OutboundLaneStatus {
+				state: LaneState::Opened,
+				is_paused: true,
+				queued_messages: 0
+			}),
+		);
+
+		// after lane is resumed, it accepts new messages again
+		get_ready_for_events();
+		assert_ok!(Pallet::<TestRuntime>::set_outbound_lane_paused(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			false,
+		));
+		assert_eq!(
+			System::<TestRuntime>::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::OutboundLaneResumed { lane_id: test_lane_id() }),
+				topics: vec![],
+			}],
+		);
+		send_regular_message(test_lane_id());
+	});
+}
+
+#[test]
+fn set_outbound_lane_paused_fails_for_unknown_lane_or_bad_origin() {
+	run_test(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::set_outbound_lane_paused(
+				RuntimeOrigin::signed(1),
+				test_lane_id(),
+				true,
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			Pallet::<TestRuntime>::set_outbound_lane_paused(
+				RuntimeOrigin::root(),
+				unknown_lane_id(),
+				true,
+			),
+			Error::<TestRuntime, ()>::LanesManager(LanesManagerError::UnknownOutboundLane),
+		);
+		assert_eq!(Pallet::<TestRuntime>::outbound_lane_status(unknown_lane_id()), None);
+	});
+}
+
+fn test_relayer_set(relayers: Vec<AccountId>) -> RelayerSet<AccountId, u64> {
+	RelayerSet {
+		relayers: BoundedVec::truncate_from(relayers),
+		active: 1,
+		rotation_period: 10,
+		quota: 2,
+		fallback_timeout: 100,
+		last_delivery: 0,
+	}
+}
+
+fn receive_messages_from(
+	relayer: AccountId,
+	nonces: RangeInclusive<MessageNonce>,
+) -> DispatchResult {
+	let messages_count = nonces.saturating_len() as u32;
+	Pallet::<TestRuntime>::receive_messages_proof(
+		RuntimeOrigin::signed(relayer),
+		TEST_RELAYER_A,
+		prepare_messages_proof(nonces.map(|nonce| message(nonce, REGULAR_PAYLOAD)).collect(), None),
+		messages_count,
+		REGULAR_PAYLOAD.declared_weight * messages_count as u64,
+	)
+	.map(drop)
+	.map_err(|e| e.error)
+}
+
+#[test]
+fn inbound_lane_relayers_restrict_delivery() {
+	run_test(|| {
+		get_ready_for_events();
+		assert_ok!(Pallet::<TestRuntime>::set_inbound_lane_relayers(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			Some(test_relayer_set(vec![1, 2])),
+		));
+		assert_eq!(
+			System::<TestRuntime>::events(),
+			vec![EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::InboundLaneRelayersSet {
+					lane_id: test_lane_id(),
+					relayers: 2,
+				}),
+				topics: vec![],
+			}],
+		);
+
+		// in the first rotation period only the first relayer may deliver messages
+		assert_noop!(receive_messages_from(2, 1..=1), Error::<TestRuntime, ()>::RelayerNotAllowed);
+		assert_ok!(receive_messages_from(1, 1..=1));
+		assert_noop!(
+			receive_messages_from(1, 2..=3),
+			Error::<TestRuntime, ()>::RelayerQuotaExceeded,
+		);
+		assert_ok!(receive_messages_from(1, 2..=2));
+
+		// in the next rotation period the second relayer takes over
+		System::<TestRuntime>::set_block_number(10);
+		assert_noop!(receive_messages_from(1, 3..=3), Error::<TestRuntime, ()>::RelayerNotAllowed);
+		assert_ok!(receive_messages_from(2, 3..=4));
+		assert_noop!(
+			receive_messages_from(2, 5..=5),
+			Error::<TestRuntime, ()>::RelayerQuotaExceeded,
+		);
+
+		// and then the first relayer has its full quota again
+		System::<TestRuntime>::set_block_number(20);
+		assert_ok!(receive_messages_from(1, 5..=6));
+	});
+}
+
+#[test]
+fn inbound_lane_relayers_fall_back_to_permissionless_delivery() {
+	run_test(|| {
+		System::<TestRuntime>::set_block_number(1);
+		assert_ok!(Pallet::<TestRuntime>::set_inbound_lane_relayers(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			Some(test_relayer_set(vec![1])),
+		));
+		assert_eq!(
+			InboundLaneRelayers::<TestRuntime>::get(test_lane_id()).unwrap().last_delivery,
+			1
+		);
+
+		// resubmitting already delivered messages doesn't postpone the fallback
+		assert_ok!(receive_messages_from(1, 1..=1));
+		System::<TestRuntime>::set_block_number(5);
+		assert_ok!(receive_messages_from(1, 1..=1));
+		assert_eq!(
+			InboundLaneRelayers::<TestRuntime>::get(test_lane_id()).unwrap().last_delivery,
+			1
+		);
+
+		// after the timeout, anyone may deliver messages
+		System::<TestRuntime>::set_block_number(100);
+		assert_noop!(receive_messages_from(3, 2..=2), Error::<TestRuntime, ()>::RelayerNotAllowed);
+		System::<TestRuntime>::set_block_number(101);
+		assert_ok!(receive_messages_from(3, 2..=4));
+
+		// which restarts the timeout
+		System::<TestRuntime>::set_block_number(102);
+		assert_noop!(receive_messages_from(3, 5..=5), Error::<TestRuntime, ()>::RelayerNotAllowed);
+
+		// lane without relayer set accepts messages from anyone
+		assert_ok!(Pallet::<TestRuntime>::set_inbound_lane_relayers(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			None,
+		));
+		assert_ok!(receive_messages_from(3, 5..=5));
+	});
+}
+
+#[test]
+fn set_inbound_lane_relayers_fails_for_invalid_set_or_unknown_lane_or_bad_origin() {
+	run_test(|| {
+		assert_noop!(
+			Pallet::<TestRuntime>::set_inbound_lane_relayers(
+				RuntimeOrigin::signed(1),
+				test_lane_id(),
+				Some(test_relayer_set(vec![1])),
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			Pallet::<TestRuntime>::set_inbound_lane_relayers(
+				RuntimeOrigin::root(),
+				unknown_lane_id(),
+				Some(test_relayer_set(vec![1])),
+			),
+			Error::<TestRuntime, ()>::LanesManager(LanesManagerError::UnknownInboundLane),
+		);
+		assert_noop!(
+			Pallet::<TestRuntime>::set_inbound_lane_relayers(
+				RuntimeOrigin::root(),
+				test_lane_id(),
+				Some(test_relayer_set(vec![])),
+			),
+			Error::<TestRuntime, ()>::InvalidRelayerSet,
+		);
+	});
+}
+
+#[test]
+fn purging_inbound_lane_removes_its_relayer_set() {
+	run_test(|| {
+		assert_ok!(Pallet::<TestRuntime>::set_inbound_lane_relayers(
+			RuntimeOrigin::root(),
+			test_lane_id(),
+			Some(test_relayer_set(vec![1])),
+		));
+		assert_ok!(receive_messages_from(1, 1..=1));
+		assert!(RelayerDeliveries::<TestRuntime>::contains_key(test_lane_id(), 1));
+
+		LanesManager::<TestRuntime, ()>::new()
+			.any_state_inbound_lane(test_lane_id())
+			.unwrap()
+			.purge();
+
+		assert!(!InboundLaneRelayers::<TestRuntime>::contains_key(test_lane_id()));
+		assert!(!RelayerDeliveries::<TestRuntime>::contains_key(test_lane_id(), 1));
+	});
+}
+
 #[test]
 fn send_message_works() {
 	run_test(|| {
@@ -816,7 +1100,8 @@ fn ref_time_refund_from_receive_messages_proof_works() {
 					&*proof,
 					messages_count,
 					REGULAR_PAYLOAD.declared_weight,
-				);
+				)
+				.saturating_add(relayer_set::delivery_weight::<TestRuntime, ()>());
 			let result = Pallet::<TestRuntime>::receive_messages_proof(
 				RuntimeOrigin::signed(1),
 				TEST_RELAYER_A,
@@ -871,7 +1156,8 @@ fn proof_size_refund_from_receive_messages_proof_works() {
 				&*proof,
 				messages_count,
 				REGULAR_PAYLOAD.declared_weight,
-			);
+			)
+			.saturating_add(relayer_set::delivery_weight::<TestRuntime, ()>());
 		InboundLanes::<TestRuntime>::insert(
 			test_lane_id(),
 			StoredInboundLaneData(InboundLaneData {
@@ -58,6 +58,7 @@ pub trait WeightInfo {
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight;
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight;
 	fn receive_single_n_bytes_message_proof_with_dispatch(n: u32) -> Weight;
+	fn set_outbound_lane_paused() -> Weight;
 }
 
 /// Weights for `pallet_bridge_messages` that are generated using one of the Bridge testnets.
@@ -293,6 +294,16 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOwner (r:1 w:0)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanes (r:1 w:0)
+	///
+	/// Storage: BridgeUnknownMessages PausedOutboundLanes (r:0 w:1)
+	fn set_outbound_lane_paused() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -527,4 +538,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: BridgeUnknownMessages PalletOwner (r:1 w:0)
+	///
+	/// Storage: BridgeUnknownMessages OutboundLanes (r:1 w:0)
+	///
+	/// Storage: BridgeUnknownMessages PausedOutboundLanes (r:0 w:1)
+	fn set_outbound_lane_paused() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -189,6 +189,7 @@ impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance1> for TestRun
 	type MaxFreeHeadersPerBlock = ConstU32<2>;
 	type FreeHeadersInterval = FreeHeadersInterval;
 	type HeadersToKeep = HeadersToKeep;
+	type OnAuthoritySetCompromised = ();
 	type WeightInfo = ();
 }
 
@@ -198,6 +199,7 @@ impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance2> for TestRun
 	type MaxFreeHeadersPerBlock = ConstU32<2>;
 	type FreeHeadersInterval = FreeHeadersInterval;
 	type HeadersToKeep = HeadersToKeep;
+	type OnAuthoritySetCompromised = ();
 	type WeightInfo = ();
 }
 
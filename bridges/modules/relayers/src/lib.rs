@@ -29,10 +29,12 @@ use bp_runtime::StorageDoubleMapKeyProvider;
 use frame_support::fail;
 use sp_arithmetic::traits::{AtLeast32BitUnsigned, Zero};
 use sp_runtime::{traits::CheckedSub, Saturating};
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 
 pub use pallet::*;
-pub use payment_adapter::{DeliveryConfirmationPaymentsAdapter, PayRewardFromAccount};
+pub use payment_adapter::{
+	DeliveryConfirmationPaymentsAdapter, PayRewardFromAccount, PayRewardInAsset,
+};
 pub use stake_adapter::StakeAndSlashNamed;
 pub use weights::WeightInfo;
 pub use weights_ext::WeightInfoExt;
@@ -50,6 +52,9 @@ pub mod weights;
 /// The target that will be used when publishing logs related to this pallet.
 pub const LOG_TARGET: &str = "runtime::bridge-relayers";
 
+/// Maximal number of rewards accounts that may be claimed in a single `claim_rewards_batch` call.
+pub const MAX_REWARDS_BATCH_SIZE: u32 = 32;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -99,32 +104,7 @@ pub mod pallet {
 			rewards_account_params: RewardsAccountParams<T::LaneId>,
 		) -> DispatchResult {
 			let relayer = ensure_signed(origin)?;
-
-			RelayerRewards::<T, I>::try_mutate_exists(
-				&relayer,
-				rewards_account_params,
-				|maybe_reward| -> DispatchResult {
-					let reward = maybe_reward.take().ok_or(Error::<T, I>::NoRewardForRelayer)?;
-					T::PaymentProcedure::pay_reward(&relayer, rewards_account_params, reward)
-						.map_err(|e| {
-							log::trace!(
-								target: LOG_TARGET,
-								"Failed to pay {:?} rewards to {:?}: {:?}",
-								rewards_account_params,
-								relayer,
-								e,
-							);
-							Error::<T, I>::FailedToPayReward
-						})?;
-
-					Self::deposit_event(Event::<T, I>::RewardPaid {
-						relayer: relayer.clone(),
-						rewards_account_params,
-						reward,
-					});
-					Ok(())
-				},
-			)
+			Self::do_claim_reward(&relayer, rewards_account_params).map(drop)
 		}
 
 		/// Register relayer or update its registration.
@@ -229,6 +209,41 @@ pub mod pallet {
 				},
 			)
 		}
+
+		/// Claim accumulated rewards from multiple rewards accounts in a single call.
+		///
+		/// Rewards may be claimed from different lanes, bridged chains and reward account owners.
+		/// The call fails if any of the rewards can't be paid - in this case no rewards are paid
+		/// at all. At most [`MAX_REWARDS_BATCH_SIZE`] rewards accounts may be claimed at once.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			T::WeightInfo::claim_rewards()
+				.saturating_mul((rewards_accounts_params.len() as u64).max(1))
+		)]
+		pub fn claim_rewards_batch(
+			origin: OriginFor<T>,
+			rewards_accounts_params: BoundedVec<
+				RewardsAccountParams<T::LaneId>,
+				ConstU32<MAX_REWARDS_BATCH_SIZE>,
+			>,
+		) -> DispatchResult {
+			let relayer = ensure_signed(origin)?;
+			ensure!(!rewards_accounts_params.is_empty(), Error::<T, I>::EmptyRewardsBatch);
+
+			let mut total_reward: T::Reward = Zero::zero();
+			for rewards_account_params in &rewards_accounts_params {
+				let reward = Self::do_claim_reward(&relayer, *rewards_account_params)?;
+				total_reward = total_reward.saturating_add(reward);
+			}
+
+			Self::deposit_event(Event::<T, I>::RewardsBatchPaid {
+				relayer,
+				rewards_count: rewards_accounts_params.len() as u32,
+				total_reward,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -323,6 +338,13 @@ pub mod pallet {
 			}
 		}
 
+		/// Return all rewards that may be claimed by given relayer.
+		pub fn claimable_rewards(
+			relayer: &T::AccountId,
+		) -> Vec<(RewardsAccountParams<T::LaneId>, T::Reward)> {
+			RelayerRewards::<T, I>::iter_prefix(relayer).collect()
+		}
+
 		/// Register reward for given relayer.
 		pub fn register_relayer_reward(
 			rewards_account_params: RewardsAccountParams<T::LaneId>,
@@ -375,6 +397,38 @@ pub mod pallet {
 			>>::RequiredStake::get()
 		}
 
+		/// Pay reward, accumulated by the relayer at given rewards account.
+		fn do_claim_reward(
+			relayer: &T::AccountId,
+			rewards_account_params: RewardsAccountParams<T::LaneId>,
+		) -> Result<T::Reward, DispatchError> {
+			RelayerRewards::<T, I>::try_mutate_exists(
+				relayer,
+				rewards_account_params,
+				|maybe_reward| -> Result<T::Reward, DispatchError> {
+					let reward = maybe_reward.take().ok_or(Error::<T, I>::NoRewardForRelayer)?;
+					T::PaymentProcedure::pay_reward(relayer, rewards_account_params, reward)
+						.map_err(|e| {
+							log::trace!(
+								target: LOG_TARGET,
+								"Failed to pay {:?} rewards to {:?}: {:?}",
+								rewards_account_params,
+								relayer,
+								e,
+							);
+							Error::<T, I>::FailedToPayReward
+						})?;
+
+					Self::deposit_event(Event::<T, I>::RewardPaid {
+						relayer: relayer.clone(),
+						rewards_account_params,
+						reward,
+					});
+					Ok(reward)
+				},
+			)
+		}
+
 		/// `Unreserve` given amount on relayer account.
 		fn do_unreserve(relayer: &T::AccountId, amount: T::Reward) -> DispatchResult {
 			let failed_to_unreserve = T::StakeAndSlash::unreserve(relayer, amount);
@@ -415,6 +469,15 @@ pub mod pallet {
 			/// Reward amount.
 			reward: T::Reward,
 		},
+		/// Multiple rewards have been paid to the relayer by the single call.
+		RewardsBatchPaid {
+			/// Relayer account that has been rewarded.
+			relayer: T::AccountId,
+			/// Number of rewards accounts that have paid the reward.
+			rewards_count: u32,
+			/// Total reward amount.
+			total_reward: T::Reward,
+		},
 		/// Relayer registration has been added or updated.
 		RegistrationUpdated {
 			/// Relayer account that has been registered.
@@ -455,6 +518,8 @@ pub mod pallet {
 		NotRegistered,
 		/// Failed to `deregister` relayer, because lease is still active.
 		RegistrationIsStillActive,
+		/// Cannot claim rewards from an empty list of rewards accounts.
+		EmptyRewardsBatch,
 	}
 
 	/// Map of the relayer => accumulated reward.
@@ -497,6 +562,7 @@ mod tests {
 	use bp_relayers::RewardsAccountOwner;
 	use frame_support::{
 		assert_noop, assert_ok,
+		dispatch::GetDispatchInfo,
 		traits::fungible::{Inspect, Mutate},
 	};
 	use frame_system::{EventRecord, Pallet as System, Phase};
@@ -613,6 +679,126 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn relayer_cant_claim_empty_rewards_batch() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::claim_rewards_batch(
+					RuntimeOrigin::signed(REGULAR_RELAYER),
+					vec![].try_into().unwrap()
+				),
+				Error::<TestRuntime>::EmptyRewardsBatch,
+			);
+		});
+	}
+
+	#[test]
+	fn relayer_cant_claim_rewards_batch_if_any_reward_is_missing() {
+		run_test(|| {
+			let other_reward_account_param = RewardsAccountParams::new(
+				TestLaneIdType::try_new(1, 3).unwrap(),
+				*b"test",
+				RewardsAccountOwner::BridgedChain,
+			);
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				test_reward_account_param(),
+				100,
+			);
+			assert_noop!(
+				Pallet::<TestRuntime>::claim_rewards_batch(
+					RuntimeOrigin::signed(REGULAR_RELAYER),
+					vec![test_reward_account_param(), other_reward_account_param]
+						.try_into()
+						.unwrap()
+				),
+				Error::<TestRuntime>::NoRewardForRelayer,
+			);
+		});
+	}
+
+	#[test]
+	fn relayer_can_claim_rewards_batch() {
+		run_test(|| {
+			get_ready_for_events();
+
+			let other_reward_account_param = RewardsAccountParams::new(
+				TestLaneIdType::try_new(1, 3).unwrap(),
+				*b"test",
+				RewardsAccountOwner::BridgedChain,
+			);
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				test_reward_account_param(),
+				100,
+			);
+			RelayerRewards::<TestRuntime>::insert(REGULAR_RELAYER, other_reward_account_param, 50);
+			let mut claimable = Pallet::<TestRuntime>::claimable_rewards(&REGULAR_RELAYER);
+			claimable.sort_by_key(|(_, reward)| *reward);
+			assert_eq!(
+				claimable,
+				vec![(other_reward_account_param, 50), (test_reward_account_param(), 100)],
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::claim_rewards_batch(
+				RuntimeOrigin::signed(REGULAR_RELAYER),
+				vec![test_reward_account_param(), other_reward_account_param]
+					.try_into()
+					.unwrap()
+			));
+			assert!(Pallet::<TestRuntime>::claimable_rewards(&REGULAR_RELAYER).is_empty());
+
+			// Check if the `RewardPaid` events and the `RewardsBatchPaid` event were emitted.
+			let events =
+				System::<TestRuntime>::events().into_iter().map(|r| r.event).collect::<Vec<_>>();
+			assert_eq!(
+				events,
+				vec![
+					TestEvent::BridgeRelayers(RewardPaid {
+						relayer: REGULAR_RELAYER,
+						rewards_account_params: test_reward_account_param(),
+						reward: 100
+					}),
+					TestEvent::BridgeRelayers(RewardPaid {
+						relayer: REGULAR_RELAYER,
+						rewards_account_params: other_reward_account_param,
+						reward: 50
+					}),
+					TestEvent::BridgeRelayers(Event::RewardsBatchPaid {
+						relayer: REGULAR_RELAYER,
+						rewards_count: 2,
+						total_reward: 150,
+					}),
+				],
+			);
+		});
+	}
+
+	#[test]
+	fn claim_rewards_batch_weight_scales_with_batch_size() {
+		let batch_call = |size: u32| Call::<TestRuntime>::claim_rewards_batch {
+			rewards_accounts_params: (0..size)
+				.map(|i| {
+					RewardsAccountParams::new(
+						TestLaneIdType::try_new(1, i + 2).unwrap(),
+						*b"test",
+						RewardsAccountOwner::ThisChain,
+					)
+				})
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap(),
+		};
+		let claim_weight = <TestRuntime as Config>::WeightInfo::claim_rewards();
+
+		assert_eq!(batch_call(0).get_dispatch_info().weight, claim_weight);
+		assert_eq!(batch_call(1).get_dispatch_info().weight, claim_weight);
+		assert_eq!(
+			batch_call(MAX_REWARDS_BATCH_SIZE).get_dispatch_info().weight,
+			claim_weight.saturating_mul(MAX_REWARDS_BATCH_SIZE as u64),
+		);
+	}
+
 	#[test]
 	fn pay_reward_from_account_actually_pays_reward() {
 		type Balances = pallet_balances::Pallet<TestRuntime>;
@@ -652,6 +838,66 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn pay_reward_in_asset_converts_the_reward_and_falls_back_to_native_balance() {
+		use frame_support::traits::fungibles;
+
+		type Balances = pallet_balances::Pallet<TestRuntime>;
+		type Assets = pallet_assets::Pallet<TestRuntime>;
+		type PayLaneRewardInAsset = bp_relayers::PayRewardInAsset<
+			Assets,
+			Balances,
+			TestLaneAsset,
+			TestAssetRate,
+			ThisChainAccountId,
+			TestLaneIdType,
+		>;
+
+		run_test(|| {
+			let native_lane = test_reward_account_param();
+			let asset_lane = RewardsAccountParams::new(
+				asset_reward_lane(),
+				*b"test",
+				RewardsAccountOwner::BridgedChain,
+			);
+			let native_rewards_account = TestPaymentProcedure::rewards_account(native_lane);
+			let asset_rewards_account = TestPaymentProcedure::rewards_account(asset_lane);
+
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				REWARD_ASSET,
+				REGISTER_RELAYER,
+				true,
+				1
+			));
+			assert_ok!(<Assets as fungibles::Mutate<_>>::mint_into(
+				REWARD_ASSET,
+				&asset_rewards_account,
+				100
+			));
+			Balances::mint_into(&native_rewards_account, 100).unwrap();
+			Balances::mint_into(&asset_rewards_account, 200).unwrap();
+
+			// lanes without an asset are paid in the native balance
+			assert_ok!(PayLaneRewardInAsset::pay_reward(&1, native_lane, 50));
+			assert_eq!(Balances::balance(&1), 50);
+
+			// rewards are denominated in the native balance and converted to the asset
+			assert_ok!(PayLaneRewardInAsset::pay_reward(&1, asset_lane, 60));
+			assert_eq!(Assets::balance(REWARD_ASSET, 1), 30);
+			assert_eq!(Assets::balance(REWARD_ASSET, asset_rewards_account), 70);
+			assert_eq!(Balances::balance(&1), 50);
+
+			// if there is not enough of the asset, the reward is paid in the native balance
+			assert_ok!(PayLaneRewardInAsset::pay_reward(&1, asset_lane, 160));
+			assert_eq!(Assets::balance(REWARD_ASSET, 1), 30);
+			assert_eq!(Balances::balance(&1), 210);
+			assert_eq!(Balances::balance(&asset_rewards_account), 40);
+
+			assert!(PayLaneRewardInAsset::pay_reward(&1, asset_lane, 160).is_err());
+		});
+	}
+
 	#[test]
 	fn register_fails_if_valid_till_is_a_past_block() {
 		run_test(|| {
@@ -31,13 +31,13 @@ use bp_runtime::{messages::MessageDispatchResult, Chain, ChainId, Parachain};
 use codec::Encode;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::fungible::Mutate,
+	traits::{fungible::Mutate, tokens::ConversionToAssetBalance, AsEnsureOriginWithArg},
 	weights::{ConstantMultiplier, IdentityFee, RuntimeDbWeight, Weight},
 };
 use pallet_transaction_payment::Multiplier;
 use sp_core::{ConstU64, ConstU8, H256};
 use sp_runtime::{
-	traits::{BlakeTwo256, ConstU32},
+	traits::{BlakeTwo256, ConstU32, MaybeConvert},
 	BuildStorage, FixedPointNumber, Perquintill, StateVersion,
 };
 
@@ -174,6 +174,7 @@ frame_support::construct_runtime! {
 		System: frame_system,
 		Utility: pallet_utility,
 		Balances: pallet_balances,
+		Assets: pallet_assets,
 		TransactionPayment: pallet_transaction_payment,
 		BridgeRelayers: pallet_bridge_relayers,
 		BridgeGrandpa: pallet_bridge_grandpa,
@@ -212,6 +213,14 @@ impl pallet_balances::Config for TestRuntime {
 	type AccountStore = System;
 }
 
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for TestRuntime {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<ThisChainAccountId>>;
+	type ForceOrigin = frame_system::EnsureRoot<ThisChainAccountId>;
+	type Freezer = ();
+}
+
 impl pallet_utility::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -241,6 +250,7 @@ impl pallet_bridge_grandpa::Config for TestRuntime {
 	type MaxFreeHeadersPerBlock = ConstU32<4>;
 	type FreeHeadersInterval = ConstU32<1_024>;
 	type HeadersToKeep = ConstU32<8>;
+	type OnAuthoritySetCompromised = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<TestRuntime>;
 }
 
@@ -340,6 +350,40 @@ impl PaymentProcedure<ThisChainAccountId, ThisChainBalance> for TestPaymentProce
 	}
 }
 
+/// Asset that the rewards of the `asset_reward_lane()` are paid in.
+pub const REWARD_ASSET: u32 = 1;
+
+/// Lane which rewards are paid in the `REWARD_ASSET`.
+pub fn asset_reward_lane() -> TestLaneIdType {
+	TestLaneIdType::try_new(1, 3).unwrap()
+}
+
+/// Pays the rewards of the `asset_reward_lane()` in the `REWARD_ASSET`.
+pub struct TestLaneAsset;
+
+impl MaybeConvert<TestLaneIdType, u32> for TestLaneAsset {
+	fn maybe_convert(lane_id: TestLaneIdType) -> Option<u32> {
+		(lane_id == asset_reward_lane()).then_some(REWARD_ASSET)
+	}
+}
+
+/// One unit of the `REWARD_ASSET` is worth two units of the native balance.
+pub struct TestAssetRate;
+
+impl ConversionToAssetBalance<ThisChainBalance, u32, ThisChainBalance> for TestAssetRate {
+	type Error = ();
+
+	fn to_asset_balance(
+		balance: ThisChainBalance,
+		asset_id: u32,
+	) -> Result<ThisChainBalance, Self::Error> {
+		match asset_id {
+			REWARD_ASSET => Ok(balance / 2),
+			_ => Err(()),
+		}
+	}
+}
+
 /// Dummy message dispatcher.
 pub struct DummyMessageDispatch;
 
@@ -22,7 +22,7 @@ use bp_messages::{
 	source_chain::{DeliveryConfirmationPayments, RelayersRewards},
 	MessageNonce,
 };
-pub use bp_relayers::PayRewardFromAccount;
+pub use bp_relayers::{PayRewardFromAccount, PayRewardInAsset};
 use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
 use bp_runtime::Chain;
 use frame_support::{sp_runtime::SaturatedConversion, traits::Get};
@@ -131,6 +131,9 @@ impl pallet_bridge_messages::WeightInfo for TestMessagesWeights {
 	fn receive_single_n_bytes_message_proof_with_dispatch(_n: u32) -> Weight {
 		Weight::from_parts(1, 0)
 	}
+	fn set_outbound_lane_paused() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl pallet_bridge_messages::WeightInfoExt for TestMessagesWeights {
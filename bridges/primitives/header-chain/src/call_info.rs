@@ -54,6 +54,18 @@ pub enum BridgeGrandpaCall<Header: HeaderT> {
 		/// An identifier of the validators set, that have signed the justification.
 		current_set_id: SetId,
 	},
+	/// `pallet-bridge-grandpa::Call::submit_compact_finality_proof`
+	#[codec(index = 6)]
+	submit_compact_finality_proof {
+		/// The header that we are going to finalize.
+		finality_target: Box<Header>,
+		/// Compact finality justification for the `finality_target`.
+		justification: justification::CompactGrandpaJustification<Header>,
+		/// An identifier of the validators set, that have signed the justification.
+		current_set_id: SetId,
+		/// If `true`, the transaction must be executed for free.
+		is_free_execution_expected: bool,
+	},
 }
 
 /// The `BridgeGrandpaCall` for a pallet that bridges with given `C`;
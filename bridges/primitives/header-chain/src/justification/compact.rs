@@ -0,0 +1,159 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Compact representation of GRANDPA justifications.
+//!
+//! Most justifications that are submitted to the bridge have all precommits targeting the
+//! commit target and have no votes ancestries. For such justifications we don't need to repeat
+//! the precommit target in every signed precommit and we may reference authorities by their
+//! index in the current authorities set instead of including the full authority id.
+
+use super::GrandpaJustification;
+
+use bp_runtime::HeaderId;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_consensus_grandpa::{AuthorityId, AuthoritySignature};
+use sp_runtime::{traits::Header as HeaderT, RuntimeDebug};
+use sp_std::prelude::*;
+
+/// Errors that may happen when converting between full and compact justifications.
+#[derive(Eq, RuntimeDebug, PartialEq)]
+pub enum CompactJustificationError {
+	/// Justification has votes ancestries or precommits that are not targeting the commit target.
+	NotCompactable,
+	/// Precommit is signed by an authority that is not in the authorities set.
+	UnknownAuthority,
+	/// Precommits are not sorted by authority index or there are duplicate precommits.
+	InvalidPrecommitsOrder,
+}
+
+/// Signed precommit of the compact justification.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct CompactSignedPrecommit {
+	/// Index of the authority that has signed the precommit in the authorities set.
+	#[codec(compact)]
+	pub authority_index: u32,
+	/// Signature of the precommit.
+	pub signature: AuthoritySignature,
+}
+
+/// Compact GRANDPA justification.
+///
+/// It is equivalent to the [`GrandpaJustification`] with empty votes ancestries and all
+/// precommits targeting the commit target. It must be expanded using the authorities set that
+/// has signed it before verification, so the same checks are applied to both representations.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct CompactGrandpaJustification<Header: HeaderT> {
+	/// The round (voting period) this justification is valid for.
+	pub round: u64,
+	/// Hash of the header that is finalized by this justification.
+	pub target_hash: Header::Hash,
+	/// Number of the header that is finalized by this justification.
+	pub target_number: Header::Number,
+	/// Signed precommits, sorted by the authority index.
+	pub precommits: Vec<CompactSignedPrecommit>,
+}
+
+impl<H: HeaderT> CompactGrandpaJustification<H> {
+	/// Compress given justification, signed by given authorities set.
+	pub fn try_compress(
+		justification: &GrandpaJustification<H>,
+		authorities: &[AuthorityId],
+	) -> Result<Self, CompactJustificationError> {
+		if !justification.votes_ancestries.is_empty() {
+			return Err(CompactJustificationError::NotCompactable)
+		}
+
+		let commit = &justification.commit;
+		let mut precommits = commit
+			.precommits
+			.iter()
+			.map(|signed| {
+				if signed.precommit.target_hash != commit.target_hash ||
+					signed.precommit.target_number != commit.target_number
+				{
+					return Err(CompactJustificationError::NotCompactable)
+				}
+
+				let authority_index = authorities
+					.iter()
+					.position(|id| *id == signed.id)
+					.ok_or(CompactJustificationError::UnknownAuthority)?;
+				Ok(CompactSignedPrecommit {
+					authority_index: authority_index as u32,
+					signature: signed.signature.clone(),
+				})
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		precommits.sort_by_key(|precommit| precommit.authority_index);
+
+		Ok(CompactGrandpaJustification {
+			round: justification.round,
+			target_hash: commit.target_hash,
+			target_number: commit.target_number,
+			precommits,
+		})
+	}
+
+	/// Expand compact justification, signed by given authorities set.
+	///
+	/// The returned justification still needs to be verified.
+	pub fn try_expand(
+		self,
+		authorities: &[AuthorityId],
+	) -> Result<GrandpaJustification<H>, CompactJustificationError> {
+		let mut previous_index = None;
+		let precommits = self
+			.precommits
+			.into_iter()
+			.map(|precommit| {
+				if previous_index.map_or(false, |previous| previous >= precommit.authority_index) {
+					return Err(CompactJustificationError::InvalidPrecommitsOrder)
+				}
+				previous_index = Some(precommit.authority_index);
+
+				let id = authorities
+					.get(precommit.authority_index as usize)
+					.ok_or(CompactJustificationError::UnknownAuthority)?
+					.clone();
+				Ok(finality_grandpa::SignedPrecommit {
+					precommit: finality_grandpa::Precommit {
+						target_hash: self.target_hash,
+						target_number: self.target_number,
+					},
+					signature: precommit.signature,
+					id,
+				})
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(GrandpaJustification {
+			round: self.round,
+			commit: finality_grandpa::Commit {
+				target_hash: self.target_hash,
+				target_number: self.target_number,
+				precommits,
+			},
+			votes_ancestries: vec![],
+		})
+	}
+
+	/// Return identifier of header that this justification claims to finalize.
+	pub fn commit_target_id(&self) -> HeaderId<H::Hash, H::Number> {
+		HeaderId(self.target_number, self.target_hash)
+	}
+}
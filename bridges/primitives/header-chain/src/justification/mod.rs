@@ -19,9 +19,11 @@
 //! Adapted copy of substrate/client/finality-grandpa/src/justification.rs. If origin
 //! will ever be moved to the sp_consensus_grandpa, we should reuse that implementation.
 
+mod compact;
 mod verification;
 
 use crate::ChainWithGrandpa;
+pub use compact::{CompactGrandpaJustification, CompactJustificationError, CompactSignedPrecommit};
 pub use verification::{
 	equivocation::{EquivocationsCollector, GrandpaEquivocationsFinder},
 	optimizer::verify_and_optimize_justification,
@@ -32,13 +32,12 @@ use core::{clone::Clone, cmp::Eq, default::Default, fmt::Debug};
 use frame_support::PalletError;
 use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
-use sp_consensus_grandpa::{
-	AuthorityList, ConsensusLog, ScheduledChange, SetId, GRANDPA_ENGINE_ID,
-};
+use sp_consensus_grandpa::{AuthorityList, ConsensusLog, ScheduledChange, GRANDPA_ENGINE_ID};
 use sp_runtime::{traits::Header as HeaderT, Digest, RuntimeDebug, SaturatedConversion};
 use sp_std::{boxed::Box, vec::Vec};
 
 pub use call_info::{BridgeGrandpaCall, BridgeGrandpaCallOf, SubmitFinalityProofInfo};
+pub use sp_consensus_grandpa::SetId;
 
 mod call_info;
 
@@ -232,6 +231,20 @@ pub trait FindEquivocations<FinalityProof, FinalityVerificationContext, Equivoca
 	) -> Result<Vec<EquivocationProof>, Self::Error>;
 }
 
+/// Handler of the bridged chain authorities set compromise.
+///
+/// It is called when the pallet has received a valid proof that one of the authorities of
+/// the current bridged chain authorities set has equivocated. Headers, finalized after that,
+/// can't be trusted, so everything that depends on them (e.g. messages lanes) shall be paused.
+pub trait OnAuthoritySetCompromised {
+	/// Called when the authority set with given id has been marked as compromised.
+	fn on_authority_set_compromised(set_id: SetId);
+}
+
+impl OnAuthoritySetCompromised for () {
+	fn on_authority_set_compromised(_set_id: SetId) {}
+}
+
 /// Substrate-based chain that is using direct GRANDPA finality.
 ///
 /// Keep in mind that parachains are relying on relay chain GRANDPA, so they should not implement
@@ -324,16 +337,45 @@ pub struct SubmitFinalityProofCallExtras {
 pub fn submit_finality_proof_limits_extras<C: ChainWithGrandpa>(
 	header: &C::Header,
 	proof: &justification::GrandpaJustification<C::Header>,
+) -> SubmitFinalityProofCallExtras {
+	finality_proof_limits_extras::<C>(
+		header,
+		proof.commit.precommits.len().saturated_into(),
+		proof.votes_ancestries.len().saturated_into(),
+		proof.encoded_size().saturated_into(),
+	)
+}
+
+/// Checks whether the given `header` and its compact finality `proof` fit the maximal expected
+/// call limits (size and weight).
+///
+/// Compact justification has no votes ancestries, so it never exceeds the weight limit. The
+/// size limit is the same as for the regular justification.
+pub fn submit_compact_finality_proof_limits_extras<C: ChainWithGrandpa>(
+	header: &C::Header,
+	proof: &justification::CompactGrandpaJustification<C::Header>,
+) -> SubmitFinalityProofCallExtras {
+	finality_proof_limits_extras::<C>(
+		header,
+		proof.precommits.len().saturated_into(),
+		0,
+		proof.encoded_size().saturated_into(),
+	)
+}
+
+fn finality_proof_limits_extras<C: ChainWithGrandpa>(
+	header: &C::Header,
+	precommits_len: u32,
+	votes_ancestries_len: u32,
+	proof_size: u32,
 ) -> SubmitFinalityProofCallExtras {
 	// the `submit_finality_proof` call will reject justifications with invalid, duplicate,
 	// unknown and extra signatures. It'll also reject justifications with less than necessary
 	// signatures. So we do not care about extra weight because of additional signatures here.
-	let precommits_len = proof.commit.precommits.len().saturated_into();
 	let required_precommits = precommits_len;
 
 	// the weight check is simple - we assume that there are no more than the `limit`
 	// headers in the ancestry proof
-	let votes_ancestries_len: u32 = proof.votes_ancestries.len().saturated_into();
 	let is_weight_limit_exceeded =
 		votes_ancestries_len > C::REASONABLE_HEADERS_IN_JUSTIFICATION_ANCESTRY;
 
@@ -345,7 +387,7 @@ pub fn submit_finality_proof_limits_extras<C: ChainWithGrandpa>(
 
 	// we can estimate extra call size easily, without any additional significant overhead
 	let actual_call_size: u32 =
-		header.encoded_size().saturating_add(proof.encoded_size()).saturated_into();
+		header.encoded_size().saturated_into::<u32>().saturating_add(proof_size);
 	let max_expected_call_size = max_expected_submit_finality_proof_arguments_size::<C>(
 		is_mandatory_finality_target,
 		required_precommits,
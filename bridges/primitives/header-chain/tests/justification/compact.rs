@@ -0,0 +1,115 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for compact Grandpa justifications.
+
+use bp_header_chain::justification::{
+	verify_justification, CompactGrandpaJustification, CompactJustificationError,
+	CompactSignedPrecommit,
+};
+use bp_test_utils::*;
+use codec::Encode;
+use sp_consensus_grandpa::AuthorityId;
+
+type TestHeader = sp_runtime::testing::Header;
+
+fn authorities() -> Vec<AuthorityId> {
+	authority_list().into_iter().map(|(id, _)| id).collect()
+}
+
+fn justification_without_ancestries(
+) -> bp_header_chain::justification::GrandpaJustification<TestHeader> {
+	make_justification_for_header::<TestHeader>(JustificationGeneratorParams {
+		ancestors: 0,
+		..Default::default()
+	})
+}
+
+#[test]
+fn compact_justification_roundtrip_works() {
+	let justification = justification_without_ancestries();
+	let compact =
+		CompactGrandpaJustification::try_compress(&justification, &authorities()).unwrap();
+	assert_eq!(compact.commit_target_id(), justification.commit_target_id());
+
+	let expanded = compact.try_expand(&authorities()).unwrap();
+	assert_eq!(
+		verify_justification::<TestHeader>(
+			header_id::<TestHeader>(1),
+			&verification_context(TEST_GRANDPA_SET_ID),
+			&expanded,
+		),
+		Ok(()),
+	);
+}
+
+#[test]
+fn compact_justification_is_smaller() {
+	let justification = justification_without_ancestries();
+	let compact =
+		CompactGrandpaJustification::try_compress(&justification, &authorities()).unwrap();
+
+	// every signed precommit loses its target (hash + number) and the authority id (32 bytes),
+	// which is replaced with the compact-encoded authority index (1 byte for small sets)
+	let saved_per_precommit = 32 + 8 + 32 - 1;
+	assert!(
+		justification.encoded_size() - compact.encoded_size() >=
+			justification.commit.precommits.len() * saved_per_precommit
+	);
+}
+
+#[test]
+fn justification_with_ancestries_is_not_compactable() {
+	let justification = make_default_justification::<TestHeader>(&test_header(1));
+	assert_eq!(
+		CompactGrandpaJustification::try_compress(&justification, &authorities()),
+		Err(CompactJustificationError::NotCompactable),
+	);
+}
+
+#[test]
+fn justification_signed_by_unknown_authority_is_not_compactable() {
+	let justification = justification_without_ancestries();
+	assert_eq!(
+		CompactGrandpaJustification::try_compress(&justification, &authorities()[..1]),
+		Err(CompactJustificationError::UnknownAuthority),
+	);
+}
+
+#[test]
+fn compact_justification_with_unknown_authority_index_is_rejected() {
+	let justification = justification_without_ancestries();
+	let mut compact =
+		CompactGrandpaJustification::try_compress(&justification, &authorities()).unwrap();
+	compact.precommits.last_mut().unwrap().authority_index = authorities().len() as u32;
+	assert_eq!(
+		compact.try_expand(&authorities()),
+		Err(CompactJustificationError::UnknownAuthority),
+	);
+}
+
+#[test]
+fn compact_justification_with_duplicate_precommits_is_rejected() {
+	let justification = justification_without_ancestries();
+	let mut compact =
+		CompactGrandpaJustification::try_compress(&justification, &authorities()).unwrap();
+	let first: CompactSignedPrecommit = compact.precommits[0].clone();
+	compact.precommits.insert(0, first);
+	assert_eq!(
+		compact.try_expand(&authorities()),
+		Err(CompactJustificationError::InvalidPrecommitsOrder),
+	);
+}
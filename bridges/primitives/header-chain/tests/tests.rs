@@ -15,6 +15,7 @@
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
 mod justification {
+	mod compact;
 	mod equivocation;
 	mod optimizer;
 	mod strict;
@@ -311,6 +311,20 @@ pub struct OutboundMessageDetails {
 	pub size: u32,
 }
 
+/// Outbound lane status, returned by runtime APIs.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct OutboundLaneStatus {
+	/// Lane state.
+	pub state: LaneState,
+	/// True if the lane is paused.
+	///
+	/// Paused lane rejects new outbound messages, but already queued messages are still
+	/// delivered to the bridged chain and may be confirmed.
+	pub is_paused: bool,
+	/// Number of messages that are queued for delivery.
+	pub queued_messages: MessageNonce,
+}
+
 /// Inbound message details, returned by runtime APIs.
 #[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct InboundMessageDetails {
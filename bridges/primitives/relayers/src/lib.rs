@@ -26,14 +26,20 @@ pub use extension::{
 pub use registration::{ExplicitOrAccountParams, Registration, StakeAndSlash};
 
 use bp_runtime::{ChainId, StorageDoubleMapKeyProvider};
-use frame_support::{traits::tokens::Preservation, Blake2_128Concat, Identity};
+use frame_support::{
+	traits::{
+		fungible, fungibles,
+		tokens::{ConversionToAssetBalance, Preservation},
+	},
+	Blake2_128Concat, Identity,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	codec::{Codec, Decode, Encode, EncodeLike, MaxEncodedLen},
-	traits::AccountIdConversion,
-	TypeId,
+	traits::{AccountIdConversion, MaybeConvert},
+	DispatchError, TypeId,
 };
-use sp_std::{fmt::Debug, marker::PhantomData};
+use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
 mod extension;
 mod registration;
@@ -157,6 +163,74 @@ where
 	}
 }
 
+/// Reward payment procedure that pays the rewards of some lanes in an asset, e.g. an asset of the
+/// bridged chain that is held at this end of the bridge.
+///
+/// `LaneAsset` returns the asset the rewards of a lane are paid in. The rewards are denominated in
+/// the native currency, so the reward of such lane is converted to the asset with `AssetRate`,
+/// e.g. `pallet-asset-rate` or a price quoted by `pallet-asset-conversion`, and transferred from
+/// the same account as [`PayRewardFromAccount`] uses. If the reward cannot be converted, or that
+/// account does not hold enough of the asset, the reward is paid in the native currency instead.
+///
+/// The rewards of the other lanes are paid in the native currency, as [`PayRewardFromAccount`]
+/// does.
+pub struct PayRewardInAsset<Assets, Native, LaneAsset, AssetRate, Relayer, LaneId>(
+	PhantomData<(Assets, Native, LaneAsset, AssetRate, Relayer, LaneId)>,
+);
+
+impl<Assets, Native, LaneAsset, AssetRate, Relayer, LaneId>
+	PaymentProcedure<Relayer, Native::Balance>
+	for PayRewardInAsset<Assets, Native, LaneAsset, AssetRate, Relayer, LaneId>
+where
+	Assets: fungibles::Mutate<Relayer>,
+	Native: fungible::Mutate<Relayer>,
+	LaneAsset: MaybeConvert<LaneId, Assets::AssetId>,
+	AssetRate: ConversionToAssetBalance<Native::Balance, Assets::AssetId, Assets::Balance>,
+	Relayer: Decode + Encode + Eq,
+	LaneId: Decode + Encode + Copy,
+{
+	type Error = DispatchError;
+	type LaneId = LaneId;
+
+	fn pay_reward(
+		relayer: &Relayer,
+		rewards_account_params: RewardsAccountParams<Self::LaneId>,
+		reward: Native::Balance,
+	) -> Result<(), Self::Error> {
+		let Some(asset) = LaneAsset::maybe_convert(*rewards_account_params.lane_id()) else {
+			return PayRewardFromAccount::<Native, Relayer, LaneId>::pay_reward(
+				relayer,
+				rewards_account_params,
+				reward,
+			)
+		};
+
+		let rewards_account = PayRewardFromAccount::<Native, Relayer, LaneId>::rewards_account(
+			rewards_account_params,
+		);
+		let paid_in_asset =
+			AssetRate::to_asset_balance(reward, asset.clone()).is_ok_and(|asset_reward| {
+				Assets::transfer(
+					asset,
+					&rewards_account,
+					relayer,
+					asset_reward,
+					Preservation::Expendable,
+				)
+				.is_ok()
+			});
+		if paid_in_asset {
+			return Ok(())
+		}
+
+		PayRewardFromAccount::<Native, Relayer, LaneId>::pay_reward(
+			relayer,
+			rewards_account_params,
+			reward,
+		)
+	}
+}
+
 /// Can be used to access the runtime storage key within the `RelayerRewards` map of the relayers
 /// pallet.
 pub struct RelayerRewardsKeyProvider<AccountId, Reward, LaneId>(
@@ -179,6 +253,19 @@ where
 	type Value = Reward;
 }
 
+sp_api::decl_runtime_apis! {
+	/// API for querying rewards that may be claimed by relayers.
+	pub trait RelayerRewardsApi<AccountId, Reward, LaneId> where
+		AccountId: Codec,
+		Reward: Codec,
+		LaneId: Codec,
+	{
+		/// Returns all rewards that may be claimed by given relayer, along with parameters of
+		/// accounts that will pay them.
+		fn claimable_rewards(relayer: AccountId) -> Vec<(RewardsAccountParams<LaneId>, Reward)>;
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
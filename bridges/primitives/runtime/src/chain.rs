@@ -375,9 +375,11 @@ pub mod __private {
 /// This includes:
 /// - chain-specific bridge runtime APIs:
 ///     - `To<ThisChain>OutboundLaneApi<LaneIdType>`
+///     - `To<ThisChain>OutboundLaneStatusApi<LaneIdType>`
 ///     - `From<ThisChain>InboundLaneApi<LaneIdType>`
 /// - constants that are stringified names of runtime API methods:
 ///     - `FROM_<THIS_CHAIN>_MESSAGE_DETAILS_METHOD`,
+///     - `TO_<THIS_CHAIN>_OUTBOUND_LANE_STATUS_METHOD`,
 /// The name of the chain has to be specified in snake case (e.g. `bridge_hub_polkadot`).
 #[macro_export]
 macro_rules! decl_bridge_messages_runtime_apis {
@@ -394,6 +396,11 @@ macro_rules! decl_bridge_messages_runtime_apis {
 				pub const [<FROM_ $chain:upper _MESSAGE_DETAILS_METHOD>]: &str =
 					stringify!([<From $chain:camel InboundLaneApi_message_details>]);
 
+				/// Name of the `To<ThisChain>OutboundLaneStatusApi::outbound_lane_status` runtime
+				/// method.
+				pub const [<TO_ $chain:upper _OUTBOUND_LANE_STATUS_METHOD>]: &str =
+					stringify!([<To $chain:camel OutboundLaneStatusApi_outbound_lane_status>]);
+
 				sp_api::decl_runtime_apis! {
 					/// Outbound message lane API for messages that are sent to this chain.
 					///
@@ -412,6 +419,18 @@ macro_rules! decl_bridge_messages_runtime_apis {
 						) -> sp_std::vec::Vec<bp_messages::OutboundMessageDetails>;
 					}
 
+					/// Outbound message lane status API for messages that are sent to this chain.
+					///
+					/// This API is implemented by runtimes that are sending messages to this chain, not by this
+					/// chain's runtime itself.
+					pub trait [<To $chain:camel OutboundLaneStatusApi>] {
+						/// Returns state of the outbound lane, including whether it is paused and
+						/// the number of queued messages. Returns `None` if the lane is unknown.
+						fn outbound_lane_status(
+							lane: $lane_id_type,
+						) -> Option<bp_messages::OutboundLaneStatus>;
+					}
+
 					/// Inbound message lane API for messages sent by this chain.
 					///
 					/// This API is implemented by runtimes that are receiving messages from this chain, not by this
@@ -228,6 +228,28 @@ pub fn signed_precommit<H: HeaderT>(
 	finality_grandpa::SignedPrecommit { precommit, signature, id }
 }
 
+/// Create proof that given signer has signed two precommits with different targets in the
+/// same round.
+pub fn make_precommit_equivocation_proof<H: HeaderT>(
+	signer: &Account,
+	first_target: (H::Hash, H::Number),
+	second_target: (H::Hash, H::Number),
+	round: u64,
+	set_id: SetId,
+) -> sp_consensus_grandpa::EquivocationProof<H::Hash, H::Number> {
+	let first = signed_precommit::<H>(signer, first_target, round, set_id);
+	let second = signed_precommit::<H>(signer, second_target, round, set_id);
+	sp_consensus_grandpa::EquivocationProof::new(
+		set_id,
+		sp_consensus_grandpa::Equivocation::Precommit(finality_grandpa::Equivocation {
+			round_number: round,
+			identity: (*signer).into(),
+			first: (first.precommit, first.signature),
+			second: (second.precommit, second.signature),
+		}),
+	)
+}
+
 /// Get a header for testing.
 ///
 /// The correct parent hash will be used if given a non-zero header.
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use snowbridge_core::{
+	inbound::{MessageImportStatus, MessageNonce, ProcessedMessage},
+	ChannelId,
+};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait InboundQueueApi<BlockNumber> where BlockNumber: Codec
+	{
+		/// Get the nonce of the latest message imported from `channel_id`
+		fn latest_nonce(channel_id: ChannelId) -> MessageNonce;
+
+		/// Check whether the message with `nonce` from `channel_id` has been imported and with
+		/// what result
+		fn message_import_status(channel_id: ChannelId, nonce: MessageNonce) -> MessageImportStatus<BlockNumber>;
+
+		/// Export details of processed messages from `channel_id`, starting at `from_nonce`. At
+		/// most `max_count` messages are returned. Messages with pruned details are skipped.
+		fn processed_messages(channel_id: ChannelId, from_nonce: MessageNonce, max_count: u32) -> Vec<(MessageNonce, ProcessedMessage<BlockNumber>)>;
+	}
+}
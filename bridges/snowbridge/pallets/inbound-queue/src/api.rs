@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Helpers for implementing runtime api
+
+use crate::{Config, Nonce, ProcessedMessages};
+use frame_system::pallet_prelude::BlockNumberFor;
+use snowbridge_core::{
+	inbound::{MessageImportStatus, MessageNonce, ProcessedMessage},
+	ChannelId,
+};
+use sp_core::Get;
+use sp_std::prelude::*;
+
+pub fn latest_nonce<T>(channel_id: ChannelId) -> MessageNonce
+where
+	T: Config,
+{
+	Nonce::<T>::get(channel_id)
+}
+
+pub fn message_import_status<T>(
+	channel_id: ChannelId,
+	nonce: MessageNonce,
+) -> MessageImportStatus<BlockNumberFor<T>>
+where
+	T: Config,
+{
+	// nonces are sequential and start at 1, so everything up to the latest nonce is imported
+	if nonce == 0 || nonce > Nonce::<T>::get(channel_id) {
+		return MessageImportStatus::NotImported
+	}
+
+	match ProcessedMessages::<T>::get((channel_id, nonce)) {
+		Some(message) => MessageImportStatus::Processed(message),
+		None => MessageImportStatus::Pruned,
+	}
+}
+
+pub fn processed_messages<T>(
+	channel_id: ChannelId,
+	from_nonce: MessageNonce,
+	max_count: u32,
+) -> Vec<(MessageNonce, ProcessedMessage<BlockNumberFor<T>>)>
+where
+	T: Config,
+{
+	// the ring buffer is shared by all channels, so details of messages that are older than
+	// `MaxProcessedMessagesToKeep` are always pruned and we don't need to look at them
+	let latest_nonce = Nonce::<T>::get(channel_id);
+	let oldest_kept_nonce = latest_nonce
+		.saturating_sub(T::MaxProcessedMessagesToKeep::get().into())
+		.saturating_add(1);
+	(from_nonce.max(oldest_kept_nonce)..=latest_nonce)
+		.filter_map(|nonce| {
+			ProcessedMessages::<T>::get((channel_id, nonce)).map(|message| (nonce, message))
+		})
+		.take(max_count as usize)
+		.collect()
+}
@@ -21,8 +21,15 @@
 //!
 //! * [`Call::submit`]: Submit a message for verification and dispatch the final destination
 //!   parachain.
+//!
+//! # Runtime API
+//!
+//! The [`api`] module contains helpers for implementing the inbound queue runtime API. It can be
+//! used by bridge monitors to check whether a message was imported and to detect missed or stuck
+//! messages.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod api;
 mod envelope;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -39,6 +46,7 @@ mod test;
 use codec::{Decode, DecodeAll, Encode};
 use envelope::Envelope;
 use frame_support::{
+	storage::types::OptionQuery,
 	traits::{
 		fungible::{Inspect, Mutate},
 		tokens::{Fortitude, Preservation},
@@ -57,9 +65,9 @@ use xcm::prelude::{
 use xcm_executor::traits::TransactAsset;
 
 use snowbridge_core::{
-	inbound::{Message, VerificationError, Verifier},
+	inbound::{Message, ProcessedMessage, VerificationError, Verifier},
 	sibling_sovereign_account, BasicOperatingMode, Channel, ChannelId, ParaId, PricingParameters,
-	StaticLookup,
+	RingBufferMap, RingBufferMapImpl, StaticLookup,
 };
 use snowbridge_router_primitives::inbound::{
 	ConvertMessage, ConvertMessageError, VersionedMessage,
@@ -76,6 +84,16 @@ type BalanceOf<T> =
 
 pub use pallet::*;
 
+/// Processed messages ring buffer implementation
+pub type ProcessedMessagesBuffer<T> = RingBufferMapImpl<
+	u32,
+	<T as Config>::MaxProcessedMessagesToKeep,
+	ProcessedMessagesIndex<T>,
+	ProcessedMessagesMapping<T>,
+	ProcessedMessages<T>,
+	OptionQuery,
+>;
+
 pub const LOG_TARGET: &str = "snowbridge-inbound-queue";
 
 #[frame_support::pallet]
@@ -139,6 +157,10 @@ pub mod pallet {
 
 		/// To withdraw and deposit an asset.
 		type AssetTransactor: TransactAsset;
+
+		/// Maximum number of processed messages to keep details for, across all channels
+		#[pallet::constant]
+		type MaxProcessedMessagesToKeep: Get<u32>;
 	}
 
 	#[pallet::hooks]
@@ -224,6 +246,25 @@ pub mod pallet {
 	#[pallet::getter(fn operating_mode)]
 	pub type OperatingMode<T: Config> = StorageValue<_, BasicOperatingMode, ValueQuery>;
 
+	/// Processed Messages: Details of recently processed messages, keyed by channel and nonce
+	#[pallet::storage]
+	pub type ProcessedMessages<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(ChannelId, u64),
+		ProcessedMessage<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Processed Messages: Current position in ring buffer
+	#[pallet::storage]
+	pub type ProcessedMessagesIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// Processed Messages: Mapping of ring buffer index to a pruning candidate
+	#[pallet::storage]
+	pub type ProcessedMessagesMapping<T: Config> =
+		StorageMap<_, Identity, u32, (ChannelId, u64), ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Submit an inbound message originating from the Gateway contract on Ethereum
@@ -295,6 +336,16 @@ pub mod pallet {
 			// Attempt to send XCM to a dest parachain
 			let message_id = Self::send_xcm(xcm, channel.para_id)?;
 
+			// Record processed message, so it can be audited later
+			ProcessedMessagesBuffer::<T>::insert(
+				(envelope.channel_id, envelope.nonce),
+				ProcessedMessage {
+					block_number: frame_system::Pallet::<T>::block_number(),
+					para_id: channel.para_id,
+					message_id,
+				},
+			);
+
 			Self::deposit_event(Event::MessageReceived {
 				channel_id: envelope.channel_id,
 				nonce: envelope.nonce,
@@ -246,6 +246,7 @@ impl inbound_queue::Config for Test {
 	type LengthToFee = IdentityFee<u128>;
 	type MaxMessageSize = ConstU32<1024>;
 	type AssetTransactor = SuccessfulTransactor;
+	type MaxProcessedMessagesToKeep = ConstU32<2>;
 }
 
 pub fn setup() {
@@ -4,7 +4,10 @@ use super::*;
 
 use frame_support::{assert_noop, assert_ok};
 use hex_literal::hex;
-use snowbridge_core::{inbound::Proof, ChannelId};
+use snowbridge_core::{
+	inbound::{MessageImportStatus, Proof},
+	ChannelId,
+};
 use sp_keyring::Sr25519Keyring as Keyring;
 use sp_runtime::DispatchError;
 use sp_std::convert::From;
@@ -242,3 +245,77 @@ fn test_submit_no_funds_to_reward_relayers_and_ed_preserved() {
 		assert_eq!(amount, ExistentialDeposit::get());
 	});
 }
+
+#[test]
+fn test_submit_records_processed_messages() {
+	new_tester().execute_with(|| {
+		let relayer: AccountId = Keyring::Bob.into();
+		let origin = RuntimeOrigin::signed(relayer);
+		let channel_id: ChannelId =
+			hex!("c173fac324158e77fb5840738a1a541f633cbec8884c6a601c567d2b376a0539").into();
+
+		assert_eq!(
+			api::message_import_status::<Test>(channel_id, 1),
+			MessageImportStatus::NotImported
+		);
+
+		// Submit messages with nonces 1, 2 and 3
+		let mut message_ids = vec![];
+		for nonce in 1..=3 {
+			let mut event_log = mock_event_log();
+			event_log.data[31] = nonce;
+			let message = Message {
+				event_log,
+				proof: Proof {
+					receipt_proof: Default::default(),
+					execution_proof: mock_execution_proof(),
+				},
+			};
+			assert_ok!(InboundQueue::submit(origin.clone(), message));
+
+			let message_id = frame_system::Pallet::<Test>::events()
+				.into_iter()
+				.find_map(|event| match event.event {
+					RuntimeEvent::InboundQueue(Event::MessageReceived {
+						nonce: event_nonce,
+						message_id,
+						..
+					}) if event_nonce == nonce as u64 => Some(message_id),
+					_ => None,
+				})
+				.expect("message has been received");
+			message_ids.push(message_id);
+		}
+		let processed_message = |nonce: usize| ProcessedMessage {
+			block_number: 1,
+			para_id: ASSET_HUB_PARAID.into(),
+			message_id: message_ids[nonce - 1],
+		};
+
+		// Details of the first message have been pruned
+		assert_eq!(api::latest_nonce::<Test>(channel_id), 3);
+		assert_eq!(api::message_import_status::<Test>(channel_id, 1), MessageImportStatus::Pruned);
+		assert_eq!(
+			api::message_import_status::<Test>(channel_id, 3),
+			MessageImportStatus::Processed(processed_message(3)),
+		);
+		assert_eq!(
+			api::message_import_status::<Test>(channel_id, 4),
+			MessageImportStatus::NotImported
+		);
+
+		// Export processed messages
+		assert_eq!(
+			api::processed_messages::<Test>(channel_id, 0, 10),
+			vec![(2, processed_message(2)), (3, processed_message(3))],
+		);
+		assert_eq!(
+			api::processed_messages::<Test>(channel_id, 0, 1),
+			vec![(2, processed_message(2))],
+		);
+		assert_eq!(
+			api::processed_messages::<Test>(channel_id, 3, 10),
+			vec![(3, processed_message(3))],
+		);
+	});
+}
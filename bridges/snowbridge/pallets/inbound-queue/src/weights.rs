@@ -25,7 +25,7 @@ impl WeightInfo for () {
     fn submit() -> Weight {
         Weight::from_parts(70_000_000, 0)
             .saturating_add(Weight::from_parts(0, 3601))
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
 }
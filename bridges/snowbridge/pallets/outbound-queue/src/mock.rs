@@ -66,6 +66,8 @@ impl pallet_message_queue::Config for Test {
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
 	type QueuePausedQuery = ();
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 }
 
 parameter_types! {
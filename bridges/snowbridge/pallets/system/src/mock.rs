@@ -140,6 +140,8 @@ impl pallet_message_queue::Config for Test {
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
 	type QueuePausedQuery = ();
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 }
 
 parameter_types! {
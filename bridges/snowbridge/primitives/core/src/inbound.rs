@@ -2,7 +2,8 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 //! Types for representing inbound messages
 
-use codec::{Decode, Encode};
+use crate::ParaId;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::PalletError;
 use scale_info::TypeInfo;
 use snowbridge_beacon_primitives::{BeaconHeader, ExecutionProof};
@@ -74,6 +75,28 @@ pub struct Proof {
 	pub execution_proof: ExecutionProof,
 }
 
+/// Details of the inbound message that has been imported and forwarded to its destination
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ProcessedMessage<BlockNumber> {
+	/// Block number at which the message was imported
+	pub block_number: BlockNumber,
+	/// The destination parachain
+	pub para_id: ParaId,
+	/// ID of the XCM message which was forwarded to the destination parachain
+	pub message_id: [u8; 32],
+}
+
+/// Import status of an inbound message
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MessageImportStatus<BlockNumber> {
+	/// Message has not been imported yet
+	NotImported,
+	/// Message has been imported, but its details have already been pruned
+	Pruned,
+	/// Message has been imported and forwarded to its destination
+	Processed(ProcessedMessage<BlockNumber>),
+}
+
 #[derive(Clone, RuntimeDebug)]
 pub struct InboundQueueFixture {
 	pub message: Message,
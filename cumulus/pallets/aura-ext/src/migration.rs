@@ -0,0 +1,40 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Migrations for the AuRa extension pallet.
+
+use crate::{Config, SlotInfo};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+/// Adapt the slot bookkeeping after a change of the parachain slot duration, e.g. from 12s to 6s
+/// when enabling async backing.
+///
+/// This runs [`pallet_aura::migrations::ChangeSlotDuration`] and then resets [`SlotInfo`] to the
+/// recomputed slot, without any block authored in it yet. Like the former, it can be applied more
+/// than once.
+pub struct ChangeSlotDuration<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ChangeSlotDuration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let weight = pallet_aura::migrations::ChangeSlotDuration::<T>::on_runtime_upgrade();
+		SlotInfo::<T>::put((pallet_aura::CurrentSlot::<T>::get(), 0));
+		weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+}
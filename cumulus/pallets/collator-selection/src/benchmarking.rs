@@ -221,6 +221,19 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_rotation_parameters() -> Result<(), BenchmarkError> {
+		let parameters = RotationParametersOf::<T>::default();
+		let origin =
+			T::UpdateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, parameters.clone());
+
+		assert_last_event::<T>(Event::NewRotationParameters { parameters }.into());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn set_candidacy_bond(
 		c: Linear<0, { T::MaxCandidates::get() }>,
@@ -452,5 +465,43 @@ mod benchmarks {
 		}
 	}
 
+	// worst case for session start: all tracked collators missed the session and get slashed.
+	#[benchmark]
+	fn start_session(c: Linear<1, { T::MaxCandidates::get() }>) {
+		CandidacyBond::<T>::put(T::Currency::minimum_balance());
+		DesiredCandidates::<T>::put(c);
+		T::Currency::make_free_balance_be(
+			&<CollatorSelection<T>>::account_id(),
+			T::Currency::minimum_balance(),
+		);
+
+		register_validators::<T>(c);
+		register_candidates::<T>(c);
+
+		for candidate_info in CandidateList::<T>::get() {
+			CollatorPerformance::<T>::insert(
+				&candidate_info.who,
+				CollatorPerformanceInfo {
+					active: true,
+					consecutive_sessions_missed: T::MissedSessionsThreshold::get()
+						.saturating_sub(1),
+					queued_for: Some(1),
+					..Default::default()
+				},
+			);
+		}
+
+		#[block]
+		{
+			<CollatorSelection<T> as SessionManager<_>>::start_session(1);
+		}
+
+		for candidate_info in CandidateList::<T>::get() {
+			let performance = CollatorPerformance::<T>::get(&candidate_info.who).unwrap();
+			assert_eq!(performance.sessions_missed, 1);
+			assert!(performance.active);
+		}
+	}
+
 	impl_benchmark_test_suite!(CollatorSelection, crate::mock::new_test_ext(), crate::mock::Test,);
 }
@@ -65,6 +65,23 @@
 //! the desired number of collators is reached. Candidates can increase or decrease their deposits
 //! between sessions in order to ensure they receive a slot in the collator list.
 //!
+//! ### Rotation
+//!
+//! The selection and the order of the collators of a new session is decided by the
+//! [`Config::RotationPolicy`]. The order determines which slots every collator gets assigned by
+//! Aura. The parameters of the policy are kept in [`RotationParameters`] and can be updated by the
+//! `UpdateOrigin` through `set_rotation_parameters`. See the [`rotation`] module for the available
+//! policies.
+//!
+//! ### Performance
+//!
+//! The pallet tracks the block production of every collator in [`CollatorPerformance`]. When a
+//! session ends, collators that did not author any block during it are considered to have missed
+//! the session. Candidates that miss [`Config::MissedSessionsThreshold`] sessions in a row get
+//! [`Config::MissedSessionsSlash`] of their deposit slashed into the Pot. If their remaining
+//! deposit falls below the candidacy bond, they are removed from the candidate list. The counters
+//! can be queried through the [`runtime_api::CollatorPerformanceApi`].
+//!
 //! ### Rewards
 //!
 //! The Collator Selection pallet maintains an on-chain account (the "Pot"). In each block, the
@@ -96,12 +113,15 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migration;
+pub mod rotation;
+pub mod runtime_api;
 pub mod weights;
 
 const LOG_TARGET: &str = "runtime::collator-selection";
 
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::rotation::RotationPolicy;
 	pub use crate::weights::WeightInfo;
 	use alloc::vec::Vec;
 	use core::ops::Div;
@@ -125,9 +145,16 @@ pub mod pallet {
 	/// The in-code storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
-	type BalanceOf<T> =
+	/// The balance type of this pallet.
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 
+	/// The parameters of the [`Config::RotationPolicy`].
+	pub type RotationParametersOf<T> = <<T as Config>::RotationPolicy as RotationPolicy<
+		<T as SystemConfig>::AccountId,
+		BalanceOf<T>,
+	>>::Parameters;
+
 	/// A convertor from collators id. Since this pallet does not have stash/controller, this is
 	/// just identity.
 	pub struct IdentityCollator;
@@ -179,6 +206,22 @@ pub mod pallet {
 		/// Validate a user is registered
 		type ValidatorRegistration: ValidatorRegistration<Self::ValidatorId>;
 
+		/// The policy deciding the collators of a new session and the order of their slots.
+		///
+		/// Use [`InvulnerablesThenCandidates`](crate::rotation::InvulnerablesThenCandidates) to
+		/// select the invulnerables followed by the candidates with the highest deposits.
+		type RotationPolicy: RotationPolicy<Self::AccountId, BalanceOf<Self>>;
+
+		/// Number of sessions in a row a candidate may be a collator without authoring any block
+		/// before [`Config::MissedSessionsSlash`] of its deposit is slashed.
+		///
+		/// Zero disables slashing. Invulnerables are never slashed.
+		type MissedSessionsThreshold: Get<u32>;
+
+		/// The fraction of the deposit of a candidate that is slashed once it reaches
+		/// [`Config::MissedSessionsThreshold`].
+		type MissedSessionsSlash: Get<Perbill>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -194,6 +237,36 @@ pub mod pallet {
 		pub deposit: Balance,
 	}
 
+	/// Block production performance of a collator.
+	#[derive(
+		PartialEq,
+		Eq,
+		Clone,
+		Default,
+		Encode,
+		Decode,
+		RuntimeDebug,
+		scale_info::TypeInfo,
+		MaxEncodedLen,
+	)]
+	pub struct CollatorPerformanceInfo {
+		/// Number of sessions in which the account was a collator.
+		pub sessions_active: u32,
+		/// Number of sessions in which the account was a collator without authoring any block.
+		pub sessions_missed: u32,
+		/// Number of sessions missed in a row. Reset when a block is authored or when the account
+		/// reaches [`Config::MissedSessionsThreshold`].
+		pub consecutive_sessions_missed: u32,
+		/// Number of blocks authored in total.
+		pub blocks_authored: u32,
+		/// Number of blocks authored in the current session.
+		pub session_blocks_authored: u32,
+		/// Whether the account is a collator of the current session.
+		pub active: bool,
+		/// The upcoming session the account was selected as a collator for, if any.
+		pub queued_for: Option<SessionIndex>,
+	}
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -232,6 +305,15 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// The parameters of the [`Config::RotationPolicy`].
+	#[pallet::storage]
+	pub type RotationParameters<T: Config> = StorageValue<_, RotationParametersOf<T>, ValueQuery>;
+
+	/// Block production performance of the current and recent collators.
+	#[pallet::storage]
+	pub type CollatorPerformance<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, CollatorPerformanceInfo, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -292,6 +374,12 @@ pub mod pallet {
 		/// An account was unable to be added to the Invulnerables because they did not have keys
 		/// registered. Other Invulnerables may have been set.
 		InvalidInvulnerableSkipped { account_id: T::AccountId },
+		/// The parameters of the rotation policy were set.
+		NewRotationParameters { parameters: RotationParametersOf<T> },
+		/// The collators of a new session were assembled, in the order of their slots.
+		CollatorsRotated { session_index: SessionIndex, collators: Vec<T::AccountId> },
+		/// A candidate was slashed for missing `MissedSessionsThreshold` sessions in a row.
+		CandidateSlashed { account_id: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -795,6 +883,24 @@ pub mod pallet {
 			Self::deposit_event(Event::CandidateReplaced { old: target, new: who, deposit });
 			Ok(Some(T::WeightInfo::take_candidate_slot(length as u32)).into())
 		}
+
+		/// Set the parameters of the rotation policy.
+		///
+		/// The new parameters are used from the next time the collators of a new session are
+		/// assembled.
+		///
+		/// The origin for this call must be the `UpdateOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_rotation_parameters())]
+		pub fn set_rotation_parameters(
+			origin: OriginFor<T>,
+			parameters: RotationParametersOf<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			RotationParameters::<T>::put(parameters.clone());
+			Self::deposit_event(Event::NewRotationParameters { parameters });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -835,22 +941,127 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Assemble the current set of candidates and invulnerables into the next collator set.
+		/// Assemble the current set of candidates and invulnerables into the collator set of the
+		/// session `session_index`, in the order decided by the [`Config::RotationPolicy`].
 		///
 		/// This is done on the fly, as frequent as we are told to do so, as the session manager.
-		pub fn assemble_collators() -> Vec<T::AccountId> {
-			// Casting `u32` to `usize` should be safe on all machines running this.
-			let desired_candidates = DesiredCandidates::<T>::get() as usize;
-			let mut collators = Invulnerables::<T>::get().to_vec();
-			collators.extend(
-				CandidateList::<T>::get()
+		pub fn assemble_collators(session_index: SessionIndex) -> Vec<T::AccountId> {
+			T::RotationPolicy::collators(
+				session_index,
+				&RotationParameters::<T>::get(),
+				Invulnerables::<T>::get().to_vec(),
+				CandidateList::<T>::get().to_vec(),
+				DesiredCandidates::<T>::get(),
+			)
+		}
+
+		/// Evaluate the block production of the collators of the session that just ended and mark
+		/// the collators of `session_index` as active.
+		///
+		/// Candidates reaching [`Config::MissedSessionsThreshold`] are slashed. Records of accounts
+		/// that are neither collators nor eligible collators anymore are removed.
+		///
+		/// Return value is the number of evaluated records.
+		pub fn note_session_start(session_index: SessionIndex) -> u32 {
+			let threshold = T::MissedSessionsThreshold::get();
+			let invulnerables = Invulnerables::<T>::get();
+			let candidates = CandidateList::<T>::get();
+			let mut to_slash = Vec::new();
+			let mut evaluated = 0u32;
+
+			for (who, mut performance) in CollatorPerformance::<T>::iter().collect::<Vec<_>>() {
+				evaluated.saturating_inc();
+				if performance.active {
+					if performance.session_blocks_authored.is_zero() {
+						performance.sessions_missed.saturating_inc();
+						performance.consecutive_sessions_missed.saturating_inc();
+					} else {
+						performance.consecutive_sessions_missed = 0;
+					}
+					performance.active = false;
+				}
+				performance.session_blocks_authored = 0;
+				if let Some(queued_for) = performance.queued_for {
+					if queued_for <= session_index {
+						performance.active = queued_for == session_index;
+						performance.queued_for = None;
+					}
+					if performance.active {
+						performance.sessions_active.saturating_inc();
+					}
+				}
+				if threshold > 0 && performance.consecutive_sessions_missed >= threshold {
+					performance.consecutive_sessions_missed = 0;
+					to_slash.push(who.clone());
+				}
+
+				let is_eligible = invulnerables.contains(&who) ||
+					candidates.iter().any(|candidate_info| candidate_info.who == who);
+				if performance.active || performance.queued_for.is_some() || is_eligible {
+					CollatorPerformance::<T>::insert(&who, performance);
+				} else {
+					CollatorPerformance::<T>::remove(&who);
+				}
+			}
+
+			for who in to_slash {
+				Self::slash_candidate(&who);
+			}
+
+			evaluated
+		}
+
+		/// Slash [`Config::MissedSessionsSlash`] of the deposit of the candidate `who` into the
+		/// Pot.
+		///
+		/// The candidate is removed if its remaining deposit is lower than the candidacy bond,
+		/// unless that would leave too few eligible collators. Does nothing if `who` is not a
+		/// candidate.
+		fn slash_candidate(who: &T::AccountId) {
+			let slash_fraction = T::MissedSessionsSlash::get();
+			let slashed = CandidateList::<T>::mutate(|candidates| {
+				let idx = candidates.iter().position(|candidate_info| candidate_info.who == *who)?;
+				let mut info = candidates.remove(idx);
+				let slash = slash_fraction * info.deposit;
+				// `repatriate_reserved` returns the amount that could not be moved.
+				let not_slashed = T::Currency::repatriate_reserved(
+					who,
+					&Self::account_id(),
+					slash,
+					BalanceStatus::Free,
+				)
+				.unwrap_or(slash);
+				let amount = slash.saturating_sub(not_slashed);
+				info.deposit = info.deposit.saturating_sub(amount);
+				let remaining = info.deposit;
+				// Keep the list sorted by deposit.
+				let new_pos = candidates
 					.iter()
-					.rev()
-					.cloned()
-					.take(desired_candidates)
-					.map(|candidate_info| candidate_info.who),
-			);
-			collators
+					.position(|candidate| candidate.deposit >= remaining)
+					.unwrap_or_else(|| candidates.len());
+				candidates
+					.try_insert(new_pos, info)
+					.expect("candidate previously removed from the list; qed");
+				Some((amount, remaining))
+			});
+			let Some((amount, remaining)) = slashed else { return };
+
+			Self::deposit_event(Event::CandidateSlashed { account_id: who.clone(), amount });
+			if remaining < CandidacyBond::<T>::get() &&
+				Self::eligible_collators() > T::MinEligibleCollators::get()
+			{
+				let _ = Self::try_remove_candidate(who, true);
+			}
+		}
+
+		/// The block production performance of `who`, if tracked.
+		pub fn collator_performance(who: &T::AccountId) -> Option<CollatorPerformanceInfo> {
+			CollatorPerformance::<T>::get(who)
+		}
+
+		/// The block production performance of all the tracked collators.
+		pub fn all_collator_performance() -> Vec<(T::AccountId, CollatorPerformanceInfo)> {
+			CollatorPerformance::<T>::iter().collect()
 		}
 
 		/// Kicks out candidates that did not produce a block in the kick threshold and refunds
@@ -938,6 +1149,11 @@ pub mod pallet {
 			// `reward` is half of pot account minus ED, this should never fail.
 			let _success = T::Currency::transfer(&pot, &author, reward, KeepAlive);
 			debug_assert!(_success.is_ok());
+			CollatorPerformance::<T>::mutate(&author, |performance| {
+				let performance = performance.get_or_insert_with(Default::default);
+				performance.blocks_authored.saturating_inc();
+				performance.session_blocks_authored.saturating_inc();
+			});
 			LastAuthoredBlock::<T>::insert(author, frame_system::Pallet::<T>::block_number());
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
@@ -969,16 +1185,33 @@ pub mod pallet {
 					.map(|candidate_info| candidate_info.who.clone()),
 			);
 			let removed = candidates_len_before.saturating_sub(active_candidates_count);
-			let result = Self::assemble_collators();
+			let result = Self::assemble_collators(index);
+			for collator in &result {
+				CollatorPerformance::<T>::mutate(collator, |performance| {
+					performance.get_or_insert_with(Default::default).queued_for = Some(index);
+				});
+			}
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::WeightInfo::new_session(removed, candidates_len_before),
+				T::WeightInfo::new_session(removed, candidates_len_before)
+					.saturating_add(T::RotationPolicy::weight(result.len() as u32))
+					.saturating_add(
+						T::DbWeight::get().reads_writes(result.len() as u64, result.len() as u64),
+					),
 				DispatchClass::Mandatory,
 			);
+			Self::deposit_event(Event::CollatorsRotated {
+				session_index: index,
+				collators: result.clone(),
+			});
 			Some(result)
 		}
-		fn start_session(_: SessionIndex) {
-			// we don't care.
+		fn start_session(index: SessionIndex) {
+			let evaluated = Self::note_session_start(index);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::start_session(evaluated),
+				DispatchClass::Mandatory,
+			);
 		}
 		fn end_session(_: SessionIndex) {
 			// we don't care.
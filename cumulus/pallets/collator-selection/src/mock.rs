@@ -15,6 +15,7 @@
 
 use super::*;
 use crate as collator_selection;
+use crate::rotation::RoundRobinWithBackoff;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
 	traits::{ConstBool, ConstU32, ConstU64, FindAuthor, ValidatorRegistration},
@@ -22,7 +23,9 @@ use frame_support::{
 };
 use frame_system as system;
 use frame_system::EnsureSignedBy;
-use sp_runtime::{testing::UintAuthorityId, traits::OpaqueKeys, BuildStorage, RuntimeAppPublic};
+use sp_runtime::{
+	testing::UintAuthorityId, traits::OpaqueKeys, BuildStorage, Perbill, RuntimeAppPublic,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -150,6 +153,11 @@ parameter_types! {
 	pub const PotId: PalletId = PalletId(*b"PotStake");
 }
 
+parameter_types! {
+	pub static MissedSessionsThreshold: u32 = 0;
+	pub const MissedSessionsSlash: Perbill = Perbill::from_percent(50);
+}
+
 pub struct IsRegistered;
 impl ValidatorRegistration<u64> for IsRegistered {
 	fn is_registered(id: &u64) -> bool {
@@ -169,6 +177,9 @@ impl Config for Test {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = IdentityCollator;
 	type ValidatorRegistration = IsRegistered;
+	type RotationPolicy = RoundRobinWithBackoff<Test>;
+	type MissedSessionsThreshold = MissedSessionsThreshold;
+	type MissedSessionsSlash = MissedSessionsSlash;
 	type WeightInfo = ();
 }
 
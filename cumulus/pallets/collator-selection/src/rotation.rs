@@ -0,0 +1,151 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Collator rotation policies.
+//!
+//! Aura assigns the slots of a session to its authorities in a round-robin fashion, in the order
+//! of the collators returned by the session manager. A [`RotationPolicy`] decides which of the
+//! invulnerables and candidates become the collators of a new session and in which order, i.e.
+//! which slots every collator is going to get.
+
+use crate::{BalanceOf, CandidateInfo, Config, LastAuthoredBlock};
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{pallet_prelude::*, weights::Weight};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	RuntimeDebug,
+};
+use sp_staking::SessionIndex;
+
+/// A policy deciding the collators of a new session and the order in which they get slots.
+pub trait RotationPolicy<AccountId, Balance> {
+	/// The parameters of the policy.
+	///
+	/// They are kept in the [`RotationParameters`](crate::RotationParameters) storage item and
+	/// can be updated by the `UpdateOrigin`.
+	type Parameters: Parameter + MaxEncodedLen + Default;
+
+	/// Returns the collators of the session `session_index`, in the order of their slots.
+	///
+	/// `invulnerables` are sorted by their account id and `candidates` are sorted in ascending
+	/// order by deposit. At most `desired_candidates` candidates should become collators.
+	fn collators(
+		session_index: SessionIndex,
+		parameters: &Self::Parameters,
+		invulnerables: Vec<AccountId>,
+		candidates: Vec<CandidateInfo<AccountId, Balance>>,
+		desired_candidates: u32,
+	) -> Vec<AccountId>;
+
+	/// The weight of [`Self::collators`] for the given number of collators, on top of reading
+	/// the invulnerables, the candidates and the parameters.
+	fn weight(_collators: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The invulnerables, followed by the candidates with the highest deposits.
+///
+/// This is the order that was used before the rotation policies were introduced.
+pub struct InvulnerablesThenCandidates;
+
+impl<AccountId, Balance> RotationPolicy<AccountId, Balance> for InvulnerablesThenCandidates {
+	type Parameters = ();
+
+	fn collators(
+		_session_index: SessionIndex,
+		_parameters: &Self::Parameters,
+		invulnerables: Vec<AccountId>,
+		candidates: Vec<CandidateInfo<AccountId, Balance>>,
+		desired_candidates: u32,
+	) -> Vec<AccountId> {
+		let mut collators = invulnerables;
+		// Casting `u32` to `usize` should be safe on all machines running this.
+		collators.extend(
+			candidates
+				.into_iter()
+				.rev()
+				.take(desired_candidates as usize)
+				.map(|candidate_info| candidate_info.who),
+		);
+		collators
+	}
+}
+
+/// Parameters of the [`RoundRobinWithBackoff`] rotation policy.
+///
+/// The default parameters result in the same order as [`InvulnerablesThenCandidates`].
+#[derive(
+	Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct RoundRobinParameters<BlockNumber> {
+	/// Shift the order of the collators by one position in every session, so that every
+	/// collator gets to author the first slot of a session in turn.
+	pub shift_per_session: bool,
+	/// Collators that have not authored a block for this many blocks are moved to the end of the
+	/// order, so that the following slots are assigned to the collators that are online.
+	///
+	/// Zero disables the backoff.
+	pub backoff_threshold: BlockNumber,
+}
+
+/// Round-robin over the collators selected by [`InvulnerablesThenCandidates`], with a backoff
+/// for the collators that are missing their slots.
+///
+/// See [`RoundRobinParameters`] for the available parameters.
+pub struct RoundRobinWithBackoff<T>(PhantomData<T>);
+
+impl<T: Config> RotationPolicy<T::AccountId, BalanceOf<T>> for RoundRobinWithBackoff<T> {
+	type Parameters = RoundRobinParameters<BlockNumberFor<T>>;
+
+	fn collators(
+		session_index: SessionIndex,
+		parameters: &Self::Parameters,
+		invulnerables: Vec<T::AccountId>,
+		candidates: Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
+		desired_candidates: u32,
+	) -> Vec<T::AccountId> {
+		let mut collators = <InvulnerablesThenCandidates as RotationPolicy<_, _>>::collators(
+			session_index,
+			&(),
+			invulnerables,
+			candidates,
+			desired_candidates,
+		);
+
+		if parameters.shift_per_session && !collators.is_empty() {
+			let shift = session_index as usize % collators.len();
+			collators.rotate_left(shift);
+		}
+
+		if !parameters.backoff_threshold.is_zero() {
+			let now = frame_system::Pallet::<T>::block_number();
+			// The sort is stable, so the order of the collators within both groups is kept.
+			collators.sort_by_cached_key(|collator| {
+				let since_last = now.saturating_sub(LastAuthoredBlock::<T>::get(collator));
+				since_last >= parameters.backoff_threshold
+			});
+		}
+
+		collators
+	}
+
+	fn weight(collators: u32) -> Weight {
+		T::DbWeight::get().reads(collators as u64)
+	}
+}
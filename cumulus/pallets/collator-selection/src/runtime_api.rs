@@ -0,0 +1,34 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Collator Selection pallet.
+
+use crate::CollatorPerformanceInfo;
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// API to query the block production performance of collators.
+	pub trait CollatorPerformanceApi<AccountId>
+	where
+		AccountId: Codec
+	{
+		/// The performance counters of `who`, if they are tracked.
+		fn collator_performance(who: AccountId) -> Option<CollatorPerformanceInfo>;
+
+		/// The performance counters of all the tracked collators.
+		fn all_collator_performance() -> Vec<(AccountId, CollatorPerformanceInfo)>;
+	}
+}
@@ -15,13 +15,16 @@
 
 use crate as collator_selection;
 use crate::{
-	mock::*, CandidacyBond, CandidateInfo, CandidateList, DesiredCandidates, Error, Invulnerables,
-	LastAuthoredBlock,
+	mock::*, rotation::RoundRobinParameters, CandidacyBond, CandidateInfo, CandidateList,
+	CollatorPerformanceInfo, DesiredCandidates, Error, Invulnerables, LastAuthoredBlock,
+	RotationParameters,
 };
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{Currency, OnInitialize},
+	traits::{Currency, OnInitialize, ReservableCurrency},
 };
+use pallet_authorship::EventHandler;
+use pallet_session::SessionManager;
 use pallet_balances::Error as BalancesError;
 use sp_runtime::{testing::UintAuthorityId, traits::BadOrigin, BuildStorage};
 
@@ -1575,6 +1578,188 @@ fn should_kick_invulnerables_from_candidates_on_session_change() {
 	});
 }
 
+#[test]
+fn set_rotation_parameters_works() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_eq!(RotationParameters::<Test>::get(), RoundRobinParameters::default());
+		let parameters = RoundRobinParameters { shift_per_session: true, backoff_threshold: 5 };
+
+		// only the update origin can set the parameters.
+		assert_noop!(
+			CollatorSelection::set_rotation_parameters(RuntimeOrigin::signed(1), parameters),
+			BadOrigin
+		);
+
+		assert_ok!(CollatorSelection::set_rotation_parameters(
+			RuntimeOrigin::signed(RootAccount::get()),
+			parameters
+		));
+		assert_eq!(RotationParameters::<Test>::get(), parameters);
+		System::assert_last_event(RuntimeEvent::CollatorSelection(
+			crate::Event::NewRotationParameters { parameters },
+		));
+	});
+}
+
+#[test]
+fn rotation_shifts_collators_per_session() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::set_rotation_parameters(
+			RuntimeOrigin::signed(RootAccount::get()),
+			RoundRobinParameters { shift_per_session: true, backoff_threshold: 0 }
+		));
+
+		initialize_to_block(10);
+		// the collators of session 2 are shifted by two positions.
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CollatorsRotated {
+			session_index: 2,
+			collators: vec![3, 1, 2],
+		}));
+
+		initialize_to_block(20);
+		assert_eq!(SessionHandlerCollators::get(), vec![3, 1, 2]);
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CollatorsRotated {
+			session_index: 3,
+			collators: vec![1, 2, 3],
+		}));
+	});
+}
+
+#[test]
+fn rotation_backs_off_collators_missing_blocks() {
+	new_test_ext().execute_with(|| {
+		initialize_to_block(1);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::set_rotation_parameters(
+			RuntimeOrigin::signed(RootAccount::get()),
+			RoundRobinParameters { shift_per_session: false, backoff_threshold: 5 }
+		));
+
+		initialize_to_block(10);
+		// the invulnerables never authored a block, so they get the last slots. 3 has just joined
+		// and 4 is authoring all the blocks.
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CollatorsRotated {
+			session_index: 2,
+			collators: vec![3, 4, 1, 2],
+		}));
+	});
+}
+
+#[test]
+fn collator_performance_is_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_eq!(CollatorSelection::collator_performance(&3), None);
+
+		<CollatorSelection as SessionManager<_>>::new_session(1);
+		assert_eq!(CollatorSelection::collator_performance(&3).unwrap().queued_for, Some(1));
+
+		// 3 authors a block in session 1, the invulnerables do not.
+		<CollatorSelection as SessionManager<_>>::start_session(1);
+		<CollatorSelection as EventHandler<_, _>>::note_author(3);
+		<CollatorSelection as SessionManager<_>>::new_session(2);
+		<CollatorSelection as SessionManager<_>>::start_session(2);
+
+		assert_eq!(
+			CollatorSelection::collator_performance(&3),
+			Some(CollatorPerformanceInfo {
+				sessions_active: 2,
+				sessions_missed: 0,
+				consecutive_sessions_missed: 0,
+				blocks_authored: 1,
+				session_blocks_authored: 0,
+				active: true,
+				queued_for: None,
+			})
+		);
+		assert_eq!(
+			CollatorSelection::collator_performance(&1),
+			Some(CollatorPerformanceInfo {
+				sessions_active: 2,
+				sessions_missed: 1,
+				consecutive_sessions_missed: 1,
+				blocks_authored: 0,
+				session_blocks_authored: 0,
+				active: true,
+				queued_for: None,
+			})
+		);
+		assert_eq!(CollatorSelection::all_collator_performance().len(), 3);
+	});
+}
+
+#[test]
+fn candidates_missing_sessions_are_slashed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MissedSessionsThreshold::set(2);
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 5);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+
+		// 4 authors a block in every session, 3 never does.
+		for session in 1..=3 {
+			<CollatorSelection as EventHandler<_, _>>::note_author(4);
+			<CollatorSelection as SessionManager<_>>::new_session(session);
+			<CollatorSelection as SessionManager<_>>::start_session(session);
+		}
+
+		// half of the deposit of 3 went to the pot and the rest was returned, as it is below the
+		// candidacy bond.
+		System::assert_has_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateSlashed {
+			account_id: 3,
+			amount: 5,
+		}));
+		System::assert_last_event(RuntimeEvent::CollatorSelection(crate::Event::CandidateRemoved {
+			account_id: 3,
+		}));
+		assert_eq!(Balances::free_balance(3), 95);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(CollatorSelection::account_id()), 10);
+		assert_eq!(
+			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			vec![CandidateInfo { who: 4, deposit: 10 }]
+		);
+		assert_eq!(
+			CollatorSelection::collator_performance(&3).unwrap().consecutive_sessions_missed,
+			0
+		);
+
+		// the invulnerables missed all sessions as well but are never slashed.
+		assert_eq!(CollatorSelection::collator_performance(&1).unwrap().sessions_missed, 2);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn slashed_candidate_keeps_slot_above_candidacy_bond() {
+	new_test_ext().execute_with(|| {
+		MissedSessionsThreshold::set(1);
+		Balances::make_free_balance_be(&CollatorSelection::account_id(), 5);
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(3), 30));
+		assert_ok!(CollatorSelection::register_as_candidate(RuntimeOrigin::signed(4)));
+		assert_ok!(CollatorSelection::update_bond(RuntimeOrigin::signed(4), 20));
+
+		<CollatorSelection as SessionManager<_>>::new_session(1);
+		<CollatorSelection as SessionManager<_>>::start_session(1);
+		<CollatorSelection as EventHandler<_, _>>::note_author(4);
+		<CollatorSelection as SessionManager<_>>::new_session(2);
+		<CollatorSelection as SessionManager<_>>::start_session(2);
+
+		// 3 stays a candidate with half its deposit, the list is sorted again.
+		assert_eq!(Balances::reserved_balance(3), 15);
+		assert_eq!(
+			CandidateList::<Test>::get().iter().cloned().collect::<Vec<_>>(),
+			vec![CandidateInfo { who: 3, deposit: 15 }, CandidateInfo { who: 4, deposit: 20 }]
+		);
+	});
+}
+
 #[test]
 #[should_panic = "duplicate invulnerables in genesis."]
 fn cannot_set_genesis_value_twice() {
@@ -30,6 +30,7 @@ pub trait WeightInfo {
 	fn add_invulnerable(_b: u32, _c: u32) -> Weight;
 	fn remove_invulnerable(_b: u32) -> Weight;
 	fn set_desired_candidates() -> Weight;
+	fn set_rotation_parameters() -> Weight;
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight;
 	fn register_as_candidate(_c: u32) -> Weight;
 	fn leave_intent(_c: u32) -> Weight;
@@ -37,6 +38,7 @@ pub trait WeightInfo {
 	fn take_candidate_slot(_c: u32) -> Weight;
 	fn note_author() -> Weight;
 	fn new_session(_c: u32, _r: u32) -> Weight;
+	fn start_session(_c: u32) -> Weight;
 }
 
 /// Weights for pallet_collator_selection using the Substrate node and recommended hardware.
@@ -51,6 +53,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn set_desired_candidates() -> Weight {
 		Weight::from_parts(16_363_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn set_rotation_parameters() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(16_363_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight {
 		Weight::from_parts(16_840_000_u64, 0).saturating_add(T::DbWeight::get().writes(1_u64))
 	}
@@ -83,9 +89,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	fn note_author() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
 		Weight::from_parts(71_461_000_u64, 0)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	fn new_session(r: u32, c: u32) -> Weight {
 		Weight::from_parts(0_u64, 0)
@@ -98,6 +105,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(T::DbWeight::get().writes(2_u64.saturating_mul(c as u64)))
 	}
+	fn start_session(c: u32) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(9_320_000_u64, 0)
+			.saturating_add(Weight::from_parts(12_405_000_u64, 0).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(1_u64.saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(1_u64.saturating_mul(c as u64)))
+	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	/// Storage: CollatorSelection Invulnerables (r:1 w:1)
@@ -152,6 +167,10 @@ impl WeightInfo for () {
 	fn set_desired_candidates() -> Weight {
 		Weight::from_parts(16_363_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn set_rotation_parameters() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(16_363_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	fn set_candidacy_bond(_c: u32, _k: u32) -> Weight {
 		Weight::from_parts(16_840_000_u64, 0).saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
@@ -170,9 +189,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	fn note_author() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
 		Weight::from_parts(71_461_000_u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	fn update_bond(c: u32) -> Weight {
 		Weight::from_parts(55_336_000_u64, 0)
@@ -199,6 +219,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(r as u64)))
 			.saturating_add(RocksDbWeight::get().writes(2_u64.saturating_mul(c as u64)))
 	}
+	fn start_session(c: u32) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(9_320_000_u64, 0)
+			.saturating_add(Weight::from_parts(12_405_000_u64, 0).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(1_u64.saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1_u64.saturating_mul(c as u64)))
+	}
 	/// Storage: Session NextKeys (r:1 w:0)
 	/// Proof Skipped: Session NextKeys (max_values: None, max_size: None, mode: Measured)
 	/// Storage: CollatorSelection Invulnerables (r:1 w:1)
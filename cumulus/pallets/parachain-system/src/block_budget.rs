@@ -0,0 +1,129 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Alarms for a block that is about to run out of weight or proof size.
+//!
+//! Parachain blocks are limited by the weight of the block and by the size of the PoV the relay
+//! chain accepts. Pallets doing optional work, e.g. servicing queues or sweeping through storage
+//! in their `on_idle` hook, can use this module to yield before the block is exhausted:
+//!
+//! - [`Pallet::remaining_budget`](crate::Pallet::remaining_budget) returns the weight and proof
+//!   size that are left in the current block.
+//! - Once all the transactions of a block are applied, [`Config::OnLowBudget`] is notified if the
+//!   remaining budget is below [`Config::LowBudgetThreshold`] in any of its components. The check
+//!   is done by the [`PostTransactions`] implementation of the pallet, which runtimes opt into with
+//!   `type PostTransactions = ParachainSystem` in `frame_system::Config`.
+//! - The check is repeated in `on_finalize`, and the chain is in degradation mode for as long as
+//!   its blocks end with a low budget. Entering and leaving the degradation mode emits
+//!   [`Event::DegradationModeEntered`] and [`Event::DegradationModeLeft`], notifies
+//!   [`Config::OnLowBudget`], and is exposed by
+//!   [`Pallet::is_degraded`](crate::Pallet::is_degraded).
+
+use crate::{Config, Event, InDegradationMode, LowBudget, Pallet, ValidationData};
+use cumulus_primitives_proof_size_hostfunction::{
+	storage_proof_size::storage_proof_size, PROOF_RECORDING_DISABLED,
+};
+use frame_support::{
+	traits::{Get, PostTransactions},
+	weights::Weight,
+};
+
+/// Something that is notified when the budget of a block runs low.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnLowBudget {
+	/// Called at most once per block, when the `remaining` budget of the block is found below
+	/// [`Config::LowBudgetThreshold`].
+	fn on_low_budget(remaining: Weight);
+
+	/// Called in `on_finalize` when the chain enters or leaves the degradation mode.
+	fn on_degradation_mode_changed(degraded: bool);
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns the weight and proof size that are left in the current block.
+	///
+	/// The proof size is the smaller of what is left of the block weight and of the maximum PoV
+	/// size, if the node is recording the storage proof.
+	pub fn remaining_budget() -> Weight {
+		let max_block = T::BlockWeights::get().max_block;
+		let consumed = frame_system::Pallet::<T>::block_weight().total();
+		let mut remaining = max_block.saturating_sub(consumed);
+
+		let proof_size = storage_proof_size();
+		if proof_size != PROOF_RECORDING_DISABLED {
+			if let Some(vfp) = ValidationData::<T>::get() {
+				let pov_left = (vfp.max_pov_size as u64).saturating_sub(proof_size);
+				remaining.set_proof_size(remaining.proof_size().min(pov_left));
+			}
+		}
+
+		remaining
+	}
+
+	/// Whether the chain is in degradation mode, i.e. the last block ended with a low budget.
+	pub fn is_degraded() -> bool {
+		InDegradationMode::<T>::get()
+	}
+
+	/// Checks the remaining budget of the block, notifies [`Config::OnLowBudget`] the first time
+	/// it is low, and returns whether it is low.
+	pub(crate) fn check_budget() -> bool {
+		if LowBudget::<T>::exists() {
+			return true
+		}
+
+		let remaining = Self::remaining_budget();
+		if !remaining.any_lt(T::LowBudgetThreshold::get()) {
+			return false
+		}
+
+		log::debug!(
+			target: "parachain_system",
+			"Block budget is running low, remaining: {:?}",
+			remaining,
+		);
+		LowBudget::<T>::put(remaining);
+		T::OnLowBudget::on_low_budget(remaining);
+		true
+	}
+
+	/// Enters or leaves the degradation mode, depending on the budget at the end of the block.
+	pub(crate) fn update_degradation_mode() {
+		Self::check_budget();
+		let low_budget = LowBudget::<T>::take();
+		if low_budget.is_some() == InDegradationMode::<T>::get() {
+			return
+		}
+
+		match low_budget {
+			Some(remaining) => {
+				InDegradationMode::<T>::put(true);
+				Self::deposit_event(Event::DegradationModeEntered { remaining });
+			},
+			None => {
+				InDegradationMode::<T>::kill();
+				Self::deposit_event(Event::DegradationModeLeft);
+			},
+		}
+		T::OnLowBudget::on_degradation_mode_changed(low_budget.is_some());
+	}
+}
+
+impl<T: Config> PostTransactions for Pallet<T> {
+	fn post_transactions() {
+		Self::check_budget();
+	}
+}
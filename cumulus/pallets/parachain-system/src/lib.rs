@@ -39,8 +39,8 @@ use cumulus_primitives_core::{
 	},
 	AbridgedHostConfiguration, ChannelInfo, ChannelStatus, CollationInfo, GetChannelInfo,
 	InboundDownwardMessage, InboundHrmpMessage, ListChannelInfos, MessageSendError,
-	OutboundHrmpMessage, ParaId, PersistedValidationData, UpwardMessage, UpwardMessageSender,
-	XcmpMessageHandler, XcmpMessageSource,
+	MessagingLimits, OutboundHrmpMessage, ParaId, PersistedValidationData, PoVSizeUsage,
+	UpwardMessage, UpwardMessageSender, XcmpMessageHandler, XcmpMessageSource,
 };
 use cumulus_primitives_parachain_inherent::{MessageQueueChain, ParachainInherentData};
 use frame_support::{
@@ -64,6 +64,7 @@ use xcm::{latest::XcmHash, VersionedLocation, VersionedXcm};
 use xcm_builder::InspectMessageQueues;
 
 mod benchmarking;
+pub mod block_budget;
 pub mod migration;
 mod mock;
 #[cfg(test)]
@@ -75,6 +76,7 @@ pub use weights::WeightInfo;
 mod unincluded_segment;
 
 pub mod consensus_hook;
+pub mod pov_diagnostics;
 pub mod relay_state_snapshot;
 #[macro_use]
 pub mod validate_block;
@@ -84,6 +86,7 @@ use unincluded_segment::{
 	UsedBandwidth,
 };
 
+pub use block_budget::OnLowBudget;
 pub use consensus_hook::{ConsensusHook, ExpectParentIncluded};
 /// Register the `validate_block` function that is used by parachains to validate blocks on a
 /// validator.
@@ -296,6 +299,16 @@ pub mod pallet {
 
 		/// Select core.
 		type SelectCore: SelectCore;
+
+		/// The remaining weight or proof size of a block below which its budget is low.
+		///
+		/// The budget is low if any component of the remaining budget is below the threshold, so
+		/// a zero component is never low. See [`block_budget`] for more information.
+		type LowBudgetThreshold: Get<Weight>;
+
+		/// Something that is notified when the budget of a block runs low and when the chain
+		/// enters or leaves the degradation mode.
+		type OnLowBudget: OnLowBudget;
 	}
 
 	#[pallet::hooks]
@@ -472,6 +485,8 @@ pub mod pallet {
 				UnincludedSegment::<T>::append(ancestor);
 			}
 			HrmpOutboundMessages::<T>::put(outbound_messages);
+
+			Self::update_degradation_mode();
 		}
 
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -550,6 +565,9 @@ pub mod pallet {
 			// Always try to read `UpgradeGoAhead` in `on_finalize`.
 			weight += T::DbWeight::get().reads(1);
 
+			// Weight for updating the degradation mode in `on_finalize`.
+			weight += T::DbWeight::get().reads_writes(3, 2);
+
 			weight
 		}
 	}
@@ -725,6 +743,10 @@ pub mod pallet {
 		DownwardMessagesProcessed { weight_used: Weight, dmq_head: relay_chain::Hash },
 		/// An upward message was sent to the relay chain.
 		UpwardMessageSent { message_hash: Option<XcmHash> },
+		/// A block ended with a low budget and the chain entered the degradation mode.
+		DegradationModeEntered { remaining: Weight },
+		/// A block ended with enough budget left and the chain left the degradation mode.
+		DegradationModeLeft,
 	}
 
 	#[pallet::error]
@@ -923,6 +945,28 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CustomValidationHeadData<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
 
+	/// The proof size consumed by every pallet, together with the block it was recorded in.
+	///
+	/// Only written if the `pov-diagnostics` feature is enabled. Entries that were recorded in
+	/// previous blocks are stale and get reset once the pallet consumes proof size again.
+	///
+	/// See [`pov_diagnostics`] for more information.
+	#[pallet::storage]
+	pub type PoVSizeDiagnostics<T: Config> =
+		StorageMap<_, Twox64Concat, Vec<u8>, (BlockNumberFor<T>, PoVSizeUsage), OptionQuery>;
+
+	/// The remaining budget of the current block, if it was found low.
+	///
+	/// This value is ephemeral, it is set at most once per block and removed in `on_finalize`.
+	#[pallet::storage]
+	pub type LowBudget<T: Config> = StorageValue<_, Weight, OptionQuery>;
+
+	/// Whether the last block ended with a low budget.
+	///
+	/// See [`block_budget`] for more information.
+	#[pallet::storage]
+	pub type InDegradationMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::inherent]
 	impl<T: Config> ProvideInherent for Pallet<T> {
 		type Call = Call<T>;
@@ -1545,6 +1589,36 @@ impl<T: Config> frame_system::SetCode<T> for ParachainSetCode<T> {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Returns the effective limits for upward messages.
+	///
+	/// The remaining capacity of the relay chain queue is reduced by the messages that are still
+	/// pending to be sent with the current block. Returns `None` if the relay chain state is not
+	/// known yet.
+	pub fn upward_message_limits() -> Option<MessagingLimits> {
+		let config = HostConfiguration::<T>::get()?;
+		let capacity = RelevantMessagingState::<T>::get()?.relay_dispatch_queue_remaining_capacity;
+		let pending = PendingUpwardMessages::<T>::get();
+		let pending_size: usize = pending.iter().map(UpwardMessage::len).sum();
+		Some(MessagingLimits {
+			max_message_size: config.max_upward_message_size,
+			remaining_count: capacity.remaining_count.saturating_sub(pending.len() as u32),
+			remaining_size: capacity.remaining_size.saturating_sub(pending_size as u32),
+			suspended: false,
+		})
+	}
+
+	/// Returns the proof size consumed by every pallet in the current block.
+	///
+	/// Only returns something if the `pov-diagnostics` feature is enabled and the runtime is
+	/// set up to measure the proof size, see [`pov_diagnostics`].
+	pub fn pov_size_diagnostics() -> Vec<(Vec<u8>, PoVSizeUsage)> {
+		let current_block = frame_system::Pallet::<T>::block_number();
+		PoVSizeDiagnostics::<T>::iter()
+			.filter(|(_, (block, _))| *block == current_block)
+			.map(|(pallet_name, (_, usage))| (pallet_name, usage))
+			.collect()
+	}
+
 	/// Puts a message in the `PendingUpwardMessages` storage item.
 	/// The message will be later sent in `on_finalize`.
 	/// Checks host configuration to see if message is too big.
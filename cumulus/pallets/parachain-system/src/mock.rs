@@ -95,6 +95,27 @@ impl Config for Test {
 	type ConsensusHook = TestConsensusHook;
 	type WeightInfo = ();
 	type SelectCore = DefaultCoreSelector<Test>;
+	type LowBudgetThreshold = LowBudgetThreshold;
+	type OnLowBudget = RecordLowBudget;
+}
+
+parameter_types! {
+	pub static LowBudgetThreshold: Weight = Weight::zero();
+	pub static LowBudgetReports: Vec<Weight> = vec![];
+	pub static DegradationModeChanges: Vec<bool> = vec![];
+}
+
+/// An `OnLowBudget` that records its notifications.
+pub struct RecordLowBudget;
+
+impl OnLowBudget for RecordLowBudget {
+	fn on_low_budget(remaining: Weight) {
+		LowBudgetReports::mutate(|reports| reports.push(remaining));
+	}
+
+	fn on_degradation_mode_changed(degraded: bool) {
+		DegradationModeChanges::mutate(|changes| changes.push(degraded));
+	}
 }
 
 std::thread_local! {
@@ -122,6 +143,8 @@ impl pallet_message_queue::Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MaxWeight;
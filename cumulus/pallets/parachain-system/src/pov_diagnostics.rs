@@ -0,0 +1,359 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Measurement of the proof size consumed by the pallets of a parachain runtime.
+//!
+//! The proof size (PoV) is usually the scarcest resource of a parachain block. This module
+//! provides the tools to find out which pallets consume it, without any external tooling:
+//!
+//! - [`MeasurePoVSize`] wraps `AllPalletsWithSystem` and measures the proof size consumed by the
+//!   `on_initialize`, `on_idle` and `on_finalize` hooks of every pallet. It should be passed to
+//!   `frame_executive::Executive` instead of `AllPalletsWithSystem`.
+//! - [`MeasureExtrinsicPoVSize`] is a transaction extension that measures the proof size consumed
+//!   by every extrinsic and attributes it to the pallet of the dispatched call.
+//! - [`Pallet::pov_size_diagnostics`](crate::Pallet::pov_size_diagnostics) returns the recorded
+//!   values and should be used to implement the
+//!   [`PoVSizeDiagnosticsApi`](cumulus_primitives_core::PoVSizeDiagnosticsApi) runtime api.
+//!
+//! The values are only recorded if the `pov-diagnostics` feature is enabled and the node is
+//! recording the storage proof, otherwise all the types of this module are no-ops. Recording the
+//! values adds storage accesses to every block, so the feature should not be enabled in
+//! production runtimes.
+
+use crate::{Config, PoVSizeDiagnostics};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use cumulus_primitives_core::PoVSizeUsage;
+use cumulus_primitives_proof_size_hostfunction::{
+	storage_proof_size::storage_proof_size, PROOF_RECORDING_DISABLED,
+};
+use frame_support::{
+	dispatch::{DispatchInfo, GetCallMetadata, PostDispatchInfo},
+	traits::{
+		BeforeAllRuntimeMigrations, OffchainWorker, OnFinalize, OnIdle, OnInitialize, OnPoll,
+		OnRuntimeUpgrade, PalletInfoAccess,
+	},
+	weights::{Weight, WeightMeter},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{
+		AtLeast32BitUnsigned, DispatchInfoOf, Dispatchable, PostDispatchInfoOf,
+		TransactionExtension,
+	},
+	transaction_validity::TransactionValidityError,
+	DispatchResult,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use frame_support::traits::{
+	TryDecodeEntireStorage, TryDecodeEntireStorageError, TryState, TryStateSelect,
+};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The kind of work that has consumed the proof size.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PoVSizeConsumer {
+	/// The `on_initialize`, `on_idle` or `on_finalize` hook of a pallet.
+	Hooks,
+	/// An extrinsic that was dispatched to a pallet.
+	Extrinsics,
+}
+
+/// Returns the current storage proof size.
+///
+/// Returns `None` if the `pov-diagnostics` feature is disabled or if the node is not recording
+/// the storage proof.
+fn proof_size() -> Option<u64> {
+	if !cfg!(feature = "pov-diagnostics") {
+		return None
+	}
+
+	let proof_size = storage_proof_size();
+	(proof_size != PROOF_RECORDING_DISABLED).then_some(proof_size)
+}
+
+/// Adds `proof_size` to the proof size consumed by the pallet `pallet_name` in the current block.
+pub fn note_pov_size<T: Config>(pallet_name: &str, consumer: PoVSizeConsumer, proof_size: u64) {
+	if proof_size == 0 {
+		return
+	}
+
+	let current_block = frame_system::Pallet::<T>::block_number();
+	PoVSizeDiagnostics::<T>::mutate(pallet_name.as_bytes(), |entry| {
+		let (block, usage) = entry.get_or_insert_with(|| (current_block, Default::default()));
+		if *block != current_block {
+			*block = current_block;
+			*usage = PoVSizeUsage::default();
+		}
+
+		match consumer {
+			PoVSizeConsumer::Hooks => usage.hooks = usage.hooks.saturating_add(proof_size),
+			PoVSizeConsumer::Extrinsics =>
+				usage.extrinsics = usage.extrinsics.saturating_add(proof_size),
+		}
+	});
+}
+
+/// Executes `f` and notes the proof size it has consumed for the pallet `pallet_name`.
+fn measure<T: Config, R>(pallet_name: &str, consumer: PoVSizeConsumer, f: impl FnOnce() -> R) -> R {
+	let Some(before) = proof_size() else { return f() };
+	let result = f();
+	if let Some(after) = proof_size() {
+		note_pov_size::<T>(pallet_name, consumer, after.saturating_sub(before));
+	}
+	result
+}
+
+/// The hooks of a tuple of pallets, measuring the proof size consumed by every pallet.
+///
+/// Implemented for all the tuples of pallets, like `AllPalletsWithSystem`.
+pub trait MeasuredHooks<BlockNumber> {
+	/// Calls `on_initialize` of every pallet.
+	fn measured_on_initialize<T: Config>(n: BlockNumber) -> Weight;
+
+	/// Calls `on_idle` of every pallet, in the same order as `frame_support` does.
+	fn measured_on_idle<T: Config>(n: BlockNumber, remaining_weight: Weight) -> Weight;
+
+	/// Calls `on_finalize` of every pallet.
+	fn measured_on_finalize<T: Config>(n: BlockNumber);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(64)]
+#[tuple_types_custom_trait_bound(
+	PalletInfoAccess + OnInitialize<BlockNumber> + OnIdle<BlockNumber> + OnFinalize<BlockNumber>
+)]
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> MeasuredHooks<BlockNumber> for Tuple {
+	fn measured_on_initialize<T: Config>(n: BlockNumber) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight = weight.saturating_add(measure::<T, _>(
+				Tuple::name(),
+				PoVSizeConsumer::Hooks,
+				|| <Tuple as OnInitialize<BlockNumber>>::on_initialize(n),
+			));
+		)* );
+		weight
+	}
+
+	fn measured_on_idle<T: Config>(n: BlockNumber, remaining_weight: Weight) -> Weight {
+		let on_idle_functions: &[(&str, fn(BlockNumber, Weight) -> Weight)] = &[for_tuples!(
+			#( (Tuple::name(), <Tuple as OnIdle<BlockNumber>>::on_idle) ),*
+		)];
+		let mut weight = Weight::zero();
+		let len = on_idle_functions.len();
+		let start_index = n % (len as u32).into();
+		let start_index = start_index.try_into().ok().expect(
+			"`start_index % len` always fits into `usize`, because `len` can be in maximum `usize::MAX`; qed"
+		);
+		for (pallet_name, on_idle_fn) in
+			on_idle_functions.iter().cycle().skip(start_index).take(len)
+		{
+			let adjusted_remaining_weight = remaining_weight.saturating_sub(weight);
+			weight =
+				weight.saturating_add(measure::<T, _>(pallet_name, PoVSizeConsumer::Hooks, || {
+					on_idle_fn(n, adjusted_remaining_weight)
+				}));
+		}
+		weight
+	}
+
+	fn measured_on_finalize<T: Config>(n: BlockNumber) {
+		for_tuples!( #(
+			measure::<T, _>(
+				Tuple::name(),
+				PoVSizeConsumer::Hooks,
+				|| <Tuple as OnFinalize<BlockNumber>>::on_finalize(n),
+			);
+		)* );
+	}
+}
+
+/// Wrapper around `AllPalletsWithSystem` that measures the proof size consumed by the hooks of
+/// every pallet.
+///
+/// All the other hooks are forwarded to `AllPallets` as they are.
+///
+/// # Example
+///
+/// ```ignore
+/// pub type Executive = frame_executive::Executive<
+///     Runtime,
+///     Block,
+///     frame_system::ChainContext<Runtime>,
+///     Runtime,
+///     cumulus_pallet_parachain_system::pov_diagnostics::MeasurePoVSize<
+///         Runtime,
+///         AllPalletsWithSystem,
+///     >,
+/// >;
+/// ```
+pub struct MeasurePoVSize<T, AllPallets>(PhantomData<(T, AllPallets)>);
+
+impl<T: Config, AllPallets: MeasuredHooks<BlockNumberFor<T>>> OnInitialize<BlockNumberFor<T>>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+		AllPallets::measured_on_initialize::<T>(n)
+	}
+}
+
+impl<T: Config, AllPallets: MeasuredHooks<BlockNumberFor<T>>> OnIdle<BlockNumberFor<T>>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		AllPallets::measured_on_idle::<T>(n, remaining_weight)
+	}
+}
+
+impl<T: Config, AllPallets: MeasuredHooks<BlockNumberFor<T>>> OnFinalize<BlockNumberFor<T>>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn on_finalize(n: BlockNumberFor<T>) {
+		AllPallets::measured_on_finalize::<T>(n)
+	}
+}
+
+impl<T, AllPallets: OnRuntimeUpgrade> OnRuntimeUpgrade for MeasurePoVSize<T, AllPallets> {
+	fn on_runtime_upgrade() -> Weight {
+		AllPallets::on_runtime_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn try_on_runtime_upgrade(checks: bool) -> Result<Weight, TryRuntimeError> {
+		AllPallets::try_on_runtime_upgrade(checks)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		AllPallets::pre_upgrade()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		AllPallets::post_upgrade(state)
+	}
+}
+
+impl<T, AllPallets: BeforeAllRuntimeMigrations> BeforeAllRuntimeMigrations
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn before_all_runtime_migrations() -> Weight {
+		AllPallets::before_all_runtime_migrations()
+	}
+}
+
+impl<T, BlockNumber, AllPallets: OffchainWorker<BlockNumber>> OffchainWorker<BlockNumber>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn offchain_worker(n: BlockNumber) {
+		AllPallets::offchain_worker(n)
+	}
+}
+
+impl<T, BlockNumber, AllPallets: OnPoll<BlockNumber>> OnPoll<BlockNumber>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn on_poll(n: BlockNumber, weight: &mut WeightMeter) {
+		AllPallets::on_poll(n, weight)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T, BlockNumber, AllPallets: TryState<BlockNumber>> TryState<BlockNumber>
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn try_state(n: BlockNumber, targets: TryStateSelect) -> Result<(), TryRuntimeError> {
+		AllPallets::try_state(n, targets)
+	}
+}
+
+#[cfg(feature = "try-runtime")]
+impl<T, AllPallets: TryDecodeEntireStorage> TryDecodeEntireStorage
+	for MeasurePoVSize<T, AllPallets>
+{
+	fn try_decode_entire_state() -> Result<usize, Vec<TryDecodeEntireStorageError>> {
+		AllPallets::try_decode_entire_state()
+	}
+}
+
+/// Transaction extension that measures the proof size consumed by every extrinsic.
+///
+/// The proof size is attributed to the pallet of the dispatched call. The extension should be
+/// put last in the list of transaction extensions, so that the proof size consumed by the other
+/// extensions is not attributed to the pallet.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct MeasureExtrinsicPoVSize<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> MeasureExtrinsicPoVSize<T> {
+	/// Create a new `MeasureExtrinsicPoVSize` instance.
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for MeasureExtrinsicPoVSize<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+		write!(f, "MeasureExtrinsicPoVSize")
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for MeasureExtrinsicPoVSize<T>
+where
+	T::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo> + GetCallMetadata,
+{
+	const IDENTIFIER: &'static str = "MeasureExtrinsicPoVSize";
+	type Implicit = ();
+	type Val = ();
+	type Pre = Option<(&'static str, u64)>;
+
+	fn prepare(
+		self,
+		_val: Self::Val,
+		_origin: &T::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(proof_size().map(|proof_size| (call.get_call_metadata().pallet_name, proof_size)))
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		if let (Some((pallet_name, before)), Some(after)) = (pre, proof_size()) {
+			note_pov_size::<T>(
+				pallet_name,
+				PoVSizeConsumer::Extrinsics,
+				after.saturating_sub(before),
+			);
+		}
+		Ok(Weight::zero())
+	}
+
+	impl_tx_ext_default!(T::RuntimeCall; weight validate);
+}
@@ -637,6 +637,113 @@ fn send_upward_message_num_per_candidate() {
 		);
 }
 
+#[test]
+fn upward_message_limits_works() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			sproof.host_config.max_upward_message_size = 64;
+			sproof.relay_dispatch_queue_remaining_capacity = Some((5, 1024));
+		})
+		.add(1, || {
+			ParachainSystem::send_upward_message(vec![0u8; 8]).unwrap();
+			// The pending message is accounted for.
+			assert_eq!(
+				ParachainSystem::upward_message_limits(),
+				Some(MessagingLimits {
+					max_message_size: 64,
+					remaining_count: 4,
+					remaining_size: 1016,
+					suspended: false,
+				})
+			);
+		});
+}
+
+#[test]
+fn pov_size_diagnostics_works() {
+	use pov_diagnostics::{note_pov_size, PoVSizeConsumer};
+
+	BlockTests::new()
+		.add(1, || {
+			note_pov_size::<Test>("Balances", PoVSizeConsumer::Hooks, 10);
+			note_pov_size::<Test>("Balances", PoVSizeConsumer::Extrinsics, 5);
+			note_pov_size::<Test>("Balances", PoVSizeConsumer::Hooks, 10);
+			// Pallets that didn't consume anything are not recorded.
+			note_pov_size::<Test>("System", PoVSizeConsumer::Hooks, 0);
+			assert_eq!(
+				ParachainSystem::pov_size_diagnostics(),
+				vec![(b"Balances".to_vec(), PoVSizeUsage { hooks: 20, extrinsics: 5 })],
+			);
+		})
+		.add(2, || {
+			// The values of the previous block are stale.
+			assert!(ParachainSystem::pov_size_diagnostics().is_empty());
+
+			note_pov_size::<Test>("System", PoVSizeConsumer::Hooks, 7);
+			note_pov_size::<Test>("Balances", PoVSizeConsumer::Extrinsics, 3);
+			let mut diagnostics = ParachainSystem::pov_size_diagnostics();
+			diagnostics.sort_by(|a, b| a.0.cmp(&b.0));
+			assert_eq!(
+				diagnostics,
+				vec![
+					(b"Balances".to_vec(), PoVSizeUsage { hooks: 0, extrinsics: 3 }),
+					(b"System".to_vec(), PoVSizeUsage { hooks: 7, extrinsics: 0 }),
+				],
+			);
+		});
+}
+
+#[test]
+fn low_block_budget_is_signalled() {
+	use frame_support::{
+		dispatch::DispatchClass, traits::PostTransactions,
+		weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+	};
+
+	LowBudgetThreshold::set(Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND / 2, 0));
+
+	BlockTests::new()
+		.add_with_post_test(
+			1,
+			|| {
+				ParachainSystem::post_transactions();
+				assert!(LowBudgetReports::get().is_empty());
+
+				System::register_extra_weight_unchecked(
+					Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND * 3 / 4, 0),
+					DispatchClass::Normal,
+				);
+				// The alarm is only raised once per block.
+				ParachainSystem::post_transactions();
+				ParachainSystem::post_transactions();
+				let remaining = ParachainSystem::remaining_budget();
+				assert_eq!(remaining.ref_time(), WEIGHT_REF_TIME_PER_SECOND / 4);
+				assert_eq!(LowBudgetReports::get(), vec![remaining]);
+				assert!(!ParachainSystem::is_degraded());
+			},
+			|| {
+				assert!(ParachainSystem::is_degraded());
+				assert_eq!(DegradationModeChanges::get(), vec![true]);
+				System::assert_has_event(
+					Event::DegradationModeEntered { remaining: LowBudgetReports::get()[0] }.into(),
+				);
+			},
+		)
+		.add_with_post_test(
+			2,
+			|| {
+				// The chain stays degraded until a block ends with enough budget left.
+				assert!(ParachainSystem::is_degraded());
+			},
+			|| {
+				assert!(!ParachainSystem::is_degraded());
+				assert_eq!(DegradationModeChanges::get(), vec![true, false]);
+				assert_eq!(LowBudgetReports::get().len(), 1);
+				System::assert_has_event(Event::DegradationModeLeft.into());
+			},
+		);
+}
+
 #[test]
 fn send_upward_message_relay_bottleneck() {
 	BlockTests::new()
@@ -57,7 +57,7 @@ use bounded_collections::BoundedBTreeSet;
 use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
 use cumulus_primitives_core::{
 	relay_chain::BlockNumber as RelayBlockNumber, ChannelStatus, GetChannelInfo, MessageSendError,
-	ParaId, XcmpMessageFormat, XcmpMessageHandler, XcmpMessageSource,
+	MessagingLimits, ParaId, XcmpMessageFormat, XcmpMessageHandler, XcmpMessageSource,
 };
 
 use frame_support::{
@@ -691,6 +691,25 @@ impl<T: Config> Pallet<T> {
 			.max(<T as crate::Config>::WeightInfo::on_idle_large_msg())
 	}
 
+	/// Returns the effective limits for messages sent to the sibling parachain `recipient`.
+	///
+	/// The maximum message size accounts for [`Config::MaxPageSize`] and the message format
+	/// prefix. Returns `None` if there is no open channel to `recipient`.
+	pub fn outbound_channel_limits(recipient: ParaId) -> Option<MessagingLimits> {
+		let info = T::ChannelInfo::get_channel_info(recipient)?;
+		let max_page_size = info.max_message_size.min(T::MaxPageSize::get());
+		let format_size = XcmpMessageFormat::ConcatenatedVersionedXcm.encoded_size() as u32;
+		let suspended = <OutboundXcmpStatus<T>>::get()
+			.iter()
+			.any(|c| c.recipient == recipient && c.state == OutboundState::Suspended);
+		Some(MessagingLimits {
+			max_message_size: max_page_size.saturating_sub(format_size),
+			remaining_count: info.max_capacity.saturating_sub(info.msg_count),
+			remaining_size: info.max_total_size.saturating_sub(info.total_size),
+			suspended,
+		})
+	}
+
 	#[cfg(feature = "bridging")]
 	fn is_inbound_channel_suspended(sender: ParaId) -> bool {
 		<InboundXcmpSuspended<T>>::get().iter().any(|c| c == &sender)
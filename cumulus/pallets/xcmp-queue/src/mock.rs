@@ -105,6 +105,8 @@ impl cumulus_pallet_parachain_system::Config for Test {
 	type CheckAssociatedRelayNumber = AnyRelayNumber;
 	type ConsensusHook = cumulus_pallet_parachain_system::consensus_hook::ExpectParentIncluded;
 	type SelectCore = cumulus_pallet_parachain_system::DefaultCoreSelector<Test>;
+	type LowBudgetThreshold = ();
+	type OnLowBudget = ();
 }
 
 parameter_types! {
@@ -732,6 +732,39 @@ fn xcmp_queue_send_xcm_works() {
 	})
 }
 
+#[test]
+fn outbound_channel_limits_works() {
+	new_test_ext().execute_with(|| {
+		let sibling_para_id = ParaId::from(12345);
+		assert_eq!(XcmpQueue::outbound_channel_limits(sibling_para_id), None);
+
+		ParachainSystem::open_custom_outbound_hrmp_channel_for_benchmarks_or_tests(
+			sibling_para_id,
+			cumulus_primitives_core::AbridgedHrmpChannel {
+				max_message_size: 100,
+				max_capacity: 10,
+				max_total_size: 1_000,
+				msg_count: 2,
+				total_size: 50,
+				mqc_head: None,
+			},
+		);
+		// The format prefix of a page is not available for the message itself.
+		assert_eq!(
+			XcmpQueue::outbound_channel_limits(sibling_para_id),
+			Some(MessagingLimits {
+				max_message_size: 99,
+				remaining_count: 8,
+				remaining_size: 950,
+				suspended: false,
+			})
+		);
+
+		XcmpQueue::suspend_channel(sibling_para_id);
+		assert!(XcmpQueue::outbound_channel_limits(sibling_para_id).unwrap().suspended);
+	})
+}
+
 #[test]
 fn xcmp_queue_send_too_big_xcm_fails() {
 	new_test_ext().execute_with(|| {
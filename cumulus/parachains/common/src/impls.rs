@@ -290,6 +290,9 @@ mod tests {
 		type ValidatorId = <Self as frame_system::Config>::AccountId;
 		type ValidatorIdOf = IdentityCollator;
 		type ValidatorRegistration = IsRegistered;
+		type RotationPolicy = pallet_collator_selection::rotation::InvulnerablesThenCandidates;
+		type MissedSessionsThreshold = ();
+		type MissedSessionsSlash = ();
 		type KickThreshold = ();
 		type WeightInfo = ();
 	}
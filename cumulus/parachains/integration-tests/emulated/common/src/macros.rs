@@ -679,6 +679,257 @@ macro_rules! test_dry_run_transfer_across_pk_bridge {
 	};
 }
 
+/// Sends `$token` from Ethereum to `$asset_hub` through the Snowbridge inbound queue of
+/// `$bridge_hub`, and then over the Polkadot <> Kusama bridge to `$bridged_asset_hub`, paying the
+/// bridge fees with `$bridge_fee` of the native asset of `$asset_hub`.
+///
+/// `$bridged_native` is the native asset of `$asset_hub` on `$bridged_asset_hub`, which must
+/// already be registered there and able to pay for execution.
+#[macro_export]
+macro_rules! test_ethereum_asset_across_pk_bridge {
+	(
+		$bridge_hub:ty, $asset_hub:ty, $bridged_bridge_hub:ty, $bridged_asset_hub:ty,
+		$bridged_asset_hub_location:expr, $bridged_native:expr,
+		$chain_id:expr, $token:expr, $amount:expr, $ethereum_fee:expr, $bridge_fee:expr
+	) => {
+		$crate::macros::paste::paste! {
+			use frame_support::traits::fungibles::metadata::Inspect as MetadataInspect;
+			use snowbridge_router_primitives::inbound::{
+				Command, Destination, EthereumLocationsConverterFor, MessageV1, VersionedMessage,
+			};
+			use $crate::impls::Inspect;
+
+			let sender = [<$asset_hub Sender>]::get();
+			let receiver = [<$bridged_asset_hub Receiver>]::get();
+			let bridged_asset_hub_location: Location = $bridged_asset_hub_location;
+			let bridged_native: Location = $bridged_native;
+			let token_location = Location::new(
+				2,
+				[
+					GlobalConsensus(Ethereum { chain_id: $chain_id }),
+					AccountKey20 { network: None, key: $token },
+				],
+			);
+			let snowbridge_sovereign: AccountId =
+				EthereumLocationsConverterFor::<[u8; 32]>::from_chain_id(&$chain_id).into();
+			let asset_hub_sovereign_on_bridge_hub = <$bridge_hub>::sovereign_account_id_of(
+				<$bridge_hub>::sibling_location_of(<$asset_hub>::para_id()),
+			);
+			let reserve_on_asset_hub =
+				<$asset_hub>::sovereign_account_id_of(bridged_asset_hub_location.clone());
+
+			// Fund the sovereign account of the asset hub to pay for execution of the inbound
+			// messages and for the export over the bridge.
+			<$bridge_hub>::fund_para_sovereign(<$asset_hub>::para_id(), 10_000_000_000_000u128);
+
+			// Register the token and send it from Ethereum to the asset hub.
+			<$bridge_hub>::execute_with(|| {
+				type RuntimeEvent = <$bridge_hub as Chain>::RuntimeEvent;
+				type EthereumInboundQueue =
+					<$bridge_hub as [<$bridge_hub Pallet>]>::EthereumInboundQueue;
+
+				for command in [
+					Command::RegisterToken { token: $token.into(), fee: $ethereum_fee },
+					Command::SendToken {
+						token: $token.into(),
+						destination: Destination::AccountId32 { id: sender.clone().into() },
+						amount: $amount,
+						fee: $ethereum_fee,
+					},
+				] {
+					let message = VersionedMessage::V1(MessageV1 { chain_id: $chain_id, command });
+					let (xcm, _) = EthereumInboundQueue::do_convert([0; 32].into(), message).unwrap();
+					assert_ok!(EthereumInboundQueue::send_xcm(xcm, <$asset_hub>::para_id().into()));
+				}
+
+				assert_expected_events!(
+					$bridge_hub,
+					vec![
+						RuntimeEvent::XcmpQueue(
+							$crate::macros::cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. }
+						) => {},
+					]
+				);
+			});
+
+			<$asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						// Token is registered, owned by the Snowbridge sovereign account
+						RuntimeEvent::ForeignAssets(
+							$crate::macros::pallet_assets::Event::Created { asset_id, owner, .. }
+						) => {
+							asset_id: *asset_id == token_location,
+							owner: *owner == snowbridge_sovereign,
+						},
+						// Token is minted to the sender
+						RuntimeEvent::ForeignAssets(
+							$crate::macros::pallet_assets::Event::Issued { asset_id, owner, amount }
+						) => {
+							asset_id: *asset_id == token_location,
+							owner: *owner == sender,
+							amount: *amount == $amount,
+						},
+					]
+				);
+			});
+
+			// Register the token on the bridged asset hub with the same metadata.
+			<$bridged_asset_hub>::force_create_foreign_asset(
+				token_location.clone(),
+				<$bridged_asset_hub>::account_id_of($crate::accounts::ALICE),
+				true,
+				1,
+				vec![],
+			);
+			let metadata_on_asset_hub = <$asset_hub>::execute_with(|| {
+				type ForeignAssets = <$asset_hub as [<$asset_hub Pallet>]>::ForeignAssets;
+				(
+					<ForeignAssets as Inspect<_>>::minimum_balance(token_location.clone()),
+					<ForeignAssets as MetadataInspect<_>>::decimals(token_location.clone()),
+				)
+			});
+			let metadata_on_bridged_asset_hub = <$bridged_asset_hub>::execute_with(|| {
+				type ForeignAssets =
+					<$bridged_asset_hub as [<$bridged_asset_hub Pallet>]>::ForeignAssets;
+				(
+					<ForeignAssets as Inspect<_>>::minimum_balance(token_location.clone()),
+					<ForeignAssets as MetadataInspect<_>>::decimals(token_location.clone()),
+				)
+			});
+			assert_eq!(metadata_on_asset_hub, metadata_on_bridged_asset_hub);
+
+			// Bridge setup.
+			<$asset_hub>::force_xcm_version(bridged_asset_hub_location.clone(), XCM_VERSION);
+			open_bridge_between_asset_hub_rococo_and_asset_hub_westend();
+
+			let foreign_balance_on_bridged_asset_hub = |id: Location, who: &AccountId| {
+				<$bridged_asset_hub>::execute_with(|| {
+					type ForeignAssets =
+						<$bridged_asset_hub as [<$bridged_asset_hub Pallet>]>::ForeignAssets;
+					<ForeignAssets as Inspect<_>>::balance(id, who)
+				})
+			};
+			let receiver_native_before =
+				foreign_balance_on_bridged_asset_hub(bridged_native.clone(), &receiver);
+			let receiver_tokens_before =
+				foreign_balance_on_bridged_asset_hub(token_location.clone(), &receiver);
+
+			// Send the token over the bridge, the asset hub being its reserve.
+			let assets: Assets = vec![
+				(Parent, $bridge_fee).into(),
+				(token_location.clone(), $amount).into(),
+			]
+			.into();
+			let fees: AssetId = Parent.into();
+			let custom_xcm_on_dest = Xcm::<()>(vec![DepositAsset {
+				assets: Wild(AllCounted(assets.len() as u32)),
+				beneficiary: $crate::macros::AccountId32 { network: None, id: receiver.clone().into() }
+					.into(),
+			}]);
+			<$asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+				assert_ok!(
+					<$asset_hub as [<$asset_hub Pallet>]>::PolkadotXcm::transfer_assets_using_type_and_then(
+						<$asset_hub as Chain>::RuntimeOrigin::signed(sender.clone()),
+						bx!(bridged_asset_hub_location.clone().into()),
+						bx!(assets.into()),
+						bx!(TransferType::LocalReserve),
+						bx!(fees.into()),
+						bx!(TransferType::LocalReserve),
+						bx!(VersionedXcm::from(custom_xcm_on_dest)),
+						WeightLimit::Unlimited,
+					)
+				);
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						// Token is moved to the reserve of the bridged asset hub
+						RuntimeEvent::ForeignAssets(
+							$crate::macros::pallet_assets::Event::Transferred { asset_id, from, to, amount }
+						) => {
+							asset_id: *asset_id == token_location,
+							from: *from == sender,
+							to: *to == reserve_on_asset_hub,
+							amount: *amount == $amount,
+						},
+					]
+				);
+			});
+
+			<$bridge_hub>::execute_with(|| {
+				type RuntimeEvent = <$bridge_hub as Chain>::RuntimeEvent;
+				assert_expected_events!(
+					$bridge_hub,
+					vec![
+						// Bridge fees are paid by the sovereign account of the asset hub
+						RuntimeEvent::Balances(
+							$crate::macros::pallet_balances::Event::Burned { who, .. }
+						) => {
+							who: *who == asset_hub_sovereign_on_bridge_hub,
+						},
+						// Message is exported
+						RuntimeEvent::MessageQueue(
+							$crate::macros::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+			});
+
+			<$bridged_bridge_hub>::execute_with(|| {
+				type RuntimeEvent = <$bridged_bridge_hub as Chain>::RuntimeEvent;
+				assert_expected_events!(
+					$bridged_bridge_hub,
+					vec![
+						// Message is forwarded to the bridged asset hub
+						RuntimeEvent::XcmpQueue(
+							$crate::macros::cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. }
+						) => {},
+					]
+				);
+			});
+
+			<$bridged_asset_hub>::execute_with(|| {
+				type RuntimeEvent = <$bridged_asset_hub as Chain>::RuntimeEvent;
+				assert_expected_events!(
+					$bridged_asset_hub,
+					vec![
+						// Token is minted to the receiver
+						RuntimeEvent::ForeignAssets(
+							$crate::macros::pallet_assets::Event::Issued { asset_id, owner, amount }
+						) => {
+							asset_id: *asset_id == token_location,
+							owner: *owner == receiver,
+							amount: *amount == $amount,
+						},
+						RuntimeEvent::MessageQueue(
+							$crate::macros::pallet_message_queue::Event::Processed { success: true, .. }
+						) => {},
+					]
+				);
+			});
+
+			let receiver_native_after =
+				foreign_balance_on_bridged_asset_hub(bridged_native, &receiver);
+			let receiver_tokens_after =
+				foreign_balance_on_bridged_asset_hub(token_location.clone(), &receiver);
+			let reserve_tokens = <$asset_hub>::execute_with(|| {
+				type ForeignAssets = <$asset_hub as [<$asset_hub Pallet>]>::ForeignAssets;
+				<ForeignAssets as Inspect<_>>::balance(token_location, &reserve_on_asset_hub)
+			});
+
+			// The whole amount of tokens is held in reserve and received.
+			assert_eq!(reserve_tokens, $amount);
+			assert_eq!(receiver_tokens_after, receiver_tokens_before + $amount);
+			// Execution on the bridged asset hub is paid from the bridge fee.
+			assert!(receiver_native_after > receiver_native_before);
+			assert!(receiver_native_after < receiver_native_before + $bridge_fee);
+		}
+	};
+}
+
 #[macro_export]
 macro_rules! test_xcm_fee_querying_apis_work_for_asset_hub {
 	( $asset_hub:ty ) => {
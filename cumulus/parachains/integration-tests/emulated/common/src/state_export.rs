@@ -0,0 +1,98 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Export the state of emulated chains, so that a scenario set up by an integration test can be
+//! replayed against real nodes.
+//!
+//! Two formats are supported:
+//! - a raw chain spec, which can be used as `chain_spec_path` of a zombienet node;
+//! - a chopsticks config holding the storage as `import-storage`, to be merged with a config
+//!   pointing to a live `endpoint`.
+//!
+//! Only the top trie is exported, child tries are not.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde_json::{json, Value};
+use xcm_emulator::TestExt;
+
+/// Raw key-value pairs of a chain storage.
+pub type RawStorage = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// Read the whole top trie of the chain `C`, pending changes included.
+pub fn raw_storage<C: TestExt>() -> RawStorage {
+	C::ext_wrapper(|| {
+		let mut storage = RawStorage::new();
+		let mut next_key = sp_io::storage::next_key(&[]);
+		while let Some(key) = next_key {
+			if let Some(value) = sp_io::storage::get(&key) {
+				storage.insert(key.clone(), value.to_vec());
+			}
+			next_key = sp_io::storage::next_key(&key);
+		}
+		storage
+	})
+}
+
+/// Build a raw chain spec with `storage` as genesis.
+pub fn to_chain_spec(name: &str, id: &str, storage: &RawStorage) -> Value {
+	let top: serde_json::Map<String, Value> = storage
+		.iter()
+		.map(|(key, value)| {
+			(array_bytes::bytes2hex("0x", key), json!(array_bytes::bytes2hex("0x", value)))
+		})
+		.collect();
+	json!({
+		"name": name,
+		"id": id,
+		"chainType": "Local",
+		"bootNodes": [],
+		"telemetryEndpoints": null,
+		"protocolId": null,
+		"properties": null,
+		"codeSubstitutes": {},
+		"genesis": {
+			"raw": {
+				"top": top,
+				"childrenDefault": {},
+			},
+		},
+	})
+}
+
+/// Build a chopsticks config overriding the storage of the forked chain with `storage`.
+pub fn to_chopsticks_config(storage: &RawStorage) -> Value {
+	let pairs: Vec<Value> = storage
+		.iter()
+		.map(|(key, value)| {
+			json!([array_bytes::bytes2hex("0x", key), array_bytes::bytes2hex("0x", value)])
+		})
+		.collect();
+	json!({ "import-storage": pairs })
+}
+
+/// Write the state of the chain `C` to `<dir>/<name>.chain-spec.json` and
+/// `<dir>/<name>.chopsticks.json`.
+pub fn export_chain_state<C: TestExt>(dir: &Path, name: &str) -> io::Result<()> {
+	let storage = raw_storage::<C>();
+	fs::create_dir_all(dir)?;
+	write_json(&dir.join(format!("{name}.chain-spec.json")), &to_chain_spec(name, name, &storage))?;
+	write_json(&dir.join(format!("{name}.chopsticks.json")), &to_chopsticks_config(&storage))
+}
+
+fn write_json(path: &Path, value: &Value) -> io::Result<()> {
+	let json = serde_json::to_string_pretty(value).map_err(io::Error::other)?;
+	fs::write(path, json)
+}
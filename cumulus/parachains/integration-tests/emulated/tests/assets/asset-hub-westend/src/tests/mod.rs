@@ -22,6 +22,7 @@ mod set_asset_claimer;
 mod set_xcm_versions;
 mod swap;
 mod teleport;
+mod time_and_randomness;
 mod transact;
 mod treasury;
 mod xcm_fee_estimation;
@@ -0,0 +1,101 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::imports::*;
+use cumulus_pallet_parachain_system::{RelayChainStateProof, RelayStateProof, ValidationData};
+use emulated_integration_tests_common::xcm_emulator::{
+	polkadot_primitives::well_known_keys::ONE_EPOCH_AGO_RANDOMNESS, set_timestamp_storage, Network,
+};
+use westend_system_emulated_network::{westend_emulated_chain::westend_runtime, WestendMockNet};
+
+/// The Relay Chain randomness in the relay state proof of the current Asset Hub block.
+fn asset_hub_relay_randomness() -> [u8; 32] {
+	type Runtime = <AssetHubWestend as Chain>::Runtime;
+	let validation_data = ValidationData::<Runtime>::get().expect("set by the inherent; qed");
+	let proof = RelayStateProof::<Runtime>::get().expect("set by the inherent; qed");
+	RelayChainStateProof::new(
+		AssetHubWestend::para_id(),
+		validation_data.relay_parent_storage_root,
+		proof,
+	)
+	.unwrap()
+	.read_entry(ONE_EPOCH_AGO_RANDOMNESS, None)
+	.unwrap()
+}
+
+#[test]
+fn relay_chain_sees_set_timestamp_and_randomness() {
+	Westend::set_timestamp(1_000_000);
+	Westend::set_randomness([7; 32]);
+
+	Westend::execute_with(|| {
+		assert_eq!(westend_runtime::Timestamp::get(), 1_000_000);
+		assert_eq!(westend_runtime::Babe::randomness(), [7; 32]);
+
+		// Overwritten by the next block.
+		set_timestamp_storage(0);
+	});
+
+	Westend::execute_with(|| {
+		assert_eq!(westend_runtime::Timestamp::get(), 1_000_000);
+	});
+}
+
+#[test]
+fn parachain_sees_set_timestamp_and_randomness() {
+	AssetHubWestend::set_timestamp(2_000_000);
+	AssetHubWestend::set_randomness([8; 32]);
+
+	AssetHubWestend::execute_with(|| {
+		assert_eq!(asset_hub_westend_runtime::Timestamp::get(), 2_000_000);
+		assert_eq!(asset_hub_relay_randomness(), [8; 32]);
+	});
+
+	// The other chains are left untouched.
+	assert_eq!(WestendMockNet::timestamp(None), None);
+	assert_eq!(WestendMockNet::randomness(None), None);
+}
+
+#[test]
+fn parachain_randomness_falls_back_to_relay_chain() {
+	Westend::set_randomness([1; 32]);
+
+	AssetHubWestend::execute_with(|| {
+		assert_eq!(asset_hub_relay_randomness(), [1; 32]);
+	});
+
+	AssetHubWestend::set_randomness([2; 32]);
+
+	AssetHubWestend::execute_with(|| {
+		assert_eq!(asset_hub_relay_randomness(), [2; 32]);
+	});
+	Westend::execute_with(|| {
+		assert_eq!(westend_runtime::Babe::randomness(), [1; 32]);
+	});
+}
+
+#[test]
+fn reset_clears_timestamp_and_randomness() {
+	let para_id = Some(AssetHubWestend::para_id().into());
+	Westend::set_timestamp(1_000_000);
+	AssetHubWestend::set_randomness([3; 32]);
+	assert_eq!(WestendMockNet::timestamp(None), Some(1_000_000));
+	assert_eq!(WestendMockNet::randomness(para_id), Some([3; 32]));
+
+	WestendMockNet::reset();
+
+	assert_eq!(WestendMockNet::timestamp(None), None);
+	assert_eq!(WestendMockNet::randomness(para_id), None);
+}
@@ -0,0 +1,205 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests related to recovering assets trapped on the other side of the bridge.
+
+use crate::tests::*;
+
+/// Asset created on AH Rococo, which is not registered on AH Westend until the trapped assets are
+/// claimed.
+const UNREGISTERED_ASSET_ID: u32 = 2;
+
+fn asset_hub_rococo_location_on_ah_westend() -> Location {
+	Location::new(
+		2,
+		[
+			GlobalConsensus(ByGenesis(ROCOCO_GENESIS_HASH)),
+			Parachain(AssetHubRococo::para_id().into()),
+		],
+	)
+}
+
+fn unregistered_asset_at_ah_rococo() -> Location {
+	Location::new(
+		0,
+		[PalletInstance(ASSETS_PALLET_ID), GeneralIndex(UNREGISTERED_ASSET_ID.into())],
+	)
+}
+
+fn unregistered_asset_at_ah_westend() -> v5::Location {
+	v5::Location::new(
+		2,
+		[
+			v5::Junction::GlobalConsensus(v5::NetworkId::ByGenesis(ROCOCO_GENESIS_HASH)),
+			v5::Junction::Parachain(AssetHubRococo::para_id().into()),
+			v5::Junction::PalletInstance(ASSETS_PALLET_ID),
+			v5::Junction::GeneralIndex(UNREGISTERED_ASSET_ID.into()),
+		],
+	)
+}
+
+/// Send ROCs together with an asset not registered on AH Westend from AH Rococo to AH Westend.
+///
+/// The deposit to `beneficiary` fails on AH Westend, so all the assets left after paying for
+/// execution are trapped there, claimable by AH Rococo. Returns the trapped assets.
+pub(crate) fn trap_assets_on_asset_hub_westend(
+	sender: AccountId,
+	beneficiary: AccountId,
+	roc_amount: u128,
+	asset_amount: u128,
+) -> Assets {
+	let claimer = asset_hub_rococo_location_on_ah_westend();
+	let assets: Assets = vec![
+		(roc_at_ah_rococo(), roc_amount).into(),
+		(unregistered_asset_at_ah_rococo(), asset_amount).into(),
+	]
+	.into();
+	let fees: AssetId = roc_at_ah_rococo().into();
+	let custom_xcm_on_dest = Xcm::<()>::builder_unsafe()
+		.set_hints(vec![AssetClaimer { location: claimer.clone() }])
+		.deposit_asset(
+			AllCounted(assets.len() as u32),
+			AccountId32Junction { network: None, id: beneficiary.into() },
+		)
+		.build();
+
+	send_assets_over_bridge(|| {
+		assert_ok!(AssetHubRococo::execute_with(|| {
+			type PolkadotXcm = <AssetHubRococo as AssetHubRococoPallet>::PolkadotXcm;
+			PolkadotXcm::transfer_assets_using_type_and_then(
+				<AssetHubRococo as Chain>::RuntimeOrigin::signed(sender),
+				bx!(asset_hub_westend_location().into()),
+				bx!(assets.into()),
+				bx!(TransferType::LocalReserve),
+				bx!(fees.into()),
+				bx!(TransferType::LocalReserve),
+				bx!(VersionedXcm::from(custom_xcm_on_dest)),
+				WeightLimit::Unlimited,
+			)
+		}));
+	});
+
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		AssetHubWestend::events()
+			.into_iter()
+			.find_map(|event| match event {
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsTrapped {
+					origin,
+					assets,
+					..
+				}) if origin == claimer => Some(assets.try_into().expect("assets are v5")),
+				_ => None,
+			})
+			.expect("assets are trapped on AH Westend")
+	})
+}
+
+/// Claim `assets` trapped on AH Westend by sending `ClaimAsset` over the bridge as AH Rococo, and
+/// deposit them to the sovereign account of AH Rococo on AH Westend, using `fees` for execution.
+pub(crate) fn claim_assets_trapped_on_asset_hub_westend(assets: Assets, fees: Asset) {
+	let xcm = Xcm::<()>::builder_unsafe()
+		.claim_asset(assets.clone(), Here)
+		.buy_execution(fees, WeightLimit::Unlimited)
+		.deposit_asset(AllCounted(assets.len() as u32), asset_hub_rococo_location_on_ah_westend())
+		.build();
+
+	AssetHubRococo::execute_with(|| {
+		assert_ok!(<AssetHubRococo as AssetHubRococoPallet>::PolkadotXcm::send(
+			<AssetHubRococo as Chain>::RuntimeOrigin::root(),
+			bx!(asset_hub_westend_location().into()),
+			bx!(VersionedXcm::from(xcm)),
+		));
+
+		AssetHubRococo::assert_xcm_pallet_sent();
+	});
+
+	assert_bridge_hub_rococo_message_accepted(true);
+	assert_bridge_hub_westend_message_received();
+	AssetHubWestend::execute_with(|| {
+		type RuntimeEvent = <AssetHubWestend as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubWestend,
+			vec![
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsClaimed { origin, .. }) => {
+					origin: *origin == asset_hub_rococo_location_on_ah_westend(),
+				},
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+	});
+}
+
+#[test]
+fn assets_trapped_over_bridge_can_be_claimed_back() {
+	let roc_amount = ASSET_HUB_ROCOCO_ED * 1_000_000;
+	let asset_amount = ASSET_MIN_BALANCE * 1_000_000;
+	let sender = AssetHubRococoSender::get();
+	let receiver = AssetHubWestendReceiver::get();
+	let bridged_roc_at_asset_hub_westend = bridged_roc_at_ah_westend();
+	let bridged_asset_at_asset_hub_westend = unregistered_asset_at_ah_westend();
+
+	// ROCs can pay for execution on AH Westend, the other asset is unknown there
+	create_foreign_on_ah_westend(bridged_roc_at_asset_hub_westend.clone(), true);
+	set_up_pool_with_wnd_on_ah_westend(bridged_roc_at_asset_hub_westend.clone(), true);
+	AssetHubRococo::force_create_asset(
+		UNREGISTERED_ASSET_ID,
+		AssetHubRococo::account_id_of(ALICE),
+		true,
+		ASSET_MIN_BALANCE,
+		vec![(sender.clone(), asset_amount * 2)],
+	);
+
+	let sov_ahr_on_ahw = AssetHubWestend::sovereign_account_of_parachain_on_other_global_consensus(
+		ByGenesis(ROCOCO_GENESIS_HASH),
+		AssetHubRococo::para_id(),
+	);
+	let receiver_rocs_before =
+		foreign_balance_on_ah_westend(bridged_roc_at_asset_hub_westend.clone(), &receiver);
+
+	let trapped =
+		trap_assets_on_asset_hub_westend(sender, receiver.clone(), roc_amount, asset_amount);
+
+	// Nothing reached the receiver
+	let receiver_rocs_after =
+		foreign_balance_on_ah_westend(bridged_roc_at_asset_hub_westend.clone(), &receiver);
+	assert_eq!(receiver_rocs_after, receiver_rocs_before);
+
+	// Register the missing asset, then claim everything back to the sovereign account of AH Rococo
+	create_foreign_on_ah_westend(bridged_asset_at_asset_hub_westend.clone(), true);
+	let fees = trapped
+		.inner()
+		.iter()
+		.find(|asset| asset.id.0 == bridged_roc_at_asset_hub_westend)
+		.cloned()
+		.expect("ROCs are trapped");
+	let sov_rocs_before =
+		foreign_balance_on_ah_westend(bridged_roc_at_asset_hub_westend.clone(), &sov_ahr_on_ahw);
+
+	claim_assets_trapped_on_asset_hub_westend(trapped, fees);
+
+	let sov_rocs_after =
+		foreign_balance_on_ah_westend(bridged_roc_at_asset_hub_westend, &sov_ahr_on_ahw);
+	let sov_assets_after =
+		foreign_balance_on_ah_westend(bridged_asset_at_asset_hub_westend, &sov_ahr_on_ahw);
+
+	// The whole amount of the asset is recovered
+	assert_eq!(sov_assets_after, asset_amount);
+	// ROCs are recovered, less the fees of both executions
+	assert!(sov_rocs_after > sov_rocs_before);
+	assert!(sov_rocs_after < sov_rocs_before + roc_amount);
+}
@@ -17,10 +17,12 @@ use crate::imports::*;
 use xcm::opaque::v5;
 
 mod asset_transfers;
+mod asset_trapping;
 mod claim_assets;
 mod register_bridged_assets;
 mod send_xcm;
 mod snowbridge;
+mod state_export;
 mod teleport;
 
 pub(crate) fn asset_hub_westend_location() -> Location {
@@ -241,6 +243,27 @@ pub(crate) fn assert_bridge_hub_westend_message_received() {
 	})
 }
 
+pub(crate) fn send_assets_over_bridge<F: FnOnce()>(send_fn: F) {
+	// fund the AHR's SA on BHR for paying bridge delivery fees
+	BridgeHubRococo::fund_para_sovereign(AssetHubRococo::para_id(), 10_000_000_000_000u128);
+
+	// set XCM versions
+	let local_asset_hub = PenpalA::sibling_location_of(AssetHubRococo::para_id());
+	PenpalA::force_xcm_version(local_asset_hub.clone(), XCM_VERSION);
+	AssetHubRococo::force_xcm_version(asset_hub_westend_location(), XCM_VERSION);
+	BridgeHubRococo::force_xcm_version(bridge_hub_westend_location(), XCM_VERSION);
+
+	// open bridge
+	open_bridge_between_asset_hub_rococo_and_asset_hub_westend();
+
+	// send message over bridge
+	send_fn();
+
+	// process and verify intermediary hops
+	assert_bridge_hub_rococo_message_accepted(true);
+	assert_bridge_hub_westend_message_received();
+}
+
 pub(crate) fn open_bridge_between_asset_hub_rococo_and_asset_hub_westend() {
 	use testnet_parachains_constants::{
 		rococo::currency::UNITS as ROC, westend::currency::UNITS as WND,
@@ -0,0 +1,36 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::*;
+use emulated_integration_tests_common::state_export::{export_chain_state, raw_storage};
+use frame_support::storage::storage_prefix;
+
+#[test]
+fn export_state_after_bridge_setup_works() {
+	open_bridge_between_asset_hub_rococo_and_asset_hub_westend();
+
+	let storage = raw_storage::<BridgeHubRococo>();
+	let block_number =
+		storage.get(storage_prefix(b"System", b"Number").as_slice()).expect("always set");
+	assert_eq!(
+		block_number,
+		&BridgeHubRococo::ext_wrapper(|| <BridgeHubRococo as Chain>::System::block_number().encode())
+	);
+
+	let dir = std::env::temp_dir().join("bridge-hub-rococo-state-export");
+	assert_ok!(export_chain_state::<BridgeHubRococo>(&dir, "bridge-hub-rococo"));
+	assert!(dir.join("bridge-hub-rococo.chain-spec.json").exists());
+	assert!(dir.join("bridge-hub-rococo.chopsticks.json").exists());
+}
@@ -32,8 +32,9 @@ mod imports {
 	pub use emulated_integration_tests_common::{
 		accounts::ALICE,
 		impls::Inspect,
-		test_dry_run_transfer_across_pk_bridge, test_parachain_is_trusted_teleporter,
-		test_parachain_is_trusted_teleporter_for_relay, test_relay_is_trusted_teleporter,
+		test_dry_run_transfer_across_pk_bridge, test_ethereum_asset_across_pk_bridge,
+		test_parachain_is_trusted_teleporter, test_parachain_is_trusted_teleporter_for_relay,
+		test_relay_is_trusted_teleporter,
 		xcm_emulator::{
 			assert_expected_events, bx, Chain, Parachain as Para, RelayChain as Relay, TestExt,
 		},
@@ -267,6 +267,34 @@ fn send_wnds_usdt_and_weth_from_asset_hub_westend_to_asset_hub_rococo() {
 	assert_eq!(receiver_weth_after, receiver_weth_before + amount);
 }
 
+#[test]
+/// Test transfer of wETH from Ethereum to AssetHub Westend over Snowbridge, and then over to
+/// AssetHub Rococo through this bridge.
+fn send_weth_from_ethereum_through_asset_hub_westend_to_asset_hub_rococo() {
+	let bridged_wnd_at_asset_hub_rococo = bridged_wnd_at_ah_rococo();
+	create_foreign_on_ah_rococo(bridged_wnd_at_asset_hub_rococo.clone(), true);
+	create_pool_with_native_on!(
+		AssetHubRococo,
+		bridged_wnd_at_asset_hub_rococo.clone(),
+		true,
+		AssetHubRococoSender::get()
+	);
+
+	test_ethereum_asset_across_pk_bridge!(
+		BridgeHubWestend,
+		AssetHubWestend,
+		BridgeHubRococo,
+		AssetHubRococo,
+		asset_hub_rococo_location(),
+		bridged_wnd_at_asset_hub_rococo,
+		snowbridge::CHAIN_ID,
+		snowbridge::WETH,
+		100_000_000_000u128,
+		100_000_000_000u128,
+		ASSET_HUB_WESTEND_ED * 1_000
+	);
+}
+
 #[test]
 /// Send bridged ROCs "back" from AssetHub Westend to AssetHub Rococo.
 fn send_back_rocs_from_asset_hub_westend_to_asset_hub_rococo() {
@@ -270,6 +270,8 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = pallet_assets::AutoIncAssetId<Runtime, TrustBackedAssetsInstance>;
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	type AssetAccountDeposit = AssetAccountDeposit;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -314,6 +316,8 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -438,6 +442,8 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	type AssetAccountDeposit = ForeignAssetsAssetAccountDeposit;
 	type RemoveItemsLimit = frame_support::traits::ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -466,6 +472,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxCallSize = ConstU32<{ 16 * 1024 }>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -654,6 +661,7 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type CallValue = ();
 }
 
 parameter_types! {
@@ -674,6 +682,8 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
 	type ConsensusHook = ConsensusHook;
 	type SelectCore = cumulus_pallet_parachain_system::DefaultCoreSelector<Runtime>;
+	type LowBudgetThreshold = ();
+	type OnLowBudget = ();
 }
 
 type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
@@ -706,6 +716,8 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -801,6 +813,9 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type RotationPolicy = pallet_collator_selection::rotation::InvulnerablesThenCandidates;
+	type MissedSessionsThreshold = ();
+	type MissedSessionsSlash = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -971,6 +986,9 @@ impl pallet_revive::Config for Runtime {
 	type UnsafeUnstableInterface = ConstBool<false>;
 	type UploadOrigin = EnsureSigned<Self::AccountId>;
 	type InstantiateOrigin = EnsureSigned<Self::AccountId>;
+	type AllowlistOrigin = EnsureRoot<AccountId>;
+	type Scheduler = ();
+	type MaxScheduledCalls = ConstU32<0>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
 	type Debug = ();
@@ -1589,6 +1607,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl xcm_runtime_apis::conversions::LocationToAccountApi<Block, AccountId> for Runtime {
 		fn convert_location(location: VersionedLocation) -> Result<
 			AccountId,
@@ -1599,6 +1618,15 @@ impl_runtime_apis! {
 				xcm_config::LocationToAccountId,
 			>::convert_location(location)
 		}
+
+		fn derived_accounts(location: VersionedLocation) -> Result<
+			Vec<xcm_runtime_apis::conversions::DerivedAccount>,
+			xcm_runtime_apis::conversions::Error
+		> {
+			xcm_runtime_apis::conversions::DerivedAccountsHelper::<
+				xcm_config::LocationToAccountIdSchemes,
+			>::derived_accounts(location)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
@@ -1676,6 +1704,26 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::MessagingLimitsApi<Block> for Runtime {
+		fn upward_message_limits() -> Option<cumulus_primitives_core::MessagingLimits> {
+			ParachainSystem::upward_message_limits()
+		}
+
+		fn outbound_hrmp_channel_limits(recipient: ParaId) -> Option<cumulus_primitives_core::MessagingLimits> {
+			XcmpQueue::outbound_channel_limits(recipient)
+		}
+	}
+
+	impl pallet_collator_selection::runtime_api::CollatorPerformanceApi<Block, AccountId> for Runtime {
+		fn collator_performance(who: AccountId) -> Option<pallet_collator_selection::CollatorPerformanceInfo> {
+			CollatorSelection::collator_performance(&who)
+		}
+
+		fn all_collator_performance() -> Vec<(AccountId, pallet_collator_selection::CollatorPerformanceInfo)> {
+			CollatorSelection::all_collator_performance()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -2076,6 +2124,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl xcm_runtime_apis::outcomes::XcmOutcomeApi<Block, BlockNumber> for Runtime {
+		fn xcm_outcome(message_id: xcm::latest::XcmHash) -> Option<xcm_runtime_apis::outcomes::XcmOutcomeRecord<BlockNumber>> {
+			PolkadotXcm::xcm_outcome(message_id)
+		}
+	}
+
+	impl xcm_runtime_apis::barrier::XcmBarrierApi<Block> for Runtime {
+		fn check_barrier(origin: VersionedLocation, message: VersionedXcm<()>) -> Result<(), xcm_runtime_apis::barrier::Error> {
+			PolkadotXcm::check_barrier::<xcm_config::XcmConfig>(origin, message)
+		}
+	}
+
 	impl pallet_revive::ReviveApi<Block, AccountId, Balance, Nonce, BlockNumber, EventRecord> for Runtime
 	{
 		fn balance(address: H160) -> U256 {
@@ -2171,6 +2231,10 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn code_metadata(code_hash: sp_core::H256) -> Option<pallet_revive::CodeMetadataInfo> {
+			Revive::code_metadata(code_hash)
+		}
 	}
 }
 
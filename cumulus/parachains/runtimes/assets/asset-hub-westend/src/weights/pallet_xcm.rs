@@ -262,6 +262,14 @@ impl<T: frame_system::Config> pallet_xcm::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `PolkadotXcm::DestinationFeeHints` (r:0 w:1)
+	/// Proof: `PolkadotXcm::DestinationFeeHints` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn set_destination_fee_hint() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(2_432_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `PolkadotXcm::SupportedVersion` (r:6 w:2)
 	/// Proof: `PolkadotXcm::SupportedVersion` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	fn migrate_supported_version() -> Weight {
@@ -61,6 +61,7 @@ use xcm_builder::{
 	XcmFeeManagerFromComponents,
 };
 use xcm_executor::XcmExecutor;
+use xcm_runtime_apis::conversions::{AccountId32ToAddress, DerivationScheme};
 
 parameter_types! {
 	pub const RootLocation: Location = Location::here();
@@ -105,6 +106,45 @@ pub type LocationToAccountId = (
 	EthereumLocationsConverterFor<AccountId>,
 );
 
+parameter_types! {
+	pub const ParentIsPresetScheme: &'static str = "ParentIsPreset";
+	pub const SiblingParachainScheme: &'static str = "SiblingParachainConvertsVia";
+	pub const AccountId32AliasesScheme: &'static str = "AccountId32Aliases";
+	pub const HashedDescriptionScheme: &'static str = "HashedDescription";
+	pub const GlobalConsensusParachainScheme: &'static str = "GlobalConsensusParachainConvertsFor";
+	pub const EthereumLocationsScheme: &'static str = "EthereumLocationsConverterFor";
+	pub const ReviveAddressScheme: &'static str = "ReviveAddress";
+}
+
+/// The account derivation schemes exposed by the `LocationToAccountApi`: each of the
+/// `LocationToAccountId` ones, followed by the Ethereum address `pallet-revive` maps the
+/// `LocationToAccountId` account to.
+pub type LocationToAccountIdSchemes = (
+	DerivationScheme<ParentIsPresetScheme, ParentIsPreset<AccountId>, AccountId>,
+	DerivationScheme<
+		SiblingParachainScheme,
+		SiblingParachainConvertsVia<Sibling, AccountId>,
+		AccountId,
+	>,
+	DerivationScheme<
+		AccountId32AliasesScheme,
+		AccountId32Aliases<RelayNetwork, AccountId>,
+		AccountId,
+	>,
+	DerivationScheme<
+		HashedDescriptionScheme,
+		HashedDescription<AccountId, DescribeFamily<DescribeAllTerminal>>,
+		AccountId,
+	>,
+	DerivationScheme<
+		GlobalConsensusParachainScheme,
+		GlobalConsensusParachainConvertsFor<UniversalLocation, AccountId>,
+		AccountId,
+	>,
+	DerivationScheme<EthereumLocationsScheme, EthereumLocationsConverterFor<AccountId>, AccountId>,
+	DerivationScheme<ReviveAddressScheme, LocationToAccountId, AccountId, AccountId32ToAddress>,
+);
+
 /// Means for transacting the native currency on this chain.
 pub type FungibleTransactor = FungibleAdapter<
 	// Use this currency:
@@ -532,6 +572,7 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = ConstU32<1200>;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
@@ -21,7 +21,10 @@
 //! For example, the messaging pallet needs to know the sending and receiving chains, but the
 //! GRANDPA tracking pallet only needs to be aware of one chain.
 
-use super::{weights, AccountId, Balance, Balances, BlockNumber, Runtime, RuntimeEvent};
+use super::{
+	weights, AccountId, Balance, Balances, BlockNumber, BridgeRococoBulletinMessages,
+	BridgeWestendMessages, Runtime, RuntimeEvent,
+};
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
 use frame_support::{parameter_types, traits::ConstU32};
 
@@ -47,6 +50,7 @@ impl pallet_bridge_grandpa::Config<BridgeGrandpaWestendInstance> for Runtime {
 	type MaxFreeHeadersPerBlock = ConstU32<4>;
 	type FreeHeadersInterval = ConstU32<5>;
 	type HeadersToKeep = RelayChainHeadersToKeep;
+	type OnAuthoritySetCompromised = BridgeWestendMessages;
 	type WeightInfo = weights::pallet_bridge_grandpa::WeightInfo<Runtime>;
 }
 
@@ -115,6 +119,7 @@ impl pallet_bridge_grandpa::Config<BridgeGrandpaRococoBulletinInstance> for Runt
 	type MaxFreeHeadersPerBlock = ConstU32<4>;
 	type FreeHeadersInterval = ConstU32<5>;
 	type HeadersToKeep = RelayChainHeadersToKeep;
+	type OnAuthoritySetCompromised = BridgeRococoBulletinMessages;
 	// Technically this is incorrect - we have two pallet instances and ideally we shall
 	// benchmark every instance separately. But the benchmarking engine has a flaw - it
 	// messes with components. E.g. in Kusama maximal validators count is 1024 and in
@@ -262,4 +262,13 @@ impl<T: frame_system::Config> pallet_bridge_messages::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `BridgeWestendMessages::PalletOwner` (r:1 w:0)
+	/// Storage: `BridgeWestendMessages::OutboundLanes` (r:1 w:0)
+	/// Proof: `BridgeWestendMessages::OutboundLanes` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeWestendMessages::PausedOutboundLanes` (r:0 w:1)
+	fn set_outbound_lane_paused() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -56,6 +56,12 @@ impl<T: frame_system::Config> snowbridge_pallet_inbound_queue::WeightInfo for We
 	/// Proof: EthereumInboundQueue Nonce (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: EthereumInboundQueue ProcessedMessagesIndex (r:1 w:1)
+	/// Proof: EthereumInboundQueue ProcessedMessagesIndex (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	/// Storage: EthereumInboundQueue ProcessedMessagesMapping (r:1 w:1)
+	/// Proof: EthereumInboundQueue ProcessedMessagesMapping (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: EthereumInboundQueue ProcessedMessages (r:0 w:2)
+	/// Proof: EthereumInboundQueue ProcessedMessages (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `800`
@@ -63,7 +69,7 @@ impl<T: frame_system::Config> snowbridge_pallet_inbound_queue::WeightInfo for We
 		// Minimum execution time: 200_000_000 picoseconds.
 		Weight::from_parts(200_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 7200))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
 	}
 }
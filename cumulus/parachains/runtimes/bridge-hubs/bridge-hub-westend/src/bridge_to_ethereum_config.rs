@@ -100,6 +100,7 @@ impl snowbridge_pallet_inbound_queue::Config for Runtime {
 	type WeightInfo = crate::weights::snowbridge_pallet_inbound_queue::WeightInfo<Runtime>;
 	type PricingParameters = EthereumSystem;
 	type AssetTransactor = <xcm_config::XcmConfig as xcm_executor::Config>::AssetTransactor;
+	type MaxProcessedMessagesToKeep = ConstU32<8192>;
 }
 
 impl snowbridge_pallet_outbound_queue::Config for Runtime {
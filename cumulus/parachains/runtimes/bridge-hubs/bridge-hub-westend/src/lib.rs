@@ -99,8 +99,9 @@ use parachains_common::{
 	AVERAGE_ON_INITIALIZE_RATIO, NORMAL_DISPATCH_RATIO,
 };
 use snowbridge_core::{
+	inbound::{MessageImportStatus, ProcessedMessage},
 	outbound::{Command, Fee},
-	AgentId, PricingParameters,
+	AgentId, ChannelId, PricingParameters,
 };
 use testnet_parachains_constants::westend::{consensus::*, currency::*, fee::WeightToFee, time::*};
 use xcm::VersionedLocation;
@@ -369,6 +370,8 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
 	type ConsensusHook = ConsensusHook;
 	type SelectCore = cumulus_pallet_parachain_system::DefaultCoreSelector<Runtime>;
+	type LowBudgetThreshold = ();
+	type OnLowBudget = ();
 }
 
 type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
@@ -403,6 +406,8 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -495,6 +500,9 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type RotationPolicy = pallet_collator_selection::rotation::InvulnerablesThenCandidates;
+	type MissedSessionsThreshold = ();
+	type MissedSessionsSlash = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -512,6 +520,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<{ 16 * 1024 }>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -891,6 +900,43 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_bridge_hub_rococo::ToBridgeHubRococoOutboundLaneStatusApi<Block> for Runtime {
+		fn outbound_lane_status(
+			lane: LaneIdOf<Runtime, bridge_to_rococo_config::WithBridgeHubRococoMessagesInstance>,
+		) -> Option<bp_messages::OutboundLaneStatus> {
+			bridge_runtime_common::messages_api::outbound_lane_status::<
+				Runtime,
+				bridge_to_rococo_config::WithBridgeHubRococoMessagesInstance,
+			>(lane)
+		}
+	}
+
+	impl bp_relayers::RelayerRewardsApi<Block, AccountId, Balance, bp_messages::LegacyLaneId> for Runtime {
+		fn claimable_rewards(
+			relayer: AccountId,
+		) -> Vec<(bp_relayers::RewardsAccountParams<bp_messages::LegacyLaneId>, Balance)> {
+			BridgeRelayers::claimable_rewards(&relayer)
+		}
+	}
+
+	impl snowbridge_inbound_queue_runtime_api::InboundQueueApi<Block, BlockNumber> for Runtime {
+		fn latest_nonce(channel_id: ChannelId) -> u64 {
+			snowbridge_pallet_inbound_queue::api::latest_nonce::<Runtime>(channel_id)
+		}
+
+		fn message_import_status(channel_id: ChannelId, nonce: u64) -> MessageImportStatus<BlockNumber> {
+			snowbridge_pallet_inbound_queue::api::message_import_status::<Runtime>(channel_id, nonce)
+		}
+
+		fn processed_messages(
+			channel_id: ChannelId,
+			from_nonce: u64,
+			max_count: u32,
+		) -> Vec<(u64, ProcessedMessage<BlockNumber>)> {
+			snowbridge_pallet_inbound_queue::api::processed_messages::<Runtime>(channel_id, from_nonce, max_count)
+		}
+	}
+
 	impl snowbridge_outbound_queue_runtime_api::OutboundQueueApi<Block, Balance> for Runtime {
 		fn prove_message(leaf_index: u64) -> Option<snowbridge_pallet_outbound_queue::MerkleProof> {
 			snowbridge_pallet_outbound_queue::api::prove_message::<Runtime>(leaf_index)
@@ -1358,6 +1404,18 @@ impl_runtime_apis! {
 			PolkadotXcm::is_trusted_teleporter(asset, location)
 		}
 	}
+
+	impl xcm_runtime_apis::outcomes::XcmOutcomeApi<Block, BlockNumber> for Runtime {
+		fn xcm_outcome(message_id: xcm::latest::XcmHash) -> Option<xcm_runtime_apis::outcomes::XcmOutcomeRecord<BlockNumber>> {
+			PolkadotXcm::xcm_outcome(message_id)
+		}
+	}
+
+	impl xcm_runtime_apis::barrier::XcmBarrierApi<Block> for Runtime {
+		fn check_barrier(origin: VersionedLocation, message: VersionedXcm<()>) -> Result<(), xcm_runtime_apis::barrier::Error> {
+			PolkadotXcm::check_barrier::<xcm_config::XcmConfig>(origin, message)
+		}
+	}
 }
 
 cumulus_pallet_parachain_system::register_validate_block! {
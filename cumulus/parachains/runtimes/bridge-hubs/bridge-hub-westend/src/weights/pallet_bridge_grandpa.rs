@@ -76,6 +76,31 @@ impl<T: frame_system::Config> pallet_bridge_grandpa::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	/// Storage: `BridgeRococoGrandpa::CurrentAuthoritySet` (r:1 w:0)
+	/// Proof: `BridgeRococoGrandpa::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(50250), added: 50745, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::PalletOperatingMode` (r:1 w:0)
+	/// Proof: `BridgeRococoGrandpa::PalletOperatingMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::BestFinalized` (r:1 w:1)
+	/// Proof: `BridgeRococoGrandpa::BestFinalized` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::FreeHeadersRemaining` (r:1 w:0)
+	/// Proof: `BridgeRococoGrandpa::FreeHeadersRemaining` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::ImportedHashesPointer` (r:1 w:1)
+	/// Proof: `BridgeRococoGrandpa::ImportedHashesPointer` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::ImportedHashes` (r:1 w:1)
+	/// Proof: `BridgeRococoGrandpa::ImportedHashes` (`max_values`: Some(1024), `max_size`: Some(36), added: 1521, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::ImportedHeaders` (r:0 w:2)
+	/// Proof: `BridgeRococoGrandpa::ImportedHeaders` (`max_values`: Some(1024), `max_size`: Some(68), added: 1553, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 168]`.
+	fn submit_compact_finality_proof(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `268 + p * (60 ±0)`
+		//  Estimated: `51735`
+		Weight::from_parts(406_081_000, 0)
+			.saturating_add(Weight::from_parts(0, 51735))
+			.saturating_add(Weight::from_parts(40_356_046, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 	/// Storage: `BridgeRococoGrandpa::CurrentAuthoritySet` (r:1 w:1)
 	/// Proof: `BridgeRococoGrandpa::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(50250), added: 50745, mode: `MaxEncodedLen`)
 	/// Storage: `BridgeRococoGrandpa::ImportedHashesPointer` (r:1 w:1)
@@ -96,4 +121,15 @@ impl<T: frame_system::Config> pallet_bridge_grandpa::WeightInfo for WeightInfo<T
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(6))
 	}
+	/// Storage: `BridgeRococoGrandpa::CurrentAuthoritySet` (r:1 w:0)
+	/// Proof: `BridgeRococoGrandpa::CurrentAuthoritySet` (`max_values`: Some(1), `max_size`: Some(50250), added: 50745, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoGrandpa::CompromisedAuthoritySetId` (r:1 w:1)
+	/// Proof: `BridgeRococoGrandpa::CompromisedAuthoritySetId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn report_equivocation() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(101_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 51735))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
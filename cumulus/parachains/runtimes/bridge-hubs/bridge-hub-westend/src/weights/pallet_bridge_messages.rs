@@ -259,4 +259,13 @@ impl<T: frame_system::Config> pallet_bridge_messages::WeightInfo for WeightInfo<
 			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `BridgeRococoMessages::PalletOwner` (r:1 w:0)
+	/// Storage: `BridgeRococoMessages::OutboundLanes` (r:1 w:0)
+	/// Proof: `BridgeRococoMessages::OutboundLanes` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	/// Storage: `BridgeRococoMessages::PausedOutboundLanes` (r:0 w:1)
+	fn set_outbound_lane_paused() -> Weight {
+		Weight::from_parts(0, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
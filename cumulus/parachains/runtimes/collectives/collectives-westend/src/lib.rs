@@ -257,6 +257,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<{ 16 * 1024 }>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -384,6 +385,7 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type CallValue = ();
 }
 
 parameter_types! {
@@ -404,6 +406,8 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type CheckAssociatedRelayNumber = RelayNumberMonotonicallyIncreases;
 	type ConsensusHook = ConsensusHook;
 	type SelectCore = cumulus_pallet_parachain_system::DefaultCoreSelector<Runtime>;
+	type LowBudgetThreshold = ();
+	type OnLowBudget = ();
 }
 
 type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
@@ -436,6 +440,8 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -535,6 +541,9 @@ impl pallet_collator_selection::Config for Runtime {
 	type ValidatorId = <Self as frame_system::Config>::AccountId;
 	type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
 	type ValidatorRegistration = Session;
+	type RotationPolicy = pallet_collator_selection::rotation::InvulnerablesThenCandidates;
+	type MissedSessionsThreshold = ();
+	type MissedSessionsSlash = ();
 	type WeightInfo = weights::pallet_collator_selection::WeightInfo<Runtime>;
 }
 
@@ -654,6 +663,7 @@ impl pallet_asset_rate::Config for Runtime {
 	>;
 	type RemoveOrigin = Self::CreateOrigin;
 	type UpdateOrigin = Self::CreateOrigin;
+	type OracleOrigin = Self::CreateOrigin;
 	type Currency = Balances;
 	type AssetKind = VersionedLocatableAsset;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1183,6 +1193,18 @@ impl_runtime_apis! {
 			PolkadotXcm::is_trusted_teleporter(asset, location)
 		}
 	}
+
+	impl xcm_runtime_apis::outcomes::XcmOutcomeApi<Block, BlockNumber> for Runtime {
+		fn xcm_outcome(message_id: xcm::latest::XcmHash) -> Option<xcm_runtime_apis::outcomes::XcmOutcomeRecord<BlockNumber>> {
+			PolkadotXcm::xcm_outcome(message_id)
+		}
+	}
+
+	impl xcm_runtime_apis::barrier::XcmBarrierApi<Block> for Runtime {
+		fn check_barrier(origin: VersionedLocation, message: VersionedXcm<()>) -> Result<(), xcm_runtime_apis::barrier::Error> {
+			PolkadotXcm::check_barrier::<xcm_config::XcmConfig>(origin, message)
+		}
+	}
 }
 
 cumulus_pallet_parachain_system::register_validate_block! {
@@ -290,6 +290,7 @@ impl pallet_xcm::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = ConstU32<1200>;
 }
 
 impl cumulus_pallet_xcm::Config for Runtime {
@@ -32,7 +32,7 @@ pub use polkadot_parachain_primitives::primitives::{
 	XcmpMessageHandler,
 };
 pub use polkadot_primitives::{
-	vstaging::{ClaimQueueOffset, CoreSelector},
+	vstaging::{ClaimQueueOffset, CoreSelector, MessagingLimits},
 	AbridgedHostConfiguration, AbridgedHrmpChannel, PersistedValidationData,
 };
 
@@ -184,6 +184,17 @@ impl XcmpMessageSource for () {
 	}
 }
 
+/// Proof size consumed by a pallet in a block.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Eq, RuntimeDebug,
+)]
+pub struct PoVSizeUsage {
+	/// Proof size consumed by the `on_initialize`, `on_idle` and `on_finalize` hooks.
+	pub hooks: u64,
+	/// Proof size consumed by the extrinsics that were dispatched to the pallet.
+	pub extrinsics: u64,
+}
+
 /// The "quality of service" considerations for message sending.
 #[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug)]
 pub enum ServiceQuality {
@@ -402,4 +413,33 @@ sp_api::decl_runtime_apis! {
 		/// Retrieve core selector and claim queue offset for the next block.
 		fn core_selector() -> (CoreSelector, ClaimQueueOffset);
 	}
+
+	/// Runtime api to query the effective limits for messages sent by this parachain.
+	///
+	/// This allows messages to be validated before they are sent. The limits are based on the
+	/// relay chain state that was provided with the latest block.
+	pub trait MessagingLimitsApi {
+		/// Returns the limits for upward messages sent to the relay chain.
+		///
+		/// Returns `None` if the relay chain state is not known yet.
+		fn upward_message_limits() -> Option<MessagingLimits>;
+
+		/// Returns the limits for messages sent to the sibling parachain `recipient`.
+		///
+		/// Returns `None` if there is no open channel to `recipient`.
+		fn outbound_hrmp_channel_limits(recipient: ParaId) -> Option<MessagingLimits>;
+	}
+
+	/// Runtime api to query the proof size consumed by every pallet of the parachain runtime.
+	///
+	/// The proof size is only recorded if the runtime is built with the `pov-diagnostics`
+	/// feature of `cumulus-pallet-parachain-system`. It is meant to find the PoV hotspots of a
+	/// runtime and should not be enabled in production.
+	pub trait PoVSizeDiagnosticsApi {
+		/// Returns the proof size consumed by every pallet in the latest block, keyed by the
+		/// pallet name.
+		///
+		/// Pallets that did not consume any proof size are not returned.
+		fn pov_size_diagnostics() -> Vec<(Vec<u8>, PoVSizeUsage)>;
+	}
 }
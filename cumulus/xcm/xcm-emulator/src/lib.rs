@@ -107,6 +107,10 @@ thread_local! {
 	pub static INITIALIZED: RefCell<HashMap<String, bool>> = RefCell::new(HashMap::new());
 	/// Most recent `HeadData` of each parachain, encoded.
 	pub static LAST_HEAD: RefCell<HashMap<String, HashMap<u32, HeadData>>> = RefCell::new(HashMap::new());
+	/// Timestamp of each chain by para id, `None` being the Relay Chain.
+	pub static TIMESTAMPS: RefCell<HashMap<String, HashMap<Option<u32>, u64>>> = RefCell::new(HashMap::new());
+	/// Relay Chain randomness seen by each chain by para id, `None` being the Relay Chain.
+	pub static RANDOMNESS: RefCell<HashMap<String, HashMap<Option<u32>, [u8; 32]>>> = RefCell::new(HashMap::new());
 }
 
 pub trait CheckAssertion<Origin, Destination, Hops, Args>
@@ -219,6 +223,31 @@ pub trait Network {
 	fn send_bridged_messages(msg: BridgeMessage) {
 		BRIDGED_MESSAGES.with(|b| b.borrow_mut().get_mut(Self::name()).unwrap().push_back(msg));
 	}
+
+	fn set_timestamp(para_id: Option<u32>, moment: u64) {
+		TIMESTAMPS.with(|b| {
+			b.borrow_mut().entry(Self::name().to_string()).or_default().insert(para_id, moment)
+		});
+	}
+
+	fn timestamp(para_id: Option<u32>) -> Option<u64> {
+		TIMESTAMPS.with(|b| b.borrow().get(Self::name())?.get(&para_id).copied())
+	}
+
+	fn set_randomness(para_id: Option<u32>, randomness: [u8; 32]) {
+		RANDOMNESS.with(|b| {
+			b.borrow_mut().entry(Self::name().to_string()).or_default().insert(para_id, randomness)
+		});
+	}
+
+	/// The randomness of a parachain falls back to the one of the Relay Chain.
+	fn randomness(para_id: Option<u32>) -> Option<[u8; 32]> {
+		RANDOMNESS.with(|b| {
+			let randomness = b.borrow();
+			let randomness = randomness.get(Self::name())?;
+			randomness.get(&para_id).or_else(|| randomness.get(&None)).copied()
+		})
+	}
 }
 
 pub trait Chain: TestExt {
@@ -237,6 +266,30 @@ pub trait Chain: TestExt {
 	fn account_data_of(account: AccountIdOf<Self::Runtime>) -> AccountData<Balance>;
 
 	fn events() -> Vec<<Self as Chain>::RuntimeEvent>;
+
+	/// Set the timestamp in milliseconds that the chain sees from its next block on.
+	fn set_timestamp(moment: u64);
+
+	/// Set the Relay Chain randomness that the chain sees from its next block on.
+	///
+	/// Parachains without their own randomness see the one of the Relay Chain.
+	fn set_randomness(randomness: [u8; 32]);
+}
+
+/// Set the `Now` storage item of `pallet-timestamp`, which must be named `Timestamp`.
+pub fn set_timestamp_storage(moment: u64) {
+	frame_support::storage::unhashed::put(
+		&frame_support::storage::storage_prefix(b"Timestamp", b"Now"),
+		&moment,
+	);
+}
+
+/// Set the randomness of BABE, which is the one of the previous epoch.
+pub fn set_randomness_storage(randomness: [u8; 32]) {
+	frame_support::storage::unhashed::put(
+		polkadot_primitives::well_known_keys::ONE_EPOCH_AGO_RANDOMNESS,
+		&randomness,
+	);
 }
 
 pub trait RelayChain: Chain {
@@ -394,6 +447,14 @@ macro_rules! decl_test_relay_chains {
 						.map(|record| record.event.clone())
 						.collect()
 				}
+
+				fn set_timestamp(moment: u64) {
+					N::set_timestamp(None, moment);
+				}
+
+				fn set_randomness(randomness: [u8; 32]) {
+					N::set_randomness(None, randomness);
+				}
 			}
 
 			impl<N: $crate::Network> $crate::RelayChain for $name<N> {
@@ -533,6 +594,18 @@ macro_rules! __impl_test_ext_for_relay_chain {
 				// Make sure the Network is initialized
 				<$network>::init();
 
+				// Set the timestamp and randomness of the block
+				$local_ext.with(|v| {
+					v.borrow_mut().execute_with(|| {
+						if let Some(moment) = <$network>::timestamp(None) {
+							$crate::set_timestamp_storage(moment);
+						}
+						if let Some(randomness) = <$network>::randomness(None) {
+							$crate::set_randomness_storage(randomness);
+						}
+					})
+				});
+
 				// Execute
 				let r = $local_ext.with(|v| {
 					$crate::log::info!(target: "xcm::emulator::execute_with", "Executing as {}", stringify!($name));
@@ -631,6 +704,16 @@ macro_rules! decl_test_parachains {
 						.map(|record| record.event.clone())
 						.collect()
 				}
+
+				fn set_timestamp(moment: u64) {
+					use $crate::Parachain;
+					N::set_timestamp(Some(Self::para_id().into()), moment);
+				}
+
+				fn set_randomness(randomness: [u8; 32]) {
+					use $crate::Parachain;
+					N::set_randomness(Some(Self::para_id().into()), randomness);
+				}
 			}
 
 			impl<N: $crate::Network> $crate::Parachain for $name<N> {
@@ -678,6 +761,9 @@ macro_rules! decl_test_parachains {
 							.clone()
 						);
 						<Self as Chain>::System::initialize(&block_number, &parent_head_data.hash(), &Default::default());
+						if let Some(moment) = N::timestamp(Some(para_id)) {
+							$crate::set_timestamp_storage(moment);
+						}
 						<<Self as Parachain>::ParachainSystem as Hooks<$crate::BlockNumberFor<Self::Runtime>>>::on_initialize(block_number);
 
 						let _ = <Self as Parachain>::ParachainSystem::set_validation_data(
@@ -947,6 +1033,8 @@ macro_rules! decl_test_networks {
 					$crate::HORIZONTAL_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::BRIDGED_MESSAGES.with(|b| b.borrow_mut().remove(Self::name()));
 					$crate::LAST_HEAD.with(|b| b.borrow_mut().remove(Self::name()));
+					$crate::TIMESTAMPS.with(|b| b.borrow_mut().remove(Self::name()));
+					$crate::RANDOMNESS.with(|b| b.borrow_mut().remove(Self::name()));
 
 					<$relay_chain<Self>>::reset_ext();
 					$( <$parachain<Self>>::reset_ext(); )*
@@ -1118,6 +1206,9 @@ macro_rules! decl_test_networks {
 				) -> $crate::ParachainInherentData {
 					let mut sproof = $crate::RelayStateSproofBuilder::default();
 					sproof.para_id = para_id.into();
+					if let Some(randomness) = Self::randomness(Some(para_id)) {
+						sproof.randomness = randomness.into();
+					}
 
 					// egress channel
 					let e_index = sproof.hrmp_egress_channel_index.get_or_insert_with(Vec::new);
@@ -583,6 +583,9 @@ where
 				beefy: polkadot_rpc::BeefyDeps::<ecdsa_crypto::AuthorityId> {
 					beefy_finality_proof_stream: beefy_rpc_links.from_voter_justif_stream.clone(),
 					beefy_best_block_stream: beefy_rpc_links.from_voter_best_beefy_stream.clone(),
+					beefy_justif_request_sender: beefy_rpc_links
+						.to_voter_justif_request_sender
+						.clone(),
 					subscription_executor,
 				},
 				backend: backend.clone(),
@@ -1033,6 +1036,7 @@ pub fn new_full<
 				network_provider: Arc::new(network.clone()),
 				is_validator: role.is_authority(),
 				enable_http_requests: false,
+				http_response_size_limit: config.offchain_worker.http_response_size_limit,
 				custom_extensions: move |_| vec![],
 			})?
 			.run(client.clone(), task_manager.spawn_handle())
@@ -297,5 +297,23 @@ sp_api::decl_runtime_apis! {
 		/// Elastic scaling support
 		#[api_version(11)]
 		fn candidates_pending_availability(para_id: ppp::Id) -> Vec<CommittedCandidateReceipt<Hash>>;
+
+		/***** Added in v12 *****/
+		/// Returns the effective limits for upward messages sent by the given parachain.
+		#[api_version(12)]
+		fn upward_message_limits(para_id: ppp::Id) -> vstaging::MessagingLimits;
+
+		/***** Added in v12 *****/
+		/// Returns the effective limits of the HRMP channel from `sender` to `recipient`.
+		///
+		/// Returns `None` if no such channel is open.
+		#[api_version(12)]
+		fn hrmp_channel_limits(sender: ppp::Id, recipient: ppp::Id) -> Option<vstaging::MessagingLimits>;
+
+		/***** Added in v12 *****/
+		/// Returns the inclusion statistics of the given parachain for each of the recent sessions
+		/// in which it had candidates, oldest first.
+		#[api_version(12)]
+		fn para_inclusion_stats(para_id: ppp::Id) -> Vec<(SessionIndex, vstaging::InclusionStats)>;
 	}
 }
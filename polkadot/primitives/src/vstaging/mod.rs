@@ -926,6 +926,37 @@ impl<H: Copy> From<CoreState<H>> for super::v8::CoreState<H> {
 	}
 }
 
+/// The effective limits that apply to messages sent by a parachain over a single channel, i.e. the
+/// upward message queue or an outbound HRMP channel.
+///
+/// This allows messages to be validated before they are sent.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct MessagingLimits {
+	/// The maximum size of a single message.
+	pub max_message_size: u32,
+	/// The number of messages that can still be enqueued before the channel is full.
+	pub remaining_count: u32,
+	/// The total size of the messages that can still be enqueued before the channel is full.
+	pub remaining_size: u32,
+	/// Whether no further messages should be sent over the channel, e.g. because it is being
+	/// closed or the recipient asked the sender to suspend sending.
+	pub suspended: bool,
+}
+
+/// Statistics of the candidates of a parachain over a session.
+///
+/// This allows parachain teams and coretime purchasers to verify the service level they get.
+#[derive(Clone, Copy, Default, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct InclusionStats {
+	/// The number of candidates that were backed.
+	pub backed: u32,
+	/// The number of candidates that were included, i.e. became available.
+	pub included: u32,
+	/// The number of candidates that timed out before becoming available, including their
+	/// descendants that were evicted with them.
+	pub timed_out: u32,
+}
+
 /// The claim queue mapped by parachain id.
 pub type TransposedClaimQueue = BTreeMap<ParaId, BTreeMap<u8, BTreeSet<CoreIndex>>>;
 
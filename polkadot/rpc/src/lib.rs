@@ -24,7 +24,7 @@ use jsonrpsee::RpcModule;
 use polkadot_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
 use sc_client_api::AuxStore;
 use sc_consensus_beefy::communication::notification::{
-	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
+	BeefyBestBlockStream, BeefyJustificationRequestSender, BeefyVersionedFinalityProofStream,
 };
 use sc_consensus_grandpa::FinalityProofProvider;
 pub use sc_rpc::SubscriptionTaskExecutor;
@@ -69,6 +69,8 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
 	pub beefy_finality_proof_stream: BeefyVersionedFinalityProofStream<Block, AuthorityId>,
 	/// Receives notifications about best block events from BEEFY.
 	pub beefy_best_block_stream: BeefyBestBlockStream<Block>,
+	/// Sends requests for BEEFY justifications to BEEFY.
+	pub beefy_justif_request_sender: BeefyJustificationRequestSender<Block, AuthorityId>,
 	/// Executor to drive the subscription manager in the BEEFY RPC handler.
 	pub subscription_executor: sc_rpc::SubscriptionTaskExecutor,
 }
@@ -175,6 +177,7 @@ where
 		Beefy::<Block, AuthorityId>::new(
 			beefy.beefy_finality_proof_stream,
 			beefy.beefy_best_block_stream,
+			beefy.beefy_justif_request_sender,
 			beefy.subscription_executor,
 		)?
 		.into_rpc(),
@@ -299,7 +299,7 @@ mod tests {
 	};
 	use frame_system::limits;
 	use polkadot_primitives::AccountId;
-	use sp_core::{ConstU64, H256};
+	use sp_core::{ConstU32, ConstU64, H256};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup},
 		BuildStorage, Perbill,
@@ -372,6 +372,7 @@ mod tests {
 	impl pallet_treasury::Config for Test {
 		type Currency = pallet_balances::Pallet<Test>;
 		type RejectOrigin = frame_system::EnsureRoot<AccountId>;
+		type MilestoneApproveOrigin = frame_system::EnsureRoot<AccountId>;
 		type RuntimeEvent = RuntimeEvent;
 		type SpendPeriod = ();
 		type Burn = ();
@@ -387,6 +388,7 @@ mod tests {
 		type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 		type BalanceConverter = UnityAssetBalanceConversion;
 		type PayoutPeriod = ConstU64<0>;
+		type MaxMilestones = ConstU32<16>;
 		type BlockNumberProvider = System;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
@@ -29,8 +29,8 @@ use frame_support::{pallet_prelude::*, traits::ReservableCurrency, DefaultNoBoun
 use frame_system::pallet_prelude::*;
 use polkadot_parachain_primitives::primitives::{HorizontalMessages, IsSystem};
 use polkadot_primitives::{
-	Balance, Hash, HrmpChannelId, Id as ParaId, InboundHrmpMessage, OutboundHrmpMessage,
-	SessionIndex,
+	vstaging::MessagingLimits, Balance, Hash, HrmpChannelId, Id as ParaId, InboundHrmpMessage,
+	OutboundHrmpMessage, SessionIndex,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{
@@ -1666,6 +1666,23 @@ impl<T: Config> Pallet<T> {
 
 		inbound_hrmp_channels_contents
 	}
+
+	/// Returns the effective limits of the HRMP channel from `sender` to `recipient`.
+	///
+	/// Returns `None` if no such channel is open.
+	pub(crate) fn outbound_channel_limits(
+		sender: ParaId,
+		recipient: ParaId,
+	) -> Option<MessagingLimits> {
+		let channel_id = HrmpChannelId { sender, recipient };
+		let channel = HrmpChannels::<T>::get(&channel_id)?;
+		Some(MessagingLimits {
+			max_message_size: channel.max_message_size,
+			remaining_count: channel.max_capacity.saturating_sub(channel.msg_count),
+			remaining_size: channel.max_total_size.saturating_sub(channel.total_size),
+			suspended: HrmpCloseChannelRequests::<T>::contains_key(&channel_id),
+		})
+	}
 }
 
 impl<T: Config> Pallet<T> {
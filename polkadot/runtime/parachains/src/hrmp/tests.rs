@@ -530,6 +530,60 @@ fn send_recv_messages() {
 	});
 }
 
+#[test]
+fn outbound_channel_limits_works() {
+	let para_a = 2032.into();
+	let para_b = 2064.into();
+	let para_b_origin: crate::Origin = 2064.into();
+
+	let mut genesis = GenesisConfigBuilder::default();
+	genesis.hrmp_channel_max_message_size = 20;
+	genesis.hrmp_channel_max_total_size = 20;
+	new_test_ext(genesis.build()).execute_with(|| {
+		register_parachain(para_a);
+		register_parachain(para_b);
+
+		run_to_block(5, Some(vec![4, 5]));
+		assert_eq!(Hrmp::outbound_channel_limits(para_a, para_b), None);
+		Hrmp::init_open_channel(para_a, para_b, 2, 20).unwrap();
+		Hrmp::accept_open_channel(para_b, para_a).unwrap();
+
+		run_to_block(6, Some(vec![6]));
+		assert_eq!(
+			Hrmp::outbound_channel_limits(para_a, para_b),
+			Some(MessagingLimits {
+				max_message_size: 20,
+				remaining_count: 2,
+				remaining_size: 20,
+				suspended: false,
+			})
+		);
+		// The reverse channel is not open.
+		assert_eq!(Hrmp::outbound_channel_limits(para_b, para_a), None);
+
+		let _ = Hrmp::queue_outbound_hrmp(
+			para_a,
+			vec![OutboundHrmpMessage { recipient: para_b, data: b"knock".to_vec() }]
+				.try_into()
+				.unwrap(),
+		);
+		assert_eq!(
+			Hrmp::outbound_channel_limits(para_a, para_b),
+			Some(MessagingLimits {
+				max_message_size: 20,
+				remaining_count: 1,
+				remaining_size: 15,
+				suspended: false,
+			})
+		);
+
+		// A channel that is about to be closed is reported as suspended.
+		let channel_id = HrmpChannelId { sender: para_a, recipient: para_b };
+		Hrmp::hrmp_close_channel(para_b_origin.into(), channel_id).unwrap();
+		assert!(Hrmp::outbound_channel_limits(para_a, para_b).unwrap().suspended);
+	});
+}
+
 #[test]
 fn hrmp_mqc_head_fixture() {
 	let para_a = 2000.into();
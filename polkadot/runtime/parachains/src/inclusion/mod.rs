@@ -48,11 +48,11 @@ use polkadot_primitives::{
 	vstaging::{
 		skip_ump_signals, BackedCandidate, CandidateDescriptorV2 as CandidateDescriptor,
 		CandidateReceiptV2 as CandidateReceipt,
-		CommittedCandidateReceiptV2 as CommittedCandidateReceipt,
+		CommittedCandidateReceiptV2 as CommittedCandidateReceipt, InclusionStats, MessagingLimits,
 	},
 	well_known_keys, CandidateCommitments, CandidateHash, CoreIndex, GroupIndex, HeadData,
-	Id as ParaId, SignedAvailabilityBitfields, SigningContext, UpwardMessage, ValidatorId,
-	ValidatorIndex, ValidityAttestation,
+	Id as ParaId, SessionIndex, SignedAvailabilityBitfields, SigningContext, UpwardMessage,
+	ValidatorId, ValidatorIndex, ValidityAttestation,
 };
 use scale_info::TypeInfo;
 use sp_runtime::{traits::One, DispatchError, SaturatedConversion, Saturating};
@@ -349,12 +349,20 @@ pub mod pallet {
 		VecDeque<CandidatePendingAvailability<T::Hash, BlockNumberFor<T>>>,
 	>;
 
+	/// The inclusion statistics of each para for the last [`INCLUSION_STATS_SESSIONS`] sessions.
+	#[pallet::storage]
+	pub(crate) type ParaInclusionStats<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SessionIndex, Twox64Concat, ParaId, InclusionStats>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {}
 }
 
 const LOG_TARGET: &str = "runtime::inclusion";
 
+/// The number of sessions the inclusion statistics of paras are kept for.
+pub const INCLUSION_STATS_SESSIONS: SessionIndex = 42;
+
 /// The reason that a candidate's outputs were rejected for.
 #[derive(Debug)]
 enum AcceptanceCheckErr {
@@ -455,13 +463,17 @@ impl<T: Config> Pallet<T> {
 
 	/// Handle an incoming session change.
 	pub(crate) fn initializer_on_new_session(
-		_notification: &crate::initializer::SessionChangeNotification<BlockNumberFor<T>>,
+		notification: &crate::initializer::SessionChangeNotification<BlockNumberFor<T>>,
 		outgoing_paras: &[ParaId],
 	) {
 		// unlike most drain methods, drained elements are not cleared on `Drop` of the iterator
 		// and require consumption.
 		for _ in PendingAvailability::<T>::drain() {}
 
+		if let Some(pruned) = notification.session_index.checked_sub(INCLUSION_STATS_SESSIONS) {
+			let _ = ParaInclusionStats::<T>::clear_prefix(pruned, u32::MAX, None);
+		}
+
 		Self::cleanup_outgoing_ump_dispatch_queues(outgoing_paras);
 	}
 
@@ -703,6 +715,8 @@ impl<T: Config> Pallet<T> {
 					}
 				});
 
+				Self::note_inclusion_stats(*para_id, |stats| stats.backed.saturating_inc());
+
 				// Deposit backed event.
 				Self::deposit_event(Event::<T>::CandidateBacked(
 					candidate.candidate().to_plain(),
@@ -888,6 +902,9 @@ impl<T: Config> Pallet<T> {
 			commitments.horizontal_messages,
 		);
 
+		Self::note_inclusion_stats(receipt.descriptor.para_id(), |stats| {
+			stats.included.saturating_inc()
+		});
 		Self::deposit_event(Event::<T>::CandidateIncluded(
 			plain,
 			commitments.head_data.clone(),
@@ -907,6 +924,38 @@ impl<T: Config> Pallet<T> {
 		(fp.storage.count as u32, fp.storage.size as u32)
 	}
 
+	/// Update the inclusion statistics of `para_id` in the current session.
+	fn note_inclusion_stats(para_id: ParaId, update: impl FnOnce(&mut InclusionStats)) {
+		let session_index = shared::CurrentSessionIndex::<T>::get();
+		ParaInclusionStats::<T>::mutate(session_index, para_id, |stats| {
+			update(stats.get_or_insert_with(Default::default))
+		});
+	}
+
+	/// Returns the inclusion statistics of `para_id` for each of the last
+	/// [`INCLUSION_STATS_SESSIONS`] sessions in which it had candidates, oldest first.
+	pub(crate) fn para_inclusion_stats(para_id: ParaId) -> Vec<(SessionIndex, InclusionStats)> {
+		let current_session = shared::CurrentSessionIndex::<T>::get();
+		(current_session.saturating_sub(INCLUSION_STATS_SESSIONS - 1)..=current_session)
+			.filter_map(|session_index| {
+				ParaInclusionStats::<T>::get(session_index, para_id)
+					.map(|stats| (session_index, stats))
+			})
+			.collect()
+	}
+
+	/// Returns the effective limits for upward messages sent by `para_id`.
+	pub(crate) fn upward_message_limits(para_id: ParaId) -> MessagingLimits {
+		let config = configuration::ActiveConfig::<T>::get();
+		let (count, size) = Self::relay_dispatch_queue_size(para_id);
+		MessagingLimits {
+			max_message_size: config.max_upward_message_size,
+			remaining_count: config.max_upward_queue_count.saturating_sub(count),
+			remaining_size: config.max_upward_queue_size.saturating_sub(size),
+			suspended: paras::Pallet::<T>::is_offboarding(para_id),
+		}
+	}
+
 	/// Check that all the upward messages sent by a candidate pass the acceptance criteria.
 	pub(crate) fn check_upward_messages(
 		config: &HostConfiguration<BlockNumberFor<T>>,
@@ -1012,6 +1061,9 @@ impl<T: Config> Pallet<T> {
 		let mut timed_out_cores = Vec::with_capacity(timed_out.len());
 		for candidate in timed_out.iter() {
 			timed_out_cores.push(candidate.core);
+			Self::note_inclusion_stats(candidate.descriptor.para_id(), |stats| {
+				stats.timed_out.saturating_inc()
+			});
 
 			let receipt = CandidateReceipt {
 				descriptor: candidate.descriptor.clone(),
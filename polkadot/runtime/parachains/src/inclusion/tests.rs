@@ -529,6 +529,12 @@ fn free_timedout() {
 				.collect::<Vec<_>>(),
 			vec![CoreIndex(9), CoreIndex(10), CoreIndex(11)]
 		);
+
+		// All the test candidates are of the default para.
+		assert_eq!(
+			ParaInclusion::para_inclusion_stats(ParaId::from(0)),
+			vec![(0, InclusionStats { timed_out: 7, ..Default::default() })]
+		);
 	});
 }
 
@@ -2868,3 +2874,36 @@ fn para_upgrade_delay_scheduled_from_inclusion() {
 		);
 	});
 }
+
+#[test]
+fn para_inclusion_stats_are_pruned() {
+	let chain_a = ParaId::from(1_u32);
+	let paras = vec![(chain_a, ParaKind::Parachain)];
+
+	new_test_ext(genesis_config(paras)).execute_with(|| {
+		assert!(ParaInclusion::para_inclusion_stats(chain_a).is_empty());
+
+		ParaInclusion::note_inclusion_stats(chain_a, |stats| stats.backed.saturating_inc());
+		run_to_block_default_notifications(2, vec![2]);
+		ParaInclusion::note_inclusion_stats(chain_a, |stats| stats.included.saturating_inc());
+
+		assert_eq!(
+			ParaInclusion::para_inclusion_stats(chain_a),
+			vec![
+				(0, InclusionStats { backed: 1, ..Default::default() }),
+				(1, InclusionStats { included: 1, ..Default::default() }),
+			]
+		);
+
+		// The stats of session 0 are pruned once `INCLUSION_STATS_SESSIONS` sessions started
+		// after it.
+		let sessions = INCLUSION_STATS_SESSIONS as BlockNumber;
+		run_to_block_default_notifications(sessions + 1, (3..=sessions + 1).collect());
+		assert_eq!(shared::CurrentSessionIndex::<Test>::get(), INCLUSION_STATS_SESSIONS);
+		assert_eq!(
+			ParaInclusion::para_inclusion_stats(chain_a),
+			vec![(1, InclusionStats { included: 1, ..Default::default() })]
+		);
+		assert_eq!(ParaInclusionStats::<Test>::iter().count(), 1);
+	});
+}
@@ -91,6 +91,56 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.into()), BalanceOf::<T>::max_value(), para_id)
 	}
 
+	#[benchmark]
+	fn place_spot_order(s: Linear<1, MAX_FILL_BENCH>) {
+		// Setup
+		let caller: T::AccountId = whitelisted_caller();
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		Pallet::<T>::populate_queue(para_id, s);
+		// Make sure the order can not be filled right away and has to be held in escrow.
+		QueueStatus::<T>::mutate(|queue_status| queue_status.traffic = FixedU128::max_value());
+		let max_price = T::Currency::minimum_balance().saturating_mul(10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), max_price, para_id, 10u32.into());
+
+		assert_eq!(SpotOrderQueue::<T>::get().len(), 1);
+	}
+
+	#[benchmark]
+	fn process_spot_orders(n: Linear<0, MAX_SPOT_ORDERS_PER_BLOCK>) {
+		// Setup
+		let para_id = ParaId::from(111u32);
+		init_parathread::<T>(para_id);
+		let config = configuration::ActiveConfig::<T>::get();
+		let max_price = Pallet::<T>::spot_price(&config, FixedU128::from_u32(1_000));
+		for i in 0..n {
+			SpotOrders::<T>::insert(
+				i,
+				SpotOrder {
+					para_id,
+					ordered_by: account("orderer", i, 0),
+					max_price,
+					expiry: 10u32.into(),
+				},
+			);
+		}
+		SpotOrderQueue::<T>::put(SpotOrderQueueIndices { head: 0, tail: n });
+		// Every order gets filled, which is the most expensive outcome.
+		let escrow = Pallet::<T>::escrow_account_id();
+		frame_system::Pallet::<T>::inc_providers(&escrow);
+		T::Currency::make_free_balance_be(&escrow, BalanceOf::<T>::max_value());
+
+		#[block]
+		{
+			Pallet::<T>::process_spot_orders(1u32.into(), &config);
+		}
+
+		assert!(SpotOrderQueue::<T>::get().is_empty());
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(
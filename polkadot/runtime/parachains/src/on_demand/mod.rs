@@ -25,6 +25,11 @@
 //! a specific `ParaId`, orders for blockspace for that `ParaId` will only be assigned to
 //! that `CoreIndex`.
 //!
+//! Orders can also be placed as spot orders with a maximum price and an expiry block. A spot
+//! order which can not be filled right away holds its maximum price in an escrow account and is
+//! filled in a later block as soon as the spot price drops far enough. Once it expires unfilled,
+//! the held amount is refunded automatically.
+//!
 //! NOTE: Once we have elastic scaling implemented we might want to extend this module to support
 //! ignoring core affinity up to a certain extend. This should be opt-in though as the parachain
 //! needs to support multiple cores in the same block. If we want to enable a single parachain
@@ -62,17 +67,22 @@ use sp_runtime::{
 	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, Saturating,
 };
 use types::{
-	BalanceOf, CoreAffinityCount, EnqueuedOrder, QueuePushDirection, QueueStatusType,
-	SpotTrafficCalculationErr,
+	BalanceOf, CoreAffinityCount, EnqueuedOrder, QueuePushDirection, QueueStatusType, SpotOrder,
+	SpotOrderOf, SpotOrderQueueIndices, SpotTrafficCalculationErr,
 };
 
 const LOG_TARGET: &str = "runtime::parachains::on-demand";
 
+/// The maximum number of waiting spot orders considered in a block.
+pub const MAX_SPOT_ORDERS_PER_BLOCK: u32 = 64;
+
 pub use pallet::*;
 
 pub trait WeightInfo {
 	fn place_order_allow_death(s: u32) -> Weight;
 	fn place_order_keep_alive(s: u32) -> Weight;
+	fn place_spot_order(s: u32) -> Weight;
+	fn process_spot_orders(n: u32) -> Weight;
 }
 
 /// A weight info that is only suitable for testing.
@@ -86,6 +96,14 @@ impl WeightInfo for TestWeightInfo {
 	fn place_order_keep_alive(_: u32) -> Weight {
 		Weight::MAX
 	}
+
+	fn place_spot_order(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn process_spot_orders(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 #[frame_support::pallet]
@@ -169,6 +187,16 @@ pub mod pallet {
 	pub type Revenue<T: Config> =
 		StorageValue<_, BoundedVec<BalanceOf<T>, T::MaxHistoricalRevenue>, ValueQuery>;
 
+	/// Spot orders which could not be filled at their maximum price yet, by their index in the
+	/// spot order queue.
+	#[pallet::storage]
+	pub(super) type SpotOrders<T: Config> =
+		StorageMap<_, Twox64Concat, u32, SpotOrderOf<T>, OptionQuery>;
+
+	/// The indices of the oldest and the next spot order in [`SpotOrders`].
+	#[pallet::storage]
+	pub(super) type SpotOrderQueue<T: Config> = StorageValue<_, SpotOrderQueueIndices, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -176,6 +204,17 @@ pub mod pallet {
 		OnDemandOrderPlaced { para_id: ParaId, spot_price: BalanceOf<T>, ordered_by: T::AccountId },
 		/// The value of the spot price has likely changed
 		SpotPriceSet { spot_price: BalanceOf<T> },
+		/// A spot order could not be filled right away and waits for the spot price to drop.
+		SpotOrderPlaced {
+			para_id: ParaId,
+			max_price: BalanceOf<T>,
+			expiry: BlockNumberFor<T>,
+			ordered_by: T::AccountId,
+		},
+		/// A spot order was filled at `fill_price`.
+		SpotOrderFilled { para_id: ParaId, fill_price: BalanceOf<T>, ordered_by: T::AccountId },
+		/// A spot order expired without being filled and `refund` was returned to the orderer.
+		SpotOrderRefunded { para_id: ParaId, refund: BalanceOf<T>, ordered_by: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -185,11 +224,15 @@ pub mod pallet {
 		/// The current spot price is higher than the max amount specified in the `place_order`
 		/// call, making it invalid.
 		SpotPriceHigherThanMaxAmount,
+		/// The expiry of the spot order is in the past.
+		SpotOrderExpired,
+		/// There are as many spot orders waiting as the order queue can hold.
+		TooManySpotOrders,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
 			// Update revenue information storage.
 			Revenue::<T>::mutate(|revenue| {
 				if let Some(overdue) =
@@ -210,9 +253,13 @@ pub mod pallet {
 				Self::update_spot_traffic(&config, queue_status);
 			});
 
+			let processed = Self::process_spot_orders(now, &config);
+
 			// Reads: `Revenue`, `ActiveConfig`, `QueueStatus`
 			// Writes: `Revenue`, `QueueStatus`
-			T::DbWeight::get().reads_writes(3, 2)
+			T::DbWeight::get()
+				.reads_writes(3, 2)
+				.saturating_add(<T as Config>::WeightInfo::process_spot_orders(processed))
 		}
 	}
 
@@ -241,7 +288,7 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, AllowDeath)
+			Pallet::<T>::do_place_order(sender, max_amount, para_id, AllowDeath).map(|_| ())
 		}
 
 		/// Same as the [`place_order_allow_death`](Self::place_order_allow_death) call , but with a
@@ -267,7 +314,41 @@ pub mod pallet {
 			para_id: ParaId,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			Pallet::<T>::do_place_order(sender, max_amount, para_id, KeepAlive)
+			Pallet::<T>::do_place_order(sender, max_amount, para_id, KeepAlive).map(|_| ())
+		}
+
+		/// Create a spot order which is filled at the spot price as long as that does not exceed
+		/// `max_price`.
+		///
+		/// If the order can not be filled right away, `max_price` is moved to an escrow account
+		/// and the order is filled in the first block up to and including `expiry` in which the
+		/// spot price has dropped far enough, refunding the difference. Otherwise it is refunded
+		/// in full once `expiry` has passed. Placing the order will not reap the account.
+		///
+		/// Parameters:
+		/// - `origin`: The sender of the call, funds will be withdrawn from this account.
+		/// - `max_price`: The maximum spot price to fill the order at.
+		/// - `para_id`: A `ParaId` the origin wants to provide blockspace for.
+		/// - `expiry`: The last block in which the order may be filled.
+		///
+		/// Errors:
+		/// - `InsufficientBalance`: from the Currency implementation
+		/// - `SpotOrderExpired`
+		/// - `TooManySpotOrders`
+		///
+		/// Events:
+		/// - `OnDemandOrderPlaced` and `SpotOrderFilled` if filled right away
+		/// - `SpotOrderPlaced` otherwise
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_spot_order(QueueStatus::<T>::get().size()))]
+		pub fn place_spot_order(
+			origin: OriginFor<T>,
+			max_price: BalanceOf<T>,
+			para_id: ParaId,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Pallet::<T>::do_place_spot_order(sender, max_price, para_id, expiry)
 		}
 	}
 }
@@ -353,6 +434,8 @@ where
 	/// with a keep alive check or to allow the account to be reaped. The amount charged is
 	/// stored to the pallet account to be later paid out as revenue.
 	///
+	/// Returns the spot price the order was placed at.
+	///
 	/// Parameters:
 	/// - `sender`: The sender of the call, funds will be withdrawn from this account.
 	/// - `max_amount`: The maximum balance to withdraw from the origin to place an order.
@@ -371,7 +454,7 @@ where
 		max_amount: BalanceOf<T>,
 		para_id: ParaId,
 		existence_requirement: ExistenceRequirement,
-	) -> DispatchResult {
+	) -> Result<BalanceOf<T>, DispatchError> {
 		let config = configuration::ActiveConfig::<T>::get();
 
 		QueueStatus::<T>::mutate(|queue_status| {
@@ -379,9 +462,7 @@ where
 			let traffic = queue_status.traffic;
 
 			// Calculate spot price
-			let spot_price: BalanceOf<T> = traffic.saturating_mul_int(
-				config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
-			);
+			let spot_price = Self::spot_price(&config, traffic);
 
 			// Is the current price higher than `max_amount`
 			ensure!(spot_price.le(&max_amount), Error::<T>::SpotPriceHigherThanMaxAmount);
@@ -399,26 +480,7 @@ where
 				WithdrawReasons::FEE,
 				existence_requirement,
 			)?;
-
-			// Consume the negative imbalance and deposit it into the pallet account. Make sure the
-			// account preserves even without the existential deposit.
-			let pot = Self::account_id();
-			if !System::<T>::account_exists(&pot) {
-				System::<T>::inc_providers(&pot);
-			}
-			T::Currency::resolve_creating(&pot, amt);
-
-			// Add the amount to the current block's (index 0) revenue information.
-			Revenue::<T>::mutate(|bounded_revenue| {
-				if let Some(current_block) = bounded_revenue.get_mut(0) {
-					*current_block = current_block.saturating_add(spot_price);
-				} else {
-					// Revenue has already been claimed in the same block, including the block
-					// itself. It shouldn't normally happen as revenue claims in the future are
-					// not allowed.
-					bounded_revenue.try_push(spot_price).defensive_ok();
-				}
-			});
+			Self::deposit_revenue(amt, spot_price);
 
 			Pallet::<T>::add_on_demand_order(queue_status, para_id, QueuePushDirection::Back);
 			Pallet::<T>::deposit_event(Event::<T>::OnDemandOrderPlaced {
@@ -427,10 +489,207 @@ where
 				ordered_by: sender,
 			});
 
-			Ok(())
+			Ok(spot_price)
 		})
 	}
 
+	/// Consume the negative imbalance of a filled order and deposit it into the pallet account,
+	/// adding `spot_price` to the current block's revenue information.
+	fn deposit_revenue(
+		amt: <T::Currency as Currency<T::AccountId>>::NegativeImbalance,
+		spot_price: BalanceOf<T>,
+	) {
+		// Make sure the account preserves even without the existential deposit.
+		let pot = Self::account_id();
+		if !System::<T>::account_exists(&pot) {
+			System::<T>::inc_providers(&pot);
+		}
+		T::Currency::resolve_creating(&pot, amt);
+
+		// Add the amount to the current block's (index 0) revenue information.
+		Revenue::<T>::mutate(|bounded_revenue| {
+			if let Some(current_block) = bounded_revenue.get_mut(0) {
+				*current_block = current_block.saturating_add(spot_price);
+			} else {
+				// Revenue has already been claimed in the same block, including the block
+				// itself. It shouldn't normally happen as revenue claims in the future are
+				// not allowed.
+				bounded_revenue.try_push(spot_price).defensive_ok();
+			}
+		});
+	}
+
+	/// Helper function for the `place_spot_order` call.
+	///
+	/// Fills the order right away if the spot price allows it, otherwise moves `max_price` to the
+	/// escrow account and adds the order to the spot order queue until it is filled or expires.
+	///
+	/// Errors:
+	/// - `InsufficientBalance`: from the Currency implementation
+	/// - `SpotOrderExpired`
+	/// - `TooManySpotOrders`
+	///
+	/// Events:
+	/// - `OnDemandOrderPlaced` and `SpotOrderFilled` if filled right away
+	/// - `SpotOrderPlaced` otherwise
+	fn do_place_spot_order(
+		sender: <T as frame_system::Config>::AccountId,
+		max_price: BalanceOf<T>,
+		para_id: ParaId,
+		expiry: BlockNumberFor<T>,
+	) -> DispatchResult {
+		ensure!(expiry >= System::<T>::block_number(), Error::<T>::SpotOrderExpired);
+
+		match Self::do_place_order(sender.clone(), max_price, para_id, KeepAlive) {
+			Ok(fill_price) => {
+				Pallet::<T>::deposit_event(Event::<T>::SpotOrderFilled {
+					para_id,
+					fill_price,
+					ordered_by: sender,
+				});
+				return Ok(())
+			},
+			Err(e)
+				if e == Error::<T>::SpotPriceHigherThanMaxAmount.into() ||
+					e == Error::<T>::QueueFull.into() => {},
+			Err(e) => return Err(e),
+		}
+
+		let config = configuration::ActiveConfig::<T>::get();
+		let mut indices = SpotOrderQueue::<T>::get();
+		ensure!(
+			indices.len() < config.scheduler_params.on_demand_queue_max_size,
+			Error::<T>::TooManySpotOrders
+		);
+
+		let escrow = Self::escrow_account_id();
+		if !System::<T>::account_exists(&escrow) {
+			System::<T>::inc_providers(&escrow);
+		}
+		T::Currency::transfer(&sender, &escrow, max_price, KeepAlive)?;
+
+		SpotOrders::<T>::insert(
+			indices.tail,
+			SpotOrder { para_id, ordered_by: sender.clone(), max_price, expiry },
+		);
+		indices.tail = indices.tail.wrapping_add(1);
+		SpotOrderQueue::<T>::put(indices);
+		Pallet::<T>::deposit_event(Event::<T>::SpotOrderPlaced {
+			para_id,
+			max_price,
+			expiry,
+			ordered_by: sender,
+		});
+		Ok(())
+	}
+
+	/// Fill the oldest waiting spot orders the current spot price allows and refund the expired
+	/// ones, considering at most [`MAX_SPOT_ORDERS_PER_BLOCK`] orders.
+	///
+	/// The orders which keep waiting are moved to the back of the queue, so that every order is
+	/// considered again after all the others. Returns the number of orders considered.
+	fn process_spot_orders(
+		now: BlockNumberFor<T>,
+		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
+	) -> u32 {
+		let mut indices = SpotOrderQueue::<T>::get();
+		let count = indices.len().min(MAX_SPOT_ORDERS_PER_BLOCK);
+		if count == 0 {
+			return 0
+		}
+		let escrow = Self::escrow_account_id();
+
+		QueueStatus::<T>::mutate(|queue_status| {
+			for _ in 0..count {
+				let index = indices.head;
+				indices.head = indices.head.wrapping_add(1);
+				let Some(order) = SpotOrders::<T>::take(index).defensive() else { continue };
+				if Self::process_spot_order(now, config, queue_status, &escrow, &order) {
+					SpotOrders::<T>::insert(indices.tail, order);
+					indices.tail = indices.tail.wrapping_add(1);
+				}
+			}
+		});
+		SpotOrderQueue::<T>::put(indices);
+
+		count
+	}
+
+	/// Fill `order` if the spot price allows it or refund it if it expired.
+	///
+	/// Returns whether the order keeps waiting.
+	fn process_spot_order(
+		now: BlockNumberFor<T>,
+		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
+		queue_status: &mut QueueStatusType,
+		escrow: &T::AccountId,
+		order: &SpotOrderOf<T>,
+	) -> bool {
+		if order.expiry < now {
+			Self::refund_spot_order(escrow, order, order.max_price);
+			Pallet::<T>::deposit_event(Event::<T>::SpotOrderRefunded {
+				para_id: order.para_id,
+				refund: order.max_price,
+				ordered_by: order.ordered_by.clone(),
+			});
+			return false
+		}
+
+		let spot_price = Self::spot_price(config, queue_status.traffic);
+		if spot_price > order.max_price ||
+			queue_status.size() >= config.scheduler_params.on_demand_queue_max_size
+		{
+			return true
+		}
+
+		let Ok(amt) =
+			T::Currency::withdraw(escrow, spot_price, WithdrawReasons::FEE, AllowDeath).defensive()
+		else {
+			return true
+		};
+		Self::deposit_revenue(amt, spot_price);
+		let change = order.max_price.saturating_sub(spot_price);
+		Self::refund_spot_order(escrow, order, change);
+
+		Pallet::<T>::add_on_demand_order(queue_status, order.para_id, QueuePushDirection::Back);
+		Self::update_spot_traffic(config, queue_status);
+		Pallet::<T>::deposit_event(Event::<T>::OnDemandOrderPlaced {
+			para_id: order.para_id,
+			spot_price,
+			ordered_by: order.ordered_by.clone(),
+		});
+		Pallet::<T>::deposit_event(Event::<T>::SpotOrderFilled {
+			para_id: order.para_id,
+			fill_price: spot_price,
+			ordered_by: order.ordered_by.clone(),
+		});
+		false
+	}
+
+	/// Return `amount` held in escrow for `order` to the account which placed it.
+	fn refund_spot_order(escrow: &T::AccountId, order: &SpotOrderOf<T>, amount: BalanceOf<T>) {
+		if amount.is_zero() {
+			return
+		}
+		if let Err(err) = T::Currency::transfer(escrow, &order.ordered_by, amount, AllowDeath) {
+			log::debug!(
+				target: LOG_TARGET,
+				"Failed to refund spot order of {:?} for para {:?}: {:?}",
+				order.ordered_by, order.para_id, err
+			);
+		}
+	}
+
+	/// The spot price of an order at the given traffic.
+	fn spot_price(
+		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
+		traffic: FixedU128,
+	) -> BalanceOf<T> {
+		traffic.saturating_mul_int(
+			config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
+		)
+	}
+
 	/// Calculate and update spot traffic.
 	fn update_spot_traffic(
 		config: &configuration::HostConfiguration<BlockNumberFor<T>>,
@@ -450,9 +709,7 @@ where
 					queue_status.traffic = new_traffic;
 
 					// calculate the new spot price
-					let spot_price: BalanceOf<T> = new_traffic.saturating_mul_int(
-						config.scheduler_params.on_demand_base_fee.saturated_into::<BalanceOf<T>>(),
-					);
+					let spot_price = Self::spot_price(config, new_traffic);
 
 					// emit the event for updated new price
 					Pallet::<T>::deposit_event(Event::<T>::SpotPriceSet { spot_price });
@@ -659,6 +916,11 @@ where
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// Account holding the maximum price of waiting spot orders until they are filled or expire.
+	pub fn escrow_account_id() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"spot")
+	}
+
 	/// Getter for the affinity tracker.
 	#[cfg(test)]
 	fn get_affinity_map(para_id: ParaId) -> Option<CoreAffinityCount> {
@@ -25,7 +25,7 @@ use crate::{
 	on_demand::{
 		self,
 		mock_helpers::GenesisConfigBuilder,
-		types::{QueueIndex, ReverseQueueIndex},
+		types::{QueueIndex, ReverseQueueIndex, SpotOrderQueueIndices},
 		Error,
 	},
 	paras::{ParaGenesisArgs, ParaKind},
@@ -811,3 +811,208 @@ fn pot_account_is_immortal() {
 		assert_eq!(System::providers(&pot), 1);
 	});
 }
+
+fn spot_order_events() -> Vec<on_demand::Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			crate::mock::RuntimeEvent::OnDemand(event) => Some(event),
+			_ => None,
+		})
+		.filter(|event| {
+			matches!(
+				event,
+				on_demand::Event::SpotOrderPlaced { .. } |
+					on_demand::Event::SpotOrderFilled { .. } |
+					on_demand::Event::SpotOrderRefunded { .. }
+			)
+		})
+		.collect()
+}
+
+#[test]
+fn spot_order_is_filled_right_away_when_price_allows() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amt);
+
+		assert_ok!(OnDemand::place_spot_order(RuntimeOrigin::signed(alice), amt, para_id, 110));
+
+		assert_eq!(
+			spot_order_events(),
+			vec![on_demand::Event::SpotOrderFilled {
+				para_id,
+				fill_price: 10_000,
+				ordered_by: alice
+			}]
+		);
+		assert_eq!(Balances::free_balance(&alice), amt - 10_000);
+		assert_eq!(OnDemand::get_queue_status().size(), 1);
+		assert!(SpotOrderQueue::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn spot_order_is_filled_once_price_drops() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let max_price = 100_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amt);
+
+		// Spike the spot price well above the maximum price.
+		OnDemand::set_queue_status(QueueStatusType {
+			traffic: FixedU128::from_u32(100),
+			..Default::default()
+		});
+		assert_ok!(OnDemand::place_spot_order(
+			RuntimeOrigin::signed(alice),
+			max_price,
+			para_id,
+			110
+		));
+		assert_eq!(
+			spot_order_events(),
+			vec![on_demand::Event::SpotOrderPlaced {
+				para_id,
+				max_price,
+				expiry: 110,
+				ordered_by: alice
+			}]
+		);
+		assert_eq!(Balances::free_balance(&alice), amt - max_price);
+		assert_eq!(Balances::free_balance(&OnDemand::escrow_account_id()), max_price);
+		assert_eq!(OnDemand::get_queue_status().size(), 0);
+
+		// The traffic decays while the queue is idle and the order gets filled.
+		run_to_block(101, |_| None);
+		let fill_price = match spot_order_events().last() {
+			Some(on_demand::Event::SpotOrderFilled { para_id: p, fill_price, ordered_by }) => {
+				assert_eq!((*p, *ordered_by), (para_id, alice));
+				*fill_price
+			},
+			e => panic!("Unexpected event: {:?}", e),
+		};
+		assert!(fill_price <= max_price);
+		assert_eq!(Balances::free_balance(&alice), amt - fill_price);
+		assert_eq!(Balances::free_balance(&OnDemand::escrow_account_id()), 0);
+		assert_eq!(OnDemand::get_revenue()[0], fill_price);
+		assert_eq!(OnDemand::get_queue_status().size(), 1);
+		assert!(SpotOrderQueue::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn spot_order_is_refunded_after_expiry() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	// Lower than the base fee, so it can never be filled.
+	let max_price = 5_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amt);
+
+		// Orders can not expire in the past.
+		assert_noop!(
+			OnDemand::place_spot_order(RuntimeOrigin::signed(alice), max_price, para_id, 99),
+			Error::<Test>::SpotOrderExpired
+		);
+
+		assert_ok!(OnDemand::place_spot_order(
+			RuntimeOrigin::signed(alice),
+			max_price,
+			para_id,
+			102
+		));
+		assert_eq!(Balances::free_balance(&alice), amt - max_price);
+
+		// Still waiting in the last block it may be filled in.
+		run_to_block(102, |_| None);
+		assert_eq!(SpotOrderQueue::<Test>::get().len(), 1);
+
+		run_to_block(103, |_| None);
+		assert!(SpotOrderQueue::<Test>::get().is_empty());
+		assert_eq!(
+			spot_order_events().last(),
+			Some(&on_demand::Event::SpotOrderRefunded {
+				para_id,
+				refund: max_price,
+				ordered_by: alice
+			})
+		);
+		assert_eq!(Balances::free_balance(&alice), amt);
+		assert_eq!(Balances::free_balance(&OnDemand::escrow_account_id()), 0);
+		assert_eq!(OnDemand::get_queue_status().size(), 0);
+	});
+}
+
+#[test]
+fn spot_orders_are_processed_in_bounded_steps() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amt);
+
+		// None of the orders can be filled. The oldest one expires first.
+		assert_ok!(OnDemand::place_spot_order(RuntimeOrigin::signed(alice), 1, para_id, 100));
+		for _ in 0..MAX_SPOT_ORDERS_PER_BLOCK {
+			assert_ok!(OnDemand::place_spot_order(RuntimeOrigin::signed(alice), 1, para_id, 110));
+		}
+		let placed = MAX_SPOT_ORDERS_PER_BLOCK + 1;
+		assert_eq!(SpotOrderQueue::<Test>::get().len(), placed);
+
+		// Only the oldest orders are considered. The expired one is refunded and the others are
+		// moved to the back of the queue.
+		run_to_block(101, |_| None);
+		assert_eq!(
+			SpotOrderQueue::<Test>::get(),
+			SpotOrderQueueIndices { head: MAX_SPOT_ORDERS_PER_BLOCK, tail: 2 * placed - 2 }
+		);
+		assert!(SpotOrders::<Test>::get(0).is_none());
+		assert_eq!(
+			spot_order_events().last(),
+			Some(&on_demand::Event::SpotOrderRefunded { para_id, refund: 1, ordered_by: alice })
+		);
+		assert_eq!(Balances::free_balance(&alice), amt - u128::from(placed) + 1);
+	});
+}
+
+#[test]
+fn spot_orders_are_bounded_by_queue_size() {
+	let alice = 1u64;
+	let amt = 10_000_000u128;
+	let para_id = ParaId::from(111);
+
+	new_test_ext(
+		GenesisConfigBuilder { on_demand_max_queue_size: 2, ..Default::default() }.build(),
+	)
+	.execute_with(|| {
+		schedule_blank_para(para_id, ParaKind::Parathread);
+		run_to_block(100, |n| if n == 100 { Some(Default::default()) } else { None });
+		Balances::make_free_balance_be(&alice, amt);
+
+		for _ in 0..2 {
+			assert_ok!(OnDemand::place_spot_order(RuntimeOrigin::signed(alice), 1, para_id, 110));
+		}
+		assert_noop!(
+			OnDemand::place_spot_order(RuntimeOrigin::signed(alice), 1, para_id, 110),
+			Error::<Test>::TooManySpotOrders
+		);
+	});
+}
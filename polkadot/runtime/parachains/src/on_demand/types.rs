@@ -23,6 +23,7 @@ use frame_support::{
 	pallet_prelude::{Decode, Encode, RuntimeDebug, TypeInfo},
 	traits::Currency,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use polkadot_primitives::{CoreIndex, Id as ParaId, ON_DEMAND_MAX_QUEUE_MAX_SIZE};
 use sp_runtime::FixedU128;
 
@@ -219,6 +220,52 @@ pub struct CoreAffinityCount {
 	pub count: u32,
 }
 
+/// A spot order waiting for the spot price to drop to its maximum price.
+///
+/// The maximum price is held in the spot order escrow account until the order is either filled or
+/// expires.
+#[derive(Encode, Decode, Clone, TypeInfo)]
+#[cfg_attr(test, derive(PartialEq, RuntimeDebug))]
+pub struct SpotOrder<AccountId, Balance, BlockNumber> {
+	/// The para the order provides blockspace for.
+	pub para_id: ParaId,
+	/// The account which placed the order and receives any refund.
+	pub ordered_by: AccountId,
+	/// The highest spot price the order may be filled at.
+	pub max_price: Balance,
+	/// The last block in which the order may be filled.
+	pub expiry: BlockNumber,
+}
+
+/// Shorthand for the `SpotOrder` type of a runtime.
+pub type SpotOrderOf<T> =
+	SpotOrder<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// The indices of the spot orders in the spot order queue.
+///
+/// The queue holds the orders with the indices from `head` up to, but excluding, `tail`. The
+/// indices wrap around.
+#[derive(Encode, Decode, Default, Clone, Copy, TypeInfo)]
+#[cfg_attr(test, derive(PartialEq, RuntimeDebug))]
+pub struct SpotOrderQueueIndices {
+	/// The index of the oldest spot order.
+	pub head: u32,
+	/// The index of the next spot order.
+	pub tail: u32,
+}
+
+impl SpotOrderQueueIndices {
+	/// The number of spot orders in the queue.
+	pub fn len(&self) -> u32 {
+		self.tail.wrapping_sub(self.head)
+	}
+
+	/// Whether the queue holds no spot orders.
+	pub fn is_empty(&self) -> bool {
+		self.head == self.tail
+	}
+}
+
 /// An indicator as to which end of the `OnDemandQueue` an assignment will be placed.
 #[cfg_attr(test, derive(RuntimeDebug))]
 pub enum QueuePushDirection {
@@ -22,7 +22,7 @@
 //! for the time being, although we will build schemes to do so in the future.
 
 use crate::{session_info, shared};
-use alloc::collections::btree_set::BTreeSet;
+use alloc::{collections::btree_set::BTreeSet, vec::Vec};
 use frame_support::traits::{Defensive, ValidatorSet};
 use polkadot_primitives::{SessionIndex, ValidatorIndex};
 
@@ -90,3 +90,48 @@ where
 		Self::reward_only_active(session, validators, DISPUTE_STATEMENT_POINTS);
 	}
 }
+
+/// Notes the validators participating in parachains, by backing candidates or making them
+/// available, as alive in the liveness tracker of `pallet-session`, before passing them on to the
+/// inner hook `R`.
+pub struct NoteParticipation<C, R>(core::marker::PhantomData<(C, R)>);
+
+impl<C, R> NoteParticipation<C, R>
+where
+	C: pallet_session::liveness::Config + shared::Config + session_info::Config,
+	<C as pallet_session::liveness::Config>::ValidatorSet:
+		ValidatorSet<C::AccountId, ValidatorId = C::AccountId>,
+{
+	fn note(indices: &[ValidatorIndex]) {
+		let session_index = shared::CurrentSessionIndex::<C>::get();
+		let Some(validators) = session_info::AccountKeys::<C>::get(&session_index)
+			.defensive_proof("account_keys are present for the current session")
+		else {
+			return
+		};
+
+		for validator in indices.iter().filter_map(|i| validators.get(i.0 as usize)) {
+			pallet_session::liveness::Pallet::<C>::note_participation(validator.clone());
+		}
+	}
+}
+
+impl<C, R> crate::inclusion::RewardValidators for NoteParticipation<C, R>
+where
+	C: pallet_session::liveness::Config + shared::Config + session_info::Config,
+	<C as pallet_session::liveness::Config>::ValidatorSet:
+		ValidatorSet<C::AccountId, ValidatorId = C::AccountId>,
+	R: crate::inclusion::RewardValidators,
+{
+	fn reward_backing(indices: impl IntoIterator<Item = ValidatorIndex>) {
+		let indices = indices.into_iter().collect::<Vec<_>>();
+		Self::note(&indices);
+		R::reward_backing(indices);
+	}
+
+	fn reward_bitfields(indices: impl IntoIterator<Item = ValidatorIndex>) {
+		let indices = indices.into_iter().collect::<Vec<_>>();
+		Self::note(&indices);
+		R::reward_bitfields(indices);
+	}
+}
@@ -15,3 +15,30 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 //! Put implementations of functions from staging APIs here.
+
+use crate::{hrmp, inclusion};
+use alloc::vec::Vec;
+use polkadot_primitives::{
+	vstaging::{InclusionStats, MessagingLimits},
+	Id as ParaId, SessionIndex,
+};
+
+/// Implementation for the `upward_message_limits` function of the runtime API.
+pub fn upward_message_limits<T: inclusion::Config>(para_id: ParaId) -> MessagingLimits {
+	inclusion::Pallet::<T>::upward_message_limits(para_id)
+}
+
+/// Implementation for the `hrmp_channel_limits` function of the runtime API.
+pub fn hrmp_channel_limits<T: hrmp::Config>(
+	sender: ParaId,
+	recipient: ParaId,
+) -> Option<MessagingLimits> {
+	hrmp::Pallet::<T>::outbound_channel_limits(sender, recipient)
+}
+
+/// Implementation for the `para_inclusion_stats` function of the runtime API.
+pub fn para_inclusion_stats<T: inclusion::Config>(
+	para_id: ParaId,
+) -> Vec<(SessionIndex, InclusionStats)> {
+	inclusion::Pallet::<T>::para_inclusion_stats(para_id)
+}
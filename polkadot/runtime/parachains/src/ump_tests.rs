@@ -32,7 +32,7 @@ use frame_support::{
 	weights::Weight,
 };
 use polkadot_primitives::{
-	vstaging::{ClaimQueueOffset, CoreSelector, UMPSignal, UMP_SEPARATOR},
+	vstaging::{ClaimQueueOffset, CoreSelector, MessagingLimits, UMPSignal, UMP_SEPARATOR},
 	well_known_keys, Id as ParaId, UpwardMessage,
 };
 use sp_crypto_hashing::{blake2_256, twox_64};
@@ -416,6 +416,35 @@ fn relay_dispatch_queue_size_is_updated() {
 	});
 }
 
+#[test]
+fn upward_message_limits_works() {
+	new_test_ext(GenesisConfigBuilder::default().build()).execute_with(|| {
+		let cfg = configuration::ActiveConfig::<Test>::get();
+		let para = ParaId::from(2000);
+
+		assert_eq!(
+			ParaInclusion::upward_message_limits(para),
+			MessagingLimits {
+				max_message_size: cfg.max_upward_message_size,
+				remaining_count: cfg.max_upward_queue_count,
+				remaining_size: cfg.max_upward_queue_size,
+				suspended: false,
+			}
+		);
+
+		queue_upward_msg(para, vec![1, 2, 3]);
+		assert_eq!(
+			ParaInclusion::upward_message_limits(para),
+			MessagingLimits {
+				max_message_size: cfg.max_upward_message_size,
+				remaining_count: cfg.max_upward_queue_count - 1,
+				remaining_size: cfg.max_upward_queue_size - 3,
+				suspended: false,
+			}
+		);
+	});
+}
+
 /// Assert that the old and the new way of accessing `relay_dispatch_queue_size` is the same.
 #[test]
 fn relay_dispatch_queue_size_key_is_correct() {
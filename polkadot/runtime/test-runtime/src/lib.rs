@@ -396,6 +396,11 @@ impl pallet_staking::Config for Runtime {
 	type EventListeners = ();
 	type WeightInfo = ();
 	type DisablingStrategy = pallet_staking::UpToLimitWithReEnablingDisablingStrategy;
+	type RemotePayout = ();
+	type EraPointsReward = ();
+	type ExposureKeeper = ();
+	type MaxPendingCandidates = ConstU32<64>;
+	type BondActivationDelay = ConstU32<0>;
 }
 
 parameter_types! {
@@ -504,6 +509,7 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = frame_system::EnsureRoot<AccountId>;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -24,7 +24,7 @@ use crate::{
 use alloc::format;
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
-use pallet_staking::{Forcing, StakerStatus};
+use pallet_staking::{genesis::GenesisStaker, Forcing, StakerStatus};
 use polkadot_primitives::{AccountId, AssignmentId, SchedulerParams, ValidatorId};
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
 use sp_consensus_babe::AuthorityId as BabeId;
@@ -36,6 +36,12 @@ use sp_keyring::Sr25519Keyring;
 use sp_runtime::Perbill;
 use westend_runtime_constants::currency::UNITS as WND;
 
+/// Preset with a hundred generated validators and a thousand generated nominators.
+const STAKING_DEV_PRESET: &str = "staking_dev";
+
+/// Preset with as many generated validators and voters as the staking election can handle.
+const STAKING_BENCHMARK_PRESET: &str = "staking_benchmark";
+
 /// Helper function to generate stash, controller and session key from seed
 fn get_authority_keys_from_seed(
 	seed: &str,
@@ -166,16 +172,25 @@ fn westend_testnet_genesis(
 	)>,
 	root_key: AccountId,
 	endowed_accounts: Option<Vec<AccountId>>,
+	extra_stakers: Vec<GenesisStaker<AccountId, u128>>,
 ) -> serde_json::Value {
 	let endowed_accounts: Vec<AccountId> = endowed_accounts.unwrap_or_else(testnet_accounts);
 
 	const ENDOWMENT: u128 = 1_000_000 * WND;
 	const STASH: u128 = 100 * WND;
+	const EXTRA_STAKER_FREE_BALANCE: u128 = 10 * WND;
+
+	// the authorities out-stake all extra stakers together, so that they keep being elected.
+	let extra_stake: u128 = extra_stakers.iter().map(|x| x.2).sum();
+	let stash = STASH + extra_stake;
+	let balances = endowed_accounts
+		.iter()
+		.map(|k| (k.clone(), ENDOWMENT + extra_stake))
+		.chain(extra_stakers.iter().map(|x| (x.0.clone(), x.2 + EXTRA_STAKER_FREE_BALANCE)))
+		.collect::<Vec<_>>();
 
 	build_struct_json_patch!(RuntimeGenesisConfig {
-		balances: BalancesConfig {
-			balances: endowed_accounts.iter().map(|k| (k.clone(), ENDOWMENT)).collect::<Vec<_>>(),
-		},
+		balances: BalancesConfig { balances },
 		session: SessionConfig {
 			keys: initial_authorities
 				.iter()
@@ -200,7 +215,8 @@ fn westend_testnet_genesis(
 			validator_count: initial_authorities.len() as u32,
 			stakers: initial_authorities
 				.iter()
-				.map(|x| (x.0.clone(), x.0.clone(), STASH, StakerStatus::<AccountId>::Validator))
+				.map(|x| (x.0.clone(), x.0.clone(), stash, StakerStatus::<AccountId>::Validator))
+				.chain(extra_stakers)
 				.collect::<Vec<_>>(),
 			invulnerables: initial_authorities.iter().map(|x| x.0.clone()).collect::<Vec<_>>(),
 			force_era: Forcing::NotForcing,
@@ -390,6 +406,7 @@ fn westend_development_config_genesis() -> serde_json::Value {
 		Vec::from([get_authority_keys_from_seed("Alice")]),
 		Sr25519Keyring::Alice.to_account_id(),
 		None,
+		Vec::new(),
 	)
 }
 
@@ -399,6 +416,40 @@ fn westend_local_testnet_genesis() -> serde_json::Value {
 		Vec::from([get_authority_keys_from_seed("Alice"), get_authority_keys_from_seed("Bob")]),
 		Sr25519Keyring::Alice.to_account_id(),
 		None,
+		Vec::new(),
+	)
+}
+
+/// Local testnet with `validators` generated validators and `nominators` generated nominators
+/// next to the Alice and Bob authorities.
+///
+/// The generated stakers are derived from the `//staker//validator{i}` and
+/// `//staker//nominator{i}` seeds and are all part of the voter and target lists at genesis.
+fn westend_staking_testnet_genesis(validators: u32, nominators: u32) -> serde_json::Value {
+	let accounts = |role: &str, count: u32| {
+		(0..count)
+			.map(|i| {
+				get_public_from_string_or_panic::<sr25519::Public>(&format!(
+					"//staker//{}{}",
+					role, i
+				))
+				.into()
+			})
+			.collect::<Vec<AccountId>>()
+	};
+	let extra_stakers = pallet_staking::genesis::dev_stakers(
+		&accounts("validator", validators),
+		&accounts("nominator", nominators),
+		crate::MaxNominations::get(),
+		WND,
+		10_000 * WND,
+	);
+
+	westend_testnet_genesis(
+		Vec::from([get_authority_keys_from_seed("Alice"), get_authority_keys_from_seed("Bob")]),
+		Sr25519Keyring::Alice.to_account_id(),
+		None,
+		extra_stakers,
 	)
 }
 
@@ -408,6 +459,11 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 		sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => westend_local_testnet_genesis(),
 		sp_genesis_builder::DEV_RUNTIME_PRESET => westend_development_config_genesis(),
 		"staging_testnet" => westend_staging_testnet_config_genesis(),
+		STAKING_DEV_PRESET => westend_staking_testnet_genesis(100, 1_000),
+		STAKING_BENCHMARK_PRESET => westend_staking_testnet_genesis(
+			crate::MaxActiveValidators::get() - 2,
+			crate::MaxElectingVoters::get() - crate::MaxActiveValidators::get(),
+		),
 		_ => return None,
 	};
 	Some(
@@ -423,5 +479,7 @@ pub fn preset_names() -> Vec<PresetId> {
 		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
 		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
 		PresetId::from("staging_testnet"),
+		PresetId::from(STAKING_DEV_PRESET),
+		PresetId::from(STAKING_BENCHMARK_PRESET),
 	]
 }
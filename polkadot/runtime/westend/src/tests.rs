@@ -111,7 +111,7 @@ mod remote_tests {
 	use super::*;
 	use frame_try_runtime::{runtime_decl_for_try_runtime::TryRuntime, UpgradeCheckSelect};
 	use remote_externalities::{
-		Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig, Transport,
+		Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig, StorageDiff, Transport,
 	};
 	use std::env::var;
 
@@ -141,7 +141,13 @@ mod remote_tests {
 			.build()
 			.await
 			.unwrap();
-		ext.execute_with(|| Runtime::on_runtime_upgrade(UpgradeCheckSelect::PreAndPost));
+		let (_, diff) = StorageDiff::from_execution(&mut ext, || {
+			Runtime::on_runtime_upgrade(UpgradeCheckSelect::PreAndPost)
+		});
+		log::info!(
+			"Storage changed by the migrations:\n{}",
+			diff.with_metadata(&Runtime::metadata())
+		);
 	}
 
 	#[tokio::test]
@@ -208,7 +214,7 @@ mod remote_tests {
 					k.clone(),
 				) {
 					// reasons migrations can fail:
-					let is_direct_staker = pallet_staking::Bonded::<Runtime>::contains_key(&k);
+					let is_direct_staker = pallet_staking::Pallet::<Runtime>::bonded(&k).is_some();
 
 					let migration = pallet_nomination_pools::Pallet::<Runtime>::migrate_delegation(
 						RuntimeOrigin::signed(alice.clone()).into(),
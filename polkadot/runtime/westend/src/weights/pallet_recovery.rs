@@ -183,4 +183,53 @@ impl<T: frame_system::Config> pallet_recovery::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Recovery Recoverable (r:1 w:0)
+	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery Frozen (r:1 w:0)
+	/// Proof: Recovery Frozen (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Recovery ActiveRecoveries (r:1 w:0)
+	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
+	/// Storage: Recovery GuardianPolicies (r:0 w:1)
+	/// Proof: Recovery GuardianPolicies (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	fn set_guardian_policy() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(17_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Recovery Recoverable (r:1 w:0)
+	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery ActiveRecoveries (r:1 w:0)
+	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
+	/// Storage: Recovery GuardianPolicies (r:1 w:0)
+	/// Proof: Recovery GuardianPolicies (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: Recovery Frozen (r:1 w:1)
+	/// Proof: Recovery Frozen (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 9]`.
+	fn guardian_freeze(n: u32, ) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(27_100_000, 0)
+			.saturating_add(Weight::from_parts(0, 3854))
+			.saturating_add(Weight::from_parts(160_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Recovery Recoverable (r:1 w:0)
+	/// Proof: Recovery Recoverable (max_values: None, max_size: Some(351), added: 2826, mode: MaxEncodedLen)
+	/// Storage: Recovery ActiveRecoveries (r:1 w:0)
+	/// Proof: Recovery ActiveRecoveries (max_values: None, max_size: Some(389), added: 2864, mode: MaxEncodedLen)
+	/// Storage: Recovery GuardianPolicies (r:1 w:1)
+	/// Proof: Recovery GuardianPolicies (max_values: None, max_size: Some(110), added: 2585, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[1, 9]`.
+	fn guardian_transfer(n: u32, ) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(62_800_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(Weight::from_parts(165_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -826,4 +826,31 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Staking::ExposureStorageMode` (r:0 w:1)
+	/// Proof: `Staking::ExposureStorageMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_exposure_storage` benchmark is run.
+	fn set_exposure_storage() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Staking::UnbondingQueueParams` (r:0 w:1)
+	/// Proof: `Staking::UnbondingQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_unbonding_queue_params` benchmark is run.
+	fn set_unbonding_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Staking::CandidacyQueueParams` (r:0 w:1)
+	/// Proof: `Staking::CandidacyQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_candidacy_queue_params` benchmark is run.
+	fn set_candidacy_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
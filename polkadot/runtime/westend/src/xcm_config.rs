@@ -17,11 +17,12 @@
 //! XCM configurations for Westend.
 
 use super::{
-	parachains_origin, AccountId, AllPalletsWithSystem, Balances, Dmp, FellowshipAdmin,
+	parachains_origin, AccountId, AllPalletsWithSystem, Balance, Balances, Dmp, FellowshipAdmin,
 	GeneralAdmin, ParaId, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, StakingAdmin,
 	TransactionByteFee, Treasury, WeightToFee, XcmPallet,
 };
 use crate::governance::pallet_custom_origins::Treasurer;
+use alloc::{boxed::Box, vec};
 use frame_support::{
 	parameter_types,
 	traits::{Contains, Equals, Everything, Nothing},
@@ -33,6 +34,7 @@ use polkadot_runtime_common::{
 	ToAuthor,
 };
 use sp_core::ConstU32;
+use sp_runtime::DispatchResult;
 use westend_runtime_constants::{
 	currency::CENTS, system_parachain::*, xcm::body::FELLOWSHIP_ADMIN_INDEX,
 };
@@ -305,6 +307,33 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = ConstU32<8>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = ConstU32<1200>;
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
+
+/// Transfers the staking rewards of stashes with a remote reward destination to their account on
+/// Asset Hub, teleporting them like any other WND sent to Asset Hub.
+///
+/// The transfer is paid for on Asset Hub with the reward itself.
+pub struct StakingRewardsToAssetHub;
+impl pallet_staking::RemotePayout<AccountId, Balance> for StakingRewardsToAssetHub {
+	fn pay(stash: &AccountId, beneficiary: &AccountId, amount: Balance) -> DispatchResult {
+		let beneficiary: Location =
+			AccountId32 { network: None, id: beneficiary.clone().into() }.into();
+		let assets: Assets = vec![(Here, amount).into()].into();
+		XcmPallet::transfer_assets(
+			RuntimeOrigin::signed(stash.clone()),
+			Box::new(AssetHub::get().into()),
+			Box::new(beneficiary.into()),
+			Box::new(assets.into()),
+			0,
+			Unlimited,
+		)
+	}
+
+	fn pay_weight() -> Weight {
+		use pallet_xcm::WeightInfo as _;
+		crate::weights::pallet_xcm::WeightInfo::<Runtime>::transfer_assets()
+	}
+}
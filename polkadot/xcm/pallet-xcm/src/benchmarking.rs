@@ -388,6 +388,15 @@ mod benchmarks {
 		_(RawOrigin::Root, true)
 	}
 
+	#[benchmark]
+	fn set_destination_fee_hint() {
+		let dest = VersionedLocation::from(Location::parent());
+		let asset_id = VersionedAssetId::from(AssetId(Location::parent()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Box::new(dest), Box::new(asset_id), Some(1_000_000));
+	}
+
 	#[benchmark]
 	fn migrate_supported_version() {
 		let old_version = XCM_VERSION - 1;
@@ -62,14 +62,16 @@ use xcm_executor::{
 	traits::{
 		AssetTransferError, CheckSuspension, ClaimAssets, ConvertLocation, ConvertOrigin,
 		DropAssets, MatchesFungible, OnResponse, Properties, QueryHandler, QueryResponseStatus,
-		RecordXcm, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
+		RecordXcm, ShouldExecute, TransactAsset, TransferType, VersionChangeNotifier, WeightBounds,
 		XcmAssetTransfers,
 	},
 	AssetsInHolding,
 };
 use xcm_runtime_apis::{
+	barrier::Error as XcmBarrierApiError,
 	dry_run::{CallDryRunEffects, Error as XcmDryRunApiError, XcmDryRunEffects},
 	fees::Error as XcmPaymentApiError,
+	outcomes::XcmOutcomeRecord,
 	trusted_query::Error as TrustedQueryApiError,
 };
 
@@ -88,6 +90,7 @@ pub trait WeightInfo {
 	fn force_subscribe_version_notify() -> Weight;
 	fn force_unsubscribe_version_notify() -> Weight;
 	fn force_suspension() -> Weight;
+	fn set_destination_fee_hint() -> Weight;
 	fn migrate_supported_version() -> Weight;
 	fn migrate_version_notifiers() -> Weight;
 	fn already_notified_target() -> Weight;
@@ -143,6 +146,10 @@ impl WeightInfo for TestWeightInfo {
 		Weight::from_parts(100_000_000, 0)
 	}
 
+	fn set_destination_fee_hint() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+	}
+
 	fn migrate_supported_version() -> Weight {
 		Weight::from_parts(100_000_000, 0)
 	}
@@ -292,6 +299,14 @@ pub mod pallet {
 		/// The ID type for local consumers of remote locks.
 		type RemoteLockConsumerIdentifier: Parameter + Member + MaxEncodedLen + Ord + Copy;
 
+		/// For how many blocks the outcome of executed XCMs is kept in [`XcmOutcomes`]. Zero
+		/// disables recording outcomes.
+		///
+		/// Only relevant if this pallet is being used as the [`xcm_executor::traits::RecordXcm`]
+		/// implementation in the XCM executor configuration, in which case recording is part of
+		/// the weight of every executed XCM.
+		type XcmOutcomeRetention: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -505,6 +520,8 @@ pub mod pallet {
 		AssetsClaimed { hash: H256, origin: Location, assets: VersionedAssets },
 		/// A XCM version migration finished.
 		VersionMigrationFinished { version: XcmVersion },
+		/// The fee hint of a destination for an asset was set, or removed if `min_fee` is `None`.
+		DestinationFeeHintSet { destination: Location, asset_id: AssetId, min_fee: Option<u128> },
 	}
 
 	#[pallet::origin]
@@ -578,6 +595,15 @@ pub mod pallet {
 		/// Local XCM execution incomplete.
 		#[codec(index = 24)]
 		LocalExecutionIncomplete,
+		/// The number of transfer types does not match the number of assets.
+		#[codec(index = 25)]
+		TransferTypesMismatch,
+		/// The destination is not known to accept the fee asset for the execution of transfers.
+		#[codec(index = 26)]
+		FeeAssetNotAccepted,
+		/// The fees are predicted to not pay for the execution of the transfer at the destination.
+		#[codec(index = 27)]
+		DestinationFeesNotMet,
 	}
 
 	impl<T: Config> From<SendError> for Error<T> {
@@ -797,6 +823,50 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type RecordedXcm<T: Config> = StorageValue<_, Xcm<()>>;
 
+	/// Outcome of the XCMs executed in the last [`Config::XcmOutcomeRetention`] blocks, keyed by
+	/// message ID.
+	/// Runtime APIs can fetch why a message failed by accessing this value.
+	#[pallet::storage]
+	pub(crate) type XcmOutcomes<T: Config> =
+		StorageMap<_, Blake2_128Concat, XcmHash, XcmOutcomeRecord<BlockNumberFor<T>>, OptionQuery>;
+
+	/// IDs of the messages in [`XcmOutcomes`], keyed by the block in which they were executed.
+	#[pallet::storage]
+	pub(crate) type XcmOutcomeBlocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		XcmHash,
+		(),
+		OptionQuery,
+	>;
+
+	/// The oldest block in [`XcmOutcomeBlocks`] which is not pruned yet, or zero if no outcomes
+	/// are recorded.
+	#[pallet::storage]
+	pub(crate) type XcmOutcomePruneCursor<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Minimum fees that destinations charge for the execution of a transfer, maintained by
+	/// [`Config::AdminOrigin`].
+	///
+	/// Maps a destination and a fee asset, both as seen from this chain and in the latest XCM
+	/// version, to the minimum amount of the asset that pays for the execution of a transfer at
+	/// the destination. A destination with hints is assumed not to accept other fee assets.
+	///
+	/// The keys are re-encoded in a new XCM version by [`migration::MigrateToLatestXcmVersion`].
+	#[pallet::storage]
+	pub(crate) type DestinationFeeHints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		VersionedLocation,
+		Blake2_128Concat,
+		VersionedAssetId,
+		u128,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		#[serde(skip)]
@@ -820,8 +890,8 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			let mut weight_used = Weight::zero();
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut weight_used = Self::prune_xcm_outcomes(n);
 			if let Some(migration) = CurrentMigration::<T>::get() {
 				// Consume 10% of block at most
 				let max_weight = T::BlockWeights::get().max_block / 10;
@@ -1235,8 +1305,10 @@ pub mod pallet {
 		/// `weight_limit` of weight. If more weight is needed than `weight_limit`, then the
 		/// operation will fail and the sent assets may be at risk.
 		///
-		/// `assets` (excluding `fees`) must have same reserve location or otherwise be teleportable
-		/// to `dest`, no limitations imposed on `fees`.
+		/// `assets` (excluding `fees`) may use different transfer types, each asset being moved by
+		/// its own reserve location or teleported to `dest`, all within the same XCM program.
+		/// Remote reserves can only be used if all `assets` (including `fees`) share the same
+		/// remote reserve.
 		///  - for local reserve: transfer assets to sovereign account of destination chain and
 		///    forward a notification XCM to `dest` to mint and deposit reserve-based assets to
 		///    `beneficiary`.
@@ -1281,19 +1353,16 @@ pub mod pallet {
 
 			ensure!(assets.len() <= MAX_ASSETS_FOR_TRANSFER, Error::<T>::TooManyAssets);
 			let assets = assets.into_inner();
-			let fee_asset_item = fee_asset_item as usize;
-			// Find transfer types for fee and non-fee assets.
-			let (fees_transfer_type, assets_transfer_type) =
-				Self::find_fee_and_assets_transfer_types(&assets, fee_asset_item, &dest)?;
+			// Find transfer type for each of the assets.
+			let transfer_types = Self::find_assets_transfer_types(&assets, &dest)?;
 
-			Self::do_transfer_assets(
+			Self::do_transfer_assets_using_types(
 				origin,
 				dest,
 				Either::Left(beneficiary),
 				assets,
-				assets_transfer_type,
-				fee_asset_item,
-				fees_transfer_type,
+				transfer_types,
+				fee_asset_item as usize,
 				weight_limit,
 			)
 		}
@@ -1429,11 +1498,147 @@ pub mod pallet {
 				weight_limit,
 			)
 		}
+
+		/// Transfer assets from the local chain to the destination chain using an explicit
+		/// transfer type for each of the assets.
+		///
+		/// Works like `transfer_assets_using_type_and_then`, but `assets_transfer_types` holds one
+		/// `TransferType` per entry of `assets` (including the one identified by `remote_fees_id`),
+		/// so that e.g. one asset may be teleported while another is reserve-transferred in the
+		/// same message. Each group of assets sharing a transfer type is checked against the
+		/// corresponding filter (`XcmTeleportFilter` or `XcmReserveTransferFilter`).
+		///
+		/// `TransferType::RemoteReserve` may only be used if all assets share the same remote
+		/// reserve.
+		///
+		/// - `origin`: Must be capable of withdrawing the `assets` and executing XCM.
+		/// - `dest`: Destination context for the assets.
+		/// - `assets`: The assets to be withdrawn. This should include the assets used to pay the
+		///   fee on the `dest` (and possibly reserve) chains.
+		/// - `assets_transfer_types`: The XCM `TransferType` used for each of the `assets`, in the
+		///   same order.
+		/// - `remote_fees_id`: One of the included `assets` to be used to pay fees.
+		/// - `custom_xcm_on_dest`: The XCM to be executed on `dest` chain as the last step of the
+		///   transfer, which also determines what happens to the assets on the destination chain.
+		/// - `weight_limit`: The remote-side weight limit, if any, for the XCM fee purchase.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::transfer_assets())]
+		pub fn transfer_assets_using_types_and_then(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			assets: Box<VersionedAssets>,
+			assets_transfer_types: Vec<TransferType>,
+			remote_fees_id: Box<VersionedAssetId>,
+			custom_xcm_on_dest: Box<VersionedXcm<()>>,
+			weight_limit: WeightLimit,
+		) -> DispatchResult {
+			let origin_location = T::ExecuteXcmOrigin::ensure_origin(origin)?;
+			let dest: Location = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let assets: Assets = (*assets).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let fees_id: AssetId =
+				(*remote_fees_id).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let remote_xcm: Xcm<()> =
+				(*custom_xcm_on_dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			tracing::debug!(
+				target: "xcm::pallet_xcm::transfer_assets_using_types_and_then",
+				?origin_location, ?dest, ?assets, ?assets_transfer_types, ?fees_id, ?remote_xcm,
+				?weight_limit,
+			);
+
+			let assets = assets.into_inner();
+			ensure!(assets.len() <= MAX_ASSETS_FOR_TRANSFER, Error::<T>::TooManyAssets);
+
+			let fee_asset_index =
+				assets.iter().position(|a| a.id == fees_id).ok_or(Error::<T>::FeesNotMet)?;
+			Self::do_transfer_assets_using_types(
+				origin_location,
+				dest,
+				Either::Right(remote_xcm),
+				assets,
+				assets_transfer_types,
+				fee_asset_index,
+				weight_limit,
+			)
+		}
+
+		/// Set or remove the minimum fee that `dest` charges in `asset_id` for the execution of a
+		/// transfer.
+		///
+		/// The hints are consulted by `transfer_assets_checked` to reject transfers that would
+		/// fail to pay for their execution at the destination, and trap the assets there.
+		///
+		/// - `origin`: Must be an origin specified by AdminOrigin.
+		/// - `dest`: The destination, as seen from this chain.
+		/// - `asset_id`: The fee asset, as seen from this chain.
+		/// - `min_fee`: The minimum amount of `asset_id` that pays for the execution of a transfer
+		///   at `dest`, or `None` to remove the hint.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_destination_fee_hint())]
+		pub fn set_destination_fee_hint(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			asset_id: Box<VersionedAssetId>,
+			min_fee: Option<u128>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let dest: Location = (*dest).try_into().map_err(|()| Error::<T>::BadVersion)?;
+			let asset_id: AssetId = (*asset_id).try_into().map_err(|()| Error::<T>::BadVersion)?;
+
+			DestinationFeeHints::<T>::set(
+				VersionedLocation::from(dest.clone()),
+				VersionedAssetId::from(asset_id.clone()),
+				min_fee,
+			);
+			Self::deposit_event(Event::DestinationFeeHintSet {
+				destination: dest,
+				asset_id,
+				min_fee,
+			});
+			Ok(())
+		}
+
+		/// Same as `transfer_assets`, but if `ensure_deliverable` is set, the transfer is rejected
+		/// if it would predictably fail to pay for its execution at `dest`.
+		///
+		/// The prediction is made from the fee hints set with `set_destination_fee_hint`: `fees`
+		/// must be an asset with a hint for `dest`, and at least its minimum fee. Transfers to
+		/// destinations without any hint are not rejected.
+		///
+		/// - `ensure_deliverable`: Whether to check `fees` against the fee hints of `dest`.
+		///
+		/// See `transfer_assets` for the other parameters.
+		#[pallet::call_index(16)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_assets().saturating_add(T::DbWeight::get().reads(2))
+		)]
+		pub fn transfer_assets_checked(
+			origin: OriginFor<T>,
+			dest: Box<VersionedLocation>,
+			beneficiary: Box<VersionedLocation>,
+			assets: Box<VersionedAssets>,
+			fee_asset_item: u32,
+			weight_limit: WeightLimit,
+			ensure_deliverable: bool,
+		) -> DispatchResult {
+			if ensure_deliverable {
+				let dest: Location =
+					dest.as_ref().clone().try_into().map_err(|()| Error::<T>::BadVersion)?;
+				let assets: Assets =
+					assets.as_ref().clone().try_into().map_err(|()| Error::<T>::BadVersion)?;
+				let fees = assets.get(fee_asset_item as usize).ok_or(Error::<T>::Empty)?;
+				Self::ensure_deliverable(&dest, fees)?;
+			}
+
+			Self::transfer_assets(origin, dest, beneficiary, assets, fee_asset_item, weight_limit)
+		}
 	}
 }
 
 /// The maximum number of distinct assets allowed to be transferred in a single helper extrinsic.
-const MAX_ASSETS_FOR_TRANSFER: usize = 2;
+const MAX_ASSETS_FOR_TRANSFER: usize = 3;
+
+/// The maximum number of recorded XCM outcomes pruned in a single block.
+const MAX_XCM_OUTCOME_PRUNES: u32 = 256;
 
 /// Specify how assets used for fees are handled during asset transfers.
 #[derive(Clone, PartialEq)]
@@ -1558,6 +1763,24 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
+	/// Find the `TransferType` of each of the `assets` when transferring to `dest`.
+	fn find_assets_transfer_types(
+		assets: &[Asset],
+		dest: &Location,
+	) -> Result<Vec<TransferType>, Error<T>> {
+		ensure!(!assets.is_empty(), Error::<T>::Empty);
+		assets
+			.iter()
+			.map(|asset| {
+				if let Fungible(x) = asset.fun {
+					// If fungible asset, ensure non-zero amount.
+					ensure!(!x.is_zero(), Error::<T>::Empty);
+				}
+				T::XcmExecutor::determine_for(asset, dest).map_err(Error::<T>::from)
+			})
+			.collect()
+	}
+
 	fn do_reserve_transfer_assets(
 		origin: OriginFor<T>,
 		dest: Box<VersionedLocation>,
@@ -1670,32 +1893,16 @@ impl<T: Config> Pallet<T> {
 				!matches!(assets_transfer_type, TransferType::RemoteReserve(_)),
 				Error::<T>::InvalidAssetUnsupportedReserve
 			);
-			let weight_limit = weight_limit.clone();
 			// remove `fees` from `assets` and build separate fees transfer instructions to be
 			// added to assets transfers XCM programs
 			let fees = assets.remove(fee_asset_index);
-			let (local_xcm, remote_xcm) = match fees_transfer_type {
-				TransferType::LocalReserve => Self::local_reserve_fees_instructions(
-					origin.clone(),
-					dest.clone(),
-					fees,
-					weight_limit,
-				)?,
-				TransferType::DestinationReserve => Self::destination_reserve_fees_instructions(
-					origin.clone(),
-					dest.clone(),
-					fees,
-					weight_limit,
-				)?,
-				TransferType::Teleport => Self::teleport_fees_instructions(
-					origin.clone(),
-					dest.clone(),
-					fees,
-					weight_limit,
-				)?,
-				TransferType::RemoteReserve(_) =>
-					return Err(Error::<T>::InvalidAssetUnsupportedReserve.into()),
-			};
+			let (local_xcm, remote_xcm) = Self::fees_instructions(
+				origin.clone(),
+				dest.clone(),
+				fees,
+				fees_transfer_type,
+				weight_limit.clone(),
+			)?;
 			FeesHandling::Separate { local_xcm, remote_xcm }
 		};
 
@@ -1711,6 +1918,95 @@ impl<T: Config> Pallet<T> {
 		Self::execute_xcm_transfer(origin, dest, local_xcm, remote_xcm)
 	}
 
+	/// Transfer `assets` to `dest`, each asset using the transfer type found at the same index
+	/// of `transfer_types`.
+	///
+	/// If all `assets` (excluding `fees`) share the same transfer type, this is the same as
+	/// `do_transfer_assets`. Otherwise `fees` are handled separately and each group of assets
+	/// sharing a transfer type gets its own instructions, all within the same local and remote
+	/// XCM programs.
+	fn do_transfer_assets_using_types(
+		origin: Location,
+		dest: Location,
+		beneficiary: Either<Location, Xcm<()>>,
+		mut assets: Vec<Asset>,
+		mut transfer_types: Vec<TransferType>,
+		fee_asset_index: usize,
+		weight_limit: WeightLimit,
+	) -> DispatchResult {
+		ensure!(assets.len() == transfer_types.len(), Error::<T>::TransferTypesMismatch);
+		let fees_transfer_type =
+			transfer_types.get(fee_asset_index).ok_or(Error::<T>::Empty)?.clone();
+		let mut assets_transfer_types = transfer_types
+			.iter()
+			.enumerate()
+			.filter(|(idx, _)| *idx != fee_asset_index)
+			.map(|(_, transfer_type)| transfer_type);
+		// single asset also marked as fee item
+		let assets_transfer_type =
+			assets_transfer_types.next().unwrap_or(&fees_transfer_type).clone();
+		if assets_transfer_types.all(|transfer_type| transfer_type == &assets_transfer_type) {
+			return Self::do_transfer_assets(
+				origin,
+				dest,
+				beneficiary,
+				assets,
+				assets_transfer_type,
+				fee_asset_index,
+				fees_transfer_type,
+				weight_limit,
+			)
+		}
+
+		// Disallow _remote reserves_ when mixing transfer types, for the same reason as in
+		// `do_transfer_assets`: there is no guarantee of delivery order on final destination.
+		ensure!(
+			!transfer_types.iter().any(|t| matches!(t, TransferType::RemoteReserve(_))),
+			Error::<T>::InvalidAssetUnsupportedReserve
+		);
+		// max assets is `assets` including `fees`
+		let max_assets = assets.len() as u32;
+		let fees = assets.remove(fee_asset_index);
+		transfer_types.remove(fee_asset_index);
+		// fees go first so that execution is bought on `dest` before receiving the other assets
+		let (mut local_xcm, mut remote_xcm) = Self::fees_instructions(
+			origin.clone(),
+			dest.clone(),
+			fees,
+			fees_transfer_type,
+			weight_limit,
+		)?;
+
+		// group assets by transfer type, so that each group passes its own filter
+		let mut groups: Vec<(TransferType, Vec<Asset>)> = Vec::new();
+		for (asset, transfer_type) in assets.into_iter().zip(transfer_types) {
+			match groups.iter_mut().find(|(existing, _)| existing == &transfer_type) {
+				Some((_, group)) => group.push(asset),
+				None => groups.push((transfer_type, vec![asset])),
+			}
+		}
+		for (transfer_type, group) in groups {
+			let (local, remote) =
+				Self::transfer_instructions(origin.clone(), dest.clone(), group, transfer_type)?;
+			local_xcm.inner_mut().extend(local.into_iter());
+			remote_xcm.inner_mut().extend(remote.into_iter());
+		}
+		// following instructions are not exec'ed on behalf of origin chain anymore
+		remote_xcm.inner_mut().push(ClearOrigin);
+
+		// Use custom XCM on remote chain, or just default to depositing everything to beneficiary.
+		let custom_remote_xcm = match beneficiary {
+			Either::Right(custom_xcm) => custom_xcm,
+			Either::Left(beneficiary) => {
+				// deposit all remaining assets in holding to `beneficiary` location
+				Xcm(vec![DepositAsset { assets: Wild(AllCounted(max_assets)), beneficiary }])
+			},
+		};
+		remote_xcm.inner_mut().extend(custom_remote_xcm.into_iter());
+
+		Self::execute_xcm_transfer(origin, dest, local_xcm, Some(remote_xcm))
+	}
+
 	fn build_xcm_transfer_type(
 		origin: Location,
 		dest: Location,
@@ -1861,6 +2157,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn fees_instructions(
+		origin: Location,
+		dest: Location,
+		fees: Asset,
+		fees_transfer_type: TransferType,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		match fees_transfer_type {
+			TransferType::LocalReserve =>
+				Self::local_reserve_fees_instructions(origin, dest, fees, weight_limit),
+			TransferType::DestinationReserve =>
+				Self::destination_reserve_fees_instructions(origin, dest, fees, weight_limit),
+			TransferType::Teleport =>
+				Self::teleport_fees_instructions(origin, dest, fees, weight_limit),
+			TransferType::RemoteReserve(_) => Err(Error::<T>::InvalidAssetUnsupportedReserve),
+		}
+	}
+
+	/// Build the local and remote instructions moving `assets` to `dest` using `transfer_type`,
+	/// without buying execution or depositing them on `dest`.
+	fn transfer_instructions(
+		origin: Location,
+		dest: Location,
+		assets: Vec<Asset>,
+		transfer_type: TransferType,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		match transfer_type {
+			TransferType::LocalReserve =>
+				Self::local_reserve_transfer_instructions(origin, dest, assets),
+			TransferType::DestinationReserve =>
+				Self::destination_reserve_transfer_instructions(origin, dest, assets),
+			TransferType::Teleport => Self::teleport_transfer_instructions(origin, dest, assets),
+			TransferType::RemoteReserve(_) => Err(Error::<T>::InvalidAssetUnsupportedReserve),
+		}
+	}
+
 	fn local_reserve_fees_instructions(
 		origin: Location,
 		dest: Location,
@@ -1889,43 +2221,53 @@ impl<T: Config> Pallet<T> {
 		Ok((local_execute_xcm, xcm_on_dest))
 	}
 
-	fn local_reserve_transfer_programs(
+	fn local_reserve_transfer_instructions(
 		origin: Location,
 		dest: Location,
-		beneficiary: Either<Location, Xcm<()>>,
 		assets: Vec<Asset>,
-		fees: FeesHandling<T>,
-		weight_limit: WeightLimit,
 	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
 		let value = (origin, assets);
 		ensure!(T::XcmReserveTransferFilter::contains(&value), Error::<T>::Filtered);
 		let (_, assets) = value;
 
-		// max assets is `assets` (+ potentially separately handled fee)
-		let max_assets =
-			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
 		let assets: Assets = assets.into();
 		let context = T::UniversalLocation::get();
 		let mut reanchored_assets = assets.clone();
 		reanchored_assets
 			.reanchor(&dest, &context)
 			.map_err(|e| {
-				tracing::error!(target: "xcm::pallet_xcm::local_reserve_transfer_programs", ?e, ?dest, ?context, "Failed to re-anchor assets");
+				tracing::error!(target: "xcm::pallet_xcm::local_reserve_transfer_instructions", ?e, ?dest, ?context, "Failed to re-anchor assets");
 				Error::<T>::CannotReanchor
 			})?;
 
 		// XCM instructions to be executed on local chain
-		let mut local_execute_xcm = Xcm(vec![
+		let local_execute_xcm = Xcm(vec![
 			// locally move `assets` to `dest`s local sovereign account
-			TransferAsset { assets, beneficiary: dest.clone() },
+			TransferAsset { assets, beneficiary: dest },
 		]);
 		// XCM instructions to be executed on destination chain
-		let mut xcm_on_dest = Xcm(vec![
+		let xcm_on_dest = Xcm(vec![
 			// let (dest) chain know assets are in its SA on reserve
 			ReserveAssetDeposited(reanchored_assets),
-			// following instructions are not exec'ed on behalf of origin chain anymore
-			ClearOrigin,
 		]);
+		Ok((local_execute_xcm, xcm_on_dest))
+	}
+
+	fn local_reserve_transfer_programs(
+		origin: Location,
+		dest: Location,
+		beneficiary: Either<Location, Xcm<()>>,
+		assets: Vec<Asset>,
+		fees: FeesHandling<T>,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		// max assets is `assets` (+ potentially separately handled fee)
+		let max_assets =
+			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
+		let (mut local_execute_xcm, mut xcm_on_dest) =
+			Self::local_reserve_transfer_instructions(origin, dest.clone(), assets)?;
+		// following instructions are not exec'ed on behalf of origin chain anymore
+		xcm_on_dest.inner_mut().push(ClearOrigin);
 		// handle fees
 		Self::add_fees_to_xcm(dest, fees, weight_limit, &mut local_execute_xcm, &mut xcm_on_dest)?;
 
@@ -1980,13 +2322,10 @@ impl<T: Config> Pallet<T> {
 		Ok((local_execute_xcm, xcm_on_dest))
 	}
 
-	fn destination_reserve_transfer_programs(
+	fn destination_reserve_transfer_instructions(
 		origin: Location,
 		dest: Location,
-		beneficiary: Either<Location, Xcm<()>>,
 		assets: Vec<Asset>,
-		fees: FeesHandling<T>,
-		weight_limit: WeightLimit,
 	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
 		let value = (origin, assets);
 		ensure!(T::XcmReserveTransferFilter::contains(&value), Error::<T>::Filtered);
@@ -1998,33 +2337,46 @@ impl<T: Config> Pallet<T> {
 			);
 		}
 
-		// max assets is `assets` (+ potentially separately handled fee)
-		let max_assets =
-			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
 		let assets: Assets = assets.into();
 		let context = T::UniversalLocation::get();
 		let mut reanchored_assets = assets.clone();
 		reanchored_assets
 			.reanchor(&dest, &context)
 			.map_err(|e| {
-				tracing::error!(target: "xcm::pallet_xcm::destination_reserve_transfer_programs", ?e, ?dest, ?context, "Failed to re-anchor assets");
+				tracing::error!(target: "xcm::pallet_xcm::destination_reserve_transfer_instructions", ?e, ?dest, ?context, "Failed to re-anchor assets");
 				Error::<T>::CannotReanchor
 			})?;
 
 		// XCM instructions to be executed on local chain
-		let mut local_execute_xcm = Xcm(vec![
+		let local_execute_xcm = Xcm(vec![
 			// withdraw reserve-based assets
 			WithdrawAsset(assets.clone()),
 			// burn reserve-based assets
 			BurnAsset(assets),
 		]);
 		// XCM instructions to be executed on destination chain
-		let mut xcm_on_dest = Xcm(vec![
+		let xcm_on_dest = Xcm(vec![
 			// withdraw `assets` from origin chain's sovereign account
 			WithdrawAsset(reanchored_assets),
-			// following instructions are not exec'ed on behalf of origin chain anymore
-			ClearOrigin,
 		]);
+		Ok((local_execute_xcm, xcm_on_dest))
+	}
+
+	fn destination_reserve_transfer_programs(
+		origin: Location,
+		dest: Location,
+		beneficiary: Either<Location, Xcm<()>>,
+		assets: Vec<Asset>,
+		fees: FeesHandling<T>,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		// max assets is `assets` (+ potentially separately handled fee)
+		let max_assets =
+			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
+		let (mut local_execute_xcm, mut xcm_on_dest) =
+			Self::destination_reserve_transfer_instructions(origin, dest.clone(), assets)?;
+		// following instructions are not exec'ed on behalf of origin chain anymore
+		xcm_on_dest.inner_mut().push(ClearOrigin);
 		// handle fees
 		Self::add_fees_to_xcm(dest, fees, weight_limit, &mut local_execute_xcm, &mut xcm_on_dest)?;
 
@@ -2169,13 +2521,10 @@ impl<T: Config> Pallet<T> {
 		Ok((local_execute_xcm, xcm_on_dest))
 	}
 
-	fn teleport_assets_program(
+	fn teleport_transfer_instructions(
 		origin: Location,
 		dest: Location,
-		beneficiary: Either<Location, Xcm<()>>,
 		assets: Vec<Asset>,
-		fees: FeesHandling<T>,
-		weight_limit: WeightLimit,
 	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
 		let value = (origin, assets);
 		ensure!(T::XcmTeleportFilter::contains(&value), Error::<T>::Filtered);
@@ -2187,16 +2536,13 @@ impl<T: Config> Pallet<T> {
 			);
 		}
 
-		// max assets is `assets` (+ potentially separately handled fee)
-		let max_assets =
-			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
 		let context = T::UniversalLocation::get();
 		let assets: Assets = assets.into();
 		let mut reanchored_assets = assets.clone();
 		reanchored_assets
 			.reanchor(&dest, &context)
 			.map_err(|e| {
-				tracing::error!(target: "xcm::pallet_xcm::teleport_assets_program", ?e, ?dest, ?context, "Failed to re-anchor asset");
+				tracing::error!(target: "xcm::pallet_xcm::teleport_transfer_instructions", ?e, ?dest, ?context, "Failed to re-anchor asset");
 				Error::<T>::CannotReanchor
 			})?;
 
@@ -2214,7 +2560,7 @@ impl<T: Config> Pallet<T> {
 				&dummy_context,
 			)
 			.map_err(|e| {
-				tracing::error!(target: "xcm::pallet_xcm::teleport_assets_program", ?e, ?asset, ?dest, "Failed can_check_out asset");
+				tracing::error!(target: "xcm::pallet_xcm::teleport_transfer_instructions", ?e, ?asset, ?dest, "Failed can_check_out asset");
 				Error::<T>::CannotCheckOutTeleport
 			})?;
 		}
@@ -2229,19 +2575,35 @@ impl<T: Config> Pallet<T> {
 		}
 
 		// XCM instructions to be executed on local chain
-		let mut local_execute_xcm = Xcm(vec![
+		let local_execute_xcm = Xcm(vec![
 			// withdraw assets to be teleported
 			WithdrawAsset(assets.clone()),
 			// burn assets on local chain
 			BurnAsset(assets),
 		]);
 		// XCM instructions to be executed on destination chain
-		let mut xcm_on_dest = Xcm(vec![
+		let xcm_on_dest = Xcm(vec![
 			// teleport `assets` in from origin chain
 			ReceiveTeleportedAsset(reanchored_assets),
-			// following instructions are not exec'ed on behalf of origin chain anymore
-			ClearOrigin,
 		]);
+		Ok((local_execute_xcm, xcm_on_dest))
+	}
+
+	fn teleport_assets_program(
+		origin: Location,
+		dest: Location,
+		beneficiary: Either<Location, Xcm<()>>,
+		assets: Vec<Asset>,
+		fees: FeesHandling<T>,
+		weight_limit: WeightLimit,
+	) -> Result<(Xcm<<T as Config>::RuntimeCall>, Xcm<()>), Error<T>> {
+		// max assets is `assets` (+ potentially separately handled fee)
+		let max_assets =
+			assets.len() as u32 + if matches!(&fees, FeesHandling::Batched { .. }) { 0 } else { 1 };
+		let (mut local_execute_xcm, mut xcm_on_dest) =
+			Self::teleport_transfer_instructions(origin, dest.clone(), assets)?;
+		// following instructions are not exec'ed on behalf of origin chain anymore
+		xcm_on_dest.inner_mut().push(ClearOrigin);
 		// handle fees
 		Self::add_fees_to_xcm(dest, fees, weight_limit, &mut local_execute_xcm, &mut xcm_on_dest)?;
 
@@ -2609,10 +2971,13 @@ impl<T: Config> Pallet<T> {
 				XcmPaymentApiError::VersionedConversionFailed
 			})?;
 
-		T::Weigher::weight(&mut message.clone().into()).map_err(|()| {
-			tracing::error!(target: "xcm::pallet_xcm::query_xcm_weight", ?message, "Error when querying XCM weight");
-			XcmPaymentApiError::WeightNotComputable
-		})
+		// weighed like the executor does, which includes recording the outcome.
+		T::XcmExecutor::prepare(message.clone().into())
+			.map(|prepared| prepared.weight_of())
+			.map_err(|_| {
+				tracing::error!(target: "xcm::pallet_xcm::query_xcm_weight", ?message, "Error when querying XCM weight");
+				XcmPaymentApiError::WeightNotComputable
+			})
 	}
 
 	/// Given an Asset and a Location, returns if the provided location is a trusted reserve for the
@@ -2666,6 +3031,119 @@ impl<T: Config> Pallet<T> {
 		Ok(<T::XcmExecutor as XcmAssetTransfers>::IsTeleporter::contains(&a, &location))
 	}
 
+	/// Given an origin and a message, returns whether the barrier of `XcmConfig` would let the
+	/// message through.
+	///
+	/// Meant to be used in the `xcm_runtime_apis::barrier::XcmBarrierApi` runtime API.
+	pub fn check_barrier<XcmConfig: xcm_executor::Config>(
+		origin: VersionedLocation,
+		message: VersionedXcm<()>,
+	) -> Result<(), XcmBarrierApiError> {
+		let origin: Location = origin.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::check_barrier",
+				?e, "Location version conversion failed"
+			);
+			XcmBarrierApiError::VersionedConversionFailed
+		})?;
+		let message: Xcm<()> = message.try_into().map_err(|e| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::check_barrier",
+				?e, "Xcm version conversion failed"
+			);
+			XcmBarrierApiError::VersionedConversionFailed
+		})?;
+
+		let mut message: Xcm<XcmConfig::RuntimeCall> = message.into();
+		let weight = XcmConfig::Weigher::weight(&mut message).map_err(|()| {
+			tracing::debug!(
+				target: "xcm::pallet_xcm::check_barrier",
+				?message, "Error when computing XCM weight"
+			);
+			XcmBarrierApiError::WeightNotComputable
+		})?;
+		let mut properties = Properties { weight_credit: Weight::zero(), message_id: None };
+		XcmConfig::Barrier::should_execute(&origin, message.inner_mut(), weight, &mut properties)
+			.map_err(XcmBarrierApiError::Rejected)
+	}
+
+	/// Given the ID of an executed XCM, returns the recorded outcome of its execution, if still
+	/// kept.
+	pub fn xcm_outcome(message_id: XcmHash) -> Option<XcmOutcomeRecord<BlockNumberFor<T>>> {
+		let retention: BlockNumberFor<T> = T::XcmOutcomeRetention::get().into();
+		let now = frame_system::Pallet::<T>::block_number();
+		// the outcomes out of the retention window may not be pruned yet.
+		XcmOutcomes::<T>::get(message_id).filter(|record| record.at.saturating_add(retention) > now)
+	}
+
+	/// Prune the outcomes of the XCMs executed before the [`Config::XcmOutcomeRetention`] window
+	/// of block `now`, at most [`MAX_XCM_OUTCOME_PRUNES`] of them, and return the weight consumed.
+	///
+	/// If the retention was lowered, the outcomes out of the new window are pruned over as many
+	/// blocks as needed.
+	fn prune_xcm_outcomes(now: BlockNumberFor<T>) -> Weight {
+		let retention: BlockNumberFor<T> = T::XcmOutcomeRetention::get().into();
+		let mut cursor = XcmOutcomePruneCursor::<T>::get();
+		let mut weight_used = T::DbWeight::get().reads(1);
+		if cursor.is_zero() {
+			if retention.is_zero() {
+				return weight_used
+			}
+			// no outcome was recorded before this block.
+			XcmOutcomePruneCursor::<T>::put(now);
+			return weight_used.saturating_add(T::DbWeight::get().writes(1))
+		}
+
+		let mut budget = MAX_XCM_OUTCOME_PRUNES;
+		while budget > 0 && cursor.saturating_add(retention) <= now {
+			let mut pruned = 0u32;
+			for (message_id, ()) in
+				XcmOutcomeBlocks::<T>::drain_prefix(cursor).take(budget as usize)
+			{
+				XcmOutcomes::<T>::remove(message_id);
+				pruned.saturating_inc();
+			}
+			weight_used.saturating_accrue(
+				T::DbWeight::get()
+					.reads_writes(1, 2)
+					.saturating_mul(pruned.into())
+					.saturating_add(T::DbWeight::get().reads(1)),
+			);
+			budget.saturating_reduce(pruned);
+			// the block may have more outcomes to prune.
+			if budget == 0 {
+				break
+			}
+			cursor.saturating_inc();
+			// the blocks without outcomes are also bounded.
+			budget.saturating_dec();
+		}
+
+		if retention.is_zero() && cursor >= now {
+			XcmOutcomePruneCursor::<T>::kill();
+		} else {
+			XcmOutcomePruneCursor::<T>::put(cursor);
+		}
+		weight_used.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Ensure that `fees` are predicted to pay for the execution of a transfer at `dest`,
+	/// according to the [`DestinationFeeHints`].
+	///
+	/// Destinations without any hint are assumed to accept any fees.
+	pub fn ensure_deliverable(dest: &Location, fees: &Asset) -> Result<(), Error<T>> {
+		let dest = VersionedLocation::from(dest.clone());
+		if DestinationFeeHints::<T>::iter_prefix_values(&dest).next().is_none() {
+			return Ok(())
+		}
+
+		let min_fee = DestinationFeeHints::<T>::get(&dest, VersionedAssetId::from(fees.id.clone()))
+			.ok_or(Error::<T>::FeeAssetNotAccepted)?;
+		let Fungible(amount) = fees.fun else { return Err(Error::<T>::FeeAssetNotAccepted) };
+		ensure!(amount >= min_fee, Error::<T>::DestinationFeesNotMet);
+		Ok(())
+	}
+
 	pub fn query_delivery_fees(
 		destination: VersionedLocation,
 		message: VersionedXcm<()>,
@@ -3412,6 +3890,30 @@ impl<T: Config> RecordXcm for Pallet<T> {
 	fn record(xcm: Xcm<()>) {
 		RecordedXcm::<T>::put(xcm);
 	}
+
+	fn record_outcome(message_id: &XcmHash, outcome: &Outcome) {
+		let retention: BlockNumberFor<T> = T::XcmOutcomeRetention::get().into();
+		if retention.is_zero() {
+			return
+		}
+		// A message executed again (e.g. after failing) replaces its previous outcome.
+		if let Some(previous) = XcmOutcomes::<T>::get(message_id) {
+			XcmOutcomeBlocks::<T>::remove(previous.at, message_id);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		XcmOutcomeBlocks::<T>::insert(now, message_id, ());
+		XcmOutcomes::<T>::insert(
+			message_id,
+			XcmOutcomeRecord { at: now, outcome: outcome.clone() },
+		);
+	}
+
+	fn record_outcome_weight() -> Weight {
+		if T::XcmOutcomeRetention::get() == 0 {
+			return Weight::zero()
+		}
+		T::DbWeight::get().reads_writes(1, 3)
+	}
 }
 
 /// Ensure that the origin `o` represents an XCM (`Transact`) origin.
@@ -170,6 +170,26 @@ pub mod data {
 		}
 	}
 
+	/// Implementation of `NeedsMigration` for `DestinationFeeHints` key type.
+	impl NeedsMigration for (VersionedLocation, VersionedAssetId) {
+		type MigratedData = Self;
+
+		fn needs_migration(&self, minimal_allowed_xcm_version: XcmVersion) -> bool {
+			self.0.identify_version() < minimal_allowed_xcm_version ||
+				self.1.identify_version() < minimal_allowed_xcm_version
+		}
+
+		fn try_migrate(self, to_xcm_version: XcmVersion) -> Result<Option<Self::MigratedData>, ()> {
+			if !self.needs_migration(to_xcm_version) {
+				return Ok(None)
+			}
+
+			let Ok(dest) = self.0.into_version(to_xcm_version) else { return Err(()) };
+			let Ok(asset_id) = self.1.into_version(to_xcm_version) else { return Err(()) };
+			Ok(Some((dest, asset_id)))
+		}
+	}
+
 	impl<T: Config> Pallet<T> {
 		/// Migrates relevant data to the `required_xcm_version`.
 		pub(crate) fn migrate_data_to_xcm_version(
@@ -323,6 +343,43 @@ pub mod data {
 				>(&old_key, &new_key);
 				weight.saturating_add(T::DbWeight::get().writes(1));
 			}
+
+			// check and migrate `DestinationFeeHints` keys
+			let destination_fee_hints_keys_to_migrate = DestinationFeeHints::<T>::iter_keys()
+				.filter_map(|key| {
+					weight.saturating_accrue(T::DbWeight::get().reads(1));
+					let old_key = key.clone();
+					match key.try_migrate(required_xcm_version) {
+						Ok(Some(new_key)) => Some((old_key, new_key)),
+						Ok(None) => None,
+						Err(_) => {
+							tracing::error!(
+								target: LOG_TARGET,
+								key = ?old_key,
+								?required_xcm_version,
+								"`DestinationFeeHints` key cannot be migrated!"
+							);
+							None
+						},
+					}
+				})
+				.collect::<Vec<_>>();
+			for ((old_dest, old_asset_id), (new_dest, new_asset_id)) in
+				destination_fee_hints_keys_to_migrate
+			{
+				tracing::info!(
+					target: LOG_TARGET,
+					?old_dest,
+					?old_asset_id,
+					?new_dest,
+					?new_asset_id,
+					"Migrating `DestinationFeeHints` key"
+				);
+				if let Some(min_fee) = DestinationFeeHints::<T>::take(&old_dest, &old_asset_id) {
+					DestinationFeeHints::<T>::insert(&new_dest, &new_asset_id, min_fee);
+				}
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 2));
+			}
 		}
 	}
 }
@@ -453,12 +510,32 @@ impl<T: Config> OnRuntimeUpgrade for MigrateToLatestXcmVersion<T> {
 				})
 				.count();
 
+		let number_of_destination_fee_hints_to_migrate =
+			crate::DestinationFeeHints::<T>::iter_keys()
+				.filter(|(dest, asset_id)| {
+					let needs_migration = (dest.clone(), asset_id.clone()).needs_migration(latest);
+					if needs_migration {
+						tracing::warn!(
+							target: LOG_TARGET,
+							?dest,
+							?asset_id,
+							"DestinationFeeHints item was not migrated!"
+						)
+					}
+					needs_migration
+				})
+				.count();
+
 		ensure!(number_of_queries_to_migrate == 0, "must migrate all `Queries`.");
 		ensure!(number_of_locked_fungibles_to_migrate == 0, "must migrate all `LockedFungibles`.");
 		ensure!(
 			number_of_remote_locked_fungibles_to_migrate == 0,
 			"must migrate all `RemoteLockedFungibles`."
 		);
+		ensure!(
+			number_of_destination_fee_hints_to_migrate == 0,
+			"must migrate all `DestinationFeeHints`."
+		);
 
 		Ok(())
 	}
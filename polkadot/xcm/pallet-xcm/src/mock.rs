@@ -302,6 +302,8 @@ impl pallet_assets::Config for Test {
 	type Freezer = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -522,6 +524,7 @@ pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, A
 
 parameter_types! {
 	pub static AdvertisedXcmVersion: pallet_xcm::XcmVersion = 4;
+	pub static XcmOutcomeRetention: u32 = 10;
 }
 
 pub struct XcmTeleportFiltered;
@@ -555,6 +558,7 @@ impl pallet_xcm::Config for Test {
 	type MaxLockers = frame_support::traits::ConstU32<8>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = XcmOutcomeRetention;
 	type WeightInfo = TestWeightInfo;
 }
 
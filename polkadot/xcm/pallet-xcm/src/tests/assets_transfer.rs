@@ -29,7 +29,7 @@ use frame_support::{
 use polkadot_parachain_primitives::primitives::Id as ParaId;
 use sp_runtime::traits::AccountIdConversion;
 use xcm::prelude::*;
-use xcm_executor::traits::ConvertLocation;
+use xcm_executor::traits::{ConvertLocation, TransferType};
 
 /// Test `limited_teleport_assets`
 ///
@@ -2596,3 +2596,184 @@ fn limited_reserve_transfer_assets_with_remote_asset_reserve_and_remote_asset_fe
 		expected_result,
 	);
 }
+
+/// Test `transfer_assets` with mixed transfer types for the non-fee assets.
+///
+/// Transferring teleport-trusted USDT and foreign asset (destination reserve) to
+/// `FOREIGN_ASSET_RESERVE_PARA_ID`. Using native asset (local reserve) for fees.
+#[test]
+fn transfer_assets_with_mixed_transfer_types_works() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	let beneficiary: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+	new_test_ext_with_balances(balances).execute_with(|| {
+		// create sufficient foreign asset USDT
+		let usdt_initial_local_amount = 42;
+		let (_, _, usdt_id_location) =
+			set_up_foreign_asset(USDT_PARA_ID, None, ALICE, usdt_initial_local_amount, true);
+		// create non-sufficient foreign asset BLA
+		let foreign_initial_amount = 142;
+		let (reserve_location, reserve_sovereign_account, foreign_asset_id_location) =
+			set_up_foreign_asset(
+				FOREIGN_ASSET_RESERVE_PARA_ID,
+				Some(FOREIGN_ASSET_INNER_JUNCTION),
+				ALICE,
+				foreign_initial_amount,
+				false,
+			);
+
+		// transfer destination is reserve location, USDT can be teleported there
+		let dest = reserve_location;
+
+		let fee_asset: Asset = (Location::here(), FEE_AMOUNT).into();
+		let usdt_asset: Asset = (usdt_id_location.clone(), SEND_AMOUNT).into();
+		let foreign_asset: Asset = (foreign_asset_id_location.clone(), SEND_AMOUNT).into();
+		let assets: Assets =
+			vec![fee_asset.clone(), usdt_asset.clone(), foreign_asset.clone()].into();
+		let fee_index = assets.inner().iter().position(|a| a == &fee_asset).unwrap();
+
+		// reanchor according to test-case
+		let context = UniversalLocation::get();
+		let expected_fee = fee_asset.reanchored(&dest, &context).unwrap();
+		let expected_usdt = usdt_asset.reanchored(&dest, &context).unwrap();
+		let expected_foreign = foreign_asset.reanchored(&dest, &context).unwrap();
+
+		assert_ok!(XcmPallet::transfer_assets(
+			RuntimeOrigin::signed(ALICE),
+			Box::new(dest.clone().into()),
+			Box::new(beneficiary.clone().into()),
+			Box::new(assets.into()),
+			fee_index as u32,
+			Unlimited,
+		));
+
+		let weight = BaseXcmWeight::get() * 5;
+		assert!(last_events(3).contains(&RuntimeEvent::XcmPallet(crate::Event::Attempted {
+			outcome: Outcome::Complete { used: weight }
+		})));
+		// Alice spent native asset for fees, USDT and foreign asset for transfer
+		assert_eq!(Balances::free_balance(ALICE), INITIAL_BALANCE - FEE_AMOUNT);
+		assert_eq!(
+			AssetsPallet::balance(usdt_id_location, ALICE),
+			usdt_initial_local_amount - SEND_AMOUNT
+		);
+		assert_eq!(
+			AssetsPallet::balance(foreign_asset_id_location.clone(), ALICE),
+			foreign_initial_amount - SEND_AMOUNT
+		);
+		// Sovereign account of dest parachain holds `FEE_AMOUNT` native asset in local reserve
+		assert_eq!(Balances::free_balance(reserve_sovereign_account.clone()), FEE_AMOUNT);
+		assert_eq!(AssetsPallet::balance(foreign_asset_id_location, reserve_sovereign_account), 0);
+
+		// Verify sent XCM program
+		assert_eq!(
+			sent_xcm(),
+			vec![(
+				dest,
+				Xcm(vec![
+					// fees are reserve-transferred from local chain
+					ReserveAssetDeposited(expected_fee.clone().into()),
+					buy_limited_execution(expected_fee, Unlimited),
+					// USDT is teleported
+					ReceiveTeleportedAsset(expected_usdt.into()),
+					// foreign asset is withdrawn from our sovereign account on its reserve
+					WithdrawAsset(expected_foreign.into()),
+					ClearOrigin,
+					DepositAsset { assets: AllCounted(3).into(), beneficiary },
+				])
+			)]
+		);
+	});
+}
+
+/// Test `transfer_assets_using_types_and_then` requires a transfer type for each of the assets.
+#[test]
+fn transfer_assets_using_types_and_then_with_missing_transfer_type_fails() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	let beneficiary: Location = Junction::AccountId32 { network: None, id: ALICE.into() }.into();
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let (_, _, usdt_id_location) = set_up_foreign_asset(USDT_PARA_ID, None, ALICE, 42, true);
+		let dest: Location = Parachain(USDT_PARA_ID).into();
+		let assets: Assets =
+			vec![(Location::here(), FEE_AMOUNT).into(), (usdt_id_location, SEND_AMOUNT).into()]
+				.into();
+
+		assert_err!(
+			XcmPallet::transfer_assets_using_types_and_then(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(dest.into()),
+				Box::new(assets.into()),
+				vec![TransferType::LocalReserve],
+				Box::new(VersionedAssetId::from(AssetId(Location::here()))),
+				Box::new(VersionedXcm::from(Xcm::<()>(vec![DepositAsset {
+					assets: AllCounted(2).into(),
+					beneficiary,
+				}]))),
+				Unlimited,
+			),
+			crate::Error::<Test>::TransferTypesMismatch
+		);
+	});
+}
+
+/// Test `transfer_assets_checked` rejects the transfers that the fee hints of the destination
+/// predict to fail to pay for their execution.
+#[test]
+fn transfer_assets_checked_follows_destination_fee_hints() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	let beneficiary: Location = Junction::AccountId32 { network: None, id: BOB.into() }.into();
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let dest = RelayLocation::get();
+		let transfer = |ensure_deliverable: bool| {
+			XcmPallet::transfer_assets_checked(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(dest.clone().into()),
+				Box::new(beneficiary.clone().into()),
+				Box::new((Here, SEND_AMOUNT).into()),
+				0,
+				Unlimited,
+				ensure_deliverable,
+			)
+		};
+		let set_hint = |asset_id: Location, min_fee: Option<u128>| {
+			XcmPallet::set_destination_fee_hint(
+				RuntimeOrigin::root(),
+				Box::new(dest.clone().into()),
+				Box::new(VersionedAssetId::from(AssetId(asset_id))),
+				min_fee,
+			)
+		};
+
+		// destinations without hints accept any fees.
+		assert_ok!(transfer(true));
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - SEND_AMOUNT);
+
+		// only the admin may set hints.
+		assert_err!(
+			XcmPallet::set_destination_fee_hint(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(dest.clone().into()),
+				Box::new(VersionedAssetId::from(AssetId(Here.into()))),
+				Some(1),
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(set_hint(Here.into(), Some(SEND_AMOUNT + 1)));
+		System::assert_last_event(RuntimeEvent::XcmPallet(crate::Event::DestinationFeeHintSet {
+			destination: dest.clone(),
+			asset_id: AssetId(Here.into()),
+			min_fee: Some(SEND_AMOUNT + 1),
+		}));
+		assert_err!(transfer(true), crate::Error::<Test>::DestinationFeesNotMet);
+
+		// a destination with hints does not accept other fee assets.
+		assert_ok!(set_hint(Here.into(), None));
+		assert_ok!(set_hint(Parachain(OTHER_PARA_ID).into(), Some(1)));
+		assert_err!(transfer(true), crate::Error::<Test>::FeeAssetNotAccepted);
+
+		// the transfer goes through without checks, or with enough fees.
+		assert_ok!(transfer(false));
+		assert_ok!(set_hint(Here.into(), Some(SEND_AMOUNT)));
+		assert_ok!(transfer(true));
+		assert_eq!(Balances::total_balance(&ALICE), INITIAL_BALANCE - 3 * SEND_AMOUNT);
+	});
+}
@@ -21,11 +21,11 @@ pub(crate) mod assets_transfer;
 use crate::{
 	migration::data::NeedsMigration,
 	mock::*,
-	pallet::{LockedFungibles, RemoteLockedFungibles, SupportedVersion},
+	pallet::{DestinationFeeHints, LockedFungibles, RemoteLockedFungibles, SupportedVersion},
 	AssetTraps, Config, CurrentMigration, Error, ExecuteControllerWeightInfo,
 	LatestVersionedLocation, Pallet, Queries, QueryStatus, RecordedXcm, RemoteLockedFungibleRecord,
 	ShouldRecordXcm, VersionDiscoveryQueue, VersionMigrationStage, VersionNotifiers,
-	VersionNotifyTargets, WeightInfo,
+	VersionNotifyTargets, WeightInfo, XcmOutcomeBlocks, XcmOutcomePruneCursor,
 };
 use bounded_collections::BoundedVec;
 use frame_support::{
@@ -38,9 +38,10 @@ use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
 use xcm::{latest::QueryResponseInfo, prelude::*};
 use xcm_builder::AllowKnownQueryResponses;
 use xcm_executor::{
-	traits::{Properties, QueryHandler, QueryResponseStatus, ShouldExecute},
+	traits::{Properties, QueryHandler, QueryResponseStatus, RecordXcm, ShouldExecute},
 	XcmExecutor,
 };
+use xcm_runtime_apis::outcomes::XcmOutcomeRecord;
 
 const ALICE: AccountId = AccountId::new([0u8; 32]);
 const BOB: AccountId = AccountId::new([1u8; 32]);
@@ -1422,6 +1423,38 @@ fn migrate_data_to_xcm_version_works() {
 			);
 			assert!(Pallet::<Test>::do_try_state().is_ok());
 		}
+
+		// `DestinationFeeHints` migration
+		{
+			let dest = VersionedLocation::from(Location::new(1, [Parachain(1000)]));
+			let asset_id = VersionedAssetId::from(AssetId(Location::parent()));
+			let key_as_latest = (dest.clone(), asset_id.clone());
+			let key_as_previous = (
+				dest.into_version(previous_version).unwrap(),
+				asset_id.into_version(previous_version).unwrap(),
+			);
+			assert_ne!(key_as_latest, key_as_previous);
+			assert!(!key_as_latest.needs_migration(latest_version));
+			assert!(key_as_previous.needs_migration(latest_version));
+			assert!(!key_as_previous.needs_migration(previous_version));
+
+			// store a hint with a key of the previous version
+			DestinationFeeHints::<Test>::insert(&key_as_previous.0, &key_as_previous.1, 42);
+
+			// trigger migration
+			Pallet::<Test>::migrate_data_to_xcm_version(&mut Weight::zero(), latest_version);
+
+			// the hint is found with a key of the latest version
+			assert_eq!(
+				DestinationFeeHints::<Test>::get(&key_as_previous.0, &key_as_previous.1),
+				None
+			);
+			assert_eq!(
+				DestinationFeeHints::<Test>::get(&key_as_latest.0, &key_as_latest.1),
+				Some(42)
+			);
+			assert_eq!(DestinationFeeHints::<Test>::iter().count(), 1);
+		}
 	})
 }
 
@@ -1456,3 +1489,92 @@ fn record_xcm_works() {
 		assert_eq!(RecordedXcm::<Test>::get(), Some(message.into()));
 	});
 }
+
+#[test]
+fn xcm_outcomes_are_recorded_and_pruned() {
+	let balances = vec![(ALICE, INITIAL_BALANCE)];
+	new_test_ext_with_balances(balances).execute_with(|| {
+		let now = System::block_number();
+		// the pruning starts from the first block in which outcomes are recorded.
+		XcmPallet::on_initialize(now);
+		assert_eq!(XcmOutcomePruneCursor::<Test>::get(), now);
+
+		let weight = BaseXcmWeight::get() * 3;
+		let sender: Location = AccountId32 { network: None, id: ALICE.into() }.into();
+		let message = Xcm(vec![
+			WithdrawAsset((Here, SEND_AMOUNT).into()),
+			buy_execution((Here, SEND_AMOUNT)),
+			DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: AccountId32 { network: None, id: BOB.into() }.into(),
+			},
+		]);
+		let mut success_id = [1u8; 32];
+		let r = XcmExecutor::<XcmConfig>::prepare_and_execute(
+			sender.clone(),
+			message,
+			&mut success_id,
+			weight,
+			Weight::zero(),
+		);
+		assert_eq!(r, Outcome::Complete { used: weight });
+
+		// unpaid message is stopped by the barrier and not recorded
+		let mut barrier_id = [2u8; 32];
+		let r = XcmExecutor::<XcmConfig>::prepare_and_execute(
+			sender,
+			Xcm(vec![ClearOrigin]),
+			&mut barrier_id,
+			BaseXcmWeight::get(),
+			Weight::zero(),
+		);
+		assert_eq!(r, Outcome::Error { error: XcmError::Barrier });
+
+		assert_eq!(
+			XcmPallet::xcm_outcome(success_id),
+			Some(XcmOutcomeRecord { at: now, outcome: Outcome::Complete { used: weight } })
+		);
+		assert_eq!(XcmPallet::xcm_outcome(barrier_id), None);
+		assert_eq!(XcmPallet::xcm_outcome([3u8; 32]), None);
+
+		// outcomes are kept for `XcmOutcomeRetention` blocks
+		XcmPallet::on_initialize(now + 9);
+		assert!(XcmPallet::xcm_outcome(success_id).is_some());
+		XcmPallet::on_initialize(now + 10);
+		assert_eq!(XcmPallet::xcm_outcome(success_id), None);
+		assert_eq!(XcmOutcomeBlocks::<Test>::iter().count(), 0);
+		assert_eq!(XcmOutcomePruneCursor::<Test>::get(), now + 1);
+	});
+}
+
+#[test]
+fn xcm_outcomes_are_pruned_after_the_retention_is_lowered() {
+	new_test_ext_with_balances(vec![]).execute_with(|| {
+		let now = System::block_number();
+		XcmPallet::on_initialize(now);
+		let outcome = Outcome::Complete { used: Weight::zero() };
+		for id in 0..3 {
+			XcmPallet::record_outcome(&[id; 32], &outcome);
+		}
+		System::set_block_number(now + 1);
+		XcmPallet::on_initialize(now + 1);
+		XcmPallet::record_outcome(&[3; 32], &outcome);
+
+		// the outcomes out of the new window are not returned anymore...
+		XcmOutcomeRetention::set(1);
+		assert_eq!(XcmPallet::xcm_outcome([0; 32]), None);
+		assert!(XcmPallet::xcm_outcome([3; 32]).is_some());
+		assert_eq!(XcmOutcomeBlocks::<Test>::iter().count(), 4);
+
+		// ...and are pruned in the next blocks.
+		System::set_block_number(now + 2);
+		XcmPallet::on_initialize(now + 2);
+		assert_eq!(XcmOutcomeBlocks::<Test>::iter().count(), 0);
+		assert_eq!(XcmPallet::xcm_outcome([3; 32]), None);
+
+		// disabling the recording stops the pruning once everything is pruned.
+		XcmOutcomeRetention::set(0);
+		XcmPallet::on_initialize(now + 2);
+		assert!(!XcmOutcomePruneCursor::<Test>::exists());
+	});
+}
@@ -83,6 +83,8 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 }
 
 #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
@@ -98,6 +100,8 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Freezer = ();
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 }
 
 /// Union fungibles implementation for `Assets` and `Balances`.
@@ -319,6 +323,7 @@ impl pallet_xcm::Config for Runtime {
 	type MaxLockers = frame_support::traits::ConstU32<0>;
 	type MaxRemoteLockConsumers = frame_support::traits::ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = frame_support::traits::ConstU32<0>;
 	// How to turn locations into accounts
 	type SovereignAccountOf = LocationToAccountId;
 	// A currency to pay for things and its matcher, we are using the relay token
@@ -0,0 +1,370 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! A `ShouldExecute` implementation configured with a table of rules.
+//!
+//! Rather than nesting barrier types, the rules of [`RuleBasedBarrier`] are plain values that can
+//! be declared in a constant:
+//!
+//! ```
+//! use xcm_builder::{BarrierRule, InstructionSet, JunctionPattern, LocationPattern};
+//!
+//! const RULES: &[BarrierRule] = &[
+//! 	// Sibling parachains may not `Transact`.
+//! 	BarrierRule::deny(LocationPattern::exact(1, &[JunctionPattern::AnyParachain]))
+//! 		.instructions(InstructionSet::TRANSACT),
+//! 	// The relay chain may do anything.
+//! 	BarrierRule::allow(LocationPattern::exact(1, &[])),
+//! ];
+//! ```
+
+use core::{marker::PhantomData, slice};
+use frame_support::traits::{Get, ProcessMessageError};
+use xcm::prelude::*;
+use xcm_executor::traits::{Properties, ShouldExecute};
+
+/// Whether a [`BarrierRule`] lets the messages it matches through or rejects them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleAction {
+	/// The messages are let through.
+	Allow,
+	/// The messages are rejected.
+	Deny,
+}
+
+/// A pattern matching a single junction of a location.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JunctionPattern {
+	/// Matches any junction.
+	Any,
+	/// Matches any `Parachain` junction.
+	AnyParachain,
+	/// Matches the `Parachain` junction with the given id.
+	Parachain(u32),
+	/// Matches any `AccountId32`, `AccountIndex64` or `AccountKey20` junction.
+	AnyAccount,
+	/// Matches the `PalletInstance` junction with the given index.
+	PalletInstance(u8),
+	/// Matches the `GeneralIndex` junction with the given index.
+	GeneralIndex(u128),
+	/// Matches any `Plurality` junction.
+	AnyPlurality,
+	/// Matches the `GlobalConsensus` junction of the given network.
+	GlobalConsensus(NetworkId),
+}
+
+impl JunctionPattern {
+	/// Returns whether `junction` matches the pattern.
+	pub fn matches(&self, junction: &Junction) -> bool {
+		match (self, junction) {
+			(Self::Any, _) |
+			(Self::AnyParachain, Junction::Parachain(_)) |
+			(
+				Self::AnyAccount,
+				AccountId32 { .. } | AccountIndex64 { .. } | AccountKey20 { .. },
+			) |
+			(Self::AnyPlurality, Plurality { .. }) => true,
+			(Self::Parachain(id), Junction::Parachain(other)) => id == other,
+			(Self::PalletInstance(index), Junction::PalletInstance(other)) => index == other,
+			(Self::GeneralIndex(index), Junction::GeneralIndex(other)) => index == other,
+			(Self::GlobalConsensus(network), Junction::GlobalConsensus(other)) => network == other,
+			_ => false,
+		}
+	}
+}
+
+/// A pattern matching locations, junction by junction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LocationPattern {
+	parents: Option<u8>,
+	junctions: &'static [JunctionPattern],
+	exact: bool,
+}
+
+impl LocationPattern {
+	/// Matches any location.
+	pub const ANY: Self = Self { parents: None, junctions: &[], exact: false };
+
+	/// Matches the locations with `parents` and as many junctions as `junctions`, each matching
+	/// the pattern at the same position.
+	pub const fn exact(parents: u8, junctions: &'static [JunctionPattern]) -> Self {
+		Self { parents: Some(parents), junctions, exact: true }
+	}
+
+	/// Matches the locations with `parents` which first junctions match `junctions`, i.e. the
+	/// locations matching [`Self::exact`] and all the locations under them.
+	pub const fn starting_with(parents: u8, junctions: &'static [JunctionPattern]) -> Self {
+		Self { parents: Some(parents), junctions, exact: false }
+	}
+
+	/// Returns whether `location` matches the pattern.
+	pub fn matches(&self, location: &Location) -> bool {
+		let interior = location.interior();
+		self.parents.map_or(true, |parents| parents == location.parent_count()) &&
+			interior.len() >= self.junctions.len() &&
+			(!self.exact || interior.len() == self.junctions.len()) &&
+			self.junctions
+				.iter()
+				.zip(interior.iter())
+				.all(|(pattern, j)| pattern.matches(j))
+	}
+}
+
+/// A pattern matching assets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AssetPattern {
+	/// Matches any asset.
+	Any,
+	/// Matches the assets which id matches the location pattern.
+	Id(LocationPattern),
+}
+
+impl AssetPattern {
+	/// Returns whether `asset` matches the pattern.
+	pub fn matches(&self, asset: &Asset) -> bool {
+		match self {
+			Self::Any => true,
+			Self::Id(pattern) => pattern.matches(&asset.id.0),
+		}
+	}
+}
+
+/// A set of XCM instructions, grouped by what they do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstructionSet(u16);
+
+impl InstructionSet {
+	/// No instruction.
+	pub const NONE: Self = Self(0);
+	/// All instructions.
+	pub const ALL: Self = Self(u16::MAX);
+	/// `WithdrawAsset`, `ReserveAssetDeposited`, `ReceiveTeleportedAsset` and `ClaimAsset`.
+	pub const RECEIVE_ASSETS: Self = Self(1 << 0);
+	/// `TransferAsset`, `TransferReserveAsset`, `DepositAsset`, `DepositReserveAsset`,
+	/// `ExchangeAsset`, `InitiateReserveWithdraw`, `InitiateTeleport`, `InitiateTransfer` and
+	/// `BurnAsset`.
+	pub const MOVE_ASSETS: Self = Self(1 << 1);
+	/// `BuyExecution`, `PayFees`, `RefundSurplus` and `SetFeesMode`.
+	pub const FEES: Self = Self(1 << 2);
+	/// `UnpaidExecution`.
+	pub const UNPAID_EXECUTION: Self = Self(1 << 3);
+	/// `Transact`.
+	pub const TRANSACT: Self = Self(1 << 4);
+	/// `ClearOrigin`, `DescendOrigin`, `UniversalOrigin`, `AliasOrigin` and `ExecuteWithOrigin`.
+	pub const ORIGIN: Self = Self(1 << 5);
+	/// `ExportMessage`.
+	pub const EXPORT_MESSAGE: Self = Self(1 << 6);
+	/// `LockAsset`, `UnlockAsset`, `NoteUnlockable` and `RequestUnlock`.
+	pub const LOCKS: Self = Self(1 << 7);
+	/// `HrmpNewChannelOpenRequest`, `HrmpChannelAccepted` and `HrmpChannelClosing`.
+	pub const HRMP: Self = Self(1 << 8);
+	/// `SubscribeVersion` and `UnsubscribeVersion`.
+	pub const VERSION_SUBSCRIPTIONS: Self = Self(1 << 9);
+	/// `QueryResponse`, `ReportError`, `ReportHolding`, `QueryPallet` and `ReportTransactStatus`.
+	pub const QUERIES: Self = Self(1 << 10);
+	/// All the other instructions, e.g. `SetTopic` or `ExpectAsset`.
+	pub const OTHER: Self = Self(1 << 11);
+
+	/// Returns the set of the instructions in `self` or in `other`.
+	pub const fn union(self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+
+	/// Returns whether `instruction` is in the set.
+	pub fn contains<Call>(&self, instruction: &Instruction<Call>) -> bool {
+		self.0 & Self::of(instruction).0 != 0
+	}
+
+	fn of<Call>(instruction: &Instruction<Call>) -> Self {
+		match instruction {
+			WithdrawAsset(..) |
+			ReserveAssetDeposited(..) |
+			ReceiveTeleportedAsset(..) |
+			ClaimAsset { .. } => Self::RECEIVE_ASSETS,
+			TransferAsset { .. } |
+			TransferReserveAsset { .. } |
+			DepositAsset { .. } |
+			DepositReserveAsset { .. } |
+			ExchangeAsset { .. } |
+			InitiateReserveWithdraw { .. } |
+			InitiateTeleport { .. } |
+			InitiateTransfer { .. } |
+			BurnAsset(..) => Self::MOVE_ASSETS,
+			BuyExecution { .. } | PayFees { .. } | RefundSurplus | SetFeesMode { .. } => Self::FEES,
+			UnpaidExecution { .. } => Self::UNPAID_EXECUTION,
+			Transact { .. } => Self::TRANSACT,
+			ClearOrigin |
+			DescendOrigin(..) |
+			UniversalOrigin(..) |
+			AliasOrigin(..) |
+			ExecuteWithOrigin { .. } => Self::ORIGIN,
+			ExportMessage { .. } => Self::EXPORT_MESSAGE,
+			LockAsset { .. } |
+			UnlockAsset { .. } |
+			NoteUnlockable { .. } |
+			RequestUnlock { .. } => Self::LOCKS,
+			HrmpNewChannelOpenRequest { .. } |
+			HrmpChannelAccepted { .. } |
+			HrmpChannelClosing { .. } => Self::HRMP,
+			SubscribeVersion { .. } | UnsubscribeVersion => Self::VERSION_SUBSCRIPTIONS,
+			QueryResponse { .. } |
+			ReportError(..) |
+			ReportHolding { .. } |
+			QueryPallet { .. } |
+			ReportTransactStatus(..) => Self::QUERIES,
+			_ => Self::OTHER,
+		}
+	}
+}
+
+/// A rule of [`RuleBasedBarrier`].
+///
+/// A rule matches the messages of the origins matching [`Self::origin`]. A rule that denies
+/// messages only matches the messages containing any of [`Self::instructions`] and carrying any
+/// asset matching [`Self::assets`]. A rule that allows messages only matches the messages made of
+/// [`Self::instructions`] only, which carry no other assets than the ones matching
+/// [`Self::assets`]. Instructions nested in `SetErrorHandler`, `SetAppendix` and
+/// `ExecuteWithOrigin` are part of the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BarrierRule {
+	/// Whether the messages matching the rule are let through or rejected.
+	pub action: RuleAction,
+	/// The origins the rule applies to.
+	pub origin: LocationPattern,
+	/// The instructions the rule applies to.
+	pub instructions: InstructionSet,
+	/// The assets the rule applies to, as carried by the instructions that withdraw, receive,
+	/// transfer, burn or lock assets, or pay fees.
+	pub assets: AssetPattern,
+}
+
+impl BarrierRule {
+	/// A rule that allows any message of the origins matching `origin`.
+	pub const fn allow(origin: LocationPattern) -> Self {
+		Self {
+			action: RuleAction::Allow,
+			origin,
+			instructions: InstructionSet::ALL,
+			assets: AssetPattern::Any,
+		}
+	}
+
+	/// A rule that denies any message of the origins matching `origin`.
+	pub const fn deny(origin: LocationPattern) -> Self {
+		Self {
+			action: RuleAction::Deny,
+			origin,
+			instructions: InstructionSet::ALL,
+			assets: AssetPattern::Any,
+		}
+	}
+
+	/// Restricts the rule to `instructions`.
+	pub const fn instructions(self, instructions: InstructionSet) -> Self {
+		Self { instructions, ..self }
+	}
+
+	/// Restricts the rule to the assets matching `assets`.
+	pub const fn assets(self, assets: AssetPattern) -> Self {
+		Self { assets, ..self }
+	}
+
+	/// Returns whether the message made of `instructions` from `origin` matches the rule.
+	pub fn matches<Call>(&self, origin: &Location, instructions: &[Instruction<Call>]) -> bool {
+		if !self.origin.matches(origin) {
+			return false
+		}
+
+		let restricted_assets = self.assets != AssetPattern::Any;
+		match self.action {
+			RuleAction::Allow => !any_instruction(instructions, &|instruction| {
+				!self.instructions.contains(instruction) ||
+					(restricted_assets &&
+						!assets_of(instruction)
+							.iter()
+							.all(|asset| self.assets.matches(asset)))
+			}),
+			RuleAction::Deny =>
+				any_instruction(instructions, &|instruction| {
+					self.instructions.contains(instruction)
+				}) && (!restricted_assets ||
+					any_instruction(instructions, &|instruction| {
+						assets_of(instruction).iter().any(|asset| self.assets.matches(asset))
+					})),
+		}
+	}
+}
+
+/// Returns whether any of `instructions`, or of the instructions nested in them, satisfies `f`.
+fn any_instruction<Call>(
+	instructions: &[Instruction<Call>],
+	f: &impl Fn(&Instruction<Call>) -> bool,
+) -> bool {
+	instructions.iter().any(|instruction| {
+		f(instruction) ||
+			match instruction {
+				SetErrorHandler(xcm) | SetAppendix(xcm) | ExecuteWithOrigin { xcm, .. } =>
+					any_instruction(xcm.inner(), f),
+				_ => false,
+			}
+	})
+}
+
+/// Returns the assets carried by `instruction`.
+fn assets_of<Call>(instruction: &Instruction<Call>) -> &[Asset] {
+	match instruction {
+		WithdrawAsset(assets) |
+		ReserveAssetDeposited(assets) |
+		ReceiveTeleportedAsset(assets) |
+		BurnAsset(assets) |
+		ClaimAsset { assets, .. } |
+		TransferAsset { assets, .. } |
+		TransferReserveAsset { assets, .. } => assets.inner(),
+		BuyExecution { fees: asset, .. } | PayFees { asset } | LockAsset { asset, .. } =>
+			slice::from_ref(asset),
+		_ => &[],
+	}
+}
+
+/// Barrier that lets a message through or rejects it according to the first rule of `Rules` that
+/// matches it.
+///
+/// The messages that no rule matches are passed on to `Otherwise`, which can be `()` to reject
+/// them, or the barrier the rules are added to.
+pub struct RuleBasedBarrier<Rules, Otherwise>(PhantomData<(Rules, Otherwise)>);
+impl<Rules, Otherwise> ShouldExecute for RuleBasedBarrier<Rules, Otherwise>
+where
+	Rules: Get<&'static [BarrierRule]>,
+	Otherwise: ShouldExecute,
+{
+	fn should_execute<RuntimeCall>(
+		origin: &Location,
+		instructions: &mut [Instruction<RuntimeCall>],
+		max_weight: Weight,
+		properties: &mut Properties,
+	) -> Result<(), ProcessMessageError> {
+		log::trace!(
+			target: "xcm::barriers",
+			"RuleBasedBarrier origin: {:?}, instructions: {:?}, max_weight: {:?}, properties: {:?}",
+			origin, instructions, max_weight, properties,
+		);
+		match Rules::get().iter().find(|rule| rule.matches(origin, instructions)) {
+			Some(rule) if rule.action == RuleAction::Allow => Ok(()),
+			Some(_) => Err(ProcessMessageError::Unsupported),
+			None => Otherwise::should_execute(origin, instructions, max_weight, properties),
+		}
+	}
+}
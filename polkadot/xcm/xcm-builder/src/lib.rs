@@ -38,6 +38,12 @@ pub use asset_conversion::{
 mod asset_exchange;
 pub use asset_exchange::SingleAssetExchangeAdapter;
 
+mod barrier_rules;
+pub use barrier_rules::{
+	AssetPattern, BarrierRule, InstructionSet, JunctionPattern, LocationPattern, RuleAction,
+	RuleBasedBarrier,
+};
+
 mod barriers;
 pub use barriers::{
 	AllowExplicitUnpaidExecutionFrom, AllowHrmpNotificationsFromRelayChain,
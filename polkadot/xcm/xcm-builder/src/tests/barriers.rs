@@ -532,3 +532,91 @@ fn allow_hrmp_notifications_from_relay_chain_should_work() {
 		Ok(()),
 	);
 }
+
+#[test]
+fn rule_based_barrier_should_work() {
+	const SIBLING: LocationPattern = LocationPattern::exact(1, &[JunctionPattern::AnyParachain]);
+	const RELAY_ASSET: AssetPattern = AssetPattern::Id(LocationPattern::exact(1, &[]));
+	const RULES: &[BarrierRule] = &[
+		// sibling parachains may not transact.
+		BarrierRule::deny(SIBLING).instructions(InstructionSet::TRANSACT),
+		// but may move the relay chain asset around.
+		BarrierRule::allow(SIBLING)
+			.instructions(
+				InstructionSet::RECEIVE_ASSETS
+					.union(InstructionSet::MOVE_ASSETS)
+					.union(InstructionSet::FEES),
+			)
+			.assets(RELAY_ASSET),
+		// the accounts of the relay chain may not receive anything.
+		BarrierRule::deny(LocationPattern::starting_with(1, &[JunctionPattern::AnyAccount]))
+			.instructions(InstructionSet::RECEIVE_ASSETS),
+		// anything goes for the relay chain itself.
+		BarrierRule::allow(LocationPattern::exact(1, &[])),
+	];
+	parameter_types! {
+		pub Rules: &'static [BarrierRule] = RULES;
+	}
+
+	let assert_should_execute = |mut xcm: Vec<Instruction<()>>, origin, expected_result| {
+		assert_eq!(
+			RuleBasedBarrier::<Rules, ()>::should_execute(
+				&origin,
+				&mut xcm,
+				Weight::from_parts(10, 10),
+				&mut props(Weight::zero()),
+			),
+			expected_result
+		);
+	};
+	let sibling = Location::new(1, [Parachain(1000)]);
+	let transfer = |id: Location| {
+		vec![
+			WithdrawAsset((id.clone(), 100).into()),
+			BuyExecution { fees: (id.clone(), 1).into(), weight_limit: Unlimited },
+			DepositAsset { assets: AllCounted(1).into(), beneficiary: Here.into() },
+		]
+	};
+	let transact = Transact {
+		origin_kind: OriginKind::Native,
+		call: Vec::new().into(),
+		fallback_max_weight: None,
+	};
+
+	// the first matching rule decides.
+	assert_should_execute(transfer(Parent.into()), sibling.clone(), Ok(()));
+	assert_should_execute(
+		transfer(Location::new(1, [Parachain(1000)])),
+		sibling.clone(),
+		Err(ProcessMessageError::Unsupported),
+	);
+	assert_should_execute(
+		vec![SetAppendix(Xcm(vec![transact.clone()]))],
+		sibling.clone(),
+		Err(ProcessMessageError::Unsupported),
+	);
+	assert_should_execute(vec![transact], Parent.into(), Ok(()));
+	assert_should_execute(
+		transfer(Parent.into()),
+		Location::new(1, [AccountId32 { network: None, id: [1; 32] }]),
+		Err(ProcessMessageError::Unsupported),
+	);
+
+	// messages that no rule matches are rejected by `()`.
+	assert_should_execute(
+		vec![ClearOrigin],
+		Location::new(1, [AccountId32 { network: None, id: [1; 32] }]),
+		Err(ProcessMessageError::Unsupported),
+	);
+	assert_should_execute(vec![ClearOrigin], sibling, Err(ProcessMessageError::Unsupported));
+	assert_should_execute(vec![ClearOrigin], Here.into(), Err(ProcessMessageError::Unsupported));
+	assert_eq!(
+		RuleBasedBarrier::<Rules, TakeWeightCredit>::should_execute(
+			&Here.into(),
+			&mut [Instruction::<()>::ClearOrigin],
+			Weight::from_parts(10, 10),
+			&mut props(Weight::from_parts(10, 10)),
+		),
+		Ok(())
+	);
+}
@@ -222,7 +222,11 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 		mut message: Xcm<Config::RuntimeCall>,
 	) -> Result<Self::Prepared, Xcm<Config::RuntimeCall>> {
 		match Config::Weigher::weight(&mut message) {
-			Ok(weight) => Ok(WeighedMessage(weight, message)),
+			// recording the outcome is part of executing the message.
+			Ok(weight) => Ok(WeighedMessage(
+				weight.saturating_add(Config::XcmRecorder::record_outcome_weight()),
+				message,
+			)),
 			Err(_) => Err(message),
 		}
 	}
@@ -283,7 +287,9 @@ impl<Config: config::Config> ExecuteXcm<Config::RuntimeCall> for XcmExecutor<Con
 			}
 		}
 
-		vm.post_process(xcm_weight)
+		let outcome = vm.post_process(xcm_weight);
+		Config::XcmRecorder::record_outcome(id, &outcome);
+		outcome
 	}
 
 	fn charge_fees(origin: impl Into<Location>, fees: Assets) -> XcmResult {
@@ -16,7 +16,7 @@
 
 //! Trait for recording XCMs and a dummy implementation.
 
-use xcm::latest::Xcm;
+use xcm::latest::{Outcome, Weight, Xcm, XcmHash};
 
 /// Trait for recording XCMs.
 pub trait RecordXcm {
@@ -29,6 +29,14 @@ pub trait RecordXcm {
 	fn recorded_xcm() -> Option<Xcm<()>>;
 	/// Record `xcm`.
 	fn record(xcm: Xcm<()>);
+	/// Record the `outcome` of executing the XCM identified by `message_id`.
+	///
+	/// Unlike [`Self::record`], this is called for every XCM which passed the barrier.
+	fn record_outcome(_message_id: &XcmHash, _outcome: &Outcome) {}
+	/// The weight of [`Self::record_outcome`], added to the weight of every XCM.
+	fn record_outcome_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl RecordXcm for () {
@@ -0,0 +1,50 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for checking whether an XCM would pass the barrier of this chain.
+
+use codec::{Decode, Encode};
+use frame_support::{pallet_prelude::TypeInfo, traits::ProcessMessageError};
+use xcm::{VersionedLocation, VersionedXcm};
+
+sp_api::decl_runtime_apis! {
+	/// API for checking whether a message would be let through by the barrier of this chain,
+	/// without executing it.
+	pub trait XcmBarrierApi {
+		/// Returns whether `message` would pass the barrier if it were received from `origin`.
+		///
+		/// The barrier is given the weight of the message and no weight credit, as for a message
+		/// received from another chain.
+		///
+		/// # Arguments
+		/// * `origin`: `VersionedLocation`.
+		/// * `message`: `VersionedXcm<()>`.
+		fn check_barrier(origin: VersionedLocation, message: VersionedXcm<()>) -> Result<(), Error>;
+	}
+}
+
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub enum Error {
+	/// Converting a versioned data structure from one version to another failed.
+	#[codec(index = 0)]
+	VersionedConversionFailed,
+	/// The weight of the message could not be computed.
+	#[codec(index = 1)]
+	WeightNotComputable,
+	/// The barrier rejected the message.
+	#[codec(index = 2)]
+	Rejected(ProcessMessageError),
+}
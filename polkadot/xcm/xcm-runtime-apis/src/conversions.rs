@@ -16,19 +16,41 @@
 
 //! Contains runtime APIs for useful conversions, such as between XCM `Location` and `AccountId`.
 
+use alloc::{string::String, vec::Vec};
 use codec::{Decode, Encode};
+use frame_support::{sp_runtime::traits::Convert, traits::Get};
 use scale_info::TypeInfo;
-use xcm::VersionedLocation;
+use xcm::{latest::Location, VersionedLocation};
 use xcm_executor::traits::ConvertLocation;
 
 sp_api::decl_runtime_apis! {
 	/// API for useful conversions between XCM `Location` and `AccountId`.
+	#[api_version(2)]
 	pub trait LocationToAccountApi<AccountId> where AccountId: Decode {
 		/// Converts `Location` to `AccountId`.
 		fn convert_location(location: VersionedLocation) -> Result<AccountId, Error>;
+
+		/// Derives the accounts of `Location` under each of the account derivation schemes of the
+		/// runtime that support it, e.g. to pre-fund the account a cross-chain call will be
+		/// dispatched from.
+		///
+		/// The accounts are returned in the order of the schemes, so the first one is usually the
+		/// one `convert_location` returns.
+		#[api_version(2)]
+		fn derived_accounts(location: VersionedLocation) -> Result<Vec<DerivedAccount>, Error>;
 	}
 }
 
+/// An account derived from a `Location` by an account derivation scheme of the runtime.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct DerivedAccount {
+	/// The name of the derivation scheme.
+	pub scheme: String,
+	/// The raw bytes of the account, e.g. 32 bytes for an `AccountId32` or 20 bytes for an
+	/// Ethereum address.
+	pub account: Vec<u8>,
+}
+
 #[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
 pub enum Error {
 	/// Requested `Location` is not supported by the local conversion.
@@ -54,3 +76,61 @@ impl<AccountId: Decode, Conversion: ConvertLocation<AccountId>>
 		Conversion::convert_location(&location).ok_or(Error::Unsupported)
 	}
 }
+
+/// A set of account derivation schemes, implemented for [`DerivationScheme`] and tuples of them.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait DeriveAccounts {
+	/// Append the accounts derived from `location` by the schemes that support it to `accounts`.
+	fn derive_accounts(location: &Location, accounts: &mut Vec<DerivedAccount>);
+}
+
+/// An account derivation scheme called `Name`, deriving an `AccountId` from a `Location` with
+/// `Conversion` and mapping it to its raw bytes with `Mapping`.
+pub struct DerivationScheme<Name, Conversion, AccountId, Mapping = EncodedAccount>(
+	core::marker::PhantomData<(Name, Conversion, AccountId, Mapping)>,
+);
+impl<
+		Name: Get<&'static str>,
+		Conversion: ConvertLocation<AccountId>,
+		AccountId,
+		Mapping: Convert<AccountId, Vec<u8>>,
+	> DeriveAccounts for DerivationScheme<Name, Conversion, AccountId, Mapping>
+{
+	fn derive_accounts(location: &Location, accounts: &mut Vec<DerivedAccount>) {
+		if let Some(account) = Conversion::convert_location(location) {
+			accounts.push(DerivedAccount {
+				scheme: Name::get().into(),
+				account: Mapping::convert(account),
+			});
+		}
+	}
+}
+
+/// Maps an account to its SCALE encoding, which are its raw bytes for `AccountId32` or `H160`.
+pub struct EncodedAccount;
+impl<AccountId: Encode> Convert<AccountId, Vec<u8>> for EncodedAccount {
+	fn convert(account: AccountId) -> Vec<u8> {
+		account.encode()
+	}
+}
+
+/// Maps a 32 byte account to the 20 byte Ethereum address that `pallet-revive` maps it to, i.e.
+/// its first 20 bytes.
+pub struct AccountId32ToAddress;
+impl<AccountId: AsRef<[u8; 32]>> Convert<AccountId, Vec<u8>> for AccountId32ToAddress {
+	fn convert(account: AccountId) -> Vec<u8> {
+		account.as_ref()[..20].to_vec()
+	}
+}
+
+/// A helper implementation that can be used for `LocationToAccountApi::derived_accounts`
+/// implementations, given the derivation schemes of the runtime.
+pub struct DerivedAccountsHelper<Schemes>(core::marker::PhantomData<Schemes>);
+impl<Schemes: DeriveAccounts> DerivedAccountsHelper<Schemes> {
+	pub fn derived_accounts(location: VersionedLocation) -> Result<Vec<DerivedAccount>, Error> {
+		let location = location.try_into().map_err(|_| Error::VersionedConversionFailed)?;
+		let mut accounts = Vec::new();
+		Schemes::derive_accounts(&location, &mut accounts);
+		Ok(accounts)
+	}
+}
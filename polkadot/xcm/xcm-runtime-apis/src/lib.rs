@@ -23,10 +23,18 @@ extern crate alloc;
 /// Exposes runtime APIs for various XCM-related conversions.
 pub mod conversions;
 
+/// Barrier check API.
+/// Given an origin and an XCM program, it returns whether the barrier would let it through.
+pub mod barrier;
+
 /// Dry-run API.
 /// Given an extrinsic or an XCM program, it returns the outcome of its execution.
 pub mod dry_run;
 
+/// Outcome query API.
+/// Given the ID of an XCM executed on this chain, it returns the recorded outcome of its execution.
+pub mod outcomes;
+
 /// Fee estimation API.
 /// Given an XCM program, it will return the fees needed to execute it properly or send it.
 pub mod fees;
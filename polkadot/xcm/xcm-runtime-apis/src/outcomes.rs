@@ -0,0 +1,45 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API definition for querying the outcome of XCMs executed on this chain.
+
+use codec::{Codec, Decode, Encode};
+use frame_support::pallet_prelude::TypeInfo;
+use xcm::latest::{Outcome, XcmHash};
+
+/// Outcome of an XCM executed on this chain, as returned by [`XcmOutcomeApi`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+pub struct XcmOutcomeRecord<BlockNumber> {
+	/// Block in which the XCM was executed.
+	pub at: BlockNumber,
+	/// Outcome of the execution, with the weight used and the error, if any.
+	pub outcome: Outcome,
+}
+
+sp_api::decl_runtime_apis! {
+	/// API for querying why an XCM failed (or that it succeeded) without scraping events.
+	pub trait XcmOutcomeApi<BlockNumber> where BlockNumber: Codec {
+		/// Returns the recorded outcome of the XCM identified by `message_id`.
+		///
+		/// The ID is the topic of the message if it has one (see `SetTopic`), or the ID it was
+		/// executed with otherwise. Outcomes are only kept for a limited number of blocks, so
+		/// `None` is returned for older or unknown messages.
+		///
+		/// # Arguments
+		/// * `message_id`: `XcmHash`.
+		fn xcm_outcome(message_id: XcmHash) -> Option<XcmOutcomeRecord<BlockNumber>>;
+	}
+}
@@ -17,7 +17,7 @@
 mod mock;
 
 use frame_support::{
-	assert_err, assert_ok,
+	assert_err, assert_ok, parameter_types,
 	sp_runtime::{
 		testing::H256,
 		traits::{IdentifyAccount, Verify},
@@ -28,6 +28,7 @@ use mock::*;
 use sp_api::ProvideRuntimeApi;
 use xcm::prelude::*;
 use xcm_runtime_apis::conversions::{
+	AccountId32ToAddress, DerivationScheme, DerivedAccount, DerivedAccountsHelper,
 	Error as LocationToAccountApiError, LocationToAccountApi, LocationToAccountHelper,
 };
 
@@ -81,3 +82,43 @@ fn location_to_account_helper_with_multi_signature_works() {
 		AccountId32::from(hex_literal::hex!("506172656e740000000000000000000000000000000000000000000000000000"))
 	);
 }
+
+#[test]
+fn derived_accounts_helper_works() {
+	parameter_types! {
+		pub const ParentScheme: &'static str = "ParentIsPreset";
+		pub const AliasesScheme: &'static str = "AccountId32Aliases";
+		pub const AddressScheme: &'static str = "Address";
+	}
+	type Aliases = xcm_builder::AccountId32Aliases<(), AccountId32>;
+	type Schemes = (
+		DerivationScheme<ParentScheme, xcm_builder::ParentIsPreset<AccountId32>, AccountId32>,
+		DerivationScheme<AliasesScheme, Aliases, AccountId32>,
+		DerivationScheme<AddressScheme, Aliases, AccountId32, AccountId32ToAddress>,
+	);
+
+	// No scheme supports `Here`.
+	assert_eq!(
+		DerivedAccountsHelper::<Schemes>::derived_accounts(Location::here().into_versioned()),
+		Ok(vec![])
+	);
+
+	// Only the parent scheme supports `Parent`.
+	let parent =
+		hex_literal::hex!("506172656e740000000000000000000000000000000000000000000000000000");
+	assert_eq!(
+		DerivedAccountsHelper::<Schemes>::derived_accounts(Location::parent().into_versioned()),
+		Ok(vec![DerivedAccount { scheme: "ParentIsPreset".into(), account: parent.to_vec() }])
+	);
+
+	// An account is derived both as 32 bytes and as its 20 bytes address.
+	let id = [1u8; 32];
+	let location = Location::new(0, [Junction::AccountId32 { network: None, id }]);
+	assert_eq!(
+		DerivedAccountsHelper::<Schemes>::derived_accounts(location.into_versioned()),
+		Ok(vec![
+			DerivedAccount { scheme: "AccountId32Aliases".into(), account: id.to_vec() },
+			DerivedAccount { scheme: "Address".into(), account: id[..20].to_vec() },
+		])
+	);
+}
@@ -357,6 +357,7 @@ impl pallet_xcm::Config for TestRuntime {
 	type MaxLockers = ConstU32<0>;
 	type MaxRemoteLockConsumers = ConstU32<0>;
 	type RemoteLockConsumerIdentifier = ();
+	type XcmOutcomeRetention = ConstU32<10>;
 	type WeightInfo = TestWeightInfo;
 }
 
@@ -95,9 +95,15 @@ pub enum Subcommand {
 	/// Remove the whole chain.
 	PurgeChain(sc_cli::PurgeChainCmd),
 
+	/// Re-execute historical blocks and report what each extrinsic did.
+	ReplayBlocks(sc_cli::ReplayBlocksCmd),
+
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Dry-run the upgrade to a new runtime against the current state.
+	DryRunUpgrade(sc_cli::DryRunUpgradeCmd),
+
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
 }
@@ -25,6 +25,7 @@ use crate::{
 	Cli, Subcommand,
 };
 use frame_benchmarking_cli::*;
+use frame_try_runtime::UpgradeDryRunReport;
 use kitchensink_runtime::{ExistentialDeposit, RuntimeApi};
 use node_primitives::Block;
 use sc_cli::{Result, SubstrateCli};
@@ -211,6 +212,22 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(cmd)?;
 			runner.sync_run(|config| cmd.run(config.database))
 		},
+		Some(Subcommand::ReplayBlocks(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let PartialComponents { client, task_manager, .. } = new_partial(&config, None)?;
+				Ok((cmd.run(client), task_manager))
+			})
+		},
+		Some(Subcommand::DryRunUpgrade(cmd)) => {
+			let runner = cli.create_runner(cmd)?;
+			runner.async_run(|config| {
+				let executor =
+					sc_service::new_wasm_executor::<service::HostFunctions>(&config.executor);
+				let PartialComponents { client, task_manager, .. } = new_partial(&config, None)?;
+				Ok((cmd.run::<Block, _, _, UpgradeDryRunReport>(client, executor), task_manager))
+			})
+		},
 		Some(Subcommand::Revert(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			runner.async_run(|config| {
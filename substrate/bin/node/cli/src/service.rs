@@ -346,6 +346,9 @@ pub fn new_partial(
 						beefy_best_block_stream: beefy_rpc_links
 							.from_voter_best_beefy_stream
 							.clone(),
+						beefy_justif_request_sender: beefy_rpc_links
+							.to_voter_justif_request_sender
+							.clone(),
 						subscription_executor,
 					},
 					statement_store: rpc_statement_store.clone(),
@@ -417,6 +420,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let enable_offchain_worker = config.offchain_worker.enabled;
+	let http_response_size_limit = config.offchain_worker.http_response_size_limit;
 
 	let hwbench = (!disable_hardware_benchmarks)
 		.then(|| {
@@ -790,6 +794,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 				network_provider: Arc::new(network.clone()),
 				is_validator: role.is_authority(),
 				enable_http_requests: true,
+				http_response_size_limit,
 				custom_extensions: move |_| {
 					vec![Box::new(statement_store.clone().as_statement_store_ext()) as Box<_>]
 				},
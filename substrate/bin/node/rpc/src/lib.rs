@@ -38,7 +38,7 @@ use node_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
 use sc_client_api::AuxStore;
 use sc_consensus_babe::BabeWorkerHandle;
 use sc_consensus_beefy::communication::notification::{
-	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
+	BeefyBestBlockStream, BeefyJustificationRequestSender, BeefyVersionedFinalityProofStream,
 };
 use sc_consensus_grandpa::{
 	FinalityProofProvider, GrandpaJustificationStream, SharedAuthoritySet, SharedVoterState,
@@ -82,6 +82,8 @@ pub struct BeefyDeps<AuthorityId: AuthorityIdBound> {
 	pub beefy_finality_proof_stream: BeefyVersionedFinalityProofStream<Block, AuthorityId>,
 	/// Receives notifications about best block events from BEEFY.
 	pub beefy_best_block_stream: BeefyBestBlockStream<Block>,
+	/// Sends requests for BEEFY justifications to BEEFY.
+	pub beefy_justif_request_sender: BeefyJustificationRequestSender<Block, AuthorityId>,
 	/// Executor to drive the subscription manager in the BEEFY RPC handler.
 	pub subscription_executor: SubscriptionTaskExecutor,
 }
@@ -139,7 +141,8 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
-	P: TransactionPool + 'static,
+	C::Api: substrate_frame_rpc_system::ExtrinsicOutcomeApi<Block>,
+	P: TransactionPool<Block = Block> + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
@@ -157,7 +160,7 @@ where
 		statement::StatementApiServer,
 	};
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
-	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use substrate_frame_rpc_system::{BlockDryRun, BlockDryRunApiServer, System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
 
 	let mut io = RpcModule::new(());
@@ -171,7 +174,8 @@ where
 		finality_provider,
 	} = grandpa;
 
-	io.merge(System::new(client.clone(), pool).into_rpc())?;
+	io.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
+	io.merge(BlockDryRun::new(client.clone(), pool).into_rpc())?;
 	// Making synchronous calls in light client freezes the browser currently,
 	// more context: https://github.com/paritytech/substrate/pull/3480
 	// These RPCs should use an asynchronous caller instead.
@@ -218,6 +222,7 @@ where
 		Beefy::<Block, AuthorityId>::new(
 			beefy.beefy_finality_proof_stream,
 			beefy.beefy_best_block_stream,
+			beefy.beefy_justif_request_sender,
 			beefy.subscription_executor,
 		)?
 		.into_rpc(),
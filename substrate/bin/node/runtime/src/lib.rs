@@ -226,6 +226,7 @@ const MAXIMUM_BLOCK_WEIGHT: Weight =
 
 parameter_types! {
 	pub const BlockHashCount: BlockNumber = 2400;
+	pub const EventTopicRetention: BlockNumber = 100;
 	pub const Version: RuntimeVersion = VERSION;
 	pub RuntimeBlockLength: BlockLength =
 		BlockLength::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
@@ -321,6 +322,8 @@ parameter_types! {
 	pub const ExtendDuration: BlockNumber = 2 * HOURS;
 	pub const ExtendDepositAmount: Balance = 1_000_000 * DOLLARS;
 	pub const ReleaseDelay: u32 = 2 * DAYS;
+	pub const MaxTriggerDuration: BlockNumber = HOURS;
+	pub const FaultWindow: BlockNumber = DAYS;
 }
 
 impl pallet_safe_mode::Config for Runtime {
@@ -338,6 +341,8 @@ impl pallet_safe_mode::Config for Runtime {
 	type ForceDepositOrigin = EnsureRoot<AccountId>;
 	type ReleaseDelay = ReleaseDelay;
 	type Notify = ();
+	type MaxTriggerDuration = MaxTriggerDuration;
+	type FaultWindow = FaultWindow;
 	type WeightInfo = pallet_safe_mode::weights::SubstrateWeight<Runtime>;
 }
 
@@ -353,6 +358,7 @@ impl frame_system::Config for Runtime {
 	type Lookup = Indices;
 	type Block = Block;
 	type BlockHashCount = BlockHashCount;
+	type EventTopicRetention = EventTopicRetention;
 	type Version = Version;
 	type AccountData = pallet_balances::AccountData<Balance>;
 	type SystemWeightInfo = frame_system::weights::SubstrateWeight<Runtime>;
@@ -391,6 +397,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<{ 16 * 1024 }>;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
 }
@@ -481,6 +488,36 @@ impl pallet_proxy::Config for Runtime {
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
 	type BlockNumberProvider = frame_system::Pallet<Runtime>;
+	type CallValue = ProxyTransferValue;
+}
+
+/// The value of balance transfers made by a proxy, including those in batches.
+pub struct ProxyTransferValue;
+impl pallet_proxy::ProxyCallValue<RuntimeCall, Balance> for ProxyTransferValue {
+	fn value(call: &RuntimeCall) -> Balance {
+		match call {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { value, .. }) |
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive { value, .. }) =>
+				*value,
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+				calls.iter().fold(0, |total, call| total.saturating_add(Self::value(call))),
+			_ => 0,
+		}
+	}
+}
+
+impl frame_support::traits::GetInnerCalls<RuntimeCall> for RuntimeCall {
+	fn get_inner_calls(&self) -> Vec<&RuntimeCall> {
+		use frame_support::traits::GetInnerCalls;
+		match self {
+			RuntimeCall::Utility(call) => call.get_inner_calls(),
+			RuntimeCall::Proxy(call) => call.get_inner_calls(),
+			RuntimeCall::Multisig(call) => call.get_inner_calls(),
+			_ => Vec::new(),
+		}
+	}
 }
 
 parameter_types! {
@@ -741,10 +778,15 @@ impl pallet_staking::Config for Runtime {
 	type MaxUnlockingChunks = ConstU32<32>;
 	type MaxControllersInDeprecationBatch = MaxControllersInDeprecationBatch;
 	type HistoryDepth = HistoryDepth;
-	type EventListeners = NominationPools;
+	type EventListeners = (NominationPools, ConvictionVoting);
 	type WeightInfo = pallet_staking::weights::SubstrateWeight<Runtime>;
 	type BenchmarkingConfig = StakingBenchmarkingConfig;
 	type DisablingStrategy = pallet_staking::UpToLimitWithReEnablingDisablingStrategy;
+	type RemotePayout = ();
+	type EraPointsReward = ();
+	type ExposureKeeper = ();
+	type MaxPendingCandidates = ConstU32<64>;
+	type BondActivationDelay = ConstU32<0>;
 }
 
 impl pallet_fast_unstake::Config for Runtime {
@@ -864,6 +906,7 @@ impl pallet_election_provider_multi_phase::MinerConfig for Runtime {
 	type MaxLength = MinerMaxLength;
 	type MaxWeight = MinerMaxWeight;
 	type Solution = NposSolution16;
+	type TargetCapacity = ();
 	type MaxVotesPerVoter =
 	<<Self as pallet_election_provider_multi_phase::Config>::DataProvider as ElectionDataProvider>::MaxVotesPerVoter;
 	type MaxWinners = MaxActiveValidators;
@@ -923,6 +966,7 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type BagThresholds = BagThresholds;
 	type Score = VoteWeight;
 	type WeightInfo = pallet_bags_list::weights::SubstrateWeight<Runtime>;
+	type MaxScoreRecalculations = ConstU32<64>;
 }
 
 parameter_types! {
@@ -1047,6 +1091,33 @@ impl pallet_referenda::Config for Runtime {
 	type Preimages = Preimage;
 }
 
+/// The calls dispatched by batches, derivative and `dispatch_as` calls and whitelisted calls, used
+/// to preview referendum enactments.
+pub struct GovernanceCallExpander;
+impl pallet_referenda::ExpandCall<RuntimeCall, Hash> for GovernanceCallExpander {
+	fn nested_calls(call: &RuntimeCall) -> Vec<pallet_referenda::NestedCall<RuntimeCall, Hash>> {
+		use pallet_referenda::NestedCall;
+		match call {
+			RuntimeCall::Utility(pallet_utility::Call::batch { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::batch_all { calls }) |
+			RuntimeCall::Utility(pallet_utility::Call::force_batch { calls }) =>
+				calls.iter().cloned().map(NestedCall::Inline).collect(),
+			RuntimeCall::Utility(pallet_utility::Call::as_derivative { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::dispatch_as { call, .. }) |
+			RuntimeCall::Utility(pallet_utility::Call::with_weight { call, .. }) |
+			RuntimeCall::Whitelist(pallet_whitelist::Call::dispatch_whitelisted_call_with_preimage {
+				call,
+			}) => vec![NestedCall::Inline((**call).clone())],
+			RuntimeCall::Whitelist(pallet_whitelist::Call::dispatch_whitelisted_call {
+				call_hash,
+				call_encoded_len,
+				..
+			}) => vec![NestedCall::Hashed { hash: *call_hash, len: *call_encoded_len }],
+			_ => Vec::new(),
+		}
+	}
+}
+
 impl pallet_referenda::Config<pallet_referenda::Instance2> for Runtime {
 	type WeightInfo = pallet_referenda::weights::SubstrateWeight<Self>;
 	type RuntimeCall = RuntimeCall;
@@ -1268,6 +1339,8 @@ impl pallet_membership::Config<pallet_membership::Instance1> for Runtime {
 	type MembershipInitialized = TechnicalCommittee;
 	type MembershipChanged = TechnicalCommittee;
 	type MaxMembers = TechnicalMaxMembers;
+	type TermDuration = ();
+	type ReElection = ();
 	type WeightInfo = pallet_membership::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1292,6 +1365,10 @@ impl pallet_treasury::Config for Runtime {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type MilestoneApproveOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type RuntimeEvent = RuntimeEvent;
 	type SpendPeriod = SpendPeriod;
 	type Burn = Burn;
@@ -1306,15 +1383,31 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
 }
 
+impl pallet_treasury::escrow::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
+	type CreateOrigin = EnsureWithSuccess<EnsureRoot<AccountId>, AccountId, MaxBalance>;
+	type DisputeOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type MaxMilestones = ConstU32<16>;
+	type WeightInfo = pallet_treasury::escrow::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_asset_rate::Config for Runtime {
 	type CreateOrigin = EnsureRoot<AccountId>;
 	type RemoveOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = EnsureRoot<AccountId>;
+	type OracleOrigin = EnsureRoot<AccountId>;
 	type Currency = Balances;
 	type AssetKind = NativeOrWithId<u32>;
 	type RuntimeEvent = RuntimeEvent;
@@ -1365,6 +1458,8 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type QueueServiceQuota = ();
+	type QueuePriority = ();
 	type HeapSize = ConstU32<{ 64 * 1024 }>;
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -1462,6 +1557,9 @@ impl pallet_revive::Config for Runtime {
 	type UnsafeUnstableInterface = ConstBool<false>;
 	type UploadOrigin = EnsureSigned<Self::AccountId>;
 	type InstantiateOrigin = EnsureSigned<Self::AccountId>;
+	type AllowlistOrigin = EnsureRoot<AccountId>;
+	type Scheduler = Scheduler;
+	type MaxScheduledCalls = ConstU32<16>;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
 	type Debug = ();
@@ -1705,15 +1803,16 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
-	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
-	// highest number of schedules that encodes less than 2^10.
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	// `VestingInfo` encode length is at most 42 bytes. 28 schedules gets encoded as at most 1177
+	// bytes.
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
 impl pallet_mmr::Config for Runtime {
 	const INDEXING_PREFIX: &'static [u8] = b"mmr";
 	type Hashing = Keccak256;
-	type LeafData = pallet_mmr::ParentNumberAndHash<Self>;
+	type LeafData = pallet_beefy_mmr::Pallet<Runtime>;
 	type OnNewRoot = pallet_beefy_mmr::DepositBeefyDigest<Runtime>;
 	type BlockHashProvider = pallet_mmr::DefaultBlockHashProvider<Runtime>;
 	type WeightInfo = ();
@@ -1725,11 +1824,35 @@ parameter_types! {
 	pub LeafVersion: MmrLeafVersion = MmrLeafVersion::new(0, 0);
 }
 
+/// The exposures of the validators of the active era, sorted by validator, committed to in the
+/// BEEFY MMR leaves.
+pub struct ActiveEraExposures;
+impl pallet_beefy_mmr::ExposuresProvider for ActiveEraExposures {
+	type AccountId = AccountId;
+	type Balance = Balance;
+
+	fn exposures() -> Vec<pallet_beefy_mmr::ValidatorExposure<AccountId, Balance>> {
+		let Some(era) = pallet_staking::ActiveEra::<Runtime>::get().map(|info| info.index) else {
+			return Vec::new()
+		};
+		let mut exposures = pallet_staking::ErasStakersOverview::<Runtime>::iter_prefix(era)
+			.map(|(validator, overview)| pallet_beefy_mmr::ValidatorExposure {
+				era,
+				validator,
+				total: overview.total,
+				own: overview.own,
+			})
+			.collect::<Vec<_>>();
+		exposures.sort_by(|a, b| a.validator.cmp(&b.validator));
+		exposures
+	}
+}
+
 impl pallet_beefy_mmr::Config for Runtime {
 	type LeafVersion = LeafVersion;
 	type BeefyAuthorityToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
-	type LeafExtra = Vec<u8>;
-	type BeefyDataProvider = ();
+	type LeafExtra = sp_core::H256;
+	type BeefyDataProvider = pallet_beefy_mmr::ExposuresRootProvider<Runtime, ActiveEraExposures>;
 	type WeightInfo = ();
 }
 
@@ -1777,6 +1900,8 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type Freezer = ();
 	type Extra = ();
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1806,6 +1931,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
@@ -2221,7 +2348,8 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations =
+		pallet_staking::migrations::deprecate_controllers::LazyDeprecateControllers<Runtime>;
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -2634,6 +2762,9 @@ mod runtime {
 
 	#[runtime::pallet_index(81)]
 	pub type VerifySignature = pallet_verify_signature::Pallet<Runtime>;
+
+	#[runtime::pallet_index(82)]
+	pub type TreasuryEscrow = pallet_treasury::escrow::Pallet<Runtime>;
 }
 
 impl TryFrom<RuntimeCall> for pallet_revive::Call<Runtime> {
@@ -2882,6 +3013,7 @@ mod benches {
 		[pallet_tips, Tips]
 		[pallet_transaction_storage, TransactionStorage]
 		[pallet_treasury, Treasury]
+		[pallet_treasury::escrow, TreasuryEscrow]
 		[pallet_asset_rate, AssetRate]
 		[pallet_uniques, Uniques]
 		[pallet_nfts, Nfts]
@@ -2969,6 +3101,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(4)]
 	impl sp_consensus_grandpa::GrandpaApi<Block> for Runtime {
 		fn grandpa_authorities() -> sp_consensus_grandpa::AuthorityList {
 			Grandpa::grandpa_authorities()
@@ -2978,6 +3111,11 @@ impl_runtime_apis! {
 			Grandpa::current_set_id()
 		}
 
+		fn authority_set_diagnostics(
+		) -> sp_consensus_grandpa::AuthoritySetDiagnostics<NumberFor<Block>> {
+			Grandpa::authority_set_diagnostics()
+		}
+
 		fn submit_report_equivocation_unsigned_extrinsic(
 			equivocation_proof: sp_consensus_grandpa::EquivocationProof<
 				<Block as BlockT>::Hash,
@@ -3045,6 +3183,25 @@ impl_runtime_apis! {
 		fn pool_accounts(pool_id: PoolId) -> (AccountId, AccountId) {
 			NominationPools::api_pool_accounts(pool_id)
 		}
+
+		fn member_unbonding_preview(
+			member: AccountId,
+		) -> Option<pallet_nomination_pools::UnbondingPreview<Balance>> {
+			NominationPools::api_member_unbonding_preview(member)
+		}
+	}
+
+	#[api_version(8)]
+	impl pallet_bags_list_runtime_api::BagsListApi<Block, AccountId, VoteWeight> for Runtime {
+		fn list_page(start: Option<AccountId>, count: u32) -> Vec<(AccountId, VoteWeight)> {
+			VoterList::api_list_page(start, count)
+		}
+	}
+
+	impl pallet_staking_runtime_api::StakingEraPointsApi<Block, ()> for Runtime {
+		fn era_points_by_source(era: sp_staking::EraIndex) -> Vec<((), u32)> {
+			Staking::api_era_points_by_source(era)
+		}
 	}
 
 	impl pallet_staking_runtime_api::StakingApi<Block, Balance, AccountId> for Runtime {
@@ -3059,6 +3216,70 @@ impl_runtime_apis! {
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool {
 			Staking::api_pending_rewards(era, account)
 		}
+
+		fn projected_rewards(
+			account: AccountId,
+			era_duration: Option<u64>,
+		) -> Option<sp_staking::ProjectedRewards<AccountId, Balance>> {
+			Staking::api_projected_rewards(account, era_duration)
+		}
+
+		fn estimate_exit_era(account: AccountId) -> Option<sp_staking::EraIndex> {
+			Staking::api_estimate_exit_era(account)
+		}
+
+		fn nominator_backing(
+			account: AccountId,
+		) -> Option<sp_staking::NominatorBacking<AccountId, Balance>> {
+			Staking::api_nominator_backing(account)
+		}
+
+		fn stake_breakdown(account: AccountId) -> sp_staking::StakeableBreakdown<Balance> {
+			Staking::api_stake_breakdown(account)
+		}
+
+		fn validator_performance(account: AccountId) -> Option<sp_staking::ValidatorPerformance> {
+			Staking::api_validator_performance(account)
+		}
+
+		fn pending_slashes() -> Vec<sp_staking::PendingSlash<AccountId, Balance>> {
+			Staking::api_pending_slashes()
+		}
+
+		fn pending_candidates() -> Vec<sp_staking::PendingCandidate<AccountId, Balance>> {
+			Staking::api_pending_candidates()
+		}
+	}
+
+	impl pallet_multisig::runtime_api::MultisigApi<Block, AccountId, BlockNumber> for Runtime {
+		fn open_multisigs(
+			who: AccountId,
+		) -> Vec<pallet_multisig::OpenMultisig<AccountId, BlockNumber>> {
+			Multisig::open_multisigs(&who)
+		}
+	}
+
+	impl pallet_treasury::runtime_api::TreasuryMilestonesApi<Block, NativeOrWithId<u32>, Balance, AccountId> for Runtime {
+		fn pending_milestones(
+		) -> Vec<pallet_treasury::PendingMilestones<NativeOrWithId<u32>, Balance, AccountId>> {
+			Treasury::pending_milestones()
+		}
+	}
+
+	impl pallet_conviction_voting::runtime_api::ConvictionVotingApi<Block, AccountId, u16, Balance> for Runtime {
+		fn delegations(
+			who: AccountId,
+		) -> Vec<(u16, pallet_conviction_voting::DelegationInfo<AccountId, Balance>)> {
+			ConvictionVoting::delegations_of(&who)
+		}
+	}
+
+	impl pallet_referenda::runtime_api::ReferendaApi<Block, RuntimeCall, Hash, BlockNumber> for Runtime {
+		fn enactment_preview(
+			index: pallet_referenda::ReferendumIndex,
+		) -> Option<pallet_referenda::EnactmentPreview<RuntimeCall, Hash, BlockNumber>> {
+			Referenda::enactment_preview::<GovernanceCallExpander>(index)
+		}
 	}
 
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
@@ -3122,6 +3343,58 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::EventTopicsApi<Block, Hash, BlockNumber, frame_system::EventRecord<RuntimeEvent, Hash>> for Runtime {
+		fn events_by_topic(topic: Hash) -> Vec<(BlockNumber, frame_system::EventRecord<RuntimeEvent, Hash>)> {
+			System::events_by_topic(&topic)
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::BlockWeightApi<Block, frame_support::dispatch::PerDispatchClass<Weight>> for Runtime {
+		fn remaining_block_weight() -> frame_support::dispatch::PerDispatchClass<Weight> {
+			System::remaining_block_weight()
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::DecodeCallApi<Block, frame_support::traits::DecodedCall> for Runtime {
+		fn decode_extrinsic_call(extrinsic: Vec<u8>) -> Option<frame_support::traits::DecodedCall> {
+			let extrinsic = UncheckedExtrinsic::decode(&mut &extrinsic[..]).ok()?;
+			Some(frame_support::traits::DecodedCall::new(&extrinsic.function))
+		}
+	}
+
+	impl frame_system_rpc_runtime_api::ExtrinsicOutcomeApi<Block> for Runtime {
+		/// The outcome is a `(Weight, Balance, Vec<frame_system::EventRecord<RuntimeEvent, Hash>>)`
+		/// of the weight consumed by the extrinsic, the fee it paid in the native currency and its
+		/// events.
+		fn extrinsic_outcome(index: u32) -> Vec<u8> {
+			let events = System::read_events_no_consensus()
+				.map(|record| *record)
+				.filter(|record| record.phase == frame_system::Phase::ApplyExtrinsic(index))
+				.collect::<Vec<_>>();
+			let weight = events
+				.iter()
+				.find_map(|record| match &record.event {
+					RuntimeEvent::System(frame_system::Event::ExtrinsicSuccess { dispatch_info }) |
+					RuntimeEvent::System(frame_system::Event::ExtrinsicFailed {
+						dispatch_info,
+						..
+					}) => Some(dispatch_info.weight),
+					_ => None,
+				})
+				.unwrap_or_default();
+			let fee: Balance = events
+				.iter()
+				.find_map(|record| match &record.event {
+					RuntimeEvent::TransactionPayment(
+						pallet_transaction_payment::Event::TransactionFeePaid { actual_fee, .. },
+					) => Some(*actual_fee),
+					_ => None,
+				})
+				.unwrap_or_default();
+			(weight, fee, events).encode()
+		}
+	}
+
 	impl assets_api::AssetsApi<
 		Block,
 		AccountId,
@@ -3301,6 +3574,10 @@ impl_runtime_apis! {
 				key
 			)
 		}
+
+		fn code_metadata(code_hash: sp_core::H256) -> Option<pallet_revive::CodeMetadataInfo> {
+			Revive::code_metadata(code_hash)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
@@ -3340,6 +3617,31 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_asset_rate::AssetRateApi<Block, NativeOrWithId<u32>, Balance, BlockNumber> for Runtime {
+		fn quote_to_native(
+			asset_kind: NativeOrWithId<u32>,
+			amount: Balance,
+		) -> Option<(Balance, pallet_asset_rate::RateInfo<BlockNumber>)> {
+			AssetRate::quote_to_native(asset_kind, amount)
+		}
+	}
+
+	impl pallet_safe_mode::SafeModeApi<Block, RuntimeCall, BlockNumber> for Runtime {
+		fn status() -> pallet_safe_mode::SafeModeStatus<BlockNumber> {
+			SafeMode::status()
+		}
+
+		fn is_allowed(call: RuntimeCall) -> bool {
+			SafeMode::is_allowed(&call)
+		}
+	}
+
+	impl pallet_tx_pause::TxPauseApi<Block, RuntimeCallNameOf<Runtime>> for Runtime {
+		fn paused_calls() -> Vec<RuntimeCallNameOf<Runtime>> {
+			TxPause::paused_calls()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi<Block, Balance, RuntimeCall>
 		for Runtime
 	{
@@ -3473,6 +3775,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
 	impl pallet_mmr::primitives::MmrApi<
 		Block,
 		mmr::Hash,
@@ -3521,6 +3824,13 @@ impl_runtime_apis! {
 			let nodes = leaves.into_iter().map(|leaf|mmr::DataOrHash::Data(leaf.into_opaque_leaf())).collect();
 			pallet_mmr::verify_leaves_proof::<mmr::Hashing, _>(root, nodes, proof)
 		}
+
+		fn generate_ancestry_proof(
+			prev_block_number: BlockNumber,
+			best_known_block_number: Option<BlockNumber>,
+		) -> Result<mmr::AncestryProof<mmr::Hash>, mmr::Error> {
+			Mmr::generate_ancestry_proof(prev_block_number, best_known_block_number)
+		}
 	}
 
 	impl sp_mixnet::runtime_api::MixnetApi<Block> for Runtime {
@@ -3553,6 +3863,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_try_runtime::UpgradeDryRun<Block> for Runtime {
+		fn dry_run_upgrade() -> frame_try_runtime::UpgradeDryRunReport {
+			Executive::dry_run_runtime_upgrade()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -0,0 +1,110 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+use crate::{
+	error,
+	params::{BlockNumberOrHash, ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use codec::Decode;
+use log::info;
+use sc_client_api::HeaderBackend;
+use sc_service::chain_ops::dry_run_upgrade;
+use serde::Serialize;
+use sp_api::CallApiAt;
+use sp_core::traits::CodeExecutor;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use std::{fmt::Debug, fs, io, io::Write, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `dry-run-upgrade` command used to verify a runtime upgrade against the current state.
+///
+/// The migrations of the given runtime are executed on top of the state of a block in memory,
+/// the database is never changed. The weight consumed, the pallets whose storage version changed
+/// and the result of the `try-runtime` checks, if the runtime was built with them, are written
+/// as one JSON object. The runtime must implement the `UpgradeDryRun` runtime api.
+#[derive(Debug, Clone, Parser)]
+pub struct DryRunUpgradeCmd {
+	/// Path to the wasm blob of the new runtime.
+	#[arg(value_name = "RUNTIME")]
+	pub runtime: PathBuf,
+
+	/// Output file name or stdout if unspecified.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+
+	/// Block hash or number to execute the upgrade on top of.
+	/// Default is the best block.
+	#[arg(long, value_name = "HASH or NUMBER")]
+	pub at: Option<BlockNumberOrHash>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl DryRunUpgradeCmd {
+	/// Run the dry-run-upgrade command.
+	///
+	/// `R` is the report returned by the `UpgradeDryRun` runtime api.
+	pub async fn run<B, C, E, R>(&self, client: Arc<C>, executor: E) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + CallApiAt<B> + 'static,
+		E: CodeExecutor + Clone + 'static,
+		R: Decode + Serialize,
+		<B::Hash as FromStr>::Err: Debug,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let code = fs::read(&self.runtime)
+			.map_err(|e| format!("Failed to read {}: {}", self.runtime.display(), e))?;
+		let at = match self.at.as_ref().map(|at| at.parse()).transpose()? {
+			Some(id) => client.expect_block_hash_from_id(&id)?,
+			None => client.info().best_hash,
+		};
+
+		let outcome = dry_run_upgrade::<B, C, E, R>(&*client, &executor, code, at)?;
+		info!(
+			"Dry-run upgrade to {} v{} on top of {} changed {} keys",
+			outcome.spec_name, outcome.spec_version, at, outcome.changed_keys,
+		);
+
+		let mut output: Box<dyn Write> = match &self.output {
+			Some(filename) => Box::new(fs::File::create(filename)?),
+			None => Box::new(io::stdout()),
+		};
+		serde_json::to_writer_pretty(&mut output, &outcome)
+			.map_err(|e| format!("Failed to write the report: {}", e))?;
+		output.write_all(b"\n")?;
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for DryRunUpgradeCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -21,6 +21,7 @@
 mod build_spec_cmd;
 mod chain_info_cmd;
 mod check_block_cmd;
+mod dry_run_upgrade_cmd;
 mod export_blocks_cmd;
 mod export_state_cmd;
 mod generate;
@@ -31,6 +32,7 @@ mod inspect_key;
 mod inspect_node_key;
 mod key;
 mod purge_chain_cmd;
+mod replay_blocks_cmd;
 mod revert_cmd;
 mod run_cmd;
 mod sign;
@@ -41,9 +43,10 @@ mod verify;
 
 pub use self::{
 	build_spec_cmd::BuildSpecCmd, chain_info_cmd::ChainInfoCmd, check_block_cmd::CheckBlockCmd,
-	export_blocks_cmd::ExportBlocksCmd, export_state_cmd::ExportStateCmd, generate::GenerateCmd,
+	dry_run_upgrade_cmd::DryRunUpgradeCmd, export_blocks_cmd::ExportBlocksCmd,
+	export_state_cmd::ExportStateCmd, generate::GenerateCmd,
 	generate_node_key::GenerateKeyCmdCommon, import_blocks_cmd::ImportBlocksCmd,
 	insert_key::InsertKeyCmd, inspect_key::InspectKeyCmd, inspect_node_key::InspectNodeKeyCmd,
-	key::KeySubcommand, purge_chain_cmd::PurgeChainCmd, revert_cmd::RevertCmd, run_cmd::RunCmd,
-	sign::SignCmd, vanity::VanityCmd, verify::VerifyCmd,
+	key::KeySubcommand, purge_chain_cmd::PurgeChainCmd, replay_blocks_cmd::ReplayBlocksCmd,
+	revert_cmd::RevertCmd, run_cmd::RunCmd, sign::SignCmd, vanity::VanityCmd, verify::VerifyCmd,
 };
@@ -0,0 +1,107 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	error,
+	params::{GenericNumber, ImportParams, SharedParams},
+	CliConfiguration,
+};
+use clap::Parser;
+use log::info;
+use sc_client_api::{BlockBackend, HeaderBackend};
+use sc_service::chain_ops::replay_block;
+use sp_api::CallApiAt;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, One, Zero};
+use std::{fmt::Debug, fs, io, io::Write, path::PathBuf, str::FromStr, sync::Arc};
+
+/// The `replay-blocks` command used to re-execute historical blocks.
+///
+/// Every block is executed on top of the state of its parent in memory, the database is never
+/// changed. Per extrinsic, the dispatch result, the consumed weight, the number of deposited
+/// events and the storage changes are written as one JSON object per block. Pass
+/// `--wasm-runtime-overrides` to replay the blocks with a locally built runtime.
+#[derive(Debug, Clone, Parser)]
+pub struct ReplayBlocksCmd {
+	/// Output file name or stdout if unspecified.
+	#[arg()]
+	pub output: Option<PathBuf>,
+
+	/// Specify the first block number to replay.
+	#[arg(long, value_name = "BLOCK")]
+	pub from: GenericNumber,
+
+	/// Specify the last block number to replay.
+	/// Default is the first block.
+	#[arg(long, value_name = "BLOCK")]
+	pub to: Option<GenericNumber>,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[clap(flatten)]
+	pub import_params: ImportParams,
+}
+
+impl ReplayBlocksCmd {
+	/// Run the replay-blocks command
+	pub async fn run<B, C>(&self, client: Arc<C>) -> error::Result<()>
+	where
+		B: BlockT,
+		C: HeaderBackend<B> + BlockBackend<B> + CallApiAt<B> + 'static,
+		<<B::Header as HeaderT>::Number as FromStr>::Err: Debug,
+	{
+		let from: <B::Header as HeaderT>::Number = self.from.parse()?;
+		let to = self.to.as_ref().map(|to| to.parse()).transpose()?.unwrap_or(from);
+		if from.is_zero() {
+			return Err("Cannot replay the genesis block".into())
+		}
+
+		let mut output: Box<dyn Write> = match &self.output {
+			Some(filename) => Box::new(fs::File::create(filename)?),
+			None => Box::new(io::stdout()),
+		};
+
+		let mut number = from;
+		while number <= to {
+			let hash = client.hash(number)?.ok_or_else(|| format!("Block {} not found", number))?;
+			let replayed = replay_block(&*client, hash)?;
+			if !replayed.state_root_matches {
+				info!("Replay of block {} ({}) ended in a different state root", number, hash);
+			}
+
+			serde_json::to_writer(&mut output, &replayed)
+				.map_err(|e| format!("Failed to write block {}: {}", number, e))?;
+			output.write_all(b"\n")?;
+			number += One::one();
+		}
+
+		Ok(())
+	}
+}
+
+impl CliConfiguration for ReplayBlocksCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn import_params(&self) -> Option<&ImportParams> {
+		Some(&self.import_params)
+	}
+}
@@ -594,6 +594,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		Ok(self.shared_params().disable_log_color())
 	}
 
+	/// Get the runtime log targets whose error-class events are counted in Prometheus.
+	///
+	/// By default this is retrieved from `SharedParams`.
+	fn runtime_error_metrics(&self) -> Result<Vec<String>> {
+		Ok(self.shared_params().runtime_error_metrics().to_vec())
+	}
+
 	/// Initialize substrate. This must be done only once per process.
 	///
 	/// This method:
@@ -631,7 +638,8 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		let mut logger = LoggerBuilder::new(self.log_filters()?);
 		logger
 			.with_log_reloading(self.enable_log_reloading()?)
-			.with_detailed_output(self.detailed_log_output()?);
+			.with_detailed_output(self.detailed_log_output()?)
+			.with_runtime_error_metrics(self.runtime_error_metrics()?);
 
 		if let Some(tracing_targets) = self.tracing_targets()? {
 			let tracing_receiver = self.tracing_receiver()?;
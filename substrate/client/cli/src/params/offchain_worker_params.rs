@@ -47,6 +47,13 @@ pub struct OffchainWorkerParams {
 	/// Allows the runtime to write directly to offchain workers DB during block import.
 	#[arg(long = "enable-offchain-indexing", value_name = "ENABLE_OFFCHAIN_INDEXING", default_value_t = false, action = ArgAction::Set)]
 	pub indexing_enabled: bool,
+
+	/// Maximum size in bytes of the body of the HTTP responses read by offchain workers.
+	///
+	/// The limit applies to the decoded body of gzip encoded responses. Reading past it fails.
+	/// There is no limit by default.
+	#[arg(long = "offchain-http-response-size-limit", value_name = "BYTES")]
+	pub http_response_size_limit: Option<usize>,
 }
 
 impl OffchainWorkerParams {
@@ -60,6 +67,7 @@ impl OffchainWorkerParams {
 		};
 
 		let indexing_enabled = self.indexing_enabled;
-		Ok(OffchainWorkerConfig { enabled, indexing_enabled })
+		let http_response_size_limit = self.http_response_size_limit;
+		Ok(OffchainWorkerConfig { enabled, indexing_enabled, http_response_size_limit })
 	}
 }
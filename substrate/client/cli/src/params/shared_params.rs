@@ -80,6 +80,17 @@ pub struct SharedParams {
 	#[arg(long)]
 	pub enable_log_reloading: bool,
 
+	/// Count error and warning log events of the given runtime targets in Prometheus.
+	///
+	/// Events are counted in the `substrate_runtime_log_events_total` metric, labelled by target
+	/// and kind. Defensive failures of the runtime are always counted. Failed dispatches of the
+	/// pallets named like the targets in imported blocks are counted in the
+	/// `substrate_runtime_dispatch_errors_total` metric, labelled by pallet and error.
+	///
+	/// *Example*: `--runtime-error-metrics runtime::stake-tracker,runtime::staking`.
+	#[arg(long, value_name = "TARGETS", value_delimiter = ',')]
+	pub runtime_error_metrics: Vec<String>,
+
 	/// Sets a custom profiling filter.
 	///
 	/// Syntax is the same as for logging (`--log`).
@@ -141,6 +152,11 @@ impl SharedParams {
 		self.tracing_receiver.into()
 	}
 
+	/// Runtime log targets whose error-class events are counted in Prometheus.
+	pub fn runtime_error_metrics(&self) -> &[String] {
+		&self.runtime_error_metrics
+	}
+
 	/// Comma separated list of targets for tracing.
 	pub fn tracing_targets(&self) -> Option<String> {
 		self.tracing_targets.clone()
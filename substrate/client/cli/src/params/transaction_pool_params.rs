@@ -59,17 +59,39 @@ pub struct TransactionPoolParams {
 	/// The type of transaction pool to be instantiated.
 	#[arg(long, value_enum, default_value_t = TransactionPoolType::SingleState)]
 	pub pool_type: TransactionPoolType,
+
+	/// Reserve part of the pool for transactions with at least this priority.
+	///
+	/// Transactions with a lower priority cannot use the reserved part of the pool, so that high
+	/// priority transactions, such as operational staking calls, keep room during congestion.
+	#[arg(long, value_name = "PRIORITY")]
+	pub pool_priority_lane: Option<u64>,
+
+	/// Percentage of the pool limits reserved by `--pool-priority-lane`.
+	#[arg(
+		long,
+		value_name = "PERCENT",
+		default_value_t = 10,
+		value_parser = clap::value_parser!(u8).range(0..=100)
+	)]
+	pub pool_priority_lane_reserve: u8,
 }
 
 impl TransactionPoolParams {
 	/// Fill the given `PoolConfiguration` by looking at the cli parameters.
 	pub fn transaction_pool(&self, is_dev: bool) -> TransactionPoolOptions {
-		TransactionPoolOptions::new_with_params(
+		let options = TransactionPoolOptions::new_with_params(
 			self.pool_limit,
 			self.pool_kbytes * 1024,
 			self.tx_ban_seconds,
 			self.pool_type.into(),
 			is_dev,
-		)
+		);
+
+		match self.pool_priority_lane {
+			Some(min_priority) =>
+				options.with_priority_lane(min_priority, self.pool_priority_lane_reserve),
+			None => options,
+		}
 	}
 }
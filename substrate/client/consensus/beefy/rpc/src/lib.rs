@@ -36,13 +36,15 @@ use jsonrpsee::{
 	core::async_trait,
 	proc_macros::rpc,
 	types::{ErrorObject, ErrorObjectOwned},
-	PendingSubscriptionSink,
+	Extensions, PendingSubscriptionSink,
 };
 use log::warn;
 
 use sc_consensus_beefy::communication::notification::{
-	BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
+	BeefyBestBlockStream, BeefyJustificationRequestSender, BeefyVersionedFinalityProofStream,
+	JustificationRequestError,
 };
+use sc_rpc_api::{check_if_safe, UnsafeRpcError};
 
 mod notification;
 
@@ -55,6 +57,12 @@ pub enum Error {
 	/// The BEEFY RPC background task failed to spawn.
 	#[error("BEEFY RPC background task failed to spawn")]
 	RpcTaskFailure(#[from] SpawnError),
+	/// The requested BEEFY justification is not available.
+	#[error("BEEFY justification not available: {0}")]
+	JustificationUnavailable(#[from] JustificationRequestError),
+	/// Call to an unsafe RPC was denied.
+	#[error(transparent)]
+	UnsafeRpcCalled(#[from] UnsafeRpcError),
 }
 
 /// The error codes returned by jsonrpc.
@@ -63,6 +71,10 @@ pub enum ErrorCode {
 	NotReady = 1,
 	/// Returned on BEEFY RPC background task failure.
 	TaskFailure = 2,
+	/// Returned when the requested BEEFY justification is not available.
+	JustificationUnavailable = 3,
+	/// Returned when an unsafe RPC is called externally.
+	UnsafeRpcCalled = 4,
 }
 
 impl From<Error> for ErrorCode {
@@ -70,12 +82,17 @@ impl From<Error> for ErrorCode {
 		match error {
 			Error::EndpointNotReady => ErrorCode::NotReady,
 			Error::RpcTaskFailure(_) => ErrorCode::TaskFailure,
+			Error::JustificationUnavailable(_) => ErrorCode::JustificationUnavailable,
+			Error::UnsafeRpcCalled(_) => ErrorCode::UnsafeRpcCalled,
 		}
 	}
 }
 
 impl From<Error> for ErrorObjectOwned {
 	fn from(error: Error) -> Self {
+		if let Error::UnsafeRpcCalled(e) = error {
+			return e.into()
+		}
 		let message = error.to_string();
 		let code = ErrorCode::from(error);
 		ErrorObject::owned(code as i32, message, None::<()>)
@@ -100,12 +117,24 @@ pub trait BeefyApi<Notification, Hash> {
 	/// In such case an error would be returned.
 	#[method(name = "beefy_getFinalizedHead")]
 	async fn latest_finalized(&self) -> Result<Hash, Error>;
+
+	/// Returns the BEEFY justification of the given finalized block.
+	///
+	/// Justifications of mandatory blocks, i.e. the first blocks of sessions, that are not stored
+	/// by this client are requested from its peers. An error is returned for any other block that
+	/// has no stored justification, or if none of the peers provides the justification.
+	///
+	/// This method is unsafe, as it makes this client send requests to its peers. These requests
+	/// only go out while the BEEFY voter does not need to request a justification itself.
+	#[method(name = "beefy_getJustification", with_extensions)]
+	async fn justification(&self, block: Hash) -> Result<Notification, Error>;
 }
 
 /// Implements the BeefyApi RPC trait for interacting with BEEFY.
 pub struct Beefy<Block: BlockT, AuthorityId: AuthorityIdBound> {
 	finality_proof_stream: BeefyVersionedFinalityProofStream<Block, AuthorityId>,
 	beefy_best_block: Arc<RwLock<Option<Block::Hash>>>,
+	justif_request_sender: BeefyJustificationRequestSender<Block, AuthorityId>,
 	executor: SubscriptionTaskExecutor,
 }
 
@@ -118,6 +147,7 @@ where
 	pub fn new(
 		finality_proof_stream: BeefyVersionedFinalityProofStream<Block, AuthorityId>,
 		best_block_stream: BeefyBestBlockStream<Block>,
+		justif_request_sender: BeefyJustificationRequestSender<Block, AuthorityId>,
 		executor: SubscriptionTaskExecutor,
 	) -> Result<Self, Error> {
 		let beefy_best_block = Arc::new(RwLock::new(None));
@@ -130,7 +160,7 @@ where
 		});
 
 		executor.spawn("substrate-rpc-subscription", Some("rpc"), future.map(drop).boxed());
-		Ok(Self { finality_proof_stream, beefy_best_block, executor })
+		Ok(Self { finality_proof_stream, beefy_best_block, justif_request_sender, executor })
 	}
 }
 
@@ -157,6 +187,16 @@ where
 	async fn latest_finalized(&self) -> Result<Block::Hash, Error> {
 		self.beefy_best_block.read().as_ref().cloned().ok_or(Error::EndpointNotReady)
 	}
+
+	async fn justification(
+		&self,
+		ext: &Extensions,
+		block: Block::Hash,
+	) -> Result<notification::EncodedVersionedFinalityProof, Error> {
+		check_if_safe(ext)?;
+		let proof = self.justif_request_sender.request(block).await?;
+		Ok(notification::EncodedVersionedFinalityProof::new::<Block, AuthorityId>(proof))
+	}
 }
 
 #[cfg(test)]
@@ -166,9 +206,13 @@ mod tests {
 	use codec::{Decode, Encode};
 	use jsonrpsee::{core::EmptyServerParams as EmptyParams, RpcModule};
 	use sc_consensus_beefy::{
-		communication::notification::BeefyVersionedFinalityProofSender,
+		communication::notification::{
+			justification_request_channel, BeefyJustificationRequest,
+			BeefyVersionedFinalityProofSender,
+		},
 		justification::BeefyVersionedFinalityProof,
 	};
+	use sc_rpc_api::DenyUnsafe;
 	use sp_consensus_beefy::{ecdsa_crypto, known_payloads, Payload, SignedCommitment};
 	use sp_runtime::traits::{BlakeTwo256, Hash};
 	use substrate_test_runtime_client::runtime::Block;
@@ -178,11 +222,13 @@ mod tests {
 		BeefyVersionedFinalityProofSender<Block, ecdsa_crypto::AuthorityId>,
 	) {
 		let (_, stream) = BeefyBestBlockStream::<Block>::channel();
-		setup_io_handler_with_best_block_stream(stream)
+		let (justif_request_sender, _) = justification_request_channel();
+		setup_io_handler_with_streams(stream, justif_request_sender)
 	}
 
-	fn setup_io_handler_with_best_block_stream(
+	fn setup_io_handler_with_streams(
 		best_block_stream: BeefyBestBlockStream<Block>,
+		justif_request_sender: BeefyJustificationRequestSender<Block, ecdsa_crypto::AuthorityId>,
 	) -> (
 		RpcModule<Beefy<Block, ecdsa_crypto::AuthorityId>>,
 		BeefyVersionedFinalityProofSender<Block, ecdsa_crypto::AuthorityId>,
@@ -190,9 +236,13 @@ mod tests {
 		let (finality_proof_sender, finality_proof_stream) =
 			BeefyVersionedFinalityProofStream::<Block, ecdsa_crypto::AuthorityId>::channel();
 
-		let handler =
-			Beefy::new(finality_proof_stream, best_block_stream, sc_rpc::testing::test_executor())
-				.expect("Setting up the BEEFY RPC handler works");
+		let handler = Beefy::new(
+			finality_proof_stream,
+			best_block_stream,
+			justif_request_sender,
+			sc_rpc::testing::test_executor(),
+		)
+		.expect("Setting up the BEEFY RPC handler works");
 
 		(handler.into_rpc(), finality_proof_sender)
 	}
@@ -210,7 +260,8 @@ mod tests {
 	#[tokio::test]
 	async fn latest_finalized_rpc() {
 		let (sender, stream) = BeefyBestBlockStream::<Block>::channel();
-		let (io, _) = setup_io_handler_with_best_block_stream(stream);
+		let (justif_request_sender, _) = justification_request_channel();
+		let (io, _) = setup_io_handler_with_streams(stream, justif_request_sender);
 
 		let hash = BlakeTwo256::hash(b"42");
 		let r: Result<(), ()> = sender.notify(|| Ok(hash));
@@ -302,4 +353,65 @@ mod tests {
 		assert_eq!(&recv_sub_id, sub.subscription_id());
 		assert_eq!(recv_finality_proof, finality_proof);
 	}
+
+	#[tokio::test]
+	async fn justification_rpc() {
+		let (_, stream) = BeefyBestBlockStream::<Block>::channel();
+		let (justif_request_sender, justif_requests) = justification_request_channel();
+		let (mut io, _) = setup_io_handler_with_streams(stream, justif_request_sender);
+		io.extensions_mut().insert(DenyUnsafe::No);
+
+		// Serve the justification of one block, like the voter does.
+		let known = BlakeTwo256::hash(b"42");
+		let finality_proof = create_finality_proof();
+		let proof = finality_proof.clone();
+		tokio::spawn(async move {
+			while let Ok(BeefyJustificationRequest { block, response }) =
+				justif_requests.recv().await
+			{
+				let result = if block == known {
+					Ok(proof.clone())
+				} else {
+					Err(JustificationRequestError::NotMandatory)
+				};
+				let _ = response.send(result);
+			}
+		});
+
+		let bytes: sp_core::Bytes = io.call("beefy_getJustification", [known]).await.unwrap();
+		let recv_finality_proof: BeefyVersionedFinalityProof<Block, ecdsa_crypto::AuthorityId> =
+			Decode::decode(&mut &bytes[..]).unwrap();
+		assert_eq!(recv_finality_proof, finality_proof);
+
+		let request = format!(
+			r#"{{"jsonrpc":"2.0","method":"beefy_getJustification","params":["{:?}"],"id":1}}"#,
+			BlakeTwo256::hash(b"43")
+		);
+		let expected = "{\
+			\"jsonrpc\":\"2.0\",\
+			\"id\":1,\
+			\"error\":{\"code\":3,\"message\":\"BEEFY justification not available: \
+			No justification stored for a block that is not mandatory\"}\
+		}";
+		let (response, _) = io.raw_json_request(&request, 1).await.unwrap();
+		assert_eq!(response, expected);
+	}
+
+	#[tokio::test]
+	async fn justification_rpc_is_unsafe() {
+		let (mut io, _) = setup_io_handler();
+		io.extensions_mut().insert(DenyUnsafe::Yes);
+
+		let request = format!(
+			r#"{{"jsonrpc":"2.0","method":"beefy_getJustification","params":["{:?}"],"id":1}}"#,
+			BlakeTwo256::hash(b"42")
+		);
+		let expected = "{\
+			\"jsonrpc\":\"2.0\",\
+			\"id\":1,\
+			\"error\":{\"code\":-32601,\"message\":\"RPC call is unsafe to be called externally\"}\
+		}";
+		let (response, _) = io.raw_json_request(&request, 1).await.unwrap();
+		assert_eq!(response, expected);
+	}
 }
@@ -16,11 +16,16 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
+use futures::channel::oneshot;
 use sc_utils::notification::{NotificationSender, NotificationStream, TracingKeyStr};
+use sp_consensus_beefy::AuthorityIdBound;
 use sp_runtime::traits::Block as BlockT;
 
 use crate::justification::BeefyVersionedFinalityProof;
 
+/// Maximum number of justification requests from the RPC layer queued for the voter.
+const JUSTIF_REQUEST_CHANNEL_SIZE: usize = 64;
+
 /// The sending half of the notifications channel(s) used to send
 /// notifications about best BEEFY block from the gadget side.
 pub type BeefyBestBlockSender<Block> = NotificationSender<<Block as BlockT>::Hash>;
@@ -55,3 +60,82 @@ pub struct BeefyVersionedFinalityProofTracingKey;
 impl TracingKeyStr for BeefyVersionedFinalityProofTracingKey {
 	const TRACING_KEY: &'static str = "mpsc_beefy_versioned_finality_proof_notification_stream";
 }
+
+/// Reasons for the voter not being able to provide a requested BEEFY justification.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum JustificationRequestError {
+	/// The requested block is not known.
+	#[error("Unknown block")]
+	UnknownBlock,
+	/// The requested block is not finalized (yet).
+	#[error("Block is not finalized")]
+	NotFinalized,
+	/// The justification is not stored locally and the block is not a mandatory block, so peers
+	/// are not required to have it either.
+	#[error("No justification stored for a block that is not mandatory")]
+	NotMandatory,
+	/// The justification is not stored locally and none of the peers provided it.
+	#[error("Justification not available from any peer")]
+	Unavailable,
+	/// The BEEFY voter is not running or too busy to handle the request.
+	#[error("BEEFY voter not available")]
+	VoterUnavailable,
+}
+
+/// A request for the BEEFY justification of a block, sent from the RPC layer to the voter.
+pub struct BeefyJustificationRequest<Block: BlockT, AuthorityId: AuthorityIdBound> {
+	/// Hash of the block to get the justification of.
+	pub block: Block::Hash,
+	/// Sends back the justification, or the reason it is not available.
+	pub response: BeefyJustificationResponseSender<Block, AuthorityId>,
+}
+
+/// Used by the voter to answer a [`BeefyJustificationRequest`].
+pub type BeefyJustificationResponseSender<Block, AuthorityId> = oneshot::Sender<
+	Result<BeefyVersionedFinalityProof<Block, AuthorityId>, JustificationRequestError>,
+>;
+
+/// The sending half of the channel used to request BEEFY justifications from the voter.
+pub struct BeefyJustificationRequestSender<Block: BlockT, AuthorityId: AuthorityIdBound>(
+	async_channel::Sender<BeefyJustificationRequest<Block, AuthorityId>>,
+);
+
+impl<Block: BlockT, AuthorityId: AuthorityIdBound> Clone
+	for BeefyJustificationRequestSender<Block, AuthorityId>
+{
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<Block: BlockT, AuthorityId: AuthorityIdBound>
+	BeefyJustificationRequestSender<Block, AuthorityId>
+{
+	/// Get the BEEFY justification of `block`.
+	///
+	/// The voter answers with the locally stored justification if there is one, otherwise it
+	/// requests the justification of mandatory blocks from its peers.
+	pub async fn request(
+		&self,
+		block: Block::Hash,
+	) -> Result<BeefyVersionedFinalityProof<Block, AuthorityId>, JustificationRequestError> {
+		let (response, receiver) = oneshot::channel();
+		self.0
+			.try_send(BeefyJustificationRequest { block, response })
+			.map_err(|_| JustificationRequestError::VoterUnavailable)?;
+		receiver.await.map_err(|_| JustificationRequestError::VoterUnavailable)?
+	}
+}
+
+/// The receiving half of the channel used by the voter to receive BEEFY justification requests.
+pub type BeefyJustificationRequestStream<Block, AuthorityId> =
+	async_channel::Receiver<BeefyJustificationRequest<Block, AuthorityId>>;
+
+/// Create a channel for requesting BEEFY justifications from the voter.
+pub fn justification_request_channel<Block: BlockT, AuthorityId: AuthorityIdBound>() -> (
+	BeefyJustificationRequestSender<Block, AuthorityId>,
+	BeefyJustificationRequestStream<Block, AuthorityId>,
+) {
+	let (sender, receiver) = async_channel::bounded(JUSTIF_REQUEST_CHANNEL_SIZE);
+	(BeefyJustificationRequestSender(sender), receiver)
+}
@@ -157,6 +157,14 @@ impl<B: Block, AuthorityId: AuthorityIdBound> OnDemandJustificationsEngine<B, Au
 		}
 	}
 
+	/// The block number of the request in progress, if any.
+	pub fn requested_block(&self) -> Option<NumberFor<B>> {
+		match &self.state {
+			State::AwaitingResponse(_, req_info, _) => Some(req_info.block),
+			State::Idle => None,
+		}
+	}
+
 	/// Cancel any pending request for block numbers smaller or equal to `block`.
 	pub fn cancel_requests_older_than(&mut self, block: NumberFor<B>) {
 		match &self.state {
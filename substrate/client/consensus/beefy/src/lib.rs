@@ -19,8 +19,9 @@
 use crate::{
 	communication::{
 		notification::{
-			BeefyBestBlockSender, BeefyBestBlockStream, BeefyVersionedFinalityProofSender,
-			BeefyVersionedFinalityProofStream,
+			justification_request_channel, BeefyBestBlockSender, BeefyBestBlockStream,
+			BeefyJustificationRequestSender, BeefyJustificationRequestStream,
+			BeefyVersionedFinalityProofSender, BeefyVersionedFinalityProofStream,
 		},
 		peers::KnownPeers,
 		request_response::{
@@ -127,6 +128,10 @@ pub struct BeefyVoterLinks<B: Block, AuthorityId: AuthorityIdBound> {
 	/// Stream of BEEFY signed commitments from block import to voter.
 	pub from_block_import_justif_stream: BeefyVersionedFinalityProofStream<B, AuthorityId>,
 
+	// RPC -> Voter links
+	/// Stream of BEEFY justification requests from RPC to voter.
+	pub from_rpc_justif_requests: BeefyJustificationRequestStream<B, AuthorityId>,
+
 	// Voter -> RPC links
 	/// Sends BEEFY signed commitments from voter to RPC.
 	pub to_rpc_justif_sender: BeefyVersionedFinalityProofSender<B, AuthorityId>,
@@ -141,6 +146,8 @@ pub struct BeefyRPCLinks<B: Block, AuthorityId: AuthorityIdBound> {
 	pub from_voter_justif_stream: BeefyVersionedFinalityProofStream<B, AuthorityId>,
 	/// Stream of BEEFY best block hashes coming from the voter.
 	pub from_voter_best_beefy_stream: BeefyBestBlockStream<B>,
+	/// Sends BEEFY justification requests to the voter.
+	pub to_voter_justif_request_sender: BeefyJustificationRequestSender<B, AuthorityId>,
 }
 
 /// Make block importer and link half necessary to tie the background voter to it.
@@ -168,6 +175,10 @@ where
 	let (to_rpc_best_block_sender, from_voter_best_beefy_stream) =
 		BeefyBestBlockStream::<B>::channel();
 
+	// RPC -> Voter links
+	let (to_voter_justif_request_sender, from_rpc_justif_requests) =
+		justification_request_channel::<B, AuthorityId>();
+
 	// BlockImport -> Voter links
 	let (to_voter_justif_sender, from_block_import_justif_stream) =
 		BeefyVersionedFinalityProofStream::<B, AuthorityId>::channel();
@@ -183,10 +194,15 @@ where
 	);
 	let voter_links = BeefyVoterLinks {
 		from_block_import_justif_stream,
+		from_rpc_justif_requests,
 		to_rpc_justif_sender,
 		to_rpc_best_block_sender,
 	};
-	let rpc_links = BeefyRPCLinks { from_voter_best_beefy_stream, from_voter_justif_stream };
+	let rpc_links = BeefyRPCLinks {
+		from_voter_best_beefy_stream,
+		from_voter_justif_stream,
+		to_voter_justif_request_sender,
+	};
 
 	(import, voter_links, rpc_links)
 }
@@ -329,6 +345,7 @@ where
 			comms,
 			links,
 			pending_justifications,
+			rpc_justif_requests: BTreeMap::new(),
 			is_authority,
 		}
 	}
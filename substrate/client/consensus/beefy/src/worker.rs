@@ -19,6 +19,9 @@
 use crate::{
 	communication::{
 		gossip::{proofs_topic, votes_topic, GossipFilterCfg, GossipMessage},
+		notification::{
+			BeefyJustificationRequest, BeefyJustificationResponseSender, JustificationRequestError,
+		},
 		request_response::outgoing_requests_engine::ResponseInfo,
 	},
 	error::Error,
@@ -40,6 +43,7 @@ use sc_client_api::{Backend, HeaderBackend};
 use sc_utils::notification::NotificationReceiver;
 use sp_api::ProvideRuntimeApi;
 use sp_arithmetic::traits::{AtLeast32Bit, Saturating};
+use sp_blockchain::Backend as BlockchainBackend;
 use sp_consensus::SyncOracle;
 use sp_consensus_beefy::{
 	AuthorityIdBound, BeefyApi, Commitment, DoubleVotingProof, PayloadProvider, ValidatorSet,
@@ -379,6 +383,15 @@ impl<B: Block, AuthorityId: AuthorityIdBound> PersistedState<B, AuthorityId> {
 	}
 }
 
+/// Justification requests from the RPC layer, waiting for the justification of a mandatory block
+/// to be provided by peers.
+pub(crate) struct PendingRpcJustifRequests<B: Block, AuthorityId: AuthorityIdBound> {
+	/// Validator set the justification is verified against.
+	pub active_set: ValidatorSet<AuthorityId>,
+	/// Channels to send the justification back on.
+	pub responses: Vec<BeefyJustificationResponseSender<B, AuthorityId>>,
+}
+
 /// A BEEFY worker/voter that follows the BEEFY protocol
 pub(crate) struct BeefyWorker<B: Block, BE, P, RuntimeApi, S, N, AuthorityId: AuthorityIdBound> {
 	// utilities
@@ -399,6 +412,8 @@ pub(crate) struct BeefyWorker<B: Block, BE, P, RuntimeApi, S, N, AuthorityId: Au
 	// voter state
 	/// Buffer holding justifications for future processing.
 	pub pending_justifications: BTreeMap<NumberFor<B>, BeefyVersionedFinalityProof<B, AuthorityId>>,
+	/// Justification requests from the RPC layer to be fulfilled by peers, by block number.
+	pub rpc_justif_requests: BTreeMap<NumberFor<B>, PendingRpcJustifRequests<B, AuthorityId>>,
 	/// Persisted voter state.
 	pub persisted_state: PersistedState<B, AuthorityId>,
 	/// BEEFY voter metrics
@@ -649,6 +664,8 @@ where
 
 		metric_set!(self.metrics, beefy_best_block, block_num);
 
+		// Requests on behalf of the RPC layer are cancelled as well, they stay queued in
+		// `rpc_justif_requests` and are sent again by `process_new_state`.
 		self.comms.on_demand_justifications.cancel_requests_older_than(block_num);
 
 		if let Err(e) = self
@@ -682,6 +699,79 @@ where
 		Ok(())
 	}
 
+	/// Serve a BEEFY justification request coming from the RPC layer.
+	///
+	/// Locally stored justifications are sent back right away. Missing justifications of mandatory
+	/// blocks are requested from peers in [`Self::process_new_state`], while the voter does not
+	/// need the on-demand justifications engine itself.
+	fn handle_rpc_justif_request(&mut self, request: BeefyJustificationRequest<B, AuthorityId>) {
+		let BeefyJustificationRequest { block, response } = request;
+		let blockchain = self.backend.blockchain();
+		let header = match blockchain.header(block) {
+			Ok(Some(header)) => header,
+			_ => {
+				let _ = response.send(Err(JustificationRequestError::UnknownBlock));
+				return;
+			},
+		};
+		let number = *header.number();
+		if number > self.best_grandpa_block() ||
+			blockchain.hash(number).ok().flatten() != Some(block)
+		{
+			let _ = response.send(Err(JustificationRequestError::NotFinalized));
+			return;
+		}
+
+		let stored = blockchain
+			.justifications(block)
+			.ok()
+			.flatten()
+			.and_then(|justifs| justifs.get(BEEFY_ENGINE_ID).cloned())
+			.and_then(|encoded| {
+				BeefyVersionedFinalityProof::<B, AuthorityId>::decode(&mut &encoded[..]).ok()
+			});
+		if let Some(proof) = stored {
+			let _ = response.send(Ok(proof));
+		} else if let Some(active_set) = find_authorities_change::<B, AuthorityId>(&header) {
+			debug!(target: LOG_TARGET, "🥩 Justification #{:?} requested by RPC.", number);
+			self.rpc_justif_requests
+				.entry(number)
+				.or_insert_with(|| PendingRpcJustifRequests { active_set, responses: Vec::new() })
+				.responses
+				.push(response);
+		} else {
+			let _ = response.send(Err(JustificationRequestError::NotMandatory));
+		}
+	}
+
+	/// Answer the RPC requests for the justification of `block`, if any.
+	///
+	/// A justification is also stored, so that it is served locally from now on.
+	fn answer_rpc_justif_requests(
+		&mut self,
+		block: NumberFor<B>,
+		result: Result<BeefyVersionedFinalityProof<B, AuthorityId>, JustificationRequestError>,
+	) {
+		let Some(requests) = self.rpc_justif_requests.remove(&block) else { return };
+		if let Ok(ref proof) = result {
+			if let Err(e) = self
+				.backend
+				.blockchain()
+				.expect_block_hash_from_id(&BlockId::Number(block))
+				.and_then(|hash| {
+					self.backend.append_justification(hash, (BEEFY_ENGINE_ID, proof.encode()))
+				}) {
+				debug!(
+					target: LOG_TARGET,
+					"🥩 Error {:?} on appending justification: {:?}", e, proof
+				);
+			}
+		}
+		for response in requests.responses {
+			let _ = response.send(result.clone());
+		}
+	}
+
 	/// Handle previously buffered justifications, that now land in the voting interval.
 	fn try_pending_justifications(&mut self) -> Result<(), Error> {
 		// Interval of blocks for which we can process justifications and votes right now.
@@ -835,9 +925,31 @@ where
 			// If the current target is a mandatory block,
 			// make sure there's also an on-demand justification request out for it.
 			if let Some((block, active)) = self.voting_oracle().mandatory_pending() {
+				// A request on behalf of the RPC layer gives way to the voter's, it stays queued
+				// in `rpc_justif_requests` and is sent again once the voter is done.
+				if let Some(requested) = self.comms.on_demand_justifications.requested_block() {
+					if requested != block && self.rpc_justif_requests.contains_key(&requested) {
+						self.comms.on_demand_justifications.cancel_requests_older_than(requested);
+					}
+				}
 				// This only starts new request if there isn't already an active one.
 				self.comms.on_demand_justifications.request(block, active);
+				// The voter's request (if any peer was available) holds the engine.
+				if self.comms.on_demand_justifications.requested_block().is_some() {
+					return
+				}
+			}
+		}
+
+		// Then request the justifications missing for the RPC layer, lowest block first.
+		while let Some((&block, requests)) = self.rpc_justif_requests.first_key_value() {
+			let active_set = requests.active_set.clone();
+			self.comms.on_demand_justifications.request(block, active_set);
+			if self.comms.on_demand_justifications.requested_block().is_some() {
+				break;
 			}
+			// There is no peer to request the justification from.
+			self.answer_rpc_justif_requests(block, Err(JustificationRequestError::Unavailable));
 		}
 	}
 
@@ -887,8 +999,13 @@ where
 				.fuse(),
 		);
 
+		// The RPC layer is optional, so this stream ending is not an error.
+		let mut rpc_justif_requests = Box::pin(self.links.from_rpc_justif_requests.clone().fuse());
+
 		self.process_new_state();
 		let error = loop {
+			// Block of the on-demand justification request in progress, if any.
+			let requested_block = self.comms.on_demand_justifications.requested_block();
 			// Mutable reference used to drive the gossip engine.
 			let mut gossip_engine = &mut self.comms.gossip_engine;
 
@@ -915,6 +1032,10 @@ where
 				response_info = self.comms.on_demand_justifications.next().fuse() => {
 					match response_info {
 						ResponseInfo::ValidProof(justif, peer_report) => {
+							let block = match justif {
+								VersionedFinalityProof::V1(ref sc) => sc.commitment.block_number,
+							};
+							self.answer_rpc_justif_requests(block, Ok(justif.clone()));
 							if let Err(err) = self.triage_incoming_justif(justif) {
 								debug!(target: LOG_TARGET, "🥩 {}", err);
 							}
@@ -925,6 +1046,15 @@ where
 						},
 						ResponseInfo::Pending => {},
 					}
+					// The engine ran out of peers to request the justification from.
+					if let Some(block) = requested_block {
+						if self.comms.on_demand_justifications.requested_block().is_none() {
+							self.answer_rpc_justif_requests(
+								block,
+								Err(JustificationRequestError::Unavailable),
+							);
+						}
+					}
 				},
 				justif = block_import_justif.next() => {
 					if let Some(justif) = justif {
@@ -958,6 +1088,12 @@ where
 						break Error::VotesGossipStreamTerminated;
 					}
 				},
+				// Serve justification requests from the RPC layer.
+				request = rpc_justif_requests.next() => {
+					if let Some(request) = request {
+						self.handle_rpc_justif_request(request);
+					}
+				},
 			}
 
 			// Act on changed 'state'.
@@ -1024,7 +1160,10 @@ pub(crate) mod tests {
 	use crate::{
 		communication::{
 			gossip::{tests::TestNetwork, GossipValidator},
-			notification::{BeefyBestBlockStream, BeefyVersionedFinalityProofStream},
+			notification::{
+				justification_request_channel, BeefyBestBlockStream,
+				BeefyVersionedFinalityProofStream,
+			},
 			request_response::outgoing_requests_engine::OnDemandJustificationsEngine,
 		},
 		tests::{
@@ -1092,12 +1231,19 @@ pub(crate) mod tests {
 		let (_, from_block_import_justif_stream) =
 			BeefyVersionedFinalityProofStream::<Block, ecdsa_crypto::AuthorityId>::channel();
 
-		let beefy_rpc_links =
-			BeefyRPCLinks { from_voter_justif_stream, from_voter_best_beefy_stream };
+		let (to_voter_justif_request_sender, from_rpc_justif_requests) =
+			justification_request_channel::<Block, ecdsa_crypto::AuthorityId>();
+
+		let beefy_rpc_links = BeefyRPCLinks {
+			from_voter_justif_stream,
+			from_voter_best_beefy_stream,
+			to_voter_justif_request_sender,
+		};
 		*peer.data.beefy_rpc_links.lock() = Some(beefy_rpc_links);
 
 		let links = BeefyVoterLinks {
 			from_block_import_justif_stream,
+			from_rpc_justif_requests,
 			to_rpc_justif_sender,
 			to_rpc_best_block_sender,
 		};
@@ -1159,6 +1305,7 @@ pub(crate) mod tests {
 			links,
 			comms,
 			pending_justifications: BTreeMap::new(),
+			rpc_justif_requests: BTreeMap::new(),
 			persisted_state,
 			is_authority: true,
 		}
@@ -1632,4 +1779,57 @@ pub(crate) mod tests {
 		// verify nothing reported to runtime
 		assert!(api_alice.reported_equivocations.as_ref().unwrap().lock().is_empty());
 	}
+
+	#[tokio::test]
+	async fn should_serve_rpc_justification_requests() {
+		let keys = [Keyring::Alice];
+		let validator_set = ValidatorSet::new(make_beefy_ids(&keys), 0).unwrap();
+		let mut net = BeefyTestNet::new(1);
+		let mut worker = create_beefy_worker(net.peer(0), &keys[0], 1, validator_set.clone());
+		let backend = net.peer(0).client().as_backend();
+		// #1 is finalized, #2 is not.
+		let hashof1 = backend.blockchain().expect_block_hash_from_id(&BlockId::Number(1)).unwrap();
+		let hashof2 = net.peer(0).push_blocks(1, false)[0];
+
+		let request = |worker: &mut BeefyWorker<Block, Backend, _, _, _, _, _>, block| {
+			let (response, mut receiver) = futures::channel::oneshot::channel();
+			worker.handle_rpc_justif_request(BeefyJustificationRequest { block, response });
+			receiver.try_recv().unwrap()
+		};
+
+		assert_eq!(
+			request(&mut worker, Default::default()),
+			Some(Err(JustificationRequestError::UnknownBlock))
+		);
+		assert_eq!(
+			request(&mut worker, hashof2),
+			Some(Err(JustificationRequestError::NotFinalized))
+		);
+		// #1 has no justification and no authorities change digest.
+		assert_eq!(
+			request(&mut worker, hashof1),
+			Some(Err(JustificationRequestError::NotMandatory))
+		);
+
+		// stored justifications are served.
+		let commitment = Commitment {
+			payload: Payload::from_single_entry(known_payloads::MMR_ROOT_ID, vec![]),
+			block_number: 1,
+			validator_set_id: validator_set.id(),
+		};
+		let justif =
+			VersionedFinalityProof::V1(SignedCommitment { commitment, signatures: vec![None] });
+		worker.finalize(justif.clone()).unwrap();
+		assert_eq!(request(&mut worker, hashof1), Some(Ok(justif)));
+
+		// missing justifications of mandatory blocks are requested from peers, there are none.
+		let (response, mut receiver) = futures::channel::oneshot::channel();
+		worker.rpc_justif_requests.insert(
+			2,
+			PendingRpcJustifRequests { active_set: validator_set, responses: vec![response] },
+		);
+		worker.process_new_state();
+		assert!(worker.rpc_justif_requests.is_empty());
+		assert_eq!(receiver.try_recv().unwrap(), Some(Err(JustificationRequestError::Unavailable)));
+	}
 }
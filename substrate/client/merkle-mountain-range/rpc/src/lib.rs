@@ -37,13 +37,19 @@ use sp_core::{
 	Bytes,
 };
 use sp_mmr_primitives::{Error as MmrError, LeafProof};
-use sp_runtime::traits::{Block as BlockT, NumberFor};
+use sp_runtime::traits::{Block as BlockT, NumberFor, One, UniqueSaturatedInto};
 
 pub use sp_mmr_primitives::MmrApi as MmrRuntimeApi;
 
 const RUNTIME_ERROR: i32 = 8000;
 const MMR_ERROR: i32 = 8010;
 
+/// The maximum number of blocks in a range passed to `mmr_generateProofForRange`.
+pub const MAX_PROOF_RANGE: u64 = 1024;
+
+/// The version of the MMR runtime API that introduced `generate_ancestry_proof`.
+const ANCESTRY_PROOF_API_VERSION: u32 = 3;
+
 /// Retrieved MMR leaves and their proof.
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -72,6 +78,16 @@ impl<BlockHash> LeavesProof<BlockHash> {
 	}
 }
 
+/// Retrieved MMR ancestry proof.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EncodedAncestryProof<BlockHash> {
+	/// Block hash the proof was generated for.
+	pub block_hash: BlockHash,
+	/// SCALE-encoded proof data. See [sp_mmr_primitives::AncestryProof].
+	pub proof: Bytes,
+}
+
 /// MMR RPC methods.
 #[rpc(client, server)]
 pub trait MmrApi<BlockHash, BlockNumber, MmrHash> {
@@ -106,6 +122,36 @@ pub trait MmrApi<BlockHash, BlockNumber, MmrHash> {
 		at: Option<BlockHash>,
 	) -> RpcResult<LeavesProof<BlockHash>>;
 
+	/// Generate an MMR proof for all blocks from `from` to `to`, both inclusive.
+	///
+	/// This is a batch version of `mmr_generateProof` that covers a whole range of blocks, for
+	/// example all blocks of a sync committee update. At most [`MAX_PROOF_RANGE`] blocks can be
+	/// requested at once. `best_known_block_number` and `at` have the same meaning as in
+	/// `mmr_generateProof`.
+	#[method(name = "mmr_generateProofForRange")]
+	fn generate_proof_for_range(
+		&self,
+		from: BlockNumber,
+		to: BlockNumber,
+		best_known_block_number: Option<BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<LeavesProof<BlockHash>>;
+
+	/// Generate an MMR ancestry proof.
+	///
+	/// The proof shows that the MMR at `prev_block_number` is a prefix of the MMR at
+	/// `best_known_block_number`, e.g. between the blocks of two BEEFY commitments. If
+	/// `best_known_block_number` is not provided, the MMR state at `at` is used.
+	///
+	/// Requires version 3 of the MMR runtime API.
+	#[method(name = "mmr_generateAncestryProof")]
+	fn generate_ancestry_proof(
+		&self,
+		prev_block_number: BlockNumber,
+		best_known_block_number: Option<BlockNumber>,
+		at: Option<BlockHash>,
+	) -> RpcResult<EncodedAncestryProof<BlockHash>>;
+
 	/// Verify an MMR `proof`.
 	///
 	/// This method calls into a runtime with MMR pallet included and attempts to verify
@@ -186,6 +232,63 @@ where
 		Ok(LeavesProof::new(block_hash, leaves, proof))
 	}
 
+	fn generate_proof_for_range(
+		&self,
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+		best_known_block_number: Option<NumberFor<Block>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<LeavesProof<<Block as BlockT>::Hash>> {
+		if from > to {
+			return Err(invalid_range("`from` must not be greater than `to`"))
+		}
+		let len = UniqueSaturatedInto::<u64>::unique_saturated_into(to - from).saturating_add(1);
+		if len > MAX_PROOF_RANGE {
+			return Err(invalid_range(format!("At most {MAX_PROOF_RANGE} blocks can be proven")))
+		}
+
+		let mut block_numbers = Vec::with_capacity(len as usize);
+		let mut block_number = from;
+		while block_number <= to {
+			block_numbers.push(block_number);
+			block_number += One::one();
+		}
+
+		self.generate_proof(block_numbers, best_known_block_number, at)
+	}
+
+	fn generate_ancestry_proof(
+		&self,
+		prev_block_number: NumberFor<Block>,
+		best_known_block_number: Option<NumberFor<Block>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<EncodedAncestryProof<<Block as BlockT>::Hash>> {
+		let mut api = self.client.runtime_api();
+		let block_hash = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+
+		let version = api
+			.api_version::<dyn MmrRuntimeApi<Block, MmrHash, NumberFor<Block>>>(block_hash)
+			.map_err(runtime_error_into_rpc_error)?;
+		if version.map_or(true, |version| version < ANCESTRY_PROOF_API_VERSION) {
+			return Err(ErrorObject::owned(
+				RUNTIME_ERROR,
+				"Ancestry proofs are not supported by the runtime",
+				Some(format!("MMR runtime API version {:?}", version)),
+			))
+		}
+
+		api.register_extension(OffchainDbExt::new(self.offchain_db.clone()));
+
+		let proof = api
+			.generate_ancestry_proof(block_hash, prev_block_number, best_known_block_number)
+			.map_err(runtime_error_into_rpc_error)?
+			.map_err(mmr_error_into_rpc_error)?;
+
+		Ok(EncodedAncestryProof { block_hash, proof: Bytes(proof.encode()) })
+	}
+
 	fn verify_proof(&self, proof: LeavesProof<<Block as BlockT>::Hash>) -> RpcResult<bool> {
 		let mut api = self.client.runtime_api();
 
@@ -241,6 +344,14 @@ fn runtime_error_into_rpc_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
 	ErrorObject::owned(RUNTIME_ERROR, "Runtime trapped", Some(format!("{:?}", err)))
 }
 
+fn invalid_range(message: impl Into<String>) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		jsonrpsee::types::error::ErrorCode::InvalidParams.code(),
+		message.into(),
+		None::<()>,
+	)
+}
+
 fn invalid_params(e: impl std::error::Error) -> ErrorObjectOwned {
 	ErrorObject::owned(
 		jsonrpsee::types::error::ErrorCode::InvalidParams.code(),
@@ -352,4 +463,27 @@ mod tests {
 		// then
 		assert_eq!(actual, expected);
 	}
+
+	#[test]
+	fn should_serialize_ancestry_proof() {
+		// given
+		let proof = sp_mmr_primitives::AncestryProof {
+			prev_peaks: vec![H256::repeat_byte(1)],
+			prev_leaf_count: 1,
+			leaf_count: 2,
+			items: vec![],
+		};
+
+		let ancestry_proof =
+			EncodedAncestryProof { block_hash: H256::repeat_byte(0), proof: Bytes(proof.encode()) };
+
+		// when
+		let actual = serde_json::to_string(&ancestry_proof).unwrap();
+
+		// then
+		assert_eq!(
+			actual,
+			r#"{"blockHash":"0x0000000000000000000000000000000000000000000000000000000000000000","proof":"0x0401010101010101010101010101010101010101010101010101010101010101010100000000000000020000000000000000"}"#
+		);
+	}
 }
@@ -26,8 +26,8 @@ use sc_network::Multiaddr;
 use sc_network_types::PeerId;
 use sp_core::{
 	offchain::{
-		self, HttpError, HttpRequestId, HttpRequestStatus, OpaqueMultiaddr, OpaqueNetworkState,
-		Timestamp,
+		self, HttpError, HttpRequestId, HttpRequestMeta, HttpRequestStatus, OpaqueMultiaddr,
+		OpaqueNetworkState, Timestamp,
 	},
 	OpaquePeerId,
 };
@@ -77,9 +77,14 @@ impl offchain::Externalities for Api {
 		&mut self,
 		method: &str,
 		uri: &str,
-		_meta: &[u8],
+		meta: &[u8],
 	) -> Result<HttpRequestId, ()> {
-		self.http.request_start(method, uri)
+		let meta = HttpRequestMeta::decode(&mut &meta[..]).unwrap_or_default();
+		let request_id = self.http.request_start(method, uri)?;
+		if meta.decode_gzip {
+			self.http.request_decode_gzip(request_id)?;
+		}
+		Ok(request_id)
 	}
 
 	fn http_request_add_header(
@@ -201,8 +206,9 @@ impl AsyncApi {
 		network_provider: Arc<dyn NetworkProvider + Send + Sync>,
 		is_validator: bool,
 		shared_http_client: SharedClient,
+		http_response_size_limit: Option<usize>,
 	) -> (Api, Self) {
-		let (http_api, http_worker) = http::http(shared_http_client);
+		let (http_api, http_worker) = http::http(shared_http_client, http_response_size_limit);
 
 		let api = Api { network_provider, is_validator, http: http_api };
 
@@ -328,7 +334,7 @@ mod tests {
 		let mock = Arc::new(TestNetwork());
 		let shared_client = SharedClient::new().unwrap();
 
-		AsyncApi::new(mock, false, shared_client)
+		AsyncApi::new(mock, false, shared_client, None)
 	}
 
 	fn offchain_db() -> OffchainDb<LocalStorage> {
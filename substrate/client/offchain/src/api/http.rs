@@ -29,7 +29,8 @@
 
 use crate::api::timestamp;
 use bytes::buf::{Buf, Reader};
-use fnv::FnvHashMap;
+use flate2::write::GzDecoder;
+use fnv::{FnvHashMap, FnvHashSet};
 use futures::{channel::mpsc, future, prelude::*};
 use http_body_util::{combinators::BoxBody, StreamBody};
 use hyper::body::Body as _;
@@ -40,7 +41,7 @@ use sc_utils::mpsc::{tracing_unbounded, TracingUnboundedReceiver, TracingUnbound
 use sp_core::offchain::{HttpError, HttpRequestId, HttpRequestStatus, Timestamp};
 use std::{
 	fmt,
-	io::Read as _,
+	io::{Read as _, Write as _},
 	pin::Pin,
 	sync::Arc,
 	task::{Context, Poll},
@@ -72,7 +73,13 @@ impl SharedClient {
 }
 
 /// Creates a pair of [`HttpApi`] and [`HttpWorker`].
-pub fn http(shared_client: SharedClient) -> (HttpApi, HttpWorker) {
+///
+/// Reading more than `response_size_limit` bytes of the body of a response, if any, fails with
+/// [`HttpError::IoError`].
+pub fn http(
+	shared_client: SharedClient,
+	response_size_limit: Option<usize>,
+) -> (HttpApi, HttpWorker) {
 	let (to_worker, from_api) = tracing_unbounded("mpsc_ocw_to_worker", 100_000);
 	let (to_api, from_worker) = tracing_unbounded("mpsc_ocw_to_api", 100_000);
 
@@ -83,6 +90,8 @@ pub fn http(shared_client: SharedClient) -> (HttpApi, HttpWorker) {
 		// writing runtime code with hardcoded IDs.
 		next_id: HttpRequestId(rand::random::<u16>() % 2000),
 		requests: FnvHashMap::default(),
+		decode_gzip: FnvHashSet::default(),
+		response_size_limit,
 	};
 
 	let engine =
@@ -105,6 +114,10 @@ pub struct HttpApi {
 	next_id: HttpRequestId,
 	/// List of HTTP requests in preparation or in progress.
 	requests: FnvHashMap<HttpRequestId, HttpApiRequest>,
+	/// HTTP requests whose gzip encoded responses are decoded while they are read.
+	decode_gzip: FnvHashSet<HttpRequestId>,
+	/// Maximum number of bytes that can be read from the body of a response, after decoding.
+	response_size_limit: Option<usize>,
 }
 
 /// One active request within `HttpApi`.
@@ -141,6 +154,11 @@ struct HttpApiRequestRp {
 	/// Chunk that has been extracted from the channel and that is currently being read.
 	/// Reading data from the response should read from this field in priority.
 	current_read_chunk: Option<Reader<hyper::body::Bytes>>,
+	/// Decodes the chunks extracted from the channel if the body is gzip encoded. It is taken out
+	/// at the end of the body, to flush the last decoded bytes.
+	decoder: Option<GzDecoder<Vec<u8>>>,
+	/// Number of bytes of the body that have been read so far, after decoding.
+	read_bytes: usize,
 }
 
 impl HttpApi {
@@ -182,6 +200,20 @@ impl HttpApi {
 		Ok(new_id)
 	}
 
+	/// Decode the body of the response to the request while it is read, if it is gzip encoded.
+	///
+	/// The `Content-Encoding` and `Content-Length` headers of such a response are removed. Set
+	/// through the `meta` of the request in the offchain API.
+	pub fn request_decode_gzip(&mut self, request_id: HttpRequestId) -> Result<(), ()> {
+		match self.requests.get(&request_id) {
+			Some(HttpApiRequest::NotDispatched(_, _)) => {},
+			_ => return Err(()),
+		}
+
+		self.decode_gzip.insert(request_id);
+		Ok(())
+	}
+
 	/// Mimics the corresponding method in the offchain API.
 	pub fn request_add_header(
 		&mut self,
@@ -434,9 +466,16 @@ impl HttpApi {
 
 			// Update internal state based on received message.
 			match next_message {
-				Some(WorkerToApi::Response { id, status_code, headers, body }) => {
+				Some(WorkerToApi::Response { id, status_code, mut headers, body }) => {
+					let decode_gzip = self.decode_gzip.remove(&id);
 					match self.requests.remove(&id) {
 						Some(HttpApiRequest::Dispatched(sending_body)) => {
+							let decoder = (decode_gzip && is_gzip_encoded(&headers)).then(|| {
+								// The body is read decoded, so these headers no longer apply.
+								headers.remove(hyper::header::CONTENT_ENCODING);
+								headers.remove(hyper::header::CONTENT_LENGTH);
+								GzDecoder::new(Vec::new())
+							});
 							self.requests.insert(
 								id,
 								HttpApiRequest::Response(HttpApiRequestRp {
@@ -445,6 +484,8 @@ impl HttpApi {
 									headers,
 									body: body.fuse(),
 									current_read_chunk: None,
+									decoder,
+									read_bytes: 0,
 								}),
 							);
 						},
@@ -455,15 +496,18 @@ impl HttpApi {
 					}
 				},
 
-				Some(WorkerToApi::Fail { id, error }) => match self.requests.remove(&id) {
-					Some(HttpApiRequest::Dispatched(_)) => {
-						tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Request failed");
-						self.requests.insert(id, HttpApiRequest::Fail(error));
-					},
-					None => {}, // can happen if we detected an IO error when sending the body
-					_ => {
-						tracing::error!(target: "offchain-worker::http", "State mismatch between the API and worker")
-					},
+				Some(WorkerToApi::Fail { id, error }) => {
+					self.decode_gzip.remove(&id);
+					match self.requests.remove(&id) {
+						Some(HttpApiRequest::Dispatched(_)) => {
+							tracing::debug!(target: LOG_TARGET, id = %id.0, ?error, "Request failed");
+							self.requests.insert(id, HttpApiRequest::Fail(error));
+						},
+						None => {}, // can happen if we detected an IO error when sending the body
+						_ => {
+							tracing::error!(target: "offchain-worker::http", "State mismatch between the API and worker")
+						},
+					}
 				},
 
 				None => {
@@ -529,6 +573,16 @@ impl HttpApi {
 				match current_read_chunk.read(buffer) {
 					Ok(0) => {},
 					Ok(n) => {
+						response.read_bytes += n;
+						if self.response_size_limit.is_some_and(|limit| response.read_bytes > limit)
+						{
+							tracing::debug!(
+								target: LOG_TARGET,
+								id = %request_id.0,
+								"Response size limit exceeded",
+							);
+							return Err(HttpError::IoError);
+						}
 						self.requests.insert(
 							request_id,
 							HttpApiRequest::Response(HttpApiRequestRp {
@@ -556,10 +610,23 @@ impl HttpApi {
 				match next_body {
 					Some(Ok(chunk)) =>
 						if let Ok(chunk) = chunk.into_data() {
+							let chunk = match response.decoder.as_mut() {
+								Some(decoder) =>
+									decode_chunk(decoder, &chunk).map_err(|_| HttpError::IoError)?,
+								None => chunk,
+							};
 							response.current_read_chunk = Some(chunk.reader());
 						},
 					Some(Err(_)) => return Err(HttpError::IoError),
-					None => return Ok(0), // eof
+					None => match response.decoder.take() {
+						// The decoder may hold the end of the body.
+						Some(decoder) => {
+							let rest = decoder.finish().map_err(|_| HttpError::IoError)?;
+							response.current_read_chunk =
+								Some(hyper::body::Bytes::from(rest).reader());
+						},
+						None => return Ok(0), // eof
+					},
 				}
 			}
 
@@ -571,6 +638,23 @@ impl HttpApi {
 	}
 }
 
+/// Whether the body of a response with `headers` is gzip encoded.
+fn is_gzip_encoded(headers: &hyper::HeaderMap) -> bool {
+	headers
+		.get(hyper::header::CONTENT_ENCODING)
+		.is_some_and(|encoding| encoding.as_bytes().eq_ignore_ascii_case(b"gzip"))
+}
+
+/// Feed `chunk` of a gzip encoded body to `decoder`, and return what has been decoded so far.
+fn decode_chunk(
+	decoder: &mut GzDecoder<Vec<u8>>,
+	chunk: &[u8],
+) -> std::io::Result<hyper::body::Bytes> {
+	decoder.write_all(chunk)?;
+	decoder.flush()?;
+	Ok(std::mem::take(decoder.get_mut()).into())
+}
+
 impl fmt::Debug for HttpApi {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		f.debug_list().entries(self.requests.iter()).finish()
@@ -794,9 +878,12 @@ mod tests {
 				hyper::body::Bytes::from("Hello World!")
 			)))
 		};
-		( $response:expr ) => {{
+		( $response:expr ) => {
+			build_api_server!($response, None)
+		};
+		( $response:expr, $response_size_limit:expr ) => {{
 			let hyper_client = SHARED_CLIENT.clone();
-			let (api, worker) = http(hyper_client.clone());
+			let (api, worker) = http(hyper_client.clone(), $response_size_limit);
 
 			let (addr_tx, addr_rx) = std::sync::mpsc::channel();
 			std::thread::spawn(move || {
@@ -887,6 +974,104 @@ mod tests {
 		assert_eq!(&buf[..n], b"Hello World!");
 	}
 
+	fn gzip(data: &[u8]) -> Vec<u8> {
+		use std::io::Write;
+
+		let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+		encoder.write_all(data).unwrap();
+		encoder.finish().unwrap()
+	}
+
+	#[test]
+	fn gzip_encoded_response_is_decoded() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+
+		let (mut api, addr) = build_api_server!(hyper::Response::builder()
+			.header(hyper::header::CONTENT_ENCODING, "gzip")
+			.body(http_body_util::Full::new(hyper::body::Bytes::from(gzip(&[7; 10_000]))))
+			.unwrap());
+
+		let id = api.request_start("GET", &format!("http://{}", addr)).unwrap();
+		api.request_decode_gzip(id).unwrap();
+		api.request_add_header(id, "Accept-Encoding", "gzip").unwrap();
+
+		match api.response_wait(&[id], Some(deadline))[0] {
+			HttpRequestStatus::Finished(200) => {},
+			v => panic!("Connecting to localhost failed: {:?}", v),
+		}
+
+		let headers = api.response_headers(id);
+		assert!(!headers.iter().any(|(h, _)| h.eq_ignore_ascii_case(b"Content-Encoding")));
+
+		let mut body = Vec::new();
+		let mut buf = vec![0; 1024];
+		loop {
+			match api.response_read_body(id, &mut buf, Some(deadline)).unwrap() {
+				0 => break,
+				n => body.extend_from_slice(&buf[..n]),
+			}
+		}
+		assert_eq!(body, vec![7; 10_000]);
+	}
+
+	#[test]
+	fn gzip_encoded_response_is_not_decoded_by_default() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+		let encoded = gzip(&[7; 10_000]);
+
+		let (mut api, addr) = build_api_server!(hyper::Response::builder()
+			.header(hyper::header::CONTENT_ENCODING, "gzip")
+			.body(http_body_util::Full::new(hyper::body::Bytes::from(encoded.clone())))
+			.unwrap());
+
+		let id = api.request_start("GET", &format!("http://{}", addr)).unwrap();
+		api.request_add_header(id, "Accept-Encoding", "gzip").unwrap();
+
+		match api.response_wait(&[id], Some(deadline))[0] {
+			HttpRequestStatus::Finished(200) => {},
+			v => panic!("Connecting to localhost failed: {:?}", v),
+		}
+
+		let headers = api.response_headers(id);
+		assert!(headers.iter().any(|(h, _)| h.eq_ignore_ascii_case(b"Content-Encoding")));
+
+		let mut body = Vec::new();
+		let mut buf = vec![0; 1024];
+		loop {
+			match api.response_read_body(id, &mut buf, Some(deadline)).unwrap() {
+				0 => break,
+				n => body.extend_from_slice(&buf[..n]),
+			}
+		}
+		assert_eq!(body, encoded);
+	}
+
+	#[test]
+	fn response_size_limit_is_enforced() {
+		let deadline = timestamp::now().add(Duration::from_millis(10_000));
+
+		let (mut api, addr) = build_api_server!(
+			hyper::Response::new(http_body_util::Full::new(hyper::body::Bytes::from(
+				"Hello World!"
+			))),
+			Some(8)
+		);
+
+		let id = api.request_start("GET", &format!("http://{}", addr)).unwrap();
+
+		match api.response_wait(&[id], Some(deadline))[0] {
+			HttpRequestStatus::Finished(200) => {},
+			v => panic!("Connecting to localhost failed: {:?}", v),
+		}
+
+		let mut buf = vec![0; 4];
+		assert_eq!(api.response_read_body(id, &mut buf, Some(deadline)), Ok(4));
+		assert_eq!(api.response_read_body(id, &mut buf, Some(deadline)), Ok(4));
+		assert_eq!(api.response_read_body(id, &mut buf, Some(deadline)), Err(HttpError::IoError));
+		// the request is dropped.
+		assert_eq!(api.response_read_body(id, &mut buf, Some(deadline)), Err(HttpError::Invalid));
+	}
+
 	#[test]
 	fn request_start_invalid_call() {
 		let (mut api, addr) = build_api_server!();
@@ -1130,7 +1315,7 @@ mod tests {
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone(), None);
 			api.timestamp();
 
 			futures::executor::block_on(async move {
@@ -1145,7 +1330,7 @@ mod tests {
 
 		{
 			let mock = Arc::new(TestNetwork());
-			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone());
+			let (mut api, async_api) = AsyncApi::new(mock, false, shared_client.clone(), None);
 			let id = api.http_request_start("lol", "nope", &[]).unwrap();
 			api.http_request_write_body(id, &[], None).unwrap();
 			futures::executor::block_on(async move {
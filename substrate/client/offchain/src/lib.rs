@@ -110,6 +110,11 @@ pub struct OffchainWorkerOptions<RA, Block: traits::Block, Storage, CE> {
 	///
 	/// If not enabled, any http request will panic.
 	pub enable_http_requests: bool,
+	/// Maximum number of bytes that can be read from the body of an http response, after
+	/// decoding.
+	///
+	/// Reading past it fails with [`offchain::HttpError::IoError`]. `None` for no limit.
+	pub http_response_size_limit: Option<usize>,
 	/// Callback to create custom [`Extension`]s that should be registered for the
 	/// `offchain_worker` runtime call.
 	///
@@ -132,6 +137,7 @@ pub struct OffchainWorkers<RA, Block: traits::Block, Storage> {
 	thread_pool: Mutex<ThreadPool>,
 	shared_http_client: api::SharedClient,
 	enable_http_requests: bool,
+	http_response_size_limit: Option<usize>,
 	keystore: Option<KeystorePtr>,
 	offchain_db: Option<OffchainDb<Storage>>,
 	transaction_pool: Option<OffchainTransactionPoolFactory<Block>>,
@@ -151,6 +157,7 @@ impl<RA, Block: traits::Block, Storage> OffchainWorkers<RA, Block, Storage> {
 			network_provider,
 			is_validator,
 			enable_http_requests,
+			http_response_size_limit,
 			custom_extensions,
 		}: OffchainWorkerOptions<RA, Block, Storage, CE>,
 	) -> std::io::Result<Self> {
@@ -162,6 +169,7 @@ impl<RA, Block: traits::Block, Storage> OffchainWorkers<RA, Block, Storage> {
 			)),
 			shared_http_client: api::SharedClient::new()?,
 			enable_http_requests,
+			http_response_size_limit,
 			keystore,
 			offchain_db: offchain_db.map(OffchainDb::new),
 			transaction_pool,
@@ -247,6 +255,7 @@ where
 				self.network_provider.clone(),
 				self.is_validator,
 				self.shared_http_client.clone(),
+				self.http_response_size_limit,
 			);
 			tracing::debug!(target: LOG_TARGET, "Spawning offchain workers at {hash:?}");
 			let header = header.clone();
@@ -465,6 +474,7 @@ mod tests {
 			network_provider: network,
 			is_validator: false,
 			enable_http_requests: false,
+			http_response_size_limit: None,
 			custom_extensions: |_| Vec::new(),
 		})
 		.unwrap();
@@ -20,7 +20,8 @@
 
 use crate::{
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+		ArchiveReorgEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent,
+		ArchiveStoragePage, StorageQuery, StorageQueryType,
 	},
 	MethodResult,
 };
@@ -111,6 +112,28 @@ pub trait ArchiveApi<Hash> {
 		child_trie: Option<String>,
 	);
 
+	/// Returns a page of the storage entries under `prefix` at a specific block's state.
+	///
+	/// `query_type` must be either `descendantsValues` or `descendantsHashes`. At most
+	/// `page_size` entries are returned, in lexicographic key order, together with a
+	/// `continuation` token if there are more entries. Passing the token back resumes the
+	/// iteration right after the last returned entry. A token is only valid for the block,
+	/// prefix and child trie it was issued for, so that paging never switches state midway.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	#[method(name = "archive_unstable_storagePaged", blocking)]
+	fn archive_unstable_storage_paged(
+		&self,
+		hash: Hash,
+		prefix: String,
+		query_type: StorageQueryType,
+		page_size: u32,
+		continuation: Option<String>,
+		child_trie: Option<String>,
+	) -> RpcResult<ArchiveStoragePage>;
+
 	/// Returns the storage difference between two blocks.
 	///
 	/// # Unstable
@@ -127,4 +150,24 @@ pub trait ArchiveApi<Hash> {
 		items: Vec<ArchiveStorageDiffItem<String>>,
 		previous_hash: Option<Hash>,
 	);
+
+	/// Reports the re-orgs of the best chain.
+	///
+	/// Each time the best block moves to a block that does not descend from the previous best
+	/// block, a `reorg` event lists the retracted and the enacted blocks together with the hashes
+	/// of their extrinsics, and the extrinsics that were dropped with the retracted blocks. This
+	/// lets indexers revert what they derived from the abandoned fork before it is finalized.
+	///
+	/// # Unstable
+	///
+	/// This method is unstable and can change in minor or patch releases.
+	///
+	/// This method is considered unsafe and is limited to a few subscriptions per connection.
+	#[subscription(
+		name = "archive_unstable_reorgs" => "archive_unstable_reorgEvent",
+		unsubscribe = "archive_unstable_stopReorgs",
+		item = ArchiveReorgEvent,
+		with_extensions,
+	)]
+	fn archive_unstable_reorgs(&self);
 }
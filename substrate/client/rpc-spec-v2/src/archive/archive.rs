@@ -20,35 +20,43 @@
 
 use crate::{
 	archive::{
-		archive_storage::ArchiveStorageDiff, error::Error as ArchiveError, ArchiveApiServer,
+		archive_storage::{ArchiveStorageDiff, StoragePageToken},
+		error::Error as ArchiveError,
+		ArchiveApiServer,
 	},
 	common::{
+		connections::RpcConnections,
 		events::{
-			ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageEvent, StorageQuery,
+			ArchiveReorg, ArchiveReorgBlock, ArchiveReorgEvent, ArchiveStorageDiffEvent,
+			ArchiveStorageDiffItem, ArchiveStorageEvent, ArchiveStoragePage, StorageQuery,
+			StorageQueryType,
 		},
-		storage::{QueryResult, StorageSubscriptionClient},
+		storage::{IterQueryType, QueryIter, QueryResult, Storage, StorageSubscriptionClient},
 	},
 	hex_string, MethodResult, SubscriptionTaskExecutor,
 };
 
 use codec::Encode;
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
-	PendingSubscriptionSink,
+	types::ErrorObject,
+	Extensions, PendingSubscriptionSink,
 };
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, CallExecutor, ChildInfo, ExecutorProvider, StorageKey,
 	StorageProvider,
 };
-use sc_rpc::utils::Subscription;
+use sc_rpc::utils::{spawn_subscription_task, Subscription};
+use sc_rpc_api::check_if_safe;
 use sp_api::{CallApiAt, CallContext};
 use sp_blockchain::{
 	Backend as BlockChainBackend, Error as BlockChainError, HeaderBackend, HeaderMetadata,
+	TreeRoute,
 };
 use sp_core::{Bytes, U256};
 use sp_runtime::{
-	traits::{Block as BlockT, Header as HeaderT, NumberFor},
+	traits::{Block as BlockT, Hash as HashT, Header as HeaderT, NumberFor},
 	SaturatedConversion,
 };
 use std::{collections::HashSet, marker::PhantomData, sync::Arc};
@@ -63,6 +71,12 @@ pub(crate) const LOG_TARGET: &str = "rpc-spec-v2::archive";
 /// its down buffer capacity per connection as well.
 const STORAGE_QUERY_BUF: usize = 16;
 
+/// The maximum number of entries returned by a single `archive_unstable_storagePaged` call.
+const MAX_STORAGE_PAGE_SIZE: u32 = 1000;
+
+/// The maximum number of `archive_unstable_reorgs` subscriptions active for each connection.
+const MAX_REORG_SUBSCRIPTIONS_PER_CONNECTION: usize = 4;
+
 /// An API for archive RPC calls.
 pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	/// Substrate client.
@@ -73,6 +87,9 @@ pub struct Archive<BE: Backend<Block>, Block: BlockT, Client> {
 	executor: SubscriptionTaskExecutor,
 	/// The hexadecimal encoded hash of the genesis block.
 	genesis_hash: String,
+	/// Keep track of how many `archive_unstable_reorgs` subscriptions are active for each
+	/// connection.
+	reorg_subscriptions: RpcConnections,
 	/// Phantom member to pin the block type.
 	_phantom: PhantomData<Block>,
 }
@@ -86,7 +103,14 @@ impl<BE: Backend<Block>, Block: BlockT, Client> Archive<BE, Block, Client> {
 		executor: SubscriptionTaskExecutor,
 	) -> Self {
 		let genesis_hash = hex_string(&genesis_hash.as_ref());
-		Self { client, backend, executor, genesis_hash, _phantom: PhantomData }
+		Self {
+			client,
+			backend,
+			executor,
+			genesis_hash,
+			reorg_subscriptions: RpcConnections::new(MAX_REORG_SUBSCRIPTIONS_PER_CONNECTION),
+			_phantom: PhantomData,
+		}
 	}
 }
 
@@ -265,6 +289,62 @@ where
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
 
+	fn archive_unstable_storage_paged(
+		&self,
+		hash: Block::Hash,
+		prefix: String,
+		query_type: StorageQueryType,
+		page_size: u32,
+		continuation: Option<String>,
+		child_trie: Option<String>,
+	) -> RpcResult<ArchiveStoragePage> {
+		let ty = match query_type {
+			StorageQueryType::DescendantsValues => IterQueryType::Value,
+			StorageQueryType::DescendantsHashes => IterQueryType::Hash,
+			_ =>
+				return Err(ArchiveError::InvalidParam(format!(
+					"Only descendants queries can be paged: {query_type:?}"
+				))
+				.into()),
+		};
+		if page_size == 0 || page_size > MAX_STORAGE_PAGE_SIZE {
+			return Err(ArchiveError::InvalidParam(format!(
+				"Page size must be between 1 and {MAX_STORAGE_PAGE_SIZE}: {page_size}"
+			))
+			.into())
+		}
+
+		let prefix = parse_hex_param(prefix)?;
+		let child_trie = child_trie.map(parse_hex_param).transpose()?;
+
+		let pagination_start_key = match continuation {
+			Some(continuation) => {
+				let token = StoragePageToken::<Block::Hash>::from_hex(continuation)?;
+				if token.hash != hash || token.prefix != prefix || token.child_trie != child_trie {
+					return Err(ArchiveError::InvalidContinuation(
+						"token was issued for a different block, prefix or child trie".into(),
+					)
+					.into())
+				}
+				Some(StorageKey(token.last_key))
+			},
+			None => None,
+		};
+
+		let storage = Storage::<Client, Block, BE>::new(self.client.clone());
+		let query = QueryIter { query_key: StorageKey(prefix.clone()), pagination_start_key, ty };
+		let child_info = child_trie.clone().map(ChildInfo::new_default_from_vec);
+		let (items, last_key) = storage
+			.query_iter_page(query, hash, child_info.as_ref(), page_size as usize)
+			.map_err(ArchiveError::Storage)?;
+
+		let continuation = last_key.map(|last_key| {
+			StoragePageToken { hash, prefix, child_trie, last_key: last_key.0 }.to_hex()
+		});
+
+		Ok(ArchiveStoragePage { items, continuation })
+	}
+
 	fn archive_unstable_storage_diff(
 		&self,
 		pending: PendingSubscriptionSink,
@@ -306,6 +386,171 @@ where
 
 		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
 	}
+
+	fn archive_unstable_reorgs(&self, pending: PendingSubscriptionSink, ext: &Extensions) {
+		if let Err(err) = check_if_safe(ext) {
+			spawn_subscription_task(&self.executor, pending.reject(ErrorObject::from(err)));
+			return
+		}
+
+		// The RAII `reserved` frees the reserved subscription for the connection on drop.
+		let Some(reserved) = self.reorg_subscriptions.reserve_space(pending.connection_id()) else {
+			spawn_subscription_task(&self.executor, pending.reject(ArchiveError::ReachedLimits));
+			return
+		};
+
+		let client = self.client.clone();
+		// Subscribe before accepting, so that no re-org is missed once the subscription is live.
+		let mut imports = client.import_notification_stream();
+		let mut finalized = client.finality_notification_stream();
+		let mut best_hash = client.info().best_hash;
+
+		let fut = async move {
+			let _reserved = reserved;
+			let Ok(mut sink) = pending.accept().await.map(Subscription::from) else { return };
+
+			loop {
+				let result = tokio::select! {
+					_ = sink.closed() => {
+						return
+					},
+
+					maybe_notification = imports.next() => {
+						let Some(notification) = maybe_notification else {
+							return
+						};
+						if !notification.is_new_best {
+							continue
+						}
+						best_hash = notification.hash;
+
+						// Only the imports of a new best block that retract blocks are re-orgs.
+						let Some(tree_route) = notification.tree_route.as_ref() else { continue };
+						if tree_route.retracted().is_empty() {
+							continue
+						}
+						reorg::<Block, _>(&*client, tree_route, &notification.header)
+					},
+
+					maybe_notification = finalized.next() => {
+						if maybe_notification.is_none() {
+							return
+						}
+
+						// Finalizing a fork moves the best block without an import notification.
+						let new_best_hash = client.info().best_hash;
+						if new_best_hash == best_hash {
+							continue
+						}
+						let old_best_hash = std::mem::replace(&mut best_hash, new_best_hash);
+						match finality_reorg::<Block, _>(&*client, old_best_hash, new_best_hash) {
+							Ok(Some(reorg)) => Ok(reorg),
+							Ok(None) => continue,
+							Err(error) => Err(error),
+						}
+					}
+				};
+
+				let event = match result {
+					Ok(reorg) => ArchiveReorgEvent::Reorg(reorg),
+					Err(error) => {
+						log::debug!(target: LOG_TARGET, "Failed to report re-org: {error}");
+						ArchiveReorgEvent::err(error.to_string())
+					},
+				};
+				if sink.send(&event).await.is_err() {
+					return
+				}
+			}
+		};
+
+		self.executor.spawn("substrate-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
+
+/// Describes the re-org from `old_best` to `new_best`, if any block was retracted.
+///
+/// This is used when the finalization of a fork moves the best block.
+fn finality_reorg<Block, Client>(
+	client: &Client,
+	old_best: Block::Hash,
+	new_best: Block::Hash,
+) -> Result<Option<ArchiveReorg>, ArchiveError>
+where
+	Block: BlockT,
+	Client:
+		BlockBackend<Block> + HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError>,
+{
+	let new_best = client
+		.header(new_best)
+		.map_err(|error| ArchiveError::FetchBlock(error.to_string()))?
+		.ok_or_else(|| ArchiveError::FetchBlock(format!("Header is not present: {new_best}")))?;
+	let tree_route = sp_blockchain::tree_route(client, old_best, *new_best.parent_hash())
+		.map_err(|error| ArchiveError::FetchBlock(error.to_string()))?;
+	if tree_route.retracted().is_empty() {
+		return Ok(None)
+	}
+
+	reorg::<Block, _>(client, &tree_route, &new_best).map(Some)
+}
+
+/// Describes the re-org from the old best block to `new_best`, along `tree_route`.
+///
+/// `tree_route` goes from the old best block to the parent of `new_best`.
+fn reorg<Block, Client>(
+	client: &Client,
+	tree_route: &TreeRoute<Block>,
+	new_best: &Block::Header,
+) -> Result<ArchiveReorg, ArchiveError>
+where
+	Block: BlockT,
+	Client: BlockBackend<Block>,
+{
+	let reorg_block = |hash: Block::Hash, number: NumberFor<Block>| {
+		let extrinsics = client
+			.block_body(hash)
+			.map_err(|error| ArchiveError::FetchBlock(error.to_string()))?
+			.ok_or_else(|| ArchiveError::FetchBlock(format!("Block body is not present: {hash}")))?
+			.iter()
+			.map(|extrinsic| {
+				hex_string(&<Block::Header as HeaderT>::Hashing::hash_of(extrinsic).as_ref())
+			})
+			.collect();
+
+		Ok::<_, ArchiveError>(ArchiveReorgBlock {
+			hash: hex_string(&hash.as_ref()),
+			number: number.saturated_into(),
+			extrinsics,
+		})
+	};
+
+	let retracted = tree_route
+		.retracted()
+		.iter()
+		.map(|block| reorg_block(block.hash, block.number))
+		.collect::<Result<Vec<_>, _>>()?;
+	let enacted = tree_route
+		.enacted()
+		.iter()
+		.map(|block| reorg_block(block.hash, block.number))
+		.chain(std::iter::once(reorg_block(new_best.hash(), *new_best.number())))
+		.collect::<Result<Vec<_>, _>>()?;
+
+	let included: HashSet<&String> =
+		enacted.iter().flat_map(|block| block.extrinsics.iter()).collect();
+	let dropped_extrinsics = retracted
+		.iter()
+		.flat_map(|block| block.extrinsics.iter())
+		.filter(|extrinsic| !included.contains(extrinsic))
+		.cloned()
+		.collect();
+
+	Ok(ArchiveReorg {
+		common_ancestor: hex_string(&tree_route.common_block().hash.as_ref()),
+		retracted,
+		enacted,
+		dropped_extrinsics,
+	})
 }
 
 /// Sends all the events of the storage_diff method to the sink.
@@ -23,6 +23,7 @@ use std::{
 	sync::Arc,
 };
 
+use codec::{Decode, Encode};
 use itertools::Itertools;
 use sc_client_api::{Backend, ChildInfo, StorageKey, StorageProvider};
 use sp_runtime::traits::Block as BlockT;
@@ -37,6 +38,7 @@ use crate::{
 		},
 		storage::Storage,
 	},
+	hex_string,
 };
 use tokio::sync::mpsc;
 
@@ -52,6 +54,34 @@ pub fn parse_hex_param(param: String) -> Result<Vec<u8>, ArchiveError> {
 	array_bytes::hex2bytes(&param).map_err(|_| ArchiveError::InvalidParam(param))
 }
 
+/// The decoded continuation token of the `archive_unstable_storagePaged` method.
+///
+/// The token pins the iteration to the state it was started on.
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct StoragePageToken<Hash> {
+	/// The block hash of the iterated state.
+	pub hash: Hash,
+	/// The iterated key prefix.
+	pub prefix: Vec<u8>,
+	/// The iterated child trie, if any.
+	pub child_trie: Option<Vec<u8>>,
+	/// The last returned key, after which the iteration resumes.
+	pub last_key: Vec<u8>,
+}
+
+impl<Hash: Encode + Decode> StoragePageToken<Hash> {
+	/// Encode the token as an opaque hex-encoded string.
+	pub fn to_hex(&self) -> String {
+		hex_string(&self.encode())
+	}
+
+	/// Decode a token from its hex-encoded representation.
+	pub fn from_hex(token: String) -> Result<Self, ArchiveError> {
+		let bytes = parse_hex_param(token.clone())?;
+		Self::decode(&mut &bytes[..]).map_err(|_| ArchiveError::InvalidContinuation(token))
+	}
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct DiffDetails {
 	key: StorageKey,
@@ -32,6 +32,18 @@ pub enum Error {
 	/// Failed to fetch leaves.
 	#[error("Failed to fetch leaves of the chain: {0}")]
 	FetchLeaves(String),
+	/// The continuation token is invalid for the query.
+	#[error("Invalid continuation token: {0}")]
+	InvalidContinuation(String),
+	/// Failed to query the storage.
+	#[error("Storage query: {0}")]
+	Storage(String),
+	/// Failed to fetch a block.
+	#[error("Failed to fetch block: {0}")]
+	FetchBlock(String),
+	/// Maximum number of `archive_unstable_reorgs` subscriptions has been reached.
+	#[error("Maximum number of reorg subscriptions has been reached")]
+	ReachedLimits,
 }
 
 // Base code for all `archive` errors.
@@ -42,6 +54,14 @@ const INVALID_PARAM_ERROR: i32 = BASE_ERROR + 1;
 const RUNTIME_CALL_ERROR: i32 = BASE_ERROR + 2;
 /// Failed to fetch leaves.
 const FETCH_LEAVES_ERROR: i32 = BASE_ERROR + 3;
+/// Invalid continuation token.
+const INVALID_CONTINUATION_ERROR: i32 = BASE_ERROR + 4;
+/// Storage query error.
+const STORAGE_ERROR: i32 = BASE_ERROR + 5;
+/// Failed to fetch a block.
+const FETCH_BLOCK_ERROR: i32 = BASE_ERROR + 6;
+/// Reached the limit of subscriptions.
+const REACHED_LIMITS_ERROR: i32 = BASE_ERROR + 7;
 
 impl From<Error> for ErrorObject<'static> {
 	fn from(e: Error) -> Self {
@@ -51,6 +71,11 @@ impl From<Error> for ErrorObject<'static> {
 			Error::InvalidParam(_) => ErrorObject::owned(INVALID_PARAM_ERROR, msg, None::<()>),
 			Error::RuntimeCall(_) => ErrorObject::owned(RUNTIME_CALL_ERROR, msg, None::<()>),
 			Error::FetchLeaves(_) => ErrorObject::owned(FETCH_LEAVES_ERROR, msg, None::<()>),
+			Error::InvalidContinuation(_) =>
+				ErrorObject::owned(INVALID_CONTINUATION_ERROR, msg, None::<()>),
+			Error::Storage(_) => ErrorObject::owned(STORAGE_ERROR, msg, None::<()>),
+			Error::FetchBlock(_) => ErrorObject::owned(FETCH_BLOCK_ERROR, msg, None::<()>),
+			Error::ReachedLimits => ErrorObject::owned(REACHED_LIMITS_ERROR, msg, None::<()>),
 		}
 		.into()
 	}
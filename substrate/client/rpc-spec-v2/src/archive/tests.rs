@@ -18,9 +18,10 @@
 
 use crate::{
 	common::events::{
-		ArchiveStorageDiffEvent, ArchiveStorageDiffItem, ArchiveStorageDiffOperationType,
-		ArchiveStorageDiffResult, ArchiveStorageDiffType, ArchiveStorageEvent, StorageQuery,
-		StorageQueryType, StorageResult, StorageResultType,
+		ArchiveReorg, ArchiveReorgEvent, ArchiveStorageDiffEvent, ArchiveStorageDiffItem,
+		ArchiveStorageDiffOperationType, ArchiveStorageDiffResult, ArchiveStorageDiffType,
+		ArchiveStorageEvent, ArchiveStoragePage, StorageQuery, StorageQueryType, StorageResult,
+		StorageResultType,
 	},
 	hex_string, MethodResult,
 };
@@ -36,7 +37,7 @@ use jsonrpsee::{
 
 use sc_block_builder::BlockBuilderBuilder;
 use sc_client_api::ChildInfo;
-use sc_rpc::testing::TokioTestExecutor;
+use sc_rpc::{testing::TokioTestExecutor, DenyUnsafe};
 use sp_blockchain::HeaderBackend;
 use sp_consensus::BlockOrigin;
 use sp_core::{Blake2Hasher, Hasher};
@@ -70,13 +71,14 @@ fn setup_api() -> (Arc<Client<Backend>>, RpcModule<Archive<Backend, Block, Clien
 	let backend = builder.backend();
 	let client = Arc::new(builder.build());
 
-	let api = Archive::new(
+	let mut api = Archive::new(
 		client.clone(),
 		backend,
 		CHAIN_GENESIS,
 		Arc::new(TokioTestExecutor::default()),
 	)
 	.into_rpc();
+	api.extensions_mut().insert(DenyUnsafe::No);
 
 	(client, api)
 }
@@ -747,6 +749,100 @@ async fn archive_storage_iterations() {
 	);
 }
 
+#[tokio::test]
+async fn archive_storage_paged() {
+	let (client, api) = setup_api();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	builder.push_storage_change(b":m".to_vec(), Some(b"a".to_vec())).unwrap();
+	builder.push_storage_change(b":mo".to_vec(), Some(b"ab".to_vec())).unwrap();
+	builder.push_storage_change(b":moc".to_vec(), Some(b"abc".to_vec())).unwrap();
+	builder.push_storage_change(b":moD".to_vec(), Some(b"abcmoD".to_vec())).unwrap();
+	builder.push_storage_change(b":mock".to_vec(), Some(b"abcd".to_vec())).unwrap();
+	let block = builder.build().unwrap().block;
+	let block_hash = format!("{:?}", block.header.hash());
+	client.import(BlockOrigin::Own, block.clone()).await.unwrap();
+
+	let value = |key: &[u8], value: &[u8]| StorageResult {
+		key: hex_string(&key),
+		result: StorageResultType::Value(hex_string(&value)),
+		child_trie_key: None,
+	};
+
+	// Only descendants queries can be paged.
+	let err = api
+		.call::<_, ArchiveStoragePage>(
+			"archive_unstable_storagePaged",
+			rpc_params![&block_hash, hex_string(b":m"), StorageQueryType::Value, 2],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3001);
+
+	let page: ArchiveStoragePage = api
+		.call(
+			"archive_unstable_storagePaged",
+			rpc_params![&block_hash, hex_string(b":m"), StorageQueryType::DescendantsValues, 2],
+		)
+		.await
+		.unwrap();
+	assert_eq!(page.items, vec![value(b":m", b"a"), value(b":mo", b"ab")]);
+	let continuation = page.continuation.unwrap();
+
+	// The continuation token cannot be used on another block.
+	let genesis_hash = format!("{:?}", client.chain_info().genesis_hash);
+	let err = api
+		.call::<_, ArchiveStoragePage>(
+			"archive_unstable_storagePaged",
+			rpc_params![
+				&genesis_hash,
+				hex_string(b":m"),
+				StorageQueryType::DescendantsValues,
+				2,
+				&continuation
+			],
+		)
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3004);
+
+	let page: ArchiveStoragePage = api
+		.call(
+			"archive_unstable_storagePaged",
+			rpc_params![
+				&block_hash,
+				hex_string(b":m"),
+				StorageQueryType::DescendantsValues,
+				2,
+				&continuation
+			],
+		)
+		.await
+		.unwrap();
+	assert_eq!(page.items, vec![value(b":moD", b"abcmoD"), value(b":moc", b"abc")]);
+	let continuation = page.continuation.unwrap();
+
+	let page: ArchiveStoragePage = api
+		.call(
+			"archive_unstable_storagePaged",
+			rpc_params![
+				&block_hash,
+				hex_string(b":m"),
+				StorageQueryType::DescendantsValues,
+				2,
+				&continuation
+			],
+		)
+		.await
+		.unwrap();
+	assert_eq!(page.items, vec![value(b":mock", b"abcd")]);
+	assert!(page.continuation.is_none());
+}
+
 #[tokio::test]
 async fn archive_storage_diff_main_trie() {
 	let (client, api) = setup_api();
@@ -1003,3 +1099,163 @@ async fn archive_storage_diff_invalid_params() {
 		ArchiveStorageDiffEvent::StorageDiffError(ref err) if err.error.contains("Header was not found")
 	);
 }
+
+#[tokio::test]
+async fn archive_reorgs() {
+	let (client, api) = setup_api();
+	let mut sub = api
+		.subscribe_unbounded("archive_unstable_reorgs", EmptyParams::new())
+		.await
+		.unwrap();
+
+	// Import block 1 and block 2 on top of it, with a transfer.
+	let block_1 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	client.import(BlockOrigin::Own, block_1.clone()).await.unwrap();
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 42,
+			nonce: 0,
+		})
+		.unwrap();
+	let block_2 = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block_2.clone()).await.unwrap();
+
+	// Import a fork of block 2, with another transfer. It does not become the best block.
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_1.hash())
+		.with_parent_block_number(1)
+		.build()
+		.unwrap();
+	builder
+		.push_transfer(Transfer {
+			from: Sr25519Keyring::Alice.into(),
+			to: Sr25519Keyring::Ferdie.into(),
+			amount: 41,
+			nonce: 0,
+		})
+		.unwrap();
+	let block_3 = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block_3.clone()).await.unwrap();
+	assert_eq!(client.chain_info().best_hash, block_2.hash());
+
+	// Block 4 on top of the fork becomes the best block.
+	let block_4 = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(block_3.hash())
+		.with_parent_block_number(2)
+		.build()
+		.unwrap()
+		.build()
+		.unwrap()
+		.block;
+	client.import(BlockOrigin::Own, block_4.clone()).await.unwrap();
+	assert_eq!(client.chain_info().best_hash, block_4.hash());
+
+	let extrinsic_hash =
+		|block: &Block| hex_string(&Blake2Hasher::hash(&block.extrinsics[0].encode()).as_ref());
+	let event = get_next_event::<ArchiveReorgEvent>(&mut sub).await;
+	assert_matches!(event, ArchiveReorgEvent::Reorg(ArchiveReorg {
+		common_ancestor,
+		retracted,
+		enacted,
+		dropped_extrinsics,
+	}) => {
+		assert_eq!(common_ancestor, hex_string(&block_1.hash().as_ref()));
+		assert_eq!(
+			retracted.iter().map(|block| block.hash.clone()).collect::<Vec<_>>(),
+			vec![hex_string(&block_2.hash().as_ref())],
+		);
+		assert_eq!(retracted[0].number, 2);
+		assert_eq!(
+			enacted.iter().map(|block| block.hash.clone()).collect::<Vec<_>>(),
+			vec![hex_string(&block_3.hash().as_ref()), hex_string(&block_4.hash().as_ref())],
+		);
+		assert_eq!(enacted[0].extrinsics, vec![extrinsic_hash(&block_3)]);
+		assert_eq!(dropped_extrinsics, vec![extrinsic_hash(&block_2)]);
+	});
+
+	// Finalizing block 2 moves the best block back to it, without importing any block.
+	client.finalize_block(block_2.hash(), None).unwrap();
+	assert_eq!(client.chain_info().best_hash, block_2.hash());
+
+	let event = get_next_event::<ArchiveReorgEvent>(&mut sub).await;
+	assert_matches!(event, ArchiveReorgEvent::Reorg(ArchiveReorg {
+		common_ancestor,
+		retracted,
+		enacted,
+		dropped_extrinsics,
+	}) => {
+		assert_eq!(common_ancestor, hex_string(&block_1.hash().as_ref()));
+		assert_eq!(
+			retracted.iter().map(|block| block.hash.clone()).collect::<Vec<_>>(),
+			vec![hex_string(&block_4.hash().as_ref()), hex_string(&block_3.hash().as_ref())],
+		);
+		assert_eq!(
+			enacted.iter().map(|block| block.hash.clone()).collect::<Vec<_>>(),
+			vec![hex_string(&block_2.hash().as_ref())],
+		);
+		assert_eq!(dropped_extrinsics, vec![extrinsic_hash(&block_3)]);
+	});
+}
+
+#[tokio::test]
+async fn archive_reorgs_is_unsafe() {
+	let (_client, mut api) = setup_api();
+	api.extensions_mut().insert(DenyUnsafe::Yes);
+
+	let err = api
+		.subscribe_unbounded("archive_unstable_reorgs", EmptyParams::new())
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.message().contains("RPC call is unsafe"));
+}
+
+#[tokio::test]
+async fn archive_reorgs_limits_subscriptions() {
+	let (_client, api) = setup_api();
+
+	let mut subs = Vec::new();
+	for _ in 0..4 {
+		subs.push(
+			api.subscribe_unbounded("archive_unstable_reorgs", EmptyParams::new())
+				.await
+				.unwrap(),
+		);
+	}
+
+	let err = api
+		.subscribe_unbounded("archive_unstable_reorgs", EmptyParams::new())
+		.await
+		.unwrap_err();
+	assert_matches!(err, Error::JsonRpc(err) if err.code() == 3007);
+
+	// Dropping a subscription frees its slot.
+	drop(subs.pop());
+	let mut accepted = false;
+	for _ in 0..100 {
+		if api
+			.subscribe_unbounded("archive_unstable_reorgs", EmptyParams::new())
+			.await
+			.is_ok()
+		{
+			accepted = true;
+			break
+		}
+		tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+	}
+	assert!(accepted);
+}
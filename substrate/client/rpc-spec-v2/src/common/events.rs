@@ -147,6 +147,18 @@ impl ArchiveStorageEvent {
 	}
 }
 
+/// A page of storage results of the `archive_unstable_storagePaged` method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveStoragePage {
+	/// The storage results of this page, in lexicographic key order.
+	pub items: Vec<StorageResult>,
+	/// The opaque token to fetch the next page with, if there are more results.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	#[serde(default)]
+	pub continuation: Option<String>,
+}
+
 /// The result of a storage call.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -262,6 +274,67 @@ impl ArchiveStorageDiffEvent {
 	}
 }
 
+/// A block of a re-org reported by the `archive_unstable_reorgs` method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveReorgBlock {
+	/// The hexadecimal-encoded hash of the block.
+	pub hash: String,
+	/// The height of the block.
+	pub number: u64,
+	/// The hexadecimal-encoded hashes of the extrinsics of the block, in the order of the block.
+	pub extrinsics: Vec<String>,
+}
+
+/// A re-org of the best chain, reported by the `archive_unstable_reorgs` method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveReorg {
+	/// The hexadecimal-encoded hash of the last block that both chains have in common.
+	pub common_ancestor: String,
+	/// The blocks that are no longer part of the best chain, from the old best block down to the
+	/// common ancestor.
+	pub retracted: Vec<ArchiveReorgBlock>,
+	/// The blocks that are now part of the best chain, from the common ancestor up to the new best
+	/// block.
+	pub enacted: Vec<ArchiveReorgBlock>,
+	/// The hexadecimal-encoded hashes of the extrinsics of the retracted blocks that are not
+	/// included in any of the enacted blocks.
+	pub dropped_extrinsics: Vec<String>,
+}
+
+/// The error of the `archive_unstable_reorgs` method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveReorgErr {
+	/// Reported error.
+	pub error: String,
+}
+
+/// The event generated by the `archive_unstable_reorgs` method.
+///
+/// The `archive_unstable_reorgs` can generate the following events:
+///  - `reorg` event - generated when the best block moves to a block that does not descend from the
+///    previous best block.
+///  - `reorgError` event - generated when a re-org cannot be reported, e.g. because the body of one
+///    of its blocks is not available. The subscription carries on with the next re-org.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "event")]
+pub enum ArchiveReorgEvent {
+	/// The `reorg` event.
+	Reorg(ArchiveReorg),
+	/// The `reorgError` event.
+	ReorgError(ArchiveReorgErr),
+}
+
+impl ArchiveReorgEvent {
+	/// Create a new `ArchiveReorgEvent::ReorgError` event.
+	pub fn err(error: String) -> Self {
+		Self::ReorgError(ArchiveReorgErr { error })
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
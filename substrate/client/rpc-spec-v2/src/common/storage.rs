@@ -199,6 +199,48 @@ where
 		}
 	}
 
+	/// Fetch at most `count` storage results of the iteration described by `query`.
+	///
+	/// Returns the results along with the key the iteration should resume after, if there are
+	/// more keys to iterate over.
+	pub fn query_iter_page(
+		&self,
+		query: QueryIter,
+		hash: Block::Hash,
+		child_key: Option<&ChildInfo>,
+		count: usize,
+	) -> Result<(Vec<StorageResult>, Option<StorageKey>), String> {
+		let QueryIter { ty, query_key, pagination_start_key } = query;
+
+		let keys_iter = if let Some(child_key) = child_key {
+			self.client.child_storage_keys(
+				hash,
+				child_key.to_owned(),
+				Some(&query_key),
+				pagination_start_key.as_ref(),
+			)
+		} else {
+			self.client.storage_keys(hash, Some(&query_key), pagination_start_key.as_ref())
+		}
+		.map_err(|error| error.to_string())?;
+
+		// Fetch one extra key to find out whether the iteration is complete.
+		let mut keys: Vec<_> = keys_iter.take(count.saturating_add(1)).collect();
+		let has_more = keys.len() > count;
+		keys.truncate(count);
+
+		let mut results = Vec::with_capacity(keys.len());
+		for key in &keys {
+			let result = match ty {
+				IterQueryType::Value => self.query_value(hash, key, child_key),
+				IterQueryType::Hash => self.query_hash(hash, key, child_key),
+			}?;
+			results.extend(result);
+		}
+
+		Ok((results, if has_more { keys.pop() } else { None }))
+	}
+
 	/// Raw iterator over the keys.
 	pub fn raw_keys_iter(
 		&self,
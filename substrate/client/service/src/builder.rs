@@ -495,6 +495,18 @@ where
 				prometheus_endpoint::init_prometheus(port, registry).map(drop),
 			);
 
+			let runtime_error_targets = sc_tracing::logging::runtime_error_targets();
+			if !runtime_error_targets.is_empty() {
+				spawn_handle.spawn(
+					"runtime-dispatch-errors",
+					None,
+					crate::runtime_errors::count_dispatch_errors(
+						client.clone(),
+						runtime_error_targets.to_vec(),
+					),
+				);
+			}
+
 			metrics
 		} else {
 			MetricsService::new(telemetry)
@@ -0,0 +1,125 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use codec::Decode;
+use serde::Serialize;
+use sp_api::CallApiAt;
+use sp_core::{
+	storage::well_known_keys,
+	traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+	H256,
+};
+use sp_externalities::Extensions;
+use sp_runtime::traits::{Block as BlockT, HashingFor};
+use sp_state_machine::{Backend, OverlayedChanges, StateMachine};
+use sp_version::RuntimeVersion;
+
+/// The runtime function that executes the runtime upgrade and returns its report.
+const DRY_RUN_UPGRADE: &str = "UpgradeDryRun_dry_run_upgrade";
+
+/// The outcome of dry-running a runtime upgrade.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", bound = "R: Serialize")]
+pub struct UpgradeDryRun<B: BlockT, R> {
+	/// The block on top of which the upgrade was executed.
+	pub at: B::Hash,
+	/// The blake2-256 hash of the new runtime code.
+	pub code_hash: H256,
+	/// The spec name of the new runtime.
+	pub spec_name: String,
+	/// The spec version of the new runtime.
+	pub spec_version: u32,
+	/// The number of storage keys changed by the upgrade, not counting the runtime code.
+	pub changed_keys: u32,
+	/// The report returned by the new runtime.
+	pub report: R,
+}
+
+/// Execute the runtime upgrade of `code` on top of the state of block `at`.
+///
+/// The new runtime is written to `:code` in an in-memory overlay and its
+/// `UpgradeDryRun::dry_run_upgrade` runtime API is called. The overlay is thrown away afterwards,
+/// so the database is never changed. The report returned by the runtime is decoded as `R`.
+pub fn dry_run_upgrade<B, C, E, R>(
+	client: &C,
+	executor: &E,
+	code: Vec<u8>,
+	at: B::Hash,
+) -> Result<UpgradeDryRun<B, R>, Error>
+where
+	B: BlockT,
+	C: CallApiAt<B>,
+	E: CodeExecutor + Clone + 'static,
+	R: Decode,
+{
+	let state = client.state_at(at).map_err(sp_blockchain::Error::from)?;
+	let mut extensions = Extensions::default();
+	client.initialize_extensions(at, &mut extensions).map_err(sp_blockchain::Error::from)?;
+
+	let code_hash = H256(sp_core::hashing::blake2_256(&code));
+	let heap_pages = state
+		.storage(well_known_keys::HEAP_PAGES)
+		.map_err(|e| format!("Failed to read the heap pages: {}", e))?
+		.and_then(|raw| u64::decode(&mut &raw[..]).ok());
+	let fetcher = WrappedRuntimeCode(code.as_slice().into());
+	let runtime_code =
+		RuntimeCode { code_fetcher: &fetcher, heap_pages, hash: code_hash.as_bytes().to_vec() };
+
+	let mut changes = OverlayedChanges::<HashingFor<B>>::default();
+	changes.set_storage(well_known_keys::CODE.to_vec(), Some(code.clone()));
+
+	let mut call = |method: &str, changes: &mut OverlayedChanges<HashingFor<B>>| {
+		StateMachine::new(
+			&state,
+			changes,
+			executor,
+			method,
+			&[],
+			&mut extensions,
+			&runtime_code,
+			CallContext::Offchain,
+		)
+		.set_parent_hash(at)
+		.execute()
+		.map_err(|e| format!("Failed to call `{}` of the new runtime: {}", method, e))
+	};
+
+	let version = call("Core_version", &mut Default::default())?;
+	let version = RuntimeVersion::decode(&mut &version[..])
+		.map_err(|e| format!("Failed to decode the version of the new runtime: {}", e))?;
+
+	let report = call(DRY_RUN_UPGRADE, &mut changes)?;
+	let report = R::decode(&mut &report[..])
+		.map_err(|e| format!("Failed to decode the upgrade report: {}", e))?;
+
+	let changed_keys = changes
+		.changes_mut()
+		.filter(|(key, _)| key.as_slice() != well_known_keys::CODE)
+		.filter(|(key, value)| state.storage(key).ok().flatten().as_ref() != value.value())
+		.count() as u32;
+
+	Ok(UpgradeDryRun {
+		at,
+		code_hash,
+		spec_name: version.spec_name.to_string(),
+		spec_version: version.spec_version,
+		changed_keys,
+		report,
+	})
+}
@@ -19,13 +19,17 @@
 //! Chain utilities.
 
 mod check_block;
+mod dry_run_upgrade;
 mod export_blocks;
 mod export_raw_state;
 mod import_blocks;
+mod replay_blocks;
 mod revert_chain;
 
 pub use check_block::*;
+pub use dry_run_upgrade::*;
 pub use export_blocks::*;
 pub use export_raw_state::*;
 pub use import_blocks::*;
+pub use replay_blocks::*;
 pub use revert_chain::*;
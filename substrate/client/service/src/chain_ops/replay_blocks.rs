@@ -0,0 +1,257 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::error::Error;
+use codec::{Compact, Decode, Encode};
+use sc_client_api::BlockBackend;
+use serde::Serialize;
+use sp_api::{CallApiAt, CallApiAtParams, CallContext};
+use sp_core::{
+	storage::{StorageData, StorageKey},
+	twox_128,
+};
+use sp_externalities::Extensions;
+use sp_runtime::{
+	traits::{Block as BlockT, HashingFor, Header as HeaderT, NumberFor},
+	ApplyExtrinsicResult, DigestItem,
+};
+use sp_state_machine::{Backend, OverlayedChanges};
+use std::{cell::RefCell, collections::BTreeMap};
+
+/// A single storage change made while replaying a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplayedStorageChange {
+	/// The changed key.
+	pub key: StorageKey,
+	/// The value before the change, `None` if the key did not exist.
+	pub pre: Option<StorageData>,
+	/// The value after the change, `None` if the key was removed.
+	pub post: Option<StorageData>,
+}
+
+/// The outcome of replaying a single extrinsic.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayedExtrinsic {
+	/// The index of the extrinsic in the block.
+	pub index: u32,
+	/// The [`ApplyExtrinsicResult`] returned by the runtime, or the raw result if it does not
+	/// decode.
+	pub result: String,
+	/// The `ref_time` consumed by the extrinsic.
+	///
+	/// Only known for FRAME based runtimes.
+	pub ref_time: Option<u64>,
+	/// The `proof_size` consumed by the extrinsic.
+	///
+	/// Only known for FRAME based runtimes.
+	pub proof_size: Option<u64>,
+	/// The number of events deposited by the extrinsic.
+	///
+	/// Only known for FRAME based runtimes.
+	pub events: Option<u32>,
+	/// The storage changes made by the extrinsic.
+	pub storage_changes: Vec<ReplayedStorageChange>,
+}
+
+/// The outcome of replaying a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", bound = "")]
+pub struct ReplayedBlock<B: BlockT> {
+	/// The number of the block.
+	pub number: NumberFor<B>,
+	/// The hash of the block.
+	pub hash: B::Hash,
+	/// The storage changes made while initializing the block.
+	pub initialize_changes: Vec<ReplayedStorageChange>,
+	/// The replayed extrinsics.
+	pub extrinsics: Vec<ReplayedExtrinsic>,
+	/// The storage changes made while finalizing the block.
+	pub finalize_changes: Vec<ReplayedStorageChange>,
+	/// Whether the replay ended in the state root of the original block.
+	///
+	/// This is expected to be `false` when the block is replayed with a different runtime.
+	pub state_root_matches: bool,
+}
+
+/// Re-execute the block `hash` on top of the state of its parent.
+///
+/// The block is executed extrinsic by extrinsic in an in-memory overlay that is thrown away
+/// afterwards, so the database is never changed. The runtime is the one the client would use to
+/// import the block, including any configured runtime overrides or code substitutes.
+pub fn replay_block<B, C>(client: &C, hash: B::Hash) -> Result<ReplayedBlock<B>, Error>
+where
+	B: BlockT,
+	C: BlockBackend<B> + CallApiAt<B>,
+{
+	let block = client.block(hash)?.ok_or("Unknown block")?.block;
+	let (mut header, extrinsics) = block.deconstruct();
+	let expected_state_root = *header.state_root();
+	// The seal is checked and removed by the consensus engine before the block is executed.
+	header.digest_mut().logs.retain(|item| !matches!(item, DigestItem::Seal(_, _)));
+
+	let replay = Replay::new(client, *header.parent_hash())?;
+	let mut snapshot = replay.snapshot();
+
+	replay.call("Core_initialize_block", header.encode())?;
+	let initialize_changes = replay.changes_since(&mut snapshot);
+
+	let mut replayed = Vec::with_capacity(extrinsics.len());
+	for (index, extrinsic) in extrinsics.into_iter().enumerate() {
+		let weight_before = replay.consumed_weight();
+		let events_before = replay.event_count();
+
+		let result = replay.call("BlockBuilder_apply_extrinsic", extrinsic.encode())?;
+		let result = match ApplyExtrinsicResult::decode(&mut &result[..]) {
+			Ok(result) => format!("{:?}", result),
+			Err(_) => format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&result)),
+		};
+		let weight = weight_before.zip(replay.consumed_weight()).map(|(before, after)| {
+			(after.0.saturating_sub(before.0), after.1.saturating_sub(before.1))
+		});
+
+		replayed.push(ReplayedExtrinsic {
+			index: index as u32,
+			result,
+			ref_time: weight.map(|w| w.0),
+			proof_size: weight.map(|w| w.1),
+			events: events_before
+				.zip(replay.event_count())
+				.map(|(before, after)| after.saturating_sub(before)),
+			storage_changes: replay.changes_since(&mut snapshot),
+		});
+	}
+
+	let finalized = replay.call("BlockBuilder_finalize_block", Vec::new())?;
+	let finalize_changes = replay.changes_since(&mut snapshot);
+	let state_root_matches = B::Header::decode(&mut &finalized[..])
+		.map_or(false, |header| *header.state_root() == expected_state_root);
+
+	Ok(ReplayedBlock {
+		number: *header.number(),
+		hash,
+		initialize_changes,
+		extrinsics: replayed,
+		finalize_changes,
+		state_root_matches,
+	})
+}
+
+/// The values of all keys changed in the overlay so far.
+type Snapshot = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+/// Mirrors `frame_system::ConsumedWeight`: `ref_time` and `proof_size` per dispatch class.
+#[derive(Decode)]
+struct ConsumedWeight([(Compact<u64>, Compact<u64>); 3]);
+
+/// Runtime calls on top of a forked, in-memory state.
+struct Replay<'a, B: BlockT, C: CallApiAt<B>> {
+	client: &'a C,
+	at: B::Hash,
+	state: C::StateBackend,
+	changes: RefCell<OverlayedChanges<HashingFor<B>>>,
+	extensions: RefCell<Extensions>,
+}
+
+impl<'a, B: BlockT, C: CallApiAt<B>> Replay<'a, B, C> {
+	fn new(client: &'a C, at: B::Hash) -> Result<Self, Error> {
+		let mut extensions = Extensions::default();
+		client.initialize_extensions(at, &mut extensions).map_err(sp_blockchain::Error::from)?;
+		let state = client.state_at(at).map_err(sp_blockchain::Error::from)?;
+
+		Ok(Self {
+			client,
+			at,
+			state,
+			changes: Default::default(),
+			extensions: RefCell::new(extensions),
+		})
+	}
+
+	fn call(&self, function: &'static str, arguments: Vec<u8>) -> Result<Vec<u8>, Error> {
+		self.client
+			.call_api_at(CallApiAtParams {
+				at: self.at,
+				function,
+				arguments,
+				overlayed_changes: &self.changes,
+				call_context: CallContext::Onchain,
+				recorder: &None,
+				extensions: &self.extensions,
+			})
+			.map_err(|e| sp_blockchain::Error::from(e).into())
+	}
+
+	fn storage(&self, key: &[u8]) -> Option<Vec<u8>> {
+		match self.changes.borrow_mut().storage(key) {
+			Some(value) => value.map(|v| v.to_vec()),
+			None => self.state.storage(key).ok().flatten(),
+		}
+	}
+
+	fn snapshot(&self) -> Snapshot {
+		self.changes
+			.borrow_mut()
+			.changes_mut()
+			.map(|(key, value)| (key.clone(), value.value().cloned()))
+			.collect()
+	}
+
+	/// The storage changes since `snapshot`, which is moved forward to the current state.
+	fn changes_since(&self, snapshot: &mut Snapshot) -> Vec<ReplayedStorageChange> {
+		let current = self.snapshot();
+		let changes = current
+			.iter()
+			.filter(|(key, value)| snapshot.get(*key) != Some(*value))
+			.filter_map(|(key, post)| {
+				let pre = match snapshot.get(key) {
+					Some(pre) => pre.clone(),
+					None => self.state.storage(key).ok().flatten(),
+				};
+				(pre != *post).then(|| ReplayedStorageChange {
+					key: StorageKey(key.clone()),
+					pre: pre.map(StorageData),
+					post: post.clone().map(StorageData),
+				})
+			})
+			.collect();
+
+		*snapshot = current;
+		changes
+	}
+
+	/// The total `ref_time` and `proof_size` consumed in the block so far.
+	fn consumed_weight(&self) -> Option<(u64, u64)> {
+		let raw = self.storage(&system_key(b"BlockWeight"))?;
+		let ConsumedWeight(classes) = ConsumedWeight::decode(&mut &raw[..]).ok()?;
+		Some(classes.iter().fold((0u64, 0u64), |(ref_time, proof_size), (r, p)| {
+			(ref_time.saturating_add(r.0), proof_size.saturating_add(p.0))
+		}))
+	}
+
+	/// The number of events deposited in the block so far.
+	fn event_count(&self) -> Option<u32> {
+		let raw = self.storage(&system_key(b"EventCount"))?;
+		u32::decode(&mut &raw[..]).ok()
+	}
+}
+
+/// The storage key of a `frame_system` storage value.
+fn system_key(item: &[u8]) -> Vec<u8> {
+	[twox_128(b"System"), twox_128(item)].concat()
+}
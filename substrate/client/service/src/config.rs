@@ -154,6 +154,8 @@ pub struct OffchainWorkerConfig {
 	pub enabled: bool,
 	/// allow writes from the runtime to the offchain worker database.
 	pub indexing_enabled: bool,
+	/// Maximum size of the body of the http responses read by offchain workers, after decoding.
+	pub http_response_size_limit: Option<usize>,
 }
 
 /// Configuration of the Prometheus endpoint.
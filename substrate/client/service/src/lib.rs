@@ -29,6 +29,7 @@ pub mod error;
 
 mod builder;
 mod metrics;
+mod runtime_errors;
 mod task_manager;
 
 use crate::config::Multiaddr;
@@ -0,0 +1,362 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Counting of the failed dispatches of monitored pallets in imported blocks.
+//!
+//! The `System::Events` of every imported block are decoded with the runtime metadata. Every
+//! `System::ExtrinsicFailed` event with a module error of a monitored pallet is counted in the
+//! `substrate_runtime_dispatch_errors_total` metric, labelled by pallet and error name.
+
+use codec::{Compact, Decode};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use futures::StreamExt;
+use log::debug;
+use sc_client_api::{Backend, BlockchainEvents, StorageProvider};
+use sc_utils::metrics::RUNTIME_DISPATCH_ERRORS;
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use sp_api::{CallApiAt, Metadata, ProvideRuntimeApi};
+use sp_core::{storage::StorageKey, twox_128};
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::BTreeMap, sync::Arc};
+
+const LOG_TARGET: &str = "runtime-errors";
+
+/// Count the failed dispatches of the pallets matching `targets` in every imported block.
+///
+/// `targets` are runtime log targets, such as `runtime::stake-tracker`. A target matches the
+/// pallet of the same name, ignoring case, `-` and `_`.
+pub async fn count_dispatch_errors<Block, Client, BE>(client: Arc<Client>, targets: Vec<String>)
+where
+	Block: BlockT,
+	BE: Backend<Block>,
+	Client: BlockchainEvents<Block>
+		+ StorageProvider<Block, BE>
+		+ ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>,
+	Client::Api: Metadata<Block>,
+{
+	let targets = targets.iter().map(|target| normalize(target)).collect::<Vec<_>>();
+	let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+	let mut decoder: Option<(u32, EventsDecoder)> = None;
+	let mut imports = client.import_notification_stream();
+
+	while let Some(notification) = imports.next().await {
+		let hash = notification.hash;
+		let Ok(version) = client.runtime_version_at(hash) else { continue };
+		if decoder
+			.as_ref()
+			.map_or(true, |(spec_version, _)| *spec_version != version.spec_version)
+		{
+			decoder = client
+				.runtime_api()
+				.metadata(hash)
+				.ok()
+				.and_then(|metadata| RuntimeMetadataPrefixed::decode(&mut &metadata[..]).ok())
+				.and_then(|metadata| EventsDecoder::new(metadata.1, &targets))
+				.map(|decoder| (version.spec_version, decoder));
+		}
+		let Some((_, decoder)) = &decoder else {
+			debug!(target: LOG_TARGET, "Failed to read the metadata at {hash:?}");
+			continue
+		};
+
+		let Ok(Some(events)) = client.storage(hash, &events_key) else { continue };
+		match decoder.dispatch_errors(&events.0) {
+			Some(errors) =>
+				for (pallet, error) in errors {
+					RUNTIME_DISPATCH_ERRORS.with_label_values(&[pallet, error]).inc();
+				},
+			None => debug!(target: LOG_TARGET, "Failed to decode the events of {hash:?}"),
+		}
+	}
+}
+
+/// Lowercase `target` without the `runtime::` prefix, sub-targets, `-` and `_`.
+fn normalize(target: &str) -> String {
+	let target = target.strip_prefix("runtime::").unwrap_or(target);
+	let pallet = target.split("::").next().unwrap_or(target);
+	pallet
+		.chars()
+		.filter(|c| *c != '-' && *c != '_')
+		.collect::<String>()
+		.to_lowercase()
+}
+
+/// Finds the module errors of monitored pallets in the encoded `System::Events`.
+struct EventsDecoder {
+	types: PortableRegistry,
+	/// The type of `System::Events`.
+	events_ty: u32,
+	/// The names of the errors of the monitored pallets, by pallet index.
+	errors: BTreeMap<u8, (String, BTreeMap<u8, String>)>,
+}
+
+impl EventsDecoder {
+	/// Returns `None` if the metadata version is not supported or it has no `System::Events`.
+	fn new(metadata: RuntimeMetadata, targets: &[String]) -> Option<Self> {
+		macro_rules! from_pallets {
+			($metadata:expr) => {{
+				let metadata = $metadata;
+				let events_ty = metadata
+					.pallets
+					.iter()
+					.find(|pallet| pallet.name == "System")?
+					.storage
+					.as_ref()?
+					.entries
+					.iter()
+					.find(|entry| entry.name == "Events")
+					.map(|entry| match &entry.ty {
+						frame_metadata::v14::StorageEntryType::Plain(ty) => ty.id,
+						frame_metadata::v14::StorageEntryType::Map { value, .. } => value.id,
+					})?;
+				let errors = metadata
+					.pallets
+					.iter()
+					.filter(|pallet| targets.contains(&normalize(&pallet.name)))
+					.map(|pallet| {
+						let errors = pallet
+							.error
+							.as_ref()
+							.and_then(|error| metadata.types.resolve(error.ty.id))
+							.map(|ty| match &ty.type_def {
+								TypeDef::Variant(variant) => variant
+									.variants
+									.iter()
+									.map(|v| (v.index, v.name.clone()))
+									.collect(),
+								_ => BTreeMap::new(),
+							})
+							.unwrap_or_default();
+						(pallet.index, (pallet.name.clone(), errors))
+					})
+					.collect();
+				Some(Self { types: metadata.types, events_ty, errors })
+			}};
+		}
+
+		match metadata {
+			RuntimeMetadata::V14(metadata) => from_pallets!(metadata),
+			RuntimeMetadata::V15(metadata) => from_pallets!(metadata),
+			_ => None,
+		}
+	}
+
+	/// The pallet and error names of the monitored module errors in `events`.
+	fn dispatch_errors(&self, mut events: &[u8]) -> Option<Vec<(&str, &str)>> {
+		let input = &mut events;
+		let mut module_errors = Vec::new();
+
+		let TypeDef::Sequence(records) = &self.types.resolve(self.events_ty)?.type_def else {
+			return None
+		};
+		let len = Compact::<u32>::decode(input).ok()?.0;
+		for _ in 0..len {
+			let TypeDef::Composite(record) = &self.types.resolve(records.type_param.id)?.type_def
+			else {
+				return None
+			};
+			for field in &record.fields {
+				match field.name.as_deref() {
+					Some("event") => module_errors.extend(self.module_error(field.ty.id, input)?),
+					_ => skip(&self.types, field.ty.id, input)?,
+				}
+			}
+		}
+
+		Some(
+			module_errors
+				.into_iter()
+				.filter_map(|(pallet, error)| {
+					let (name, errors) = self.errors.get(&pallet)?;
+					Some((name.as_str(), errors.get(&error).map_or("Unknown", String::as_str)))
+				})
+				.collect(),
+		)
+	}
+
+	/// Decode a `RuntimeEvent`, returning the pallet and error index if it is
+	/// `System::ExtrinsicFailed` with a module error.
+	fn module_error(&self, ty: u32, input: &mut &[u8]) -> Option<Option<(u8, u8)>> {
+		let (pallet, fields) = variant(&self.types, ty, input)?;
+		let [event] = fields else { return skip_fields(&self.types, fields, input).map(|_| None) };
+		let (name, fields) = variant(&self.types, event.ty.id, input)?;
+		if pallet != "System" || name != "ExtrinsicFailed" {
+			return skip_fields(&self.types, fields, input).map(|_| None)
+		}
+
+		let mut module_error = None;
+		for field in fields {
+			if field.name.as_deref() == Some("dispatch_error") {
+				let (name, error_fields) = variant(&self.types, field.ty.id, input)?;
+				if name == "Module" {
+					// `ModuleError` starts with the pallet index and the error index.
+					module_error = Some((*input.first()?, *input.get(1)?));
+				}
+				skip_fields(&self.types, error_fields, input)?;
+			} else {
+				skip(&self.types, field.ty.id, input)?;
+			}
+		}
+		Some(module_error)
+	}
+}
+
+/// Decode the index of the enum `ty`, returning the name and fields of the variant.
+fn variant<'a>(
+	types: &'a PortableRegistry,
+	ty: u32,
+	input: &mut &[u8],
+) -> Option<(&'a str, &'a [Field<PortableForm>])> {
+	let TypeDef::Variant(variants) = &types.resolve(ty)?.type_def else { return None };
+	let index = u8::decode(input).ok()?;
+	let variant = variants.variants.iter().find(|v| v.index == index)?;
+	Some((variant.name.as_str(), &variant.fields))
+}
+
+fn skip_fields(
+	types: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	input: &mut &[u8],
+) -> Option<()> {
+	fields.iter().try_for_each(|field| skip(types, field.ty.id, input))
+}
+
+/// Skip a value of type `ty` in `input`.
+fn skip(types: &PortableRegistry, ty: u32, input: &mut &[u8]) -> Option<()> {
+	let len = match &types.resolve(ty)?.type_def {
+		TypeDef::Composite(composite) => return skip_fields(types, &composite.fields, input),
+		TypeDef::Variant(_) => {
+			let (_, fields) = variant(types, ty, input)?;
+			return skip_fields(types, fields, input)
+		},
+		TypeDef::Sequence(sequence) => {
+			let len = Compact::<u32>::decode(input).ok()?.0;
+			return (0..len).try_for_each(|_| skip(types, sequence.type_param.id, input))
+		},
+		TypeDef::Array(array) =>
+			return (0..array.len).try_for_each(|_| skip(types, array.type_param.id, input)),
+		TypeDef::Tuple(tuple) =>
+			return tuple.fields.iter().try_for_each(|field| skip(types, field.id, input)),
+		TypeDef::Compact(_) => return Compact::<u128>::decode(input).ok().map(drop),
+		TypeDef::BitSequence(_) => return None,
+		TypeDef::Primitive(primitive) => match primitive {
+			TypeDefPrimitive::Bool | TypeDefPrimitive::U8 | TypeDefPrimitive::I8 => 1,
+			TypeDefPrimitive::U16 | TypeDefPrimitive::I16 => 2,
+			TypeDefPrimitive::Char | TypeDefPrimitive::U32 | TypeDefPrimitive::I32 => 4,
+			TypeDefPrimitive::U64 | TypeDefPrimitive::I64 => 8,
+			TypeDefPrimitive::U128 | TypeDefPrimitive::I128 => 16,
+			TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => 32,
+			TypeDefPrimitive::Str => Compact::<u32>::decode(input).ok()?.0 as usize,
+		},
+	};
+	*input = input.get(len..)?;
+	Some(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use scale_info::{meta_type, Registry, TypeInfo};
+
+	#[derive(Encode, TypeInfo)]
+	struct ModuleError {
+		index: u8,
+		error: [u8; 4],
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum DispatchError {
+		Other(String),
+		Module(ModuleError),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum SystemEvent {
+		ExtrinsicSuccess { dispatch_info: u64 },
+		ExtrinsicFailed { dispatch_error: DispatchError, dispatch_info: u64 },
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum TrackerEvent {
+		Tracked(u32, Vec<u8>),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	enum RuntimeEvent {
+		#[codec(index = 0)]
+		System(SystemEvent),
+		#[codec(index = 5)]
+		StakeTracker(TrackerEvent),
+	}
+
+	#[derive(Encode, TypeInfo)]
+	struct EventRecord {
+		phase: Option<u32>,
+		event: RuntimeEvent,
+		topics: Vec<[u8; 32]>,
+	}
+
+	fn record(event: RuntimeEvent) -> EventRecord {
+		EventRecord { phase: Some(1), event, topics: vec![[7; 32]] }
+	}
+
+	fn failed(dispatch_error: DispatchError) -> EventRecord {
+		record(RuntimeEvent::System(SystemEvent::ExtrinsicFailed {
+			dispatch_error,
+			dispatch_info: 3,
+		}))
+	}
+
+	#[test]
+	fn finds_module_errors_of_monitored_pallets() {
+		let mut registry = Registry::new();
+		let events_ty = registry.register_type(&meta_type::<Vec<EventRecord>>()).id;
+		let decoder = EventsDecoder {
+			types: registry.into(),
+			events_ty,
+			errors: [(5, ("StakeTracker".to_string(), [(1, "Boom".to_string())].into()))].into(),
+		};
+
+		let events = vec![
+			record(RuntimeEvent::StakeTracker(TrackerEvent::Tracked(1, vec![1, 2]))),
+			failed(DispatchError::Module(ModuleError { index: 5, error: [1, 0, 0, 0] })),
+			record(RuntimeEvent::System(SystemEvent::ExtrinsicSuccess { dispatch_info: 2 })),
+			failed(DispatchError::Other("not a module error".into())),
+			// not monitored.
+			failed(DispatchError::Module(ModuleError { index: 6, error: [1, 0, 0, 0] })),
+			failed(DispatchError::Module(ModuleError { index: 5, error: [9, 0, 0, 0] })),
+		];
+
+		assert_eq!(
+			decoder.dispatch_errors(&events.encode()),
+			Some(vec![("StakeTracker", "Boom"), ("StakeTracker", "Unknown")]),
+		);
+		// trailing garbage of a truncated record is not decoded.
+		assert_eq!(decoder.dispatch_errors(&events.encode()[..20]), None);
+	}
+
+	#[test]
+	fn normalize_matches_pallet_names() {
+		assert_eq!(normalize("runtime::stake-tracker"), normalize("StakeTracker"));
+		assert_eq!(normalize("runtime::staking::migration"), normalize("Staking"));
+		assert_eq!(normalize("voter_list"), normalize("VoterList"));
+		assert_ne!(normalize("runtime::staking"), normalize("StakeTracker"));
+	}
+}
@@ -17,5 +17,7 @@
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
 mod prefix_layer;
+mod runtime_errors_layer;
 
 pub use prefix_layer::*;
+pub use runtime_errors_layer::*;
@@ -0,0 +1,120 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use sc_utils::metrics::RUNTIME_LOG_EVENTS;
+use std::sync::OnceLock;
+use tracing::{Event, Level, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::{layer::Context, Layer};
+
+/// Log target used by `frame_support::defensive!` and the `Defensive*` traits.
+pub const DEFENSIVE_LOG_TARGET: &str = "runtime::defensive";
+
+/// The targets the global logger was initialized with.
+static RUNTIME_ERROR_TARGETS: OnceLock<Vec<String>> = OnceLock::new();
+
+/// The runtime log targets whose error-class events are counted in Prometheus.
+///
+/// Empty until the global logger was initialized with
+/// [`LoggerBuilder::with_runtime_error_metrics`](crate::logging::LoggerBuilder::with_runtime_error_metrics).
+pub fn runtime_error_targets() -> &'static [String] {
+	RUNTIME_ERROR_TARGETS.get().map_or(&[], Vec::as_slice)
+}
+
+pub(crate) fn set_runtime_error_targets(targets: Vec<String>) {
+	let _ = RUNTIME_ERROR_TARGETS.set(targets);
+}
+
+/// A `Layer` that counts runtime anomalies in the `substrate_runtime_log_events_total` metric.
+///
+/// Every event logged to [`DEFENSIVE_LOG_TARGET`] is counted with the `defensive` kind. Error and
+/// warning events of the configured targets, or any of their sub-targets, are counted with the
+/// `error` and `warn` kinds respectively. This lets node operators alert on anomalies that the
+/// runtime otherwise handles silently.
+pub struct RuntimeErrorsLayer {
+	targets: Vec<String>,
+}
+
+impl RuntimeErrorsLayer {
+	/// Create a new [`RuntimeErrorsLayer`] counting error-class events of the given `targets`.
+	pub fn new(targets: Vec<String>) -> Self {
+		Self { targets }
+	}
+
+	fn is_tracked(&self, target: &str) -> bool {
+		self.targets.iter().any(|tracked| {
+			target
+				.strip_prefix(tracked.as_str())
+				.map_or(false, |rest| rest.is_empty() || rest.starts_with("::"))
+		})
+	}
+}
+
+impl<S: Subscriber> Layer<S> for RuntimeErrorsLayer {
+	fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+		// Runtime logs are emitted through the `log` crate, so we need the original metadata.
+		let normalized_meta = event.normalized_metadata();
+		let meta = normalized_meta.as_ref().unwrap_or_else(|| event.metadata());
+		let target = meta.target();
+
+		let kind = if target == DEFENSIVE_LOG_TARGET {
+			"defensive"
+		} else if !self.is_tracked(target) {
+			return
+		} else {
+			match *meta.level() {
+				Level::ERROR => "error",
+				Level::WARN => "warn",
+				_ => return,
+			}
+		};
+
+		RUNTIME_LOG_EVENTS.with_label_values(&[target, kind]).inc();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use tracing_subscriber::layer::SubscriberExt;
+
+	fn count(target: &str, kind: &str) -> u64 {
+		RUNTIME_LOG_EVENTS.with_label_values(&[target, kind]).get()
+	}
+
+	#[test]
+	fn counts_defensive_and_tracked_error_events() {
+		let layer = RuntimeErrorsLayer::new(vec!["runtime::test-tracker".into()]);
+		let subscriber = tracing_subscriber::registry().with(layer);
+		let defensive_before = count(DEFENSIVE_LOG_TARGET, "defensive");
+
+		tracing::subscriber::with_default(subscriber, || {
+			tracing::error!(target: "runtime::defensive", "defensive failure");
+			tracing::error!(target: "runtime::test-tracker", "error");
+			tracing::warn!(target: "runtime::test-tracker::sub", "warning");
+			tracing::info!(target: "runtime::test-tracker", "info is not counted");
+			tracing::error!(target: "runtime::test-trackerx", "other targets are not counted");
+		});
+
+		assert_eq!(count(DEFENSIVE_LOG_TARGET, "defensive"), defensive_before + 1);
+		assert_eq!(count("runtime::test-tracker", "error"), 1);
+		assert_eq!(count("runtime::test-tracker::sub", "warn"), 1);
+		assert_eq!(count("runtime::test-tracker", "warn"), 0);
+		assert_eq!(count("runtime::test-trackerx", "error"), 0);
+	}
+}
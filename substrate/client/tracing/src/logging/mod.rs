@@ -97,6 +97,7 @@ fn prepare_subscriber<N, E, F, W>(
 	profiling_targets: Option<&str>,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	runtime_error_targets: Vec<String>,
 	builder_hook: impl Fn(
 		SubscriberBuilder<format::DefaultFields, EventFormat, EnvFilter, DefaultLogger>,
 	) -> SubscriberBuilder<N, E, F, W>,
@@ -206,7 +207,10 @@ where
 
 	let builder = builder_hook(builder);
 
-	let subscriber = builder.finish().with(PrefixLayer);
+	let subscriber = builder
+		.finish()
+		.with(PrefixLayer)
+		.with(RuntimeErrorsLayer::new(runtime_error_targets));
 
 	Ok(subscriber)
 }
@@ -219,6 +223,7 @@ pub struct LoggerBuilder {
 	log_reloading: bool,
 	force_colors: Option<bool>,
 	detailed_output: bool,
+	runtime_error_targets: Vec<String>,
 }
 
 impl LoggerBuilder {
@@ -231,6 +236,7 @@ impl LoggerBuilder {
 			log_reloading: false,
 			force_colors: None,
 			detailed_output: false,
+			runtime_error_targets: Vec::new(),
 		}
 	}
 
@@ -270,6 +276,15 @@ impl LoggerBuilder {
 		self
 	}
 
+	/// Log targets whose error and warning events are counted in the
+	/// `substrate_runtime_log_events_total` metric.
+	///
+	/// Defensive failures are always counted. See [`RuntimeErrorsLayer`] for details.
+	pub fn with_runtime_error_metrics(&mut self, targets: Vec<String>) -> &mut Self {
+		self.runtime_error_targets = targets;
+		self
+	}
+
 	/// Force enable/disable colors.
 	pub fn with_colors(&mut self, enable: bool) -> &mut Self {
 		self.force_colors = Some(enable);
@@ -280,6 +295,8 @@ impl LoggerBuilder {
 	///
 	/// This sets various global logging and tracing instances and thus may only be called once.
 	pub fn init(self) -> Result<()> {
+		layers::set_runtime_error_targets(self.runtime_error_targets.clone());
+
 		if let Some((tracing_receiver, profiling_targets)) = self.profiling {
 			if self.log_reloading {
 				let subscriber = prepare_subscriber(
@@ -287,6 +304,7 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.runtime_error_targets,
 					|builder| enable_log_reloading!(builder),
 				)?;
 				let mut profiling =
@@ -305,6 +323,7 @@ impl LoggerBuilder {
 					Some(&profiling_targets),
 					self.force_colors,
 					self.detailed_output,
+					self.runtime_error_targets,
 					|builder| builder,
 				)?;
 				let mut profiling =
@@ -324,6 +343,7 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.runtime_error_targets,
 				|builder| enable_log_reloading!(builder),
 			)?;
 
@@ -336,6 +356,7 @@ impl LoggerBuilder {
 				None,
 				self.force_colors,
 				self.detailed_output,
+				self.runtime_error_targets,
 				|builder| builder,
 			)?;
 
@@ -21,7 +21,10 @@
 use crate::{
 	common::api::FullChainApi,
 	fork_aware_txpool::ForkAwareTxPool as ForkAwareFullPool,
-	graph::{base_pool::Transaction, ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options},
+	graph::{
+		base_pool::{Limit, PriorityLane, Transaction},
+		ChainApi, ExtrinsicFor, ExtrinsicHash, IsValidator, Options,
+	},
 	single_state_txpool::BasicPool as SingleStateFullPool,
 	TransactionPoolWrapper, LOG_TARGET,
 };
@@ -84,6 +87,21 @@ impl TransactionPoolOptions {
 		TransactionPoolOptions { options, txpool_type }
 	}
 
+	/// Reserve a `reserved_percent` share of the ready queue limits for transactions with at least
+	/// `min_priority`.
+	///
+	/// See [`PriorityLane`] for details.
+	pub fn with_priority_lane(mut self, min_priority: u64, reserved_percent: u8) -> Self {
+		let percent = usize::from(reserved_percent.min(100));
+		let share = |limit: usize| limit.saturating_mul(percent) / 100;
+		let ready = &self.options.ready;
+		self.options.priority_lane = Some(PriorityLane {
+			min_priority,
+			reserved: Limit { count: share(ready.count), total_bytes: share(ready.total_bytes) },
+		});
+		self
+	}
+
 	/// Creates predefined options for benchmarking
 	pub fn new_for_benchmarks() -> TransactionPoolOptions {
 		TransactionPoolOptions {
@@ -98,6 +116,7 @@ impl TransactionPoolOptions {
 				},
 				reject_future_transactions: false,
 				ban_time: Duration::from_secs(30 * 60),
+				priority_lane: None,
 			},
 			txpool_type: TransactionPoolType::SingleState,
 		}
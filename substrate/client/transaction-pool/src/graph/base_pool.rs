@@ -433,52 +433,98 @@ impl<Hash: hash::Hash + Member + Serialize, Ex: std::fmt::Debug> BasePool<Hash,
 		ready.into_iter().zip(future).map(|(a, b)| a.or(b)).collect()
 	}
 
-	/// Returns pool transaction by hash.
-	pub fn ready_by_hash(&self, hash: &Hash) -> Option<Arc<Transaction<Hash, Ex>>> {
+	/// Removes the worst ready transaction whose priority matches `filter`, and all transactions
+	/// that depend on it.
+	///
+	/// Returns `None` if no ready transaction matches `filter`.
+	fn remove_worst_ready(
+		&mut self,
+		filter: impl Fn(Priority) -> bool,
+	) -> Option<Vec<Arc<Transaction<Hash, Ex>>>> {
+		// find the worst transaction
+		let worst = self.ready.fold::<TransactionRef<Hash, Ex>, _>(|worst, current| {
+			let transaction = &current.transaction;
+			if !filter(transaction.transaction.priority) {
+				return worst
+			}
+			worst
+				.map(|worst| {
+					// Here we don't use `TransactionRef`'s ordering implementation because
+					// while it prefers priority like need here, it also prefers older
+					// transactions for inclusion purposes and limit enforcement needs to prefer
+					// newer transactions instead and drop the older ones.
+					match worst.transaction.priority.cmp(&transaction.transaction.priority) {
+						Ordering::Less => worst,
+						Ordering::Equal =>
+							if worst.insertion_id > transaction.insertion_id {
+								transaction.clone()
+							} else {
+								worst
+							},
+						Ordering::Greater => transaction.clone(),
+					}
+				})
+				.or_else(|| Some(transaction.clone()))
+		})?;
+
+		Some(self.remove_subtree(&[worst.transaction.hash.clone()]))
+	}
+
+	/// Returns pool transaction by hash.&self, hash: &Hash) -> Option<Arc<Transaction<Hash, Ex>>> {
 		self.ready.by_hash(hash)
 	}
 
+	/// Returns the number and the total size of the ready transactions outside of the priority
+	/// `lane`.
+	pub fn ready_outside_lane(&mut self, lane: &PriorityLane) -> (usize, usize) {
+		self.ready
+			.fold(|acc, current| {
+				let (count, bytes) = acc.unwrap_or_default();
+				let transaction = &current.transaction.transaction;
+				if lane.contains(transaction.priority) {
+					Some((count, bytes))
+				} else {
+					Some((count + 1, bytes + transaction.bytes))
+				}
+			})
+			.unwrap_or_default()
+	}
+
 	/// Makes sure that the transactions in the queues stay within provided limits.
 	///
 	/// Removes and returns worst transactions from the queues and all transactions that depend on
 	/// them. Technically the worst transaction should be evaluated by computing the entire pending
 	/// set. We use a simplified approach to remove transactions with the lowest priority first or
 	/// those that occupy the pool for the longest time in case priority is the same.
+	///
+	/// If a `priority_lane` is given, the ready transactions outside of the lane are first limited
+	/// to the part of the `ready` limits that is not reserved for the lane.
 	pub fn enforce_limits(
 		&mut self,
 		ready: &Limit,
 		future: &Limit,
+		priority_lane: Option<&PriorityLane>,
 	) -> Vec<Arc<Transaction<Hash, Ex>>> {
 		let mut removed = vec![];
 
-		while ready.is_exceeded(self.ready.len(), self.ready.bytes()) {
-			// find the worst transaction
-			let worst = self.ready.fold::<TransactionRef<Hash, Ex>, _>(|worst, current| {
-				let transaction = &current.transaction;
-				worst
-					.map(|worst| {
-						// Here we don't use `TransactionRef`'s ordering implementation because
-						// while it prefers priority like need here, it also prefers older
-						// transactions for inclusion purposes and limit enforcement needs to prefer
-						// newer transactions instead and drop the older ones.
-						match worst.transaction.priority.cmp(&transaction.transaction.priority) {
-							Ordering::Less => worst,
-							Ordering::Equal =>
-								if worst.insertion_id > transaction.insertion_id {
-									transaction.clone()
-								} else {
-									worst
-								},
-							Ordering::Greater => transaction.clone(),
-						}
-					})
-					.or_else(|| Some(transaction.clone()))
-			});
+		if let Some(lane) = priority_lane {
+			let shared = lane.shared_limit(ready);
+			loop {
+				let (count, bytes) = self.ready_outside_lane(lane);
+				if !shared.is_exceeded(count, bytes) {
+					break
+				}
+				match self.remove_worst_ready(|priority| !lane.contains(priority)) {
+					Some(mut worst) => removed.append(&mut worst),
+					None => break,
+				}
+			}
+		}
 
-			if let Some(worst) = worst {
-				removed.append(&mut self.remove_subtree(&[worst.transaction.hash.clone()]))
-			} else {
-				break
+		while ready.is_exceeded(self.ready.len(), self.ready.bytes()) {
+			match self.remove_worst_ready(|_| true) {
+				Some(mut worst) => removed.append(&mut worst),
+				None => break,
 			}
 		}
 
@@ -610,6 +656,34 @@ impl Limit {
 	}
 }
 
+/// A lane of the ready queue reserved for high priority transactions.
+///
+/// Transactions with at least `min_priority` belong to the lane. Transactions outside of the lane
+/// can only use the part of the ready queue limits that is not `reserved`, so that the lane keeps
+/// room for transactions such as operational staking calls when the pool is congested.
+#[derive(Debug, Clone)]
+pub struct PriorityLane {
+	/// The minimal priority of the transactions in the lane.
+	pub min_priority: Priority,
+	/// The part of the ready queue limits reserved for the lane.
+	pub reserved: Limit,
+}
+
+impl PriorityLane {
+	/// Returns true if a transaction with the given `priority` belongs to the lane.
+	pub fn contains(&self, priority: Priority) -> bool {
+		priority >= self.min_priority
+	}
+
+	/// Returns the part of the `ready` limits that transactions outside of the lane can use.
+	pub fn shared_limit(&self, ready: &Limit) -> Limit {
+		Limit {
+			count: ready.count.saturating_sub(self.reserved.count),
+			total_bytes: ready.total_bytes.saturating_sub(self.reserved.total_bytes),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1254,4 +1328,32 @@ source: TimedTransactionSource { source: TransactionSource::External, timestamp:
 		assert_eq!(pool.reject_future_transactions, true);
 		assert_eq!(pool.future.len(), 1);
 	}
+
+	#[test]
+	fn priority_lane_reserves_ready_limits() {
+		// given
+		let mut pool = pool();
+		let ready = Limit { count: 4, total_bytes: 100 };
+		let lane = PriorityLane { min_priority: 100, reserved: Limit { count: 2, total_bytes: 0 } };
+		for hash in 1..=3u64 {
+			pool.import(Transaction { hash, provides: vec![vec![hash as u8]], ..default_tx() })
+				.unwrap();
+		}
+		pool.import(Transaction { hash: 4, priority: 100, provides: vec![vec![4]], ..default_tx() })
+			.unwrap();
+
+		// when
+		let removed = pool.enforce_limits(&ready, &ready, Some(&lane));
+
+		// then the newest transaction outside of the lane is removed, even though the ready limits
+		// are not exceeded.
+		assert_eq!(removed.iter().map(|tx| tx.hash).collect::<Vec<_>>(), vec![3]);
+		assert_eq!(pool.ready_outside_lane(&lane), (2, 2));
+
+		// and the lane can use the reserved limits.
+		pool.import(Transaction { hash: 5, priority: 100, provides: vec![vec![5]], ..default_tx() })
+			.unwrap();
+		assert!(pool.enforce_limits(&ready, &ready, Some(&lane)).is_empty());
+		assert_eq!(pool.ready.len(), 4);
+	}
 }
@@ -249,7 +249,14 @@ impl<B: ChainApi> ValidatedPool<B> {
 		let ready_limit = &self.options.ready;
 		let future_limit = &self.options.future;
 
-		if ready_limit.is_exceeded(status.ready, status.ready_bytes) ||
+		let priority_lane = self.options.priority_lane.as_ref();
+		let lane_exceeded = priority_lane.map_or(false, |lane| {
+			let (count, bytes) = self.pool.write().ready_outside_lane(lane);
+			lane.shared_limit(ready_limit).is_exceeded(count, bytes)
+		});
+
+		if lane_exceeded ||
+			ready_limit.is_exceeded(status.ready, status.ready_bytes) ||
 			future_limit.is_exceeded(status.future, status.future_bytes)
 		{
 			log::debug!(
@@ -265,7 +272,7 @@ impl<B: ChainApi> ValidatedPool<B> {
 			let removed = {
 				let mut pool = self.pool.write();
 				let removed = pool
-					.enforce_limits(ready_limit, future_limit)
+					.enforce_limits(ready_limit, future_limit, priority_lane)
 					.into_iter()
 					.map(|x| x.hash)
 					.collect::<HashSet<_>>();
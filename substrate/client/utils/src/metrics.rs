@@ -62,6 +62,28 @@ pub static UNBOUNDED_CHANNELS_SIZE: LazyLock<GenericGaugeVec<AtomicU64>> = LazyL
 	.expect("Creating of statics doesn't fail. qed")
 });
 
+pub static RUNTIME_LOG_EVENTS: LazyLock<GenericCounterVec<AtomicU64>> = LazyLock::new(|| {
+	GenericCounterVec::new(
+		Opts::new(
+			"substrate_runtime_log_events_total",
+			"Number of defensive triggers and error-class log events emitted by the runtime",
+		),
+		&["target", "kind"], // log target, defensive|error|warn
+	)
+	.expect("Creating of statics doesn't fail. qed")
+});
+
+pub static RUNTIME_DISPATCH_ERRORS: LazyLock<GenericCounterVec<AtomicU64>> = LazyLock::new(|| {
+	GenericCounterVec::new(
+		Opts::new(
+			"substrate_runtime_dispatch_errors_total",
+			"Number of failed dispatches of monitored pallets in imported blocks",
+		),
+		&["pallet", "error"],
+	)
+	.expect("Creating of statics doesn't fail. qed")
+});
+
 pub static SENT_LABEL: &'static str = "send";
 pub static RECEIVED_LABEL: &'static str = "received";
 pub static DROPPED_LABEL: &'static str = "dropped";
@@ -72,6 +94,8 @@ pub fn register_globals(registry: &Registry) -> Result<(), PrometheusError> {
 	registry.register(Box::new(TOKIO_THREADS_TOTAL.clone()))?;
 	registry.register(Box::new(UNBOUNDED_CHANNELS_COUNTER.clone()))?;
 	registry.register(Box::new(UNBOUNDED_CHANNELS_SIZE.clone()))?;
+	registry.register(Box::new(RUNTIME_LOG_EVENTS.clone()))?;
+	registry.register(Box::new(RUNTIME_DISPATCH_ERRORS.clone()))?;
 
 	Ok(())
 }
@@ -25,6 +25,11 @@
 //! The AssetRate pallet provides means of setting conversion rates for some asset to native
 //! balance.
 //!
+//! Besides the rates set by governance, rates can be pushed by an oracle. An oracle rate takes
+//! precedence as long as it is fresh, i.e. not older than the staleness window of its asset.
+//! Once it is stale, conversions fall back to the rate set by governance, or fail if there is
+//! none.
+//!
 //! The supported dispatchable functions are documented in the [`Call`] enum.
 //!
 //! ### Terminology
@@ -47,6 +52,8 @@
 //! * `create`: Creates a new asset conversion rate.
 //! * `remove`: Removes an existing asset conversion rate.
 //! * `update`: Overwrites an existing assert conversion rate.
+//! * `push_rate`: Pushes a conversion rate from the oracle.
+//! * `set_staleness_window`: Sets the number of blocks after which an oracle rate is stale.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -62,13 +69,16 @@
 extern crate alloc;
 
 use alloc::boxed::Box;
+use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use frame_support::traits::{
 	fungible::Inspect,
 	tokens::{ConversionFromAssetBalance, ConversionToAssetBalance},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedDiv, Zero},
-	FixedPointNumber, FixedU128,
+	traits::{CheckedDiv, Saturating, Zero},
+	FixedPointNumber, FixedU128, RuntimeDebug,
 };
 
 pub use pallet::*;
@@ -91,6 +101,35 @@ type AssetKindOf<T> = <T as Config>::AssetKind;
 // Generic fungible balance type.
 type BalanceOf<T> = <<T as Config>::Currency as Inspect<AccountIdOf<T>>>::Balance;
 
+/// The source of a conversion rate.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum RateSource {
+	/// The rate was pushed by the oracle and is fresh.
+	Oracle,
+	/// The rate was set by governance.
+	Governance,
+}
+
+/// A conversion rate pushed by the oracle.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct OracleRate<BlockNumber> {
+	/// The conversion rate to native balance.
+	pub rate: FixedU128,
+	/// The block in which the rate was pushed.
+	pub updated_at: BlockNumber,
+}
+
+/// The conversion rate used for an asset, together with its metadata.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct RateInfo<BlockNumber> {
+	/// The conversion rate to native balance.
+	pub rate: FixedU128,
+	/// Where the rate comes from.
+	pub source: RateSource,
+	/// The block in which the rate was pushed, if it comes from the oracle.
+	pub updated_at: Option<BlockNumber>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -117,6 +156,9 @@ pub mod pallet {
 		/// The origin permissioned to update an existing conversion rate for an asset.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin permissioned to push conversion rates from an oracle.
+		type OracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// The currency mechanism for this pallet.
 		type Currency: Inspect<Self::AccountId>;
 
@@ -135,6 +177,20 @@ pub mod pallet {
 	pub type ConversionRateToNative<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetKind, FixedU128, OptionQuery>;
 
+	/// The latest conversion rate pushed by the oracle for an asset.
+	///
+	/// Takes precedence over [`ConversionRateToNative`] while it is not stale.
+	#[pallet::storage]
+	pub type OracleRateToNative<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetKind, OracleRate<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The number of blocks after which the oracle rate of an asset is stale.
+	///
+	/// Oracle rates of assets without a window never become stale.
+	#[pallet::storage]
+	pub type StalenessWindow<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetKind, BlockNumberFor<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -144,6 +200,10 @@ pub mod pallet {
 		AssetRateRemoved { asset_kind: T::AssetKind },
 		// Some existing `asset_kind` conversion rate was updated from `old` to `new`.
 		AssetRateUpdated { asset_kind: T::AssetKind, old: FixedU128, new: FixedU128 },
+		// The oracle pushed a new conversion rate for some `asset_kind`.
+		OracleRatePushed { asset_kind: T::AssetKind, rate: FixedU128 },
+		// The staleness window of the oracle rate of some `asset_kind` was set.
+		StalenessWindowSet { asset_kind: T::AssetKind, window: Option<BlockNumberFor<T>> },
 	}
 
 	#[pallet::error]
@@ -154,6 +214,8 @@ pub mod pallet {
 		AlreadyExists,
 		/// Overflow ocurred when calculating the inverse rate.
 		Overflow,
+		/// The oracle rate of the given asset is stale and there is no rate to fall back to.
+		StaleRate,
 	}
 
 	#[pallet::call]
@@ -224,14 +286,99 @@ pub mod pallet {
 			T::RemoveOrigin::ensure_origin(origin)?;
 
 			ensure!(
-				ConversionRateToNative::<T>::contains_key(asset_kind.as_ref()),
+				ConversionRateToNative::<T>::contains_key(asset_kind.as_ref()) ||
+					OracleRateToNative::<T>::contains_key(asset_kind.as_ref()),
 				Error::<T>::UnknownAssetKind
 			);
 			ConversionRateToNative::<T>::remove(asset_kind.as_ref());
+			OracleRateToNative::<T>::remove(asset_kind.as_ref());
+			StalenessWindow::<T>::remove(asset_kind.as_ref());
 
 			Self::deposit_event(Event::AssetRateRemoved { asset_kind: *asset_kind });
 			Ok(())
 		}
+
+		/// Push a conversion rate to native balance for the given asset from the oracle.
+		///
+		/// The rate is used instead of the one set by governance until it becomes stale.
+		///
+		/// ## Complexity
+		/// - O(1)
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::update())]
+		pub fn push_rate(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			rate: FixedU128,
+		) -> DispatchResult {
+			T::OracleOrigin::ensure_origin(origin)?;
+
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			OracleRateToNative::<T>::insert(asset_kind.as_ref(), OracleRate { rate, updated_at });
+
+			Self::deposit_event(Event::OracleRatePushed { asset_kind: *asset_kind, rate });
+			Ok(())
+		}
+
+		/// Set the number of blocks after which the oracle rate of the given asset is stale.
+		///
+		/// `None` removes the window, so that the oracle rate never becomes stale.
+		///
+		/// ## Complexity
+		/// - O(1)
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::update())]
+		pub fn set_staleness_window(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			window: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			StalenessWindow::<T>::set(asset_kind.as_ref(), window);
+
+			Self::deposit_event(Event::StalenessWindowSet { asset_kind: *asset_kind, window });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The conversion rate used for `asset_kind`.
+	///
+	/// A fresh oracle rate takes precedence. Once it is stale, the rate set by governance is used
+	/// instead.
+	pub fn rate_info(asset_kind: &T::AssetKind) -> Result<RateInfo<BlockNumberFor<T>>, Error<T>> {
+		let oracle = OracleRateToNative::<T>::get(asset_kind);
+		if let Some(OracleRate { rate, updated_at }) = oracle {
+			let now = frame_system::Pallet::<T>::block_number();
+			let fresh = StalenessWindow::<T>::get(asset_kind)
+				.map_or(true, |window| now.saturating_sub(updated_at) <= window);
+			if fresh {
+				return Ok(RateInfo {
+					rate,
+					source: RateSource::Oracle,
+					updated_at: Some(updated_at),
+				})
+			}
+		}
+
+		match ConversionRateToNative::<T>::get(asset_kind) {
+			Some(rate) => Ok(RateInfo { rate, source: RateSource::Governance, updated_at: None }),
+			None if oracle.is_some() => Err(Error::<T>::StaleRate),
+			None => Err(Error::<T>::UnknownAssetKind),
+		}
+	}
+
+	/// Convert `amount` of `asset_kind` to native balance, returning the rate that was used.
+	///
+	/// Used by the [`AssetRateApi`] runtime API.
+	pub fn quote_to_native(
+		asset_kind: T::AssetKind,
+		amount: BalanceOf<T>,
+	) -> Option<(BalanceOf<T>, RateInfo<BlockNumberFor<T>>)> {
+		let info = Self::rate_info(&asset_kind).ok()?;
+		Some((info.rate.saturating_mul_int(amount), info))
 	}
 }
 
@@ -246,8 +393,7 @@ where
 		balance: BalanceOf<T>,
 		asset_kind: AssetKindOf<T>,
 	) -> Result<BalanceOf<T>, pallet::Error<T>> {
-		let rate = pallet::ConversionRateToNative::<T>::get(asset_kind)
-			.ok_or(pallet::Error::<T>::UnknownAssetKind.into())?;
+		let rate = Pallet::<T>::rate_info(&asset_kind)?.rate;
 		Ok(rate.saturating_mul_int(balance))
 	}
 	/// Set a conversion rate to `1` for the `asset_id`.
@@ -268,8 +414,7 @@ where
 		balance: BalanceOf<T>,
 		asset_kind: AssetKindOf<T>,
 	) -> Result<BalanceOf<T>, pallet::Error<T>> {
-		let rate = pallet::ConversionRateToNative::<T>::get(asset_kind)
-			.ok_or(pallet::Error::<T>::UnknownAssetKind.into())?;
+		let rate = Pallet::<T>::rate_info(&asset_kind)?.rate;
 
 		// We cannot use `saturating_div` here so we use `checked_div`.
 		Ok(FixedU128::from_u32(1)
@@ -278,3 +423,22 @@ where
 			.saturating_mul_int(balance))
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to quote conversions of asset balances to native balance.
+	pub trait AssetRateApi<AssetKind, Balance, BlockNumber>
+	where
+		AssetKind: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		/// Convert `amount` of `asset_kind` to native balance.
+		///
+		/// Returns the converted amount together with the rate that was used and its metadata, or
+		/// `None` if the asset has no usable rate.
+		fn quote_to_native(
+			asset_kind: AssetKind,
+			amount: Balance,
+		) -> Option<(Balance, RateInfo<BlockNumber>)>;
+	}
+}
@@ -18,7 +18,7 @@
 //! The crate's mock.
 
 use crate as pallet_asset_rate;
-use frame_support::derive_impl;
+use frame_support::{derive_impl, ord_parameter_types};
 use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -49,12 +49,17 @@ impl pallet_asset_rate::Config for Test {
 	type CreateOrigin = frame_system::EnsureRoot<u64>;
 	type RemoveOrigin = frame_system::EnsureRoot<u64>;
 	type UpdateOrigin = frame_system::EnsureRoot<u64>;
+	type OracleOrigin = frame_system::EnsureSignedBy<Oracle, u64>;
 	type Currency = Balances;
 	type AssetKind = u32;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
 
+ord_parameter_types! {
+	pub const Oracle: u64 = 7;
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
@@ -20,8 +20,8 @@
 use super::*;
 use crate::pallet as pallet_asset_rate;
 use frame_support::{assert_noop, assert_ok};
-use mock::{new_test_ext, AssetRate, RuntimeOrigin, Test};
-use sp_runtime::FixedU128;
+use mock::{new_test_ext, AssetRate, Oracle, RuntimeOrigin, System, Test};
+use sp_runtime::{DispatchError, FixedU128};
 
 const ASSET_ID: u32 = 42;
 
@@ -176,3 +176,91 @@ fn convert_overflow_throws() {
 		assert!(conversion.is_err());
 	});
 }
+
+#[test]
+fn oracle_rate_takes_precedence_until_stale() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			FixedU128::from_u32(2)
+		));
+
+		// only the oracle can push rates.
+		assert_noop!(
+			AssetRate::push_rate(RuntimeOrigin::root(), Box::new(ASSET_ID), FixedU128::from_u32(3)),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(AssetRate::push_rate(
+			RuntimeOrigin::signed(Oracle::get()),
+			Box::new(ASSET_ID),
+			FixedU128::from_u32(3)
+		));
+		assert_ok!(AssetRate::set_staleness_window(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			Some(10)
+		));
+
+		let quote = || AssetRate::quote_to_native(ASSET_ID, 10);
+		assert_eq!(
+			quote(),
+			Some((
+				30,
+				RateInfo {
+					rate: FixedU128::from_u32(3),
+					source: RateSource::Oracle,
+					updated_at: Some(1),
+				}
+			))
+		);
+
+		// once stale, the rate set by governance is used.
+		System::set_block_number(12);
+		assert_eq!(
+			quote(),
+			Some((
+				20,
+				RateInfo {
+					rate: FixedU128::from_u32(2),
+					source: RateSource::Governance,
+					updated_at: None,
+				}
+			))
+		);
+	});
+}
+
+#[test]
+fn stale_oracle_rate_without_fallback_throws() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(AssetRate::push_rate(
+			RuntimeOrigin::signed(Oracle::get()),
+			Box::new(ASSET_ID),
+			FixedU128::from_u32(3)
+		));
+		assert_ok!(AssetRate::set_staleness_window(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			Some(10)
+		));
+
+		System::set_block_number(12);
+		let conversion = <AssetRate as ConversionFromAssetBalance<
+			BalanceOf<Test>,
+			<Test as pallet_asset_rate::Config>::AssetKind,
+			BalanceOf<Test>,
+		>>::from_asset_balance(10, ASSET_ID);
+		assert_eq!(conversion, Err(Error::<Test>::StaleRate));
+
+		// removing the window makes the oracle rate usable again.
+		assert_ok!(AssetRate::set_staleness_window(
+			RuntimeOrigin::root(),
+			Box::new(ASSET_ID),
+			None
+		));
+		assert_eq!(AssetRate::quote_to_native(ASSET_ID, 10).map(|(amount, _)| amount), Some(30));
+	});
+}
@@ -105,6 +105,8 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type RemoveItemsLimit = ConstU32<10>;
 	type CallbackHandle = ();
+	type TransferPolicy = ();
+	type MaxBatchSize = ConstU32<64>;
 	type Currency = Balances;
 	type Freezer = AssetsFreezer;
 	type RuntimeEvent = RuntimeEvent;
@@ -19,7 +19,7 @@
 
 use super::*;
 use alloc::vec;
-use frame_support::{defensive, traits::Get, BoundedVec};
+use frame_support::{defensive, traits::Get, weights::Weight, BoundedVec};
 
 #[must_use]
 pub(super) enum DeadConsequence {
@@ -438,6 +438,40 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Increases the asset `id` balance of each beneficiary in `mints` by its amount.
+	///
+	/// This alters the registered supply of the asset and emits a single `BatchIssued` event.
+	/// The issuer is checked once, before any of the mints.
+	///
+	/// Will return an error or will increase the amount of each beneficiary by exactly its amount.
+	/// The mints made before an error are not reverted, so this must run in a storage layer.
+	pub(super) fn do_mint_batch(
+		id: T::AssetId,
+		mints: Vec<(T::AccountId, T::Balance)>,
+		maybe_check_issuer: Option<T::AccountId>,
+	) -> DispatchResult {
+		if let Some(check_issuer) = maybe_check_issuer {
+			let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(check_issuer == details.issuer, Error::<T, I>::NoPermission);
+		}
+
+		for (beneficiary, amount) in mints.iter() {
+			let amount = *amount;
+			Self::increase_balance(id.clone(), beneficiary, amount, |details| -> DispatchResult {
+				debug_assert!(
+					details.supply.checked_add(&amount).is_some(),
+					"checked in prep; qed"
+				);
+				details.supply = details.supply.saturating_add(amount);
+				Ok(())
+			})?;
+		}
+
+		Self::deposit_event(Event::BatchIssued { asset_id: id, issued: mints });
+
+		Ok(())
+	}
+
 	/// Increases the asset `id` balance of `beneficiary` by `amount`.
 	///
 	/// LOW-LEVEL: Does not alter the supply of asset or emit an event. Use `do_mint` if you need
@@ -604,6 +638,83 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(balance)
 	}
 
+	/// Reduces the asset `id` balance of `source` by the amount of each transfer in `transfers`
+	/// and increases the balance of its destination by (similar) amount.
+	///
+	/// Emits a single `BatchTransferred` event with the actual amounts placed into the
+	/// destinations, see [`Self::do_transfer`].
+	///
+	/// Will return an error if any of the transfers fails. The transfers made before are not
+	/// reverted, so this must run in a storage layer.
+	pub(super) fn do_transfer_batch(
+		id: T::AssetId,
+		source: &T::AccountId,
+		transfers: Vec<(T::AccountId, T::Balance)>,
+		f: TransferFlags,
+	) -> DispatchResult {
+		let mut transferred = Vec::with_capacity(transfers.len());
+		for (dest, amount) in transfers {
+			let (credit, died) =
+				Self::transfer_and_die_silently(id.clone(), source, &dest, amount, None, f)?;
+			if let Some(Remove) = died {
+				T::Freezer::died(id.clone(), source);
+			}
+			transferred.push((dest, credit));
+		}
+
+		Self::deposit_event(Event::BatchTransferred {
+			asset_id: id,
+			from: source.clone(),
+			transfers: transferred,
+		});
+		Ok(())
+	}
+
+	/// Ensure that the transfer policy of the asset, if any, allows `source` to transfer `amount`
+	/// to `dest`.
+	pub(super) fn ensure_transfer_allowed(
+		id: &T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match TransferPolicies::<T, I>::get(id) {
+			Some(policy) => T::TransferPolicy::check(&policy, id, source, dest, amount),
+			None => Ok(()),
+		}
+	}
+
+	/// Ensure that the transfer policy of the asset, if any, allows `amount` to be minted into
+	/// `who` outside of the dispatchables of the issuer.
+	pub(super) fn ensure_deposit_allowed(
+		id: &T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match TransferPolicies::<T, I>::get(id) {
+			Some(policy) => T::TransferPolicy::check_deposit(&policy, id, who, amount),
+			None => Ok(()),
+		}
+	}
+
+	/// Ensure that the transfer policy of the asset, if any, allows `amount` to be burned from
+	/// `who` outside of the dispatchables of the admin.
+	pub(super) fn ensure_withdrawal_allowed(
+		id: &T::AssetId,
+		who: &T::AccountId,
+		amount: T::Balance,
+	) -> DispatchResult {
+		match TransferPolicies::<T, I>::get(id) {
+			Some(policy) => T::TransferPolicy::check_withdrawal(&policy, id, who, amount),
+			None => Ok(()),
+		}
+	}
+
+	/// The weight of reading and checking the transfer policy of an asset.
+	pub fn transfer_policy_weight() -> Weight {
+		T::DbWeight::get().reads(1).saturating_add(T::TransferPolicy::weight())
+	}
+
 	/// Same as `do_transfer` but it does not execute the `FrozenBalance::died` hook and
 	/// instead returns whether and how the `source` account died in this operation.
 	fn transfer_and_die(
@@ -613,6 +724,29 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		amount: T::Balance,
 		maybe_need_admin: Option<T::AccountId>,
 		f: TransferFlags,
+	) -> Result<(T::Balance, Option<DeadConsequence>), DispatchError> {
+		let (credit, source_died) =
+			Self::transfer_and_die_silently(id.clone(), source, dest, amount, maybe_need_admin, f)?;
+		// No-op transfers do not emit an event.
+		if !amount.is_zero() {
+			Self::deposit_event(Event::Transferred {
+				asset_id: id,
+				from: source.clone(),
+				to: dest.clone(),
+				amount: credit,
+			});
+		}
+		Ok((credit, source_died))
+	}
+
+	/// Same as `transfer_and_die` but it does not emit the `Transferred` event.
+	fn transfer_and_die_silently(
+		id: T::AssetId,
+		source: &T::AccountId,
+		dest: &T::AccountId,
+		amount: T::Balance,
+		maybe_need_admin: Option<T::AccountId>,
+		f: TransferFlags,
 	) -> Result<(T::Balance, Option<DeadConsequence>), DispatchError> {
 		// Early exit if no-op.
 		if amount.is_zero() {
@@ -621,6 +755,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
 		ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
 
+		// Forced transfers of the admin are not subject to the transfer policy.
+		if maybe_need_admin.is_none() {
+			Self::ensure_transfer_allowed(&id, source, dest, amount)?;
+		}
+
 		// Figure out the debit and credit, together with side-effects.
 		let debit = Self::prep_debit(id.clone(), source, amount, f.into())?;
 		let (credit, maybe_burn) = Self::prep_credit(id.clone(), dest, amount, debit, f.burn_dust)?;
@@ -691,12 +830,6 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
-		Self::deposit_event(Event::Transferred {
-			asset_id: id,
-			from: source.clone(),
-			to: dest.clone(),
-			amount: credit,
-		});
 		Ok((credit, source_died))
 	}
 
@@ -858,6 +991,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
 			let metadata = Metadata::<T, I>::take(&id);
+			TransferPolicies::<T, I>::remove(&id);
 			T::Currency::unreserve(
 				&details.owner,
 				details.deposit.saturating_add(metadata.deposit),
@@ -22,7 +22,7 @@ use frame_support::{
 	defensive,
 	traits::tokens::{
 		Fortitude,
-		Precision::{self, BestEffort},
+		Precision::{self, BestEffort, Exact},
 		Preservation::{self, Expendable},
 		Provenance::{self, Minted},
 	},
@@ -83,6 +83,112 @@ impl<T: Config<I>, I: 'static> fungibles::Inspect<<T as SystemConfig>::AccountId
 }
 
 impl<T: Config<I>, I: 'static> fungibles::Mutate<<T as SystemConfig>::AccountId> for Pallet<T, I> {
+	/// Same as the default implementation, but subject to the transfer policy of the asset.
+	fn transfer(
+		asset: Self::AssetId,
+		source: &<T as SystemConfig>::AccountId,
+		dest: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_transfer_allowed(&asset, source, dest, amount)?;
+
+		let _extra = <Self as fungibles::Inspect<_>>::can_withdraw(asset.clone(), source, amount)
+			.into_result(preservation != Expendable)?;
+		<Self as fungibles::Inspect<_>>::can_deposit(
+			asset.clone(),
+			dest,
+			amount,
+			Provenance::Extant,
+		)
+		.into_result()?;
+		if source == dest {
+			return Ok(amount)
+		}
+
+		<Self as fungibles::Unbalanced<_>>::decrease_balance(
+			asset.clone(),
+			source,
+			amount,
+			BestEffort,
+			preservation,
+			Fortitude::Polite,
+		)?;
+		// This should never fail as we checked `can_deposit` earlier. But we do a best-effort
+		// anyway.
+		let _ = <Self as fungibles::Unbalanced<_>>::increase_balance(
+			asset.clone(),
+			dest,
+			amount,
+			BestEffort,
+		);
+		<Self as fungibles::Mutate<_>>::done_transfer(asset, source, dest, amount);
+		Ok(amount)
+	}
+
+	/// Same as the default implementation, but subject to the transfer policy of the asset.
+	fn mint_into(
+		asset: Self::AssetId,
+		who: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_deposit_allowed(&asset, who, amount)?;
+
+		<Self as fungibles::Inspect<_>>::total_issuance(asset.clone())
+			.checked_add(&amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		let actual = <Self as fungibles::Unbalanced<_>>::increase_balance(
+			asset.clone(),
+			who,
+			amount,
+			Exact,
+		)?;
+		<Self as fungibles::Unbalanced<_>>::set_total_issuance(
+			asset.clone(),
+			<Self as fungibles::Inspect<_>>::total_issuance(asset.clone()).saturating_add(actual),
+		);
+		<Self as fungibles::Mutate<_>>::done_mint_into(asset, who, amount);
+		Ok(actual)
+	}
+
+	/// Same as the default implementation, but subject to the transfer policy of the asset.
+	fn burn_from(
+		asset: Self::AssetId,
+		who: &<T as SystemConfig>::AccountId,
+		amount: Self::Balance,
+		preservation: Preservation,
+		precision: Precision,
+		force: Fortitude,
+	) -> Result<Self::Balance, DispatchError> {
+		Self::ensure_withdrawal_allowed(&asset, who, amount)?;
+
+		let actual = <Self as fungibles::Inspect<_>>::reducible_balance(
+			asset.clone(),
+			who,
+			preservation,
+			force,
+		)
+		.min(amount);
+		ensure!(actual == amount || precision == BestEffort, TokenError::FundsUnavailable);
+		<Self as fungibles::Inspect<_>>::total_issuance(asset.clone())
+			.checked_sub(&actual)
+			.ok_or(ArithmeticError::Overflow)?;
+		let actual = <Self as fungibles::Unbalanced<_>>::decrease_balance(
+			asset.clone(),
+			who,
+			actual,
+			BestEffort,
+			preservation,
+			force,
+		)?;
+		<Self as fungibles::Unbalanced<_>>::set_total_issuance(
+			asset.clone(),
+			<Self as fungibles::Inspect<_>>::total_issuance(asset.clone()).saturating_sub(actual),
+		);
+		<Self as fungibles::Mutate<_>>::done_burn_from(asset, who, actual);
+		Ok(actual)
+	}
+
 	fn done_mint_into(
 		asset_id: Self::AssetId,
 		beneficiary: &<T as SystemConfig>::AccountId,
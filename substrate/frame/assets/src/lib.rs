@@ -297,6 +297,8 @@ pub mod pallet {
 			type StringLimit = ConstU32<50>;
 			type Extra = ();
 			type CallbackHandle = ();
+			type TransferPolicy = ();
+			type MaxBatchSize = ConstU32<64>;
 			type WeightInfo = ();
 			#[cfg(feature = "runtime-benchmarks")]
 			type BenchmarkHelper = ();
@@ -404,6 +406,15 @@ pub mod pallet {
 		/// used to set up auto-incrementing asset IDs for this collection.
 		type CallbackHandle: AssetsCallback<Self::AssetId, Self::AccountId>;
 
+		/// The transfer policies that asset owners may register for their assets.
+		///
+		/// Use `()` if assets may not have a transfer policy.
+		type TransferPolicy: TransferPolicy<Self::AssetId, Self::AccountId, Self::Balance>;
+
+		/// The maximum number of recipients of a `mint_batch` or `transfer_batch` call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -468,6 +479,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextAssetId<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AssetId, OptionQuery>;
 
+	/// The transfer policy registered by the owner of an asset, if any.
+	#[pallet::storage]
+	pub type TransferPolicies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, TransferPolicyOf<T, I>, OptionQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -639,6 +655,16 @@ pub mod pallet {
 		Deposited { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
 		/// Some assets were withdrawn from the account (e.g. for transaction fees).
 		Withdrawn { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
+		/// The transfer policy of an asset was set, or removed if `None`.
+		TransferPolicySet { asset_id: T::AssetId, policy: Option<TransferPolicyOf<T, I>> },
+		/// Some assets were issued to several accounts at once.
+		BatchIssued { asset_id: T::AssetId, issued: Vec<(T::AccountId, T::Balance)> },
+		/// Some assets were transferred from one account to several accounts at once.
+		BatchTransferred {
+			asset_id: T::AssetId,
+			from: T::AccountId,
+			transfers: Vec<(T::AccountId, T::Balance)>,
+		},
 	}
 
 	#[pallet::error]
@@ -952,6 +978,9 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(8)]
+		#[pallet::weight(
+			T::WeightInfo::transfer().saturating_add(Pallet::<T, I>::transfer_policy_weight())
+		)]
 		pub fn transfer(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -985,6 +1014,10 @@ pub mod pallet {
 		/// Modes: Pre-existence of `target`; Post-existence of sender; Account pre-existence of
 		/// `target`.
 		#[pallet::call_index(9)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_keep_alive()
+				.saturating_add(Pallet::<T, I>::transfer_policy_weight())
+		)]
 		pub fn transfer_keep_alive(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1575,6 +1608,10 @@ pub mod pallet {
 		///
 		/// Weight: `O(1)`
 		#[pallet::call_index(25)]
+		#[pallet::weight(
+			T::WeightInfo::transfer_approved()
+				.saturating_add(Pallet::<T, I>::transfer_policy_weight())
+		)]
 		pub fn transfer_approved(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1773,7 +1810,9 @@ pub mod pallet {
 		///   (false), or transfer everything except at least the minimum balance, which will
 		///   guarantee to keep the sender asset account alive (true).
 		#[pallet::call_index(32)]
-		#[pallet::weight(T::WeightInfo::transfer_all())]
+		#[pallet::weight(
+			T::WeightInfo::transfer_all().saturating_add(Pallet::<T, I>::transfer_policy_weight())
+		)]
 		pub fn transfer_all(
 			origin: OriginFor<T>,
 			id: T::AssetIdParameter,
@@ -1798,6 +1837,101 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Set or remove the transfer policy of an asset.
+		///
+		/// Origin must be Signed and the sender has to be the Owner of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `policy`: The policy consulted on every transfer of the asset, except for the forced
+		///   transfers of its admin, or `None` to remove it.
+		///
+		/// Emits `TransferPolicySet` event when successful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_min_balance())]
+		pub fn set_transfer_policy(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			policy: Option<TransferPolicyOf<T, I>>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let details = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+			ensure!(details.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+			ensure!(origin == details.owner, Error::<T, I>::NoPermission);
+
+			TransferPolicies::<T, I>::set(&id, policy.clone());
+			Self::deposit_event(Event::TransferPolicySet { asset_id: id, policy });
+			Ok(())
+		}
+
+		/// Mint assets of a particular class to several accounts at once.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the asset `id`. The
+		/// origin is checked once for the whole batch.
+		///
+		/// - `id`: The identifier of the asset to have some amount minted.
+		/// - `mints`: The accounts to be credited with the minted assets, each with the amount to
+		///   be minted.
+		///
+		/// Emits a single `BatchIssued` event when successful. Fails, and mints nothing, if any of
+		/// the mints fails.
+		///
+		/// Weight: `O(M)` where `M` is the number of `mints`. An empty batch is charged as a single
+		/// mint.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::mint().saturating_mul((mints.len() as u64).max(1)))]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			mints: BoundedVec<(AccountIdLookupOf<T>, T::Balance), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let mints = mints
+				.into_iter()
+				.map(|(beneficiary, amount)| Ok((T::Lookup::lookup(beneficiary)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			Self::do_mint_batch(id, mints, Some(origin))
+		}
+
+		/// Move some assets from the sender account to several accounts at once.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `transfers`: The accounts to be credited, each with the amount by which the sender's
+		///   balance of assets should be reduced and the account's balance increased. As with
+		///   `transfer`, the amount actually transferred may be slightly greater.
+		///
+		/// Emits a single `BatchTransferred` event with the actual amounts transferred. Fails, and
+		/// transfers nothing, if any of the transfers fails.
+		///
+		/// Weight: `O(T)` where `T` is the number of `transfers`. An empty batch is charged as a
+		/// single transfer.
+		#[pallet::call_index(35)]
+		#[pallet::weight(
+			T::WeightInfo::transfer()
+				.saturating_add(Pallet::<T, I>::transfer_policy_weight())
+				.saturating_mul((transfers.len() as u64).max(1))
+		)]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			transfers: BoundedVec<(AccountIdLookupOf<T>, T::Balance), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let transfers = transfers
+				.into_iter()
+				.map(|(dest, amount)| Ok((T::Lookup::lookup(dest)?, amount)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			let f = TransferFlags { keep_alive: false, best_effort: false, burn_dust: false };
+			Self::do_transfer_batch(id, &origin, transfers, f)
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -24,6 +24,8 @@ use codec::Encode;
 use frame_support::{
 	construct_runtime, derive_impl, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32},
+	weights::Weight,
+	BoundedVec,
 };
 use sp_io::storage;
 use sp_runtime::BuildStorage;
@@ -104,6 +106,48 @@ impl Config for Test {
 	type ForceOrigin = frame_system::EnsureRoot<u64>;
 	type Freezer = TestFreezer;
 	type CallbackHandle = (AssetsCallbackHandle, AutoIncAssetId<Test>);
+	type TransferPolicy = TestTransferPolicy;
+}
+
+/// Transfer policy allowing transfers only between the accounts of an allowlist.
+pub struct TestTransferPolicy;
+impl TransferPolicy<u32, u64, u64> for TestTransferPolicy {
+	type Policy = BoundedVec<u64, ConstU32<4>>;
+
+	fn check(
+		allowlist: &Self::Policy,
+		_asset: &u32,
+		from: &u64,
+		to: &u64,
+		_amount: u64,
+	) -> DispatchResult {
+		ensure!(allowlist.contains(from) && allowlist.contains(to), TokenError::Blocked);
+		Ok(())
+	}
+
+	fn check_deposit(
+		allowlist: &Self::Policy,
+		_asset: &u32,
+		who: &u64,
+		_amount: u64,
+	) -> DispatchResult {
+		ensure!(allowlist.contains(who), TokenError::Blocked);
+		Ok(())
+	}
+
+	fn check_withdrawal(
+		allowlist: &Self::Policy,
+		_asset: &u32,
+		who: &u64,
+		_amount: u64,
+	) -> DispatchResult {
+		ensure!(allowlist.contains(who), TokenError::Blocked);
+		Ok(())
+	}
+
+	fn weight() -> Weight {
+		Weight::from_parts(1_000, 0)
+	}
 }
 
 use std::collections::HashMap;
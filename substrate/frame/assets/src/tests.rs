@@ -24,9 +24,15 @@ use frame_support::{
 	dispatch::GetDispatchInfo,
 	traits::{
 		fungibles::InspectEnumerable,
-		tokens::{Preservation::Protect, Provenance},
+		tokens::{
+			Fortitude::Polite,
+			Precision::Exact,
+			Preservation::{Expendable, Protect},
+			Provenance,
+		},
 		Currency,
 	},
+	BoundedVec,
 };
 use pallet_balances::Error as BalancesError;
 use sp_io::storage;
@@ -1921,3 +1927,202 @@ fn asset_id_cannot_be_reused() {
 		assert!(Asset::<Test>::contains_key(7));
 	});
 }
+
+#[test]
+fn transfer_policy_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		Balances::make_free_balance_be(&1, 2);
+		let allowlist = BoundedVec::truncate_from(vec![1, 2]);
+
+		// only the owner may set the policy.
+		assert_noop!(
+			Assets::set_transfer_policy(RuntimeOrigin::signed(2), 0, Some(allowlist.clone())),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Assets::set_transfer_policy(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(allowlist.clone())
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::TransferPolicySet {
+			asset_id: 0,
+			policy: Some(allowlist),
+		}));
+
+		// transfers within the allowlist are allowed.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 10));
+		// but not to other accounts, whichever way they are made.
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10), TokenError::Blocked);
+		assert_noop!(
+			Assets::transfer_keep_alive(RuntimeOrigin::signed(1), 0, 3, 10),
+			TokenError::Blocked
+		);
+		assert_noop!(
+			Assets::transfer_all(RuntimeOrigin::signed(2), 0, 3, false),
+			TokenError::Blocked
+		);
+		assert_ok!(Assets::approve_transfer(RuntimeOrigin::signed(1), 0, 2, 50));
+		assert_noop!(
+			Assets::transfer_approved(RuntimeOrigin::signed(2), 0, 1, 3, 10),
+			TokenError::Blocked
+		);
+
+		// the admin may still force transfers.
+		assert_ok!(Assets::force_transfer(RuntimeOrigin::signed(1), 0, 1, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 10);
+
+		// the policy is consulted within the transfer weight.
+		let weight = Call::<Test>::transfer { id: 0, target: 3, amount: 10 }.get_dispatch_info();
+		assert_eq!(
+			weight.call_weight,
+			<Test as Config>::WeightInfo::transfer()
+				.saturating_add(Assets::transfer_policy_weight())
+		);
+
+		// without policy, any transfer is allowed again.
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, None));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 3, 10));
+		assert_eq!(Assets::balance(0, 3), 20);
+	});
+}
+
+#[test]
+fn mint_batch_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		let mints = BoundedVec::truncate_from(vec![(2, 100), (3, 50), (2, 20)]);
+
+		// only the issuer may mint.
+		assert_noop!(
+			Assets::mint_batch(RuntimeOrigin::signed(2), 0, mints.clone()),
+			Error::<Test>::NoPermission
+		);
+		// a single failing mint fails the whole batch.
+		assert_noop!(
+			Assets::mint_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				BoundedVec::truncate_from(vec![(2, 100), (3, 5)])
+			),
+			TokenError::BelowMinimum
+		);
+
+		assert_ok!(Assets::mint_batch(RuntimeOrigin::signed(1), 0, mints));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::BatchIssued {
+			asset_id: 0,
+			issued: vec![(2, 100), (3, 50), (2, 20)],
+		}));
+		assert_eq!(Assets::balance(0, 2), 120);
+		assert_eq!(Assets::balance(0, 3), 50);
+		assert_eq!(Assets::total_supply(0), 170);
+		// no event for each of the mints.
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::Assets(crate::Event::Issued { .. })
+		)));
+	});
+}
+
+#[test]
+fn transfer_batch_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		let transfers = BoundedVec::truncate_from(vec![(2, 30), (3, 40)]);
+
+		// a single failing transfer fails the whole batch.
+		assert_noop!(
+			Assets::transfer_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				BoundedVec::truncate_from(vec![(2, 30), (3, 80)])
+			),
+			Error::<Test>::BalanceLow
+		);
+
+		System::reset_events();
+		assert_ok!(Assets::transfer_batch(RuntimeOrigin::signed(1), 0, transfers));
+		assert_eq!(
+			System::events().into_iter().map(|record| record.event).collect::<Vec<_>>(),
+			vec![RuntimeEvent::Assets(crate::Event::BatchTransferred {
+				asset_id: 0,
+				from: 1,
+				transfers: vec![(2, 30), (3, 40)],
+			})]
+		);
+		assert_eq!(Assets::balance(0, 1), 30);
+		assert_eq!(Assets::balance(0, 2), 30);
+		assert_eq!(Assets::balance(0, 3), 40);
+
+		// the last transfer takes the dust of the sender.
+		assert_ok!(Assets::transfer_batch(
+			RuntimeOrigin::signed(1),
+			0,
+			BoundedVec::truncate_from(vec![(2, 15), (3, 10)])
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::BatchTransferred {
+			asset_id: 0,
+			from: 1,
+			transfers: vec![(2, 15), (3, 15)],
+		}));
+		assert_eq!(Assets::balance(0, 1), 0);
+		assert_eq!(Assets::balance(0, 3), 55);
+
+		// the weight grows with the number of transfers.
+		let weight = Call::<Test>::transfer_batch {
+			id: 0,
+			transfers: BoundedVec::truncate_from(vec![(2, 1), (3, 1)]),
+		}
+		.get_dispatch_info();
+		assert_eq!(
+			weight.call_weight,
+			<Test as Config>::WeightInfo::transfer()
+				.saturating_add(Assets::transfer_policy_weight())
+				.saturating_mul(2)
+		);
+
+		// an empty batch is not free.
+		let weight = Call::<Test>::transfer_batch { id: 0, transfers: BoundedVec::new() }
+			.get_dispatch_info();
+		assert_eq!(
+			weight.call_weight,
+			<Test as Config>::WeightInfo::transfer()
+				.saturating_add(Assets::transfer_policy_weight())
+		);
+		let weight =
+			Call::<Test>::mint_batch { id: 0, mints: BoundedVec::new() }.get_dispatch_info();
+		assert_eq!(weight.call_weight, <Test as Config>::WeightInfo::mint());
+	});
+}
+
+#[test]
+fn transfer_policy_applies_to_mint_into_and_burn_from() {
+	use frame_support::traits::fungibles::Mutate;
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 3, 100));
+		Balances::make_free_balance_be(&1, 2);
+		let allowlist = BoundedVec::truncate_from(vec![1, 2]);
+		assert_ok!(Assets::set_transfer_policy(RuntimeOrigin::signed(1), 0, Some(allowlist)));
+
+		// assets can arrive at and leave accounts of the allowlist, e.g. over XCM.
+		assert_ok!(<Assets as Mutate<_>>::mint_into(0, &2, 50));
+		assert_ok!(<Assets as Mutate<_>>::burn_from(0, &2, 20, Expendable, Exact, Polite));
+		assert_eq!(Assets::balance(0, 2), 30);
+
+		// but not other accounts.
+		assert_noop!(<Assets as Mutate<_>>::mint_into(0, &4, 50), TokenError::Blocked);
+		assert_noop!(
+			<Assets as Mutate<_>>::burn_from(0, &3, 20, Expendable, Exact, Polite),
+			TokenError::Blocked
+		);
+
+		// the issuer and the admin are not subject to the policy.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 4, 50));
+		assert_ok!(Assets::burn(RuntimeOrigin::signed(1), 0, 3, 20));
+		assert_eq!(Assets::balance(0, 3), 80);
+		assert_eq!(Assets::balance(0, 4), 50);
+	});
+}
@@ -34,6 +34,12 @@ pub(super) type AssetAccountOf<T, I> = AssetAccount<
 >;
 pub(super) type ExistenceReasonOf<T, I> =
 	ExistenceReason<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
+/// The transfer policy type of the pallet instance, see [`TransferPolicy`].
+pub type TransferPolicyOf<T, I = ()> = <<T as Config<I>>::TransferPolicy as TransferPolicy<
+	<T as Config<I>>::AssetId,
+	<T as SystemConfig>::AccountId,
+	<T as Config<I>>::Balance,
+>>::Policy;
 
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
@@ -232,6 +238,69 @@ impl<AssetId, AccountId, Balance> FrozenBalance<AssetId, AccountId, Balance> for
 	fn died(_: AssetId, _: &AccountId) {}
 }
 
+/// Trait for consulting a transfer policy registered by the owner of an asset, e.g. to keep a
+/// compliance-managed asset within an allowlist of accounts.
+///
+/// The policy is consulted on every transfer of the asset, except for the forced transfers of its
+/// admin. It is also consulted when the asset is minted or burned through `fungibles::Mutate`,
+/// which is how the asset enters and leaves the chain over XCM.
+pub trait TransferPolicy<AssetId, AccountId, Balance> {
+	/// The policy an asset owner may register, e.g. the identifier of an allowlist, or the
+	/// address of a contract to ask.
+	type Policy: Member + Parameter + MaxEncodedLen;
+
+	/// Check that `from` may transfer `amount` of the `asset` to `to` under the `policy`.
+	fn check(
+		policy: &Self::Policy,
+		asset: &AssetId,
+		from: &AccountId,
+		to: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// Check that `amount` of the `asset` may be minted into `who` under the `policy`, e.g. when
+	/// it is received over XCM.
+	fn check_deposit(
+		policy: &Self::Policy,
+		asset: &AssetId,
+		who: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// Check that `amount` of the `asset` may be burned from `who` under the `policy`, e.g. when
+	/// it is sent over XCM.
+	fn check_withdrawal(
+		policy: &Self::Policy,
+		asset: &AssetId,
+		who: &AccountId,
+		amount: Balance,
+	) -> DispatchResult;
+
+	/// The maximal weight of [`Self::check`], [`Self::check_deposit`] and
+	/// [`Self::check_withdrawal`].
+	fn weight() -> Weight;
+}
+
+impl<AssetId, AccountId, Balance> TransferPolicy<AssetId, AccountId, Balance> for () {
+	type Policy = ();
+
+	fn check(_: &(), _: &AssetId, _: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_deposit(_: &(), _: &AssetId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn check_withdrawal(_: &(), _: &AssetId, _: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(super) struct TransferFlags {
 	/// The debited account must stay alive at the end of the operation; an error is returned if
@@ -17,7 +17,13 @@
 
 //! Migrations for the AURA pallet.
 
-use frame_support::{pallet_prelude::*, traits::Get, weights::Weight};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use sp_consensus_aura::Slot;
+use sp_runtime::traits::{SaturatedConversion, Zero};
 
 struct __LastTimestamp<T>(core::marker::PhantomData<T>);
 impl<T: RemoveLastTimestamp> frame_support::traits::StorageInstance for __LastTimestamp<T> {
@@ -43,3 +49,33 @@ pub fn remove_last_timestamp<T: RemoveLastTimestamp>() -> Weight {
 	LastTimestamp::<T>::kill();
 	T::DbWeight::get().writes(1)
 }
+
+/// Recompute `CurrentSlot` after a change of `SlotDuration`.
+///
+/// The slot of the parent block is derived again from its timestamp and the new slot duration,
+/// otherwise the first block authored with a longer slot duration would be rejected for moving
+/// the slot backwards. Only the new slot duration is needed, so applying it more than once is
+/// harmless. `MinimumPeriod` of `pallet-timestamp` is a constant and has to be changed alongside
+/// in the same runtime upgrade, usually through [`super::MinimumPeriodTimesTwo`].
+pub struct ChangeSlotDuration<T>(core::marker::PhantomData<T>);
+
+impl<T: super::Config> OnRuntimeUpgrade for ChangeSlotDuration<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let slot_duration = super::Pallet::<T>::slot_duration();
+		if slot_duration.is_zero() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		let now = pallet_timestamp::Now::<T>::get();
+		let slot = Slot::from((now / slot_duration).saturated_into::<u64>());
+		log::info!(
+			target: super::LOG_TARGET,
+			"Recomputed the current slot {:?} for a slot duration of {:?}",
+			slot,
+			slot_duration,
+		);
+		super::CurrentSlot::<T>::put(slot);
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
@@ -117,3 +117,23 @@ fn pallet_always_rejects_decreasing_slot() {
 		Aura::on_initialize(43);
 	});
 }
+
+#[test]
+fn change_slot_duration_recomputes_current_slot() {
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	build_ext_and_execute_test(vec![0, 1, 2, 3], || {
+		// The parent block was authored in slot 50 of a 4ms slot duration.
+		pallet::CurrentSlot::<Test>::put(Slot::from(50));
+		pallet_timestamp::Now::<Test>::put(200);
+
+		crate::migrations::ChangeSlotDuration::<Test>::on_runtime_upgrade();
+
+		// Slot 100 with the 2ms slot duration of the mock.
+		assert_eq!(pallet::CurrentSlot::<Test>::get(), Slot::from(100));
+
+		// Applying it again has no effect.
+		crate::migrations::ChangeSlotDuration::<Test>::on_runtime_upgrade();
+		assert_eq!(pallet::CurrentSlot::<Test>::get(), Slot::from(100));
+	});
+}
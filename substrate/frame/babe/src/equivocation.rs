@@ -199,52 +199,143 @@ where
 }
 
 /// Methods for the `ValidateUnsigned` implementation:
-/// It restricts calls to `report_equivocation_unsigned` to local calls (i.e. extrinsics generated
-/// on this node) or that already in a block. This guarantees that only block authors can include
-/// unsigned equivocation reports.
+/// It restricts calls to `report_equivocation_unsigned` and `report_equivocations_unsigned` to
+/// local calls (i.e. extrinsics generated on this node) or that already in a block. This
+/// guarantees that only block authors can include unsigned equivocation reports.
 impl<T: Config> Pallet<T> {
 	pub fn validate_unsigned(source: TransactionSource, call: &Call<T>) -> TransactionValidity {
-		if let Call::report_equivocation_unsigned { equivocation_proof, key_owner_proof } = call {
-			// discard equivocation report not coming from the local node
-			match source {
-				TransactionSource::Local | TransactionSource::InBlock => { /* allowed */ },
-				_ => {
-					log::warn!(
-						target: LOG_TARGET,
-						"rejecting unsigned report equivocation transaction because it is not local/in-block.",
-					);
-
-					return InvalidTransaction::Call.into()
-				},
+		let evidence = Self::unsigned_evidence(call)?;
+
+		// discard equivocation report not coming from the local node
+		match source {
+			TransactionSource::Local | TransactionSource::InBlock => { /* allowed */ },
+			_ => {
+				log::warn!(
+					target: LOG_TARGET,
+					"rejecting unsigned report equivocation transaction because it is not local/in-block.",
+				);
+
+				return InvalidTransaction::Call.into()
+			},
+		}
+
+		// Check report validity
+		let fresh = Self::check_unsigned_evidence(evidence)?;
+
+		let longevity =
+			<T::EquivocationReportSystem as OffenceReportSystem<_, _>>::Longevity::get();
+
+		fresh
+			.into_iter()
+			// Only one equivocation report for the same offender at the same slot.
+			.fold(ValidTransaction::with_tag_prefix("BabeEquivocation"), |tx, tag| {
+				tx.and_provides(tag)
+			})
+			// We assign the maximum priority for any equivocation report.
+			.priority(TransactionPriority::max_value())
+			.longevity(longevity)
+			// We don't propagate this. This can never be included on a remote node.
+			.propagate(false)
+			.build()
+	}
+
+	pub fn pre_dispatch(call: &Call<T>) -> Result<(), TransactionValidityError> {
+		let evidence = Self::unsigned_evidence(call)?;
+		Self::check_unsigned_evidence(evidence).map(|_| ())
+	}
+
+	/// The evidence carried by an unsigned equivocation report.
+	fn unsigned_evidence(
+		call: &Call<T>,
+	) -> Result<Vec<(EquivocationProof<HeaderFor<T>>, T::KeyOwnerProof)>, TransactionValidityError>
+	{
+		match call {
+			Call::report_equivocation_unsigned { equivocation_proof, key_owner_proof } =>
+				Ok(vec![(*equivocation_proof.clone(), key_owner_proof.clone())]),
+			Call::report_equivocations_unsigned { equivocation_proofs, key_owner_proof } => {
+				let proofs = Self::sanitize_equivocations(equivocation_proofs.to_vec())
+					.ok_or(InvalidTransaction::Call)?;
+				Ok(proofs.into_iter().map(|proof| (proof, key_owner_proof.clone())).collect())
+			},
+			_ => Err(InvalidTransaction::Call.into()),
+		}
+	}
+
+	/// Check the given evidence and return the `(offender, slot)` tags of the equivocations that
+	/// were not reported yet.
+	///
+	/// Fails with [`InvalidTransaction::Stale`] if all of them were already reported.
+	fn check_unsigned_evidence(
+		evidence: Vec<(EquivocationProof<HeaderFor<T>>, T::KeyOwnerProof)>,
+	) -> Result<Vec<(AuthorityId, u64)>, TransactionValidityError> {
+		let mut fresh = Vec::with_capacity(evidence.len());
+		for (equivocation_proof, key_owner_proof) in evidence {
+			let tag = (equivocation_proof.offender.clone(), *equivocation_proof.slot);
+			match T::EquivocationReportSystem::check_evidence((equivocation_proof, key_owner_proof))
+			{
+				Ok(()) => fresh.push(tag),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Stale)) => {},
+				Err(e) => return Err(e),
 			}
+		}
 
-			// Check report validity
-			let evidence = (*equivocation_proof.clone(), key_owner_proof.clone());
-			T::EquivocationReportSystem::check_evidence(evidence)?;
-
-			let longevity =
-				<T::EquivocationReportSystem as OffenceReportSystem<_, _>>::Longevity::get();
-
-			ValidTransaction::with_tag_prefix("BabeEquivocation")
-				// We assign the maximum priority for any equivocation report.
-				.priority(TransactionPriority::max_value())
-				// Only one equivocation report for the same offender at the same slot.
-				.and_provides((equivocation_proof.offender.clone(), *equivocation_proof.slot))
-				.longevity(longevity)
-				// We don't propagate this. This can never be included on a remote node.
-				.propagate(false)
-				.build()
-		} else {
-			InvalidTransaction::Call.into()
+		if fresh.is_empty() {
+			return Err(InvalidTransaction::Stale.into())
+		}
+		Ok(fresh)
+	}
+}
+
+/// Methods for batched equivocation reports.
+impl<T: Config> Pallet<T> {
+	/// Report all `equivocation_proofs` of the same offender, skipping those already reported.
+	pub(crate) fn do_report_equivocations(
+		reporter: Option<T::AccountId>,
+		equivocation_proofs: Vec<EquivocationProof<HeaderFor<T>>>,
+		key_owner_proof: T::KeyOwnerProof,
+	) -> Result<(), DispatchError> {
+		let equivocation_proofs = Self::sanitize_equivocations(equivocation_proofs)
+			.ok_or(Error::<T>::InvalidEquivocationBatch)?;
+
+		let mut reported = 0u32;
+		for equivocation_proof in equivocation_proofs {
+			match T::EquivocationReportSystem::process_evidence(
+				reporter.clone(),
+				(equivocation_proof, key_owner_proof.clone()),
+			) {
+				Ok(()) => reported += 1,
+				Err(e) if e == Error::<T>::DuplicateOffenceReport.into() => {},
+				Err(e) => return Err(e),
+			}
 		}
+
+		if reported == 0 {
+			return Err(Error::<T>::DuplicateOffenceReport.into())
+		}
+		Ok(())
 	}
 
-	pub fn pre_dispatch(call: &Call<T>) -> Result<(), TransactionValidityError> {
-		if let Call::report_equivocation_unsigned { equivocation_proof, key_owner_proof } = call {
-			let evidence = (*equivocation_proof.clone(), key_owner_proof.clone());
-			T::EquivocationReportSystem::check_evidence(evidence)
-		} else {
-			Err(InvalidTransaction::Call.into())
+	/// Sort `equivocation_proofs` by slot and drop duplicate slots.
+	///
+	/// Returns `None` if there are no proofs, or if they are not all from the same offender in
+	/// the same epoch.
+	fn sanitize_equivocations(
+		mut equivocation_proofs: Vec<EquivocationProof<HeaderFor<T>>>,
+	) -> Option<Vec<EquivocationProof<HeaderFor<T>>>> {
+		let genesis_slot = crate::GenesisSlot::<T>::get();
+		let epoch_index = |slot: Slot| *slot.saturating_sub(genesis_slot) / T::EpochDuration::get();
+
+		let first = equivocation_proofs.first()?;
+		let (offender, epoch) = (first.offender.clone(), epoch_index(first.slot));
+		if equivocation_proofs
+			.iter()
+			.any(|proof| proof.offender != offender || epoch_index(proof.slot) != epoch)
+		{
+			return None
 		}
+
+		equivocation_proofs.sort_by_key(|p| p.slot);
+		equivocation_proofs.dedup_by_key(|p| p.slot);
+		Some(equivocation_proofs)
 	}
 }
@@ -109,6 +109,9 @@ impl EpochChangeTrigger for SameAuthoritiesForever {
 
 const UNDER_CONSTRUCTION_SEGMENT_LENGTH: u32 = 256;
 
+/// The maximum number of equivocation proofs in a single batched report.
+pub const MAX_EQUIVOCATIONS_PER_REPORT: u32 = 16;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -183,6 +186,9 @@ pub mod pallet {
 		DuplicateOffenceReport,
 		/// Submitted configuration is invalid.
 		InvalidConfiguration,
+		/// A batched equivocation report is empty, or its proofs are not all from the same
+		/// offender in the same epoch.
+		InvalidEquivocationBatch,
 	}
 
 	/// Current epoch index.
@@ -469,6 +475,30 @@ pub mod pallet {
 			PendingEpochConfigChange::<T>::put(config);
 			Ok(())
 		}
+
+		/// Report multiple equivocations of the same authority within a single epoch.
+		///
+		/// All proofs share the given key ownership proof. Duplicate slots are reported once and
+		/// slots that were already reported are skipped, the call only fails if none of the
+		/// equivocations is new. Like [`Call::report_equivocation_unsigned`], this extrinsic must
+		/// be called unsigned and is expected to be submitted by block authors only.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_equivocation(
+			key_owner_proof.validator_count(),
+			T::MaxNominators::get(),
+		).saturating_mul(equivocation_proofs.len() as u64))]
+		pub fn report_equivocations_unsigned(
+			origin: OriginFor<T>,
+			equivocation_proofs: BoundedVec<
+				EquivocationProof<HeaderFor<T>>,
+				ConstU32<MAX_EQUIVOCATIONS_PER_REPORT>,
+			>,
+			key_owner_proof: T::KeyOwnerProof,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Self::do_report_equivocations(None, equivocation_proofs.into_inner(), key_owner_proof)?;
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::validate_unsigned]
@@ -939,6 +939,71 @@ fn valid_equivocation_reports_dont_pay_fees() {
 	})
 }
 
+#[test]
+fn report_equivocations_batch_works() {
+	let (pairs, mut ext) = new_test_ext_with_pairs(3);
+
+	ext.execute_with(|| {
+		start_era(1);
+
+		let authorities = Authorities::<Test>::get();
+		let offending_validator_index = 1;
+		let offending_authority_pair = pairs
+			.into_iter()
+			.find(|p| p.public() == authorities[offending_validator_index].0)
+			.unwrap();
+		let key = (sp_consensus_babe::KEY_TYPE, &offending_authority_pair.public());
+		let key_owner_proof = Historical::prove(key).unwrap();
+
+		let epoch_start = Babe::current_epoch_start();
+		let proof_at = |slot: Slot| {
+			generate_equivocation_proof(
+				offending_validator_index as u32,
+				&offending_authority_pair,
+				slot,
+			)
+		};
+
+		// proofs must all be from the same epoch.
+		let next_epoch = epoch_start + EpochDuration::get();
+		assert_noop!(
+			Babe::report_equivocations_unsigned(
+				RuntimeOrigin::none(),
+				vec![proof_at(epoch_start), proof_at(next_epoch)].try_into().unwrap(),
+				key_owner_proof.clone(),
+			),
+			Error::<Test>::InvalidEquivocationBatch,
+		);
+
+		// a duplicate slot is only reported once.
+		let batch: BoundedVec<_, _> =
+			vec![proof_at(epoch_start + 1), proof_at(epoch_start), proof_at(epoch_start + 1)]
+				.try_into()
+				.unwrap();
+		let inner = Call::report_equivocations_unsigned {
+			equivocation_proofs: batch.clone(),
+			key_owner_proof: key_owner_proof.clone(),
+		};
+		assert_ok!(<Babe as sp_runtime::traits::ValidateUnsigned>::pre_dispatch(&inner));
+		assert_ok!(Babe::report_equivocations_unsigned(
+			RuntimeOrigin::none(),
+			batch.clone(),
+			key_owner_proof.clone(),
+		));
+		assert_eq!(pallet_offences::Reports::<Test>::iter().count(), 2);
+
+		// once all slots are reported, the batch is stale.
+		assert_err!(
+			<Babe as sp_runtime::traits::ValidateUnsigned>::pre_dispatch(&inner),
+			sp_runtime::transaction_validity::InvalidTransaction::Stale,
+		);
+		assert_noop!(
+			Babe::report_equivocations_unsigned(RuntimeOrigin::none(), batch, key_owner_proof),
+			Error::<Test>::DuplicateOffenceReport,
+		);
+	});
+}
+
 #[test]
 fn add_epoch_configurations_migration_works() {
 	use frame_support::storage::migration::{get_storage_value, put_storage_value};
@@ -38,10 +38,7 @@ pub async fn execute<Runtime, Block>(
 			transport: ws_url.to_string().into(),
 			pallets: vec![pallet_bags_list::Pallet::<Runtime, pallet_bags_list::Instance1>::name()
 				.to_string()],
-			hashed_prefixes: vec![
-				<pallet_staking::Bonded<Runtime>>::prefix_hash().to_vec(),
-				<pallet_staking::Ledger<Runtime>>::prefix_hash().to_vec(),
-			],
+			hashed_prefixes: vec![<pallet_staking::Ledger<Runtime>>::prefix_hash().to_vec()],
 			..Default::default()
 		}))
 		.build()
@@ -0,0 +1,39 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the bags-list pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait BagsListApi<AccountId, Score>
+		where
+			AccountId: Codec,
+			Score: Codec,
+	{
+		/// Returns up to `count` ids of the list with their score, in iteration order.
+		///
+		/// The page starts right after `start`, or at the head of the list if `start` is `None`.
+		/// It is empty if `start` is not in the list.
+		fn list_page(start: Option<AccountId>, count: u32) -> Vec<(AccountId, Score)>;
+	}
+}
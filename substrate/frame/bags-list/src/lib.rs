@@ -47,7 +47,13 @@
 //!
 //! Each bags has a upper and lower range of scores, denoted by [`Config::BagThresholds`]. All nodes
 //! within a bag must be within the range of the bag. If not, the permissionless [`Pallet::rebag`]
-//! can be used to move any node to the right bag.
+//! can be used to move any node to the right bag. Instead of rebagging accounts one by one,
+//! anyone can also call [`Pallet::recalculate_scores`] to re-read the scores of a bounded page of
+//! nodes and rebag those that drifted. It walks the whole list with a cursor, one page per block.
+//!
+//! If the list ever gets corrupted, e.g. as detected by `try-state`, the root origin can rebuild it
+//! from scratch with [`Pallet::start_rebuild`]. The nodes are then put back into their bags with
+//! their current score over multiple blocks, see [`ListRebuild`].
 //!
 //! Once a `rebag` happens, the order within a node is still not enforced. To move a node to the
 //! optimal position in a bag, the [`Pallet::put_in_front_of`] or [`Pallet::put_in_front_of_other`]
@@ -124,11 +130,16 @@ pub mod example {}
 
 extern crate alloc;
 
-use alloc::boxed::Box;
-use codec::FullCodec;
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use frame_election_provider_support::{ScoreProvider, SortedListProvider};
-use frame_system::ensure_signed;
-use sp_runtime::traits::{AtLeast32BitUnsigned, Bounded, StaticLookup};
+use frame_support::{traits::Get, weights::Weight};
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Bounded, StaticLookup},
+	RuntimeDebug,
+};
 
 #[cfg(any(test, feature = "try-runtime", feature = "fuzz"))]
 use sp_runtime::TryRuntimeError;
@@ -142,6 +153,8 @@ pub mod migrations;
 pub mod mock;
 #[cfg(test)]
 mod tests;
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub mod testing_utils;
 pub mod weights;
 
 pub use list::{notional_bag_for, Bag, List, ListError, Node};
@@ -166,6 +179,15 @@ macro_rules! log {
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// The progress of a rebuild of the list, see [`ListRebuild`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RebuildStatus<AccountId> {
+	/// The bags were cleared, and no node was rebuilt yet.
+	Started,
+	/// The nodes were rebuilt up to the given one, in the order of their storage keys.
+	After(AccountId),
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -247,6 +269,12 @@ pub mod pallet {
 			+ TypeInfo
 			+ FullCodec
 			+ MaxEncodedLen;
+
+		/// The maximum number of nodes whose score can be recalculated by a single call to
+		/// [`Pallet::recalculate_scores`], or that can be rebuilt by a single call to
+		/// [`Pallet::rebuild`] or in the idle time of a block.
+		#[pallet::constant]
+		type MaxScoreRecalculations: Get<u32>;
 	}
 
 	/// A single node, within some bag.
@@ -263,6 +291,28 @@ pub mod pallet {
 	pub(crate) type ListBags<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::Score, list::Bag<T, I>>;
 
+	/// The last node visited by [`Pallet::recalculate_scores`].
+	///
+	/// The next call continues right after it. If not set, or if the node has since been removed,
+	/// the next call starts from the head of the list.
+	#[pallet::storage]
+	pub type ScoreRecalculationCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// The block in which [`Pallet::recalculate_scores`] was last called.
+	#[pallet::storage]
+	pub type LastScoreRecalculation<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The progress of the rebuild of the list started by [`Pallet::start_rebuild`], if any.
+	///
+	/// While the list is rebuilt, only the nodes up to the cursor are linked into their bags, so
+	/// the list cannot be iterated. Changes to the nodes that are not rebuilt yet are only
+	/// recorded, without following their links.
+	#[pallet::storage]
+	pub type ListRebuild<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, RebuildStatus<T::AccountId>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -270,6 +320,12 @@ pub mod pallet {
 		Rebagged { who: T::AccountId, from: T::Score, to: T::Score },
 		/// Updated the score of some account to the given amount.
 		ScoreUpdated { who: T::AccountId, new_score: T::Score },
+		/// The scores of a page of nodes were recalculated, and `updated` of them had drifted.
+		ScoresRecalculated { checked: u32, updated: u32 },
+		/// A rebuild of the list was started.
+		RebuildStarted,
+		/// The rebuild of the list is complete.
+		RebuildCompleted,
 	}
 
 	#[pallet::error]
@@ -277,6 +333,14 @@ pub mod pallet {
 	pub enum Error<T, I = ()> {
 		/// A error in the list interface implementation.
 		List(ListError),
+		/// More nodes were requested than [`Config::MaxScoreRecalculations`] allows.
+		TooManyRecalculations,
+		/// Scores were already recalculated in this block.
+		AlreadyRecalculated,
+		/// The list is being rebuilt.
+		RebuildInProgress,
+		/// The list is not being rebuilt.
+		NoRebuild,
 	}
 
 	impl<T, I> From<ListError> for Error<T, I> {
@@ -325,6 +389,7 @@ pub mod pallet {
 			lighter: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let heavier = ensure_signed(origin)?;
+			ensure!(!ListRebuild::<T, I>::exists(), Error::<T, I>::RebuildInProgress);
 			let lighter = T::Lookup::lookup(lighter)?;
 			List::<T, I>::put_in_front_of(&lighter, &heavier)
 				.map_err::<Error<T, I>, _>(Into::into)
@@ -342,12 +407,90 @@ pub mod pallet {
 			lighter: AccountIdLookupOf<T>,
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
+			ensure!(!ListRebuild::<T, I>::exists(), Error::<T, I>::RebuildInProgress);
 			let lighter = T::Lookup::lookup(lighter)?;
 			let heavier = T::Lookup::lookup(heavier)?;
 			List::<T, I>::put_in_front_of(&lighter, &heavier)
 				.map_err::<Error<T, I>, _>(Into::into)
 				.map_err::<DispatchError, _>(Into::into)
 		}
+
+		/// Recalculate the scores of the next `count` nodes of the list, and rebag those whose
+		/// score drifted, for example because of a slash or a reward.
+		///
+		/// Anyone can call this function. It continues from where the previous call left off and
+		/// wraps around to the head of the list once the tail is reached. It can only be called
+		/// once per block, and `count` cannot exceed [`Config::MaxScoreRecalculations`]. A `count`
+		/// of zero does nothing.
+		///
+		/// Fee is paid by the origin under all circumstances, but the weight of the nodes that
+		/// were not visited is refunded.
+		#[pallet::call_index(3)]
+		#[pallet::weight(Pallet::<T, I>::recalculate_scores_weight(*count))]
+		pub fn recalculate_scores(origin: OriginFor<T>, count: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				count <= T::MaxScoreRecalculations::get(),
+				Error::<T, I>::TooManyRecalculations
+			);
+			ensure!(!ListRebuild::<T, I>::exists(), Error::<T, I>::RebuildInProgress);
+			// neither reset the cursor nor use up the call of this block.
+			if count == 0 {
+				return Ok(Some(Self::recalculate_scores_weight(0)).into())
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				LastScoreRecalculation::<T, I>::get() != Some(now),
+				Error::<T, I>::AlreadyRecalculated
+			);
+			LastScoreRecalculation::<T, I>::put(now);
+
+			let (checked, updated) = Self::do_recalculate_scores(count);
+			Self::deposit_event(Event::<T, I>::ScoresRecalculated { checked, updated });
+			Ok(Some(Self::recalculate_scores_weight(checked)).into())
+		}
+
+		/// Start rebuilding the whole list from [`Config::ScoreProvider`], for example to recover
+		/// from a corruption of the list.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// All bags are cleared. The nodes are then put back into their bags with their current
+		/// score, in the order of their storage keys, by [`Pallet::rebuild`] and in the idle time
+		/// of the following blocks. Until the rebuild is complete, the list cannot be iterated and
+		/// no snapshot of it can be taken, see [`SortedListProvider::is_rebuilding`].
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T, I>::start_rebuild_weight())]
+		pub fn start_rebuild(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!ListRebuild::<T, I>::exists(), Error::<T, I>::RebuildInProgress);
+
+			List::<T, I>::clear_bags();
+			ScoreRecalculationCursor::<T, I>::kill();
+			ListRebuild::<T, I>::put(RebuildStatus::Started);
+			Self::deposit_event(Event::<T, I>::RebuildStarted);
+			Ok(())
+		}
+
+		/// Rebuild the next `count` nodes of the rebuild started with [`Pallet::start_rebuild`].
+		///
+		/// The dispatch origin for this call must be _Root_. `count` cannot exceed
+		/// [`Config::MaxScoreRecalculations`], and the weight of the nodes that were not rebuilt
+		/// is refunded.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T, I>::rebuild_weight(*count))]
+		pub fn rebuild(origin: OriginFor<T>, count: u32) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			ensure!(
+				count <= T::MaxScoreRecalculations::get(),
+				Error::<T, I>::TooManyRecalculations
+			);
+			ensure!(ListRebuild::<T, I>::exists(), Error::<T, I>::NoRebuild);
+
+			let rebuilt = Self::do_rebuild(count);
+			Ok(Some(Self::rebuild_weight(rebuilt)).into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -360,6 +503,24 @@ pub mod pallet {
 			);
 		}
 
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			if !ListRebuild::<T, I>::exists() {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let count = remaining_weight
+				.saturating_sub(Self::rebuild_weight(0))
+				.checked_div_per_component(&Self::node_update_weight())
+				.unwrap_or_default()
+				.min(T::MaxScoreRecalculations::get().into()) as u32;
+			if count == 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let rebuilt = Self::do_rebuild(count);
+			Self::rebuild_weight(rebuilt)
+		}
+
 		#[cfg(feature = "try-runtime")]
 		fn try_state(_: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
 			<Self as SortedListProvider<T::AccountId>>::try_state()
@@ -370,6 +531,10 @@ pub mod pallet {
 #[cfg(any(test, feature = "try-runtime", feature = "fuzz"))]
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn do_try_state() -> Result<(), TryRuntimeError> {
+		// the list is only consistent again once it is fully rebuilt.
+		if ListRebuild::<T, I>::exists() {
+			return Ok(())
+		}
 		List::<T, I>::do_try_state()
 	}
 }
@@ -383,7 +548,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		new_score: T::Score,
 	) -> Result<Option<(T::Score, T::Score)>, ListError> {
 		// If no voter at that node, don't do anything. the caller just wasted the fee to call this.
-		let node = list::Node::<T, I>::get(&account).ok_or(ListError::NodeNotFound)?;
+		let mut node = list::Node::<T, I>::get(&account).ok_or(ListError::NodeNotFound)?;
+		if !Self::is_rebuilt(account) {
+			// the node is put into the right bag once it is rebuilt.
+			node.score = new_score;
+			ListNodes::<T, I>::insert(account, node);
+			Self::deposit_event(Event::<T, I>::ScoreUpdated { who: account.clone(), new_score });
+			return Ok(None)
+		}
 		let maybe_movement = List::update_position_for(node, new_score);
 		if let Some((from, to)) = maybe_movement {
 			Self::deposit_event(Event::<T, I>::Rebagged { who: account.clone(), from, to });
@@ -392,6 +564,125 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(maybe_movement)
 	}
 
+	/// Recalculate the scores of up to `count` nodes, starting after the
+	/// [`ScoreRecalculationCursor`], and rebag the ones that drifted.
+	///
+	/// Returns the number of nodes that were checked and the number of nodes that were updated.
+	pub fn do_recalculate_scores(count: u32) -> (u32, u32) {
+		if count == 0 {
+			return (0, 0)
+		}
+
+		let nodes: Vec<list::Node<T, I>> = match ScoreRecalculationCursor::<T, I>::get()
+			.and_then(|cursor| List::<T, I>::iter_from(&cursor).ok())
+		{
+			Some(iter) => iter.take(count as usize).collect(),
+			None => List::<T, I>::iter().take(count as usize).collect(),
+		};
+
+		// a short page means that the tail was reached, so the next call starts over.
+		match nodes.last() {
+			Some(last) if nodes.len() == count as usize =>
+				ScoreRecalculationCursor::<T, I>::put(last.id()),
+			_ => ScoreRecalculationCursor::<T, I>::kill(),
+		}
+
+		let mut updated = 0u32;
+		for node in nodes.iter() {
+			let score = T::ScoreProvider::score(node.id());
+			if score != node.score() && Self::do_rebag(node.id(), score).is_ok() {
+				updated += 1;
+			}
+		}
+
+		(nodes.len() as u32, updated)
+	}
+
+	/// Rebuild up to `count` nodes after the [`ListRebuild`] cursor, and complete the rebuild if
+	/// no node is left.
+	///
+	/// Returns the number of nodes that were rebuilt.
+	pub fn do_rebuild(count: u32) -> u32 {
+		let Some(status) = ListRebuild::<T, I>::get() else { return 0 };
+		if count == 0 {
+			return 0
+		}
+
+		let last = match status {
+			RebuildStatus::Started => None,
+			RebuildStatus::After(last) => Some(last),
+		};
+		let rebuilt = List::<T, I>::rebuild_nodes(last.as_ref(), count);
+
+		// a short page means that all nodes were rebuilt.
+		match rebuilt.last() {
+			Some(last) if rebuilt.len() == count as usize =>
+				ListRebuild::<T, I>::put(RebuildStatus::After(last.clone())),
+			_ => {
+				ListRebuild::<T, I>::kill();
+				Self::deposit_event(Event::<T, I>::RebuildCompleted);
+			},
+		}
+
+		rebuilt.len() as u32
+	}
+
+	/// Whether `id` is linked into the list, i.e. the list is not being rebuilt or `id` was
+	/// already rebuilt.
+	fn is_rebuilt(id: &T::AccountId) -> bool {
+		match ListRebuild::<T, I>::get() {
+			None => true,
+			Some(RebuildStatus::Started) => false,
+			Some(RebuildStatus::After(last)) =>
+				ListNodes::<T, I>::hashed_key_for(id) <= ListNodes::<T, I>::hashed_key_for(&last),
+		}
+	}
+
+	/// Up to `count` ids of the list with their score, starting right after `start`.
+	///
+	/// Empty if `start` is not part of the list, or while the list is being rebuilt.
+	pub fn api_list_page(start: Option<T::AccountId>, count: u32) -> Vec<(T::AccountId, T::Score)> {
+		if ListRebuild::<T, I>::exists() {
+			return Vec::new()
+		}
+		let nodes: Box<dyn Iterator<Item = list::Node<T, I>>> = match start {
+			Some(start) => match List::<T, I>::iter_from(&start) {
+				Ok(iter) => Box::new(iter),
+				Err(_) => return Vec::new(),
+			},
+			None => Box::new(List::<T, I>::iter()),
+		};
+		nodes
+			.take(count as usize)
+			.map(|node| (node.id().clone(), node.score()))
+			.collect()
+	}
+
+	/// The weight of moving a single node to its new bag.
+	fn node_update_weight() -> Weight {
+		T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal())
+	}
+
+	/// The weight of recalculating the scores of `count` nodes.
+	fn recalculate_scores_weight(count: u32) -> Weight {
+		Self::node_update_weight()
+			.saturating_mul(count.into())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+
+	/// The weight of clearing all bags to start a rebuild.
+	fn start_rebuild_weight() -> Weight {
+		let bags = T::BagThresholds::get().len() as u64 + 1;
+		T::DbWeight::get().reads_writes(1, bags.saturating_add(2))
+	}
+
+	/// The weight of rebuilding `count` nodes.
+	fn rebuild_weight(count: u32) -> Weight {
+		Self::node_update_weight()
+			.saturating_mul(count.into())
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+
 	/// Equivalent to `ListBags::get`, but public. Useful for tests in outside of this crate.
 	#[cfg(feature = "std")]
 	pub fn list_bags_get(score: T::Score) -> Option<list::Bag<T, I>> {
@@ -404,12 +695,17 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 	type Score = T::Score;
 
 	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
+		// only the rebuilt nodes could be reached, which is not a consistent view of the list.
+		if ListRebuild::<T, I>::exists() {
+			return Box::new(core::iter::empty())
+		}
 		Box::new(List::<T, I>::iter().map(|n| n.id().clone()))
 	}
 
 	fn iter_from(
 		start: &T::AccountId,
 	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, Self::Error> {
+		frame_support::ensure!(!ListRebuild::<T, I>::exists(), ListError::NodeNotFound);
 		let iter = List::<T, I>::iter_from(start)?;
 		Ok(Box::new(iter.map(|n| n.id().clone())))
 	}
@@ -418,12 +714,20 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 		ListNodes::<T, I>::count()
 	}
 
+	fn is_rebuilding() -> bool {
+		ListRebuild::<T, I>::exists()
+	}
+
 	fn contains(id: &T::AccountId) -> bool {
 		List::<T, I>::contains(id)
 	}
 
 	fn on_insert(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
-		List::<T, I>::insert(id, score)
+		if Self::is_rebuilt(&id) {
+			List::<T, I>::insert(id, score)
+		} else {
+			List::<T, I>::insert_unlinked(id, score)
+		}
 	}
 
 	fn get_score(id: &T::AccountId) -> Result<T::Score, ListError> {
@@ -435,7 +739,11 @@ impl<T: Config<I>, I: 'static> SortedListProvider<T::AccountId> for Pallet<T, I>
 	}
 
 	fn on_remove(id: &T::AccountId) -> Result<(), ListError> {
-		List::<T, I>::remove(id)
+		if Self::is_rebuilt(id) {
+			List::<T, I>::remove(id)
+		} else {
+			List::<T, I>::remove_unlinked(id)
+		}
 	}
 
 	fn unsafe_regenerate(
@@ -28,6 +28,7 @@ use crate::Config;
 use alloc::{
 	boxed::Box,
 	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec::Vec,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::{iter, marker::PhantomData};
@@ -40,14 +41,6 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_runtime::traits::{Bounded, Zero};
 
-#[cfg(any(
-	test,
-	feature = "try-runtime",
-	feature = "fuzz",
-	feature = "std",
-	feature = "runtime-benchmarks"
-))]
-use alloc::vec::Vec;
 #[cfg(any(test, feature = "try-runtime", feature = "fuzz"))]
 use sp_runtime::TryRuntimeError;
 
@@ -107,6 +100,7 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		crate::ListBags::<T, I>::remove_all(None);
 		#[allow(deprecated)]
 		crate::ListNodes::<T, I>::remove_all();
+		crate::ListRebuild::<T, I>::kill();
 	}
 
 	/// Regenerate all of the data from the given ids.
@@ -232,6 +226,60 @@ impl<T: Config<I>, I: 'static> List<T, I> {
 		num_affected
 	}
 
+	/// Remove all bags, so that the list can be rebuilt with [`Self::rebuild_nodes`].
+	///
+	/// The nodes are kept in storage, but they are not part of any bag until they are rebuilt.
+	pub(crate) fn clear_bags() {
+		// there are at most as many bags as thresholds, plus the implied one.
+		let _ = crate::ListBags::<T, I>::clear(u32::MAX, None);
+	}
+
+	/// Put up to `count` nodes back into their bags, in the order of their storage keys and
+	/// starting right after `last`, with their score from `T::ScoreProvider`.
+	///
+	/// The previous links of the nodes are not trusted, and simply dropped. Hence, the bags must
+	/// only contain the nodes up to `last`, i.e. they were cleared with [`Self::clear_bags`] before
+	/// the first node was rebuilt.
+	///
+	/// Returns the ids of the rebuilt nodes.
+	pub(crate) fn rebuild_nodes(last: Option<&T::AccountId>, count: u32) -> Vec<T::AccountId> {
+		let ids: Vec<T::AccountId> = match last {
+			Some(last) =>
+				crate::ListNodes::<T, I>::iter_from(crate::ListNodes::<T, I>::hashed_key_for(last))
+					.map(|(id, _)| id)
+					.take(count as usize)
+					.collect(),
+			None => crate::ListNodes::<T, I>::iter_keys().take(count as usize).collect(),
+		};
+
+		for id in ids.iter() {
+			crate::ListNodes::<T, I>::remove(id);
+			let _ = Self::insert(id.clone(), T::ScoreProvider::score(id)).defensive();
+		}
+
+		ids
+	}
+
+	/// Store a node for `id` without linking it into its bag, while the list is rebuilt.
+	///
+	/// The node is linked once it is rebuilt with [`Self::rebuild_nodes`]. Returns an error if the
+	/// list already contains `id`.
+	pub(crate) fn insert_unlinked(id: T::AccountId, score: T::Score) -> Result<(), ListError> {
+		ensure!(!Self::contains(&id), ListError::Duplicate);
+		let bag_upper = notional_bag_for::<T, I>(score);
+		Node::<T, I> { id, prev: None, next: None, bag_upper, score, _phantom: PhantomData }.put();
+		Ok(())
+	}
+
+	/// Remove a node that is not linked into its bag yet, while the list is rebuilt.
+	///
+	/// Unlike [`Self::remove`], this does not follow the links of the node, which are not trusted.
+	pub(crate) fn remove_unlinked(id: &T::AccountId) -> Result<(), ListError> {
+		ensure!(Self::contains(id), ListError::NodeNotFound);
+		crate::ListNodes::<T, I>::remove(id);
+		Ok(())
+	}
+
 	/// Returns `true` if the list contains `id`, otherwise returns `false`.
 	pub(crate) fn contains(id: &T::AccountId) -> bool {
 		crate::ListNodes::<T, I>::contains_key(id)
@@ -64,6 +64,7 @@ impl bags_list::Config for Runtime {
 	type BagThresholds = BagThresholds;
 	type ScoreProvider = StakingMock;
 	type Score = VoteWeight;
+	type MaxScoreRecalculations = frame_support::traits::ConstU32<4>;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
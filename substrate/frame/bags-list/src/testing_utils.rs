@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Testing utils for the bags list. Provides functions to lay out the list in the shapes that are
+//! the most expensive to operate on, for the benchmarks of this pallet and of the pallets that use
+//! it as their sorted list, such as the `VoterList` of staking.
+
+use crate::{list::List, Config};
+use alloc::vec::Vec;
+use frame_benchmarking::account;
+use frame_election_provider_support::ScoreProvider;
+use frame_support::{assert_ok, traits::Get};
+use sp_runtime::traits::{Bounded, One};
+
+const SEED: u32 = 0;
+
+/// Remove all nodes and bags from the list.
+pub fn clear<T: Config<I>, I: 'static>() {
+	// NOTE: safe to call outside block production
+	List::<T, I>::unsafe_clear();
+}
+
+/// The upper thresholds of all bags, including the implied `Score::max_value()` one.
+pub fn bag_uppers<T: Config<I>, I: 'static>() -> Vec<T::Score> {
+	let mut uppers = T::BagThresholds::get().to_vec();
+	if uppers.last() != Some(&T::Score::max_value()) {
+		uppers.push(T::Score::max_value());
+	}
+	uppers
+}
+
+/// Insert the account `name` with `index` into the list with `score`, which is also set in
+/// `T::ScoreProvider`.
+pub fn insert_node<T: Config<I>, I: 'static>(
+	name: &'static str,
+	index: u32,
+	score: T::Score,
+) -> T::AccountId {
+	let id: T::AccountId = account(name, index, SEED);
+	T::ScoreProvider::set_score_of(&id, score);
+	assert_ok!(List::<T, I>::insert(id.clone(), score));
+	id
+}
+
+/// Put `count` nodes with `score` into the list, which all end up in the same bag.
+///
+/// Walking that bag, e.g. to iterate the list, visits all of them. Returns the nodes from the head
+/// to the tail of the bag.
+pub fn populate_single_bag<T: Config<I>, I: 'static>(
+	count: u32,
+	score: T::Score,
+) -> Vec<T::AccountId> {
+	(0..count).map(|i| insert_node::<T, I>("single_bag", i, score)).collect()
+}
+
+/// Put `per_bag` nodes into every bag, with the upper threshold of their bag as score.
+///
+/// Iterating the list visits every bag, and every node sits on the edge of its bag: the smallest
+/// increase of its score moves it to the next bag. Returns the nodes in iteration order.
+pub fn populate_all_bags<T: Config<I>, I: 'static>(per_bag: u32) -> Vec<T::AccountId> {
+	let mut nodes = Vec::new();
+	for (bag, upper) in bag_uppers::<T, I>().into_iter().enumerate().rev() {
+		for i in 0..per_bag {
+			nodes.push(insert_node::<T, I>("all_bags", bag as u32 * per_bag + i, upper));
+		}
+	}
+	nodes
+}
+
+/// Lay out the most expensive rebag, and return the node to rebag.
+///
+/// The node is in the middle of the lowest bag, next to the other `count - 1` nodes of that bag
+/// (at least two), so that both of its neighbours are updated when it is removed. Its score in
+/// `T::ScoreProvider` is just above its bag, so that it is moved to the second lowest bag, which
+/// is not empty either and has its tail updated.
+pub fn worst_case_rebag<T: Config<I>, I: 'static>(count: u32) -> T::AccountId {
+	let uppers = bag_uppers::<T, I>();
+	assert!(uppers.len() > 1, "rebag needs at least two bags");
+	let (origin, destination) = (uppers[0], uppers[1]);
+
+	let nodes = (0..count.max(3))
+		.map(|i| insert_node::<T, I>("rebag_origin", i, origin))
+		.collect::<Vec<_>>();
+	insert_node::<T, I>("rebag_destination", 0, destination);
+
+	let node = nodes[nodes.len() / 2].clone();
+	T::ScoreProvider::set_score_of(&node, origin + One::one());
+	node
+}
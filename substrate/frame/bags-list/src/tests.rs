@@ -227,6 +227,166 @@ mod pallet {
 		});
 	}
 
+	#[test]
+	fn recalculate_scores_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given 2 and 1 have drifted out of their bags
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+			StakingMock::set_score_of(&2, 10);
+			StakingMock::set_score_of(&1, 2_000);
+
+			// when the first page is recalculated
+			System::set_block_number(1);
+			assert_ok!(BagsList::recalculate_scores(RuntimeOrigin::signed(0), 2));
+
+			// then only the drifted id within the page is rebagged
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1, 2]), (1_000, vec![3, 4])]);
+			assert_eq!(ScoreRecalculationCursor::<Runtime>::get(), Some(3));
+			System::assert_last_event(
+				Event::<Runtime>::ScoresRecalculated { checked: 2, updated: 1 }.into(),
+			);
+
+			// and it cannot be called again in the same block
+			assert_noop!(
+				BagsList::recalculate_scores(RuntimeOrigin::signed(0), 2),
+				Error::<Runtime>::AlreadyRecalculated
+			);
+
+			// when the next page is recalculated, it continues after the cursor
+			System::set_block_number(2);
+			assert_ok!(BagsList::recalculate_scores(RuntimeOrigin::signed(0), 2));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![2]), (1_000, vec![3, 4]), (2_000, vec![1])]
+			);
+			assert_eq!(ScoreRecalculationCursor::<Runtime>::get(), Some(1));
+
+			// when the tail is reached, the cursor is reset
+			System::set_block_number(3);
+			assert_ok!(BagsList::recalculate_scores(RuntimeOrigin::signed(0), 4));
+			assert_eq!(ScoreRecalculationCursor::<Runtime>::get(), None);
+			System::assert_last_event(
+				Event::<Runtime>::ScoresRecalculated { checked: 3, updated: 0 }.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn recalculate_zero_scores_is_noop() {
+		ExtBuilder::default().build_and_execute(|| {
+			System::set_block_number(1);
+			assert_ok!(BagsList::recalculate_scores(RuntimeOrigin::signed(0), 2));
+			assert_eq!(ScoreRecalculationCursor::<Runtime>::get(), Some(3));
+
+			// an empty page neither resets the cursor nor counts as the call of the block
+			System::set_block_number(2);
+			assert_storage_noop!(assert_ok!(BagsList::recalculate_scores(
+				RuntimeOrigin::signed(0),
+				0
+			)));
+			assert_storage_noop!(assert_eq!(BagsList::do_recalculate_scores(0), (0, 0)));
+			assert_ok!(BagsList::recalculate_scores(RuntimeOrigin::signed(0), 2));
+			assert_eq!(ScoreRecalculationCursor::<Runtime>::get(), Some(1));
+		});
+	}
+
+	#[test]
+	fn recalculate_scores_is_bounded() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_noop!(
+				BagsList::recalculate_scores(RuntimeOrigin::signed(0), 5),
+				Error::<Runtime>::TooManyRecalculations
+			);
+		});
+	}
+
+	#[test]
+	fn rebuild_recovers_a_corrupted_list() {
+		use frame_support::traits::OnIdle;
+		use sp_runtime::traits::BadOrigin;
+
+		let in_key_order = |mut ids: Vec<AccountId>| {
+			ids.sort_by_key(|id| ListNodes::<Runtime>::hashed_key_for(id));
+			ids
+		};
+
+		ExtBuilder::default().build_and_execute(|| {
+			// given a list where 3 lost its link to 4, and 1 drifted out of its bag
+			ListNodes::<Runtime>::mutate(3, |node| node.as_mut().unwrap().next = None);
+			StakingMock::set_score_of(&1, 2_000);
+			assert!(List::<Runtime>::do_try_state().is_err());
+
+			assert_noop!(BagsList::start_rebuild(RuntimeOrigin::signed(1)), BadOrigin);
+			assert_noop!(BagsList::rebuild(RuntimeOrigin::root(), 2), Error::<Runtime>::NoRebuild);
+
+			// when the rebuild is started, the bags are empty and the list cannot be iterated
+			assert_ok!(BagsList::start_rebuild(RuntimeOrigin::root()));
+			System::assert_last_event(Event::<Runtime>::RebuildStarted.into());
+			assert_eq!(get_list_as_ids(), Vec::<AccountId>::new());
+			assert_eq!(BagsList::count(), 4);
+			assert!(BagsList::is_rebuilding());
+			assert_eq!(BagsList::api_list_page(None, 4), vec![]);
+			assert_noop!(
+				BagsList::start_rebuild(RuntimeOrigin::root()),
+				Error::<Runtime>::RebuildInProgress
+			);
+			assert_noop!(
+				BagsList::put_in_front_of(RuntimeOrigin::signed(3), 4),
+				Error::<Runtime>::RebuildInProgress
+			);
+			assert_noop!(
+				BagsList::rebuild(RuntimeOrigin::root(), 5),
+				Error::<Runtime>::TooManyRecalculations
+			);
+
+			// then the nodes are rebuilt in the order of their storage keys
+			let order = in_key_order(vec![1, 2, 3, 4]);
+			assert_ok!(BagsList::rebuild(RuntimeOrigin::root(), 2));
+			assert_eq!(ListRebuild::<Runtime>::get(), Some(RebuildStatus::After(order[1])));
+			let mut rebuilt = get_list_as_ids();
+			rebuilt.sort();
+			let mut expected = order[..2].to_vec();
+			expected.sort();
+			assert_eq!(rebuilt, expected);
+			// but only a part of the nodes is linked, so the list still cannot be iterated
+			assert_eq!(BagsList::iter().count(), 0);
+			assert!(BagsList::iter_from(&order[0]).is_err());
+
+			// and the nodes that are not rebuilt yet can still be updated
+			assert_ok!(BagsList::on_remove(&order[2]));
+			StakingMock::set_score_of(&order[3], 15);
+			assert_ok!(BagsList::on_update(&order[3], 15));
+			StakingMock::set_score_of(&5, 15);
+			assert_ok!(BagsList::on_insert(5, 15));
+			assert_eq!(BagsList::count(), 4);
+
+			// when the rebuild is completed in the idle time of a block
+			BagsList::on_idle(1, Weight::MAX);
+			System::assert_last_event(Event::<Runtime>::RebuildCompleted.into());
+			assert_eq!(ListRebuild::<Runtime>::get(), None);
+			assert!(!BagsList::is_rebuilding());
+			assert_eq!(BagsList::iter().count(), 4);
+
+			// then all nodes are back in their bags, in the order of their storage keys
+			let remaining = [1, 2, 3, 4, 5].into_iter().filter(|id| *id != order[2]).collect();
+			let mut expected = std::collections::BTreeMap::<VoteWeight, Vec<AccountId>>::new();
+			for id in in_key_order(remaining) {
+				let bag = notional_bag_for::<Runtime, ()>(StakingMock::score(&id));
+				expected.entry(bag).or_default().push(id);
+			}
+			assert_eq!(List::<Runtime>::get_bags(), expected.into_iter().collect::<Vec<_>>());
+		});
+	}
+
+	#[test]
+	fn api_list_page_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_eq!(BagsList::api_list_page(None, 3), vec![(2, 1_000), (3, 1_000), (4, 1_000)]);
+			assert_eq!(BagsList::api_list_page(Some(3), 3), vec![(4, 1_000), (1, 10)]);
+			assert_eq!(BagsList::api_list_page(Some(42), 3), vec![]);
+		});
+	}
+
 	#[test]
 	#[should_panic = "thresholds must strictly increase, and have no duplicates"]
 	fn duplicate_in_bags_threshold_panics() {
@@ -736,3 +896,62 @@ mod sorted_list_provider {
 		})
 	}
 }
+
+mod testing_utils {
+	use super::*;
+	use crate::testing_utils::*;
+
+	#[test]
+	fn populate_single_bag_works() {
+		ExtBuilder::default().skip_genesis_ids().build_and_execute(|| {
+			let nodes = populate_single_bag::<Runtime, ()>(5, 15);
+
+			assert_eq!(nodes.len(), 5);
+			assert_eq!(List::<Runtime>::get_bags(), vec![(20, nodes)]);
+		})
+	}
+
+	#[test]
+	fn populate_all_bags_works() {
+		ExtBuilder::default().skip_genesis_ids().build_and_execute(|| {
+			let nodes = populate_all_bags::<Runtime, ()>(2);
+
+			// 9 thresholds and the implied `VoteWeight::MAX` one.
+			assert_eq!(nodes.len(), 20);
+			assert_eq!(get_list_as_ids(), nodes);
+			let bags = List::<Runtime>::get_bags();
+			assert_eq!(bags.len(), 10);
+			assert!(bags.iter().all(|(_, ids)| ids.len() == 2));
+
+			// every node is on the edge of its bag.
+			for (upper, ids) in bags.into_iter().filter(|(upper, _)| *upper != VoteWeight::MAX) {
+				for id in ids {
+					assert_eq!(BagsList::get_score(&id), Ok(upper));
+					assert_ne!(notional_bag_for::<Runtime, _>(upper + 1), upper);
+				}
+			}
+		})
+	}
+
+	#[test]
+	fn worst_case_rebag_works() {
+		ExtBuilder::default().skip_genesis_ids().build_and_execute(|| {
+			let node = worst_case_rebag::<Runtime, ()>(3);
+
+			let bags = List::<Runtime>::get_bags();
+			assert_eq!(bags.len(), 2);
+			assert_eq!(bags[0].0, 10);
+			assert_eq!(bags[0].1[1], node);
+			assert_eq!(bags[1].1.len(), 1);
+
+			// when
+			assert_ok!(BagsList::rebag(RuntimeOrigin::signed(0), node));
+
+			// then the node is moved to the tail of the next bag.
+			let bags = List::<Runtime>::get_bags();
+			assert_eq!(bags[0].1.len(), 2);
+			assert_eq!(bags[1].0, 20);
+			assert_eq!(bags[1].1.last(), Some(&node));
+		})
+	}
+}
@@ -949,4 +949,8 @@ impl<T: Config<I>, I: 'static> InspectLockableCurrency<T::AccountId> for Pallet<
 			.filter(|l| l.id == id)
 			.fold(Zero::zero(), |acc, l| acc + l.amount)
 	}
+
+	fn balance_frozen(who: &T::AccountId) -> Self::Balance {
+		Self::account(who).frozen
+	}
 }
@@ -104,6 +104,11 @@ fn inspect_lock_should_work() {
 			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_locked(ID_1, &2), 20);
 			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_locked(ID_2, &2), 0);
 			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_locked(ID_1, &3), 0);
+
+			// locks overlap.
+			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_frozen(&1), 10);
+			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_frozen(&2), 20);
+			assert_eq!(<Balances as InspectLockableCurrency<_>>::balance_frozen(&3), 0);
 		})
 }
 
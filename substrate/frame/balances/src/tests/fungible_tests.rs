@@ -28,8 +28,9 @@ use frame_support::traits::{
 	Consideration, Footprint, LinearStoragePrice, MaybeConsideration,
 };
 use fungible::{
-	FreezeConsideration, HoldConsideration, Inspect, InspectFreeze, InspectHold,
-	LoneFreezeConsideration, LoneHoldConsideration, Mutate, MutateFreeze, MutateHold, Unbalanced,
+	DepositLedger, DepositPrice, FreezeConsideration, HeldDeposit, HoldConsideration, Inspect,
+	InspectFreeze, InspectHold, LoneFreezeConsideration, LoneHoldConsideration, Mutate,
+	MutateFreeze, MutateHold, ReservedDeposit, StorageDeposit, Unbalanced,
 };
 use sp_core::ConstU64;
 
@@ -668,3 +669,93 @@ fn lone_hold_consideration_works() {
 			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 0);
 		});
 }
+
+#[test]
+fn storage_deposit_works() {
+	parameter_types! {
+		pub static Slope: u64 = 1;
+	}
+	#[frame_support::storage_alias]
+	type Ledgers =
+		StorageMap<StorageDepositTest, frame_support::Blake2_128Concat, u64, DepositLedger<u64>>;
+	type Deposit = StorageDeposit<
+		u64,
+		HeldDeposit<Balances, FooReason>,
+		LinearStoragePrice<ConstU64<0>, Slope, u64>,
+		Ledgers,
+	>;
+
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.monied(true)
+		.build_and_execute_with(|| {
+			let who = 4;
+			// hold amount taken somewhere outside of our (StorageDeposit) scope.
+			let extend_hold = 15;
+			assert_ok!(Balances::hold(&TestId::Foo, &who, extend_hold));
+
+			assert_eq!(Deposit::charge(&who, Footprint::from_parts(1, 10)), Ok(10));
+			assert_eq!(Deposit::charge(&who, Footprint::from_parts(1, 20)), Ok(20));
+			assert_eq!(
+				Deposit::ledger(&who),
+				Some(DepositLedger { count: 2, size: 30, deposit: 30 })
+			);
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 30 + extend_hold);
+
+			assert_eq!(Deposit::refund(&who, Footprint::from_parts(1, 10)), Ok(10));
+			assert_eq!(Deposit::deposit_of(&who), 20);
+
+			// A price increase only applies to new data.
+			Slope::set(2);
+			assert_eq!(Deposit::charge(&who, Footprint::from_parts(1, 10)), Ok(20));
+			assert_eq!(
+				Deposit::ledger(&who),
+				Some(DepositLedger { count: 2, size: 30, deposit: 40 })
+			);
+
+			// Refunds are proportional to the bytes removed, rounded down.
+			assert_eq!(Deposit::refund(&who, Footprint::from_parts(1, 10)), Ok(13));
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 27 + extend_hold);
+
+			assert_eq!(Deposit::refund_all(&who), Ok(27));
+			assert_eq!(Deposit::ledger(&who), None);
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 0 + extend_hold);
+		});
+}
+
+#[test]
+fn storage_deposit_update_works() {
+	type Price = DepositPrice<ConstU64<5>, ConstU64<2>, ConstU64<1>>;
+	type Held = StorageDeposit<u64, HeldDeposit<Balances, FooReason>, Price>;
+	type Reserved = StorageDeposit<u64, ReservedDeposit<Balances>, Price>;
+
+	ExtBuilder::default()
+		.existential_deposit(1)
+		.monied(true)
+		.build_and_execute_with(|| {
+			let who = 4;
+			assert_eq!(Held::price(Footprint::from_parts(0, 10)), 0);
+			assert_eq!(Held::price(Footprint::from_parts(2, 10)), 5 + 2 * 2 + 10);
+
+			// the difference to the recorded deposit is held or released.
+			assert_eq!(Held::update(&who, 0, Footprint::from_parts(1, 3)), Ok(10));
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 10);
+			assert_eq!(Held::update(&who, 10, Footprint::from_parts(2, 3)), Ok(12));
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 12);
+			assert_eq!(Held::update(&who, 12, Footprint::from_parts(1, 0)), Ok(7));
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 7);
+			assert_eq!(Held::update(&who, 7, Footprint::default()), Ok(0));
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 0);
+
+			// deposits can be reserved instead.
+			assert_eq!(Reserved::update(&who, 0, Footprint::from_parts(1, 3)), Ok(10));
+			assert_eq!(Balances::reserved_balance(&who), 10);
+			assert_eq!(Reserved::update(&who, 10, Footprint::default()), Ok(0));
+			assert_eq!(Balances::reserved_balance(&who), 0);
+
+			// nothing changes if the deposit can not be paid.
+			let price = Balances::free_balance(&who);
+			assert!(Held::update(&who, 0, Footprint::from_parts(1, price as usize)).is_err());
+			assert_eq!(Balances::balance_on_hold(&TestId::Foo, &who), 0);
+		});
+}
@@ -32,18 +32,27 @@
 //! 3. Arbitrary extra leaf data to be used by downstream pallets to include custom data.
 //!
 //! and thanks to versioning can be easily updated in the future.
+//!
+//! The [`ExposuresRootProvider`] can be used as extra leaf data to commit to the staking exposures
+//! of the active validators. External systems following BEEFY can then prove the exposure of a
+//! validator: from a BEEFY signed MMR root, to an MMR leaf, to its extra data, and finally to the
+//! exposure itself with a proof generated by [`ExposuresRootProvider::prove`] and checked with
+//! [`verify_exposure_proof`].
 
 extern crate alloc;
 
 use sp_runtime::{
 	generic::OpaqueDigestItemId,
-	traits::{Convert, Header, Member},
-	SaturatedConversion,
+	traits::{Convert, Hash, Header, Member},
+	RuntimeDebug, SaturatedConversion,
 };
 
 use alloc::vec::Vec;
-use codec::Decode;
+use binary_merkle_tree::MerkleProof;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
 use pallet_mmr::{primitives::AncestryProof, LeafDataProvider, NodesUtils, ParentNumberAndHash};
+use scale_info::TypeInfo;
 use sp_consensus_beefy::{
 	known_payloads,
 	mmr::{BeefyAuthoritySet, BeefyDataProvider, BeefyNextAuthoritySet, MmrLeaf, MmrLeafVersion},
@@ -99,6 +108,81 @@ impl Convert<sp_consensus_beefy::ecdsa_crypto::AuthorityId, Vec<u8>> for BeefyEc
 
 type MerkleRootOf<T> = <<T as pallet_mmr::Config>::Hashing as sp_runtime::traits::Hash>::Output;
 
+/// The stake backing a validator in a staking era.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ValidatorExposure<AccountId, Balance> {
+	/// The staking era of the exposure.
+	pub era: u32,
+	/// The validator.
+	pub validator: AccountId,
+	/// The total stake backing the validator, its own stake included.
+	pub total: Balance,
+	/// The own stake of the validator.
+	pub own: Balance,
+}
+
+/// A source of the exposures of the active validators.
+pub trait ExposuresProvider {
+	/// The account id of the validators.
+	type AccountId: Encode + PartialEq;
+	/// The balance of the exposures.
+	type Balance: Encode;
+
+	/// The exposures of the validators of the active era, in a deterministic order.
+	fn exposures() -> Vec<ValidatorExposure<Self::AccountId, Self::Balance>>;
+}
+
+/// A BEEFY data provider that merkelizes the exposures given by `E`, in their order.
+///
+/// The exposures are SCALE encoded to form the leaves of the tree.
+pub struct ExposuresRootProvider<T, E>(PhantomData<(T, E)>);
+
+impl<T: pallet_mmr::Config, E: ExposuresProvider> BeefyDataProvider<MerkleRootOf<T>>
+	for ExposuresRootProvider<T, E>
+{
+	fn extra_data() -> MerkleRootOf<T> {
+		binary_merkle_tree::merkle_root::<<T as pallet_mmr::Config>::Hashing, _>(
+			E::exposures().iter().map(|exposure| exposure.encode()),
+		)
+	}
+}
+
+impl<T: pallet_mmr::Config, E: ExposuresProvider> ExposuresRootProvider<T, E> {
+	/// Prove the current exposure of `validator` against the current extra data.
+	///
+	/// Returns `None` if `validator` has no exposure in the active era.
+	pub fn prove(validator: &E::AccountId) -> Option<MerkleProof<MerkleRootOf<T>, Vec<u8>>> {
+		let exposures = E::exposures();
+		let leaf_index = exposures.iter().position(|exposure| exposure.validator == *validator)?;
+		Some(binary_merkle_tree::merkle_proof::<<T as pallet_mmr::Config>::Hashing, _, _>(
+			exposures.iter().map(|exposure| exposure.encode()).collect::<Vec<_>>(),
+			leaf_index as u32,
+		))
+	}
+}
+
+/// Verify that `proof` proves `exposure` against `root`, the extra data of an MMR leaf committed
+/// to by [`ExposuresRootProvider`].
+pub fn verify_exposure_proof<H, AccountId, Balance>(
+	root: &H::Output,
+	proof: MerkleProof<H::Output, Vec<u8>>,
+	exposure: &ValidatorExposure<AccountId, Balance>,
+) -> bool
+where
+	H: Hash,
+	AccountId: Encode,
+	Balance: Encode,
+{
+	proof.leaf == exposure.encode() &&
+		binary_merkle_tree::verify_proof::<H, _, _>(
+			root,
+			proof.proof,
+			proof.number_of_leaves,
+			proof.leaf_index,
+			&proof.leaf,
+		)
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	#![allow(missing_docs)]
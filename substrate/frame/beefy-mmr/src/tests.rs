@@ -20,7 +20,7 @@ use std::vec;
 use codec::{Decode, Encode};
 use sp_consensus_beefy::{
 	known_payloads,
-	mmr::{BeefyNextAuthoritySet, MmrLeafVersion},
+	mmr::{BeefyDataProvider, BeefyNextAuthoritySet, MmrLeafVersion},
 	AncestryHelper, Commitment, Payload, ValidatorSet,
 };
 
@@ -384,3 +384,47 @@ fn is_non_canonical_should_work_correctly() {
 		}
 	});
 }
+
+struct TestExposures;
+impl crate::ExposuresProvider for TestExposures {
+	type AccountId = u64;
+	type Balance = u128;
+
+	fn exposures() -> Vec<crate::ValidatorExposure<u64, u128>> {
+		(1..=5)
+			.map(|validator| crate::ValidatorExposure {
+				era: 7,
+				validator,
+				total: validator as u128 * 100,
+				own: validator as u128 * 10,
+			})
+			.collect()
+	}
+}
+
+#[test]
+fn exposure_proofs_should_work() {
+	type Provider = crate::ExposuresRootProvider<Test, TestExposures>;
+	let root = <Provider as BeefyDataProvider<H256>>::extra_data();
+	let exposure = crate::ValidatorExposure { era: 7, validator: 3, total: 300, own: 30 };
+
+	let proof = Provider::prove(&3).unwrap();
+	assert_eq!(proof.root, root);
+	assert!(crate::verify_exposure_proof::<Keccak256, _, _>(&root, proof, &exposure));
+
+	// a proof does not hold for another exposure, nor against another root.
+	let forged = crate::ValidatorExposure { total: 3000, ..exposure.clone() };
+	assert!(!crate::verify_exposure_proof::<Keccak256, _, _>(
+		&root,
+		Provider::prove(&3).unwrap(),
+		&forged
+	));
+	assert!(!crate::verify_exposure_proof::<Keccak256, _, _>(
+		&H256::repeat_byte(1),
+		Provider::prove(&3).unwrap(),
+		&exposure
+	));
+
+	// validators without exposure cannot be proven.
+	assert!(Provider::prove(&6).is_none());
+}
@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use super::*;
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
@@ -288,6 +289,51 @@ impl<T: Config> Pallet<T> {
 		Ok(new_region_ids)
 	}
 
+	pub(crate) fn do_split(
+		region_id: RegionId,
+		maybe_check_owner: Option<T::AccountId>,
+		pivots: Vec<Timeslice>,
+		masks: Vec<CoreMask>,
+	) -> Result<Vec<RegionId>, Error<T>> {
+		ensure!(!pivots.is_empty() || !masks.is_empty(), Error::<T>::NothingToSplit);
+
+		// Partition in time first; each pivot is an offset from the beginning of the original
+		// region, so it is rebased onto the piece which remains to be partitioned.
+		let mut pieces = Vec::with_capacity(pivots.len().saturating_add(1));
+		let mut rest = region_id;
+		for pivot in pivots {
+			let offset = region_id.begin.saturating_add(pivot).saturating_sub(rest.begin);
+			let (piece, next) = Self::do_partition(rest, maybe_check_owner.clone(), offset)?;
+			pieces.push(piece);
+			rest = next;
+		}
+		pieces.push(rest);
+
+		// Then interlace every piece by each of the masks in turn.
+		let mut new_region_ids = Vec::with_capacity(pieces.len().saturating_mul(masks.len()));
+		for piece in pieces {
+			let mut rest = piece;
+			for (i, &mask) in masks.iter().enumerate() {
+				if mask == rest.mask {
+					// Only the final mask may cover exactly what is left of the piece; it then
+					// simply becomes the remainder.
+					ensure!(i + 1 == masks.len(), Error::<T>::ExteriorPivot);
+					break
+				}
+				let (one, other) = Self::do_interlace(rest, maybe_check_owner.clone(), mask)?;
+				new_region_ids.push(one);
+				rest = other;
+			}
+			new_region_ids.push(rest);
+		}
+
+		Self::deposit_event(Event::Split {
+			old_region_id: region_id,
+			new_region_ids: new_region_ids.clone(),
+		});
+		Ok(new_region_ids)
+	}
+
 	pub(crate) fn do_assign(
 		region_id: RegionId,
 		maybe_check_owner: Option<T::AccountId>,
@@ -263,6 +263,13 @@ pub mod pallet {
 			/// The new Regions into which it became.
 			new_region_ids: (RegionId, RegionId),
 		},
+		/// A Region has been split both in time and by interlace mask in a single operation.
+		Split {
+			/// The Region which was split.
+			old_region_id: RegionId,
+			/// The new Regions into which it became, ordered by beginning and then by mask.
+			new_region_ids: Vec<RegionId>,
+		},
 		/// A Region has been assigned to a particular task.
 		Assigned {
 			/// The Region which was assigned.
@@ -544,6 +551,8 @@ pub mod pallet {
 		SovereignAccountNotFound,
 		/// Attempted to disable auto-renewal for a core that didn't have it enabled.
 		AutoRenewalNotEnabled,
+		/// Neither a time pivot nor an interlace mask was given for splitting the region.
+		NothingToSplit,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -733,6 +742,35 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Split a Bulk Coretime Region in time and by interlace mask at once.
+		///
+		/// The Region is first partitioned at each of the `pivots` and every resulting piece is
+		/// then interlaced into one Region for each of the `masks`, plus one for whatever part of
+		/// the original mask remains uncovered. Each new Region has the same owner as the
+		/// original.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region which should be split.
+		/// - `pivots`: Strictly ascending offsets in time into the Region at which to partition.
+		/// - `masks`: Mutually disjoint, non-void interlace masks within the Region's mask.
+		#[pallet::call_index(24)]
+		#[pallet::weight({
+			let pieces = pivots.len().saturating_add(1) as u64;
+			T::WeightInfo::partition().saturating_mul(pivots.len() as u64).saturating_add(
+				T::WeightInfo::interlace().saturating_mul(pieces.saturating_mul(masks.len() as u64)),
+			)
+		})]
+		pub fn split(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			pivots: SplitPivots,
+			masks: SplitMasks,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_split(region_id, Some(who), pivots.into_inner(), masks.into_inner())?;
+			Ok(())
+		}
+
 		/// Assign a Bulk Coretime Region to a task.
 		///
 		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
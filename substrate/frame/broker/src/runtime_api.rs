@@ -17,6 +17,8 @@
 
 //! Runtime API definition for the FRAME Broker pallet.
 
+use crate::OwnedRegion;
+use alloc::vec::Vec;
 use codec::Codec;
 use sp_runtime::DispatchError;
 
@@ -28,4 +30,14 @@ sp_api::decl_runtime_apis! {
 		/// If there is an ongoing sale returns the current price of a core.
 		fn sale_price() -> Result<Balance, DispatchError>;
 	}
+
+	/// API for inspecting the Regions held by an account, e.g. for secondary coretime markets.
+	pub trait BrokerRegionsApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec
+	{
+		/// All Regions owned by `who`, together with the estimated value of their remainder.
+		fn owned_regions(who: AccountId) -> Vec<OwnedRegion<Balance>>;
+	}
 }
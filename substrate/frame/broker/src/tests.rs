@@ -770,6 +770,134 @@ fn interlace_works() {
 	});
 }
 
+#[test]
+fn split_works() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let (begin, core) = (region.begin, region.core);
+		let lower = CoreMask::from_chunk(0, 40);
+		let upper = CoreMask::from_chunk(40, 80);
+		assert_ok!(Broker::split(
+			RuntimeOrigin::signed(1),
+			region,
+			vec![1].try_into().unwrap(),
+			vec![lower].try_into().unwrap(),
+		));
+		let new_region_ids = vec![
+			RegionId { begin, core, mask: lower },
+			RegionId { begin, core, mask: upper },
+			RegionId { begin: begin + 1, core, mask: lower },
+			RegionId { begin: begin + 1, core, mask: upper },
+		];
+		System::assert_last_event(
+			Event::Split { old_region_id: region, new_region_ids: new_region_ids.clone() }.into(),
+		);
+		assert_eq!(Regions::<Test>::get(region), None);
+		for (i, region_id) in new_region_ids.iter().enumerate() {
+			let record = Regions::<Test>::get(region_id).unwrap();
+			assert_eq!(record.owner, Some(1));
+			assert_eq!(record.end, if i < 2 { begin + 1 } else { region.begin + 3 });
+		}
+
+		// A final mask which covers the remainder exactly is accepted.
+		let piece = new_region_ids[3];
+		assert_ok!(Broker::do_split(
+			piece,
+			Some(1),
+			vec![],
+			vec![CoreMask::from_chunk(40, 60), CoreMask::from_chunk(60, 80)],
+		));
+		assert!(Regions::<Test>::get(RegionId { mask: CoreMask::from_chunk(60, 80), ..piece })
+			.is_some());
+	});
+}
+
+#[test]
+fn split_rejects_invalid_pieces() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		assert_noop!(
+			Broker::do_split(region, Some(1), vec![], vec![]),
+			Error::<Test>::NothingToSplit
+		);
+		assert_noop!(
+			Broker::split(
+				RuntimeOrigin::signed(2),
+				region,
+				vec![1].try_into().unwrap(),
+				Default::default(),
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			Broker::split(
+				RuntimeOrigin::signed(1),
+				region,
+				vec![2, 1].try_into().unwrap(),
+				Default::default(),
+			),
+			Error::<Test>::PivotTooEarly
+		);
+		assert_noop!(
+			Broker::split(
+				RuntimeOrigin::signed(1),
+				region,
+				vec![3].try_into().unwrap(),
+				Default::default(),
+			),
+			Error::<Test>::PivotTooLate
+		);
+		assert_noop!(
+			Broker::split(
+				RuntimeOrigin::signed(1),
+				region,
+				Default::default(),
+				vec![CoreMask::from_chunk(0, 40), CoreMask::from_chunk(20, 60)].try_into().unwrap(),
+			),
+			Error::<Test>::ExteriorPivot
+		);
+		assert_noop!(
+			Broker::split(
+				RuntimeOrigin::signed(1),
+				region,
+				Default::default(),
+				vec![CoreMask::complete(), CoreMask::from_chunk(0, 40)].try_into().unwrap(),
+			),
+			Error::<Test>::ExteriorPivot
+		);
+	});
+}
+
+#[test]
+fn owned_regions_reports_remaining_value() {
+	TestExt::new().endow(1, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let sale = SaleInfo::<Test>::get().unwrap();
+		let core_price = sale.sellout_price.unwrap();
+		let (half, _) = Broker::do_interlace(region, None, CoreMask::from_chunk(0, 40)).unwrap();
+
+		assert!(Broker::owned_regions(&2).is_empty());
+		let owned = Broker::owned_regions(&1);
+		assert_eq!(owned.len(), 2);
+		let info = owned.iter().find(|r| r.region_id == half).unwrap();
+		assert_eq!(info.end, region.begin + 3);
+		assert_eq!(info.remaining_value, Perbill::from_percent(50) * core_price);
+
+		// Once a timeslice of the region has elapsed, only the remainder is valued.
+		advance_to(2 * (region.begin as u64 + 1));
+		let sale = SaleInfo::<Test>::get().unwrap();
+		let core_price = sale.sellout_price.unwrap_or(sale.end_price);
+		let info = Broker::owned_regions(&1).into_iter().find(|r| r.region_id == half).unwrap();
+		assert_eq!(info.remaining_value, Perbill::from_rational(2u32, 6) * core_price);
+	});
+}
+
 #[test]
 fn cant_assign_unowned_region() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
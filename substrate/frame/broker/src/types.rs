@@ -90,6 +90,30 @@ pub struct RegionRecord<AccountId, Balance> {
 }
 pub type RegionRecordOf<T> = RegionRecord<<T as SConfig>::AccountId, BalanceOf<T>>;
 
+/// The maximum number of time pivots which may be given to a single `split` call.
+pub const MAX_SPLIT_PIVOTS: u32 = 16;
+
+/// The time pivots of a `split` call: offsets into the Region, in ascending order.
+pub type SplitPivots = BoundedVec<Timeslice, ConstU32<MAX_SPLIT_PIVOTS>>;
+
+/// The interlace masks of a `split` call: mutually disjoint parts of the Region's mask.
+pub type SplitMasks = BoundedVec<CoreMask, ConstU32<{ CORE_MASK_BITS as u32 }>>;
+
+/// A Region owned by some account together with an estimate of its remaining value.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct OwnedRegion<Balance> {
+	/// The identity of the Region.
+	pub region_id: RegionId,
+	/// The end of the Region.
+	pub end: Timeslice,
+	/// The amount paid to Polkadot for this Region, or `None` if renewal is not allowed.
+	pub paid: Option<Balance>,
+	/// The value of the part of the Region which has not yet elapsed, priced pro-rata against
+	/// the most recent price of a full core for a full sale period.
+	pub remaining_value: Balance,
+}
+pub type OwnedRegionOf<T> = OwnedRegion<BalanceOf<T>>;
+
 /// An distinct item which can be scheduled on a Polkadot Core.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct ScheduleItem {
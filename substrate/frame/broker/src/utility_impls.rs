@@ -16,6 +16,7 @@
 // limitations under the License.
 
 use super::*;
+use alloc::vec::Vec;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
@@ -25,8 +26,8 @@ use frame_support::{
 	},
 };
 use sp_arithmetic::{
-	traits::{SaturatedConversion, Saturating},
-	FixedPointNumber, FixedU64,
+	traits::{SaturatedConversion, Saturating, Zero},
+	FixedPointNumber, FixedU64, Perbill,
 };
 use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider};
 
@@ -65,6 +66,32 @@ impl<T: Config> Pallet<T> {
 		T::PriceAdapter::leadin_factor_at(through).saturating_mul_int(sale.end_price)
 	}
 
+	/// All Regions currently owned by `who`, together with an estimate of their remaining value.
+	///
+	/// The value of a Region is its share of a full core for a full sale period, priced at the
+	/// sellout price of the current sale (or its end price if nothing has sold yet), counting
+	/// only the timeslices which have not yet elapsed.
+	pub fn owned_regions(who: &T::AccountId) -> Vec<OwnedRegionOf<T>> {
+		let now = Self::current_timeslice();
+		let region_length = Configuration::<T>::get().map_or(0, |c| c.region_length);
+		let core_price = SaleInfo::<T>::get()
+			.map_or(Zero::zero(), |s| s.sellout_price.unwrap_or(s.end_price));
+		let full_core = (region_length as u64).saturating_mul(CORE_MASK_BITS as u64);
+		Regions::<T>::iter()
+			.filter(|(_, record)| record.owner.as_ref() == Some(who))
+			.map(|(region_id, record)| {
+				let remaining = record.end.saturating_sub(region_id.begin.max(now)) as u64;
+				let parts = remaining.saturating_mul(region_id.mask.count_ones() as u64);
+				let remaining_value = if full_core.is_zero() {
+					Zero::zero()
+				} else {
+					Perbill::from_rational(parts.min(full_core), full_core) * core_price
+				};
+				OwnedRegion { region_id, end: record.end, paid: record.paid, remaining_value }
+			})
+			.collect()
+	}
+
 	pub(crate) fn charge(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
 		let credit = T::Currency::withdraw(&who, amount, Exact, Expendable, Polite)?;
 		T::OnRevenue::on_unbalanced(credit);
@@ -29,6 +29,7 @@
 
 extern crate alloc;
 
+use alloc::{collections::btree_map::BTreeMap, vec::Vec};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -42,16 +43,18 @@ use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, Saturating, StaticLookup, Zero},
 	ArithmeticError, DispatchError, Perbill,
 };
+use sp_staking::{EraIndex, OnStakingUpdate};
 
 mod conviction;
 mod types;
 mod vote;
+pub mod runtime_api;
 pub mod weights;
 
 pub use self::{
 	conviction::Conviction,
 	pallet::*,
-	types::{Delegations, Tally, UnvoteScope},
+	types::{DelegationInfo, Delegations, Tally, UnvoteScope},
 	vote::{AccountVote, Casting, Delegating, Vote, Voting},
 	weights::WeightInfo,
 };
@@ -177,6 +180,9 @@ pub mod pallet {
 		VoteRemoved { who: T::AccountId, vote: AccountVote<BalanceOf<T, I>> },
 		/// The lockup period of a conviction vote expired, and the funds have been unlocked.
 		VoteUnlocked { who: T::AccountId, class: ClassOf<T, I> },
+		/// The balance delegated by an account in a class was reduced to `balance` after it lost
+		/// funds.
+		DelegationReduced { who: T::AccountId, class: ClassOf<T, I>, balance: BalanceOf<T, I> },
 	}
 
 	#[pallet::error]
@@ -498,6 +504,99 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		})
 	}
 
+	/// The delegation state of `who` in every class it has voting records for.
+	pub fn delegations_of(
+		who: &T::AccountId,
+	) -> Vec<(ClassOf<T, I>, DelegationInfo<T::AccountId, BalanceOf<T, I>>)> {
+		VotingFor::<T, I>::iter_prefix(who)
+			.map(|(class, voting)| {
+				let info = Self::delegation_info(who, &class, voting);
+				(class, info)
+			})
+			.collect()
+	}
+
+	/// Resolve the delegation chain and voting power of `who` in `class`, given its `voting`.
+	fn delegation_info(
+		who: &T::AccountId,
+		class: &ClassOf<T, I>,
+		voting: VotingOf<T, I>,
+	) -> DelegationInfo<T::AccountId, BalanceOf<T, I>> {
+		let mut chain = Vec::new();
+		let (delegating, delegations) = match voting {
+			Voting::Delegating(Delegating { balance, target, conviction, delegations, .. }) => {
+				chain.push(target);
+				(conviction.votes(balance), delegations)
+			},
+			Voting::Casting(Casting { delegations, .. }) => (Default::default(), delegations),
+		};
+
+		// Follow the targets until an account that is not delegating, stopping on cycles.
+		let mut target_is_casting = true;
+		while let Some(last) = chain.last() {
+			match VotingFor::<T, I>::get(last, class) {
+				Voting::Delegating(Delegating { target, .. }) => {
+					if chain.len() == 1 {
+						target_is_casting = false;
+					}
+					if &target == who || chain.contains(&target) {
+						break
+					}
+					chain.push(target);
+				},
+				Voting::Casting(_) => break,
+			}
+		}
+
+		let effective = if chain.is_empty() {
+			delegations
+		} else if target_is_casting {
+			delegating
+		} else {
+			Default::default()
+		};
+		DelegationInfo { chain, delegating, delegations, effective }
+	}
+
+	/// Reduce the balance delegated by `who` in every class to at most its total balance, so
+	/// that delegated voting power stays backed by funds the account actually has. Delegations
+	/// left without any balance are removed.
+	pub fn sync_delegations_with_balance(who: &T::AccountId) {
+		let total = T::Currency::total_balance(who);
+		let classes = VotingFor::<T, I>::iter_prefix(who)
+			.filter_map(|(class, voting)| match voting {
+				Voting::Delegating(Delegating { balance, .. }) if balance > total => Some(class),
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		for class in classes {
+			if total.is_zero() {
+				let result = Self::try_undelegate(who.clone(), class.clone());
+				debug_assert!(result.is_ok(), "account is delegating in this class; qed");
+			} else {
+				let (target, conviction, balance) = match VotingFor::<T, I>::get(who, &class) {
+					Voting::Delegating(Delegating { target, conviction, balance, .. }) =>
+						(target, conviction, balance),
+					Voting::Casting(_) => continue,
+				};
+				Self::reduce_upstream_delegation(&target, &class, conviction.votes(balance));
+				Self::increase_upstream_delegation(&target, &class, conviction.votes(total));
+				VotingFor::<T, I>::mutate(who, &class, |voting| {
+					if let Voting::Delegating(Delegating { balance, .. }) = voting {
+						*balance = total;
+					}
+				});
+				Self::deposit_event(Event::<T, I>::DelegationReduced {
+					who: who.clone(),
+					class: class.clone(),
+					balance: total,
+				});
+			}
+			Self::update_lock(&class, who);
+		}
+	}
+
 	/// Return the number of votes for `who`.
 	fn increase_upstream_delegation(
 		who: &T::AccountId,
@@ -694,3 +793,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 }
+
+/// Keeps delegations backed by the remaining balance of slashed stakers.
+///
+/// NOTE: The weight of the adjustment is not accounted for by the caller, but it is bounded by
+/// the number of classes times the `MaxVotes` of the targets.
+impl<T: Config<I>, I: 'static> OnStakingUpdate<T::AccountId, BalanceOf<T, I>> for Pallet<T, I> {
+	fn on_slash(
+		stash: &T::AccountId,
+		_slashed_active: BalanceOf<T, I>,
+		_slashed_unlocking: &BTreeMap<EraIndex, BalanceOf<T, I>>,
+		_slashed_total: BalanceOf<T, I>,
+	) {
+		Self::sync_delegations_with_balance(stash);
+	}
+}
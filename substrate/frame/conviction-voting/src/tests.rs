@@ -911,3 +911,85 @@ fn errors_with_remove_vote_work() {
 		);
 	});
 }
+
+#[test]
+fn delegations_of_resolves_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(2), 0, 3, Conviction::Locked2x, 10));
+
+		// 1 delegates through 2, which delegates itself, so the votes of 1 do not count.
+		assert_eq!(
+			Voting::delegations_of(&1),
+			vec![(
+				0,
+				DelegationInfo {
+					chain: vec![2, 3],
+					delegating: Delegations { votes: 5, capital: 5 },
+					delegations: Default::default(),
+					effective: Default::default(),
+				}
+			)]
+		);
+		assert_eq!(
+			Voting::delegations_of(&2),
+			vec![(
+				0,
+				DelegationInfo {
+					chain: vec![3],
+					delegating: Delegations { votes: 20, capital: 10 },
+					delegations: Delegations { votes: 5, capital: 5 },
+					effective: Delegations { votes: 20, capital: 10 },
+				}
+			)]
+		);
+		assert_eq!(
+			Voting::delegations_of(&3),
+			vec![(
+				0,
+				DelegationInfo {
+					chain: vec![],
+					delegating: Default::default(),
+					delegations: Delegations { votes: 20, capital: 10 },
+					effective: Delegations { votes: 20, capital: 10 },
+				}
+			)]
+		);
+
+		// Cycles end the chain.
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(3), 0, 1, Conviction::Locked1x, 5));
+		assert_eq!(Voting::delegations_of(&1)[0].1.chain, vec![2, 3]);
+	});
+}
+
+#[test]
+fn slash_reduces_delegation() {
+	new_test_ext().execute_with(|| {
+		Polls::set(vec![(0, Ongoing(Tally::new(0), 0))].into_iter().collect());
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(2), 0, aye(10, 0)));
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 10));
+		assert_eq!(tally(0), Tally::from_parts(11, 0, 20));
+
+		// Nothing changes while the delegation is still backed.
+		<Voting as OnStakingUpdate<_, _>>::on_slash(&1, 10, &Default::default(), 0);
+		assert_eq!(tally(0), Tally::from_parts(11, 0, 20));
+
+		let _ = Balances::slash(&1, 4);
+		<Voting as OnStakingUpdate<_, _>>::on_slash(&1, 6, &Default::default(), 4);
+		System::assert_last_event(
+			Event::<Test>::DelegationReduced { who: 1, class: 0, balance: 6 }.into(),
+		);
+		assert_eq!(tally(0), Tally::from_parts(7, 0, 16));
+		assert_eq!(
+			VotingFor::<Test>::get(1, 0),
+			Voting::Delegating(Delegating {
+				balance: 6,
+				target: 2,
+				conviction: Conviction::Locked1x,
+				delegations: Default::default(),
+				prior: Default::default(),
+			})
+		);
+		assert_eq!(Balances::usable_balance(1), 0);
+	});
+}
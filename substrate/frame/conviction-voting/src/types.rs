@@ -17,6 +17,7 @@
 
 //! Miscellaneous additional datatypes.
 
+use alloc::vec::Vec;
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use core::{fmt::Debug, marker::PhantomData};
 use frame_support::{
@@ -257,3 +258,21 @@ pub enum UnvoteScope {
 	/// Permitted to do only the changes that do not need the owner's permission.
 	OnlyExpired,
 }
+
+/// Delegation state of an account in a single class, as returned by the runtime API.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct DelegationInfo<AccountId, Balance> {
+	/// The accounts the voting power of the account is delegated through, starting with its
+	/// direct target and ending with the first account that is not delegating. Empty if the
+	/// account is not delegating. Only the first hop is counted in tallies, as delegations are
+	/// not passed on by an account that is delegating itself.
+	pub chain: Vec<AccountId>,
+	/// The votes and capital the account delegates to its direct target.
+	pub delegating: Delegations<Balance>,
+	/// The votes and capital delegated to the account by others.
+	pub delegations: Delegations<Balance>,
+	/// The votes and capital counted on behalf of the account in this class on top of its own
+	/// votes. For a delegating account, this is `delegating` if its target is not delegating
+	/// itself. For any other account, this is `delegations`.
+	pub effective: Delegations<Balance>,
+}
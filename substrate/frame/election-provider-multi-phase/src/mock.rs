@@ -19,7 +19,8 @@ use super::*;
 use crate::{self as multi_phase, signed::GeometricDepositBase, unsigned::MinerConfig};
 use frame_election_provider_support::{
 	bounds::{DataProviderBounds, ElectionBounds},
-	data_provider, onchain, ElectionDataProvider, NposSolution, SequentialPhragmen,
+	data_provider, onchain, ElectionDataProvider, ExtendedBalance, NposSolution,
+	SequentialPhragmen, TargetCapacity,
 };
 pub use frame_support::derive_impl;
 use frame_support::{
@@ -290,6 +291,7 @@ parameter_types! {
 	pub static OffchainRepeat: BlockNumber = 5;
 	pub static MinerMaxWeight: Weight = BlockWeights::get().max_block;
 	pub static MinerMaxLength: u32 = 256;
+	pub static MinerTargetCapacity: Option<ExtendedBalance> = None;
 	pub static MockWeightInfo: MockedWeightInfo = MockedWeightInfo::Real;
 	pub static MaxElectingVoters: VoterIndex = u32::max_value();
 	pub static MaxElectableTargets: TargetIndex = TargetIndex::max_value();
@@ -356,6 +358,14 @@ impl BenchmarkingConfig for TestBenchmarkingConfig {
 	const MAXIMUM_TARGETS: u32 = 200;
 }
 
+/// The same capacity for all the targets.
+pub struct MockTargetCapacity;
+impl TargetCapacity<AccountId> for MockTargetCapacity {
+	fn capacity(_: &AccountId) -> Option<ExtendedBalance> {
+		MinerTargetCapacity::get()
+	}
+}
+
 impl MinerConfig for Runtime {
 	type AccountId = AccountId;
 	type MaxLength = MinerMaxLength;
@@ -363,6 +373,7 @@ impl MinerConfig for Runtime {
 	type MaxVotesPerVoter = <StakingMock as ElectionDataProvider>::MaxVotesPerVoter;
 	type MaxWinners = MaxWinners;
 	type Solution = TestNposSolution;
+	type TargetCapacity = MockTargetCapacity;
 
 	fn solution_weight(v: u32, t: u32, a: u32, d: u32) -> Weight {
 		match MockWeightInfo::get() {
@@ -20,11 +20,14 @@
 use crate::{
 	helpers, Call, Config, CurrentPhase, DesiredTargets, ElectionCompute, Error, FeasibilityError,
 	Pallet, QueuedSolution, RawSolution, ReadySolution, Round, RoundSnapshot, Snapshot,
-	SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
+	SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, SolutionTargetIndexOf,
+	SolutionVoterIndexOf, Weight,
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec, vec::Vec};
 use codec::Encode;
-use frame_election_provider_support::{NposSolution, NposSolver, PerThing128, VoteWeight};
+use frame_election_provider_support::{
+	ExtendedBalance, NposSolution, NposSolver, PerThing128, TargetCapacity, VoteWeight,
+};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
@@ -117,11 +120,12 @@ impl From<FeasibilityError> for MinerError {
 pub struct TrimmingStatus {
 	weight: usize,
 	length: usize,
+	capacity: usize,
 }
 
 impl TrimmingStatus {
 	pub fn is_trimmed(&self) -> bool {
-		self.weight > 0 || self.length > 0
+		self.weight > 0 || self.length > 0 || self.capacity > 0
 	}
 
 	pub fn trimmed_weight(&self) -> usize {
@@ -131,6 +135,10 @@ impl TrimmingStatus {
 	pub fn trimmed_length(&self) -> usize {
 		self.length
 	}
+
+	pub fn trimmed_capacity(&self) -> usize {
+		self.capacity
+	}
 }
 
 /// Save a given call into OCW storage.
@@ -429,6 +437,10 @@ pub trait MinerConfig {
 	type MaxWeight: Get<Weight>;
 	/// The maximum number of winners that can be elected.
 	type MaxWinners: Get<u32>;
+	/// The capacity of the targets, i.e. the maximum backing they may receive in a solution.
+	///
+	/// Solutions are trimmed to respect this, and solutions that do not are not feasible.
+	type TargetCapacity: TargetCapacity<Self::AccountId>;
 	/// Something that can compute the weight of a solution.
 	///
 	/// This weight estimate is then used to trim the solution, based on [`MinerConfig::MaxWeight`].
@@ -542,6 +554,12 @@ impl<T: MinerConfig> Miner<T> {
 			&mut index_assignments,
 			&encoded_size_of,
 		)?;
+		let capacity_trimmed = Self::trim_assignments_capacity(
+			&mut index_assignments,
+			&voter_at,
+			&target_at,
+			&stake_of,
+		)?;
 
 		// now make solution.
 		let solution = SolutionOf::<T>::try_from(&index_assignments)?;
@@ -549,7 +567,11 @@ impl<T: MinerConfig> Miner<T> {
 		// re-calc score.
 		let score = solution.clone().score(stake_of, voter_at, target_at)?;
 
-		let is_trimmed = TrimmingStatus { weight: weight_trimmed, length: length_trimmed };
+		let is_trimmed = TrimmingStatus {
+			weight: weight_trimmed,
+			length: length_trimmed,
+			capacity: capacity_trimmed,
+		};
 
 		Ok((solution, score, size, is_trimmed))
 	}
@@ -666,6 +688,80 @@ impl<T: MinerConfig> Miner<T> {
 		removing
 	}
 
+	/// Greedily reduce the backing of the targets to fit into their
+	/// [`MinerConfig::TargetCapacity`].
+	///
+	/// The backing of the targets is computed in the same way as in the feasibility check. The
+	/// solver might already respect the capacity of the targets, e.g.
+	/// [`frame_election_provider_support::SequentialPhragmenCapped`], but the conversion of the
+	/// assignments into the accuracy of the solution can still round a backing above its
+	/// capacity. Thus, we reside to stripping away the voters of the targets that are above their
+	/// capacity, the ones with the least stake first.
+	///
+	/// Same as with the other trimming, a winner might be implicitly removed, and the score must be
+	/// computed **after** this step.
+	pub fn trim_assignments_capacity(
+		assignments: &mut Vec<IndexAssignmentOf<T>>,
+		voter_at: impl Fn(SolutionVoterIndexOf<T>) -> Option<T::AccountId>,
+		target_at: impl Fn(SolutionTargetIndexOf<T>) -> Option<T::AccountId>,
+		stake_of: impl Fn(&T::AccountId) -> VoteWeight,
+	) -> Result<usize, MinerError> {
+		let ratio = assignments
+			.iter()
+			.map(|assignment| {
+				let who = voter_at(assignment.who)
+					.ok_or(sp_npos_elections::Error::SolutionInvalidIndex)?;
+				let distribution = assignment
+					.distribution
+					.iter()
+					.map(|(target, p)| Some((target_at(*target)?, *p)))
+					.collect::<Option<Vec<_>>>()
+					.ok_or(sp_npos_elections::Error::SolutionInvalidIndex)?;
+				Ok(sp_npos_elections::Assignment { who, distribution })
+			})
+			.collect::<Result<Vec<_>, sp_npos_elections::Error>>()?;
+		let staked = assignment_ratio_to_staked_normalized(ratio, &stake_of)?;
+
+		let mut backing = BTreeMap::<T::AccountId, ExtendedBalance>::new();
+		for (target, weight) in staked.iter().flat_map(|s| s.distribution.iter()) {
+			let total = backing.entry(target.clone()).or_default();
+			*total = total.saturating_add(*weight);
+		}
+
+		// visit the voters with the least stake first.
+		let mut by_stake = (0..staked.len()).collect::<Vec<_>>();
+		by_stake.sort_by_key(|index| stake_of(&staked[*index].who));
+
+		let mut keep = vec![true; staked.len()];
+		for index in by_stake {
+			let distribution = &staked[index].distribution;
+			let above_capacity = |target: &T::AccountId| {
+				let total = backing.get(target).copied().unwrap_or_default();
+				T::TargetCapacity::capacity(target).map_or(false, |cap| total > cap)
+			};
+			if distribution.iter().any(|(target, _)| above_capacity(target)) {
+				for (target, weight) in distribution {
+					if let Some(total) = backing.get_mut(target) {
+						*total = total.saturating_sub(*weight);
+					}
+				}
+				keep[index] = false;
+			}
+		}
+
+		let removing = keep.iter().filter(|keep| !**keep).count();
+		log_no_system!(
+			debug,
+			"from {} assignments, removing {} for the capacity of the targets",
+			assignments.len(),
+			removing,
+		);
+		let mut keep = keep.into_iter();
+		assignments.retain(|_| keep.next().unwrap_or(true));
+
+		Ok(removing)
+	}
+
 	/// Find the maximum `len` that a solution can have in order to fit into the block weight.
 	///
 	/// This only returns a value between zero and `size.nominators`.
@@ -812,6 +908,10 @@ impl<T: MinerConfig> Miner<T> {
 			.map_err::<FeasibilityError, _>(Into::into)?;
 		let supports = sp_npos_elections::to_supports(&staked_assignments);
 
+		// Check that no target is backed above its capacity.
+		sp_npos_elections::check_capacity(&supports, T::TargetCapacity::capacity)
+			.map_err::<FeasibilityError, _>(Into::into)?;
+
 		// Finally, check that the claimed score was indeed correct.
 		let known_score = supports.evaluate();
 		ensure!(known_score == score, FeasibilityError::InvalidScore);
@@ -1017,9 +1117,9 @@ mod tests {
 	use crate::{
 		mock::{
 			multi_phase_events, roll_to, roll_to_signed, roll_to_unsigned, roll_to_with_ocw,
-			trim_helpers, witness, BlockNumber, ExtBuilder, Extrinsic, MinerMaxWeight, MultiPhase,
-			Runtime, RuntimeCall, RuntimeOrigin, System, TestNposSolution, TrimHelpers,
-			UnsignedPhase,
+			trim_helpers, witness, BlockNumber, ExtBuilder, Extrinsic, MinerMaxWeight,
+			MinerTargetCapacity, MultiPhase, Runtime, RuntimeCall, RuntimeOrigin, System,
+			TestNposSolution, TrimHelpers, UnsignedPhase,
 		},
 		Event, InvalidTransaction, Phase, QueuedSolution, TransactionSource,
 		TransactionValidityError,
@@ -1340,6 +1440,32 @@ mod tests {
 			})
 	}
 
+	#[test]
+	fn miner_trims_capacity() {
+		ExtBuilder::default().build_and_execute(|| {
+			roll_to_unsigned();
+			assert!(CurrentPhase::<Runtime>::get().is_unsigned());
+
+			let (raw, _, t) = MultiPhase::mine_solution().unwrap();
+			assert_eq!(t.trimmed_capacity(), 0);
+
+			// the solution mined without capacity is not feasible anymore.
+			MinerTargetCapacity::set(Some(45));
+			assert_eq!(
+				MultiPhase::feasibility_check(raw, ElectionCompute::Unsigned).unwrap_err(),
+				FeasibilityError::NposElection(sp_npos_elections::Error::TargetCapacityExceeded),
+			);
+
+			// the voters with the least stake are removed until the winners fit.
+			let (raw, witness, t) = MultiPhase::mine_solution().unwrap();
+			assert!(t.trimmed_capacity() > 0);
+			assert_ok!(MultiPhase::submit_unsigned(RuntimeOrigin::none(), Box::new(raw), witness));
+			let supports = QueuedSolution::<Runtime>::get().unwrap().supports;
+			assert_eq!(supports.len(), 2);
+			assert!(supports.iter().all(|(_, support)| support.total <= 45));
+		})
+	}
+
 	#[test]
 	fn miner_will_not_submit_if_not_enough_winners() {
 		let (mut ext, _) = ExtBuilder::default().desired_targets(8).build_offchainify(0);
@@ -210,6 +210,7 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 impl MinerConfig for Runtime {
 	type AccountId = AccountId;
 	type Solution = MockNposSolution;
+	type TargetCapacity = ();
 	type MaxVotesPerVoter =
 	<<Self as pallet_election_provider_multi_phase::Config>::DataProvider as ElectionDataProvider>::MaxVotesPerVoter;
 	type MaxLength = MinerMaxLength;
@@ -236,6 +237,7 @@ impl pallet_bags_list::Config for Runtime {
 	type ScoreProvider = Staking;
 	type BagThresholds = BagThresholds;
 	type Score = VoteWeight;
+	type MaxScoreRecalculations = ConstU32<64>;
 }
 
 pub struct BalanceToU256;
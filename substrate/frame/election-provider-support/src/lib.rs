@@ -505,6 +505,15 @@ pub trait SortedListProvider<AccountId> {
 	/// The current count of ids in the list.
 	fn count() -> u32;
 
+	/// Whether the list is being rebuilt.
+	///
+	/// While it is, only a part of the ids can be reached, so [`Self::iter`] yields nothing and
+	/// [`Self::iter_from`] fails, even though [`Self::count`] still counts all of them. Snapshots
+	/// of the list must not be taken until the rebuild is complete.
+	fn is_rebuilding() -> bool {
+		false
+	}
+
 	/// Return true if the list already contains `id`.
 	fn contains(id: &AccountId) -> bool;
 
@@ -642,6 +651,55 @@ impl<AccountId: IdentifierT, Accuracy: PerThing128, Balancing: Get<Option<Balanc
 	}
 }
 
+/// Something that provides the capacity of the election targets, i.e. the maximum backing they
+/// may receive in a solution.
+pub trait TargetCapacity<AccountId> {
+	/// The capacity of `target`, or `None` if its backing is not limited.
+	fn capacity(target: &AccountId) -> Option<ExtendedBalance>;
+}
+
+impl<AccountId> TargetCapacity<AccountId> for () {
+	fn capacity(_: &AccountId) -> Option<ExtendedBalance> {
+		None
+	}
+}
+
+/// A wrapper for [`sp_npos_elections::seq_phragmen_capped`] that implements [`NposSolver`], with
+/// the capacity of the targets provided by `Capacity`. See the documentation of
+/// [`sp_npos_elections::seq_phragmen_capped`] for more info.
+pub struct SequentialPhragmenCapped<AccountId, Accuracy, Capacity, Balancing = ()>(
+	core::marker::PhantomData<(AccountId, Accuracy, Capacity, Balancing)>,
+);
+
+impl<
+		AccountId: IdentifierT,
+		Accuracy: PerThing128,
+		Capacity: TargetCapacity<AccountId>,
+		Balancing: Get<Option<BalancingConfig>>,
+	> NposSolver for SequentialPhragmenCapped<AccountId, Accuracy, Capacity, Balancing>
+{
+	type AccountId = AccountId;
+	type Accuracy = Accuracy;
+	type Error = sp_npos_elections::Error;
+	fn solve(
+		winners: usize,
+		targets: Vec<Self::AccountId>,
+		voters: Vec<(Self::AccountId, VoteWeight, impl IntoIterator<Item = Self::AccountId>)>,
+	) -> Result<ElectionResult<Self::AccountId, Self::Accuracy>, Self::Error> {
+		sp_npos_elections::seq_phragmen_capped(
+			winners,
+			targets,
+			voters,
+			Capacity::capacity,
+			Balancing::get(),
+		)
+	}
+
+	fn weight<T: WeightInfo>(voters: u32, targets: u32, vote_degree: u32) -> Weight {
+		T::phragmen(voters, targets, vote_degree)
+	}
+}
+
 /// A wrapper for [`sp_npos_elections::phragmms()`] that implements [`NposSolver`]. See the
 /// documentation of [`sp_npos_elections::phragmms()`] for more info.
 pub struct PhragMMS<AccountId, Accuracy, Balancing = ()>(
@@ -158,12 +158,13 @@ use core::marker::PhantomData;
 use frame_support::{
 	defensive_assert,
 	dispatch::{DispatchClass, DispatchInfo, GetDispatchInfo, PostDispatchInfo},
-	migrations::MultiStepMigrator,
+	migrations::{MigratedPallet, MultiStepMigrator, UpgradeDryRunReport},
 	pallet_prelude::InvalidTransaction,
+	storage::{storage_prefix, unhashed},
 	traits::{
 		BeforeAllRuntimeMigrations, EnsureInherentsAreFirst, ExecuteBlock, OffchainWorker,
-		OnFinalize, OnIdle, OnInitialize, OnPoll, OnRuntimeUpgrade, PostInherents,
-		PostTransactions, PreInherents,
+		OnFinalize, OnIdle, OnInitialize, OnPoll, OnRuntimeUpgrade, PalletsInfoAccess,
+		PostInherents, PostTransactions, PreInherents, STORAGE_VERSION_STORAGE_KEY_POSTFIX,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -466,6 +467,23 @@ where
 		Ok(before_all_weight.saturating_add(try_on_runtime_upgrade_weight))
 	}
 
+	/// Execute all Migrations of this runtime including all checks, and report the outcome.
+	///
+	/// The reported weight is zero if any of the checks failed. The state changes are expected to
+	/// be discarded by the caller.
+	pub fn dry_run_runtime_upgrade() -> UpgradeDryRunReport
+	where
+		AllPalletsWithSystem: PalletsInfoAccess,
+	{
+		let before = storage_versions::<AllPalletsWithSystem>();
+		let (weight, checks) = match Self::try_runtime_upgrade(UpgradeCheckSelect::All) {
+			Ok(weight) => (weight, Ok(())),
+			Err(err) => (Weight::zero(), Err(<&'static str>::from(err).into())),
+		};
+
+		upgrade_dry_run_report::<System, AllPalletsWithSystem>(before, weight, Some(checks))
+	}
+
 	/// Logs the result of trying to decode the entire state.
 	fn log_decode_result(
 		res: Result<usize, alloc::vec::Vec<TryDecodeEntireStorageError>>,
@@ -540,6 +558,20 @@ where
 		before_all_weight.saturating_add(runtime_upgrade_weight)
 	}
 
+	/// Execute all `OnRuntimeUpgrade` of this runtime, and report the outcome.
+	///
+	/// The state changes are expected to be discarded by the caller.
+	#[cfg(not(feature = "try-runtime"))]
+	pub fn dry_run_runtime_upgrade() -> UpgradeDryRunReport
+	where
+		AllPalletsWithSystem: PalletsInfoAccess,
+	{
+		let before = storage_versions::<AllPalletsWithSystem>();
+		let weight = Self::execute_on_runtime_upgrade();
+
+		upgrade_dry_run_report::<System, AllPalletsWithSystem>(before, weight, None)
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize_block(
 		header: &frame_system::pallet_prelude::HeaderFor<System>,
@@ -903,3 +935,35 @@ where
 		)
 	}
 }
+
+/// The on-chain storage version of every pallet in `P`.
+fn storage_versions<P: PalletsInfoAccess>() -> alloc::vec::Vec<(&'static str, u16)> {
+	P::infos()
+		.into_iter()
+		.map(|info| {
+			let key = storage_prefix(info.name.as_bytes(), STORAGE_VERSION_STORAGE_KEY_POSTFIX);
+			(info.name, unhashed::get_or_default::<u16>(&key))
+		})
+		.collect()
+}
+
+/// Build the report of a dry-run runtime upgrade from the storage versions `before` it.
+fn upgrade_dry_run_report<System: frame_system::Config, P: PalletsInfoAccess>(
+	before: alloc::vec::Vec<(&'static str, u16)>,
+	weight: Weight,
+	checks: Option<Result<(), alloc::string::String>>,
+) -> UpgradeDryRunReport {
+	let migrated = before
+		.into_iter()
+		.zip(storage_versions::<P>())
+		.filter(|((_, from), (_, to))| from != to)
+		.map(|((name, from), (_, to))| MigratedPallet { name: name.into(), from, to })
+		.collect();
+
+	UpgradeDryRunReport {
+		weight,
+		max_block_weight: <System::BlockWeights as frame_support::traits::Get<_>>::get().max_block,
+		migrated,
+		checks,
+	}
+}
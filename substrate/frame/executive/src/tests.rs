@@ -1069,6 +1069,40 @@ fn all_weights_are_recorded_correctly() {
 	});
 }
 
+#[test]
+#[cfg(not(feature = "try-runtime"))]
+fn dry_run_runtime_upgrade_reports_outcome() {
+	new_test_ext(1).execute_with(|| {
+		let weight = frame_support::hypothetically!(Executive::execute_on_runtime_upgrade());
+
+		let report = Executive::dry_run_runtime_upgrade();
+		assert_eq!(report.weight, weight);
+		assert_eq!(
+			report.max_block_weight,
+			<Runtime as frame_system::Config>::BlockWeights::get().max_block
+		);
+		assert!(report.migrated.is_empty());
+		assert_eq!(report.checks, None);
+	});
+}
+
+#[test]
+fn storage_versions_are_read_from_state() {
+	new_test_ext(1).execute_with(|| {
+		frame_support::traits::StorageVersion::new(2).put::<Custom>();
+		let versions = storage_versions::<AllPalletsWithSystem>();
+		assert!(versions.contains(&("Custom", 2)));
+
+		let before = versions
+			.into_iter()
+			.map(|(name, version)| if name == "Custom" { (name, 1) } else { (name, version) })
+			.collect();
+		let report =
+			upgrade_dry_run_report::<Runtime, AllPalletsWithSystem>(before, Weight::zero(), None);
+		assert_eq!(report.migrated, vec![MigratedPallet { name: "Custom".into(), from: 1, to: 2 }]);
+	});
+}
+
 #[test]
 fn offchain_worker_works_as_expected() {
 	new_test_ext(1).execute_with(|| {
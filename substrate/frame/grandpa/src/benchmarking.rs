@@ -72,6 +72,17 @@ mod benchmarks {
 		assert!(Grandpa::<T>::stalled().is_some());
 	}
 
+	#[benchmark]
+	fn schedule_forced_change() {
+		let delay = 1000u32.into();
+		let best_finalized_block_number = 1u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, delay, best_finalized_block_number);
+
+		assert!(Grandpa::<T>::pending_change().is_some());
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::new_test_ext(vec![(1, 1), (2, 1), (3, 1)]),
@@ -54,4 +54,12 @@ impl crate::WeightInfo for () {
 		Weight::from_parts(3u64 * WEIGHT_REF_TIME_PER_MICROS, 0)
 			.saturating_add(DbWeight::get().writes(1))
 	}
+
+	fn schedule_forced_change() -> Weight {
+		// like `note_stalled`, this is a root call that clears the stalled state, on top of which
+		// it schedules the change itself: reading the authorities, the pending and next forced
+		// change, the set id and the session of the oldest set id, and writing all but the
+		// authorities. copying the authority list is bounded and negligible next to these.
+		Self::note_stalled().saturating_add(DbWeight::get().reads_writes(5, 5))
+	}
 }
@@ -47,8 +47,8 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_consensus_grandpa::{
-	ConsensusLog, EquivocationProof, ScheduledChange, SetId, GRANDPA_ENGINE_ID,
-	RUNTIME_LOG_TARGET as LOG_TARGET,
+	AuthoritySetDiagnostics, ConsensusLog, EquivocationProof, ScheduledChange, SetId,
+	GRANDPA_ENGINE_ID, RUNTIME_LOG_TARGET as LOG_TARGET,
 };
 use sp_runtime::{generic::DigestItem, traits::Zero, DispatchResult};
 use sp_session::{GetSessionNumber, GetValidatorCount};
@@ -263,6 +263,42 @@ pub mod pallet {
 			Self::on_stalled(delay, best_finalized_block_number);
 			Ok(())
 		}
+
+		/// Schedule a forced authority set change right away, instead of at the beginning of the
+		/// next session as with [`Call::note_stalled`].
+		///
+		/// The current authorities are kept and start a new set, enacted `delay` blocks after this
+		/// one. They will start voting on top of `best_finalized_block_number`, see
+		/// [`Call::note_stalled`] on how to choose both. Any stall noted with
+		/// [`Call::note_stalled`] is cleared.
+		///
+		/// Fails if a change is already pending, or if the last forced change was too recent.
+		///
+		/// Only callable by root.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::schedule_forced_change())]
+		pub fn schedule_forced_change(
+			origin: OriginFor<T>,
+			delay: BlockNumberFor<T>,
+			best_finalized_block_number: BlockNumberFor<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::schedule_change(
+				Self::grandpa_authorities(),
+				delay,
+				Some(best_finalized_block_number),
+			)?;
+			<Stalled<T>>::kill();
+
+			// the new set is live during the same session as the current one.
+			let session_index = Self::session_for_set(Self::current_set_id());
+			let current_set_id = Self::increment_set_id();
+			if let Some(session_index) = session_index {
+				SetIdSession::<T>::insert(current_set_id, session_index);
+			}
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -381,6 +417,7 @@ pub mod pallet {
 pub trait WeightInfo {
 	fn report_equivocation(validator_count: u32, max_nominators_per_validator: u32) -> Weight;
 	fn note_stalled() -> Weight;
+	fn schedule_forced_change() -> Weight;
 }
 
 /// Bounded version of `AuthorityList`, `Limit` being the bound
@@ -437,6 +474,23 @@ impl<T: Config> Pallet<T> {
 		Authorities::<T>::get().into_inner()
 	}
 
+	/// Get the state of the authority set handoffs, to diagnose stalled finality.
+	pub fn authority_set_diagnostics() -> AuthoritySetDiagnostics<BlockNumberFor<T>> {
+		AuthoritySetDiagnostics {
+			set_id: Self::current_set_id(),
+			pending_change: <PendingChange<T>>::get().map(|pending| fg_primitives::PendingChange {
+				scheduled_at: pending.scheduled_at,
+				change: ScheduledChange {
+					next_authorities: pending.next_authorities.into_inner(),
+					delay: pending.delay,
+				},
+				forced: pending.forced,
+			}),
+			next_forced: <NextForced<T>>::get(),
+			stalled: <Stalled<T>>::get(),
+		}
+	}
+
 	/// Schedule GRANDPA to pause starting in the given number of blocks.
 	/// Cannot be done when already paused.
 	pub fn schedule_pause(in_blocks: BlockNumberFor<T>) -> DispatchResult {
@@ -550,6 +604,22 @@ impl<T: Config> Pallet<T> {
 		T::EquivocationReportSystem::publish_evidence((equivocation_proof, key_owner_proof)).ok()
 	}
 
+	/// Increment the set id for a newly scheduled change, pruning the oldest set id to session
+	/// index mapping beyond `MaxSetIdSessionEntries`.
+	fn increment_set_id() -> SetId {
+		let current_set_id = CurrentSetId::<T>::mutate(|s| {
+			*s += 1;
+			*s
+		});
+
+		let max_set_id_session_entries = T::MaxSetIdSessionEntries::get().max(1);
+		if current_set_id >= max_set_id_session_entries {
+			SetIdSession::<T>::remove(current_set_id - max_set_id_session_entries);
+		}
+
+		current_set_id
+	}
+
 	fn on_stalled(further_wait: BlockNumberFor<T>, median: BlockNumberFor<T>) {
 		// when we record old authority sets we could try to figure out _who_
 		// failed. until then, we can't meaningfully guard against
@@ -593,17 +663,7 @@ where
 			};
 
 			if res.is_ok() {
-				let current_set_id = CurrentSetId::<T>::mutate(|s| {
-					*s += 1;
-					*s
-				});
-
-				let max_set_id_session_entries = T::MaxSetIdSessionEntries::get().max(1);
-				if current_set_id >= max_set_id_session_entries {
-					SetIdSession::<T>::remove(current_set_id - max_set_id_session_entries);
-				}
-
-				current_set_id
+				Self::increment_set_id()
 			} else {
 				// either the session module signalled that the validators have changed
 				// or the set was stalled. but since we didn't successfully schedule
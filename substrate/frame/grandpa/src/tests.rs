@@ -833,6 +833,54 @@ fn always_schedules_a_change_on_new_session_when_stalled() {
 	});
 }
 
+#[test]
+fn schedule_forced_change_starts_a_new_set_right_away() {
+	new_test_ext(vec![(1, 1), (2, 1), (3, 1)]).execute_with(|| {
+		initialize_block(1, Default::default());
+		let authorities = Grandpa::grandpa_authorities();
+		<Stalled<Test>>::put((10, 1));
+
+		assert_noop!(
+			Grandpa::schedule_forced_change(RuntimeOrigin::signed(1), 5, 0),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Grandpa::schedule_forced_change(RuntimeOrigin::root(), 5, 0));
+
+		// the current authorities start a new set in the current session.
+		assert_eq!(Grandpa::current_set_id(), 1);
+		assert_eq!(Grandpa::session_for_set(1), Grandpa::session_for_set(0));
+		assert_eq!(
+			Grandpa::authority_set_diagnostics(),
+			AuthoritySetDiagnostics {
+				set_id: 1,
+				pending_change: Some(fg_primitives::PendingChange {
+					scheduled_at: 1,
+					change: ScheduledChange { next_authorities: authorities.clone(), delay: 5 },
+					forced: Some(0),
+				}),
+				next_forced: Some(11),
+				stalled: None,
+			}
+		);
+		assert_noop!(
+			Grandpa::schedule_forced_change(RuntimeOrigin::root(), 5, 0),
+			Error::<Test>::ChangePending
+		);
+
+		Grandpa::on_finalize(1);
+		let header = System::finalize();
+		assert_eq!(
+			header.digest,
+			Digest {
+				logs: vec![grandpa_log(ConsensusLog::ForcedChange(
+					0,
+					ScheduledChange { delay: 5, next_authorities: authorities }
+				))],
+			}
+		);
+	});
+}
+
 #[test]
 fn report_equivocation_has_valid_weight() {
 	// the weight depends on the size of the validator set,
@@ -81,7 +81,9 @@
 //! #### For General Users with Sub-Identities
 //! * `set_subs` - Set the sub-accounts of an identity.
 //! * `add_sub` - Add a sub-identity to an identity.
+//! * `add_subs` - Add several sub-identities to an identity at once.
 //! * `remove_sub` - Remove a sub-identity of an identity.
+//! * `remove_subs` - Remove several sub-identities of an identity at once.
 //! * `rename_sub` - Rename a sub-identity of an identity.
 //! * `quit_sub` - Remove a sub-identity of an identity (called by the sub-identity).
 //!
@@ -109,6 +111,7 @@
 mod benchmarking;
 pub mod legacy;
 pub mod migration;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 mod types;
@@ -123,7 +126,9 @@ use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
 	traits::{
-		BalanceStatus, Currency, Defensive, Get, OnUnbalanced, ReservableCurrency, StorageVersion,
+		fungible::{DepositPrice, DepositStore, ReservedDeposit, StorageDeposit},
+		BalanceStatus, Currency, Defensive, Footprint, Get, GetDefault, OnUnbalanced,
+		ReservableCurrency, StorageVersion,
 	},
 	BoundedVec,
 };
@@ -145,6 +150,31 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 type ProviderOf<T> = Provider<BalanceOf<T>>;
 
+/// Where the deposits of the pallet are kept.
+type DepositStoreOf<T> = ReservedDeposit<<T as Config>::Currency>;
+
+/// The deposit for an identity: `BasicDeposit`, plus `ByteDeposit` for every byte of its
+/// information.
+type IdentityDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	DepositStoreOf<T>,
+	DepositPrice<<T as Config>::BasicDeposit, GetDefault, <T as Config>::ByteDeposit>,
+>;
+
+/// The deposit for the sub-accounts of an identity: `SubAccountDeposit` for every sub-account.
+type SubsDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	DepositStoreOf<T>,
+	DepositPrice<GetDefault, <T as Config>::SubAccountDeposit, GetDefault>,
+>;
+
+/// The deposit of a username authority for a username: `UsernameDeposit`.
+type UsernameDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	DepositStoreOf<T>,
+	DepositPrice<<T as Config>::UsernameDeposit, GetDefault, GetDefault>,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -522,11 +552,11 @@ pub mod pallet {
 				},
 			};
 
-			let new_deposit = Self::calculate_identity_deposit(&id.info);
-			let old_deposit = id.deposit;
-			Self::rejig_deposit(&sender, old_deposit, new_deposit)?;
-
-			id.deposit = new_deposit;
+			id.deposit = IdentityDepositOf::<T>::update(
+				&sender,
+				id.deposit,
+				Self::identity_footprint(&id.info),
+			)?;
 			let judgements = id.judgements.len();
 			IdentityOf::<T>::insert(&sender, id);
 			Self::deposit_event(Event::IdentitySet { who: sender });
@@ -565,19 +595,13 @@ pub mod pallet {
 			);
 
 			let (old_deposit, old_ids) = SubsOf::<T>::get(&sender);
-			let new_deposit = Self::subs_deposit(subs.len() as u32);
 
 			let not_other_sub =
 				subs.iter().filter_map(|i| SuperOf::<T>::get(&i.0)).all(|i| i.0 == sender);
 			ensure!(not_other_sub, Error::<T>::AlreadyClaimed);
 
-			if old_deposit < new_deposit {
-				T::Currency::reserve(&sender, new_deposit - old_deposit)?;
-			} else if old_deposit > new_deposit {
-				let err_amount = T::Currency::unreserve(&sender, old_deposit - new_deposit);
-				debug_assert!(err_amount.is_zero());
-			}
-			// do nothing if they're equal.
+			let new_deposit =
+				SubsDepositOf::<T>::update(&sender, old_deposit, Self::items(subs.len()))?;
 
 			for s in old_ids.iter() {
 				SuperOf::<T>::remove(s);
@@ -632,8 +656,7 @@ pub mod pallet {
 				SuperOf::<T>::remove(sub);
 			}
 
-			let err_amount = T::Currency::unreserve(&sender, deposit);
-			debug_assert!(err_amount.is_zero());
+			Self::release_deposit(&sender, deposit);
 
 			Self::deposit_event(Event::IdentityCleared { who: sender, deposit });
 
@@ -975,18 +998,134 @@ pub mod pallet {
 					sub_ids.len() < T::MaxSubAccounts::get() as usize,
 					Error::<T>::TooManySubAccounts
 				);
-				let deposit = T::SubAccountDeposit::get();
-				T::Currency::reserve(&sender, deposit)?;
+				let new_deposit = SubsDepositOf::<T>::update(
+					&sender,
+					*subs_deposit,
+					Self::items(sub_ids.len() + 1),
+				)?;
+				let deposit = new_deposit.saturating_sub(*subs_deposit);
 
 				SuperOf::<T>::insert(&sub, (sender.clone(), data));
 				sub_ids.try_push(sub.clone()).expect("sub ids length checked above; qed");
-				*subs_deposit = subs_deposit.saturating_add(deposit);
+				*subs_deposit = new_deposit;
 
 				Self::deposit_event(Event::SubIdentityAdded { sub, main: sender.clone(), deposit });
 				Ok(())
 			})
 		}
 
+		/// Add the given accounts to the sender's subs, keeping the existing ones.
+		///
+		/// Payment: `SubAccountDeposit` will be reserved from the sender for each added sub. The
+		/// call fails without adding any sub if the sender cannot pay for all of them.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// identity. None of the `subs` may already be a sub identity, nor be given twice, and the
+		/// sender may have at most `MaxSubAccounts` subs afterwards.
+		///
+		/// - `subs`: The accounts to add, each with its name within the context of the sender.
+		///
+		/// Emits `SubIdentityAdded` for each sub-account added.
+		#[pallet::call_index(24)]
+		#[pallet::weight(
+			T::WeightInfo::add_sub(T::MaxSubAccounts::get()).saturating_mul(subs.len() as u64)
+		)]
+		pub fn add_subs(
+			origin: OriginFor<T>,
+			subs: Vec<(AccountIdLookupOf<T>, Data)>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(
+				subs.len() <= T::MaxSubAccounts::get() as usize,
+				Error::<T>::TooManySubAccounts
+			);
+			let subs = subs
+				.into_iter()
+				.map(|(sub, data)| Ok((T::Lookup::lookup(sub)?, data)))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			// Check that none is already claimed as sub-identity, nor given twice.
+			for (i, (sub, _)) in subs.iter().enumerate() {
+				ensure!(
+					!SuperOf::<T>::contains_key(sub) && !subs[..i].iter().any(|(s, _)| s == sub),
+					Error::<T>::AlreadyClaimed
+				);
+			}
+
+			SubsOf::<T>::try_mutate(&sender, |(ref mut subs_deposit, ref mut sub_ids)| {
+				// Ensure there is space and that the deposit is paid.
+				ensure!(
+					sub_ids.len().saturating_add(subs.len()) <= T::MaxSubAccounts::get() as usize,
+					Error::<T>::TooManySubAccounts
+				);
+				*subs_deposit = SubsDepositOf::<T>::update(
+					&sender,
+					*subs_deposit,
+					Self::items(sub_ids.len() + subs.len()),
+				)?;
+
+				let deposit = SubsDepositOf::<T>::price(Self::items(1));
+				for (sub, data) in subs {
+					SuperOf::<T>::insert(&sub, (sender.clone(), data));
+					sub_ids.try_push(sub.clone()).expect("sub ids length checked above; qed");
+
+					Self::deposit_event(Event::SubIdentityAdded {
+						sub,
+						main: sender.clone(),
+						deposit,
+					});
+				}
+				Ok(())
+			})
+		}
+
+		/// Remove the given accounts from the sender's subs.
+		///
+		/// Payment: Balance reserved by a previous `set_subs` call for each sub will be
+		/// repatriated to the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// sub identity of each of `subs`.
+		///
+		/// - `subs`: The sub-accounts to remove.
+		///
+		/// Emits `SubIdentityRemoved` for each sub-account removed.
+		#[pallet::call_index(25)]
+		#[pallet::weight(
+			T::WeightInfo::remove_sub(T::MaxSubAccounts::get()).saturating_mul(subs.len() as u64)
+		)]
+		pub fn remove_subs(
+			origin: OriginFor<T>,
+			subs: Vec<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(
+				subs.len() <= T::MaxSubAccounts::get() as usize,
+				Error::<T>::TooManySubAccounts
+			);
+
+			SubsOf::<T>::try_mutate(&sender, |(ref mut subs_deposit, ref mut sub_ids)| {
+				for sub in subs {
+					let sub = T::Lookup::lookup(sub)?;
+					let (sup, _) = SuperOf::<T>::get(&sub).ok_or(Error::<T>::NotSub)?;
+					ensure!(sup == sender, Error::<T>::NotOwned);
+					SuperOf::<T>::remove(&sub);
+					sub_ids.retain(|x| x != &sub);
+					let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
+					*subs_deposit -= deposit;
+					Self::release_deposit(&sender, deposit);
+					Self::deposit_event(Event::SubIdentityRemoved {
+						sub,
+						main: sender.clone(),
+						deposit,
+					});
+				}
+				Ok::<_, DispatchError>(())
+			})
+		}
+
 		/// Alter the associated name of the given sub-account.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
@@ -1028,8 +1167,7 @@ pub mod pallet {
 				sub_ids.retain(|x| x != &sub);
 				let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
 				*subs_deposit -= deposit;
-				let err_amount = T::Currency::unreserve(&sender, deposit);
-				debug_assert!(err_amount.is_zero());
+				Self::release_deposit(&sender, deposit);
 				Self::deposit_event(Event::SubIdentityRemoved { sub, main: sender, deposit });
 			});
 			Ok(())
@@ -1148,8 +1286,8 @@ pub mod pallet {
 						properties.allocation.saturating_dec();
 						Ok(Provider::new_with_allocation())
 					} else {
-						let deposit = T::UsernameDeposit::get();
-						T::Currency::reserve(&sender, deposit)?;
+						let deposit =
+							UsernameDepositOf::<T>::update(&sender, Zero::zero(), Self::items(1))?;
 						Ok(Provider::new_with_deposit(deposit))
 					}
 				},
@@ -1219,8 +1357,7 @@ pub mod pallet {
 						let authority_account = AuthorityOf::<T>::get(&suffix)
 							.map(|auth_info| auth_info.account_id)
 							.ok_or(Error::<T>::NotUsernameAuthority)?;
-						let err_amount = T::Currency::unreserve(&authority_account, deposit);
-						debug_assert!(err_amount.is_zero());
+						Self::release_deposit(&authority_account, deposit);
 						T::WeightInfo::remove_expired_approval(0)
 					},
 					Provider::Allocation => {
@@ -1315,9 +1452,7 @@ pub mod pallet {
 					if let Some(authority_account) =
 						AuthorityOf::<T>::get(&suffix).map(|auth_info| auth_info.account_id)
 					{
-						let err_amount =
-							T::Currency::unreserve(&authority_account, username_deposit);
-						debug_assert!(err_amount.is_zero());
+						Self::release_deposit(&authority_account, username_deposit);
 					}
 				},
 				Provider::Allocation => {
@@ -1388,24 +1523,44 @@ impl<T: Config> Pallet<T> {
 			.collect()
 	}
 
-	/// Calculate the deposit required for a number of `sub` accounts.
-	fn subs_deposit(subs: u32) -> BalanceOf<T> {
-		T::SubAccountDeposit::get().saturating_mul(BalanceOf::<T>::from(subs))
+	/// Get the identities with a judgement requested from `registrar` that is not yet given,
+	/// together with the fee paid for it.
+	///
+	/// At most `limit` identities are returned, starting after the account `start_after` if
+	/// given, so that the requests can be paged through.
+	pub fn pending_judgements(
+		registrar: RegistrarIndex,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, BalanceOf<T>)> {
+		let iter = match start_after {
+			Some(account) => IdentityOf::<T>::iter_from(IdentityOf::<T>::hashed_key_for(account)),
+			None => IdentityOf::<T>::iter(),
+		};
+		iter.filter_map(|(who, registration)| {
+			registration.judgements.iter().find_map(|(index, judgement)| match judgement {
+				Judgement::FeePaid(fee) if *index == registrar => Some((who.clone(), *fee)),
+				_ => None,
+			})
+		})
+		.take(limit as usize)
+		.collect()
 	}
 
-	/// Take the `current` deposit that `who` is holding, and update it to a `new` one.
-	fn rejig_deposit(
-		who: &T::AccountId,
-		current: BalanceOf<T>,
-		new: BalanceOf<T>,
-	) -> DispatchResult {
-		if new > current {
-			T::Currency::reserve(who, new - current)?;
-		} else if new < current {
-			let err_amount = T::Currency::unreserve(who, current - new);
-			debug_assert!(err_amount.is_zero());
-		}
-		Ok(())
+	/// The footprint of `count` sub-accounts or usernames.
+	fn items(count: usize) -> Footprint {
+		Footprint::from_parts(count, 0)
+	}
+
+	/// The footprint of an identity with information `info`.
+	fn identity_footprint(info: &T::IdentityInformation) -> Footprint {
+		Footprint::from_parts(1, info.encoded_size())
+	}
+
+	/// Release the `deposit` that `who` is holding, for data no longer stored.
+	fn release_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+		let released = DepositStoreOf::<T>::release(who, deposit);
+		debug_assert_eq!(released, Ok(deposit));
 	}
 
 	/// Check if the account has corresponding identity information by the identity field.
@@ -1419,9 +1574,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Calculate the deposit required for an identity.
 	fn calculate_identity_deposit(info: &T::IdentityInformation) -> BalanceOf<T> {
-		let bytes = info.encoded_size() as u32;
-		let byte_deposit = T::ByteDeposit::get().saturating_mul(BalanceOf::<T>::from(bytes));
-		T::BasicDeposit::get().saturating_add(byte_deposit)
+		IdentityDepositOf::<T>::price(Self::identity_footprint(info))
 	}
 
 	/// Validate that a username conforms to allowed characters/format.
@@ -1563,8 +1716,7 @@ impl<T: Config> Pallet<T> {
 
 		// unreserve any deposits
 		let deposit = id.total_deposit().saturating_add(subs_deposit);
-		let err_amount = T::Currency::unreserve(&who, deposit);
-		debug_assert!(err_amount.is_zero());
+		Self::release_deposit(&who, deposit);
 		Ok((registrars, encoded_byte_size, actual_subs))
 	}
 
@@ -1585,17 +1737,12 @@ impl<T: Config> Pallet<T> {
 			&target,
 			|identity_of| -> Result<BalanceOf<T>, DispatchError> {
 				let reg = identity_of.as_mut().ok_or(Error::<T>::NoIdentity)?;
-				// Calculate what deposit should be
-				let encoded_byte_size = reg.info.encoded_size() as u32;
-				let byte_deposit =
-					T::ByteDeposit::get().saturating_mul(BalanceOf::<T>::from(encoded_byte_size));
-				let new_id_deposit = T::BasicDeposit::get().saturating_add(byte_deposit);
-
-				// Update account
-				Self::rejig_deposit(&target, reg.deposit, new_id_deposit)?;
-
-				reg.deposit = new_id_deposit;
-				Ok(new_id_deposit)
+				reg.deposit = IdentityDepositOf::<T>::update(
+					&target,
+					reg.deposit,
+					Self::identity_footprint(&reg.info),
+				)?;
+				Ok(reg.deposit)
 			},
 		)?;
 
@@ -1603,22 +1750,24 @@ impl<T: Config> Pallet<T> {
 			SubsOf::<T>::try_mutate(
 				&target,
 				|(current_subs_deposit, subs_of)| -> Result<BalanceOf<T>, DispatchError> {
-					let new_subs_deposit = Self::subs_deposit(subs_of.len() as u32);
-					Self::rejig_deposit(&target, *current_subs_deposit, new_subs_deposit)?;
-					*current_subs_deposit = new_subs_deposit;
-					Ok(new_subs_deposit)
+					*current_subs_deposit = SubsDepositOf::<T>::update(
+						&target,
+						*current_subs_deposit,
+						Self::items(subs_of.len()),
+					)?;
+					Ok(*current_subs_deposit)
 				},
 			)?
 		} else {
 			// If the item doesn't exist, there is no "old" deposit, and the new one is zero, so no
-			// need to call rejig, it'd just be zero -> zero.
+			// need to update it, it'd just be zero -> zero.
 			Zero::zero()
 		};
 		Ok((new_id_deposit, new_subs_deposit))
 	}
 
 	/// Set an identity with zero deposit. Used for benchmarking and XCM emulator tests that involve
-	/// `rejig_deposit`.
+	/// `poke_deposit`.
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 	pub fn set_identity_no_deposit(
 		who: &T::AccountId,
@@ -1636,7 +1785,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Set subs with zero deposit and default name. Only used for benchmarks that involve
-	/// `rejig_deposit`.
+	/// `poke_deposit`.
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
 	pub fn set_subs_no_deposit(
 		who: &T::AccountId,
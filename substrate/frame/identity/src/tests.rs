@@ -336,6 +336,142 @@ fn resolving_subaccount_ownership_works() {
 	});
 }
 
+#[test]
+fn editing_subaccounts_in_bulk_should_work() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, two, three, _, ten, twenty, _, _] = accounts();
+
+		assert_noop!(
+			Identity::add_subs(RuntimeOrigin::signed(ten.clone()), vec![(one.clone(), data(1))]),
+			Error::<Test>::NoIdentity
+		);
+
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		let id_deposit = id_deposit(&ten_info);
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+
+		// too many sub accounts at once
+		assert_noop!(
+			Identity::add_subs(
+				RuntimeOrigin::signed(ten.clone()),
+				vec![(one.clone(), data(1)), (two.clone(), data(2)), (three.clone(), data(3))]
+			),
+			Error::<Test>::TooManySubAccounts
+		);
+		// the same sub account twice
+		assert_noop!(
+			Identity::add_subs(
+				RuntimeOrigin::signed(ten.clone()),
+				vec![(one.clone(), data(1)), (one.clone(), data(2))]
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		assert_ok!(Identity::add_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(one.clone(), data(1)), (two.clone(), data(2))]
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubIdentityAdded {
+			sub: two.clone(),
+			main: ten.clone(),
+			deposit: sub_deposit,
+		}));
+		assert_eq!(SuperOf::<Test>::get(one.clone()), Some((ten.clone(), data(1))));
+		assert_eq!(SuperOf::<Test>::get(two.clone()), Some((ten.clone(), data(2))));
+		assert_eq!(SubsOf::<Test>::get(ten.clone()).0, 2 * sub_deposit);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
+
+		// no more space left
+		assert_noop!(
+			Identity::add_subs(RuntimeOrigin::signed(ten.clone()), vec![(three.clone(), data(3))]),
+			Error::<Test>::TooManySubAccounts
+		);
+
+		// can't remove a sub account of someone else
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(infoof_twenty())
+		));
+		assert_ok!(Identity::add_subs(
+			RuntimeOrigin::signed(twenty.clone()),
+			vec![(three.clone(), data(3))]
+		));
+		assert_noop!(
+			Identity::remove_subs(RuntimeOrigin::signed(ten.clone()), vec![one.clone(), three]),
+			Error::<Test>::NotOwned
+		);
+		// nor the same one twice
+		assert_noop!(
+			Identity::remove_subs(
+				RuntimeOrigin::signed(ten.clone()),
+				vec![one.clone(), one.clone()]
+			),
+			Error::<Test>::NotSub
+		);
+
+		assert_ok!(Identity::remove_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![one.clone(), two.clone()]
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubIdentityRemoved {
+			sub: two.clone(),
+			main: ten.clone(),
+			deposit: sub_deposit,
+		}));
+		assert_eq!(SuperOf::<Test>::get(one), None);
+		assert_eq!(SuperOf::<Test>::get(two), None);
+		assert_eq!(SubsOf::<Test>::get(ten.clone()), (0, Default::default()));
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit);
+	});
+}
+
+#[test]
+fn pending_judgements_are_paged() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, four, ten, twenty, thirty, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), four.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(four), 1, 20));
+		for who in [&ten, &twenty, &thirty] {
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(infoof_ten())
+			));
+		}
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(twenty.clone()), 0, 10));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(thirty.clone()), 1, 20));
+
+		assert_eq!(Identity::pending_judgements(1, None, 10), vec![(thirty, 20)]);
+
+		let first = Identity::pending_judgements(0, None, 1);
+		assert_eq!(first.len(), 1);
+		let rest = Identity::pending_judgements(0, Some(first[0].0.clone()), 10);
+		assert_eq!(rest.len(), 1);
+		let mut all = [first, rest].concat();
+		all.sort();
+		let mut expected = vec![(ten.clone(), 10), (twenty.clone(), 10)];
+		expected.sort();
+		assert_eq!(all, expected);
+
+		// Once given, the judgement is no longer pending.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten,
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&infoof_ten())
+		));
+		assert_eq!(Identity::pending_judgements(0, None, 10), vec![(twenty, 10)]);
+	});
+}
+
 #[test]
 fn trailing_zeros_decodes_into_default_data() {
 	let encoded = Data::Raw(b"Hello".to_vec().try_into().unwrap()).encode();
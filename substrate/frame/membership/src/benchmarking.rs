@@ -174,5 +174,27 @@ benchmarks_instance_pallet! {
 		#[cfg(test)] crate::mock::clean();
 	}
 
+	// the case of a member whose previous term has to be replaced.
+	set_term {
+		let m in 1 .. T::MaxMembers::get();
+		let members = (0..m).map(|i| account("member", i, SEED)).collect::<Vec<T::AccountId>>();
+		let who = members.last().cloned().unwrap();
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		set_members::<T, I>(members, None);
+		let origin = T::AddOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		assert_ok!(Membership::<T, I>::set_term(
+			origin.clone(),
+			who_lookup.clone(),
+			Some(now.saturating_add(10u32.into())),
+		));
+		let term_end = Some(now.saturating_add(20u32.into()));
+	}: {
+		assert_ok!(Membership::<T, I>::set_term(origin, who_lookup, term_end));
+	} verify {
+		assert_eq!(TermEnd::<T, I>::get(&who), term_end);
+		#[cfg(test)] crate::mock::clean();
+	}
+
 	impl_benchmark_test_suite!(Membership, crate::mock::new_bench_ext(), crate::mock::Test);
 }
@@ -19,6 +19,11 @@
 //!
 //! Allows control of membership of a set of `AccountId`s, useful for managing membership of a
 //! collective. A prime member may be set
+//!
+//! Members may serve limited terms. New members are given a term of [`Config::TermDuration`]
+//! blocks, which can be changed for each member with [`Pallet::set_term`]. When the term of a
+//! member ends, it is removed from the set in `on_initialize`, unless [`Config::ReElection`]
+//! re-elects it for another term.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -27,10 +32,17 @@ extern crate alloc;
 
 use alloc::vec::Vec;
 use frame_support::{
-	traits::{ChangeMembers, Contains, ContainsLengthBound, Get, InitializeMembers, SortedMembers},
+	traits::{
+		ChangeMembers, Contains, ContainsLengthBound, Defensive, Get, InitializeMembers,
+		SortedMembers,
+	},
 	BoundedVec,
 };
-use sp_runtime::traits::{StaticLookup, UniqueSaturatedInto};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{
+	traits::{Saturating, StaticLookup, UniqueSaturatedInto, Zero},
+	DispatchError,
+};
 
 pub mod migrations;
 pub mod weights;
@@ -51,6 +63,18 @@ const LOG_TARGET: &str = "runtime::membership";
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// Decides whether a member whose term ended is re-elected for another term.
+pub trait ReElection<AccountId> {
+	/// Whether `who`, whose term just ended, serves another term instead of being removed.
+	fn re_elect(who: &AccountId) -> bool;
+}
+
+impl<AccountId> ReElection<AccountId> for () {
+	fn re_elect(_who: &AccountId) -> bool {
+		false
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -100,6 +124,15 @@ pub mod pallet {
 		/// This is enforced in the code; the membership size can not exceed this limit.
 		type MaxMembers: Get<u32>;
 
+		/// The length of the term of new members, in blocks, or `None` for unlimited terms.
+		///
+		/// Must not be `Some(0)`.
+		type TermDuration: Get<Option<BlockNumberFor<Self>>>;
+
+		/// Decides whether members are re-elected when their term ends. A re-elected member serves
+		/// another term of `TermDuration` blocks.
+		type ReElection: ReElection<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -113,6 +146,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The block at which the term of a member ends. Members without an entry serve an unlimited
+	/// term.
+	#[pallet::storage]
+	pub type TermEnd<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
+	/// The members whose term ends at a given block.
+	#[pallet::storage]
+	pub type TermsEndingAt<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -135,6 +186,9 @@ pub mod pallet {
 			let mut members = self.members.clone();
 			members.sort();
 			T::MembershipInitialized::initialize_members(&members);
+			for who in &members {
+				Pallet::<T, I>::set_term_end(who, Pallet::<T, I>::new_term_end());
+			}
 			Members::<T, I>::put(members);
 		}
 	}
@@ -152,6 +206,12 @@ pub mod pallet {
 		MembersReset,
 		/// One of the members' keys changed.
 		KeyChanged,
+		/// The term of a member was changed. It ends at `term_end`, or never if `None`.
+		TermSet { who: T::AccountId, term_end: Option<BlockNumberFor<T>> },
+		/// The term of a member ended, and it was removed.
+		TermEnded { who: T::AccountId },
+		/// The term of a member ended, and it was re-elected for another term.
+		ReElected { who: T::AccountId, term_end: Option<BlockNumberFor<T>> },
 		/// Phantom member, never used.
 		Dummy { _phantom_data: PhantomData<(T::AccountId, <T as Config<I>>::RuntimeEvent)> },
 	}
@@ -164,6 +224,42 @@ pub mod pallet {
 		NotMember,
 		/// Too many members.
 		TooManyMembers,
+		/// The end of the term is not in the future.
+		TermEndInPast,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn integrity_test() {
+			assert!(
+				T::TermDuration::get().map_or(true, |duration| !duration.is_zero()),
+				"`TermDuration` must not be `Some(0)`, use `None` for unlimited terms."
+			);
+		}
+
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let ending = TermsEndingAt::<T, I>::drain_prefix(now)
+				.map(|(who, ())| who)
+				.collect::<Vec<_>>();
+			let mut weight = T::DbWeight::get().reads(1);
+
+			for who in ending {
+				TermEnd::<T, I>::remove(&who);
+				if T::ReElection::re_elect(&who) {
+					let term_end = Self::new_term_end();
+					Self::set_term_end(&who, term_end);
+					Self::deposit_event(Event::ReElected { who, term_end });
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+				} else {
+					let _ = Self::do_remove_member(&who)
+						.defensive_proof("only members have a term; qed");
+					Self::deposit_event(Event::TermEnded { who });
+					weight.saturating_accrue(T::WeightInfo::remove_member(T::MaxMembers::get()));
+				}
+			}
+
+			weight
+		}
 	}
 
 	#[pallet::call]
@@ -188,6 +284,7 @@ pub mod pallet {
 				.map_err(|_| Error::<T, I>::TooManyMembers)?;
 
 			Members::<T, I>::put(&members);
+			Self::set_term_end(&who, Self::new_term_end());
 
 			T::MembershipChanged::change_members_sorted(&[who], &[], &members[..]);
 
@@ -208,15 +305,7 @@ pub mod pallet {
 			T::RemoveOrigin::ensure_origin(origin)?;
 			let who = T::Lookup::lookup(who)?;
 
-			let mut members = Members::<T, I>::get();
-			let init_length = members.len();
-			let location = members.binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
-			members.remove(location);
-
-			Members::<T, I>::put(&members);
-
-			T::MembershipChanged::change_members_sorted(&[], &[who], &members[..]);
-			Self::rejig_prime(&members);
+			let init_length = Self::do_remove_member(&who)?;
 
 			Self::deposit_event(Event::MemberRemoved);
 			Ok(Some(T::WeightInfo::remove_member(init_length as u32)).into())
@@ -249,6 +338,8 @@ pub mod pallet {
 			members.sort();
 
 			Members::<T, I>::put(&members);
+			Self::set_term_end(&remove, None);
+			Self::set_term_end(&add, Self::new_term_end());
 
 			T::MembershipChanged::change_members_sorted(&[add], &[remove], &members[..]);
 			Self::rejig_prime(&members);
@@ -270,6 +361,12 @@ pub mod pallet {
 				BoundedVec::try_from(members).map_err(|_| Error::<T, I>::TooManyMembers)?;
 			members.sort();
 			Members::<T, I>::mutate(|m| {
+				for who in m.iter().filter(|who| members.binary_search(who).is_err()) {
+					Self::set_term_end(who, None);
+				}
+				for who in members.iter().filter(|who| m.binary_search(who).is_err()) {
+					Self::set_term_end(who, Self::new_term_end());
+				}
 				T::MembershipChanged::set_members_sorted(&members[..], m);
 				Self::rejig_prime(&members);
 				*m = members;
@@ -305,6 +402,9 @@ pub mod pallet {
 			members.sort();
 
 			Members::<T, I>::put(&members);
+			let term_end = TermEnd::<T, I>::get(&remove);
+			Self::set_term_end(&remove, None);
+			Self::set_term_end(&new, term_end);
 
 			T::MembershipChanged::change_members_sorted(
 				&[new.clone()],
@@ -350,6 +450,33 @@ pub mod pallet {
 			T::MembershipChanged::set_prime(None);
 			Ok(())
 		}
+
+		/// Set the end of the term of member `who` to `term_end`, or make it unlimited with
+		/// `None`.
+		///
+		/// May only be called from `T::AddOrigin`, as it extends membership.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::set_term(T::MaxMembers::get()))]
+		pub fn set_term(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			term_end: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::AddOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let members = Members::<T, I>::get();
+			members.binary_search(&who).ok().ok_or(Error::<T, I>::NotMember)?;
+			if let Some(term_end) = term_end {
+				ensure!(
+					term_end > frame_system::Pallet::<T>::block_number(),
+					Error::<T, I>::TermEndInPast
+				);
+			}
+
+			Self::set_term_end(&who, term_end);
+			Self::deposit_event(Event::TermSet { who, term_end });
+			Ok(Some(T::WeightInfo::set_term(members.len() as u32)).into())
+		}
 	}
 }
 
@@ -364,6 +491,39 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Prime::<T, I>::get()
 	}
 
+	/// The end of the term of a member elected now.
+	fn new_term_end() -> Option<BlockNumberFor<T>> {
+		T::TermDuration::get()
+			.map(|duration| frame_system::Pallet::<T>::block_number().saturating_add(duration))
+	}
+
+	/// Set the end of the term of `who`, replacing the previous one.
+	fn set_term_end(who: &T::AccountId, term_end: Option<BlockNumberFor<T>>) {
+		if let Some(previous) = TermEnd::<T, I>::take(who) {
+			TermsEndingAt::<T, I>::remove(previous, who);
+		}
+		if let Some(term_end) = term_end {
+			TermEnd::<T, I>::insert(who, term_end);
+			TermsEndingAt::<T, I>::insert(term_end, who, ());
+		}
+	}
+
+	/// Remove `who` from the set, returning the number of members before.
+	fn do_remove_member(who: &T::AccountId) -> Result<usize, DispatchError> {
+		let mut members = Members::<T, I>::get();
+		let init_length = members.len();
+		let location = members.binary_search(who).ok().ok_or(Error::<T, I>::NotMember)?;
+		members.remove(location);
+
+		Members::<T, I>::put(&members);
+		Self::set_term_end(who, None);
+
+		T::MembershipChanged::change_members_sorted(&[], &[who.clone()], &members[..]);
+		Self::rejig_prime(&members);
+
+		Ok(init_length)
+	}
+
 	fn rejig_prime(members: &[T::AccountId]) {
 		if let Some(prime) = Prime::<T, I>::get() {
 			match members.binary_search(&prime) {
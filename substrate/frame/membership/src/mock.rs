@@ -38,6 +38,8 @@ frame_support::construct_runtime!(
 parameter_types! {
 	pub static Members: Vec<u64> = vec![];
 	pub static Prime: Option<u64> = None;
+	pub static TermDuration: Option<u64> = None;
+	pub static ReElected: Vec<u64> = vec![];
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -80,6 +82,13 @@ impl InitializeMembers<u64> for TestChangeMembers {
 	}
 }
 
+pub struct TestReElection;
+impl ReElection<u64> for TestReElection {
+	fn re_elect(who: &u64) -> bool {
+		ReElected::get().contains(who)
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AddOrigin = EnsureSignedBy<One, u64>;
@@ -90,6 +99,8 @@ impl Config for Test {
 	type MembershipInitialized = TestChangeMembers;
 	type MembershipChanged = TestChangeMembers;
 	type MaxMembers = ConstU32<10>;
+	type TermDuration = TermDuration;
+	type ReElection = TestReElection;
 	type WeightInfo = ();
 }
 
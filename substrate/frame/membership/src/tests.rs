@@ -22,7 +22,10 @@ use crate::{mock::*, *};
 
 use sp_runtime::{bounded_vec, traits::BadOrigin, BuildStorage};
 
-use frame_support::{assert_noop, assert_ok, assert_storage_noop, traits::StorageVersion};
+use frame_support::{
+	assert_noop, assert_ok, assert_storage_noop,
+	traits::{Hooks, StorageVersion},
+};
 
 #[test]
 fn query_membership_works() {
@@ -184,6 +187,79 @@ fn reset_members_works() {
 	});
 }
 
+#[test]
+#[should_panic(expected = "`TermDuration` must not be `Some(0)`")]
+fn zero_term_duration_is_rejected() {
+	TermDuration::set(Some(0));
+	<Membership as Hooks<u64>>::integrity_test();
+}
+
+#[test]
+fn set_term_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TermDuration::set(Some(10));
+
+		// genesis members serve unlimited terms, new members serve `TermDuration`.
+		assert_eq!(TermEnd::<Test>::get(10), None);
+		assert_ok!(Membership::add_member(RuntimeOrigin::signed(1), 15));
+		assert_eq!(TermEnd::<Test>::get(15), Some(11));
+
+		assert_noop!(Membership::set_term(RuntimeOrigin::signed(5), 20, Some(11)), BadOrigin);
+		assert_noop!(
+			Membership::set_term(RuntimeOrigin::signed(1), 25, Some(11)),
+			Error::<Test, _>::NotMember
+		);
+		assert_noop!(
+			Membership::set_term(RuntimeOrigin::signed(1), 20, Some(1)),
+			Error::<Test, _>::TermEndInPast
+		);
+		assert_ok!(Membership::set_term(RuntimeOrigin::signed(1), 20, Some(5)));
+		assert_ok!(Membership::set_term(RuntimeOrigin::signed(1), 20, None));
+		assert_eq!(TermEnd::<Test>::get(20), None);
+		assert_eq!(TermsEndingAt::<Test>::iter_prefix(5).count(), 0);
+		System::assert_last_event(crate::Event::TermSet { who: 20, term_end: None }.into());
+
+		// the term is passed on to the new key, and dropped when swapped out.
+		assert_ok!(Membership::set_term(RuntimeOrigin::signed(1), 30, Some(5)));
+		assert_ok!(Membership::change_key(RuntimeOrigin::signed(30), 40));
+		assert_eq!((TermEnd::<Test>::get(30), TermEnd::<Test>::get(40)), (None, Some(5)));
+		assert_ok!(Membership::swap_member(RuntimeOrigin::signed(3), 40, 30));
+		assert_eq!((TermEnd::<Test>::get(40), TermEnd::<Test>::get(30)), (None, Some(11)));
+	});
+}
+
+#[test]
+fn terms_end_unless_re_elected() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		TermDuration::set(Some(10));
+		ReElected::set(vec![20]);
+
+		assert_ok!(Membership::add_member(RuntimeOrigin::signed(1), 15));
+		assert_ok!(Membership::set_term(RuntimeOrigin::signed(1), 20, Some(11)));
+		assert_ok!(Membership::set_prime(RuntimeOrigin::signed(5), 15));
+
+		System::set_block_number(11);
+		Membership::on_initialize(11);
+
+		// 15 is removed, along with its prime membership, and 20 serves another term.
+		assert_eq!(crate::Members::<Test>::get(), vec![10, 20, 30]);
+		assert_eq!(MEMBERS.with(|m| m.borrow().clone()), crate::Members::<Test>::get().to_vec());
+		assert_eq!(crate::Prime::<Test>::get(), None);
+		assert_eq!(TermEnd::<Test>::get(15), None);
+		assert_eq!(TermEnd::<Test>::get(20), Some(21));
+		System::assert_has_event(crate::Event::TermEnded { who: 15 }.into());
+		System::assert_has_event(crate::Event::ReElected { who: 20, term_end: Some(21) }.into());
+
+		// 20 is removed at the end of its new term, members without a term stay.
+		ReElected::set(vec![]);
+		System::set_block_number(21);
+		Membership::on_initialize(21);
+		assert_eq!(crate::Members::<Test>::get(), vec![10, 30]);
+	});
+}
+
 #[test]
 #[should_panic(expected = "Members cannot contain duplicate accounts.")]
 fn genesis_build_panics_with_duplicate_members() {
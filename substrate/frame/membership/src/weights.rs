@@ -58,6 +58,7 @@ pub trait WeightInfo {
 	fn change_key(m: u32, ) -> Weight;
 	fn set_prime(m: u32, ) -> Weight;
 	fn clear_prime() -> Weight;
+	fn set_term(m: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_membership` using the Substrate node and recommended hardware.
@@ -207,6 +208,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(2_540_000, 0)
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TechnicalMembership::Members` (r:1 w:0)
+	/// Proof: `TechnicalMembership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalMembership::TermEnd` (r:1 w:1)
+	/// Proof: `TechnicalMembership::TermEnd` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalMembership::TermsEndingAt` (r:0 w:2)
+	/// Proof: `TechnicalMembership::TermsEndingAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 100]`.
+	fn set_term(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104 + m * (32 ±0)`
+		//  Estimated: `4687 + m * (32 ±0)`
+		// Minimum execution time: 10_421_000 picoseconds.
+		Weight::from_parts(10_912_337, 4687)
+			// Standard Error: 231
+			.saturating_add(Weight::from_parts(9_538, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -355,4 +375,23 @@ impl WeightInfo for () {
 		Weight::from_parts(2_540_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `TechnicalMembership::Members` (r:1 w:0)
+	/// Proof: `TechnicalMembership::Members` (`max_values`: Some(1), `max_size`: Some(3202), added: 3697, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalMembership::TermEnd` (r:1 w:1)
+	/// Proof: `TechnicalMembership::TermEnd` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `TechnicalMembership::TermsEndingAt` (r:0 w:2)
+	/// Proof: `TechnicalMembership::TermsEndingAt` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 100]`.
+	fn set_term(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104 + m * (32 ±0)`
+		//  Estimated: `4687 + m * (32 ±0)`
+		// Minimum execution time: 10_421_000 picoseconds.
+		Weight::from_parts(10_912_337, 4687)
+			// Standard Error: 231
+			.saturating_add(Weight::from_parts(9_538, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(m.into()))
+	}
 }
@@ -192,6 +192,10 @@
 //! number of queues is constant. Creating a new queue must therefore be, possibly economically,
 //! expensive. Currently this is archived by having one queue per para-chain/thread, which keeps the
 //! number of queues within `O(n)` and should be "good enough".
+//!
+//! A runtime can further restrict how much weight a single queue may consume every time the queues
+//! are serviced through [`Config::QueueServiceQuota`], and have some queues serviced before all
+//! others through [`Config::QueuePriority`].
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -225,7 +229,7 @@ use sp_arithmetic::traits::{BaseArithmetic, Unsigned};
 use sp_core::{defer, H256};
 use sp_runtime::{
 	traits::{One, Zero},
-	SaturatedConversion, Saturating, TransactionOutcome,
+	Perbill, SaturatedConversion, Saturating, TransactionOutcome,
 };
 use sp_weights::WeightMeter;
 pub use weights::WeightInfo;
@@ -462,6 +466,44 @@ impl<Id> OnQueueChanged<Id> for () {
 	fn on_queue_changed(_: Id, _: QueueFootprint) {}
 }
 
+/// Limits the weight that a single queue may consume whenever the queues are serviced.
+pub trait QueueServiceQuota<Id> {
+	/// The maximal weight that the queue `id` may consume out of the `limit` that is available
+	/// for servicing all queues.
+	///
+	/// Returning `None` means that the queue is only limited by `limit` itself.
+	fn max_service_weight(id: &Id, limit: Weight) -> Option<Weight>;
+}
+
+impl<Id> QueueServiceQuota<Id> for () {
+	fn max_service_weight(_: &Id, _: Weight) -> Option<Weight> {
+		None
+	}
+}
+
+/// Allows every queue to consume at most the same fraction `Ratio` of the service weight.
+pub struct UniformServiceQuota<Ratio>(core::marker::PhantomData<Ratio>);
+impl<Id, Ratio: Get<Perbill>> QueueServiceQuota<Id> for UniformServiceQuota<Ratio> {
+	fn max_service_weight(_: &Id, limit: Weight) -> Option<Weight> {
+		Some(Ratio::get() * limit)
+	}
+}
+
+/// Classifies queues which need to be serviced before all others.
+pub trait QueuePriority<Id> {
+	/// The queues that are serviced before the `ReadyRing` is rotated, in order of decreasing
+	/// priority.
+	///
+	/// This is queried once every time the queues are serviced and should therefore be short.
+	fn priority_queues() -> Vec<Id>;
+}
+
+impl<Id> QueuePriority<Id> for () {
+	fn priority_queues() -> Vec<Id> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -513,6 +555,22 @@ pub mod pallet {
 		/// it that happen *within* the servicing will not be reflected.
 		type QueuePausedQuery: QueuePausedQuery<<Self::MessageProcessor as ProcessMessage>::Origin>;
 
+		/// Queried by the pallet to limit how much weight a single queue may consume every time
+		/// the queues are serviced.
+		///
+		/// This prevents one busy queue from starving all other queues within a single block. A
+		/// queue whose quota is too low to process even a single message will never make progress.
+		/// Use `()` to not impose any per-queue limit.
+		type QueueServiceQuota: QueueServiceQuota<
+			<Self::MessageProcessor as ProcessMessage>::Origin,
+		>;
+
+		/// Queried by the pallet to find the queues which are serviced before all other queues.
+		///
+		/// Priority queues are still subject to [`Self::QueueServiceQuota`] and
+		/// [`Self::QueuePausedQuery`]. Use `()` to service all queues in round-robin order.
+		type QueuePriority: QueuePriority<<Self::MessageProcessor as ProcessMessage>::Origin>;
+
 		/// The size of the page; this implies the maximum message size which can be sent.
 		///
 		/// A good value depends on the expected message sizes, their weights, the weight that is
@@ -1204,6 +1262,61 @@ impl<T: Config> Pallet<T> {
 		(total_processed > 0, next_ready)
 	}
 
+	/// Same as [`Self::service_queue`] but limits the weight that `origin` may consume in total to
+	/// its [`Config::QueueServiceQuota`].
+	///
+	/// `quota_used` tracks the weight that each queue already consumed within the current call to
+	/// `service_queues`.
+	fn service_queue_within_quota(
+		origin: MessageOriginOf<T>,
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		quota_used: &mut Vec<(MessageOriginOf<T>, Weight)>,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		let Some(quota) = T::QueueServiceQuota::max_service_weight(&origin, weight.limit()) else {
+			return Self::service_queue(origin, weight, overweight_limit)
+		};
+		let used = quota_used.iter().find(|(o, _)| o == &origin).map(|(_, w)| *w);
+		let available = quota.saturating_sub(used.unwrap_or_default()).min(weight.remaining());
+
+		let base_weight =
+			T::WeightInfo::service_queue_base().saturating_add(T::WeightInfo::ready_ring_unknit());
+		if !available.all_gte(base_weight) {
+			// The quota is exhausted; skip the queue but keep the ring rotating.
+			if weight.try_consume(T::DbWeight::get().reads(1)).is_err() {
+				return (false, None)
+			}
+			let next_ready = BookStateFor::<T>::get(&origin).ready_neighbours.map(|n| n.next);
+			return (false, next_ready)
+		}
+
+		let mut queue_weight = WeightMeter::with_limit(available);
+		let result = Self::service_queue(origin.clone(), &mut queue_weight, overweight_limit);
+		weight.consume(queue_weight.consumed());
+		match quota_used.iter_mut().find(|(o, _)| o == &origin) {
+			Some((_, w)) => w.saturating_accrue(queue_weight.consumed()),
+			None => quota_used.push((origin, queue_weight.consumed())),
+		}
+		result
+	}
+
+	/// Service all ready queues that are returned by [`Config::QueuePriority`] in order.
+	fn service_priority_queues(
+		weight: &mut WeightMeter,
+		overweight_limit: Weight,
+		quota_used: &mut Vec<(MessageOriginOf<T>, Weight)>,
+	) {
+		for origin in T::QueuePriority::priority_queues() {
+			if weight.try_consume(T::DbWeight::get().reads(1)).is_err() {
+				return
+			}
+			if BookStateFor::<T>::get(&origin).ready_neighbours.is_none() {
+				continue
+			}
+			Self::service_queue_within_quota(origin, weight, overweight_limit, quota_used);
+		}
+	}
+
 	/// Service as many messages of a page as possible.
 	///
 	/// Returns how many messages were processed and the page's status.
@@ -1579,6 +1692,10 @@ impl<T: Config> Pallet<T> {
 		});
 
 		match with_service_mutex(|| {
+			// The weight that each queue with a quota consumed so far.
+			let mut quota_used = Vec::new();
+			Self::service_priority_queues(&mut weight, overweight_limit, &mut quota_used);
+
 			let mut next = match Self::bump_service_head(&mut weight) {
 				Some(h) => h,
 				None => return weight.consumed(),
@@ -1589,8 +1706,12 @@ impl<T: Config> Pallet<T> {
 			let mut last_no_progress = None;
 
 			loop {
-				let (progressed, n) =
-					Self::service_queue(next.clone(), &mut weight, overweight_limit);
+				let (progressed, n) = Self::service_queue_within_quota(
+					next.clone(),
+					&mut weight,
+					overweight_limit,
+					&mut quota_used,
+				);
 				next = match n {
 					Some(n) =>
 						if !progressed {
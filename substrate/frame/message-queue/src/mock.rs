@@ -53,6 +53,8 @@ impl Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = RecordingQueueChangeHandler;
 	type QueuePausedQuery = MockedQueuePauser;
+	type QueueServiceQuota = MockedQueueServiceQuota;
+	type QueuePriority = MockedQueuePriority;
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
@@ -295,6 +297,25 @@ impl QueuePausedQuery<MessageOrigin> for MockedQueuePauser {
 	}
 }
 
+parameter_types! {
+	pub static QueueQuotas: Vec<(MessageOrigin, Weight)> = vec![];
+	pub static PriorityQueues: Vec<MessageOrigin> = vec![];
+}
+
+pub struct MockedQueueServiceQuota;
+impl QueueServiceQuota<MessageOrigin> for MockedQueueServiceQuota {
+	fn max_service_weight(id: &MessageOrigin, _: Weight) -> Option<Weight> {
+		QueueQuotas::get().into_iter().find(|(o, _)| o == id).map(|(_, w)| w)
+	}
+}
+
+pub struct MockedQueuePriority;
+impl QueuePriority<MessageOrigin> for MockedQueuePriority {
+	fn priority_queues() -> Vec<MessageOrigin> {
+		PriorityQueues::get()
+	}
+}
+
 /// Create new test externalities.
 ///
 /// Is generic since it is used by the unit test, integration tests and benchmarks.
@@ -268,6 +268,75 @@ fn service_queues_suspension_works() {
 	});
 }
 
+#[test]
+fn service_queues_respects_queue_quota() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b"), msg("c")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y"), msg("z")].into_iter(), There);
+		QueueQuotas::set(vec![(Here, 2.into_weight())]);
+
+		// `Here` can only process two messages before `There` is serviced.
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 5.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![
+				(vmsg("a"), Here),
+				(vmsg("b"), Here),
+				(vmsg("x"), There),
+				(vmsg("y"), There),
+				(vmsg("z"), There)
+			]
+		);
+
+		// The quota is per call to `service_queues`.
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 1.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("c"), Here)]);
+	});
+}
+
+#[test]
+fn service_queues_zero_quota_does_not_block_others() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y")].into_iter(), There);
+		QueueQuotas::set(vec![(Here, Weight::zero())]);
+
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 2.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("x"), There), (vmsg("y"), There)]);
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), Weight::zero());
+
+		QueueQuotas::take();
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 2.into_weight());
+		assert_eq!(MessagesProcessed::take(), vec![(vmsg("a"), Here), (vmsg("b"), Here)]);
+	});
+}
+
+#[test]
+fn service_queues_services_priority_queues_first() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y")].into_iter(), There);
+		MessageQueue::enqueue_messages(vec![msg("m"), msg("n")].into_iter(), Everywhere(0));
+		PriorityQueues::set(vec![Everywhere(0), There]);
+
+		assert_eq!(MessageQueue::service_queues(3.into_weight()), 3.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("m"), Everywhere(0)), (vmsg("n"), Everywhere(0)), (vmsg("x"), There)]
+		);
+
+		// Priority queues that are not ready are skipped.
+		assert_eq!(MessageQueue::service_queues(Weight::MAX), 3.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("y"), There), (vmsg("a"), Here), (vmsg("b"), Here)]
+		);
+	});
+}
+
 #[test]
 #[cfg(debug_assertions)]
 #[should_panic(expected = "Not enough weight to service a single message.")]
@@ -39,12 +39,15 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `note_multisig_call` - Store the call of an open multisig operation on-chain, so that the
+//!   other signatories can inspect it and approve it by hash only.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
 pub mod migrations;
+pub mod runtime_api;
 mod tests;
 pub mod weights;
 
@@ -52,7 +55,10 @@ extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
-	traits::{Currency, ReservableCurrency},
+	traits::{
+		fungible::{DepositPrice, ReservedDeposit, StorageDeposit},
+		Currency, Footprint, GetDefault, GetInnerCalls, ReservableCurrency,
+	},
 };
 use frame_system::RawOrigin;
 pub use weights::WeightInfo;
@@ -77,6 +83,14 @@ macro_rules! log {
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// The deposit of an open operation or of a stored call: `DepositBase`, plus `DepositFactor` for
+/// every unit.
+type DepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	ReservedDeposit<<T as Config>::Currency>,
+	DepositPrice<<T as Config>::DepositBase, <T as Config>::DepositFactor, GetDefault>,
+>;
+
 pub type BlockNumberFor<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
@@ -110,6 +124,43 @@ where
 	approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// The call of an open multisig operation, stored on-chain by one of its signatories.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCallSize, MaxSignatories))]
+pub struct StoredCall<Balance, AccountId, MaxCallSize, MaxSignatories>
+where
+	MaxCallSize: Get<u32>,
+	MaxSignatories: Get<u32>,
+{
+	/// The encoded call.
+	call: BoundedVec<u8, MaxCallSize>,
+	/// The total number of approvals needed before the call is executed.
+	threshold: u16,
+	/// All the signatories of the multisig, sorted.
+	signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The account who stored the call.
+	depositor: AccountId,
+	/// The amount held in reserve of the `depositor`, to be returned once the operation ends.
+	deposit: Balance,
+}
+
+/// An open multisig operation, as reported by [`Pallet::open_multisigs`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct OpenMultisig<AccountId, BlockNumber> {
+	/// The multisig account.
+	pub multisig: AccountId,
+	/// The hash of the call to be executed.
+	pub call_hash: CallHash,
+	/// The extrinsic when the multisig operation was opened.
+	pub when: Timepoint<BlockNumber>,
+	/// The approvals achieved so far. Always sorted.
+	pub approvals: Vec<AccountId>,
+	/// The threshold of the multisig, if its call is stored on-chain.
+	pub threshold: Option<u16>,
+	/// The encoded call, if it is stored on-chain.
+	pub call: Option<Vec<u8>>,
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -154,6 +205,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The maximum length of an encoded call that can be stored on-chain with
+		/// `note_multisig_call`.
+		///
+		/// Storing a call costs `DepositBase` plus `DepositFactor` for every 32 bytes of the
+		/// call and for every signatory.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: weights::WeightInfo;
 
@@ -179,6 +238,17 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The calls of open multisig operations which have been stored on-chain.
+	#[pallet::storage]
+	pub type StoredCalls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		StoredCall<BalanceOf<T>, T::AccountId, T::MaxCallSize, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -209,6 +279,8 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The call is too large to be stored on-chain.
+		CallTooLarge,
 	}
 
 	#[pallet::event]
@@ -238,6 +310,8 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The call of a multisig operation has been stored on-chain.
+		MultisigCallStored { depositor: T::AccountId, multisig: T::AccountId, call_hash: CallHash },
 	}
 
 	#[pallet::hooks]
@@ -391,7 +465,8 @@ pub mod pallet {
 		/// transaction index) of the first approval transaction.
 		/// - `call_hash`: The hash of the call to be executed.
 		///
-		/// NOTE: If this is the final approval, you will want to use `as_multi` instead.
+		/// NOTE: If this is the final approval, you will want to use `as_multi` instead, unless
+		/// the call was stored with `note_multisig_call`, in which case it is executed.
 		///
 		/// ## Complexity
 		/// - `O(S)`.
@@ -410,6 +485,8 @@ pub mod pallet {
 
 			T::WeightInfo::approve_as_multi_create(s)
 				.max(T::WeightInfo::approve_as_multi_approve(s))
+				// The final approval may execute a call stored with `note_multisig_call`.
+				.max(T::WeightInfo::as_multi_complete(s, T::MaxCallSize::get()))
 				.saturating_add(*max_weight)
 		})]
 		pub fn approve_as_multi(
@@ -474,9 +551,9 @@ pub mod pallet {
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 			ensure!(m.depositor == who, Error::<T>::NotOwner);
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
+			Self::release_deposit(&m.depositor, m.deposit);
 			<Multisigs<T>>::remove(&id, &call_hash);
+			Self::remove_stored_call(&id, &call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -486,6 +563,77 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Store the call of an open multisig operation on-chain, so that the other signatories
+		/// can inspect what they are approving and approve it with `approve_as_multi`.
+		///
+		/// Once the threshold is reached through `approve_as_multi`, the stored call is
+		/// executed. The deposit is returned once the operation is executed or cancelled.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` for every 32 bytes of the encoded call
+		/// and for every signatory is reserved.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call`: The call of the open multisig operation.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`.
+		/// - One balance-reserve operation.
+		/// - One call encode & hash, both of complexity `O(Z)` where `Z` is tx-len.
+		/// - I/O: 2 reads, 1 insert `O(S + Z)`.
+		/// - One event.
+		#[pallet::call_index(4)]
+		#[pallet::weight({
+			let s = other_signatories.len() as u32;
+			let z = call.using_encoded(|d| d.len()) as u32;
+
+			// Same storage accesses and reserve as opening an operation with the call.
+			T::WeightInfo::as_multi_create(s, z)
+		})]
+		pub fn note_multisig_call(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+			let encoded_call = call.encode();
+			let call_hash = blake2_256(&encoded_call);
+
+			ensure!(<Multisigs<T>>::contains_key(&id, call_hash), Error::<T>::NotFound);
+			ensure!(!<StoredCalls<T>>::contains_key(&id, call_hash), Error::<T>::AlreadyStored);
+
+			let units = (encoded_call.len() as u32).div_ceil(32) + signatories.len() as u32;
+			let call: BoundedVec<_, _> =
+				encoded_call.try_into().map_err(|_| Error::<T>::CallTooLarge)?;
+			let signatories: BoundedVec<_, _> =
+				signatories.try_into().map_err(|_| Error::<T>::TooManySignatories)?;
+
+			let deposit = DepositOf::<T>::update(&who, Zero::zero(), Self::footprint(units))?;
+
+			<StoredCalls<T>>::insert(
+				&id,
+				call_hash,
+				StoredCall { call, threshold, signatories, depositor: who.clone(), deposit },
+			);
+			Self::deposit_event(Event::MultisigCallStored {
+				depositor: who,
+				multisig: id,
+				call_hash,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -541,6 +689,17 @@ impl<T: Config> Pallet<T> {
 				approvals += 1;
 			}
 
+			// Fall back to the call stored on-chain, if any, when we're ready to execute.
+			let (maybe_call, call_len) = match maybe_call {
+				None if approvals >= threshold => <StoredCalls<T>>::get(&id, call_hash)
+					.and_then(|stored| {
+						let call = Decode::decode(&mut &stored.call[..]).ok()?;
+						Some((Some(call), stored.call.len()))
+					})
+					.unwrap_or((None, call_len)),
+				maybe_call => (maybe_call, call_len),
+			};
+
 			// We only bother fetching/decoding call if we know that we're ready to execute.
 			if let Some(call) = maybe_call.filter(|_| approvals >= threshold) {
 				// verify weight
@@ -552,7 +711,8 @@ impl<T: Config> Pallet<T> {
 				// Clean up storage before executing call to avoid an possibility of reentrancy
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
-				T::Currency::unreserve(&m.depositor, m.deposit);
+				Self::release_deposit(&m.depositor, m.deposit);
+				Self::remove_stored_call(&id, &call_hash);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
 				Self::deposit_event(Event::MultisigExecuted {
@@ -603,9 +763,8 @@ impl<T: Config> Pallet<T> {
 			ensure!(maybe_timepoint.is_none(), Error::<T>::UnexpectedTimepoint);
 
 			// Just start the operation by recording it in storage.
-			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
-
-			T::Currency::reserve(&who, deposit)?;
+			let deposit =
+				DepositOf::<T>::update(&who, Zero::zero(), Self::footprint(threshold.into()))?;
 
 			let initial_approvals =
 				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
@@ -637,6 +796,48 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Remove the stored call of a multisig operation, if any, and return its deposit.
+	fn remove_stored_call(id: &T::AccountId, call_hash: &CallHash) {
+		if let Some(stored) = <StoredCalls<T>>::take(id, call_hash) {
+			Self::release_deposit(&stored.depositor, stored.deposit);
+		}
+	}
+
+	/// The footprint of an operation or a stored call, made of `units` units.
+	fn footprint(units: u32) -> Footprint {
+		Footprint::from_parts(units as usize, 0)
+	}
+
+	/// Release the `deposit` of an operation or a stored call of `depositor`.
+	fn release_deposit(depositor: &T::AccountId, deposit: BalanceOf<T>) {
+		let released = DepositOf::<T>::update(depositor, deposit, Footprint::default());
+		debug_assert!(released.is_ok());
+	}
+
+	/// The open multisig operations which `who` has approved, or is a signatory of according to
+	/// the call stored on-chain.
+	///
+	/// This iterates over all open multisig operations, and is meant to be used off-chain only.
+	pub fn open_multisigs(
+		who: &T::AccountId,
+	) -> Vec<OpenMultisig<T::AccountId, BlockNumberFor<T>>> {
+		<Multisigs<T>>::iter()
+			.filter_map(|(multisig, call_hash, m)| {
+				let stored = <StoredCalls<T>>::get(&multisig, call_hash);
+				let is_signatory = m.approvals.binary_search(who).is_ok() ||
+					stored.as_ref().map_or(false, |s| s.signatories.binary_search(who).is_ok());
+				is_signatory.then(|| OpenMultisig {
+					multisig,
+					call_hash,
+					when: m.when,
+					approvals: m.approvals.into_inner(),
+					threshold: stored.as_ref().map(|s| s.threshold),
+					call: stored.map(|s| s.call.into_inner()),
+				})
+			})
+			.collect()
+	}
+
 	/// Check that signatories is sorted and doesn't contain sender, then insert sender.
 	fn ensure_sorted_and_insert(
 		other_signatories: Vec<T::AccountId>,
@@ -660,6 +861,17 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> GetInnerCalls<<T as Config>::RuntimeCall> for Call<T> {
+	fn get_inner_calls(&self) -> Vec<&<T as Config>::RuntimeCall> {
+		match self {
+			Call::as_multi { call, .. } |
+			Call::as_multi_threshold_1 { call, .. } |
+			Call::note_multisig_call { call, .. } => vec![&**call],
+			_ => Vec::new(),
+		}
+	}
+}
+
 /// Return the weight of a dispatch call result as an `Option`.
 ///
 /// Will return the weight regardless of what the state of the result is.
@@ -65,6 +65,7 @@ impl Config for Test {
 	type DepositBase = ConstU64<1>;
 	type DepositFactor = ConstU64<1>;
 	type MaxSignatories = ConstU32<3>;
+	type MaxCallSize = ConstU32<64>;
 	type WeightInfo = ();
 	type BlockNumberProvider = frame_system::Pallet<Test>;
 }
@@ -687,3 +688,174 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn stored_call_is_executed_on_final_approval() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 5);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		let call_deposit = 1 + (call.encode().len() as u64).div_ceil(32) + 3;
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::note_multisig_call(RuntimeOrigin::signed(1), 2, vec![2, 3], call));
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigCallStored {
+				depositor: 1,
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 3 + call_deposit);
+
+		// The final approval only needs the hash, and executes the stored call.
+		assert_noop!(
+			Multisig::approve_as_multi(
+				RuntimeOrigin::signed(2),
+				2,
+				vec![1, 3],
+				Some(now()),
+				hash,
+				Weight::zero()
+			),
+			Error::<Test>::MaxWeightTooLow,
+		);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			hash,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(StoredCalls::<Test>::get(multi, hash).is_none());
+	});
+}
+
+#[test]
+fn cancel_multisig_returns_stored_call_deposit() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::note_multisig_call(RuntimeOrigin::signed(2), 3, vec![1, 3], call));
+		assert!(Balances::reserved_balance(2) > 0);
+
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash));
+		assert_eq!(Balances::free_balance(1), 10);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		assert!(StoredCalls::<Test>::get(multi, hash).is_none());
+	});
+}
+
+#[test]
+fn note_multisig_call_checks_the_operation() {
+	new_test_ext().execute_with(|| {
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_noop!(
+			Multisig::note_multisig_call(RuntimeOrigin::signed(1), 2, vec![2, 3], call.clone()),
+			Error::<Test>::NotFound,
+		);
+
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		// Not a signatory of this multisig.
+		assert_noop!(
+			Multisig::note_multisig_call(RuntimeOrigin::signed(4), 2, vec![2, 3], call.clone()),
+			Error::<Test>::NotFound,
+		);
+		assert_ok!(Multisig::note_multisig_call(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			call.clone()
+		));
+		assert_noop!(
+			Multisig::note_multisig_call(RuntimeOrigin::signed(1), 2, vec![2, 3], call),
+			Error::<Test>::AlreadyStored,
+		);
+
+		let big_call =
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 100] }));
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			blake2_256(&big_call.encode()),
+			Weight::zero()
+		));
+		assert_noop!(
+			Multisig::note_multisig_call(RuntimeOrigin::signed(1), 2, vec![2, 3], big_call),
+			Error::<Test>::CallTooLarge,
+		);
+	});
+}
+
+#[test]
+fn open_multisigs_lists_operations_by_signatory() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+
+		// Without the stored call, only the approvals are known.
+		let open = Multisig::open_multisigs(&1);
+		assert_eq!(open.len(), 1);
+		assert_eq!(open[0].multisig, multi);
+		assert_eq!(open[0].call_hash, hash);
+		assert_eq!(open[0].approvals, vec![1]);
+		assert_eq!(open[0].call, None);
+		assert!(Multisig::open_multisigs(&2).is_empty());
+
+		assert_ok!(Multisig::note_multisig_call(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			call.clone()
+		));
+		let open = Multisig::open_multisigs(&2);
+		assert_eq!(open.len(), 1);
+		assert_eq!(open[0].threshold, Some(3));
+		assert_eq!(open[0].call, Some(call.encode()));
+		assert_eq!(Multisig::open_multisigs(&3), open);
+		assert!(Multisig::open_multisigs(&4).is_empty());
+	});
+}
@@ -101,6 +101,7 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 	type BagThresholds = BagThresholds;
 	type ScoreProvider = Staking;
 	type Score = VoteWeight;
+	type MaxScoreRecalculations = ConstU32<64>;
 }
 
 pub struct BalanceToU256;
@@ -21,7 +21,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
-use pallet_nomination_pools::PoolId;
+use pallet_nomination_pools::{PoolId, UnbondingPreview};
 
 sp_api::decl_runtime_apis! {
 	/// Runtime api for accessing information about nomination pools.
@@ -75,5 +75,11 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns the bonded account and reward account associated with the pool_id.
 		fn pool_accounts(pool_id: PoolId) -> (AccountId, AccountId);
+
+		/// Returns a preview of the pool `member` unbonding all of their active points now: the
+		/// balance they would unbond, the rewards paid out to them, and when they could withdraw.
+		///
+		/// Returns `None` if `member` is not a pool member.
+		fn member_unbonding_preview(member: AccountId) -> Option<UnbondingPreview<Balance>>;
 	}
 }
@@ -413,6 +413,11 @@ pub type BalanceOf<T> =
 	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 /// Type used for unique identifier of each pool.
 pub type PoolId = u32;
+/// Type used for unique identifier of each reward sub-pool.
+pub type RewardSubPoolId = u32;
+
+/// The maximum number of active reward sub-pools a single pool can have.
+pub const MAX_REWARD_SUB_POOLS: u32 = 32;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
@@ -486,6 +491,25 @@ impl ClaimPermission {
 	}
 }
 
+/// A preview of a pool member unbonding all of their active points now.
+///
+/// Returned by [`Pallet::api_member_unbonding_preview`].
+#[derive(Encode, Decode, Clone, Debug, PartialEq, Eq, TypeInfo)]
+pub struct UnbondingPreview<Balance> {
+	/// The active points of the member.
+	pub points: Balance,
+	/// The balance the points would be unbonded for.
+	pub balance: Balance,
+	/// The pending rewards, paid out to the member as part of unbonding.
+	pub pending_rewards: Balance,
+	/// The era from which the unbonded balance could be withdrawn.
+	pub withdraw_era: EraIndex,
+	/// The number of eras until the unbonded balance could be withdrawn.
+	pub eras_until_withdrawal: EraIndex,
+	/// Whether the member is allowed to unbond all of their points now.
+	pub can_unbond: bool,
+}
+
 /// A member in a pool.
 #[derive(
 	Encode,
@@ -699,6 +723,37 @@ pub struct PoolRoles<AccountId> {
 	pub bouncer: Option<AccountId>,
 }
 
+/// A reward sub-pool of a bonded pool.
+///
+/// A pool `root` can designate any number of reward sub-pools under the same bonded account, for
+/// example one per community. Members that join a sub-pool keep sharing the stake and rewards of
+/// the parent pool, but their reward payouts are routed through the sub-pool: a `commission` share
+/// of each payout is sent to the sub-pool `payee`, and the rest is paid to the member.
+#[derive(
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebugNoBound,
+	CloneNoBound,
+	frame_support::PartialEqNoBound,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct RewardSubPool<T: Config> {
+	/// The account that can remove this sub-pool, in addition to the parent pool `root`.
+	pub manager: T::AccountId,
+	/// The account that receives the commission of this sub-pool.
+	pub payee: T::AccountId,
+	/// The share of each member payout that is sent to `payee`.
+	pub commission: Perbill,
+	/// The number of pool members that currently receive their rewards through this sub-pool.
+	pub member_counter: u32,
+	/// The total rewards paid out to the members of this sub-pool, excluding commission.
+	pub total_rewards_paid: BalanceOf<T>,
+	/// The total commission paid out to `payee`.
+	pub total_commission_paid: BalanceOf<T>,
+}
+
 // A pool's possible commission claiming permissions.
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum CommissionClaimPermission<AccountId> {
@@ -1740,6 +1795,33 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// Number of total reward sub-pools created so far. Used to derive the next
+	/// [`RewardSubPoolId`].
+	#[pallet::storage]
+	pub type RewardSubPoolCount<T: Config> = StorageValue<_, RewardSubPoolId, ValueQuery>;
+
+	/// Number of active reward sub-pools of each pool.
+	#[pallet::storage]
+	pub type ParentRewardSubPools<T: Config> = StorageMap<_, Twox64Concat, PoolId, u32, ValueQuery>;
+
+	/// Reward sub-pools, keyed by the parent pool and the sub-pool id.
+	#[pallet::storage]
+	pub type RewardSubPools<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Twox64Concat,
+		RewardSubPoolId,
+		RewardSubPool<T>,
+		OptionQuery,
+	>;
+
+	/// Map from a pool member account to the reward sub-pool of its pool it receives its rewards
+	/// through.
+	#[pallet::storage]
+	pub type RewardSubPoolOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RewardSubPoolId, OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1865,6 +1947,26 @@ pub mod pallet {
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of af the reward pool.
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
+		/// A reward sub-pool has been created under pool `pool_id`.
+		RewardSubPoolCreated {
+			pool_id: PoolId,
+			sub_pool_id: RewardSubPoolId,
+			manager: T::AccountId,
+			payee: T::AccountId,
+			commission: Perbill,
+		},
+		/// A member now receives its rewards through a reward sub-pool.
+		RewardSubPoolJoined { member: T::AccountId, pool_id: PoolId, sub_pool_id: RewardSubPoolId },
+		/// A member no longer receives its rewards through a reward sub-pool.
+		RewardSubPoolLeft { member: T::AccountId, pool_id: PoolId, sub_pool_id: RewardSubPoolId },
+		/// A reward sub-pool has been removed.
+		RewardSubPoolRemoved { pool_id: PoolId, sub_pool_id: RewardSubPoolId },
+		/// Commission of a reward sub-pool has been paid to its payee.
+		RewardSubPoolCommissionPaid {
+			pool_id: PoolId,
+			sub_pool_id: RewardSubPoolId,
+			commission: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -1952,6 +2054,16 @@ pub mod pallet {
 		NotMigrated,
 		/// This call is not allowed in the current state of the pallet.
 		NotSupported,
+		/// The reward sub-pool does not exist.
+		RewardSubPoolNotFound,
+		/// The pool already has the maximum number of reward sub-pools.
+		TooManyRewardSubPools,
+		/// The reward sub-pool still has members and cannot be removed.
+		RewardSubPoolNotEmpty,
+		/// The member already receives its rewards through a reward sub-pool.
+		AlreadyInRewardSubPool,
+		/// The member does not receive its rewards through a reward sub-pool.
+		NotInRewardSubPool,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -2073,6 +2185,7 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::bond_extra_transfer()
 			.max(T::WeightInfo::bond_extra_other())
+			.saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
 		)]
 		pub fn bond_extra(origin: OriginFor<T>, extra: BondExtra<BalanceOf<T>>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
@@ -2095,7 +2208,10 @@ pub mod pallet {
 		///
 		/// See `claim_payout_other` to claim rewards on behalf of some `other` pool member.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::claim_payout())]
+		#[pallet::weight(
+			T::WeightInfo::claim_payout()
+			.saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
+		)]
 		pub fn claim_payout(origin: OriginFor<T>) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			// ensure signer is not in an un-migrated state.
@@ -2139,7 +2255,9 @@ pub mod pallet {
 		/// in which case, the result of this call will likely be the `NoMoreChunks` error from the
 		/// staking system.
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::unbond())]
+		#[pallet::weight(
+			T::WeightInfo::unbond().saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
+		)]
 		pub fn unbond(
 			origin: OriginFor<T>,
 			member_account: AccountIdLookupOf<T>,
@@ -2277,6 +2395,7 @@ pub mod pallet {
 		#[pallet::call_index(5)]
 		#[pallet::weight(
 			T::WeightInfo::withdraw_unbonded_kill(*num_slashing_spans)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 2))
 		)]
 		pub fn withdraw_unbonded(
 			origin: OriginFor<T>,
@@ -2403,6 +2522,7 @@ pub mod pallet {
 
 				// member being reaped.
 				PoolMembers::<T>::remove(&member_account);
+				Self::do_leave_reward_sub_pool(member.pool_id, &member_account);
 
 				// Ensure any dangling delegation is withdrawn.
 				let dangling_withdrawal = match T::StakeAdapter::member_delegation_balance(
@@ -2755,6 +2875,7 @@ pub mod pallet {
 		#[pallet::weight(
 			T::WeightInfo::bond_extra_transfer()
 			.max(T::WeightInfo::bond_extra_other())
+			.saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
 		)]
 		pub fn bond_extra_other(
 			origin: OriginFor<T>,
@@ -2806,7 +2927,10 @@ pub mod pallet {
 		/// Pool member `other` must have a `PermissionlessWithdraw` or `PermissionlessAll` claim
 		/// permission for this call to be successful.
 		#[pallet::call_index(16)]
-		#[pallet::weight(T::WeightInfo::claim_payout())]
+		#[pallet::weight(
+			T::WeightInfo::claim_payout()
+			.saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
+		)]
 		pub fn claim_payout_other(origin: OriginFor<T>, other: T::AccountId) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
 			// ensure member is not in an un-migrated state.
@@ -3089,6 +3213,158 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Create a reward sub-pool under pool `pool_id`.
+		///
+		/// Members of the pool that join the sub-pool pay `commission` of each of their reward
+		/// payouts to `payee`. The sub-pool can later be removed by either the pool `root` or
+		/// `manager`, once it has no members left.
+		///
+		/// The dispatch origin of this call must be signed by the pool `root`.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+		pub fn create_reward_sub_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			manager: AccountIdLookupOf<T>,
+			payee: AccountIdLookupOf<T>,
+			commission: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let manager = T::Lookup::lookup(manager)?;
+			let payee = T::Lookup::lookup(payee)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_manage_commission(&who), Error::<T>::DoesNotHavePermission);
+			ensure!(
+				commission <= GlobalMaxCommission::<T>::get().unwrap_or(Bounded::max_value()),
+				Error::<T>::CommissionExceedsGlobalMaximum
+			);
+			ensure!(
+				ParentRewardSubPools::<T>::get(pool_id) < MAX_REWARD_SUB_POOLS,
+				Error::<T>::TooManyRewardSubPools
+			);
+
+			let sub_pool_id = RewardSubPoolCount::<T>::get();
+			RewardSubPoolCount::<T>::put(sub_pool_id.saturating_add(1));
+			ParentRewardSubPools::<T>::mutate(pool_id, |count| count.saturating_inc());
+			RewardSubPools::<T>::insert(
+				pool_id,
+				sub_pool_id,
+				RewardSubPool {
+					manager: manager.clone(),
+					payee: payee.clone(),
+					commission,
+					member_counter: 0,
+					total_rewards_paid: Zero::zero(),
+					total_commission_paid: Zero::zero(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::RewardSubPoolCreated {
+				pool_id,
+				sub_pool_id,
+				manager,
+				payee,
+				commission,
+			});
+			Ok(())
+		}
+
+		/// Receive future reward payouts through the reward sub-pool `sub_pool_id` of the pool
+		/// the caller is a member of.
+		///
+		/// Any pending rewards are paid out before joining, so that they are not subject to the
+		/// sub-pool commission.
+		#[pallet::call_index(27)]
+		#[pallet::weight(
+			T::WeightInfo::claim_payout().saturating_add(T::DbWeight::get().reads_writes(2, 2))
+		)]
+		pub fn join_reward_sub_pool(
+			origin: OriginFor<T>,
+			sub_pool_id: RewardSubPoolId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+			ensure!(!RewardSubPoolOf::<T>::contains_key(&who), Error::<T>::AlreadyInRewardSubPool);
+
+			let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&who)?;
+			let pool_id = member.pool_id;
+			let mut sub_pool = RewardSubPools::<T>::get(pool_id, sub_pool_id)
+				.ok_or(Error::<T>::RewardSubPoolNotFound)?;
+
+			Self::do_reward_payout(&who, &mut member, &mut bonded_pool, &mut reward_pool)?;
+			Self::put_member_with_pools(&who, member, bonded_pool, reward_pool);
+
+			sub_pool.member_counter.saturating_inc();
+			RewardSubPools::<T>::insert(pool_id, sub_pool_id, sub_pool);
+			RewardSubPoolOf::<T>::insert(&who, sub_pool_id);
+
+			Self::deposit_event(Event::<T>::RewardSubPoolJoined {
+				member: who,
+				pool_id,
+				sub_pool_id,
+			});
+			Ok(())
+		}
+
+		/// Stop receiving reward payouts through the reward sub-pool the caller is in.
+		///
+		/// Any pending rewards are paid out through the sub-pool before leaving.
+		#[pallet::call_index(28)]
+		#[pallet::weight(
+			T::WeightInfo::claim_payout()
+			.saturating_add(Pallet::<T>::reward_sub_pool_payout_weight())
+		)]
+		pub fn leave_reward_sub_pool(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				!Self::api_member_needs_delegate_migration(who.clone()),
+				Error::<T>::NotMigrated
+			);
+			ensure!(RewardSubPoolOf::<T>::contains_key(&who), Error::<T>::NotInRewardSubPool);
+
+			let (mut member, mut bonded_pool, mut reward_pool) = Self::get_member_with_pools(&who)?;
+			// a fully unbonding member has nothing left to claim.
+			if !member.active_points().is_zero() {
+				Self::do_reward_payout(&who, &mut member, &mut bonded_pool, &mut reward_pool)?;
+			}
+			let pool_id = member.pool_id;
+			Self::put_member_with_pools(&who, member, bonded_pool, reward_pool);
+
+			Self::do_leave_reward_sub_pool(pool_id, &who);
+			Ok(())
+		}
+
+		/// Remove the reward sub-pool `sub_pool_id` of pool `pool_id`.
+		///
+		/// The sub-pool must not have any members left. The dispatch origin of this call must be
+		/// signed by the pool `root` or the sub-pool `manager`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn remove_reward_sub_pool(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			sub_pool_id: RewardSubPoolId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let sub_pool = RewardSubPools::<T>::get(pool_id, sub_pool_id)
+				.ok_or(Error::<T>::RewardSubPoolNotFound)?;
+			ensure!(
+				bonded_pool.can_manage_commission(&who) || sub_pool.manager == who,
+				Error::<T>::DoesNotHavePermission
+			);
+			ensure!(sub_pool.member_counter.is_zero(), Error::<T>::RewardSubPoolNotEmpty);
+
+			RewardSubPools::<T>::remove(pool_id, sub_pool_id);
+			ParentRewardSubPools::<T>::mutate(pool_id, |count| count.saturating_dec());
+
+			Self::deposit_event(Event::<T>::RewardSubPoolRemoved { pool_id, sub_pool_id });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -3138,6 +3414,8 @@ impl<T: Config> Pallet<T> {
 		ReversePoolIdLookup::<T>::remove(&bonded_account);
 		RewardPools::<T>::remove(bonded_pool.id);
 		SubPoolsStorage::<T>::remove(bonded_pool.id);
+		let _ = RewardSubPools::<T>::clear_prefix(bonded_pool.id, MAX_REWARD_SUB_POOLS, None);
+		ParentRewardSubPools::<T>::remove(bonded_pool.id);
 
 		// remove the ED restriction from the pool reward account.
 		let _ = Self::unfreeze_pool_deposit(&bonded_pool.reward_account()).defensive();
@@ -3325,10 +3603,17 @@ impl<T: Config> Pallet<T> {
 		member.last_recorded_reward_counter = current_reward_counter;
 		reward_pool.register_claimed_reward(pending_rewards);
 
+		// route the payout through the member's reward sub-pool, if any.
+		let payout = match RewardSubPoolOf::<T>::get(member_account) {
+			Some(sub_pool_id) =>
+				Self::do_reward_sub_pool_payout(bonded_pool, sub_pool_id, pending_rewards),
+			None => pending_rewards,
+		};
+
 		T::Currency::transfer(
 			&bonded_pool.reward_account(),
 			member_account,
-			pending_rewards,
+			payout,
 			// defensive: the depositor has put existential deposit into the pool and it stays
 			// untouched, reward account shall not die.
 			Preservation::Preserve,
@@ -3337,9 +3622,72 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::<T>::PaidOut {
 			member: member_account.clone(),
 			pool_id: member.pool_id,
-			payout: pending_rewards,
+			payout,
+		});
+		Ok(payout)
+	}
+
+	/// Pay the commission of reward sub-pool `sub_pool_id` out of `pending_rewards`, returning the
+	/// remainder that is due to the member.
+	///
+	/// If the commission cannot be transferred to the sub-pool payee, for example because it would
+	/// not reach the existential deposit, it stays with the member.
+	fn do_reward_sub_pool_payout(
+		bonded_pool: &BondedPool<T>,
+		sub_pool_id: RewardSubPoolId,
+		pending_rewards: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let Some(mut sub_pool) = RewardSubPools::<T>::get(bonded_pool.id, sub_pool_id) else {
+			defensive!("member should only be in an existing reward sub-pool; qed");
+			return pending_rewards
+		};
+
+		let mut commission = sub_pool.commission * pending_rewards;
+		if !commission.is_zero() &&
+			T::Currency::transfer(
+				&bonded_pool.reward_account(),
+				&sub_pool.payee,
+				commission,
+				Preservation::Preserve,
+			)
+			.is_err()
+		{
+			commission = Zero::zero();
+		}
+		let payout = pending_rewards.saturating_sub(commission);
+
+		sub_pool.total_commission_paid.saturating_accrue(commission);
+		sub_pool.total_rewards_paid.saturating_accrue(payout);
+		RewardSubPools::<T>::insert(bonded_pool.id, sub_pool_id, sub_pool);
+
+		if !commission.is_zero() {
+			Self::deposit_event(Event::<T>::RewardSubPoolCommissionPaid {
+				pool_id: bonded_pool.id,
+				sub_pool_id,
+				commission,
+			});
+		}
+		payout
+	}
+
+	/// Remove `who` from its reward sub-pool in pool `pool_id`, if it is in one.
+	fn do_leave_reward_sub_pool(pool_id: PoolId, who: &T::AccountId) {
+		let Some(sub_pool_id) = RewardSubPoolOf::<T>::take(who) else { return };
+		RewardSubPools::<T>::mutate(pool_id, sub_pool_id, |maybe_sub_pool| {
+			if let Some(sub_pool) = maybe_sub_pool {
+				sub_pool.member_counter.saturating_dec();
+			}
+		});
+		Self::deposit_event(Event::<T>::RewardSubPoolLeft {
+			member: who.clone(),
+			pool_id,
+			sub_pool_id,
 		});
-		Ok(pending_rewards)
+	}
+
+	/// The extra weight of routing a reward payout through a reward sub-pool.
+	pub(crate) fn reward_sub_pool_payout_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
 	}
 
 	fn do_create(
@@ -3822,6 +4170,24 @@ impl<T: Config> Pallet<T> {
 			"TVL must be equal to or less than the total balance of all PoolMembers."
 		);
 
+		let mut reward_sub_pool_members = BTreeMap::<(PoolId, RewardSubPoolId), u32>::new();
+		for (who, sub_pool_id) in RewardSubPoolOf::<T>::iter() {
+			let member = PoolMembers::<T>::get(&who)
+				.ok_or("Each `RewardSubPoolOf` account must be a pool member")?;
+			ensure!(
+				RewardSubPools::<T>::contains_key(member.pool_id, sub_pool_id),
+				"Each `RewardSubPoolOf` entry must point to a reward sub-pool of the member's pool"
+			);
+			*reward_sub_pool_members.entry((member.pool_id, sub_pool_id)).or_default() += 1;
+		}
+		for (pool_id, sub_pool_id, sub_pool) in RewardSubPools::<T>::iter() {
+			ensure!(
+				reward_sub_pool_members.get(&(pool_id, sub_pool_id)).copied().unwrap_or_default() ==
+					sub_pool.member_counter,
+				"Each `RewardSubPool.member_counter` must be equal to the actual count of its members"
+			);
+		}
+
 		if level <= 1 {
 			return Ok(())
 		}
@@ -4040,6 +4406,28 @@ impl<T: Config> Pallet<T> {
 		let reward_account = Self::generate_reward_account(pool_id);
 		(bonded_account, reward_account)
 	}
+
+	/// Preview the outcome of `who` unbonding all of their active points now.
+	///
+	/// Returns `None` if `who` is not a pool member. Used by runtime API.
+	pub fn api_member_unbonding_preview(
+		who: T::AccountId,
+	) -> Option<UnbondingPreview<BalanceOf<T>>> {
+		let member = PoolMembers::<T>::get(&who)?;
+		let bonded_pool = BondedPool::<T>::get(member.pool_id)?;
+		let points = member.active_points();
+		let can_unbond = !Self::api_member_needs_delegate_migration(who.clone()) &&
+			bonded_pool.ok_to_unbond_with(&who, &who, &member, points).is_ok();
+		let eras_until_withdrawal = T::StakeAdapter::bonding_duration();
+		Some(UnbondingPreview {
+			points,
+			balance: bonded_pool.points_to_balance(points),
+			pending_rewards: Self::api_pending_rewards(who).unwrap_or_default(),
+			withdraw_era: T::StakeAdapter::current_era().saturating_add(eras_until_withdrawal),
+			eras_until_withdrawal,
+			can_unbond,
+		})
+	}
 }
 
 impl<T: Config> sp_staking::OnStakingUpdate<T::AccountId, BalanceOf<T>> for Pallet<T> {
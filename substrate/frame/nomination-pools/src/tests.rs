@@ -251,6 +251,35 @@ mod bonded_pool {
 		})
 	}
 
+	#[test]
+	fn api_member_unbonding_preview_works() {
+		ExtBuilder::default().add_members(vec![(20, 20)]).build_and_execute(|| {
+			CurrentEra::set(5);
+			assert_eq!(
+				Pallet::<Runtime>::api_member_unbonding_preview(20),
+				Some(UnbondingPreview {
+					points: 20,
+					balance: 20,
+					pending_rewards: 0,
+					withdraw_era: 5 + BondingDuration::get(),
+					eras_until_withdrawal: BondingDuration::get(),
+					can_unbond: true,
+				})
+			);
+
+			// slash half of the pool's balance, the member unbonds half of their contribution.
+			StakingMock::slash_by(1, 15);
+			let preview = Pallet::<Runtime>::api_member_unbonding_preview(20).unwrap();
+			assert_eq!((preview.points, preview.balance), (20, 10));
+
+			// the depositor cannot unbond all of their points while the pool has members.
+			assert!(!Pallet::<Runtime>::api_member_unbonding_preview(10).unwrap().can_unbond);
+
+			// not a member.
+			assert_eq!(Pallet::<Runtime>::api_member_unbonding_preview(30), None);
+		})
+	}
+
 	#[test]
 	fn ok_to_join_with_works() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -7488,6 +7517,162 @@ mod chill {
 	}
 }
 
+mod reward_sub_pools {
+	use super::*;
+
+	#[test]
+	fn reward_sub_pool_lifecycle_works() {
+		ExtBuilder::default().add_members(vec![(40, 40), (50, 50)]).build_and_execute(|| {
+			let ed = Currency::minimum_balance();
+			Currency::set_balance(&40, 0);
+			Currency::set_balance(&99, ed);
+			let _ = pool_events_since_last_call();
+
+			// only the pool root can create a reward sub-pool.
+			assert_noop!(
+				Pools::create_reward_sub_pool(
+					RuntimeOrigin::signed(901),
+					1,
+					901,
+					99,
+					Perbill::from_percent(10)
+				),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_ok!(Pools::create_reward_sub_pool(
+				RuntimeOrigin::signed(900),
+				1,
+				901,
+				99,
+				Perbill::from_percent(10)
+			));
+			assert_eq!(ParentRewardSubPools::<Runtime>::get(1), 1);
+
+			// a member joins the sub-pool.
+			assert_noop!(
+				Pools::join_reward_sub_pool(RuntimeOrigin::signed(40), 1),
+				Error::<Runtime>::RewardSubPoolNotFound
+			);
+			assert_ok!(Pools::join_reward_sub_pool(RuntimeOrigin::signed(40), 0));
+			assert_noop!(
+				Pools::join_reward_sub_pool(RuntimeOrigin::signed(40), 0),
+				Error::<Runtime>::AlreadyInRewardSubPool
+			);
+			assert_eq!(RewardSubPoolOf::<Runtime>::get(40), Some(0));
+
+			// when the pool earns rewards, the sub-pool commission goes to its payee.
+			deposit_rewards(100);
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(40)));
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(50)));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::RewardSubPoolCreated {
+						pool_id: 1,
+						sub_pool_id: 0,
+						manager: 901,
+						payee: 99,
+						commission: Perbill::from_percent(10)
+					},
+					Event::RewardSubPoolJoined { member: 40, pool_id: 1, sub_pool_id: 0 },
+					Event::RewardSubPoolCommissionPaid {
+						pool_id: 1,
+						sub_pool_id: 0,
+						commission: 4
+					},
+					Event::PaidOut { member: 40, pool_id: 1, payout: 36 },
+					Event::PaidOut { member: 50, pool_id: 1, payout: 50 },
+				]
+			);
+			assert_eq!(Currency::free_balance(&40), 36);
+			assert_eq!(Currency::free_balance(&99), ed + 4);
+			let sub_pool = RewardSubPools::<Runtime>::get(1, 0).unwrap();
+			assert_eq!(sub_pool.member_counter, 1);
+			assert_eq!(sub_pool.total_rewards_paid, 36);
+			assert_eq!(sub_pool.total_commission_paid, 4);
+
+			// the sub-pool cannot be removed while it has members, nor by anyone else.
+			assert_noop!(
+				Pools::remove_reward_sub_pool(RuntimeOrigin::signed(901), 1, 0),
+				Error::<Runtime>::RewardSubPoolNotEmpty
+			);
+
+			assert_ok!(Pools::leave_reward_sub_pool(RuntimeOrigin::signed(40)));
+			assert_noop!(
+				Pools::leave_reward_sub_pool(RuntimeOrigin::signed(40)),
+				Error::<Runtime>::NotInRewardSubPool
+			);
+			assert_noop!(
+				Pools::remove_reward_sub_pool(RuntimeOrigin::signed(40), 1, 0),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_ok!(Pools::remove_reward_sub_pool(RuntimeOrigin::signed(901), 1, 0));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::RewardSubPoolLeft { member: 40, pool_id: 1, sub_pool_id: 0 },
+					Event::RewardSubPoolRemoved { pool_id: 1, sub_pool_id: 0 },
+				]
+			);
+			assert!(!RewardSubPools::<Runtime>::contains_key(1, 0));
+			assert_eq!(ParentRewardSubPools::<Runtime>::get(1), 0);
+		})
+	}
+
+	#[test]
+	fn commission_stays_with_member_if_payee_cannot_receive_it() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			Currency::set_balance(&40, 0);
+			assert_ok!(Pools::create_reward_sub_pool(
+				RuntimeOrigin::signed(900),
+				1,
+				900,
+				98,
+				Perbill::from_percent(10)
+			));
+			assert_ok!(Pools::join_reward_sub_pool(RuntimeOrigin::signed(40), 0));
+			let _ = pool_events_since_last_call();
+
+			// the commission of 4 is below the existential deposit of the empty payee account.
+			deposit_rewards(50);
+			assert_ok!(Pools::claim_payout(RuntimeOrigin::signed(40)));
+
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::PaidOut { member: 40, pool_id: 1, payout: 40 }]
+			);
+			assert_eq!(Currency::free_balance(&40), 40);
+			assert_eq!(Currency::free_balance(&98), 0);
+			assert_eq!(RewardSubPools::<Runtime>::get(1, 0).unwrap().total_commission_paid, 0);
+		})
+	}
+
+	#[test]
+	fn reaped_member_leaves_reward_sub_pool() {
+		ExtBuilder::default().add_members(vec![(40, 40)]).build_and_execute(|| {
+			assert_ok!(Pools::create_reward_sub_pool(
+				RuntimeOrigin::signed(900),
+				1,
+				900,
+				900,
+				Perbill::from_percent(10)
+			));
+			assert_ok!(Pools::join_reward_sub_pool(RuntimeOrigin::signed(40), 0));
+
+			assert_ok!(Pools::fully_unbond(RuntimeOrigin::signed(40), 40));
+			CurrentEra::set(3);
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(40), 40, 0));
+
+			assert!(!PoolMembers::<Runtime>::contains_key(40));
+			assert!(!RewardSubPoolOf::<Runtime>::contains_key(40));
+			assert_eq!(RewardSubPools::<Runtime>::get(1, 0).unwrap().member_counter, 0);
+			assert_ok!(Pools::remove_reward_sub_pool(RuntimeOrigin::signed(900), 1, 0));
+		})
+	}
+}
+
 // the test mock is using `TransferStake` and so `DelegateStake` is not tested here. Extrinsics
 // meant for `DelegateStake` should be gated.
 //
@@ -23,6 +23,9 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! The account may also constrain a proxy with an expiry block, a total spending limit and a
+//! limit on the number of calls per period, all of which are checked whenever the proxy is used.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -34,10 +37,13 @@ mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
-	traits::{Currency, ReservableCurrency},
+	traits::{
+		fungible::{DepositPrice, ReservedDeposit, StorageDeposit},
+		Currency, Footprint, GetDefault, GetInnerCalls, ReservableCurrency,
+	},
 };
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -47,6 +53,33 @@ type CallHashOf<T> = <<T as Config>::CallHasher as Hash>::Output;
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// The deposit for the proxies of an account: `ProxyDepositBase`, plus `ProxyDepositFactor` for
+/// every proxy.
+type ProxyDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	ReservedDeposit<<T as Config>::Currency>,
+	DepositPrice<<T as Config>::ProxyDepositBase, <T as Config>::ProxyDepositFactor, GetDefault>,
+>;
+
+/// The deposit for the pending announcements of an account: `AnnouncementDepositBase`, plus
+/// `AnnouncementDepositFactor` for every announcement.
+type AnnouncementDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	ReservedDeposit<<T as Config>::Currency>,
+	DepositPrice<
+		<T as Config>::AnnouncementDepositBase,
+		<T as Config>::AnnouncementDepositFactor,
+		GetDefault,
+	>,
+>;
+
+/// The deposit for the constraints of a proxy: `ProxyDepositFactor`.
+type ConstraintsDepositOf<T> = StorageDeposit<
+	<T as frame_system::Config>::AccountId,
+	ReservedDeposit<<T as Config>::Currency>,
+	DepositPrice<GetDefault, <T as Config>::ProxyDepositFactor, GetDefault>,
+>;
+
 pub type BlockNumberFor<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 
@@ -77,6 +110,53 @@ pub struct ProxyDefinition<AccountId, ProxyType, BlockNumber> {
 	pub delay: BlockNumber,
 }
 
+/// A limit on the number of calls a proxy may make per period.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct CallLimit<BlockNumber> {
+	/// The maximum number of calls in any period.
+	pub max_calls: u32,
+	/// The length of a period, in blocks.
+	pub period: BlockNumber,
+}
+
+/// The constraints under which a proxy may be used, in addition to its proxy type.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ProxyConstraints<Balance, BlockNumber> {
+	/// The last block at which the proxy may be used.
+	pub expiry: Option<BlockNumber>,
+	/// The total value the proxy may spend, as reported by [`Config::CallValue`].
+	pub spending_limit: Option<Balance>,
+	/// The number of calls the proxy may make per period.
+	pub call_limit: Option<CallLimit<BlockNumber>>,
+}
+
+/// How much of its constraints a proxy has used so far.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub struct ProxyUsage<Balance, BlockNumber> {
+	/// The total value spent by the proxy.
+	pub spent: Balance,
+	/// The block at which the current call limit period started.
+	pub period_start: BlockNumber,
+	/// The number of calls made in the current call limit period.
+	pub calls: u32,
+}
+
+/// Reports the value spent by a call, such as the amount of a transfer.
+pub trait ProxyCallValue<Call, Balance> {
+	/// The value spent by `call`, or zero if it spends nothing.
+	///
+	/// Implementations should account for calls nested in batches or other wrappers.
+	fn value(call: &Call) -> Balance;
+}
+
+impl<Call, Balance: Zero> ProxyCallValue<Call, Balance> for () {
+	fn value(_: &Call) -> Balance {
+		Zero::zero()
+	}
+}
+
 /// Details surrounding a specific instance of an announcement to make a call.
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Announcement<AccountId, Hash, BlockNumber> {
@@ -169,6 +249,10 @@ pub mod pallet {
 
 		/// Provider for the block number. Normally this is the `frame_system` pallet.
 		type BlockNumberProvider: BlockNumberProvider;
+
+		/// Reports the value spent by a proxied call, checked against the spending limit of the
+		/// proxy.
+		type CallValue: ProxyCallValue<<Self as Config>::RuntimeCall, BalanceOf<Self>>;
 	}
 
 	#[pallet::call]
@@ -186,8 +270,8 @@ pub mod pallet {
 		#[pallet::weight({
 			let di = call.get_dispatch_info();
 			(T::WeightInfo::proxy(T::MaxProxies::get())
-				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // AccountData for inner call origin accountdata and the proxy constraints.
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -201,6 +285,7 @@ pub mod pallet {
 			let real = T::Lookup::lookup(real)?;
 			let def = Self::find_proxy(&real, &who, force_proxy_type)?;
 			ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
+			Self::use_constraints(&real, &def, &call)?;
 
 			Self::do_proxy(def, real, *call);
 
@@ -237,7 +322,10 @@ pub mod pallet {
 		/// - `proxy`: The account that the `caller` would like to remove as a proxy.
 		/// - `proxy_type`: The permissions currently enabled for the removed proxy account.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::remove_proxy(T::MaxProxies::get()))]
+		#[pallet::weight(
+			T::WeightInfo::remove_proxy(T::MaxProxies::get())
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
 		pub fn remove_proxy(
 			origin: OriginFor<T>,
 			delegate: AccountIdLookupOf<T>,
@@ -256,7 +344,14 @@ pub mod pallet {
 		/// WARNING: This may be called on accounts created by `pure`, however if done, then
 		/// the unreserved fees will be inaccessible. **All access to this account will be lost.**
 		#[pallet::call_index(3)]
-		#[pallet::weight(T::WeightInfo::remove_proxies(T::MaxProxies::get()))]
+		#[pallet::weight(
+			T::WeightInfo::remove_proxies(T::MaxProxies::get()).saturating_add(
+				T::DbWeight::get().reads_writes(
+					T::MaxProxies::get().into(),
+					T::MaxProxies::get().into(),
+				),
+			)
+		)]
 		pub fn remove_proxies(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			Self::remove_all_proxy_delegates(&who);
@@ -299,8 +394,7 @@ pub mod pallet {
 			let bounded_proxies: BoundedVec<_, T::MaxProxies> =
 				vec![proxy_def].try_into().map_err(|_| Error::<T>::TooMany)?;
 
-			let deposit = T::ProxyDepositBase::get() + T::ProxyDepositFactor::get();
-			T::Currency::reserve(&who, deposit)?;
+			let deposit = ProxyDepositOf::<T>::update(&who, Zero::zero(), Self::items(1))?;
 
 			Proxies::<T>::insert(&pure, (bounded_proxies, deposit));
 			Self::deposit_event(Event::PureCreated {
@@ -330,7 +424,14 @@ pub mod pallet {
 		/// Fails with `NoPermission` in case the caller is not a previously created pure
 		/// account whose `pure` call has corresponding parameters.
 		#[pallet::call_index(5)]
-		#[pallet::weight(T::WeightInfo::kill_pure(T::MaxProxies::get()))]
+		#[pallet::weight(
+			T::WeightInfo::kill_pure(T::MaxProxies::get()).saturating_add(
+				T::DbWeight::get().reads_writes(
+					T::MaxProxies::get().into(),
+					T::MaxProxies::get().into(),
+				),
+			)
+		)]
 		pub fn kill_pure(
 			origin: OriginFor<T>,
 			spawner: AccountIdLookupOf<T>,
@@ -347,7 +448,8 @@ pub mod pallet {
 			ensure!(proxy == who, Error::<T>::NoPermission);
 
 			let (_, deposit) = Proxies::<T>::take(&who);
-			T::Currency::unreserve(&spawner, deposit);
+			ProxyDepositOf::<T>::update(&spawner, deposit, Footprint::default())?;
+			Self::remove_all_constraints(&who);
 
 			Ok(())
 		}
@@ -390,17 +492,9 @@ pub mod pallet {
 
 			Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
 				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
-				Self::rejig_deposit(
-					&who,
-					*deposit,
-					T::AnnouncementDepositBase::get(),
-					T::AnnouncementDepositFactor::get(),
-					pending.len(),
-				)
-				.map(|d| {
-					d.expect("Just pushed; pending.len() > 0; rejig_deposit returns Some; qed")
-				})
-				.map(|d| *deposit = d)
+				*deposit =
+					AnnouncementDepositOf::<T>::update(&who, *deposit, Self::items(pending.len()))?;
+				Ok::<_, DispatchError>(())
 			})?;
 			Self::deposit_event(Event::Announced { real, proxy: who, call_hash });
 
@@ -478,8 +572,8 @@ pub mod pallet {
 		#[pallet::weight({
 			let di = call.get_dispatch_info();
 			(T::WeightInfo::proxy_announced(T::MaxPending::get(), T::MaxProxies::get())
-				 // AccountData for inner call origin accountdata.
-				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				 // AccountData for inner call origin accountdata and the proxy constraints.
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 				.saturating_add(di.call_weight),
 			di.class)
 		})]
@@ -503,11 +597,71 @@ pub mod pallet {
 					now.saturating_sub(ann.height) < def.delay
 			})
 			.map_err(|_| Error::<T>::Unannounced)?;
+			Self::use_constraints(&real, &def, &call)?;
 
 			Self::do_proxy(def, real, *call);
 
 			Ok(())
 		}
+
+		/// Set or clear the constraints of one of the sender's proxies.
+		///
+		/// Setting constraints resets what the proxy has used of them so far. The first time
+		/// constraints are set for a proxy, `ProxyDepositFactor` is reserved. It is returned once
+		/// they are cleared or the proxy is removed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy account.
+		/// - `proxy_type`: The permissions of the proxy.
+		/// - `delay`: The announcement period of the proxy.
+		/// - `constraints`: The new constraints of the proxy, or `None` to remove them.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::add_proxy(T::MaxProxies::get())
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn set_proxy_constraints(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			proxy_type: T::ProxyType,
+			delay: BlockNumberFor<T>,
+			constraints: Option<ProxyConstraints<BalanceOf<T>, BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let def = ProxyDefinition { delegate, proxy_type, delay };
+			Proxies::<T>::get(&who).0.binary_search(&def).map_err(|_| Error::<T>::NotFound)?;
+
+			match constraints {
+				Some(constraints) => {
+					let deposit = match Constraints::<T>::get(&who, &def) {
+						Some((_, _, deposit)) => deposit,
+						None =>
+							ConstraintsDepositOf::<T>::update(&who, Zero::zero(), Self::items(1))?,
+					};
+					let usage = ProxyUsage {
+						spent: Zero::zero(),
+						period_start: T::BlockNumberProvider::current_block_number(),
+						calls: 0,
+					};
+					Constraints::<T>::insert(&who, &def, (constraints, usage, deposit));
+				},
+				None =>
+					if let Some((_, _, deposit)) = Constraints::<T>::take(&who, &def) {
+						ConstraintsDepositOf::<T>::update(&who, deposit, Footprint::default())?;
+					},
+			}
+
+			Self::deposit_event(Event::ProxyConstraintsSet {
+				delegator: who,
+				delegatee: def.delegate,
+				proxy_type: def.proxy_type,
+				constraints,
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -539,6 +693,13 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// The constraints of a proxy were set, or removed if `None`.
+		ProxyConstraintsSet {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			constraints: Option<ProxyConstraints<BalanceOf<T>, BlockNumberFor<T>>>,
+		},
 	}
 
 	#[pallet::error]
@@ -559,6 +720,12 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The proxy has expired.
+		Expired,
+		/// The call would exceed the spending limit of the proxy.
+		SpendingLimitExceeded,
+		/// The proxy has made as many calls as it may in the current period.
+		CallLimitExceeded,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -578,6 +745,22 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The constraints of proxies, keyed by the account which has delegated and the proxy,
+	/// together with what the proxy has used of them and the amount held on deposit.
+	#[pallet::storage]
+	pub type Constraints<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		(
+			ProxyConstraints<BalanceOf<T>, BlockNumberFor<T>>,
+			ProxyUsage<BalanceOf<T>, BlockNumberFor<T>>,
+			BalanceOf<T>,
+		),
+	>;
+
 	/// The announcements made by the proxy (key).
 	#[pallet::storage]
 	pub type Announcements<T: Config> = StorageMap<
@@ -665,13 +848,8 @@ impl<T: Config> Pallet<T> {
 			};
 			let i = proxies.binary_search(&proxy_def).err().ok_or(Error::<T>::Duplicate)?;
 			proxies.try_insert(i, proxy_def).map_err(|_| Error::<T>::TooMany)?;
-			let new_deposit = Self::deposit(proxies.len() as u32);
-			if new_deposit > *deposit {
-				T::Currency::reserve(delegator, new_deposit - *deposit)?;
-			} else if new_deposit < *deposit {
-				T::Currency::unreserve(delegator, *deposit - new_deposit);
-			}
-			*deposit = new_deposit;
+			*deposit =
+				ProxyDepositOf::<T>::update(delegator, *deposit, Self::items(proxies.len()))?;
 			Self::deposit_event(Event::<T>::ProxyAdded {
 				delegator: delegator.clone(),
 				delegatee,
@@ -705,12 +883,11 @@ impl<T: Config> Pallet<T> {
 			};
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
 			proxies.remove(i);
-			let new_deposit = Self::deposit(proxies.len() as u32);
-			if new_deposit > old_deposit {
-				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
-			} else if new_deposit < old_deposit {
-				T::Currency::unreserve(delegator, old_deposit - new_deposit);
+			if let Some((_, _, deposit)) = Constraints::<T>::take(delegator, &proxy_def) {
+				ConstraintsDepositOf::<T>::update(delegator, deposit, Footprint::default())?;
 			}
+			let new_deposit =
+				ProxyDepositOf::<T>::update(delegator, old_deposit, Self::items(proxies.len()))?;
 			if !proxies.is_empty() {
 				*x = Some((proxies, new_deposit))
 			}
@@ -725,28 +902,12 @@ impl<T: Config> Pallet<T> {
 	}
 
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
-		if num_proxies == 0 {
-			Zero::zero()
-		} else {
-			T::ProxyDepositBase::get() + T::ProxyDepositFactor::get() * num_proxies.into()
-		}
+		ProxyDepositOf::<T>::price(Self::items(num_proxies as usize))
 	}
 
-	fn rejig_deposit(
-		who: &T::AccountId,
-		old_deposit: BalanceOf<T>,
-		base: BalanceOf<T>,
-		factor: BalanceOf<T>,
-		len: usize,
-	) -> Result<Option<BalanceOf<T>>, DispatchError> {
-		let new_deposit =
-			if len == 0 { BalanceOf::<T>::zero() } else { base + factor * (len as u32).into() };
-		if new_deposit > old_deposit {
-			T::Currency::reserve(who, new_deposit - old_deposit)?;
-		} else if new_deposit < old_deposit {
-			T::Currency::unreserve(who, old_deposit - new_deposit);
-		}
-		Ok(if len == 0 { None } else { Some(new_deposit) })
+	/// The footprint of `count` proxies, announcements or constraints.
+	fn items(count: usize) -> Footprint {
+		Footprint::from_parts(count, 0)
 	}
 
 	fn edit_announcements<
@@ -760,14 +921,14 @@ impl<T: Config> Pallet<T> {
 			let orig_pending_len = pending.len();
 			pending.retain(f);
 			ensure!(orig_pending_len > pending.len(), Error::<T>::NotFound);
-			*x = Self::rejig_deposit(
+			let deposit = AnnouncementDepositOf::<T>::update(
 				delegate,
 				old_deposit,
-				T::AnnouncementDepositBase::get(),
-				T::AnnouncementDepositFactor::get(),
-				pending.len(),
-			)?
-			.map(|deposit| (pending, deposit));
+				Self::items(pending.len()),
+			)?;
+			if !pending.is_empty() {
+				*x = Some((pending, deposit));
+			}
 			Ok(())
 		})
 	}
@@ -820,6 +981,54 @@ impl<T: Config> Pallet<T> {
 	/// - `delegator`: The delegator account.
 	pub fn remove_all_proxy_delegates(delegator: &T::AccountId) {
 		let (_, old_deposit) = Proxies::<T>::take(&delegator);
-		T::Currency::unreserve(&delegator, old_deposit);
+		let _ = ProxyDepositOf::<T>::update(delegator, old_deposit, Footprint::default());
+		Self::remove_all_constraints(delegator);
+	}
+
+	/// Remove the constraints of all proxies of `delegator`, returning their deposits.
+	fn remove_all_constraints(delegator: &T::AccountId) {
+		for (_, (_, _, deposit)) in Constraints::<T>::drain_prefix(delegator) {
+			let _ = ConstraintsDepositOf::<T>::update(delegator, deposit, Footprint::default());
+		}
+	}
+
+	/// Check that the proxy `def` of `real` may make `call` under its constraints, if any, and
+	/// record the use.
+	fn use_constraints(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>,
+		call: &<T as Config>::RuntimeCall,
+	) -> DispatchResult {
+		Constraints::<T>::try_mutate(real, def, |maybe_constraints| {
+			let Some((constraints, usage, _)) = maybe_constraints else { return Ok(()) };
+			let now = T::BlockNumberProvider::current_block_number();
+
+			if let Some(expiry) = constraints.expiry {
+				ensure!(now <= expiry, Error::<T>::Expired);
+			}
+			if let Some(limit) = constraints.call_limit {
+				if now >= usage.period_start.saturating_add(limit.period) {
+					usage.period_start = now;
+					usage.calls = 0;
+				}
+				ensure!(usage.calls < limit.max_calls, Error::<T>::CallLimitExceeded);
+				usage.calls += 1;
+			}
+			if let Some(limit) = constraints.spending_limit {
+				let spent = usage.spent.saturating_add(T::CallValue::value(call));
+				ensure!(spent <= limit, Error::<T>::SpendingLimitExceeded);
+				usage.spent = spent;
+			}
+			Ok(())
+		})
+	}
+}
+
+impl<T: Config> GetInnerCalls<<T as Config>::RuntimeCall> for Call<T> {
+	fn get_inner_calls(&self) -> Vec<&<T as Config>::RuntimeCall> {
+		match self {
+			Call::proxy { call, .. } | Call::proxy_announced { call, .. } => vec![&**call],
+			_ => Vec::new(),
+		}
 	}
 }
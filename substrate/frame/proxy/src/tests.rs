@@ -120,6 +120,19 @@ impl Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CallValue = TransferValue;
+}
+
+pub struct TransferValue;
+impl ProxyCallValue<RuntimeCall, u64> for TransferValue {
+	fn value(call: &RuntimeCall) -> u64 {
+		match call {
+			RuntimeCall::Balances(BalancesCall::transfer_allow_death { value, .. }) => *value,
+			RuntimeCall::Utility(UtilityCall::batch { calls }) =>
+				calls.iter().map(Self::value).sum(),
+			_ => 0,
+		}
+	}
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -584,3 +597,127 @@ fn pure_works() {
 		);
 	});
 }
+
+#[test]
+fn proxy_constraints_are_enforced() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::set_proxy_constraints(
+				RuntimeOrigin::signed(1),
+				2,
+				ProxyType::Any,
+				0,
+				Some(ProxyConstraints { expiry: None, spending_limit: None, call_limit: None }),
+			),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		let constraints = ProxyConstraints {
+			expiry: Some(20),
+			spending_limit: Some(5),
+			call_limit: Some(CallLimit { max_calls: 3, period: 10 }),
+		};
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(constraints)
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyConstraintsSet {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				constraints: Some(constraints),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		// Spending limit, including transfers in batches.
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 3))));
+		let batch = RuntimeCall::Utility(UtilityCall::batch {
+			calls: vec![call_transfer(6, 1), call_transfer(6, 2)],
+		});
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(batch)),
+			Error::<Test>::SpendingLimitExceeded
+		);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, Box::new(call_transfer(6, 2))));
+		assert_eq!(Balances::free_balance(6), 5);
+
+		// Call limit per period.
+		let remark = Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }));
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, remark.clone()));
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, remark.clone()),
+			Error::<Test>::CallLimitExceeded
+		);
+		System::set_block_number(11);
+		assert_ok!(Proxy::proxy(RuntimeOrigin::signed(2), 1, None, remark.clone()));
+
+		// Expiry.
+		System::set_block_number(21);
+		assert_noop!(
+			Proxy::proxy(RuntimeOrigin::signed(2), 1, None, remark),
+			Error::<Test>::Expired
+		);
+	});
+}
+
+#[test]
+fn proxy_constraints_are_removed_with_their_deposit() {
+	new_test_ext().execute_with(|| {
+		let constraints =
+			Some(ProxyConstraints { expiry: Some(1), spending_limit: None, call_limit: None });
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 0));
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			constraints
+		));
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::Any,
+			0,
+			constraints
+		));
+		// Setting them again takes no further deposit.
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::Any,
+			0,
+			constraints
+		));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::Any,
+			0,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_ok!(Proxy::remove_proxy(RuntimeOrigin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_eq!(Constraints::<Test>::iter_prefix(1).count(), 0);
+
+		assert_ok!(Proxy::set_proxy_constraints(
+			RuntimeOrigin::signed(1),
+			3,
+			ProxyType::Any,
+			0,
+			constraints
+		));
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Constraints::<Test>::iter_prefix(1).count(), 0);
+	});
+}
@@ -103,6 +103,48 @@ fn insert_recovery_account<T: Config>(caller: &T::AccountId, account: &T::Accoun
 	<Recoverable<T>>::insert(&account, recovery_config);
 }
 
+/// Make `lost` recoverable by `n` friends, with a recovery by `rescuer` vouched for by all of them
+/// and in its delay period, and a guardian policy allowing every action. Returns the friends.
+fn insert_pending_recovery<T: Config>(
+	lost: &T::AccountId,
+	rescuer: &T::AccountId,
+	n: u32,
+) -> Vec<T::AccountId> {
+	T::Currency::make_free_balance_be(&lost, BalanceOf::<T>::max_value());
+
+	let friends = generate_friends::<T>(n);
+	let bounded_friends: FriendsOf<T> = friends.clone().try_into().unwrap();
+
+	// Get deposit for recovery
+	let total_deposit = get_total_deposit::<T>(&bounded_friends).unwrap();
+
+	let recovery_config = RecoveryConfig {
+		delay_period: 100u32.into(),
+		deposit: total_deposit,
+		friends: bounded_friends.clone(),
+		threshold: n as u16,
+	};
+	<Recoverable<T>>::insert(&lost, recovery_config);
+
+	let recovery_status = ActiveRecovery {
+		created: T::BlockNumberProvider::current_block_number(),
+		deposit: total_deposit,
+		friends: bounded_friends,
+	};
+	<ActiveRecoveries<T>>::insert(&lost, &rescuer, recovery_status);
+
+	let policy = GuardianPolicy {
+		allow_freeze: true,
+		rescue_account: Some(account("rescue_account", 0, SEED)),
+		spending_cap: BalanceOf::<T>::max_value(),
+		spent: Zero::zero(),
+		period_start: Zero::zero(),
+	};
+	GuardianPolicies::<T>::insert(&lost, policy);
+
+	friends
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -353,5 +395,64 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_guardian_policy() {
+		let caller: T::AccountId = whitelisted_caller();
+		let rescue_account: T::AccountId = account("rescue_account", 0, SEED);
+		let rescue_account_lookup = T::Lookup::unlookup(rescue_account);
+
+		insert_recovery_account::<T>(&caller, &caller);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			true,
+			Some(rescue_account_lookup),
+			BalanceOf::<T>::max_value(),
+		);
+		assert_last_event::<T>(Event::GuardianPolicySet { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn guardian_freeze(n: Linear<1, { T::MaxFriends::get() }>) {
+		let lost_account: T::AccountId = account("lost_account", 0, SEED);
+		let lost_account_lookup = T::Lookup::unlookup(lost_account.clone());
+		let rescuer_account: T::AccountId = account("rescuer_account", 0, SEED);
+		let rescuer_account_lookup = T::Lookup::unlookup(rescuer_account.clone());
+
+		let friends = insert_pending_recovery::<T>(&lost_account, &rescuer_account, n);
+		// The last friend is the worst case of the friend lookup.
+		let guardian = friends.last().unwrap().clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(guardian.clone()), lost_account_lookup, rescuer_account_lookup);
+		assert_last_event::<T>(Event::AccountFrozen { lost_account, guardian }.into());
+	}
+
+	#[benchmark]
+	fn guardian_transfer(n: Linear<1, { T::MaxFriends::get() }>) {
+		let lost_account: T::AccountId = account("lost_account", 0, SEED);
+		let lost_account_lookup = T::Lookup::unlookup(lost_account.clone());
+		let rescuer_account: T::AccountId = account("rescuer_account", 0, SEED);
+		let rescuer_account_lookup = T::Lookup::unlookup(rescuer_account.clone());
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+
+		let friends = insert_pending_recovery::<T>(&lost_account, &rescuer_account, n);
+		// The last friend is the worst case of the friend lookup.
+		let guardian = friends.last().unwrap().clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(guardian.clone()), lost_account_lookup, rescuer_account_lookup, amount);
+		assert_last_event::<T>(
+			Event::RescueTransferred {
+				lost_account,
+				guardian,
+				rescue_account: account("rescue_account", 0, SEED),
+				amount,
+			}
+			.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
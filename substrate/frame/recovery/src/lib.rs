@@ -73,6 +73,23 @@
 //! 10. When the recovered account becomes reaped (i.e. its free and reserved balance drops to
 //!     zero), the final recovery link is removed.
 //!
+//! ### Guardian Actions
+//!
+//! The account owner can also call `set_guardian_policy` to let their friends act as guardians of
+//! the account while a recovery is pending, i.e. once a recovery attempt has been vouched for by
+//! `threshold` friends and until its delay period has passed. Any friend can then:
+//! * freeze the account with `guardian_freeze`, if allowed by the policy. A frozen account can no
+//!   longer remove its recovery configuration or change its guardian policy. It can still close
+//!   recovery attempts, and the freeze ends once it is recovered or has closed all of them.
+//!   Runtimes can use [`Pallet::is_frozen`] to filter the calls of frozen accounts further.
+//! * move up to a spending cap of the free balance of the account to a rescue account chosen in
+//!   advance by the owner, with `guardian_transfer`. The cap applies per spending period, which is
+//!   as long as the delay period of the recovery configuration. Funds held or reserved by other
+//!   pallets (e.g. staked funds) are not affected.
+//!
+//! This protects accounts whose keys were compromised rather than lost, without giving the friends
+//! access to the account before the delay period has passed.
+//!
 //! ### Malicious Recovery Attempts
 //!
 //! Initializing a the recovery process for a recoverable account is open and
@@ -127,6 +144,10 @@
 //! #### For Friends of a Recoverable Account
 //! * `vouch_recovery` - As a `friend` of a recoverable account, vouch for a recovery attempt on the
 //!   account.
+//! * `guardian_freeze` - Freeze an account which has a vouched recovery attempt in its delay
+//!   period.
+//! * `guardian_transfer` - Move funds of an account which has a vouched recovery attempt in its
+//!   delay period to its rescue account.
 //!
 //! #### For a User Who Successfully Recovered an Account
 //!
@@ -141,6 +162,7 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `set_guardian_policy` - Choose the actions friends can take while a recovery is pending.
 //!
 //! #### For Super Users
 //!
@@ -157,15 +179,16 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		BlockNumberProvider, CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion,
-		StaticLookup,
+		BlockNumberProvider, CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, Saturating,
+		StaticLookup, Zero,
 	},
-	RuntimeDebug,
+	ArithmeticError, DispatchError, RuntimeDebug,
 };
 
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
-	traits::{BalanceStatus, Currency, ReservableCurrency},
+	ensure,
+	traits::{BalanceStatus, Currency, ExistenceRequirement, ReservableCurrency},
 	BoundedVec,
 };
 
@@ -187,6 +210,11 @@ type BalanceOf<T> =
 type BlockNumberFromProviderOf<T> =
 	<<T as Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
 type FriendsOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFriends>;
+type GuardianPolicyOf<T> = GuardianPolicy<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BlockNumberFromProviderOf<T>,
+>;
 
 /// An active recovery process.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -215,12 +243,28 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	threshold: u16,
 }
 
+/// The actions friends of a recoverable account can take while a recovery of the account is
+/// pending.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GuardianPolicy<AccountId, Balance, BlockNumber> {
+	/// Whether the account can be frozen.
+	allow_freeze: bool,
+	/// The account funds can be moved to, if any.
+	rescue_account: Option<AccountId>,
+	/// The maximum amount that can be moved to the `rescue_account` per spending period.
+	spending_cap: Balance,
+	/// The amount moved to the `rescue_account` in the current spending period.
+	spent: Balance,
+	/// The block number the current spending period started at. A new period starts with the
+	/// first transfer after the delay period of the recovery configuration has passed.
+	period_start: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -301,6 +345,17 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// The guardian policy of an account has been set.
+		GuardianPolicySet { account: T::AccountId },
+		/// Lost account has been frozen by a guardian.
+		AccountFrozen { lost_account: T::AccountId, guardian: T::AccountId },
+		/// Funds of lost account have been moved to its rescue account by a guardian.
+		RescueTransferred {
+			lost_account: T::AccountId,
+			guardian: T::AccountId,
+			rescue_account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -337,6 +392,16 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// No recovery of this account has been vouched for and is in its delay period
+		NotPending,
+		/// The guardian policy of this account does not allow this action
+		ActionNotAllowed,
+		/// This account is frozen until it is recovered
+		Frozen,
+		/// This account is already frozen
+		AlreadyFrozen,
+		/// The amount exceeds the spending cap of the guardian policy
+		SpendingCap,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -371,6 +436,19 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The actions friends of a recoverable account can take while a recovery is pending.
+	#[pallet::storage]
+	pub type GuardianPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, GuardianPolicyOf<T>>;
+
+	/// Accounts frozen by a guardian, and the block number they were frozen at.
+	///
+	/// An account stays frozen until it is recovered or has closed all its active recovery
+	/// processes.
+	#[pallet::storage]
+	pub type Frozen<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BlockNumberFromProviderOf<T>>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -617,6 +695,8 @@ pub mod pallet {
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
+			// The rescuer is now in control of the account.
+			Frozen::<T>::remove(&account);
 			Self::deposit_event(Event::<T>::AccountRecovered {
 				lost_account: account,
 				rescuer_account: who,
@@ -631,12 +711,16 @@ pub mod pallet {
 		/// the recovery deposit `RecoveryDeposit` placed by the rescuer.
 		///
 		/// The dispatch origin for this call must be _Signed_ and must be a
-		/// recoverable account with an active recovery process for it.
+		/// recoverable account with an active recovery process for it. A frozen account can
+		/// close recovery processes as well, and is unfrozen once none are left.
 		///
 		/// Parameters:
 		/// - `rescuer`: The account trying to rescue this recoverable account.
 		#[pallet::call_index(6)]
-		#[pallet::weight(T::WeightInfo::close_recovery(T::MaxFriends::get()))]
+		#[pallet::weight(
+			T::WeightInfo::close_recovery(T::MaxFriends::get())
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+		)]
 		pub fn close_recovery(
 			origin: OriginFor<T>,
 			rescuer: AccountIdLookupOf<T>,
@@ -646,6 +730,11 @@ pub mod pallet {
 			// Take the active recovery process started by the rescuer for this account.
 			let active_recovery =
 				<ActiveRecoveries<T>>::take(&who, &rescuer).ok_or(Error::<T>::NotStarted)?;
+			// A freeze only lasts while a recovery process is active.
+			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
+			if active_recoveries.next().is_none() {
+				Frozen::<T>::remove(&who);
+			}
 			// Move the reserved funds from the rescuer to the rescued account.
 			// Acts like a slashing mechanism for those who try to maliciously recover accounts.
 			let res = T::Currency::repatriate_reserved(
@@ -677,11 +766,13 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::remove_recovery(T::MaxFriends::get()))]
 		pub fn remove_recovery(origin: OriginFor<T>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			ensure!(!Frozen::<T>::contains_key(&who), Error::<T>::Frozen);
 			// Check there are no active recoveries
 			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
 			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
 			// Take the recovery configuration for this account.
 			let recovery_config = <Recoverable<T>>::take(&who).ok_or(Error::<T>::NotRecoverable)?;
+			GuardianPolicies::<T>::remove(&who);
 
 			// Unreserve the initial deposit for the recovery configuration.
 			T::Currency::unreserve(&who, recovery_config.deposit);
@@ -711,10 +802,155 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Set the actions the friends of your account can take while a recovery of your account
+		/// is pending, i.e. once it has been vouched for by `threshold` friends and until its delay
+		/// period has passed. Replaces any previous policy.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a recoverable account
+		/// (i.e. has a recovery configuration) with no active recovery process.
+		///
+		/// Parameters:
+		/// - `allow_freeze`: Whether friends can freeze your account, see `guardian_freeze`.
+		/// - `rescue_account`: The account friends can move your funds to, if any, see
+		///   `guardian_transfer`.
+		/// - `spending_cap`: The maximum amount friends can move to the `rescue_account`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_guardian_policy())]
+		pub fn set_guardian_policy(
+			origin: OriginFor<T>,
+			allow_freeze: bool,
+			rescue_account: Option<AccountIdLookupOf<T>>,
+			spending_cap: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let rescue_account = rescue_account.map(T::Lookup::lookup).transpose()?;
+			ensure!(<Recoverable<T>>::contains_key(&who), Error::<T>::NotRecoverable);
+			ensure!(!Frozen::<T>::contains_key(&who), Error::<T>::Frozen);
+			// Do not change the rules of a pending recovery.
+			let mut active_recoveries = <ActiveRecoveries<T>>::iter_prefix_values(&who);
+			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
+			let policy = GuardianPolicy {
+				allow_freeze,
+				rescue_account,
+				spending_cap,
+				spent: Zero::zero(),
+				period_start: T::BlockNumberProvider::current_block_number(),
+			};
+			GuardianPolicies::<T>::insert(&who, policy);
+			Self::deposit_event(Event::<T>::GuardianPolicySet { account: who });
+			Ok(())
+		}
+
+		/// As a friend of a recoverable account, freeze the account while a recovery of it is
+		/// pending.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a "friend" for the
+		/// recoverable account. The guardian policy of the account must allow freezing.
+		///
+		/// Parameters:
+		/// - `lost`: The lost account that you want to freeze.
+		/// - `rescuer`: The account trying to rescue the lost account. Its recovery process must
+		///   have been vouched for by `threshold` friends and be in its delay period.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::guardian_freeze(T::MaxFriends::get()))]
+		pub fn guardian_freeze(
+			origin: OriginFor<T>,
+			lost: AccountIdLookupOf<T>,
+			rescuer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost = T::Lookup::lookup(lost)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			let policy = Self::guardian_policy_of_pending(&who, &lost, &rescuer)?;
+			ensure!(policy.allow_freeze, Error::<T>::ActionNotAllowed);
+			ensure!(!Frozen::<T>::contains_key(&lost), Error::<T>::AlreadyFrozen);
+			Frozen::<T>::insert(&lost, T::BlockNumberProvider::current_block_number());
+			Self::deposit_event(Event::<T>::AccountFrozen { lost_account: lost, guardian: who });
+			Ok(())
+		}
+
+		/// As a friend of a recoverable account, move free funds of the account to its rescue
+		/// account while a recovery of it is pending.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a "friend" for the
+		/// recoverable account. The guardian policy of the account must have a rescue account,
+		/// and the total amount moved in a spending period must not exceed its spending cap. A
+		/// spending period is as long as the delay period of the recovery configuration.
+		///
+		/// Parameters:
+		/// - `lost`: The lost account that you want to move funds from.
+		/// - `rescuer`: The account trying to rescue the lost account. Its recovery process must
+		///   have been vouched for by `threshold` friends and be in its delay period.
+		/// - `amount`: The amount to move. The lost account is kept alive.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::guardian_transfer(T::MaxFriends::get()))]
+		pub fn guardian_transfer(
+			origin: OriginFor<T>,
+			lost: AccountIdLookupOf<T>,
+			rescuer: AccountIdLookupOf<T>,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lost = T::Lookup::lookup(lost)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			let mut policy = Self::guardian_policy_of_pending(&who, &lost, &rescuer)?;
+			let rescue_account =
+				policy.rescue_account.clone().ok_or(Error::<T>::ActionNotAllowed)?;
+			let delay_period = Self::recovery_config(&lost)
+				.map(|config| config.delay_period)
+				.ok_or(Error::<T>::NotRecoverable)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			if policy.period_start.saturating_add(delay_period) <= now {
+				policy.spent = Zero::zero();
+				policy.period_start = now;
+			}
+			policy.spent = policy.spent.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+			ensure!(policy.spent <= policy.spending_cap, Error::<T>::SpendingCap);
+			T::Currency::transfer(&lost, &rescue_account, amount, ExistenceRequirement::KeepAlive)?;
+			GuardianPolicies::<T>::insert(&lost, policy);
+			Self::deposit_event(Event::<T>::RescueTransferred {
+				lost_account: lost,
+				guardian: who,
+				rescue_account,
+				amount,
+			});
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Whether `who` has been frozen by a guardian and not recovered since.
+	pub fn is_frozen(who: &T::AccountId) -> bool {
+		Frozen::<T>::contains_key(who)
+	}
+
+	/// Get the guardian policy of `lost`, if `guardian` is a friend of `lost` and the recovery of
+	/// `lost` by `rescuer` has been vouched for by `threshold` friends and is in its delay period.
+	fn guardian_policy_of_pending(
+		guardian: &T::AccountId,
+		lost: &T::AccountId,
+		rescuer: &T::AccountId,
+	) -> Result<GuardianPolicyOf<T>, DispatchError> {
+		let recovery_config = Self::recovery_config(lost).ok_or(Error::<T>::NotRecoverable)?;
+		ensure!(Self::is_friend(&recovery_config.friends, guardian), Error::<T>::NotFriend);
+		let active_recovery = Self::active_recovery(lost, rescuer).ok_or(Error::<T>::NotStarted)?;
+		ensure!(
+			recovery_config.threshold as usize <= active_recovery.friends.len(),
+			Error::<T>::Threshold
+		);
+		let recoverable_block_number = active_recovery
+			.created
+			.checked_add(&recovery_config.delay_period)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			T::BlockNumberProvider::current_block_number() < recoverable_block_number,
+			Error::<T>::NotPending
+		);
+		GuardianPolicies::<T>::get(lost).ok_or_else(|| Error::<T>::ActionNotAllowed.into())
+	}
+
 	/// Check that friends list is sorted and has no duplicates.
 	fn is_sorted_and_unique(friends: &Vec<T::AccountId>) -> bool {
 		friends.windows(2).all(|w| w[0] < w[1])
@@ -495,3 +495,128 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
 	});
 }
+
+#[test]
+fn set_guardian_policy_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		// Cannot set a policy for an unrecoverable account
+		assert_noop!(
+			Recovery::set_guardian_policy(RuntimeOrigin::signed(5), true, None, 0),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		// Guardians cannot act without a policy
+		assert_noop!(
+			Recovery::guardian_freeze(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::ActionNotAllowed
+		);
+		// The policy cannot be changed while a recovery is active
+		assert_noop!(
+			Recovery::set_guardian_policy(RuntimeOrigin::signed(5), true, None, 0),
+			Error::<Test>::StillActive
+		);
+	});
+}
+
+#[test]
+fn guardian_actions_work() {
+	new_test_ext().execute_with(|| {
+		let rescue_account = 6;
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_ok!(Recovery::set_guardian_policy(
+			RuntimeOrigin::signed(5),
+			true,
+			Some(rescue_account),
+			50
+		));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		// Guardians can only act once the recovery is vouched for by `threshold` friends
+		assert_noop!(
+			Recovery::guardian_freeze(RuntimeOrigin::signed(2), 5, 1),
+			Error::<Test>::Threshold
+		);
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		// Only friends are guardians
+		assert_noop!(
+			Recovery::guardian_freeze(RuntimeOrigin::signed(1), 5, 1),
+			Error::<Test>::NotFriend
+		);
+
+		// A guardian freezes the lost account
+		assert_ok!(Recovery::guardian_freeze(RuntimeOrigin::signed(2), 5, 1));
+		assert!(Recovery::is_frozen(&5));
+		assert_noop!(
+			Recovery::guardian_freeze(RuntimeOrigin::signed(3), 5, 1),
+			Error::<Test>::AlreadyFrozen
+		);
+		// The lost account can no longer remove its recovery configuration
+		assert_noop!(Recovery::remove_recovery(RuntimeOrigin::signed(5)), Error::<Test>::Frozen);
+
+		// A guardian moves funds to the rescue account, up to the spending cap
+		assert_ok!(Recovery::guardian_transfer(RuntimeOrigin::signed(3), 5, 1, 30));
+		assert_eq!(Balances::free_balance(rescue_account), 30);
+		assert_noop!(
+			Recovery::guardian_transfer(RuntimeOrigin::signed(4), 5, 1, 30),
+			Error::<Test>::SpendingCap
+		);
+		assert_ok!(Recovery::guardian_transfer(RuntimeOrigin::signed(4), 5, 1, 20));
+		assert_eq!(Balances::free_balance(rescue_account), 50);
+
+		// Guardians cannot act after the delay period
+		run_to_block(11);
+		assert_noop!(
+			Recovery::guardian_transfer(RuntimeOrigin::signed(4), 5, 1, 0),
+			Error::<Test>::NotPending
+		);
+		// Recovering the account unfreezes it
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		assert!(!Recovery::is_frozen(&5));
+	});
+}
+
+#[test]
+fn frozen_account_can_close_recovery() {
+	new_test_ext().execute_with(|| {
+		let rescue_account = 6;
+		assert_ok!(Recovery::create_recovery(RuntimeOrigin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_ok!(Recovery::set_guardian_policy(
+			RuntimeOrigin::signed(5),
+			true,
+			Some(rescue_account),
+			50
+		));
+		let vouch_all = || {
+			assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+			assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+			assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+			assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		};
+		vouch_all();
+		assert_ok!(Recovery::guardian_freeze(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::guardian_transfer(RuntimeOrigin::signed(3), 5, 1, 50));
+
+		// The lost account can still fight off the recovery, which ends the freeze
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert!(!Recovery::is_frozen(&5));
+
+		// The spending cap is used up for the current spending period
+		vouch_all();
+		assert_noop!(
+			Recovery::guardian_transfer(RuntimeOrigin::signed(3), 5, 1, 10),
+			Error::<Test>::SpendingCap
+		);
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+
+		// and is available again in the next one
+		run_to_block(11);
+		vouch_all();
+		assert_ok!(Recovery::guardian_transfer(RuntimeOrigin::signed(3), 5, 1, 30));
+		assert_eq!(Balances::free_balance(rescue_account), 80);
+	});
+}
@@ -91,6 +91,7 @@ use sp_runtime::{
 
 mod branch;
 pub mod migration;
+pub mod runtime_api;
 mod types;
 pub mod weights;
 
@@ -99,9 +100,10 @@ pub use self::{
 	pallet::*,
 	types::{
 		BalanceOf, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit,
-		InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex, ReferendumInfo,
-		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
-		TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
+		EnactmentPreview, ExpandCall, InsertSorted, NegativeImbalanceOf, NestedCall,
+		PalletsOriginOf, ReferendumIndex, ReferendumInfo, ReferendumInfoOf, ReferendumStatus,
+		ReferendumStatusOf, ScheduleAddressOf, TallyOf, TrackIdOf, TrackInfo, TrackInfoOf,
+		TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -140,6 +142,9 @@ macro_rules! impl_tracksinfo_get {
 
 const ASSEMBLY_ID: LockIdentifier = *b"assembly";
 
+/// The maximum depth of nested calls resolved by [`Pallet::enactment_preview`].
+const MAX_PREVIEW_DEPTH: u32 = 8;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -885,6 +890,62 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// Preview what referendum `index` will enact and when, if it is deciding and passing.
+	///
+	/// The calls dispatched by the proposal are resolved with `E`, looking up hashed calls in the
+	/// preimages, up to a depth of `MAX_PREVIEW_DEPTH`.
+	pub fn enactment_preview<E: ExpandCall<CallOf<T, I>, T::Hash>>(
+		index: ReferendumIndex,
+	) -> Option<EnactmentPreview<CallOf<T, I>, T::Hash, BlockNumberFor<T>>> {
+		let status = Self::ensure_ongoing(index).ok()?;
+		let deciding = status.deciding.as_ref()?;
+		if !Self::is_referendum_passing(index).ok()? {
+			return None
+		}
+		let track = Self::track(status.track)?;
+
+		// The referendum is approved once confirmed and enacted no earlier than the minimum
+		// enactment period after that.
+		let now = frame_system::Pallet::<T>::block_number();
+		let confirmed =
+			deciding.confirming.unwrap_or_else(|| now.saturating_add(track.confirm_period));
+		let approved = Self::alarm_time(confirmed);
+		let earliest_allowed = approved.saturating_add(track.min_enactment_period.max(One::one()));
+		let when = status.enactment.evaluate(approved).max(earliest_allowed);
+
+		let mut preview =
+			EnactmentPreview { when, calls: Vec::new(), missing_preimages: Vec::new() };
+		match T::Preimages::peek(&status.proposal) {
+			Ok((call, _)) => Self::preview_call::<E>(call, 0, &mut preview),
+			Err(_) => preview.missing_preimages.push(status.proposal.hash()),
+		}
+		Some(preview)
+	}
+
+	/// Add `call` at `depth` and the calls it dispatches to `preview`.
+	fn preview_call<E: ExpandCall<CallOf<T, I>, T::Hash>>(
+		call: CallOf<T, I>,
+		depth: u32,
+		preview: &mut EnactmentPreview<CallOf<T, I>, T::Hash, BlockNumberFor<T>>,
+	) {
+		let nested = if depth < MAX_PREVIEW_DEPTH { E::nested_calls(&call) } else { Vec::new() };
+		preview.calls.push((depth, call));
+		for nested_call in nested {
+			let call = match nested_call {
+				NestedCall::Inline(call) => call,
+				NestedCall::Hashed { hash, len } =>
+					match T::Preimages::peek(&BoundedCallOf::<T, I>::Lookup { hash, len }) {
+						Ok((call, _)) => call,
+						Err(_) => {
+							preview.missing_preimages.push(hash);
+							continue
+						},
+					},
+			};
+			Self::preview_call::<E>(call, depth.saturating_add(1), preview);
+		}
+	}
+
 	// Enqueue a proposal from a referendum which has presumably passed.
 	fn schedule_enactment(
 		index: ReferendumIndex,
@@ -914,12 +975,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		call: BoundedCallOf<T, I>,
 		when: BlockNumberFor<T>,
 	) -> Option<(BlockNumberFor<T>, ScheduleAddressOf<T, I>)> {
-		let alarm_interval = T::AlarmInterval::get().max(One::one());
-		// Alarm must go off no earlier than `when`.
-		// This rounds `when` upwards to the next multiple of `alarm_interval`.
-		let when = (when.saturating_add(alarm_interval.saturating_sub(One::one())) /
-			alarm_interval)
-			.saturating_mul(alarm_interval);
+		let when = Self::alarm_time(when);
 		let result = T::Scheduler::schedule(
 			DispatchTime::At(when),
 			None,
@@ -937,6 +993,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		result.ok().map(|x| (when, x))
 	}
 
+	/// The block at which an alarm for `when` goes off.
+	fn alarm_time(when: BlockNumberFor<T>) -> BlockNumberFor<T> {
+		let alarm_interval = T::AlarmInterval::get().max(One::one());
+		// Alarm must go off no earlier than `when`.
+		// This rounds `when` upwards to the next multiple of `alarm_interval`.
+		(when.saturating_add(alarm_interval.saturating_sub(One::one())) / alarm_interval)
+			.saturating_mul(alarm_interval)
+	}
+
 	/// Mutate a referendum's `status` into the correct deciding state.
 	///
 	/// - `now` is the current block number.
@@ -0,0 +1,36 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME referenda pallet.
+
+use crate::{EnactmentPreview, ReferendumIndex};
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ReferendaApi<Call, Hash, BlockNumber>
+	where
+		Call: Codec,
+		Hash: Codec,
+		BlockNumber: Codec
+	{
+		/// The calls a deciding and passing referendum will enact and the block at which they
+		/// are projected to be dispatched. `None` if the referendum is not deciding and passing.
+		fn enactment_preview(
+			index: ReferendumIndex,
+		) -> Option<EnactmentPreview<Call, Hash, BlockNumber>>;
+	}
+}
@@ -23,7 +23,10 @@ use assert_matches::assert_matches;
 use codec::Decode;
 use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, traits::Contains};
 use pallet_balances::Error as BalancesError;
-use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError::BadOrigin,
+};
 
 #[test]
 fn params_should_work() {
@@ -706,3 +709,76 @@ fn zero_enactment_delay_executes_proposal_at_next_block() {
 		assert_eq!(Balances::free_balance(42), 20);
 	});
 }
+
+/// Expands remarks holding an encoded call into that call and remarks with event holding a
+/// `(hash, len)` into the call with that hash, standing in for batches and whitelisted calls.
+struct RemarkExpander;
+impl ExpandCall<RuntimeCall, <Test as frame_system::Config>::Hash> for RemarkExpander {
+	fn nested_calls(
+		call: &RuntimeCall,
+	) -> Vec<NestedCall<RuntimeCall, <Test as frame_system::Config>::Hash>> {
+		match call {
+			RuntimeCall::System(frame_system::Call::remark { remark }) =>
+				RuntimeCall::decode(&mut &remark[..]).map(NestedCall::Inline).into_iter().collect(),
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark }) =>
+				Decode::decode(&mut &remark[..])
+					.map(|(hash, len)| NestedCall::Hashed { hash, len })
+					.into_iter()
+					.collect(),
+			_ => Vec::new(),
+		}
+	}
+}
+
+#[test]
+fn enactment_preview_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let set_balance = RuntimeCall::decode(&mut &set_balance_proposal(1)[..]).unwrap();
+		let set_balance_hash = BlakeTwo256::hash(&set_balance_proposal(1));
+		let set_balance_len = set_balance_proposal(1).len() as u32;
+		let whitelisted = RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: (set_balance_hash, set_balance_len).encode(),
+		});
+		let proposal =
+			RuntimeCall::System(frame_system::Call::remark { remark: whitelisted.encode() });
+
+		assert_ok!(Referenda::submit(
+			RuntimeOrigin::signed(1),
+			Box::new(RawOrigin::Root.into()),
+			Preimage::bound(proposal.clone()).unwrap(),
+			DispatchTime::At(10),
+		));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		// Not deciding yet.
+		assert_eq!(Referenda::enactment_preview::<RemarkExpander>(0), None);
+
+		run_to(6);
+		set_tally(0, 100, 0);
+		run_to(7);
+		assert_eq!(confirming_until(0), 9);
+
+		// Approved at block 9 and enacted after the minimum enactment period of 4 blocks.
+		assert_eq!(
+			Referenda::enactment_preview::<RemarkExpander>(0),
+			Some(EnactmentPreview {
+				when: 13,
+				calls: vec![(0, proposal.clone()), (1, whitelisted.clone())],
+				missing_preimages: vec![set_balance_hash],
+			})
+		);
+
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(2), set_balance_proposal(1)));
+		assert_eq!(
+			Referenda::enactment_preview::<RemarkExpander>(0),
+			Some(EnactmentPreview {
+				when: 13,
+				calls: vec![(0, proposal), (1, whitelisted), (2, set_balance)],
+				missing_preimages: vec![],
+			})
+		);
+
+		// Failing referenda have no preview.
+		set_tally(0, 0, 100);
+		assert_eq!(Referenda::enactment_preview::<RemarkExpander>(0), None);
+	});
+}
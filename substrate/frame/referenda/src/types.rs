@@ -759,3 +759,40 @@ mod tests {
 		);
 	}
 }
+
+/// A call dispatched by another call, as reported by [`ExpandCall`].
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum NestedCall<Call, Hash> {
+	/// The call is part of the outer call.
+	Inline(Call),
+	/// Only the hash and encoded length of the call are part of the outer call. The call itself
+	/// is looked up in the preimages.
+	Hashed { hash: Hash, len: u32 },
+}
+
+/// Expands a call into the calls it dispatches, such as the calls of a batch or the call behind a
+/// whitelisted call hash. Used to preview what a referendum will enact.
+pub trait ExpandCall<Call, Hash> {
+	/// The calls dispatched by `call`, in dispatch order. Empty if `call` does not dispatch other
+	/// calls.
+	fn nested_calls(call: &Call) -> Vec<NestedCall<Call, Hash>>;
+}
+
+impl<Call, Hash> ExpandCall<Call, Hash> for () {
+	fn nested_calls(_: &Call) -> Vec<NestedCall<Call, Hash>> {
+		Vec::new()
+	}
+}
+
+/// What a passing referendum will enact and when.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct EnactmentPreview<Call, Hash, BlockNumber> {
+	/// The block at which the proposal is projected to be dispatched, if the referendum keeps
+	/// passing.
+	pub when: BlockNumber,
+	/// The calls that will be dispatched as `(depth, call)`, with the proposal at depth 0. Each
+	/// call is directly followed by the calls it dispatches.
+	pub calls: Vec<(u32, Call)>,
+	/// The hashes of calls which could not be previewed because their preimage is not available.
+	pub missing_preimages: Vec<Hash>,
+}
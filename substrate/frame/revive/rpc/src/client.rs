@@ -19,7 +19,14 @@
 use crate::{
 	runtime::GAS_PRICE,
 	subxt_client::{
-		revive::{calls::types::EthTransact, events::ContractEmitted},
+		balances::events::Transfer,
+		revive::{
+			calls::types::EthTransact,
+			events::{
+				ContractEmitted, StorageDepositTransferredAndHeld,
+				StorageDepositTransferredAndReleased,
+			},
+		},
 		runtime_types::pallet_revive::storage::ContractInfo,
 	},
 	LOG_TARGET,
@@ -29,15 +36,16 @@ use jsonrpsee::types::{error::CALL_EXECUTION_FAILED_CODE, ErrorObjectOwned};
 use pallet_revive::{
 	create1,
 	evm::{
-		Block, BlockNumberOrTag, BlockNumberOrTagOrHash, Bytes256, GenericTransaction, Log,
-		ReceiptInfo, SyncingProgress, SyncingStatus, TransactionSigned, H160, H256, U256,
+		Block, BlockNumberOrTag, BlockNumberOrTagOrHash, BlockTag, Bytes256, Filter,
+		GenericTransaction, Log, PolkadotReceiptExtra, ReceiptInfo, RuntimeTransfer,
+		StorageDepositCharge, SyncingProgress, SyncingStatus, TransactionSigned, H160, H256, U256,
 	},
 	EthTransactError, EthTransactInfo,
 };
 use sp_core::keccak_256;
 use sp_weights::Weight;
 use std::{
-	collections::{HashMap, VecDeque},
+	collections::{BTreeMap, HashMap, VecDeque},
 	sync::Arc,
 	time::Duration,
 };
@@ -49,6 +57,7 @@ use subxt::{
 			RpcClient,
 		},
 	},
+	blocks::ExtrinsicEvents,
 	config::Header,
 	error::RpcError,
 	storage::Storage,
@@ -96,6 +105,10 @@ struct BlockCache<const N: usize> {
 
 	/// A map of receipt hashes by block hash.
 	tx_hashes_by_block_and_index: HashMap<H256, HashMap<U256, H256>>,
+
+	/// The logs bloom and hash of the last [`LOGS_BLOOM_INDEX_SIZE`] blocks by block number.
+	/// Unlike the other entries, it is not pruned together with the blocks.
+	logs_bloom_by_number: BTreeMap<SubstrateBlockNumber, (SubstrateBlockHash, Bytes256)>,
 }
 
 /// Unwrap the original `jsonrpsee::core::client::Error::Call` error.
@@ -185,6 +198,9 @@ pub enum ClientError {
 	/// The cache is empty.
 	#[error("cache is empty")]
 	CacheEmpty,
+	/// The block range of a logs query is too large.
+	#[error("block range exceeds the maximum of {} blocks", MAX_LOGS_BLOCK_RANGE)]
+	BlockRangeTooLarge,
 }
 
 const REVERT_CODE: i32 = 3;
@@ -218,6 +234,12 @@ impl From<ClientError> for ErrorObjectOwned {
 /// For each block in the cache, we also store the EVM transaction receipts.
 pub const CACHE_SIZE: usize = 256;
 
+/// The number of recent blocks for which the cache keeps the logs bloom.
+pub const LOGS_BLOOM_INDEX_SIZE: usize = 65_536;
+
+/// The maximum number of blocks a single logs query can span.
+pub const MAX_LOGS_BLOCK_RANGE: SubstrateBlockNumber = 10_000;
+
 impl<const N: usize> BlockCache<N> {
 	fn latest_block(&self) -> Option<&Arc<SubstrateBlock>> {
 		self.buffer.back()
@@ -244,6 +266,19 @@ impl<const N: usize> BlockCache<N> {
 		self.blocks_by_number.insert(block.number(), block.clone());
 		self.blocks_by_hash.insert(block.hash(), block);
 	}
+
+	/// Index the logs bloom of a block, and prune the oldest entry if the index is full.
+	fn insert_logs_bloom(
+		&mut self,
+		number: SubstrateBlockNumber,
+		hash: SubstrateBlockHash,
+		bloom: Bytes256,
+	) {
+		self.logs_bloom_by_number.insert(number, (hash, bloom));
+		if self.logs_bloom_by_number.len() > LOGS_BLOOM_INDEX_SIZE {
+			self.logs_bloom_by_number.pop_first();
+		}
+	}
 }
 
 /// A client connect to a node and maintains a cache of the last `CACHE_SIZE` blocks.
@@ -344,9 +379,10 @@ impl ClientInner {
 						})
 					}).collect();
 
+				let polkadot_extra = extract_receipt_extra(&events);
 
 				log::debug!(target: LOG_TARGET, "Adding receipt for tx hash: {transaction_hash:?} - block: {block_number:?}");
-				let receipt = ReceiptInfo::new(
+				let mut receipt = ReceiptInfo::new(
 					block_hash,
 					block_number,
 					contract_address,
@@ -360,6 +396,7 @@ impl ClientInner {
 					transaction_index.into(),
 					tx_info.r#type.unwrap_or_default()
 				);
+				receipt.polkadot_extra = Some(polkadot_extra);
 
 				Ok::<_, ClientError>((receipt.transaction_hash, (signed_tx, receipt)))
 			})
@@ -369,6 +406,18 @@ impl ClientInner {
 			.into_iter()
 			.collect::<Result<HashMap<_, _>, _>>()
 	}
+
+	/// Fetch the logs bloom of the given block from the node.
+	///
+	/// Returns `None` if no logs bloom is stored for the block. This is the case if the runtime
+	/// does not keep one, but also if the block emitted no events, since the storage item is
+	/// cleared at the start of every block.
+	async fn fetch_logs_bloom(&self, hash: SubstrateBlockHash) -> Option<Bytes256> {
+		// A dynamic query, since the checked-in metadata predates the storage item.
+		let query = subxt::dynamic::storage("Revive", "BlockLogsBloom", ());
+		let value = self.api.storage().at(hash).fetch(&query).await.ok()??;
+		value.as_type::<[u8; 256]>().ok().map(Bytes256)
+	}
 }
 
 /// Fetch the chain ID from the substrate chain.
@@ -385,6 +434,40 @@ async fn max_block_weight(api: &OnlineClient<SrcChainConfig>) -> Result<Weight,
 	Ok(max_block.0)
 }
 
+/// Extract the runtime-level effects of a transaction from its events.
+fn extract_receipt_extra(events: &ExtrinsicEvents<SrcChainConfig>) -> PolkadotReceiptExtra {
+	// The address of an account, as mapped by `pallet_revive::AccountId32Mapper`.
+	let to_address = |account: &subxt::utils::AccountId32| H160::from_slice(&account.0[..20]);
+
+	let mut extra = PolkadotReceiptExtra::default();
+	for event in events.iter().filter_map(|event| event.ok()) {
+		if let Ok(Some(transfer)) = event.as_event::<Transfer>() {
+			extra.transfers.push(RuntimeTransfer {
+				from: to_address(&transfer.from),
+				to: to_address(&transfer.to),
+				amount: transfer.amount.into(),
+			});
+		} else if let Ok(Some(held)) = event.as_event::<StorageDepositTransferredAndHeld>() {
+			extra.storage_deposits.push(StorageDepositCharge {
+				from: held.from,
+				to: held.to,
+				amount: held.amount.into(),
+				released: false,
+			});
+		} else if let Ok(Some(released)) =
+			event.as_event::<StorageDepositTransferredAndReleased>()
+		{
+			extra.storage_deposits.push(StorageDepositCharge {
+				from: released.from,
+				to: released.to,
+				amount: released.amount.into(),
+				released: true,
+			});
+		}
+	}
+	extra
+}
+
 /// Extract the block timestamp.
 async fn extract_block_timestamp(block: &SubstrateBlock) -> Option<u64> {
 	let extrinsics = block.extrinsics().await.ok()?;
@@ -495,6 +578,7 @@ impl Client {
 			};
 
 			log::trace!(target: LOG_TARGET, "Pushing block: {}", block.number());
+			let logs_bloom = inner.fetch_logs_bloom(block.hash()).await;
 			let mut cache = inner.cache.write().await;
 
 			let receipts = inner
@@ -522,6 +606,10 @@ impl Client {
 				)
 			}
 
+			if let Some(logs_bloom) = logs_bloom {
+				cache.insert_logs_bloom(block.number(), block.hash(), logs_bloom);
+			}
+
 			cache.insert(block);
 			tx.send_replace(());
 		}
@@ -767,6 +855,109 @@ impl Client {
 		self.block_by_hash(&hash).await
 	}
 
+	/// Get the logs bloom of the given block from the index, or from the node if it is not
+	/// indexed.
+	///
+	/// Returns `None` if no logs bloom is stored for the block.
+	async fn logs_bloom(
+		&self,
+		number: SubstrateBlockNumber,
+		hash: SubstrateBlockHash,
+	) -> Option<Bytes256> {
+		let cache = self.inner.cache.read().await;
+		if let Some((indexed_hash, bloom)) = cache.logs_bloom_by_number.get(&number) {
+			if *indexed_hash == hash {
+				return Some(bloom.clone());
+			}
+		}
+		drop(cache);
+
+		self.inner.fetch_logs_bloom(hash).await
+	}
+
+	/// Get the logs matching the given filter.
+	///
+	/// Blocks whose logs bloom does not match the filter are skipped without fetching their
+	/// events. Blocks without a stored logs bloom have their receipts scanned. A query can span
+	/// at most [`MAX_LOGS_BLOCK_RANGE`] blocks.
+	pub async fn logs(&self, filter: Option<Filter>) -> Result<Vec<Log>, ClientError> {
+		let filter = filter.unwrap_or_default();
+		if let Some(hash) = filter.block_hash {
+			let block = self.block_by_hash(&hash).await?.ok_or(ClientError::BlockNotFound)?;
+			return self.block_logs(&filter, &block).await;
+		}
+
+		let latest = self.block_number().await?;
+		let block_number = |block: &Option<BlockNumberOrTag>| match block {
+			Some(BlockNumberOrTag::U256(n)) =>
+				(*n).try_into().map_err(|_| ClientError::ConversionFailed),
+			Some(BlockNumberOrTag::BlockTag(BlockTag::Earliest)) => Ok(0),
+			_ => Ok(latest),
+		};
+		let from = block_number(&filter.from_block)?;
+		let to = block_number(&filter.to_block)?;
+		if to.saturating_sub(from) >= MAX_LOGS_BLOCK_RANGE {
+			return Err(ClientError::BlockRangeTooLarge);
+		}
+
+		let mut logs = Vec::new();
+		for number in from..=to {
+			let Some(hash) = self.get_block_hash(number).await? else {
+				continue;
+			};
+
+			let bloom = self.logs_bloom(number, hash).await;
+			if bloom.is_some_and(|bloom| !filter.matches_bloom(&bloom)) {
+				continue;
+			}
+
+			if let Some(block) = self.block_by_hash(&hash).await? {
+				logs.extend(self.block_logs(&filter, &block).await?);
+			}
+		}
+
+		Ok(logs)
+	}
+
+	/// Get the logs of the given block that match the address and topics of the filter.
+	async fn block_logs(
+		&self,
+		filter: &Filter,
+		block: &SubstrateBlock,
+	) -> Result<Vec<Log>, ClientError> {
+		let cached_receipts = {
+			let cache = self.inner.cache.read().await;
+			cache.blocks_by_hash.contains_key(&block.hash()).then(|| {
+				cache
+					.tx_hashes_by_block_and_index
+					.get(&block.hash())
+					.into_iter()
+					.flat_map(|hashes| hashes.values())
+					.filter_map(|hash| cache.receipts_by_hash.get(hash).cloned())
+					.collect::<Vec<_>>()
+			})
+		};
+
+		let receipts = match cached_receipts {
+			Some(receipts) => receipts,
+			None => self
+				.inner
+				.receipt_infos(block)
+				.await?
+				.into_values()
+				.map(|(_, receipt)| receipt)
+				.collect(),
+		};
+
+		let mut logs = receipts
+			.into_iter()
+			.flat_map(|receipt| receipt.logs)
+			.filter(|log| filter.matches_log(log))
+			.collect::<Vec<_>>();
+		logs.sort_by_key(|log| log.log_index);
+		Ok(logs)
+	}
+
 	/// Get the EVM block for the given hash.
 	pub async fn evm_block(&self, block: Arc<SubstrateBlock>) -> Result<Block, ClientError> {
 		let runtime_api = self.inner.api.runtime_api().at(block.hash());
@@ -775,6 +966,7 @@ impl Client {
 
 		let header = block.header();
 		let timestamp = extract_block_timestamp(&block).await.unwrap_or_default();
+		let logs_bloom = self.logs_bloom(block.number(), block.hash()).await.unwrap_or_default();
 
 		// TODO: remove once subxt is updated
 		let parent_hash = header.parent_hash.0.into();
@@ -790,7 +982,7 @@ impl Client {
 			timestamp: timestamp.into(),
 			difficulty: Some(0u32.into()),
 			gas_limit,
-			logs_bloom: Bytes256([0u8; 256]),
+			logs_bloom,
 			receipts_root: extrinsics_root,
 			..Default::default()
 		})
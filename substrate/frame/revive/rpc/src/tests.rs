@@ -26,7 +26,7 @@ use ethabi::Token;
 use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use pallet_revive::{
 	create1,
-	evm::{Account, BlockTag, U256},
+	evm::{Account, BlockTag, Filter, FilterResults, U256},
 };
 use static_init::dynamic;
 use std::thread;
@@ -257,6 +257,16 @@ async fn event_logs() -> anyhow::Result<()> {
 		.send_and_wait_for_receipt(&client)
 		.await?;
 	assert_eq!(receipt.logs.len(), 1, "There should be one log.");
+
+	let logs = client
+		.get_logs(Some(Filter {
+			address: Some(receipt.logs[0].address.into()),
+			from_block: Some(receipt.block_number.into()),
+			to_block: Some(receipt.block_number.into()),
+			..Default::default()
+		}))
+		.await?;
+	assert_eq!(logs, FilterResults::Logs(receipt.logs), "The logs should match the receipt.");
 	Ok(())
 }
 
@@ -26,7 +26,7 @@ use crate::{
 	evm::runtime::GAS_PRICE,
 	exec::{Key, MomentOf},
 	limits,
-	storage::WriteOutcome,
+	storage::{ExecutionPolicy, WriteOutcome},
 	Pallet as Contracts, *,
 };
 use alloc::{vec, vec::Vec};
@@ -40,7 +40,10 @@ use frame_support::{
 };
 use frame_system::RawOrigin;
 use pallet_revive_uapi::{CallFlags, ReturnErrorCode, StorageFlags};
-use sp_runtime::traits::{Bounded, Hash};
+use sp_runtime::{
+	traits::{Bounded, Hash},
+	SaturatedConversion,
+};
 
 /// How many runs we do per API benchmark.
 ///
@@ -1963,6 +1966,82 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark(pov_mode = Measured)]
+	fn seal_set_execution_policy() {
+		build_runtime!(runtime, memory: []);
+
+		let result;
+		#[block]
+		{
+			result = runtime.bench_set_execution_policy(memory.as_mut_slice(), 1, 1);
+		}
+
+		assert_ok!(result);
+		assert_eq!(
+			ExecutionPolicyOf::<T>::get(runtime.ext().address()),
+			ExecutionPolicy { max_call_depth: Some(1), deny_reentry: true }
+		);
+	}
+
+	// `n`: Size of the input data in bytes.
+	#[benchmark(pov_mode = Measured)]
+	fn seal_schedule_call(n: Linear<0, { limits::PAYLOAD_BYTES }>) -> Result<(), BenchmarkError> {
+		if T::MaxScheduledCalls::get() == 0 {
+			return Err(BenchmarkError::Weightless);
+		}
+		let deposit_bytes = Into::<U256>::into(default_deposit_limit::<T>()).encode();
+		let input_bytes = vec![42u8; n as usize];
+		let when = frame_system::Pallet::<T>::block_number().saturated_into::<u64>() + 1;
+		build_runtime!(runtime, contract, memory: [deposit_bytes, input_bytes,]);
+		contract.set_balance(caller_funding::<T>());
+
+		let result;
+		#[block]
+		{
+			result = runtime.bench_schedule_call(
+				memory.as_mut_slice(),
+				0,    // id
+				when, // when
+				0,    // period
+				1,    // count
+				0,    // ref_time_limit
+				0,    // proof_size_limit
+				0,    // deposit_ptr
+				32,   // input_data_ptr
+				n,    // input_data_len
+			);
+		}
+
+		assert_ok!(result);
+		assert!(ScheduledCalls::<T>::contains_key(contract.address, 0));
+		Ok(())
+	}
+
+	#[benchmark(pov_mode = Measured)]
+	fn seal_cancel_scheduled_call() -> Result<(), BenchmarkError> {
+		if T::MaxScheduledCalls::get() == 0 {
+			return Err(BenchmarkError::Weightless);
+		}
+		let when = frame_system::Pallet::<T>::block_number().saturated_into::<u64>() + 1;
+		build_runtime!(runtime, contract, memory: []);
+		contract.set_balance(caller_funding::<T>());
+		let deposit_limit = default_deposit_limit::<T>().into();
+		runtime
+			.ext()
+			.schedule_call(0, when, 0, 1, Weight::zero(), deposit_limit, vec![])
+			.map_err(|_| "Failed to schedule call")?;
+
+		let result;
+		#[block]
+		{
+			result = runtime.bench_cancel_scheduled_call(memory.as_mut_slice(), 0);
+		}
+
+		assert_ok!(result);
+		assert!(!ScheduledCalls::<T>::contains_key(contract.address, 0));
+		Ok(())
+	}
+
 	// Benchmark the execution of instructions.
 	#[benchmark(pov_mode = Ignored)]
 	fn instr(r: Linear<0, INSTR_BENCHMARK_RUNS>) {
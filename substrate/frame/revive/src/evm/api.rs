@@ -26,6 +26,8 @@ mod type_id;
 pub use type_id::*;
 
 mod rpc_types;
+pub use rpc_types::*;
+
 mod rpc_types_gen;
 pub use rpc_types_gen::*;
 
@@ -17,8 +17,54 @@
 //! Utility impl for the RPC types.
 use super::*;
 use alloc::vec::Vec;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{H160, U256};
 
+/// The runtime-level effects of a transaction, which a standard Ethereum receipt does not show.
+///
+/// Returned in the non-standard `polkadotExtra` field of [`ReceiptInfo`]. Amounts are in the
+/// native currency of the chain.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct PolkadotReceiptExtra {
+	/// The balance transfers done by the runtime.
+	pub transfers: Vec<RuntimeTransfer>,
+	/// The storage deposits that were charged or released.
+	#[serde(rename = "storageDeposits")]
+	pub storage_deposits: Vec<StorageDepositCharge>,
+}
+
+/// A balance transfer done by the runtime.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct RuntimeTransfer {
+	/// The sender of the transfer.
+	pub from: Address,
+	/// The receiver of the transfer.
+	pub to: Address,
+	/// The amount transferred.
+	pub amount: U256,
+}
+
+/// A storage deposit transferred and held, or released.
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct StorageDepositCharge {
+	/// The account paying the deposit, or the contract releasing it.
+	pub from: Address,
+	/// The contract holding the deposit, or the account it is released to.
+	pub to: Address,
+	/// The amount of the deposit.
+	pub amount: U256,
+	/// `true` if the deposit was released, `false` if it was charged.
+	pub released: bool,
+}
+
 impl From<BlockNumberOrTag> for BlockNumberOrTagOrHash {
 	fn from(b: BlockNumberOrTag) -> Self {
 		match b {
@@ -96,16 +142,86 @@ impl ReceiptInfo {
 
 	/// Calculate receipt logs bloom.
 	fn logs_bloom(logs: &[Log]) -> Bytes256 {
-		let mut bloom = [0u8; 256];
+		let mut bloom = Bytes256::default();
 		for log in logs {
-			m3_2048(&mut bloom, &log.address.as_ref());
-			for topic in &log.topics {
-				m3_2048(&mut bloom, topic.as_ref());
-			}
+			bloom.accrue_log(&log.address, &log.topics);
+		}
+		bloom
+	}
+}
+
+impl Bytes256 {
+	/// Add the address and topics of a log to the bloom filter.
+	pub fn accrue_log(&mut self, address: &Address, topics: &[H256]) {
+		m3_2048(&mut self.0, address.as_ref());
+		for topic in topics {
+			m3_2048(&mut self.0, topic.as_ref());
+		}
+	}
+
+	/// Returns `true` if the bloom filter might contain the given address or topic.
+	///
+	/// A `false` result means that the input is definitely not contained.
+	pub fn contains_input(&self, input: &[u8]) -> bool {
+		let mut bloom = [0u8; 256];
+		m3_2048(&mut bloom, input);
+		bloom.iter().zip(self.0.iter()).all(|(bit, set)| bit & set == *bit)
+	}
+}
+
+impl AddressOrAddresses {
+	/// Returns the addresses as a slice.
+	pub fn as_slice(&self) -> &[Address] {
+		match self {
+			AddressOrAddresses::Address(address) => core::slice::from_ref(address),
+			AddressOrAddresses::Addresses(addresses) => addresses,
+		}
+	}
+}
+
+impl FilterTopic {
+	/// Returns the topics as a slice.
+	pub fn as_slice(&self) -> &[H256] {
+		match self {
+			FilterTopic::Single(topic) => core::slice::from_ref(topic),
+			FilterTopic::Multiple(topics) => topics,
 		}
-		bloom.into()
 	}
 }
+
+impl Filter {
+	/// Returns `true` if a block with the given logs bloom might contain logs matching the
+	/// address and topics of the filter.
+	pub fn matches_bloom(&self, bloom: &Bytes256) -> bool {
+		let addresses = self.address.as_ref().map_or(&[][..], |address| address.as_slice());
+		if !addresses.is_empty() &&
+			!addresses.iter().any(|address| bloom.contains_input(address.as_ref()))
+		{
+			return false;
+		}
+
+		self.topics.iter().flatten().flatten().all(|topic| {
+			let topics = topic.as_slice();
+			topics.is_empty() || topics.iter().any(|topic| bloom.contains_input(topic.as_ref()))
+		})
+	}
+
+	/// Returns `true` if the log matches the address and topics of the filter.
+	pub fn matches_log(&self, log: &Log) -> bool {
+		let addresses = self.address.as_ref().map_or(&[][..], |address| address.as_slice());
+		if !addresses.is_empty() && !addresses.contains(&log.address) {
+			return false;
+		}
+
+		self.topics.iter().flatten().enumerate().all(|(index, topic)| {
+			let Some(topic) = topic else { return true };
+			let topics = topic.as_slice();
+			topics.is_empty() ||
+				log.topics.get(index).map_or(false, |log_topic| topics.contains(log_topic))
+		})
+	}
+}
+
 /// Specialised Bloom filter that sets three bits out of 2048, given an
 /// arbitrary byte sequence.
 ///
@@ -159,6 +275,89 @@ fn logs_bloom_works() {
 	)
 	.unwrap();
 	assert_eq!(receipt.logs_bloom, ReceiptInfo::logs_bloom(&receipt.logs));
+
+	let log = &receipt.logs[0];
+	assert!(receipt.logs_bloom.contains_input(log.address.as_ref()));
+	assert!(log.topics.iter().all(|topic| receipt.logs_bloom.contains_input(topic.as_ref())));
+	assert!(!receipt.logs_bloom.contains_input(H256::repeat_byte(42).as_ref()));
+}
+
+#[test]
+fn receipt_polkadot_extra_serde_works() {
+	let mut receipt = ReceiptInfo::default();
+	let json = serde_json::to_value(&receipt).unwrap();
+	assert!(json.get("polkadotExtra").is_none());
+	assert_eq!(serde_json::from_value::<ReceiptInfo>(json).unwrap(), receipt);
+
+	receipt.polkadot_extra = Some(PolkadotReceiptExtra {
+		transfers: vec![RuntimeTransfer {
+			from: H160::repeat_byte(1),
+			to: H160::repeat_byte(2),
+			amount: 100.into(),
+		}],
+		storage_deposits: vec![StorageDepositCharge {
+			from: H160::repeat_byte(1),
+			to: H160::repeat_byte(3),
+			amount: 10.into(),
+			released: false,
+		}],
+	});
+	let json = serde_json::to_value(&receipt).unwrap();
+	assert_eq!(
+		json["polkadotExtra"],
+		serde_json::json!({
+			"transfers": [{
+				"from": "0x0101010101010101010101010101010101010101",
+				"to": "0x0202020202020202020202020202020202020202",
+				"amount": "0x64",
+			}],
+			"storageDeposits": [{
+				"from": "0x0101010101010101010101010101010101010101",
+				"to": "0x0303030303030303030303030303030303030303",
+				"amount": "0xa",
+				"released": false,
+			}],
+		})
+	);
+	assert_eq!(serde_json::from_value::<ReceiptInfo>(json).unwrap(), receipt);
+}
+
+#[test]
+fn filter_matches_log_works() {
+	let log = Log {
+		address: H160::repeat_byte(1),
+		topics: vec![H256::repeat_byte(2), H256::repeat_byte(3)],
+		..Default::default()
+	};
+	let mut bloom = Bytes256::default();
+	bloom.accrue_log(&log.address, &log.topics);
+
+	let filter: Filter = serde_json::from_str(&format!(
+		r#"{{"address": "{:?}", "topics": [null, ["{:?}", "{:?}"]]}}"#,
+		H160::repeat_byte(1),
+		H256::repeat_byte(4),
+		H256::repeat_byte(3),
+	))
+	.unwrap();
+	assert!(filter.matches_bloom(&bloom));
+	assert!(filter.matches_log(&log));
+
+	// The address does not match.
+	let filter = Filter {
+		address: Some(AddressOrAddresses::Address(H160::repeat_byte(4))),
+		..filter
+	};
+	assert!(!filter.matches_bloom(&bloom));
+	assert!(!filter.matches_log(&log));
+
+	// The topic is not at the given position.
+	let filter = Filter {
+		address: None,
+		topics: Some(vec![Some(FilterTopic::Single(H256::repeat_byte(3)))]),
+		..Default::default()
+	};
+	assert!(filter.matches_bloom(&bloom));
+	assert!(!filter.matches_log(&log));
 }
 
 impl GenericTransaction {
@@ -15,9 +15,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 //! Generated JSON-RPC types.
+//!
+//! The non-standard `polkadot_extra` field of [`ReceiptInfo`] is not part of the Ethereum
+//! JSON-RPC specification. It is added by hand and must be kept when regenerating this file.
 #![allow(missing_docs)]
 
-use super::{byte::*, TypeEip1559, TypeEip2930, TypeEip4844, TypeLegacy};
+use super::{byte::*, PolkadotReceiptExtra, TypeEip1559, TypeEip2930, TypeEip4844, TypeLegacy};
 use alloc::vec::Vec;
 use codec::{Decode, Encode};
 use derive_more::{From, TryInto};
@@ -137,6 +140,45 @@ impl Default for BlockNumberOrTagOrHash {
 	}
 }
 
+/// Filter
+#[derive(
+	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
+)]
+pub struct Filter {
+	/// Address(es)
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub address: Option<AddressOrAddresses>,
+	/// Restrict logs to a block by hash
+	#[serde(rename = "blockHash", skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<H256>,
+	/// from block
+	#[serde(rename = "fromBlock", skip_serializing_if = "Option::is_none")]
+	pub from_block: Option<BlockNumberOrTag>,
+	/// to block
+	#[serde(rename = "toBlock", skip_serializing_if = "Option::is_none")]
+	pub to_block: Option<BlockNumberOrTag>,
+	/// Topics
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub topics: Option<FilterTopics>,
+}
+
+/// Filter results
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterResults {
+	/// new block or transaction hashes
+	Hashes(Vec<H256>),
+	/// new logs
+	Logs(Vec<Log>),
+}
+impl Default for FilterResults {
+	fn default() -> Self {
+		FilterResults::Hashes(Default::default())
+	}
+}
+
 /// Transaction object generic to all types
 #[derive(
 	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
@@ -243,6 +285,11 @@ pub struct ReceiptInfo {
 	/// logs bloom
 	#[serde(rename = "logsBloom")]
 	pub logs_bloom: Bytes256,
+	/// Non-standard field with the runtime-level effects of the transaction.
+	///
+	/// Not generated: added by hand, see the module documentation.
+	#[serde(rename = "polkadotExtra", skip_serializing_if = "Option::is_none")]
+	pub polkadot_extra: Option<PolkadotReceiptExtra>,
 	/// state root
 	/// The post-transaction state root. Only specified for transactions included before the
 	/// Byzantium upgrade.
@@ -326,6 +373,23 @@ impl Default for TransactionUnsigned {
 /// Access list
 pub type AccessList = Vec<AccessListEntry>;
 
+/// Address(es)
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum AddressOrAddresses {
+	/// Address
+	Address(Address),
+	/// Addresses
+	Addresses(Addresses),
+}
+impl Default for AddressOrAddresses {
+	fn default() -> Self {
+		AddressOrAddresses::Address(Default::default())
+	}
+}
+
 /// Block tag
 /// `earliest`: The lowest numbered block the client has available; `finalized`: The most recent
 /// crypto-economically secure block, cannot be re-orged outside of manual intervention driven by
@@ -353,6 +417,10 @@ pub enum BlockTag {
 	Pending,
 }
 
+/// Filter Topics
+/// A `null` entry matches any topic at its position.
+pub type FilterTopics = Vec<Option<FilterTopic>>;
+
 #[derive(
 	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
 )]
@@ -369,6 +437,26 @@ impl Default for H256OrTransactionInfo {
 	}
 }
 
+/// hex encoded address
+pub type Addresses = Vec<Address>;
+
+/// Filter Topic List Entry
+#[derive(
+	Debug, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, From, TryInto, Eq, PartialEq,
+)]
+#[serde(untagged)]
+pub enum FilterTopic {
+	/// Single Topic Match
+	Single(H256),
+	/// Multiple Topic Match
+	Multiple(Vec<H256>),
+}
+impl Default for FilterTopic {
+	fn default() -> Self {
+		FilterTopic::Single(Default::default())
+	}
+}
+
 /// log
 #[derive(
 	Debug, Default, Clone, Encode, Decode, TypeInfo, Serialize, Deserialize, Eq, PartialEq,
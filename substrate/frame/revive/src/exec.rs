@@ -18,14 +18,17 @@
 use crate::{
 	address::{self, AddressMapper},
 	debug::{CallInterceptor, CallSpan, Tracing},
+	evm::Bytes256,
 	gas::GasMeter,
 	limits,
 	primitives::{ExecReturnValue, StorageDeposit},
 	runtime_decl_for_revive_api::{Decode, Encode, RuntimeDebugNoBound, TypeInfo},
-	storage::{self, meter::Diff, WriteOutcome},
+	storage::{self, meter::Diff, ExecutionPolicy, ScheduledCall, WriteOutcome},
 	transient_storage::TransientStorage,
-	BalanceOf, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf, DebugBuffer, Error,
-	Event, ImmutableData, ImmutableDataOf, Pallet as Contracts, LOG_TARGET,
+	weights::WeightInfo,
+	BalanceOf, BlockLogsBloom, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf,
+	DebugBuffer, Error, Event, ExecutionPolicyOf, HoldReason, ImmutableData, ImmutableDataOf,
+	Pallet as Contracts, ScheduledCallCount, ScheduledCalls, LOG_TARGET,
 };
 use alloc::vec::Vec;
 use core::{fmt::Debug, marker::PhantomData, mem};
@@ -35,9 +38,13 @@ use frame_support::{
 	ensure,
 	storage::{with_transaction, TransactionOutcome},
 	traits::{
-		fungible::{Inspect, Mutate},
-		tokens::{Fortitude, Preservation},
-		Contains, OriginTrait, Time,
+		fungible::{Inspect, Mutate, MutateHold},
+		schedule::{
+			v3::{Named as ScheduleNamed, TaskName},
+			DispatchTime, LOWEST_PRIORITY,
+		},
+		tokens::{Fortitude, Precision, Preservation},
+		Bounded, Contains, OriginTrait, Time,
 	},
 	weights::Weight,
 	Blake2_128Concat, BoundedVec, StorageHasher,
@@ -461,6 +468,33 @@ pub trait Ext: sealing::Sealed {
 	/// Note: Requires &mut self to access the contract info.
 	fn locked_delegate_dependencies_count(&mut self) -> usize;
 
+	/// Returns the number of calls the current contract has scheduled to itself.
+	fn scheduled_calls_count(&self) -> u32;
+
+	/// Sets the [`ExecutionPolicy`] of the current contract.
+	///
+	/// It is checked by every subsequent call into the contract.
+	fn set_execution_policy(&mut self, policy: ExecutionPolicy);
+
+	/// Schedule a call of the current contract to itself.
+	///
+	/// See [`pallet_revive_uapi::HostFn::schedule_call`].
+	fn schedule_call(
+		&mut self,
+		id: u32,
+		when: u64,
+		period: u32,
+		count: u32,
+		gas_limit: Weight,
+		deposit_limit: U256,
+		input_data: Vec<u8>,
+	) -> DispatchResult;
+
+	/// Cancel a call the current contract scheduled with [`Self::schedule_call`].
+	///
+	/// See [`pallet_revive_uapi::HostFn::cancel_scheduled_call`].
+	fn cancel_scheduled_call(&mut self, id: u32) -> DispatchResult;
+
 	/// Check if running in read-only context.
 	fn is_read_only(&self) -> bool;
 
@@ -890,6 +924,8 @@ where
 			BalanceOf::<T>::zero(),
 			false,
 			true,
+			1,
+			false,
 		)?
 		else {
 			return Ok(None);
@@ -925,6 +961,8 @@ where
 		deposit_limit: BalanceOf<T>,
 		read_only: bool,
 		origin_is_caller: bool,
+		call_depth: u32,
+		reentrant: bool,
 	) -> Result<Option<(Frame<T>, E)>, ExecError> {
 		let (account_id, contract_info, executable, delegate, entry_point) = match frame_args {
 			FrameArgs::Call { dest, cached_info, delegated_call } => {
@@ -944,6 +982,14 @@ where
 					if let Some(DelegatedCall { executable, caller, callee }) = delegated_call {
 						(executable, Some(DelegateInfo { caller, callee }))
 					} else {
+						let policy =
+							<ExecutionPolicyOf<T>>::get(T::AddressMapper::to_address(&dest));
+						if policy.max_call_depth.is_some_and(|max| call_depth > max) {
+							return Err(Error::<T>::CallDepthDenied.into());
+						}
+						if policy.deny_reentry && reentrant {
+							return Err(Error::<T>::ReentranceDenied.into());
+						}
 						(E::from_storage(contract.code_hash, gas_meter)?, None)
 					};
 
@@ -1011,6 +1057,13 @@ where
 			return Err(Error::<T>::MaxCallDepthReached.into());
 		}
 
+		// The new frame is pushed on top of the first frame and all nested frames.
+		let call_depth = self.frames.len() as u32 + 2;
+		let reentrant = match &frame_args {
+			FrameArgs::Call { dest, .. } => self.frames().any(|f| &f.account_id == dest),
+			FrameArgs::Instantiate { .. } => false,
+		};
+
 		// We need to make sure that changes made to the contract info are not discarded.
 		// See the `in_memory_changes_not_discarded` test for more information.
 		// We do not store on instantiate because we do not allow to call into a contract
@@ -1037,6 +1090,8 @@ where
 			deposit_limit,
 			read_only,
 			false,
+			call_depth,
+			reentrant,
 		)? {
 			self.frames.try_push(frame).map_err(|_| Error::<T>::MaxCallDepthReached)?;
 			Ok(Some(executable))
@@ -1570,6 +1625,8 @@ where
 		let account_address = T::AddressMapper::to_address(&frame.account_id);
 		ContractInfoOf::<T>::remove(&account_address);
 		ImmutableDataOf::<T>::remove(&account_address);
+		ExecutionPolicyOf::<T>::remove(&account_address);
+		Contracts::<T>::remove_all_scheduled_calls(&frame.account_id);
 		Self::decrement_refcount(info.code_hash);
 
 		for (code_hash, deposit) in info.delegate_dependencies() {
@@ -1734,11 +1791,13 @@ where
 	}
 
 	fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
-		Contracts::<Self::T>::deposit_event(Event::ContractEmitted {
-			contract: T::AddressMapper::to_address(self.account_id()),
-			data,
-			topics,
+		let contract = T::AddressMapper::to_address(self.account_id());
+		BlockLogsBloom::<T>::mutate(|bloom| {
+			let mut logs_bloom = Bytes256(*bloom);
+			logs_bloom.accrue_log(&contract, &topics);
+			*bloom = logs_bloom.0;
 		});
+		Contracts::<Self::T>::deposit_event(Event::ContractEmitted { contract, data, topics });
 	}
 
 	fn block_number(&self) -> U256 {
@@ -1916,6 +1975,111 @@ where
 		self.top_frame_mut().contract_info().delegate_dependencies_count()
 	}
 
+	fn scheduled_calls_count(&self) -> u32 {
+		ScheduledCallCount::<T>::get(T::AddressMapper::to_address(self.account_id()))
+			.unwrap_or_default()
+	}
+
+	fn set_execution_policy(&mut self, policy: ExecutionPolicy) {
+		let address = self.address();
+		if policy == ExecutionPolicy::default() {
+			ExecutionPolicyOf::<T>::remove(address);
+		} else {
+			ExecutionPolicyOf::<T>::insert(address, policy);
+		}
+	}
+
+	fn schedule_call(
+		&mut self,
+		id: u32,
+		when: u64,
+		period: u32,
+		count: u32,
+		gas_limit: Weight,
+		deposit_limit: U256,
+		input_data: Vec<u8>,
+	) -> DispatchResult {
+		let contract = self.account_id().clone();
+		let address = T::AddressMapper::to_address(&contract);
+		ensure!(
+			!ScheduledCalls::<T>::contains_key(address, id),
+			Error::<T>::DuplicateScheduledCall
+		);
+		let num_scheduled = ScheduledCallCount::<T>::get(address).unwrap_or_default();
+		ensure!(num_scheduled < T::MaxScheduledCalls::get(), Error::<T>::TooManyScheduledCalls);
+		let storage_deposit_limit =
+			deposit_limit.try_into().map_err(|_| Error::<T>::BalanceConversionFailed)?;
+		let input_data: BoundedVec<_, _> =
+			input_data.try_into().map_err(|_| Error::<T>::ValueTooLarge)?;
+
+		let (maybe_periodic, executions) =
+			if period > 0 && count > 1 { (Some((period.into(), count)), count) } else { (None, 1) };
+
+		let call: BoundedVec<_, _> =
+			<T as Config>::RuntimeCall::from(crate::Call::<T>::dispatch_scheduled_call {
+				id,
+				gas_limit,
+			})
+			.encode()
+			.try_into()
+			.map_err(|_| DispatchError::Exhausted)?;
+		let call_len = call.len() as u32;
+		T::Scheduler::schedule_named(
+			Contracts::<T>::scheduled_call_task_name(&address, id),
+			DispatchTime::At(when.saturated_into()),
+			maybe_periodic,
+			LOWEST_PRIORITY,
+			<T::RuntimeOrigin as OriginTrait>::signed(contract.clone()).into_caller(),
+			Bounded::Inline(call),
+		)?;
+
+		// The scheduler does not charge any fees when dispatching, so the contract pays for the
+		// weight of all executions upfront.
+		let fee = T::WeightPrice::convert(T::WeightInfo::call().saturating_add(gas_limit))
+			.saturating_mul(executions.into());
+		T::Currency::burn_from(
+			&contract,
+			fee,
+			Preservation::Preserve,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+
+		let mut scheduled = ScheduledCall {
+			input_data,
+			gas_limit,
+			storage_deposit_limit,
+			remaining: executions,
+			deposit: Zero::zero(),
+		};
+		// Each scheduled call occupies three storage entries until it is removed: its entry in
+		// `ScheduledCalls` and the lookup and agenda entries of the scheduler task. The agenda
+		// entry holds the call and the task name, which is also the key of the lookup entry.
+		let bytes = (scheduled.encoded_size() as u32)
+			.saturating_add(call_len)
+			.saturating_add(2 * mem::size_of::<TaskName>() as u32);
+		scheduled.deposit = T::DepositPerByte::get()
+			.saturating_mul(bytes.into())
+			.saturating_add(T::DepositPerItem::get().saturating_mul(3u32.into()));
+		T::Currency::hold(&HoldReason::ScheduledCallDeposit.into(), &contract, scheduled.deposit)?;
+
+		ScheduledCalls::<T>::insert(address, id, scheduled);
+		ScheduledCallCount::<T>::insert(address, num_scheduled.saturating_add(1));
+		Ok(())
+	}
+
+	fn cancel_scheduled_call(&mut self, id: u32) -> DispatchResult {
+		let contract = self.account_id().clone();
+		let address = T::AddressMapper::to_address(&contract);
+		let scheduled =
+			ScheduledCalls::<T>::get(address, id).ok_or(Error::<T>::ScheduledCallNotFound)?;
+		// The scheduler might have dropped the task already. This must not keep the contract from
+		// getting its deposit back.
+		let _ = T::Scheduler::cancel_named(Contracts::<T>::scheduled_call_task_name(&address, id));
+		Contracts::<T>::remove_scheduled_call(&contract, id, scheduled.deposit);
+		Ok(())
+	}
+
 	fn is_read_only(&self) -> bool {
 		self.top_frame().read_only
 	}
@@ -1950,7 +2114,7 @@ mod tests {
 		gas::GasMeter,
 		test_utils::*,
 		tests::{
-			test_utils::{get_balance, place_contract, set_balance},
+			test_utils::{get_balance, get_balance_on_hold, place_contract, set_balance},
 			ExtBuilder, RuntimeCall, RuntimeEvent as MetaEvent, Test, TestFilter,
 		},
 		AddressMapper, Error,
@@ -3641,6 +3805,149 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn call_execution_policy() {
+		// deny reentry and call CHARLIE if the input is '0'
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			if ctx.input_data[0] == 0 {
+				ctx.ext.set_execution_policy(ExecutionPolicy {
+					max_call_depth: None,
+					deny_reentry: true,
+				});
+				ctx.ext
+					.call(
+						Weight::zero(),
+						U256::zero(),
+						&CHARLIE_ADDR,
+						U256::zero(),
+						vec![],
+						true,
+						false,
+					)
+					.map(|_| ctx.ext.last_frame_output().clone())
+			} else {
+				exec_success()
+			}
+		});
+
+		// call BOB with input set to '1'
+		let code_charlie = MockLoader::insert(Call, |ctx, _| {
+			ctx.ext
+				.call(Weight::zero(), U256::zero(), &BOB_ADDR, U256::zero(), vec![1], true, false)
+				.map(|_| ctx.ext.last_frame_output().clone())
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			place_contract(&BOB, code_bob);
+			place_contract(&CHARLIE, code_charlie);
+			let origin = Origin::from_account_id(ALICE);
+			let mut storage_meter = storage::meter::Meter::new(&origin, 0, 0).unwrap();
+			let mut run = |dest, input_data| {
+				MockStack::run_call(
+					origin.clone(),
+					dest,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					U256::zero(),
+					input_data,
+					false,
+					None,
+				)
+				.map_err(|e| e.error)
+			};
+
+			// BOB -> CHARLIE -> BOB fails as the policy of BOB denies reentry, even though
+			// CHARLIE allows it.
+			assert_err!(run(BOB_ADDR, vec![0]), <Error<Test>>::ReentranceDenied);
+
+			// The policy was reverted together with the failed call.
+			assert_ok!(run(CHARLIE_ADDR, vec![]));
+
+			// BOB can only be called from the first frame.
+			ExecutionPolicyOf::<Test>::insert(
+				BOB_ADDR,
+				ExecutionPolicy { max_call_depth: Some(1), deny_reentry: false },
+			);
+			assert_err!(run(CHARLIE_ADDR, vec![]), <Error<Test>>::CallDepthDenied);
+			assert_ok!(run(BOB_ADDR, vec![1]));
+		});
+	}
+
+	#[test]
+	fn schedule_call_works() {
+		// schedule the call if the input is '0', cancel it otherwise
+		let code_bob = MockLoader::insert(Call, |ctx, _| {
+			if ctx.input_data[0] == 0 {
+				ctx.ext.schedule_call(
+					7,
+					5,
+					0,
+					1,
+					Weight::from_parts(1_000, 100),
+					U256::zero(),
+					vec![1, 2, 3],
+				)?;
+			} else {
+				ctx.ext.cancel_scheduled_call(7)?;
+			}
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			place_contract(&BOB, code_bob);
+			set_balance(&BOB, 1_000_000);
+			let origin = Origin::from_account_id(ALICE);
+			let mut storage_meter = storage::meter::Meter::new(&origin, 0, 0).unwrap();
+			let mut run = |input_data| {
+				MockStack::run_call(
+					origin.clone(),
+					BOB_ADDR,
+					&mut GasMeter::<Test>::new(GAS_LIMIT),
+					&mut storage_meter,
+					U256::zero(),
+					input_data,
+					false,
+					None,
+				)
+				.map_err(|e| e.error)
+			};
+			let task_name = Contracts::<Test>::scheduled_call_task_name(&BOB_ADDR, 7);
+
+			assert_ok!(run(vec![0]));
+			let scheduled = ScheduledCalls::<Test>::get(BOB_ADDR, 7).unwrap();
+			assert_eq!(scheduled.input_data.to_vec(), vec![1, 2, 3]);
+			assert_eq!(scheduled.remaining, 1);
+			assert_eq!(ScheduledCallCount::<Test>::get(BOB_ADDR), Some(1));
+			assert_eq!(
+				get_balance_on_hold(&HoldReason::ScheduledCallDeposit.into(), &BOB),
+				scheduled.deposit
+			);
+			assert!(pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+
+			// The scheduler dispatches the pallet call with the contract as signer.
+			let agenda = pallet_scheduler::Agenda::<Test>::get(5);
+			let Some(Some(task)) = agenda.first() else { panic!("task must be scheduled") };
+			let Bounded::Inline(call) = &task.call else { panic!("call must be inlined") };
+			assert_eq!(
+				RuntimeCall::decode(&mut &call[..]).unwrap(),
+				RuntimeCall::Contracts(crate::Call::dispatch_scheduled_call {
+					id: 7,
+					gas_limit: Weight::from_parts(1_000, 100),
+				})
+			);
+
+			assert_err!(run(vec![0]), <Error<Test>>::DuplicateScheduledCall);
+
+			assert_ok!(run(vec![1]));
+			assert!(!ScheduledCalls::<Test>::contains_key(BOB_ADDR, 7));
+			assert_eq!(get_balance_on_hold(&HoldReason::ScheduledCallDeposit.into(), &BOB), 0);
+			assert_eq!(ScheduledCallCount::<Test>::get(BOB_ADDR), None);
+			assert!(!pallet_scheduler::Lookup::<Test>::contains_key(task_name));
+
+			assert_err!(run(vec![1]), <Error<Test>>::ScheduledCallNotFound);
+		});
+	}
+
 	#[test]
 	fn call_runtime_works() {
 		let code_hash = MockLoader::insert(Call, |ctx, _| {
@@ -44,7 +44,10 @@ use crate::{
 	evm::{runtime::GAS_PRICE, GenericTransaction},
 	exec::{AccountIdOf, ExecError, Executable, Ext, Key, Origin, Stack as ExecStack},
 	gas::GasMeter,
-	storage::{meter::Meter as StorageMeter, ContractInfo, DeletionQueueManager},
+	storage::{
+		meter::Meter as StorageMeter, CodeMetadata, ContractInfo, DeletionQueueManager,
+		ExecutionPolicy, ScheduledCall,
+	},
 	wasm::{CodeInfo, RuntimeCosts, WasmBlob},
 };
 use alloc::{boxed::Box, format, vec};
@@ -59,7 +62,8 @@ use frame_support::{
 	pallet_prelude::DispatchClass,
 	traits::{
 		fungible::{Inspect, Mutate, MutateHold},
-		tokens::{Fortitude::Polite, Preservation::Preserve},
+		schedule::v3::{Named as ScheduleNamed, TaskName},
+		tokens::{Fortitude::Polite, Precision, Preservation::Preserve},
 		ConstU32, ConstU64, Contains, EnsureOrigin, Get, IsType, OriginTrait, Time,
 	},
 	weights::{Weight, WeightMeter},
@@ -99,6 +103,8 @@ type EventRecordOf<T> =
 	EventRecord<<T as frame_system::Config>::RuntimeEvent, <T as frame_system::Config>::Hash>;
 type DebugBuffer = BoundedVec<u8, ConstU32<{ limits::DEBUG_BUFFER_BYTES }>>;
 type ImmutableData = BoundedVec<u8, ConstU32<{ limits::IMMUTABLE_BYTES }>>;
+type PalletsOriginOf<T> =
+	<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
 
 /// Used as a sentinel value when reading and writing contract memory.
 ///
@@ -150,7 +156,8 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type RuntimeCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
-			+ GetDispatchInfo;
+			+ GetDispatchInfo
+			+ From<Call<Self>>;
 
 		/// Overarching hold reason.
 		#[pallet::no_default_bounds]
@@ -255,6 +262,27 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type InstantiateOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
 
+		/// Origin allowed to manage the code upload allowlist.
+		///
+		/// See [`Pallet::set_upload_allowlist`]. While the allowlist is disabled, which is the
+		/// default, uploads are only restricted by [`Self::UploadOrigin`].
+		#[pallet::no_default_bounds]
+		type AllowlistOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The scheduler that dispatches the calls contracts schedule to themselves.
+		///
+		/// Use `()` to disable scheduled calls.
+		#[pallet::no_default_bounds]
+		type Scheduler: ScheduleNamed<
+			BlockNumberFor<Self>,
+			<Self as Config>::RuntimeCall,
+			PalletsOriginOf<Self>,
+		>;
+
+		/// The maximum number of calls a single contract can have scheduled at the same time.
+		#[pallet::constant]
+		type MaxScheduledCalls: Get<u32>;
+
 		/// For most production chains, it's recommended to use the `()` implementation of this
 		/// trait. This implementation offers additional logging when the log target
 		/// "runtime::revive" is set to trace.
@@ -304,7 +332,7 @@ pub mod pallet {
 			derive_impl,
 			traits::{ConstBool, ConstU32},
 		};
-		use frame_system::EnsureSigned;
+		use frame_system::{EnsureRoot, EnsureSigned};
 		use sp_core::parameter_types;
 
 		type AccountId = sp_runtime::AccountId32;
@@ -360,6 +388,9 @@ pub mod pallet {
 			type UnsafeUnstableInterface = ConstBool<true>;
 			type UploadOrigin = EnsureSigned<AccountId>;
 			type InstantiateOrigin = EnsureSigned<AccountId>;
+			type AllowlistOrigin = EnsureRoot<AccountId>;
+			type Scheduler = ();
+			type MaxScheduledCalls = ConstU32<16>;
 			type WeightInfo = ();
 			type WeightPrice = Self;
 			type Debug = ();
@@ -451,6 +482,24 @@ pub mod pallet {
 
 		/// Some storage deposit funds have been transferred and released.
 		StorageDepositTransferredAndReleased { from: H160, to: H160, amount: BalanceOf<T> },
+
+		/// The code upload allowlist was enabled or disabled.
+		UploadAllowlistSet { enabled: bool },
+
+		/// An uploader was added to or removed from the code upload allowlist.
+		UploaderAllowlisted { uploader: H160, allowed: bool },
+
+		/// A code hash was added to or removed from the code upload allowlist.
+		CodeHashAllowlisted { code_hash: H256, allowed: bool },
+
+		/// An execution of a call that a contract scheduled to itself failed or reverted.
+		ScheduledCallFailed { contract: H160, id: u32 },
+
+		/// The ABI metadata of a code was registered or replaced.
+		CodeMetadataSet { code_hash: H256, abi_hash: H256, uri: Vec<u8> },
+
+		/// The ABI metadata of a code was removed.
+		CodeMetadataRemoved { code_hash: H256, deposit_released: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -560,6 +609,22 @@ pub mod pallet {
 		AccountUnmapped,
 		/// Tried to map an account that is already mapped.
 		AccountAlreadyMapped,
+		/// The code upload allowlist is enabled, and neither the uploader nor the code hash are
+		/// on it.
+		UploadNotAllowed,
+		/// A call tried to invoke a contract deeper in the call stack than its execution policy
+		/// allows.
+		CallDepthDenied,
+		/// The contract already has a scheduled call with this id.
+		DuplicateScheduledCall,
+		/// The contract has reached [`Config::MaxScheduledCalls`].
+		TooManyScheduledCalls,
+		/// The contract has no scheduled call with this id.
+		ScheduledCallNotFound,
+		/// The URI of the ABI metadata exceeds [`limits::code::METADATA_URI_BYTES`].
+		CodeMetadataUriTooLong,
+		/// No ABI metadata is registered for this code hash.
+		CodeMetadataNotFound,
 	}
 
 	/// A reason for the pallet contracts placing a hold on funds.
@@ -571,6 +636,10 @@ pub mod pallet {
 		StorageDepositReserve,
 		/// Deposit for creating an address mapping in [`AddressSuffix`].
 		AddressMapping,
+		/// Deposit for storing a call a contract scheduled to itself in [`ScheduledCalls`].
+		ScheduledCallDeposit,
+		/// Deposit for storing the ABI metadata of a code in [`CodeMetadataOf`].
+		CodeMetadataDeposit,
 	}
 
 	/// A mapping from a contract's code hash to its code.
@@ -581,6 +650,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type CodeInfoOf<T: Config> = StorageMap<_, Identity, H256, CodeInfo<T>>;
 
+	/// The ABI metadata registered for a code hash by the owner of the code.
+	#[pallet::storage]
+	pub(crate) type CodeMetadataOf<T: Config> = StorageMap<_, Identity, H256, CodeMetadata<T>>;
+
 	/// The code associated with a given account.
 	#[pallet::storage]
 	pub(crate) type ContractInfoOf<T: Config> = StorageMap<_, Identity, H160, ContractInfo<T>>;
@@ -589,6 +662,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ImmutableDataOf<T: Config> = StorageMap<_, Identity, H160, ImmutableData>;
 
+	/// The execution policy a contract set for itself, if it is not the default one.
+	///
+	/// Kept apart from [`ContractInfoOf`] so that the encoding of existing contracts does not
+	/// change.
+	#[pallet::storage]
+	pub(crate) type ExecutionPolicyOf<T: Config> =
+		StorageMap<_, Identity, H160, ExecutionPolicy, ValueQuery>;
+
 	/// Evicted contracts that await child trie deletion.
 	///
 	/// Child trie deletion is a heavy operation depending on the amount of storage items
@@ -610,8 +691,41 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type AddressSuffix<T: Config> = StorageMap<_, Identity, H160, [u8; 12]>;
 
+	/// Whether code uploads are restricted to [`AllowedUploaders`] and [`AllowedCodeHashes`].
+	#[pallet::storage]
+	pub(crate) type UploadAllowlistEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Accounts allowed to upload any code while the upload allowlist is enabled.
+	#[pallet::storage]
+	pub(crate) type AllowedUploaders<T: Config> = StorageMap<_, Identity, H160, ()>;
+
+	/// Code hashes that anyone may upload while the upload allowlist is enabled.
+	#[pallet::storage]
+	pub(crate) type AllowedCodeHashes<T: Config> = StorageMap<_, Identity, H256, ()>;
+
+	/// Calls that contracts scheduled to themselves, keyed by contract and call id.
+	#[pallet::storage]
+	pub(crate) type ScheduledCalls<T: Config> =
+		StorageDoubleMap<_, Identity, H160, Twox64Concat, u32, ScheduledCall<T>>;
+
+	/// The number of entries in [`ScheduledCalls`] per contract.
+	#[pallet::storage]
+	pub(crate) type ScheduledCallCount<T: Config> = StorageMap<_, Identity, H160, u32>;
+
+	/// The bloom filter of all contract events emitted in the current block.
+	///
+	/// It is reset at the start of every block. Indexers read it at each block to skip blocks
+	/// that cannot contain the logs they are looking for.
+	#[pallet::storage]
+	pub(crate) type BlockLogsBloom<T: Config> = StorageValue<_, [u8; 256], ValueQuery>;
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_block: BlockNumberFor<T>) -> Weight {
+			BlockLogsBloom::<T>::kill();
+			T::DbWeight::get().writes(1)
+		}
+
 		fn on_idle(_block: BlockNumberFor<T>, limit: Weight) -> Weight {
 			let mut meter = WeightMeter::with_limit(limit);
 			ContractInfo::<T>::process_deletion_queue_batch(&mut meter);
@@ -1041,6 +1155,155 @@ pub mod pallet {
 				T::AddressMapper::to_fallback_account_id(&T::AddressMapper::to_address(&origin));
 			call.dispatch(RawOrigin::Signed(unmapped_account).into())
 		}
+
+		/// Enable or disable the code upload allowlist.
+		///
+		/// While enabled, code can only be uploaded by an account in [`AllowedUploaders`], or if
+		/// its hash is in [`AllowedCodeHashes`]. Disabling it makes deployment permissionless
+		/// again, subject to [`Config::UploadOrigin`], while keeping the allowlist entries.
+		///
+		/// The dispatch origin must be [`Config::AllowlistOrigin`].
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_upload_allowlist(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::AllowlistOrigin::ensure_origin(origin)?;
+			UploadAllowlistEnabled::<T>::put(enabled);
+			Self::deposit_event(Event::UploadAllowlistSet { enabled });
+			Ok(())
+		}
+
+		/// Add `uploader` to, or remove it from, the code upload allowlist.
+		///
+		/// The dispatch origin must be [`Config::AllowlistOrigin`].
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_uploader_allowed(
+			origin: OriginFor<T>,
+			uploader: H160,
+			allowed: bool,
+		) -> DispatchResult {
+			T::AllowlistOrigin::ensure_origin(origin)?;
+			if allowed {
+				AllowedUploaders::<T>::insert(uploader, ());
+			} else {
+				AllowedUploaders::<T>::remove(uploader);
+			}
+			Self::deposit_event(Event::UploaderAllowlisted { uploader, allowed });
+			Ok(())
+		}
+
+		/// Add `code_hash` to, or remove it from, the code upload allowlist.
+		///
+		/// The dispatch origin must be [`Config::AllowlistOrigin`].
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn set_code_hash_allowed(
+			origin: OriginFor<T>,
+			code_hash: H256,
+			allowed: bool,
+		) -> DispatchResult {
+			T::AllowlistOrigin::ensure_origin(origin)?;
+			if allowed {
+				AllowedCodeHashes::<T>::insert(code_hash, ());
+			} else {
+				AllowedCodeHashes::<T>::remove(code_hash);
+			}
+			Self::deposit_event(Event::CodeHashAllowlisted { code_hash, allowed });
+			Ok(())
+		}
+
+		/// Dispatch a call that a contract scheduled to itself.
+		///
+		/// [`Config::Scheduler`] dispatches this on behalf of the contract, which already paid for
+		/// it when scheduling the call. Failed executions are reported with
+		/// [`Event::ScheduledCallFailed`] and do not cancel the remaining ones.
+		///
+		/// The dispatch origin must be the account of the contract.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::call().saturating_add(*gas_limit))]
+		pub fn dispatch_scheduled_call(
+			origin: OriginFor<T>,
+			id: u32,
+			gas_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			let account = ensure_signed(origin.clone())?;
+			let contract = T::AddressMapper::to_address(&account);
+			let mut scheduled =
+				ScheduledCalls::<T>::get(contract, id).ok_or(Error::<T>::ScheduledCallNotFound)?;
+
+			let output = Self::bare_call(
+				origin,
+				contract,
+				Zero::zero(),
+				scheduled.gas_limit.min(gas_limit),
+				DepositLimit::Balance(scheduled.storage_deposit_limit),
+				scheduled.input_data.to_vec(),
+				DebugInfo::Skip,
+				CollectEvents::Skip,
+			);
+			if output.result.as_ref().map_or(true, |result| result.did_revert()) {
+				Self::deposit_event(Event::ScheduledCallFailed { contract, id });
+			}
+
+			scheduled.remaining.saturating_dec();
+			if scheduled.remaining == 0 {
+				Self::remove_scheduled_call(&account, id, scheduled.deposit);
+			} else {
+				ScheduledCalls::<T>::insert(contract, id, scheduled);
+			}
+
+			Ok(Some(T::WeightInfo::call().saturating_add(output.gas_consumed)).into())
+		}
+
+		/// Register the ABI metadata of the code stored under `code_hash`, replacing any metadata
+		/// registered before.
+		///
+		/// Explorers and wallets use it to decode the calls and the traces of contracts running
+		/// this code. `abi_hash` is the hash of the ABI or metadata document, which can be fetched
+		/// from `uri`. A deposit for storing the metadata is held on the caller.
+		///
+		/// Only the owner of the code can register its metadata.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
+		pub fn set_code_metadata(
+			origin: OriginFor<T>,
+			code_hash: H256,
+			abi_hash: H256,
+			uri: Vec<u8>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let code_info = CodeInfoOf::<T>::get(code_hash).ok_or(Error::<T>::CodeNotFound)?;
+			ensure!(code_info.owner == origin, BadOrigin);
+			let uri: BoundedVec<_, _> =
+				uri.try_into().map_err(|_| Error::<T>::CodeMetadataUriTooLong)?;
+
+			Self::remove_code_metadata(code_hash);
+			let len = (abi_hash.encoded_size() + uri.encoded_size()) as u32;
+			let deposit = T::DepositPerByte::get()
+				.saturating_mul(len.into())
+				.saturating_add(T::DepositPerItem::get());
+			T::Currency::hold(&HoldReason::CodeMetadataDeposit.into(), &origin, deposit)?;
+
+			Self::deposit_event(Event::CodeMetadataSet { code_hash, abi_hash, uri: uri.to_vec() });
+			let metadata = CodeMetadata { owner: origin, abi_hash, uri, deposit };
+			CodeMetadataOf::<T>::insert(code_hash, metadata);
+			Ok(())
+		}
+
+		/// Remove the ABI metadata registered for `code_hash` and refund its deposit.
+		///
+		/// Only the account that registered the metadata can remove it. It is also removed along
+		/// with the code.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+		pub fn clear_code_metadata(origin: OriginFor<T>, code_hash: H256) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let metadata =
+				CodeMetadataOf::<T>::get(code_hash).ok_or(Error::<T>::CodeMetadataNotFound)?;
+			ensure!(metadata.owner == origin, BadOrigin);
+			Self::remove_code_metadata(code_hash);
+			Ok(())
+		}
 	}
 }
 
@@ -1467,7 +1730,14 @@ where
 		storage_deposit_limit: BalanceOf<T>,
 		skip_transfer: bool,
 	) -> Result<(WasmBlob<T>, BalanceOf<T>), DispatchError> {
+		let uploader = T::AddressMapper::to_address(&origin);
 		let mut module = WasmBlob::from_code(code, origin)?;
+		ensure!(
+			!UploadAllowlistEnabled::<T>::get() ||
+				AllowedUploaders::<T>::contains_key(uploader) ||
+				AllowedCodeHashes::<T>::contains_key(module.code_hash()),
+			<Error<T>>::UploadNotAllowed
+		);
 		let deposit = module.store_code(skip_transfer)?;
 		ensure!(storage_deposit_limit >= deposit, <Error<T>>::StorageDepositLimitExhausted);
 		Ok((module, deposit))
@@ -1522,6 +1792,68 @@ impl<T: Config> Pallet<T> {
 	fn deposit_event(event: Event<T>) {
 		<frame_system::Pallet<T>>::deposit_event(<T as Config>::RuntimeEvent::from(event))
 	}
+
+	/// The name under which [`Config::Scheduler`] knows the scheduled call `id` of `contract`.
+	pub(crate) fn scheduled_call_task_name(contract: &H160, id: u32) -> TaskName {
+		(b"revive/scheduled_call", contract, id).using_encoded(sp_io::hashing::blake2_256)
+	}
+
+	/// Remove the scheduled call `id` of `contract` from storage and release its deposit.
+	pub(crate) fn remove_scheduled_call(contract: &T::AccountId, id: u32, deposit: BalanceOf<T>) {
+		let address = T::AddressMapper::to_address(contract);
+		ScheduledCalls::<T>::remove(address, id);
+		ScheduledCallCount::<T>::mutate_exists(address, |count| {
+			*count = count.and_then(|count| count.checked_sub(1)).filter(|count| *count > 0);
+		});
+		Self::release_scheduled_call_deposit(contract, deposit);
+	}
+
+	/// Cancel all calls `contract` scheduled to itself and release their deposits.
+	///
+	/// There are at most [`Config::MaxScheduledCalls`] of them.
+	pub(crate) fn remove_all_scheduled_calls(contract: &T::AccountId) {
+		let address = T::AddressMapper::to_address(contract);
+		if ScheduledCallCount::<T>::take(address).is_none() {
+			return
+		}
+		for (id, scheduled) in ScheduledCalls::<T>::drain_prefix(address) {
+			// The scheduler might have dropped the task already.
+			let _ = T::Scheduler::cancel_named(Self::scheduled_call_task_name(&address, id));
+			Self::release_scheduled_call_deposit(contract, scheduled.deposit);
+		}
+	}
+
+	fn release_scheduled_call_deposit(contract: &T::AccountId, deposit: BalanceOf<T>) {
+		let _ = T::Currency::release(
+			&HoldReason::ScheduledCallDeposit.into(),
+			contract,
+			deposit,
+			Precision::BestEffort,
+		);
+	}
+
+	/// The ABI metadata registered for `code_hash`, if any.
+	pub fn code_metadata(code_hash: H256) -> Option<CodeMetadataInfo> {
+		CodeMetadataOf::<T>::get(code_hash).map(|metadata| CodeMetadataInfo {
+			abi_hash: metadata.abi_hash,
+			uri: metadata.uri.into(),
+		})
+	}
+
+	/// Remove the ABI metadata registered for `code_hash`, if any, and release its deposit.
+	pub(crate) fn remove_code_metadata(code_hash: H256) {
+		let Some(metadata) = CodeMetadataOf::<T>::take(code_hash) else { return };
+		let _ = T::Currency::release(
+			&HoldReason::CodeMetadataDeposit.into(),
+			&metadata.owner,
+			metadata.deposit,
+			Precision::BestEffort,
+		);
+		Self::deposit_event(Event::CodeMetadataRemoved {
+			code_hash,
+			deposit_released: metadata.deposit,
+		});
+	}
 }
 
 // Set up a global reference to the boolean flag used for the re-entrancy guard.
@@ -1529,7 +1861,7 @@ environmental!(executing_contract: bool);
 
 sp_api::decl_runtime_apis! {
 	/// The API used to dry-run contract interactions.
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait ReviveApi<AccountId, Balance, Nonce, BlockNumber, EventRecord> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -1592,5 +1924,11 @@ sp_api::decl_runtime_apis! {
 			address: H160,
 			key: [u8; 32],
 		) -> GetStorageResult;
+
+		/// Returns the ABI metadata registered for a code hash, if any.
+		///
+		/// See [`crate::Pallet::set_code_metadata`].
+		#[api_version(2)]
+		fn code_metadata(code_hash: H256) -> Option<CodeMetadataInfo>;
 	}
 }
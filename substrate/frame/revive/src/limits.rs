@@ -91,6 +91,9 @@ pub mod code {
 	/// is purely based off [`STATIC_MEMORY_BYTES`].
 	pub const BLOB_BYTES: u32 = 256 * 1024;
 
+	/// The maximum length of the URI of the ABI metadata registered for a code.
+	pub const METADATA_URI_BYTES: u32 = 256;
+
 	/// Maximum size the program is allowed to take in memory.
 	///
 	/// This includes data and code. Increasing this limit will allow
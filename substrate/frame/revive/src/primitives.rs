@@ -175,6 +175,15 @@ pub struct CodeUploadReturnValue<Balance> {
 	pub deposit: Balance,
 }
 
+/// The ABI metadata registered for a code, see [`crate::Pallet::set_code_metadata`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CodeMetadataInfo {
+	/// The hash of the ABI or metadata document.
+	pub abi_hash: sp_core::H256,
+	/// The URI the document can be fetched from.
+	pub uri: Vec<u8>,
+}
+
 /// Reference to an existing code hash or a new wasm module.
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
 pub enum Code {
@@ -42,7 +42,7 @@ use sp_core::{ConstU32, Get, H160};
 use sp_io::KillStorageResult;
 use sp_runtime::{
 	traits::{Hash, Saturating, Zero},
-	BoundedBTreeMap, DispatchError, DispatchResult, RuntimeDebug,
+	BoundedBTreeMap, BoundedVec, DispatchError, DispatchResult, RuntimeDebug,
 };
 
 type DelegateDependencyMap<T> =
@@ -80,6 +80,53 @@ pub struct ContractInfo<T: Config> {
 	immutable_data_len: u32,
 }
 
+/// Restrictions a contract places on how it can be called.
+///
+/// They are checked whenever a new call frame is pushed for the contract. Delegate calls execute
+/// in the context of the calling contract and are exempt.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ExecutionPolicy {
+	/// The deepest position on the call stack at which the contract can be called.
+	///
+	/// The first frame of a call stack is at depth 1. `None` means that only
+	/// [`limits::CALL_STACK_DEPTH`] applies.
+	pub max_call_depth: Option<u32>,
+	/// If `true`, calls into the contract fail while it is already on the call stack.
+	pub deny_reentry: bool,
+}
+
+/// A call that a contract scheduled to itself.
+#[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct ScheduledCall<T: Config> {
+	/// The input data the contract is called with.
+	pub input_data: BoundedVec<u8, ConstU32<{ limits::PAYLOAD_BYTES }>>,
+	/// The gas limit of each execution.
+	pub gas_limit: Weight,
+	/// The storage deposit limit of each execution, which is paid by the contract.
+	pub storage_deposit_limit: BalanceOf<T>,
+	/// The number of executions left, including the next one.
+	pub remaining: u32,
+	/// The deposit held on the contract for storing this call.
+	pub deposit: BalanceOf<T>,
+}
+
+/// The ABI metadata the owner of a code registered for it.
+#[derive(Encode, Decode, CloneNoBound, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct CodeMetadata<T: Config> {
+	/// The account that registered the metadata and on which the deposit is held.
+	pub owner: AccountIdOf<T>,
+	/// The hash of the ABI or metadata document.
+	pub abi_hash: sp_core::H256,
+	/// The URI the document can be fetched from.
+	pub uri: BoundedVec<u8, ConstU32<{ limits::code::METADATA_URI_BYTES }>>,
+	/// The deposit held on `owner` for storing the metadata.
+	pub deposit: BalanceOf<T>,
+}
+
 impl<T: Config> ContractInfo<T> {
 	/// Constructs a new contract info **without** writing it to storage.
 	///
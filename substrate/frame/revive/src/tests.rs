@@ -29,7 +29,7 @@ use crate::{
 		ChainExtension, Environment, Ext, RegisteredChainExtension, Result as ExtensionResult,
 		RetVal, ReturnFlags,
 	},
-	evm::{runtime::GAS_PRICE, GenericTransaction},
+	evm::{runtime::GAS_PRICE, Bytes256, GenericTransaction},
 	exec::Key,
 	limits,
 	primitives::CodeUploadReturnValue,
@@ -38,9 +38,10 @@ use crate::{
 	tests::test_utils::{get_contract, get_contract_checked},
 	wasm::Memory,
 	weights::WeightInfo,
-	AccountId32Mapper, BalanceOf, Code, CodeInfoOf, CollectEvents, Config, ContractInfo,
-	ContractInfoOf, DebugInfo, DeletionQueueCounter, DepositLimit, Error, EthTransactError,
-	HoldReason, Origin, Pallet, PristineCode, H160,
+	AccountId32Mapper, BalanceOf, BlockLogsBloom, Code, CodeInfoOf, CodeMetadataInfo,
+	CollectEvents, Config, ContractInfo, ContractInfoOf, DebugInfo, DeletionQueueCounter,
+	DepositLimit, Error, EthTransactError, HoldReason, Origin, Pallet, PristineCode, ScheduledCall,
+	ScheduledCallCount, ScheduledCalls, H160,
 };
 
 use crate::test_utils::builder::Contract;
@@ -55,11 +56,11 @@ use frame_support::{
 	traits::{
 		fungible::{BalancedHold, Inspect, Mutate, MutateHold},
 		tokens::Preservation,
-		ConstU32, ConstU64, Contains, OnIdle, OnInitialize, StorageVersion,
+		ConstU32, ConstU64, Contains, EqualPrivilegeOnly, OnIdle, OnInitialize, StorageVersion,
 	},
 	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, FixedFee, IdentityFee, Weight, WeightMeter},
 };
-use frame_system::{EventRecord, Phase};
+use frame_system::{EnsureRoot, EventRecord, Phase};
 use pallet_revive_fixtures::{bench::dummy_unique, compile_module};
 use pallet_revive_uapi::ReturnErrorCode as RuntimeReturnCode;
 use pallet_transaction_payment::{ConstFeeMultiplier, Multiplier};
@@ -84,6 +85,7 @@ frame_support::construct_runtime!(
 		Utility: pallet_utility,
 		Contracts: pallet_revive,
 		Proxy: pallet_proxy,
+		Scheduler: pallet_scheduler,
 		TransactionPayment: pallet_transaction_payment,
 		Dummy: pallet_dummy
 	}
@@ -377,6 +379,7 @@ parameter_types! {
 			Weight::from_parts(2 * WEIGHT_REF_TIME_PER_SECOND, u64::MAX),
 		);
 	pub static ExistentialDeposit: u64 = 1;
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * BlockWeights::get().max_block;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -405,6 +408,19 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_scheduler::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	type ScheduleOrigin = EnsureRoot<AccountId32>;
+	type MaxScheduledPerBlock = ConstU32<100>;
+	type WeightInfo = ();
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type Preimages = ();
+}
+
 impl pallet_proxy::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -419,6 +435,7 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CallValue = ();
 }
 
 parameter_types! {
@@ -525,6 +542,7 @@ impl Config for Test {
 	type CodeHashLockupDepositPercent = CodeHashLockupDepositPercent;
 	type Debug = TestDebug;
 	type ChainId = ChainId;
+	type Scheduler = Scheduler;
 }
 
 impl TryFrom<RuntimeCall> for crate::Call<Test> {
@@ -737,6 +755,25 @@ fn instantiate_and_call_and_deposit_event() {
 	});
 }
 
+#[test]
+fn block_logs_bloom_works() {
+	let (wasm, _code_hash) = compile_module("event_and_return_on_deploy").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, .. } =
+			builder::bare_instantiate(Code::Upload(wasm)).build_and_unwrap_contract();
+
+		let bloom = Bytes256(BlockLogsBloom::<Test>::get());
+		assert!(bloom.contains_input(addr.as_ref()));
+		assert!(bloom.contains_input(H256::repeat_byte(42).as_ref()));
+
+		// The bloom filter only covers the block it was built in.
+		Contracts::on_initialize(2);
+		assert_eq!(BlockLogsBloom::<Test>::get(), [0u8; 256]);
+	});
+}
+
 #[test]
 fn create1_address_from_extrinsic() {
 	let (wasm, code_hash) = compile_module("dummy").unwrap();
@@ -2678,6 +2715,155 @@ fn upload_code_limit_too_low() {
 	});
 }
 
+#[test]
+fn upload_allowlist_works() {
+	let (wasm, code_hash) = compile_module("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+
+		assert_noop!(
+			Contracts::set_upload_allowlist(RuntimeOrigin::signed(ALICE), true),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(Contracts::set_upload_allowlist(RuntimeOrigin::root(), true));
+
+		// Neither the uploader nor the code hash is allowlisted.
+		assert_noop!(
+			Contracts::upload_code(RuntimeOrigin::signed(ALICE), wasm.clone(), 1_000),
+			<Error<Test>>::UploadNotAllowed,
+		);
+
+		// An allowlisted uploader can upload any code.
+		assert_ok!(Contracts::set_uploader_allowed(RuntimeOrigin::root(), ALICE_ADDR, true));
+		assert_ok!(Contracts::upload_code(RuntimeOrigin::signed(ALICE), wasm.clone(), 1_000));
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash));
+
+		// Anyone can upload an allowlisted code hash.
+		assert_noop!(
+			Contracts::upload_code(RuntimeOrigin::signed(BOB), wasm.clone(), 1_000),
+			<Error<Test>>::UploadNotAllowed,
+		);
+		assert_ok!(Contracts::set_code_hash_allowed(RuntimeOrigin::root(), code_hash, true));
+		assert_ok!(Contracts::upload_code(RuntimeOrigin::signed(BOB), wasm.clone(), 1_000));
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(BOB), code_hash));
+
+		// Disabling the allowlist makes uploads permissionless again.
+		assert_ok!(Contracts::set_code_hash_allowed(RuntimeOrigin::root(), code_hash, false));
+		assert_ok!(Contracts::set_upload_allowlist(RuntimeOrigin::root(), false));
+		assert_ok!(Contracts::upload_code(RuntimeOrigin::signed(BOB), wasm, 1_000));
+		assert!(PristineCode::<Test>::contains_key(&code_hash));
+	});
+}
+
+#[test]
+fn dispatch_scheduled_call_works() {
+	let (wasm, _code_hash) = compile_module("ok_trap_revert").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let Contract { addr, account_id } =
+			builder::bare_instantiate(Code::Upload(wasm)).value(10_000).build_and_unwrap_contract();
+
+		// The contract reverts on every execution.
+		assert_ok!(<Test as Config>::Currency::hold(
+			&HoldReason::ScheduledCallDeposit.into(),
+			&account_id,
+			50
+		));
+		ScheduledCalls::<Test>::insert(
+			addr,
+			0,
+			ScheduledCall {
+				input_data: vec![1].try_into().unwrap(),
+				gas_limit: GAS_LIMIT,
+				storage_deposit_limit: deposit_limit::<Test>(),
+				remaining: 2,
+				deposit: 50,
+			},
+		);
+		ScheduledCallCount::<Test>::insert(addr, 1);
+
+		// Only the contract itself can dispatch its scheduled calls.
+		assert_noop!(
+			Contracts::dispatch_scheduled_call(RuntimeOrigin::signed(ALICE), 0, GAS_LIMIT),
+			<Error<Test>>::ScheduledCallNotFound,
+		);
+
+		initialize_block(2);
+		assert_ok!(Contracts::dispatch_scheduled_call(
+			RuntimeOrigin::signed(account_id.clone()),
+			0,
+			GAS_LIMIT
+		));
+		assert_eq!(ScheduledCalls::<Test>::get(addr, 0).unwrap().remaining, 1);
+		assert_eq!(
+			System::events().last().unwrap().event,
+			RuntimeEvent::Contracts(crate::Event::ScheduledCallFailed { contract: addr, id: 0 }),
+		);
+
+		// The deposit is released after the last execution.
+		assert_ok!(Contracts::dispatch_scheduled_call(
+			RuntimeOrigin::signed(account_id.clone()),
+			0,
+			GAS_LIMIT
+		));
+		assert!(!ScheduledCalls::<Test>::contains_key(addr, 0));
+		assert_eq!(ScheduledCallCount::<Test>::get(addr), None);
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::ScheduledCallDeposit.into(), &account_id),
+			0
+		);
+	});
+}
+
+#[test]
+fn terminate_removes_scheduled_calls() {
+	let (wasm, _code_hash) = compile_module("self_destruct").unwrap();
+
+	ExtBuilder::default().existential_deposit(1_000).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&DJANGO_FALLBACK, 1_000_000);
+		let min_balance = Contracts::min_balance();
+		let Contract { addr, account_id } = builder::bare_instantiate(Code::Upload(wasm))
+			.value(100_000)
+			.build_and_unwrap_contract();
+
+		for id in 0..2 {
+			assert_ok!(<Test as Config>::Currency::hold(
+				&HoldReason::ScheduledCallDeposit.into(),
+				&account_id,
+				50
+			));
+			ScheduledCalls::<Test>::insert(
+				addr,
+				id,
+				ScheduledCall {
+					input_data: Default::default(),
+					gas_limit: GAS_LIMIT,
+					storage_deposit_limit: deposit_limit::<Test>(),
+					remaining: 1,
+					deposit: 50,
+				},
+			);
+		}
+		ScheduledCallCount::<Test>::insert(addr, 2);
+
+		// Call the contract without input data, which terminates it.
+		assert_ok!(builder::call(addr).build());
+
+		// The scheduled calls are removed and their deposits go to the beneficiary.
+		assert_eq!(ScheduledCalls::<Test>::iter_prefix(addr).count(), 0);
+		assert_eq!(ScheduledCallCount::<Test>::get(addr), None);
+		assert_eq!(<Test as Config>::Currency::total_balance(&account_id), 0);
+		assert_eq!(
+			<Test as Config>::Currency::free_balance(DJANGO_FALLBACK),
+			1_000_000 + 100_000 + min_balance
+		);
+	});
+}
+
 #[test]
 fn upload_code_not_enough_balance() {
 	let (wasm, _code_hash) = compile_module("dummy").unwrap();
@@ -2740,6 +2926,108 @@ fn remove_code_works() {
 	});
 }
 
+#[test]
+fn code_metadata_works() {
+	let (wasm, code_hash) = compile_module("dummy").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = <Test as Config>::Currency::set_balance(&ALICE, 1_000_000);
+		let _ = <Test as Config>::Currency::set_balance(&BOB, 1_000_000);
+		let abi_hash = H256::repeat_byte(1);
+		let uri = b"ipfs://abi".to_vec();
+
+		assert_noop!(
+			Contracts::set_code_metadata(
+				RuntimeOrigin::signed(ALICE),
+				code_hash,
+				abi_hash,
+				uri.clone()
+			),
+			Error::<Test>::CodeNotFound,
+		);
+		assert_ok!(Contracts::upload_code(RuntimeOrigin::signed(ALICE), wasm, 1_000));
+
+		// Only the owner of the code can register its metadata.
+		assert_noop!(
+			Contracts::set_code_metadata(
+				RuntimeOrigin::signed(BOB),
+				code_hash,
+				abi_hash,
+				uri.clone()
+			),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			Contracts::set_code_metadata(
+				RuntimeOrigin::signed(ALICE),
+				code_hash,
+				abi_hash,
+				vec![0; limits::code::METADATA_URI_BYTES as usize + 1],
+			),
+			Error::<Test>::CodeMetadataUriTooLong,
+		);
+
+		assert_ok!(Contracts::set_code_metadata(
+			RuntimeOrigin::signed(ALICE),
+			code_hash,
+			abi_hash,
+			uri.clone()
+		));
+		assert_eq!(
+			Contracts::code_metadata(code_hash),
+			Some(CodeMetadataInfo { abi_hash, uri: uri.clone() })
+		);
+		let deposit = DepositPerByte::get() * (32 + 1 + uri.len() as u64) + DepositPerItem::get();
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeMetadataDeposit.into(), &ALICE),
+			deposit
+		);
+
+		// Replacing the metadata charges the deposit of the new one.
+		let uri = b"ipfs://new-abi".to_vec();
+		assert_ok!(Contracts::set_code_metadata(
+			RuntimeOrigin::signed(ALICE),
+			code_hash,
+			abi_hash,
+			uri.clone()
+		));
+		let deposit = DepositPerByte::get() * (32 + 1 + uri.len() as u64) + DepositPerItem::get();
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeMetadataDeposit.into(), &ALICE),
+			deposit
+		);
+
+		assert_noop!(
+			Contracts::clear_code_metadata(RuntimeOrigin::signed(BOB), code_hash),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(Contracts::clear_code_metadata(RuntimeOrigin::signed(ALICE), code_hash));
+		assert_eq!(Contracts::code_metadata(code_hash), None);
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeMetadataDeposit.into(), &ALICE),
+			0
+		);
+		assert_noop!(
+			Contracts::clear_code_metadata(RuntimeOrigin::signed(ALICE), code_hash),
+			Error::<Test>::CodeMetadataNotFound,
+		);
+
+		// Removing the code removes its metadata as well.
+		assert_ok!(Contracts::set_code_metadata(
+			RuntimeOrigin::signed(ALICE),
+			code_hash,
+			abi_hash,
+			uri.clone()
+		));
+		assert_ok!(Contracts::remove_code(RuntimeOrigin::signed(ALICE), code_hash));
+		assert_eq!(Contracts::code_metadata(code_hash), None);
+		assert_eq!(
+			test_utils::get_balance_on_hold(&HoldReason::CodeMetadataDeposit.into(), &ALICE),
+			0
+		);
+	});
+}
+
 #[test]
 fn remove_code_wrong_origin() {
 	let (wasm, code_hash) = compile_module("dummy").unwrap();
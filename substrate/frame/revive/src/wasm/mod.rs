@@ -162,6 +162,7 @@ where
 
 				*existing = None;
 				<PristineCode<T>>::remove(&code_hash);
+				<Pallet<T>>::remove_code_metadata(code_hash);
 				<Pallet<T>>::deposit_event(Event::CodeRemoved {
 					code_hash,
 					deposit_released,
@@ -24,6 +24,7 @@ use crate::{
 	gas::{ChargedAmount, Token},
 	limits,
 	primitives::ExecReturnValue,
+	storage::ExecutionPolicy,
 	weights::WeightInfo,
 	Config, Error, LOG_TARGET, SENTINEL,
 };
@@ -397,6 +398,14 @@ pub enum RuntimeCosts {
 	LockDelegateDependency,
 	/// Weight of calling `unlock_delegate_dependency`
 	UnlockDelegateDependency,
+	/// Weight of calling `set_execution_policy`
+	SetExecutionPolicy,
+	/// Weight of calling `schedule_call` with the given input length.
+	ScheduleCall(u32),
+	/// Weight of calling `cancel_scheduled_call`
+	CancelScheduledCall,
+	/// Weight of cancelling the given number of scheduled calls when terminating.
+	CancelScheduledCalls(u32),
 	/// Weight of calling `get_immutable_dependency`
 	GetImmutableData(u32),
 	/// Weight of calling `set_immutable_dependency`
@@ -528,6 +537,11 @@ impl<T: Config> Token<T> for RuntimeCosts {
 			EcdsaToEthAddress => T::WeightInfo::seal_ecdsa_to_eth_address(),
 			LockDelegateDependency => T::WeightInfo::lock_delegate_dependency(),
 			UnlockDelegateDependency => T::WeightInfo::unlock_delegate_dependency(),
+			SetExecutionPolicy => T::WeightInfo::seal_set_execution_policy(),
+			ScheduleCall(len) => T::WeightInfo::seal_schedule_call(len),
+			CancelScheduledCall => T::WeightInfo::seal_cancel_scheduled_call(),
+			CancelScheduledCalls(count) =>
+				T::WeightInfo::seal_cancel_scheduled_call().saturating_mul(count.into()),
 			GetImmutableData(len) => T::WeightInfo::seal_get_immutable_data(len),
 			SetImmutableData(len) => T::WeightInfo::seal_set_immutable_data(len),
 		}
@@ -1141,6 +1155,8 @@ impl<'a, E: Ext, M: ?Sized + Memory<E::T>> Runtime<'a, E, M> {
 	fn terminate(&mut self, memory: &M, beneficiary_ptr: u32) -> Result<(), TrapReason> {
 		let count = self.ext.locked_delegate_dependencies_count() as _;
 		self.charge_gas(RuntimeCosts::Terminate(count))?;
+		let scheduled = self.ext.scheduled_calls_count();
+		self.charge_gas(RuntimeCosts::CancelScheduledCalls(scheduled))?;
 
 		let beneficiary = memory.read_h160(beneficiary_ptr)?;
 		self.ext.terminate(&beneficiary)?;
@@ -2054,6 +2070,66 @@ pub mod env {
 		Ok(())
 	}
 
+	/// Sets the execution policy of the contract.
+	/// See [`pallet_revive_uapi::HostFn::set_execution_policy`].
+	#[mutating]
+	fn set_execution_policy(
+		&mut self,
+		memory: &mut M,
+		max_call_depth: u32,
+		deny_reentry: u32,
+	) -> Result<(), TrapReason> {
+		self.charge_gas(RuntimeCosts::SetExecutionPolicy)?;
+		self.ext.set_execution_policy(ExecutionPolicy {
+			max_call_depth: (max_call_depth != 0).then_some(max_call_depth),
+			deny_reentry: deny_reentry != 0,
+		});
+		Ok(())
+	}
+
+	/// Schedule a call of the contract to itself.
+	/// See [`pallet_revive_uapi::HostFn::schedule_call`].
+	#[mutating]
+	fn schedule_call(
+		&mut self,
+		memory: &mut M,
+		id: u32,
+		when: u64,
+		period: u32,
+		count: u32,
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit_ptr: u32,
+		input_data_ptr: u32,
+		input_data_len: u32,
+	) -> Result<(), TrapReason> {
+		self.charge_gas(RuntimeCosts::ScheduleCall(input_data_len))?;
+		if input_data_len > limits::PAYLOAD_BYTES {
+			return Err(Error::<E::T>::ValueTooLarge.into());
+		}
+		let deposit_limit = memory.read_u256(deposit_ptr)?;
+		let input_data = memory.read(input_data_ptr, input_data_len)?;
+		self.ext.schedule_call(
+			id,
+			when,
+			period,
+			count,
+			Weight::from_parts(ref_time_limit, proof_size_limit),
+			deposit_limit,
+			input_data,
+		)?;
+		Ok(())
+	}
+
+	/// Cancel a call the contract scheduled to itself.
+	/// See [`pallet_revive_uapi::HostFn::cancel_scheduled_call`].
+	#[mutating]
+	fn cancel_scheduled_call(&mut self, memory: &mut M, id: u32) -> Result<(), TrapReason> {
+		self.charge_gas(RuntimeCosts::CancelScheduledCall)?;
+		self.ext.cancel_scheduled_call(id)?;
+		Ok(())
+	}
+
 	/// Retrieve and remove the value under the given key from storage.
 	/// See [`pallet_revive_uapi::HostFn::take_storage`]
 	#[mutating]
@@ -129,6 +129,9 @@ pub trait WeightInfo {
 	fn seal_set_code_hash() -> Weight;
 	fn lock_delegate_dependency() -> Weight;
 	fn unlock_delegate_dependency() -> Weight;
+	fn seal_set_execution_policy() -> Weight;
+	fn seal_schedule_call(n: u32, ) -> Weight;
+	fn seal_cancel_scheduled_call() -> Weight;
 	fn instr(r: u32, ) -> Weight;
 }
 
@@ -630,18 +633,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(n.into()))
 	}
+	/// Storage: `Revive::BlockLogsBloom` (r:1 w:1)
+	/// Proof: `Revive::BlockLogsBloom` (`max_values`: Some(1), `max_size`: Some(256), added: 751, mode: `Measured`)
 	/// The range of component `t` is `[0, 4]`.
 	/// The range of component `n` is `[0, 448]`.
 	fn seal_deposit_event(t: u32, n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_140_000 picoseconds.
-		Weight::from_parts(4_259_301, 0)
-			// Standard Error: 3_362
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(7_035_114, 1741)
 			.saturating_add(Weight::from_parts(194_546, 0).saturating_mul(t.into()))
-			// Standard Error: 34
 			.saturating_add(Weight::from_parts(774, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// The range of component `i` is `[0, 262144]`.
 	fn seal_debug_message(i: u32, ) -> Weight {
@@ -1017,6 +1019,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	fn seal_set_execution_policy() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(301_000, 0)
+	}
+	/// Storage: `Revive::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCalls` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `Measured`)
+	/// Storage: `Revive::ScheduledCallCount` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCallCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `Measured`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// The range of component `n` is `[0, 448]`.
+	fn seal_schedule_call(n: u32, ) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(63_215_402, 42428)
+			.saturating_add(Weight::from_parts(1_211, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Revive::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCalls` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `Measured`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::ScheduledCallCount` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCallCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	fn seal_cancel_scheduled_call() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(49_870_000, 42428)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 	/// The range of component `r` is `[0, 5000]`.
 	fn instr(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -1526,18 +1568,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2563).saturating_mul(n.into()))
 	}
+	/// Storage: `Revive::BlockLogsBloom` (r:1 w:1)
+	/// Proof: `Revive::BlockLogsBloom` (`max_values`: Some(1), `max_size`: Some(256), added: 751, mode: `Measured`)
 	/// The range of component `t` is `[0, 4]`.
 	/// The range of component `n` is `[0, 448]`.
 	fn seal_deposit_event(t: u32, n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `0`
-		//  Estimated: `0`
-		// Minimum execution time: 4_140_000 picoseconds.
-		Weight::from_parts(4_259_301, 0)
-			// Standard Error: 3_362
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(7_035_114, 1741)
 			.saturating_add(Weight::from_parts(194_546, 0).saturating_mul(t.into()))
-			// Standard Error: 34
 			.saturating_add(Weight::from_parts(774, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// The range of component `i` is `[0, 262144]`.
 	fn seal_debug_message(i: u32, ) -> Weight {
@@ -1913,6 +1954,46 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	fn seal_set_execution_policy() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(301_000, 0)
+	}
+	/// Storage: `Revive::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCalls` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `Measured`)
+	/// Storage: `Revive::ScheduledCallCount` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCallCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `Measured`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	/// The range of component `n` is `[0, 448]`.
+	fn seal_schedule_call(n: u32, ) -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(63_215_402, 42428)
+			.saturating_add(Weight::from_parts(1_211, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Revive::ScheduledCalls` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCalls` (`max_values`: None, `max_size`: Some(552), added: 3027, mode: `Measured`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:1)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Agenda` (r:1 w:1)
+	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(38963), added: 41438, mode: `MaxEncodedLen`)
+	/// Storage: `Revive::ScheduledCallCount` (r:1 w:1)
+	/// Proof: `Revive::ScheduledCallCount` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(157), added: 2632, mode: `Measured`)
+	fn seal_cancel_scheduled_call() -> Weight {
+		// Placeholder: not produced by the benchmarking CLI yet.
+		Weight::from_parts(49_870_000, 42428)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
 	/// The range of component `r` is `[0, 5000]`.
 	fn instr(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
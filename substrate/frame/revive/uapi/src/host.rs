@@ -682,6 +682,70 @@ pub trait HostFn: private::Sealed {
 	#[unstable_hostfn]
 	fn set_code_hash(code_hash: &[u8; 32]);
 
+	/// Set the execution policy of the calling contract.
+	///
+	/// The policy is checked by the runtime whenever the contract is called, which makes it a
+	/// cheaper reentrancy guard than a lock kept in contract storage. Delegate calls are exempt.
+	///
+	/// # Parameters
+	///
+	/// - `max_call_depth`: The deepest position on the call stack at which the contract can be
+	///   called, where the first frame is at depth 1. `0` removes the limit.
+	/// - `deny_reentry`: If `true`, calls into the contract fail while it is already on the call
+	///   stack.
+	#[unstable_hostfn]
+	fn set_execution_policy(max_call_depth: u32, deny_reentry: bool);
+
+	/// Schedule a call of the calling contract to itself.
+	///
+	/// The call is dispatched by the runtime's scheduler with the contract as its caller and
+	/// without any value. Failed executions are reported with an event, but do not cancel the
+	/// remaining ones.
+	///
+	/// The contract pays upfront from its own balance for the weight of all executions. It also
+	/// places a deposit for storing the call, which is released after the last execution or when
+	/// the call is cancelled.
+	///
+	/// Traps if the `id` is in use, the contract has too many scheduled calls, the scheduler
+	/// rejects the call or the contract cannot pay for it.
+	///
+	/// # Parameters
+	///
+	/// - `id`: Identifies the call for [`cancel_scheduled_call`][`Self::cancel_scheduled_call`].
+	/// - `when`: The block number of the first execution.
+	/// - `period`: The number of blocks between executions.
+	/// - `count`: The number of executions. The call is executed once if this or `period` is `0`.
+	/// - `ref_time_limit`: How much *ref_time* Weight to devote to each execution.
+	/// - `proof_size_limit`: How much *proof_size* Weight to devote to each execution.
+	/// - `deposit_limit`: The storage deposit limit of each execution. Should be decodable as a
+	///   `U256`. Traps otherwise.
+	/// - `input`: The input data of each execution. Must not exceed 448 bytes.
+	#[unstable_hostfn]
+	fn schedule_call(
+		id: u32,
+		when: u64,
+		period: u32,
+		count: u32,
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit_limit: &[u8; 32],
+		input: &[u8],
+	);
+
+	/// Cancel a call the calling contract scheduled with
+	/// [`schedule_call`][`Self::schedule_call`].
+	///
+	/// The deposit is released, but the weight prepaid for the remaining executions is not
+	/// refunded.
+	///
+	/// Traps if the contract has no scheduled call with this `id`.
+	///
+	/// # Parameters
+	///
+	/// - `id`: The id the call was scheduled with.
+	#[unstable_hostfn]
+	fn cancel_scheduled_call(id: u32);
+
 	/// Verify a sr25519 signature
 	///
 	/// # Parameters
@@ -123,6 +123,9 @@ mod sys {
 			message_ptr: *const u8,
 		) -> ReturnCode;
 		pub fn set_code_hash(code_hash_ptr: *const u8);
+		pub fn set_execution_policy(max_call_depth: u32, deny_reentry: u32);
+		pub fn schedule_call(ptr: *const u8);
+		pub fn cancel_scheduled_call(id: u32);
 		pub fn ecdsa_to_eth_address(key_ptr: *const u8, out_ptr: *mut u8) -> ReturnCode;
 		pub fn instantiation_nonce() -> u64;
 		pub fn lock_delegate_dependency(code_hash_ptr: *const u8);
@@ -583,6 +586,54 @@ impl HostFn for HostFnImpl {
 		unsafe { sys::set_code_hash(code_hash.as_ptr()) }
 	}
 
+	#[unstable_hostfn]
+	fn set_execution_policy(max_call_depth: u32, deny_reentry: bool) {
+		unsafe { sys::set_execution_policy(max_call_depth, deny_reentry as u32) }
+	}
+
+	#[unstable_hostfn]
+	fn schedule_call(
+		id: u32,
+		when: u64,
+		period: u32,
+		count: u32,
+		ref_time_limit: u64,
+		proof_size_limit: u64,
+		deposit_limit: &[u8; 32],
+		input: &[u8],
+	) {
+		#[repr(C)]
+		#[allow(dead_code)]
+		struct Args {
+			id: u32,
+			when: u64,
+			period: u32,
+			count: u32,
+			ref_time_limit: u64,
+			proof_size_limit: u64,
+			deposit_limit: u32,
+			input: u32,
+			input_len: u32,
+		}
+		let args = Args {
+			id,
+			when,
+			period,
+			count,
+			ref_time_limit,
+			proof_size_limit,
+			deposit_limit: deposit_limit.as_ptr() as _,
+			input: input.as_ptr() as _,
+			input_len: input.len() as _,
+		};
+		unsafe { sys::schedule_call(&args as *const Args as *const _) }
+	}
+
+	#[unstable_hostfn]
+	fn cancel_scheduled_call(id: u32) {
+		unsafe { sys::cancel_scheduled_call(id) }
+	}
+
 	#[unstable_hostfn]
 	fn sr25519_verify(signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> Result {
 		let ret_code = unsafe {
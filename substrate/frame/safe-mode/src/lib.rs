@@ -37,6 +37,8 @@
 //! - Origin configuration items are separated for privileged entering and exiting safe mode.
 //! - A configurable duration sets the number of blocks after which the system will exit safe mode.
 //! - Safe mode may be extended beyond the configured exit by additional calls.
+//! - Other runtime components can automatically enter safe mode for a bounded duration through the
+//!   [`SafeModeTrigger`](frame_support::traits::SafeModeTrigger) implementation.
 //!
 //! ### Example
 //!
@@ -85,6 +87,7 @@ use frame_support::{
 		},
 		tokens::{Fortitude, Precision},
 		CallMetadata, Contains, Defensive, GetCallMetadata, PalletInfoAccess, SafeModeNotify,
+		SafeModeTriggerId,
 	},
 	weights::Weight,
 	DefaultNoBound,
@@ -177,6 +180,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type ReleaseDelay: Get<Option<BlockNumberFor<Self>>>;
 
+		/// The maximal number of blocks that an automatic trigger can enter the safe-mode for.
+		///
+		/// Durations requested through [`frame_support::traits::SafeModeTrigger`] are capped to
+		/// this value.
+		#[pallet::constant]
+		type MaxTriggerDuration: Get<BlockNumberFor<Self>>;
+
+		/// The number of blocks that the faults reported by a source are counted for.
+		///
+		/// A window starts with the first fault that a source reports and the faults are forgotten
+		/// once it ends. Only reaching the threshold of
+		/// [`frame_support::traits::SafeModeTrigger::report_fault`] within a window triggers the
+		/// safe-mode.
+		#[pallet::constant]
+		type FaultWindow: Get<BlockNumberFor<Self>>;
+
 		// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -217,6 +236,12 @@ pub mod pallet {
 		/// Exited the safe-mode for a specific reason.
 		Exited { reason: ExitReason },
 
+		/// The safe-mode was automatically triggered until inclusively this block.
+		Triggered { source: SafeModeTriggerId, until: BlockNumberFor<T> },
+
+		/// A runtime component reported a fault that may trigger the safe-mode.
+		FaultReported { source: SafeModeTriggerId, count: u32 },
+
 		/// An account reserved funds for either entering or extending the safe-mode.
 		DepositPlaced { account: T::AccountId, amount: BalanceOf<T> },
 
@@ -247,6 +272,15 @@ pub mod pallet {
 		Force,
 	}
 
+	/// The faults that a source reported within its current fault window.
+	#[derive(Copy, Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+	pub struct Faults<BlockNumber> {
+		/// The number of faults reported within the window.
+		pub count: u32,
+		/// The block that the window started in.
+		pub since: BlockNumber,
+	}
+
 	/// Contains the last block number that the safe-mode will remain entered in.
 	///
 	///  Set to `None` when safe-mode is exited.
@@ -255,6 +289,19 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type EnteredUntil<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
 
+	/// The source of the latest automatic trigger while the safe-mode is entered.
+	///
+	/// Set to `None` when safe-mode is exited.
+	#[pallet::storage]
+	pub type TriggeredBy<T: Config> = StorageValue<_, SafeModeTriggerId, OptionQuery>;
+
+	/// The faults that a source reported since it last triggered the safe-mode.
+	///
+	/// Faults are forgotten after [`Config::FaultWindow`] blocks, see [`Pallet::fault_count`].
+	#[pallet::storage]
+	pub type FaultCount<T: Config> =
+		StorageMap<_, Twox64Concat, SafeModeTriggerId, Faults<BlockNumberFor<T>>, OptionQuery>;
+
 	/// Holds the reserve that was taken from an account at a specific block number.
 	///
 	/// This helps governance to have an overview of outstanding deposits that should be returned or
@@ -505,11 +552,70 @@ impl<T: Config> Pallet<T> {
 	/// Errors if safe-mode is already exited.
 	pub(crate) fn do_exit(reason: ExitReason) -> Result<(), Error<T>> {
 		let _until = EnteredUntil::<T>::take().ok_or(Error::<T>::Exited)?;
+		TriggeredBy::<T>::kill();
 		Self::deposit_event(Event::Exited { reason });
 		T::Notify::exited();
 		Ok(())
 	}
 
+	/// Logic for [`frame_support::traits::SafeModeTrigger::trigger`].
+	///
+	/// Enters the safe-mode for at most [`Config::MaxTriggerDuration`] blocks. An already entered
+	/// safe-mode is only extended if it would otherwise exit before the triggered duration ends.
+	pub(crate) fn do_trigger(
+		source: SafeModeTriggerId,
+		duration: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		let duration = duration.min(T::MaxTriggerDuration::get());
+		let until = <frame_system::Pallet<T>>::block_number().saturating_add(duration);
+
+		match EnteredUntil::<T>::get() {
+			Some(current) if current >= until => return Ok(()),
+			Some(_) => EnteredUntil::<T>::put(until),
+			None => {
+				EnteredUntil::<T>::put(until);
+				Self::deposit_event(Event::Entered { until });
+				T::Notify::entered();
+			},
+		}
+
+		TriggeredBy::<T>::put(source);
+		Self::deposit_event(Event::Triggered { source, until });
+		Ok(())
+	}
+
+	/// Logic for [`frame_support::traits::SafeModeTrigger::report_fault`].
+	pub(crate) fn do_report_fault(
+		source: SafeModeTriggerId,
+		threshold: u32,
+		duration: BlockNumberFor<T>,
+	) -> Result<(), Error<T>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut faults = Self::faults_of(source).unwrap_or(Faults { count: 0, since: now });
+		faults.count.saturating_inc();
+		let count = faults.count;
+		Self::deposit_event(Event::FaultReported { source, count });
+
+		if count < threshold {
+			FaultCount::<T>::insert(source, faults);
+			return Ok(())
+		}
+		FaultCount::<T>::remove(source);
+		Self::do_trigger(source, duration)
+	}
+
+	/// The faults of `source` within its current fault window, if it did not end yet.
+	fn faults_of(source: SafeModeTriggerId) -> Option<Faults<BlockNumberFor<T>>> {
+		let now = <frame_system::Pallet<T>>::block_number();
+		FaultCount::<T>::get(source)
+			.filter(|faults| now < faults.since.saturating_add(T::FaultWindow::get()))
+	}
+
+	/// Return the number of faults that `source` reported within its current fault window.
+	pub fn fault_count(source: SafeModeTriggerId) -> u32 {
+		Self::faults_of(source).map_or(0, |faults| faults.count)
+	}
+
 	/// Logic for the [`crate::Pallet::release_deposit`] and
 	/// [`crate::Pallet::force_release_deposit`] calls.
 	pub(crate) fn do_release(
@@ -580,6 +686,14 @@ impl<T: Config> Pallet<T> {
 		EnteredUntil::<T>::exists()
 	}
 
+	/// Return the restrictions that the safe-mode currently imposes.
+	pub fn status() -> SafeModeStatus<BlockNumberFor<T>> {
+		SafeModeStatus {
+			entered_until: EnteredUntil::<T>::get(),
+			triggered_by: TriggeredBy::<T>::get(),
+		}
+	}
+
 	/// Return whether the given call is allowed to be dispatched.
 	pub fn is_allowed(call: &T::RuntimeCall) -> bool
 	where
@@ -636,6 +750,23 @@ impl<T: Config> frame_support::traits::SafeMode for Pallet<T> {
 	}
 }
 
+impl<T: Config> frame_support::traits::SafeModeTrigger<BlockNumberFor<T>> for Pallet<T> {
+	fn trigger(
+		source: SafeModeTriggerId,
+		duration: BlockNumberFor<T>,
+	) -> Result<(), frame_support::traits::SafeModeError> {
+		Self::do_trigger(source, duration).map_err(Into::into)
+	}
+
+	fn report_fault(
+		source: SafeModeTriggerId,
+		threshold: u32,
+		duration: BlockNumberFor<T>,
+	) -> Result<(), frame_support::traits::SafeModeError> {
+		Self::do_report_fault(source, threshold, duration).map_err(Into::into)
+	}
+}
+
 impl<T: Config> From<Error<T>> for frame_support::traits::SafeModeError {
 	fn from(err: Error<T>) -> Self {
 		match err {
@@ -645,3 +776,27 @@ impl<T: Config> From<Error<T>> for frame_support::traits::SafeModeError {
 		}
 	}
 }
+
+/// The restrictions that the safe-mode currently imposes.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug, Encode, Decode, TypeInfo)]
+pub struct SafeModeStatus<BlockNumber> {
+	/// The last block that the safe-mode remains entered in, or `None` if it is exited.
+	pub entered_until: Option<BlockNumber>,
+	/// The source of the latest automatic trigger, if the safe-mode was triggered.
+	pub triggered_by: Option<SafeModeTriggerId>,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to query the restrictions imposed by the safe-mode.
+	pub trait SafeModeApi<Call, BlockNumber>
+	where
+		Call: codec::Codec,
+		BlockNumber: codec::Codec,
+	{
+		/// The current status of the safe-mode.
+		fn status() -> SafeModeStatus<BlockNumber>;
+
+		/// Whether `call` is allowed to be dispatched with the current restrictions.
+		fn is_allowed(call: Call) -> bool;
+	}
+}
@@ -139,6 +139,7 @@ impl pallet_proxy::Config for Test {
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
 	type BlockNumberProvider = frame_system::Pallet<Test>;
+	type CallValue = ();
 }
 
 /// The calls that can always bypass safe-mode.
@@ -158,6 +159,8 @@ parameter_types! {
 	pub const EnterDepositAmount: u64 = 100;
 	pub const ExtendDepositAmount: u64 = 100;
 	pub const ReleaseDelay: u64 = 20;
+	pub const MaxTriggerDuration: u64 = 12;
+	pub const FaultWindow: u64 = 10;
 	pub const SafeModeHoldReason: HoldReason = HoldReason::SafeMode;
 
 	pub const ForceEnterWeak: u64 = 3;
@@ -208,6 +211,8 @@ impl Config for Test {
 	type ForceDepositOrigin = EnsureSignedBy<ForceDepositOrigin, Self::AccountId>;
 	type ReleaseDelay = ReleaseDelay;
 	type Notify = MockedNotify;
+	type MaxTriggerDuration = MaxTriggerDuration;
+	type FaultWindow = FaultWindow;
 	type WeightInfo = ();
 }
 
@@ -221,6 +221,105 @@ fn notify_works() {
 	});
 }
 
+#[test]
+fn trigger_enters_for_bounded_duration() {
+	use frame_support::traits::SafeModeTrigger;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::trigger(*b"bridge00", 100));
+		// The duration is capped to `MaxTriggerDuration`.
+		let until = 1 + mock::MaxTriggerDuration::get();
+		assert_eq!(EnteredUntil::<Test>::get(), Some(until));
+		assert_eq!(
+			SafeMode::status(),
+			SafeModeStatus { entered_until: Some(until), triggered_by: Some(*b"bridge00") }
+		);
+		System::assert_last_event(Event::Triggered { source: *b"bridge00", until }.into());
+		assert_eq!(Notifications::get(), vec![(1, true)]);
+		assert_err!(
+			call_transfer().dispatch(RuntimeOrigin::signed(0)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		// A shorter trigger does not bring the exit forward.
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::trigger(*b"faults00", 2));
+		assert_eq!(EnteredUntil::<Test>::get(), Some(until));
+		assert_eq!(TriggeredBy::<Test>::get(), Some(*b"bridge00"));
+
+		run_to(until + 1);
+		assert_eq!(SafeMode::status(), SafeModeStatus { entered_until: None, triggered_by: None });
+		assert_ok!(call_transfer().dispatch(RuntimeOrigin::signed(0)));
+	});
+}
+
+#[test]
+fn trigger_extends_but_cannot_shorten() {
+	use frame_support::traits::SafeModeTrigger;
+
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(RuntimeOrigin::signed(0)));
+		let until = 1 + mock::EnterDuration::get();
+
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::trigger(*b"bridge00", 3));
+		assert_eq!(EnteredUntil::<Test>::get(), Some(until));
+		assert_eq!(TriggeredBy::<Test>::get(), None);
+
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::trigger(*b"bridge00", 10));
+		assert_eq!(EnteredUntil::<Test>::get(), Some(11));
+		assert_eq!(TriggeredBy::<Test>::get(), Some(*b"bridge00"));
+		assert_eq!(Notifications::get(), vec![(1, true)]);
+
+		// Governance can still exit.
+		assert_ok!(SafeMode::force_exit(signed(mock::ForceExitOrigin::get())));
+		assert_eq!(TriggeredBy::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn report_fault_triggers_at_threshold() {
+	use frame_support::traits::SafeModeTrigger;
+
+	new_test_ext().execute_with(|| {
+		for count in 1..3 {
+			assert_ok!(<SafeMode as SafeModeTrigger<u64>>::report_fault(*b"faults00", 3, 5));
+			assert_eq!(SafeMode::fault_count(*b"faults00"), count);
+			assert!(!SafeMode::is_entered());
+		}
+
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::report_fault(*b"faults00", 3, 5));
+		assert_eq!(SafeMode::fault_count(*b"faults00"), 0);
+		assert!(!FaultCount::<Test>::contains_key(*b"faults00"));
+		assert_eq!(EnteredUntil::<Test>::get(), Some(6));
+		System::assert_has_event(Event::FaultReported { source: *b"faults00", count: 3 }.into());
+		System::assert_last_event(Event::Triggered { source: *b"faults00", until: 6 }.into());
+	});
+}
+
+#[test]
+fn report_fault_forgets_faults_after_window() {
+	use frame_support::traits::SafeModeTrigger;
+
+	new_test_ext().execute_with(|| {
+		// The window starts in block 1.
+		for _ in 0..2 {
+			assert_ok!(<SafeMode as SafeModeTrigger<u64>>::report_fault(*b"faults00", 3, 5));
+		}
+		run_to(mock::FaultWindow::get());
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::report_fault(*b"other000", 3, 5));
+		assert_eq!(SafeMode::fault_count(*b"faults00"), 2);
+
+		// The faults of the previous window are forgotten.
+		run_to(1 + mock::FaultWindow::get());
+		assert_eq!(SafeMode::fault_count(*b"faults00"), 0);
+		assert_eq!(SafeMode::fault_count(*b"other000"), 1);
+
+		assert_ok!(<SafeMode as SafeModeTrigger<u64>>::report_fault(*b"faults00", 3, 5));
+		assert_eq!(SafeMode::fault_count(*b"faults00"), 1);
+		assert!(!SafeMode::is_entered());
+		System::assert_last_event(Event::FaultReported { source: *b"faults00", count: 1 }.into());
+	});
+}
+
 #[test]
 fn cannot_extend() {
 	new_test_ext().execute_with(|| {
@@ -300,6 +300,138 @@ pub mod v4 {
 	}
 }
 
+/// Migration adjusting the agendas to a change of the block time.
+pub mod block_time {
+	use super::*;
+	use alloc::collections::btree_map::BTreeMap;
+	use frame_support::{migrations::BlockTimeChange, pallet_prelude::*};
+	use sp_runtime::traits::AtLeast32BitUnsigned;
+
+	/// Move the scheduled tasks after the block time changed from `OldBlockTime` to
+	/// `NewBlockTime`, both in milliseconds, so that they keep running at the same time.
+	///
+	/// Agendas of future blocks are moved, so that their distance to the current block spans the
+	/// same time with the new block time. Periods of periodic tasks and retries are converted as
+	/// well. Agendas that are due already are left untouched. Tasks that no longer fit into their
+	/// new block are pushed to the next one with room, and `Lookup` and `Retries` follow the new
+	/// addresses.
+	///
+	/// All agendas are migrated in a single block. The migration does not modify the pallet
+	/// version, but records the new block time with [`BlockTimeChange::mark_applied`]. It does
+	/// nothing if it is still in the runtime in a later upgrade, and should be removed then.
+	pub struct ChangeBlockTime<T, OldBlockTime, NewBlockTime>(
+		core::marker::PhantomData<(T, OldBlockTime, NewBlockTime)>,
+	);
+
+	impl<T: Config, OldBlockTime: Get<u64>, NewBlockTime: Get<u64>> OnRuntimeUpgrade
+		for ChangeBlockTime<T, OldBlockTime, NewBlockTime>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let change = BlockTimeChange::from_get::<OldBlockTime, NewBlockTime>();
+			if !change.should_apply::<Pallet<T>>() {
+				log::warn!(
+					target: TARGET,
+					"Block time did not change or was applied already, ChangeBlockTime should be \
+					removed."
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut agendas: Vec<_> = Agenda::<T>::iter().collect();
+			let (mut reads, mut writes) = (agendas.len() as u64 + 1, 1u64);
+			agendas.retain(|(when, _)| *when > now);
+			agendas.sort_by_key(|(when, _)| *when);
+			for (when, _) in &agendas {
+				Agenda::<T>::remove(when);
+				writes.saturating_inc();
+			}
+
+			let mut moved = BTreeMap::new();
+			for (old_when, agenda) in agendas {
+				// Never move a future agenda to the current block.
+				let mut when =
+					change.scale_future_block(now, old_when).max(now.saturating_add(One::one()));
+				for (old_index, task) in agenda.into_iter().enumerate() {
+					let Some(mut task) = task else { continue };
+					if let Some((period, count)) = task.maybe_periodic {
+						task.maybe_periodic = Some((scale_period(&change, period), count));
+					}
+					let index = loop {
+						reads.saturating_inc();
+						writes.saturating_inc();
+						match Pallet::<T>::push_to_agenda(when, task) {
+							Ok(index) => break index,
+							Err((_, t)) => {
+								task = t;
+								when.saturating_inc();
+							},
+						}
+					};
+					moved.insert((old_when, old_index as u32), (when, index));
+				}
+			}
+
+			Lookup::<T>::translate::<TaskAddress<BlockNumberFor<T>>, _>(|_, address| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				Some(moved.get(&address).copied().unwrap_or(address))
+			});
+
+			let retries: Vec<_> = Retries::<T>::iter().collect();
+			reads.saturating_accrue(retries.len() as u64);
+			for (address, mut config) in retries {
+				config.period = scale_period(&change, config.period);
+				let new_address = moved.get(&address).copied().unwrap_or(address);
+				if new_address != address {
+					Retries::<T>::remove(address);
+					writes.saturating_inc();
+				}
+				Retries::<T>::insert(new_address, config);
+				writes.saturating_inc();
+			}
+			change.mark_applied::<Pallet<T>>();
+
+			log::info!(
+				target: TARGET,
+				"Moved {} tasks from {}ms to {}ms blocks",
+				moved.len(),
+				change.old,
+				change.new
+			);
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let tasks = Agenda::<T>::iter_values().flatten().filter(Option::is_some).count();
+			Ok((tasks as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let before: u32 =
+				Decode::decode(&mut state.as_ref()).expect("Must decode pre_upgrade state");
+			let after = Agenda::<T>::iter_values().flatten().filter(Option::is_some).count();
+			ensure!(before == after as u32, "Scheduled tasks were lost during the migration");
+			for (name, (when, index)) in Lookup::<T>::iter() {
+				let task = Agenda::<T>::get(when).get(index as usize).cloned().flatten();
+				ensure!(
+					task.map_or(false, |t| t.maybe_id == Some(name)),
+					"Lookup points to the wrong task"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// A period never drops to zero blocks.
+	fn scale_period<N: AtLeast32BitUnsigned>(change: &BlockTimeChange, period: N) -> N {
+		change.scale_blocks(period).max(One::one())
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -3035,3 +3035,55 @@ fn unavailable_call_is_detected() {
 		assert!(!Preimage::is_requested(&hash));
 	});
 }
+
+#[test]
+fn change_block_time_moves_future_agendas() {
+	use crate::migration::block_time::ChangeBlockTime;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	frame_support::parameter_types! {
+		pub const Old: u64 = 12_000;
+		pub const New: u64 = 6_000;
+	}
+
+	new_test_ext().execute_with(|| {
+		run_to_block(2);
+		let call = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) }))
+		};
+		assert_ok!(Scheduler::schedule_named(
+			root().into(),
+			[1u8; 32],
+			10,
+			Some((3, 2)),
+			127,
+			call(42),
+		));
+		assert_ok!(Scheduler::schedule(root().into(), 5, None, 127, call(69)));
+		assert_ok!(Scheduler::set_retry(root().into(), (10, 0), 3, 4));
+
+		ChangeBlockTime::<Test, Old, New>::on_runtime_upgrade();
+
+		assert!(Agenda::<Test>::get(10).is_empty());
+		assert!(Agenda::<Test>::get(5).is_empty());
+		let task = Agenda::<Test>::get(18)[0].clone().unwrap();
+		assert_eq!(task.maybe_periodic, Some((6, 2)));
+		assert!(Agenda::<Test>::get(8)[0].is_some());
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((18, 0)));
+		assert_eq!(Retries::<Test>::get((10, 0)), None);
+		assert_eq!(
+			Retries::<Test>::get((18, 0)),
+			Some(RetryConfig { total_retries: 3, remaining: 3, period: 8 })
+		);
+
+		// Leaving the migration in the runtime does not apply it again.
+		ChangeBlockTime::<Test, Old, New>::on_runtime_upgrade();
+		assert_eq!(Lookup::<Test>::get([1u8; 32]), Some((18, 0)));
+		assert!(Agenda::<Test>::get(8)[0].is_some());
+
+		run_to_block(8);
+		assert_eq!(logger::log(), vec![(root(), 69u32)]);
+		run_to_block(18);
+		assert_eq!(logger::log(), vec![(root(), 69u32), (root(), 42u32)]);
+	});
+}
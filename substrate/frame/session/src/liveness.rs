@@ -0,0 +1,332 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An opt-in utility for tracking the liveness of validators in FRAME-session.
+//!
+//! This is a lightweight replacement of the heartbeats of `pallet-im-online`: rather than having
+//! validators gossip and submit heartbeats, their liveness is attested by what they already do on
+//! chain. Every block authored by a validator, as noted by `pallet-authorship` through the
+//! [`pallet_authorship::EventHandler`] implementation, and every participation noted with
+//! [`Pallet::note_participation`], e.g. backing a parachain candidate or making it available,
+//! counts towards the participation of the validator in the current session.
+//!
+//! When a session ends, as reported by the [`NoteLiveness`] session manager, the validators that
+//! did not participate at all are inactive. Validators that have been inactive for
+//! [`Config::MaxInactiveSessions`] consecutive sessions or more are reported to the offences
+//! pipeline with an [`InactivityOffence`], once per session until they participate again.
+
+use alloc::vec::Vec;
+use frame_support::{
+	traits::{Get, ValidatorSet, ValidatorSetWithIdentification},
+	LOG_TARGET,
+};
+use sp_runtime::{traits::Convert, Perbill, RuntimeDebug};
+use sp_staking::{
+	offence::{Kind, Offence, ReportOffence},
+	SessionIndex,
+};
+
+use crate::SessionManager;
+
+pub use pallet::*;
+
+/// A type for representing the validator id in a session.
+pub type ValidatorId<T> = <<T as Config>::ValidatorSet as ValidatorSet<
+	<T as frame_system::Config>::AccountId,
+>>::ValidatorId;
+
+/// A tuple of (ValidatorId, Identification) where `Identification` is the full identification of
+/// `ValidatorId`.
+pub type IdentificationTuple<T> = (
+	ValidatorId<T>,
+	<<T as Config>::ValidatorSet as ValidatorSetWithIdentification<
+		<T as frame_system::Config>::AccountId,
+	>>::Identification,
+);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Config necessary for the liveness pallet.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// A type for retrieving the validators supposed to be alive in a session.
+		type ValidatorSet: ValidatorSetWithIdentification<Self::AccountId>;
+
+		/// A type that gives us the ability to submit inactivity offence reports.
+		type ReportInactivity: ReportOffence<
+			Self::AccountId,
+			IdentificationTuple<Self>,
+			InactivityOffence<IdentificationTuple<Self>>,
+		>;
+
+		/// The number of consecutive sessions a validator can be inactive for before it is
+		/// reported.
+		#[pallet::constant]
+		type MaxInactiveSessions: Get<SessionIndex>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Validators have been reported for being inactive for too long, at the end of
+		/// `session_index`.
+		InactivityReported { session_index: SessionIndex, validators: Vec<ValidatorId<T>> },
+	}
+
+	/// For each session index, we keep a mapping of `ValidatorId<T>` to the number of blocks
+	/// authored and participations noted by the given validator.
+	#[pallet::storage]
+	pub type Participation<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		ValidatorId<T>,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The number of consecutive sessions that ended without any participation of a validator.
+	///
+	/// Only validators of the last ended session that were inactive in it are present.
+	#[pallet::storage]
+	pub type InactiveSessions<T: Config> =
+		StorageMap<_, Twox64Concat, ValidatorId<T>, SessionIndex, ValueQuery>;
+}
+
+impl<T: Config> Pallet<T> {
+	/// Note that `who` participated in the current session, e.g. by backing a parachain candidate.
+	pub fn note_participation(who: ValidatorId<T>) {
+		let current_session = T::ValidatorSet::session_index();
+
+		Participation::<T>::mutate(current_session, who, |participation| {
+			*participation = participation.saturating_add(1)
+		});
+	}
+
+	/// Whether `who` participated in the current session.
+	pub fn is_alive(who: &ValidatorId<T>) -> bool {
+		Participation::<T>::get(T::ValidatorSet::session_index(), who) > 0
+	}
+
+	/// Account for the liveness of the validators of the ending session, and report the ones that
+	/// have been inactive for too long.
+	fn end_session(end_index: SessionIndex) {
+		let validators = T::ValidatorSet::validators();
+		let validator_set_count = validators.len() as u32;
+
+		let inactive = validators
+			.into_iter()
+			.filter(|validator| Participation::<T>::get(end_index, validator) == 0)
+			.map(|validator| {
+				let sessions = InactiveSessions::<T>::get(&validator).saturating_add(1);
+				(validator, sessions)
+			})
+			.collect::<Vec<_>>();
+
+		// The validators not in `inactive` either participated or left the set.
+		let _ = InactiveSessions::<T>::clear(u32::MAX, None);
+		let _ = Participation::<T>::clear_prefix(end_index, u32::MAX, None);
+		for (validator, sessions) in &inactive {
+			InactiveSessions::<T>::insert(validator, sessions);
+		}
+
+		let offenders = inactive
+			.into_iter()
+			.filter(|(_, sessions)| *sessions >= T::MaxInactiveSessions::get())
+			.filter_map(|(validator, _)| {
+				let full_id = T::ValidatorSet::IdentificationOf::convert(validator.clone())?;
+				Some((validator, full_id))
+			})
+			.collect::<Vec<IdentificationTuple<T>>>();
+		if offenders.is_empty() {
+			return
+		}
+
+		Self::deposit_event(Event::<T>::InactivityReported {
+			session_index: end_index,
+			validators: offenders.iter().map(|(validator, _)| validator.clone()).collect(),
+		});
+		let offence =
+			InactivityOffence { session_index: end_index, validator_set_count, offenders };
+		if let Err(e) = T::ReportInactivity::report_offence(Vec::new(), offence) {
+			log::warn!(target: LOG_TARGET, "Failed to report inactivity offence: {:?}", e);
+		}
+	}
+}
+
+/// Authoring a block counts as participating in the current session.
+impl<T: Config>
+	pallet_authorship::EventHandler<ValidatorId<T>, frame_system::pallet_prelude::BlockNumberFor<T>>
+	for Pallet<T>
+{
+	fn note_author(author: ValidatorId<T>) {
+		Self::note_participation(author);
+	}
+}
+
+/// Accounts for the liveness of validators at the end of each session, before passing it on to
+/// the inner session manager `I`.
+pub struct NoteLiveness<T, I>(core::marker::PhantomData<(T, I)>);
+
+impl<T: Config, V, I: SessionManager<V>> SessionManager<V> for NoteLiveness<T, I> {
+	fn new_session(new_index: SessionIndex) -> Option<Vec<V>> {
+		I::new_session(new_index)
+	}
+
+	fn new_session_genesis(new_index: SessionIndex) -> Option<Vec<V>> {
+		I::new_session_genesis(new_index)
+	}
+
+	fn start_session(start_index: SessionIndex) {
+		I::start_session(start_index)
+	}
+
+	fn end_session(end_index: SessionIndex) {
+		Pallet::<T>::end_session(end_index);
+		I::end_session(end_index)
+	}
+}
+
+/// An offence that is filed if a validator did not participate for
+/// [`Config::MaxInactiveSessions`] consecutive sessions.
+#[derive(RuntimeDebug, scale_info::TypeInfo)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq, Eq))]
+pub struct InactivityOffence<Offender> {
+	/// The session index at the end of which we report the inactive validators.
+	pub session_index: SessionIndex,
+	/// The size of the validator set in that session.
+	pub validator_set_count: u32,
+	/// Authorities that were inactive for too long.
+	pub offenders: Vec<Offender>,
+}
+
+impl<Offender: Clone> Offence<Offender> for InactivityOffence<Offender> {
+	const ID: Kind = *b"liveness:inactiv";
+	type TimeSlot = SessionIndex;
+
+	fn offenders(&self) -> Vec<Offender> {
+		self.offenders.clone()
+	}
+
+	fn session_index(&self) -> SessionIndex {
+		self.session_index
+	}
+
+	fn validator_set_count(&self) -> u32 {
+		self.validator_set_count
+	}
+
+	fn time_slot(&self) -> Self::TimeSlot {
+		self.session_index
+	}
+
+	fn slash_fraction(&self, offenders: u32) -> Perbill {
+		// Same as the unresponsiveness offence of `pallet-im-online`: 10% can be inactive with no
+		// slash, but after that, it linearly climbs up to 7% when 13/30 are inactive.
+		if let Some(threshold) = offenders.checked_sub(self.validator_set_count / 10 + 1) {
+			let x = Perbill::from_rational(3 * threshold, self.validator_set_count);
+			x.saturating_mul(Perbill::from_percent(7))
+		} else {
+			Perbill::default()
+		}
+	}
+}
+
+#[cfg(all(test, feature = "historical"))]
+mod tests {
+	use super::*;
+	use crate::mock::{force_new_session, new_test_ext, InactivityOffences, Session, System, Test};
+	use frame_support::traits::OnInitialize;
+	use pallet_authorship::EventHandler;
+
+	type Liveness = Pallet<Test>;
+
+	fn new_session() {
+		let now = System::block_number() + 1;
+		System::set_block_number(now);
+		force_new_session();
+		Session::on_initialize(now);
+	}
+
+	#[test]
+	fn participation_is_noted_per_session() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+			assert!(!Liveness::is_alive(&1));
+
+			Liveness::note_author(1);
+			Liveness::note_participation(1);
+			Liveness::note_participation(2);
+			assert_eq!(Participation::<Test>::get(0, 1), 2);
+			assert!(Liveness::is_alive(&1));
+			assert!(Liveness::is_alive(&2));
+			assert!(!Liveness::is_alive(&3));
+
+			new_session();
+			assert_eq!(Session::current_index(), 1);
+
+			// the participation of the ended session is cleared, and 3 is inactive.
+			assert!(!Liveness::is_alive(&1));
+			assert_eq!(Participation::<Test>::iter().count(), 0);
+			assert_eq!(InactiveSessions::<Test>::iter().collect::<Vec<_>>(), vec![(3, 1)]);
+			assert!(InactivityOffences::get().is_empty());
+		});
+	}
+
+	#[test]
+	fn prolonged_inactivity_is_reported() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			// 3 is inactive for two sessions, 2 only for the second one.
+			Liveness::note_participation(1);
+			Liveness::note_participation(2);
+			new_session();
+			Liveness::note_participation(1);
+			new_session();
+
+			assert_eq!(InactivityOffences::get(), vec![(1, vec![(3, 3)])]);
+			System::assert_last_event(
+				Event::<Test>::InactivityReported { session_index: 1, validators: vec![3] }.into(),
+			);
+			assert_eq!(InactiveSessions::<Test>::get(2), 1);
+
+			// 3 is reported again, until it participates.
+			Liveness::note_participation(1);
+			Liveness::note_participation(2);
+			new_session();
+			assert_eq!(InactivityOffences::get().last(), Some(&(2, vec![(3, 3)])));
+
+			Liveness::note_participation(1);
+			Liveness::note_participation(2);
+			Liveness::note_participation(3);
+			new_session();
+			assert_eq!(InactivityOffences::get().len(), 2);
+			assert_eq!(InactiveSessions::<Test>::iter().count(), 0);
+		});
+	}
+}
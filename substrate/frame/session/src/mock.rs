@@ -21,6 +21,8 @@ use super::*;
 use crate as pallet_session;
 #[cfg(feature = "historical")]
 use crate::historical as pallet_session_historical;
+#[cfg(feature = "historical")]
+use crate::liveness as pallet_session_liveness;
 
 use std::collections::BTreeMap;
 
@@ -77,6 +79,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Session: pallet_session,
 		Historical: pallet_session_historical,
+		Liveness: pallet_session_liveness,
 	}
 );
 
@@ -102,6 +105,9 @@ parameter_types! {
 	// Stores if `on_before_session_end` was called
 	pub static BeforeSessionEndCalled: bool = false;
 	pub static ValidatorAccounts: BTreeMap<u64, u64> = BTreeMap::new();
+	pub static MaxInactiveSessions: SessionIndex = 2;
+	// Stores the inactivity offences reported by `Liveness`.
+	pub static InactivityOffences: Vec<(SessionIndex, Vec<(u64, u64)>)> = vec![];
 }
 
 pub struct TestShouldEndSession;
@@ -251,7 +257,10 @@ impl Convert<u64, Option<u64>> for TestValidatorIdOf {
 impl Config for Test {
 	type ShouldEndSession = TestShouldEndSession;
 	#[cfg(feature = "historical")]
-	type SessionManager = crate::historical::NoteHistoricalRoot<Test, TestSessionManager>;
+	type SessionManager = crate::liveness::NoteLiveness<
+		Test,
+		crate::historical::NoteHistoricalRoot<Test, TestSessionManager>,
+	>;
 	#[cfg(not(feature = "historical"))]
 	type SessionManager = TestSessionManager;
 	type SessionHandler = TestSessionHandler;
@@ -268,3 +277,34 @@ impl crate::historical::Config for Test {
 	type FullIdentification = u64;
 	type FullIdentificationOf = sp_runtime::traits::ConvertInto;
 }
+
+#[cfg(feature = "historical")]
+pub struct TestReportInactivity;
+#[cfg(feature = "historical")]
+impl
+	sp_staking::offence::ReportOffence<
+		u64,
+		(u64, u64),
+		crate::liveness::InactivityOffence<(u64, u64)>,
+	> for TestReportInactivity
+{
+	fn report_offence(
+		_reporters: Vec<u64>,
+		offence: crate::liveness::InactivityOffence<(u64, u64)>,
+	) -> Result<(), sp_staking::offence::OffenceError> {
+		InactivityOffences::mutate(|o| o.push((offence.session_index, offence.offenders)));
+		Ok(())
+	}
+
+	fn is_known_offence(_offenders: &[(u64, u64)], _time_slot: &SessionIndex) -> bool {
+		false
+	}
+}
+
+#[cfg(feature = "historical")]
+impl crate::liveness::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorSet = Historical;
+	type ReportInactivity = TestReportInactivity;
+	type MaxInactiveSessions = MaxInactiveSessions;
+}
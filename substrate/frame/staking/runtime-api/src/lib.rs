@@ -19,6 +19,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::Codec;
 
 sp_api::decl_runtime_apis! {
@@ -35,5 +38,58 @@ sp_api::decl_runtime_apis! {
 
 		/// Returns true if validator `account` has pages to be claimed for the given era.
 		fn pending_rewards(era: sp_staking::EraIndex, account: AccountId) -> bool;
+
+		/// Returns the projected rewards of validator `account` and its nominators in the active
+		/// era, or `None` if there is no active era.
+		///
+		/// The era payout is computed as if the active era lasted `era_duration` milliseconds, or
+		/// as if it ended now if `None`.
+		#[api_version(2)]
+		fn projected_rewards(
+			account: AccountId,
+			era_duration: Option<u64>,
+		) -> Option<sp_staking::ProjectedRewards<AccountId, Balance>>;
+
+		/// Returns the era in which all the funds of stash `account` could be withdrawn, if it
+		/// unbonded its whole active stake now, or `None` if `account` is not bonded.
+		#[api_version(3)]
+		fn estimate_exit_era(account: AccountId) -> Option<sp_staking::EraIndex>;
+
+		/// Returns how the stake of nominator `account` is distributed across the validators of
+		/// the active era, or `None` if there is no active era.
+		#[api_version(4)]
+		fn nominator_backing(
+			account: AccountId,
+		) -> Option<sp_staking::NominatorBacking<AccountId, Balance>>;
+
+		/// Returns the breakdown of the balance of `account` into what is staked, what can still
+		/// be bonded and what cannot be staked.
+		#[api_version(5)]
+		fn stake_breakdown(account: AccountId) -> sp_staking::StakeableBreakdown<Balance>;
+
+		/// Returns the rolling performance of validator `account`, or `None` if it has never been
+		/// active nor reported for an offence.
+		#[api_version(6)]
+		fn validator_performance(account: AccountId) -> Option<sp_staking::ValidatorPerformance>;
+
+		/// Returns the deferred slashes that have not been applied yet, ordered by the era they
+		/// are applied in. Until then, they can be canceled by governance.
+		#[api_version(7)]
+		fn pending_slashes() -> Vec<sp_staking::PendingSlash<AccountId, Balance>>;
+
+		/// Returns the stashes queued to become validator candidates at the start of the next
+		/// era, if the candidacy queue of the staking pallet is enabled.
+		#[api_version(8)]
+		fn pending_candidates() -> Vec<sp_staking::PendingCandidate<AccountId, Balance>>;
+	}
+
+	/// The era points awarded by the sources of era points of the staking pallet other than block
+	/// authoring.
+	pub trait StakingEraPointsApi<Source>
+		where
+			Source: Codec,
+	{
+		/// Returns the era points awarded in era `era` by each source which awarded any.
+		fn era_points_by_source(era: sp_staking::EraIndex) -> Vec<(Source, u32)>;
 	}
 }
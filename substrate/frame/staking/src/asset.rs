@@ -19,6 +19,8 @@
 //! asset.
 
 use frame_support::traits::{Currency, InspectLockableCurrency, LockableCurrency};
+use sp_runtime::Saturating;
+use sp_staking::StakeableBreakdown;
 
 use crate::{BalanceOf, Config, NegativeImbalanceOf, PositiveImbalanceOf};
 
@@ -51,6 +53,24 @@ pub fn staked<T: Config>(who: &T::AccountId) -> BalanceOf<T> {
 	T::Currency::balance_locked(crate::STAKING_ID, who)
 }
 
+/// Breakdown of the balance of `who` into what is staked, what can still be staked and what
+/// cannot be staked at all.
+///
+/// Other locks overlap with the staking lock, so they do not reduce what can be staked. They are
+/// reported as the overlapping `frozen` balance.
+pub fn stake_breakdown<T: Config>(who: &T::AccountId) -> StakeableBreakdown<BalanceOf<T>> {
+	let stakeable = stakeable_balance::<T>(who);
+	let staked = staked::<T>(who);
+
+	StakeableBreakdown {
+		free_to_stake: stakeable.saturating_sub(staked),
+		staked,
+		reserved: total_balance::<T>(who).saturating_sub(stakeable),
+		frozen: T::Currency::balance_frozen(who),
+		existential_deposit: existential_deposit::<T>(),
+	}
+}
+
 /// Set balance that can be staked for `who`.
 ///
 /// This includes any balance that is already staked.
@@ -233,7 +233,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(stash.clone()), amount, reward_destination);
 
-		assert!(Bonded::<T>::contains_key(stash.clone()));
+		assert_eq!(Staking::<T>::bonded(&stash), Some(stash.clone()));
 		assert!(Ledger::<T>::contains_key(stash));
 	}
 
@@ -636,8 +636,9 @@ mod benchmarks {
 			let controller = &controllers[i as usize];
 			// Ledger no longer keyed by controller.
 			assert_eq!(Ledger::<T>::get(controller), None);
-			// Bonded now maps to the stash.
-			assert_eq!(Bonded::<T>::get(stash), Some(stash.clone()));
+			// The stash is now its own controller, without a `Bonded` entry.
+			assert!(!Bonded::<T>::contains_key(stash));
+			assert_eq!(Staking::<T>::bonded(stash), Some(stash.clone()));
 			// Ledger is now keyed by stash.
 			assert_eq!(Ledger::<T>::get(stash).unwrap().stash, *stash);
 		}
@@ -804,7 +805,7 @@ mod benchmarks {
 			StakingLedger::<T>::new(stash.clone(), asset::existential_deposit::<T>() - One::one());
 		Ledger::<T>::insert(&controller, l);
 
-		assert!(Bonded::<T>::contains_key(&stash));
+		assert!(Staking::<T>::bonded(&stash).is_some());
 		assert!(T::VoterList::contains(&stash));
 
 		whitelist_account!(controller);
@@ -812,7 +813,7 @@ mod benchmarks {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(controller), stash.clone(), s);
 
-		assert!(!Bonded::<T>::contains_key(&stash));
+		assert!(Staking::<T>::bonded(&stash).is_none());
 		assert!(!T::VoterList::contains(&stash));
 
 		Ok(())
@@ -1008,6 +1009,7 @@ mod benchmarks {
 			ConfigOp::Set(Percent::max_value()),
 			ConfigOp::Set(Perbill::max_value()),
 			ConfigOp::Set(Percent::max_value()),
+			ConfigOp::Set(BalanceOf::<T>::max_value()),
 		);
 
 		assert_eq!(MinNominatorBond::<T>::get(), BalanceOf::<T>::max_value());
@@ -1017,6 +1019,7 @@ mod benchmarks {
 		assert_eq!(ChillThreshold::<T>::get(), Some(Percent::from_percent(100)));
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
 		assert_eq!(MaxStakedRewards::<T>::get(), Some(Percent::from_percent(100)));
+		assert_eq!(MinValidatorSelfStake::<T>::get(), BalanceOf::<T>::max_value());
 	}
 
 	#[benchmark]
@@ -1031,6 +1034,7 @@ mod benchmarks {
 			ConfigOp::Remove,
 			ConfigOp::Remove,
 			ConfigOp::Remove,
+			ConfigOp::Remove,
 		);
 
 		assert!(!MinNominatorBond::<T>::exists());
@@ -1040,6 +1044,7 @@ mod benchmarks {
 		assert!(!ChillThreshold::<T>::exists());
 		assert!(!MinCommission::<T>::exists());
 		assert!(!MaxStakedRewards::<T>::exists());
+		assert!(!MinValidatorSelfStake::<T>::exists());
 	}
 
 	#[benchmark]
@@ -1064,6 +1069,7 @@ mod benchmarks {
 			ConfigOp::Set(Percent::from_percent(0)),
 			ConfigOp::Set(Zero::zero()),
 			ConfigOp::Noop,
+			ConfigOp::Noop,
 		)?;
 
 		let caller = whitelisted_caller();
@@ -1119,6 +1125,40 @@ mod benchmarks {
 		assert_eq!(MinCommission::<T>::get(), Perbill::from_percent(100));
 	}
 
+	#[benchmark]
+	fn set_unbonding_queue_params() {
+		let params = UnbondingQueueConfig {
+			min_duration: T::BondingDuration::get(),
+			max_exit_ratio: Perbill::one(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(params));
+
+		assert_eq!(UnbondingQueueParams::<T>::get(), Some(params));
+	}
+
+	#[benchmark]
+	fn set_candidacy_queue_params() {
+		let params = CandidacyQueueConfig {
+			self_stake_threshold: Perbill::one(),
+			approval_threshold: Perbill::one(),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(params));
+
+		assert_eq!(CandidacyQueueParams::<T>::get(), Some(params));
+	}
+
+	#[benchmark]
+	fn set_exposure_storage() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, ExposureStorage::Full);
+
+		assert_eq!(ExposureStorageMode::<T>::get(), ExposureStorage::Full);
+	}
+
 	#[benchmark]
 	fn restore_ledger() -> Result<(), BenchmarkError> {
 		let (stash, controller) = create_stash_controller::<T>(0, 100, RewardDestination::Staked)?;
@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A transaction extension that raises the priority of operational staking transactions.
+
+use crate::{Call, Config, CurrentEra, Ledger};
+use codec::{Decode, Encode};
+use core::{fmt, marker::PhantomData};
+use frame_support::{
+	dispatch::DispatchInfo,
+	pallet_prelude::TransactionSource,
+	traits::{Contains, Get, IsSubType, Nothing},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{AsSystemOriginSigner, DispatchInfoOf, Dispatchable, TransactionExtension},
+	transaction_validity::{TransactionPriority, TransactionValidityError, ValidTransaction},
+};
+use sp_staking::EraIndex;
+
+/// Raise the priority of operational staking transactions by `Boost`.
+///
+/// Boosted transactions are the [`Call::validate`] and [`Call::chill`] calls, the payouts of the
+/// oldest era that can still be claimed before its rewards are lost, and any call matched by
+/// `Extra`, such as `pallet_session::Call::set_keys`. They are only boosted if they are signed by
+/// a staker, i.e. the controller of a bonded stash, so that other accounts cannot use the boost to
+/// jump the queue. Together with a priority lane in the transaction pool, this keeps room for
+/// validators to operate during fee spikes.
+#[derive(Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T, Boost, Extra))]
+pub struct BoostStakingPriority<T, Boost, Extra = Nothing>(PhantomData<(T, Boost, Extra)>);
+
+impl<T, Boost, Extra> BoostStakingPriority<T, Boost, Extra> {
+	/// Create new `TransactionExtension` to boost the priority of staking transactions.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T, Boost, Extra> Default for BoostStakingPriority<T, Boost, Extra> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T, Boost, Extra> Clone for BoostStakingPriority<T, Boost, Extra> {
+	fn clone(&self) -> Self {
+		Self::new()
+	}
+}
+
+impl<T, Boost, Extra> PartialEq for BoostStakingPriority<T, Boost, Extra> {
+	fn eq(&self, _: &Self) -> bool {
+		true
+	}
+}
+
+impl<T, Boost, Extra> Eq for BoostStakingPriority<T, Boost, Extra> {}
+
+impl<T, Boost, Extra> fmt::Debug for BoostStakingPriority<T, Boost, Extra> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "BoostStakingPriority")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T, Boost, Extra> BoostStakingPriority<T, Boost, Extra>
+where
+	T: Config,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+	Extra: Contains<<T as frame_system::Config>::RuntimeCall>,
+{
+	/// Whether the payout of `era` is the last chance to claim its rewards.
+	fn is_expiring(era: EraIndex) -> bool {
+		CurrentEra::<T>::get()
+			.and_then(|current_era| current_era.checked_sub(T::HistoryDepth::get())) ==
+			Some(era)
+	}
+
+	/// Whether `call` is one of the boosted calls.
+	fn is_boosted_call(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		match call.is_sub_type() {
+			Some(Call::validate { .. }) | Some(Call::chill {}) => true,
+			Some(Call::payout_stakers { era, .. }) |
+			Some(Call::payout_stakers_by_page { era, .. }) => Self::is_expiring(*era),
+			_ => Extra::contains(call),
+		}
+	}
+
+	/// Whether `call` signed by `who` is boosted.
+	pub fn is_boosted(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		Self::is_boosted_call(call) && Ledger::<T>::contains_key(who)
+	}
+}
+
+impl<T, Boost, Extra> TransactionExtension<<T as frame_system::Config>::RuntimeCall>
+	for BoostStakingPriority<T, Boost, Extra>
+where
+	T: Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo> + IsSubType<Call<T>>,
+	<<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+		AsSystemOriginSigner<T::AccountId> + Clone,
+	Boost: Get<TransactionPriority> + Send + Sync + 'static,
+	Extra: Contains<<T as frame_system::Config>::RuntimeCall> + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "BoostStakingPriority";
+	type Implicit = ();
+	type Pre = ();
+	type Val = ();
+
+	fn weight(&self, call: &<T as frame_system::Config>::RuntimeCall) -> Weight {
+		match call.is_sub_type() {
+			Some(Call::validate { .. }) | Some(Call::chill {}) => T::DbWeight::get().reads(1),
+			Some(Call::payout_stakers { .. }) | Some(Call::payout_stakers_by_page { .. }) =>
+				T::DbWeight::get().reads(2),
+			_ if Extra::contains(call) => T::DbWeight::get().reads(1),
+			_ => Weight::zero(),
+		}
+	}
+
+	fn validate(
+		&self,
+		origin: <<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		_info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> Result<
+		(
+			ValidTransaction,
+			Self::Val,
+			<<T as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		),
+		TransactionValidityError,
+	> {
+		let priority = match origin.as_system_origin_signer() {
+			Some(who) if Self::is_boosted(who, call) => Boost::get(),
+			_ => 0,
+		};
+
+		Ok((ValidTransaction { priority, ..Default::default() }, (), origin))
+	}
+
+	impl_tx_ext_default!(<T as frame_system::Config>::RuntimeCall; prepare);
+}
@@ -0,0 +1,125 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for runtimes to build staking-focused genesis config presets.
+//!
+//! [`dev_stakers`] turns a set of validator and nominator accounts into the `stakers` of the
+//! [`crate::GenesisConfig`], with a deterministic but uneven stake distribution and nominations
+//! spread over all validators. Once built, all of them are inserted into the `VoterList` (and the
+//! `TargetList`, for validators) like any other genesis staker.
+
+use crate::StakerStatus;
+use alloc::vec::Vec;
+use codec::Encode;
+use sp_runtime::{traits::AtLeast32BitUnsigned, PerThing, Perbill};
+
+/// Seed of the pseudo-random numbers used to generate stakes and nominations.
+const DEV_STAKERS_SEED: &[u8] = b"staking/dev_stakers";
+
+/// A genesis staker: stash, controller, bonded balance and status.
+pub type GenesisStaker<AccountId, Balance> =
+	(AccountId, AccountId, Balance, StakerStatus<AccountId>);
+
+/// Build genesis stakers out of `validators` and `nominators`.
+///
+/// Every staker bonds between `min_stake` and `max_stake`, most of them close to `min_stake` and a
+/// few close to `max_stake`, which is roughly how stake is spread on live networks. Each nominator
+/// nominates `nominations` distinct validators (or all of them, if there are fewer). Nominators
+/// are bonded as idle if there are no validators.
+///
+/// The same input always results in the same stakers, so that presets are reproducible.
+pub fn dev_stakers<AccountId: Clone, Balance: AtLeast32BitUnsigned + Copy>(
+	validators: &[AccountId],
+	nominators: &[AccountId],
+	nominations: u32,
+	min_stake: Balance,
+	max_stake: Balance,
+) -> Vec<GenesisStaker<AccountId, Balance>> {
+	let stake_of = |role: u8, index: usize| {
+		let spread = Perbill::from_parts(random(role, index, 0) % Perbill::ACCURACY).square();
+		min_stake.saturating_add(spread * max_stake.saturating_sub(min_stake))
+	};
+
+	let validator_stakers = validators.iter().enumerate().map(|(index, who)| {
+		(who.clone(), who.clone(), stake_of(0, index), StakerStatus::Validator)
+	});
+	let nominator_stakers = nominators.iter().enumerate().map(|(index, who)| {
+		let count = (nominations as usize).min(validators.len());
+		let mut targets: Vec<AccountId> = Vec::with_capacity(count);
+		let mut picked: Vec<usize> = Vec::with_capacity(count);
+		for nth in 0..count {
+			// probe forward from a random validator until one that is not yet nominated is found.
+			let mut target = random(1, index, nth as u32 + 1) as usize % validators.len();
+			while picked.contains(&target) {
+				target = (target + 1) % validators.len();
+			}
+			picked.push(target);
+			targets.push(validators[target].clone());
+		}
+		let status =
+			if targets.is_empty() { StakerStatus::Idle } else { StakerStatus::Nominator(targets) };
+		(who.clone(), who.clone(), stake_of(1, index), status)
+	});
+
+	validator_stakers.chain(nominator_stakers).collect()
+}
+
+/// A pseudo-random number, derived from the role and index of a staker and a nonce.
+fn random(role: u8, index: usize, nonce: u32) -> u32 {
+	let hash =
+		(DEV_STAKERS_SEED, role, index as u64, nonce).using_encoded(sp_io::hashing::blake2_256);
+	u32::from_le_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn dev_stakers_works() {
+		let validators = (0..10u64).collect::<Vec<_>>();
+		let nominators = (100..200u64).collect::<Vec<_>>();
+		let stakers = dev_stakers(&validators, &nominators, 4, 10u128, 1000u128);
+
+		assert_eq!(stakers.len(), 110);
+		// reproducible.
+		assert_eq!(stakers, dev_stakers(&validators, &nominators, 4, 10u128, 1000u128));
+
+		for (stash, controller, stake, status) in stakers {
+			assert_eq!(stash, controller);
+			assert!((10..=1000).contains(&stake));
+			match status {
+				StakerStatus::Validator => assert!(validators.contains(&stash)),
+				StakerStatus::Nominator(mut targets) => {
+					assert!(nominators.contains(&stash));
+					assert_eq!(targets.len(), 4);
+					assert!(targets.iter().all(|t| validators.contains(t)));
+					targets.sort();
+					targets.dedup();
+					assert_eq!(targets.len(), 4);
+				},
+				StakerStatus::Idle => panic!("nominators have validators to nominate"),
+			}
+		}
+
+		// no more nominations than validators, idle without validators.
+		let stakers = dev_stakers(&validators[..2], &nominators[..1], 4, 10u128, 1000u128);
+		assert!(matches!(&stakers[2].3, StakerStatus::Nominator(targets) if targets.len() == 2));
+		let stakers = dev_stakers(&[], &nominators[..1], 4, 10u128, 1000u128);
+		assert_eq!(stakers[0].3, StakerStatus::Idle);
+	}
+}
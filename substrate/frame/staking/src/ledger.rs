@@ -19,8 +19,8 @@
 //!
 //! A [`StakingLedger`] encapsulates all the state and logic related to the stake of bonded
 //! stakers, namely, it handles the following storage items:
-//! * [`Bonded`]: mutates and reads the state of the controller <> stash bond map (to be deprecated
-//! soon);
+//! * [`Bonded`]: reads and clears the controller of the (stash, controller) pairs bonded before the
+//!   controller deprecation. Stashes bonded since then are their own controller and have no entry;
 //! * [`Ledger`]: mutates and reads the state of all the stakers. The [`Ledger`] storage item stores
 //!   instances of [`StakingLedger`] keyed by the staker's controller account and should be mutated
 //!   and read through the [`StakingLedger`] API;
@@ -84,7 +84,7 @@ impl<T: Config> StakingLedger<T> {
 	/// and controller. This will be deprecated once the controller is fully deprecated as well.
 	pub(crate) fn paired_account(account: StakingAccount<T::AccountId>) -> Option<T::AccountId> {
 		match account {
-			StakingAccount::Stash(stash) => <Bonded<T>>::get(stash),
+			StakingAccount::Stash(stash) => Self::bonded_controller(&stash),
 			StakingAccount::Controller(controller) =>
 				<Ledger<T>>::get(&controller).map(|ledger| ledger.stash),
 		}
@@ -93,11 +93,24 @@ impl<T: Config> StakingLedger<T> {
 	/// Returns whether a given account is bonded.
 	pub(crate) fn is_bonded(account: StakingAccount<T::AccountId>) -> bool {
 		match account {
-			StakingAccount::Stash(stash) => <Bonded<T>>::contains_key(stash),
+			StakingAccount::Stash(stash) => Self::bonded_controller(&stash).is_some(),
 			StakingAccount::Controller(controller) => <Ledger<T>>::contains_key(controller),
 		}
 	}
 
+	/// Returns the controller bonded by `stash`, if any.
+	///
+	/// A stash is its own controller once its ledger is stored under the stash itself. Pairs bonded
+	/// before the controller deprecation keep their [`Bonded`] entry until
+	/// [`crate::migrations::deprecate_controllers::LazyDeprecateControllers`] removes it.
+	pub(crate) fn bonded_controller(stash: &T::AccountId) -> Option<T::AccountId> {
+		<Bonded<T>>::get(stash).or_else(|| {
+			<Ledger<T>>::get(stash)
+				.filter(|ledger| ledger.stash == *stash)
+				.map(|_| stash.clone())
+		})
+	}
+
 	/// Returns a staking ledger, if it is bonded and it exists in storage.
 	///
 	/// This getter can be called with either a controller or stash account, provided that the
@@ -109,7 +122,7 @@ impl<T: Config> StakingLedger<T> {
 	pub(crate) fn get(account: StakingAccount<T::AccountId>) -> Result<StakingLedger<T>, Error<T>> {
 		let (stash, controller) = match account.clone() {
 			StakingAccount::Stash(stash) =>
-				(stash.clone(), <Bonded<T>>::get(&stash).ok_or(Error::<T>::NotStash)?),
+				(stash.clone(), Self::bonded_controller(&stash).ok_or(Error::<T>::NotStash)?),
 			StakingAccount::Controller(controller) => (
 				Ledger::<T>::get(&controller)
 					.map(|l| l.stash)
@@ -131,7 +144,7 @@ impl<T: Config> StakingLedger<T> {
 		//
 		// See <https://github.com/paritytech/polkadot-sdk/issues/3245> for more details.
 		ensure!(
-			Bonded::<T>::get(&stash) == Some(controller) && ledger.stash == stash,
+			Self::bonded_controller(&stash) == Some(controller) && ledger.stash == stash,
 			Error::<T>::BadState
 		);
 
@@ -161,7 +174,7 @@ impl<T: Config> StakingLedger<T> {
 
 	/// Returns the controller account of a staking ledger.
 	///
-	/// Note: it will fallback into querying the [`Bonded`] storage with the ledger stash if the
+	/// Note: it will fallback into querying the bonded controller of the ledger stash if the
 	/// controller is not set in `self`, which most likely means that self was fetched directly from
 	/// [`Ledger`] instead of through the methods exposed in [`StakingLedger`]. If the ledger does
 	/// not exist in storage, it returns `None`.
@@ -180,10 +193,18 @@ impl<T: Config> StakingLedger<T> {
 	/// Note: To ensure lock consistency, all the [`Ledger`] storage updates should be made through
 	/// this helper function.
 	pub(crate) fn update(self) -> Result<(), Error<T>> {
-		if !<Bonded<T>>::contains_key(&self.stash) {
+		if !Self::is_bonded(StakingAccount::Stash(self.stash.clone())) {
 			return Err(Error::<T>::NotStash)
 		}
 
+		self.store()
+	}
+
+	/// Stores the ledger under its controller and updates the staking lock of its stash.
+	///
+	/// Unlike [`Self::update`], it does not require the stash to be bonded already, since the
+	/// stored ledger is what bonds a stash that is its own controller.
+	pub(crate) fn store(self) -> Result<(), Error<T>> {
 		// We skip locking virtual stakers.
 		if !Pallet::<T>::is_virtual_staker(&self.stash) {
 			// for direct stakers, update lock on stash based on ledger.
@@ -203,20 +224,20 @@ impl<T: Config> StakingLedger<T> {
 
 	/// Bonds a ledger.
 	///
-	/// It sets the reward preferences for the bonded stash.
+	/// It sets the reward preferences for the bonded stash. The stash is its own controller, so no
+	/// [`Bonded`] entry is written.
 	pub(crate) fn bond(self, payee: RewardDestination<T::AccountId>) -> Result<(), Error<T>> {
-		if <Bonded<T>>::contains_key(&self.stash) {
+		if Self::is_bonded(StakingAccount::Stash(self.stash.clone())) {
 			return Err(Error::<T>::AlreadyBonded)
 		}
 
 		<Payee<T>>::insert(&self.stash, payee);
-		<Bonded<T>>::insert(&self.stash, &self.stash);
-		self.update()
+		self.store()
 	}
 
 	/// Sets the ledger Payee.
 	pub(crate) fn set_payee(self, payee: RewardDestination<T::AccountId>) -> Result<(), Error<T>> {
-		if !<Bonded<T>>::contains_key(&self.stash) {
+		if !Self::is_bonded(StakingAccount::Stash(self.stash.clone())) {
 			return Err(Error::<T>::NotStash)
 		}
 
@@ -225,6 +246,8 @@ impl<T: Config> StakingLedger<T> {
 	}
 
 	/// Sets the ledger controller to its stash.
+	///
+	/// The [`Bonded`] entry of the pair is removed, as the stash is now its own controller.
 	pub(crate) fn set_controller_to_stash(self) -> Result<(), Error<T>> {
 		let controller = self.controller.as_ref()
             .defensive_proof("Ledger's controller field didn't exist. The controller should have been fetched using StakingLedger.")
@@ -243,7 +266,7 @@ impl<T: Config> StakingLedger<T> {
 
 		<Ledger<T>>::remove(&controller);
 		<Ledger<T>>::insert(&self.stash, &self);
-		<Bonded<T>>::insert(&self.stash, &self.stash);
+		<Bonded<T>>::remove(&self.stash);
 
 		Ok(())
 	}
@@ -251,7 +274,7 @@ impl<T: Config> StakingLedger<T> {
 	/// Clears all data related to a staking ledger and its bond in both [`Ledger`] and [`Bonded`]
 	/// storage items and updates the stash staking lock.
 	pub(crate) fn kill(stash: &T::AccountId) -> Result<(), Error<T>> {
-		let controller = <Bonded<T>>::get(stash).ok_or(Error::<T>::NotStash)?;
+		let controller = Self::bonded_controller(stash).ok_or(Error::<T>::NotStash)?;
 
 		<Ledger<T>>::get(&controller).ok_or(Error::<T>::NotController).map(|ledger| {
 			Ledger::<T>::remove(controller);
@@ -87,6 +87,12 @@
 //!
 //! An account can become a validator candidate via the [`validate`](Call::validate) call.
 //!
+//! If governance enables the candidacy queue with
+//! [`set_candidacy_queue_params`](Call::set_candidacy_queue_params), new candidates are queued
+//! instead, and only become validator candidates at the start of the next era if their self-stake
+//! and approvals are high enough compared to the validators of the active era. See
+//! [`CandidacyQueueConfig`].
+//!
 //! #### Nomination
 //!
 //! A **nominator** does not take any _direct_ role in maintaining the network, instead, it votes on
@@ -220,7 +226,9 @@
 //!
 //! Total reward is split among validators and their nominators depending on the number of points
 //! they received during the era. Points are added to a validator using
-//! [`reward_by_ids`](Pallet::reward_by_ids).
+//! [`reward_by_ids`](Pallet::reward_by_ids), or using
+//! [`reward_by_source`](Pallet::reward_by_source) on behalf of one of the sources of
+//! [`Config::EraPointsReward`], up to what the source may award in an era.
 //!
 //! [`Pallet`] implements [`pallet_authorship::EventHandler`] to add reward points to block producer
 //! and block producer of referenced uncles.
@@ -238,12 +246,19 @@
 //! unsorted. The total commission is paid out proportionally across pages based on the total stake
 //! of the page.
 //!
+//! While the [`ExposureStorageMode`] is [`ExposureStorage::Commitment`], e.g. while staking moves
+//! to a system parachain, only the hash of each page is stored, and the pages are handed to
+//! [`Config::ExposureKeeper`]. The pages of these eras are paid out with
+//! [`payout_stakers_with_exposure`](Call::payout_stakers_with_exposure), which checks the provided
+//! page against the stored hash.
+//!
 //! All entities who receive a reward have the option to choose their reward destination through the
 //! [`Payee`] storage item (see [`set_payee`](Call::set_payee)), to be one of the following:
 //!
 //! - Stash account, not increasing the staked value.
 //! - Stash account, also increasing the staked value.
 //! - Any other account, sent as free balance.
+//! - An account on another chain, transferred by [`Config::RemotePayout`].
 //!
 //! ### Additional Fund Management Operations
 //!
@@ -255,6 +270,11 @@
 //! can actually be removed. Once the `BondingDuration` is over, the
 //! [`withdraw_unbonded`](Call::withdraw_unbonded) call can be used to actually withdraw the funds.
 //!
+//! If governance enables the unbonding queue with
+//! [`set_unbonding_queue_params`](Call::set_unbonding_queue_params), the duration depends on how
+//! much stake is unbonding in the same era instead: it is short when few stakers leave, and grows
+//! up to `BondingDuration` when many do. See [`UnbondingQueueConfig`].
+//!
 //! Note that there is a limitation to the number of fund-chunks that can be scheduled to be
 //! unlocked in the future via [`unbond`](Call::unbond). In case this maximum
 //! (`MAX_UNLOCKING_CHUNKS`) is reached, the bonded account _must_ first wait until a successful
@@ -297,6 +317,8 @@ mod tests;
 
 pub mod asset;
 pub mod election_size_tracker;
+pub mod extension;
+pub mod genesis;
 pub mod inflation;
 pub mod ledger;
 pub mod migrations;
@@ -312,7 +334,8 @@ use codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use frame_support::{
 	defensive, defensive_assert,
 	traits::{
-		ConstU32, Currency, Defensive, DefensiveMax, DefensiveSaturating, Get, LockIdentifier,
+		ConstU32, Currency, Defensive, DefensiveMax, DefensiveOption, DefensiveSaturating, Get,
+		LockIdentifier,
 	},
 	weights::Weight,
 	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
@@ -320,8 +343,8 @@ use frame_support::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	curve::PiecewiseLinear,
-	traits::{AtLeast32BitUnsigned, Convert, StaticLookup, Zero},
-	Perbill, Perquintill, Rounding, RuntimeDebug, Saturating,
+	traits::{AtLeast32BitUnsigned, Convert, Hash, StaticLookup, Zero},
+	DispatchError, DispatchResult, Perbill, Perquintill, Rounding, RuntimeDebug, Saturating,
 };
 use sp_staking::{
 	offence::{Offence, OffenceError, OffenceSeverity, ReportOffence},
@@ -329,6 +352,7 @@ use sp_staking::{
 	StakingAccount,
 };
 pub use sp_staking::{Exposure, IndividualExposure, StakerStatus};
+pub use extension::BoostStakingPriority;
 pub use weights::WeightInfo;
 
 pub use pallet::{pallet::*, UseNominatorsAndValidatorsMap, UseValidatorsMap};
@@ -358,6 +382,9 @@ pub type MaxNominationsOf<T> =
 /// Counter for the number of "reward" points earned by a given validator.
 pub type RewardPoint = u32;
 
+/// A source of era points of [`Config::EraPointsReward`].
+pub type EraPointsSourceOf<T> = <<T as Config>::EraPointsReward as EraPointsReward>::Source;
+
 /// The balance type of this pallet.
 pub type BalanceOf<T> = <T as Config>::CurrencyBalance;
 
@@ -414,6 +441,11 @@ pub enum RewardDestination<AccountId> {
 	Account(AccountId),
 	/// Receive no reward.
 	None,
+	/// Pay into the stash account, and transfer it to the specified account on another chain
+	/// with [`Config::RemotePayout`].
+	///
+	/// If the transfer fails, the reward stays in the stash account.
+	Remote(AccountId),
 }
 
 /// Preference of what happens regarding validation.
@@ -429,6 +461,39 @@ pub struct ValidatorPrefs {
 	pub blocked: bool,
 }
 
+/// Parameters of the demand-based unbonding queue.
+///
+/// When set, the unbonding duration of a chunk scales linearly from `min_duration` up to
+/// [`Config::BondingDuration`] with the fraction of the era's total stake that asked to unbond in
+/// the same era. The duration is capped once that fraction reaches `max_exit_ratio`.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct UnbondingQueueConfig {
+	/// The unbonding duration, in eras, when nobody else is unbonding.
+	pub min_duration: EraIndex,
+	/// The fraction of the total stake unbonding in a single era from which on the unbonding
+	/// duration is [`Config::BondingDuration`].
+	pub max_exit_ratio: Perbill,
+}
+
+/// Parameters of the validator candidacy queue.
+///
+/// When set, [`Call::validate`] queues new candidates in [`PendingCandidates`]. The stake of the
+/// nominators that nominate a queued candidate is measured when the next election snapshot is
+/// taken, into [`PendingCandidateApprovals`]. At the start of the following era, a measured
+/// candidate becomes a validator candidate if its self-stake is at least `self_stake_threshold` of
+/// the smallest own stake among the validators of the active era, and its approvals, i.e. its
+/// self-stake plus the measured stake, are at least `approval_threshold` of the smallest total
+/// backing among them. Otherwise, its candidacy is rejected.
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CandidacyQueueConfig {
+	/// The fraction of the smallest own stake of the active validators that a candidate must
+	/// self-stake.
+	pub self_stake_threshold: Perbill,
+	/// The fraction of the smallest total backing of the active validators that a candidate must
+	/// be approved with.
+	pub approval_threshold: Perbill,
+}
+
 /// Just a Balance/BlockNumber tuple to encode when a chunk of funds will be unlocked.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct UnlockChunk<Balance: HasCompact + MaxEncodedLen> {
@@ -573,8 +638,9 @@ impl<T: Config> StakingLedger<T> {
 	/// such:
 	///
 	/// - If any unlocking chunks exist that are scheduled to be unlocked at `slash_era +
-	///   bonding_duration` and onwards, the slash is divided equally between the active ledger and
-	///   the unlocking chunks.
+	///   bonding_duration` and onwards, or from the earliest era a chunk unbonded in `slash_era` or
+	///   later unlocks in if the unbonding queue shortened it, the slash is divided equally between
+	///   the active ledger and the unlocking chunks.
 	/// - If no such chunks exist, then only the active balance is slashed.
 	///
 	/// Note that the above is only a *preference*. If for any reason the active ledger, with or
@@ -605,8 +671,9 @@ impl<T: Config> StakingLedger<T> {
 		let pre_slash_total = self.total;
 
 		// for a `slash_era = x`, any chunk that is scheduled to be unlocked at era `x + 28`
-		// (assuming 28 is the bonding duration) onwards should be slashed.
-		let slashable_chunks_start = slash_era.saturating_add(T::BondingDuration::get());
+		// (assuming 28 is the bonding duration) onwards should be slashed. The unbonding queue
+		// may have unlocked the chunks unbonded from `x` on earlier.
+		let slashable_chunks_start = Pallet::<T>::earliest_unlock_era(slash_era);
 
 		// `Some(ratio)` if this is proportional, with `ratio`, `None` otherwise. In both cases, we
 		// slash first the active chunk, and then `slash_chunks_priority`.
@@ -812,6 +879,42 @@ impl<AccountId, Balance: HasCompact + Zero> UnappliedSlash<AccountId, Balance> {
 	}
 }
 
+impl<AccountId, Balance: HasCompact + AtLeast32BitUnsigned + Copy>
+	UnappliedSlash<AccountId, Balance>
+{
+	/// Cancel `fraction` of the slash, reducing every slashed amount and the payout to the
+	/// reporters proportionally.
+	pub(crate) fn cancel(&mut self, fraction: Perbill) {
+		use sp_runtime::PerThing as _;
+		let remaining = fraction.left_from_one();
+		self.own = remaining * self.own;
+		self.others.iter_mut().for_each(|(_, value)| *value = remaining * *value);
+		self.payout = remaining * self.payout;
+	}
+
+	/// The slash as reported by the `pending_slashes` runtime API.
+	pub(crate) fn to_pending(
+		&self,
+		apply_era: EraIndex,
+		index: u32,
+	) -> sp_staking::PendingSlash<AccountId, Balance>
+	where
+		AccountId: Clone,
+	{
+		sp_staking::PendingSlash {
+			apply_era,
+			index,
+			validator: self.validator.clone(),
+			own: self.own,
+			others: self
+				.others
+				.iter()
+				.fold(Zero::zero(), |total: Balance, (_, value)| total.saturating_add(*value)),
+			payout: self.payout,
+		}
+	}
+}
+
 /// Something that defines the maximum number of nominations per nominator based on a curve.
 ///
 /// The method `curve` implements the nomination quota curve and should not be used directly.
@@ -927,6 +1030,117 @@ impl<Balance: Default> EraPayout<Balance> for () {
 	}
 }
 
+/// Transfers staking rewards to an account on another chain, for stashes whose reward
+/// destination is [`RewardDestination::Remote`].
+///
+/// Which chain and how the funds are moved, e.g. by teleporting or reserve-transferring them over
+/// XCM, is up to the runtime.
+pub trait RemotePayout<AccountId, Balance> {
+	/// Transfer `amount`, just paid out to `stash`, to `beneficiary` on the remote chain.
+	///
+	/// Changes to the storage are reverted if it fails.
+	fn pay(stash: &AccountId, beneficiary: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// The weight of [`Self::pay`], charged for every staker of an exposure page when it is paid
+	/// out.
+	fn pay_weight() -> Weight;
+}
+
+impl<AccountId, Balance> RemotePayout<AccountId, Balance> for () {
+	fn pay(_stash: &AccountId, _beneficiary: &AccountId, _amount: Balance) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn pay_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// The sources of era points other than block authoring, e.g. contributing to the availability of
+/// parachain blocks or relaying bridge messages, and how many points each of them may award.
+///
+/// Points are awarded on behalf of a source with [`Pallet::reward_by_source`].
+pub trait EraPointsReward {
+	/// A source of era points.
+	type Source: frame_support::Parameter + MaxEncodedLen;
+
+	/// The maximum number of points `source` may award in an era, or `None` if it may not award
+	/// any.
+	fn max_points_per_era(source: &Self::Source) -> Option<RewardPoint>;
+}
+
+impl EraPointsReward for () {
+	type Source = ();
+
+	fn max_points_per_era(_source: &()) -> Option<RewardPoint> {
+		None
+	}
+}
+
+/// How the exposures of the elected validators are stored at the start of an era.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum ExposureStorage {
+	/// The exposure pages are stored in [`ErasStakersPaged`].
+	#[default]
+	Full,
+	/// Only the hash of each exposure page is stored in [`ErasStakersPagedCommitment`], and the
+	/// pages are handed to [`Config::ExposureKeeper`].
+	///
+	/// The stakers are paid out with [`Call::payout_stakers_with_exposure`]. The exposure checks
+	/// and slashes of these eras read the pages back from [`Config::ExposureKeeper`], so this mode
+	/// can only be set with a keeper that [can recall](ExposureKeeper::CAN_RECALL) them.
+	Commitment,
+}
+
+/// Keeps the exposure pages of the eras for which only their hash is stored, e.g. by sending them
+/// to the system parachain that takes over staking.
+pub trait ExposureKeeper<AccountId, Balance: HasCompact> {
+	/// Whether the kept pages can be read back with [`Self::recall`].
+	///
+	/// [`ExposureStorage::Commitment`] can only be set if this is `true`.
+	const CAN_RECALL: bool;
+
+	/// Keep the exposure page `page` of `validator` in `era`.
+	fn keep(
+		era: EraIndex,
+		validator: &AccountId,
+		page: Page,
+		exposure_page: &ExposurePage<AccountId, Balance>,
+	);
+
+	/// Read back the exposure page `page` of `validator` in `era`, as it was kept.
+	///
+	/// Must return the page for [`Config::HistoryDepth`] eras, since the exposure checks and the
+	/// slashes of the era need it.
+	fn recall(
+		era: EraIndex,
+		validator: &AccountId,
+		page: Page,
+	) -> Option<ExposurePage<AccountId, Balance>>;
+}
+
+impl<AccountId, Balance: HasCompact> ExposureKeeper<AccountId, Balance> for () {
+	const CAN_RECALL: bool = false;
+
+	fn keep(
+		_era: EraIndex,
+		_validator: &AccountId,
+		_page: Page,
+		_exposure_page: &ExposurePage<AccountId, Balance>,
+	) {
+	}
+
+	fn recall(
+		_era: EraIndex,
+		_validator: &AccountId,
+		_page: Page,
+	) -> Option<ExposurePage<AccountId, Balance>> {
+		None
+	}
+}
+
 /// Adaptor to turn a `PiecewiseLinear` curve definition into an `EraPayout` impl, used for
 /// backwards compatibility.
 pub struct ConvertCurve<T>(core::marker::PhantomData<T>);
@@ -1136,6 +1350,53 @@ impl<T: Config> EraInfo<T> {
 		})
 	}
 
+	/// Get exposure for a validator at a given era and page, from `exposure_page` if only the
+	/// hash of the page is stored.
+	///
+	/// Fails if the page is stored and `exposure_page` is provided, or if the page is only
+	/// committed to and `exposure_page` is missing or does not match the commitment.
+	pub(crate) fn get_paged_exposure_with(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+		exposure_page: Option<ExposurePage<T::AccountId, BalanceOf<T>>>,
+	) -> Result<Option<PagedExposure<T::AccountId, BalanceOf<T>>>, Error<T>> {
+		let commitment = <ErasStakersPagedCommitment<T>>::get((era, validator, page));
+		let exposure_page = match (commitment, exposure_page) {
+			(None, None) => return Ok(Self::get_paged_exposure(era, validator, page)),
+			(Some(_), None) => return Err(Error::<T>::MissingExposurePage),
+			(Some(commitment), Some(exposure_page))
+				if T::Hashing::hash_of(&exposure_page) == commitment =>
+				exposure_page,
+			(_, Some(_)) => return Err(Error::<T>::InvalidExposurePage),
+		};
+
+		// a page is only committed to along with the overview.
+		let overview =
+			<ErasStakersOverview<T>>::get(&era, validator).defensive_ok_or(Error::<T>::BadState)?;
+		let validator_stake = if page == 0 { overview.own } else { Zero::zero() };
+		Ok(Some(PagedExposure {
+			exposure_metadata: PagedExposureMetadata { own: validator_stake, ..overview },
+			exposure_page,
+		}))
+	}
+
+	/// Get the exposure page of a validator at a given era and page, for the eras in which only
+	/// the hash of the page is stored, from [`Config::ExposureKeeper`].
+	///
+	/// Returns `None` if the page is not committed to, or if the keeper returns no page or a page
+	/// that does not match the commitment.
+	pub(crate) fn get_committed_page(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+	) -> Option<ExposurePage<T::AccountId, BalanceOf<T>>> {
+		let commitment = <ErasStakersPagedCommitment<T>>::get((era, validator, page))?;
+		T::ExposureKeeper::recall(era, validator, page)
+			.filter(|exposure_page| T::Hashing::hash_of(exposure_page) == commitment)
+			.defensive_proof("the keeper must recall the committed pages; qed")
+	}
+
 	/// Get full exposure of the validator at a given era.
 	pub fn get_full_exposure(
 		era: EraIndex,
@@ -1151,7 +1412,8 @@ impl<T: Config> EraInfo<T> {
 
 		let mut others = Vec::with_capacity(overview.nominator_count as usize);
 		for page in 0..overview.page_count {
-			let nominators = <ErasStakersPaged<T>>::get((era, validator, page));
+			let nominators = <ErasStakersPaged<T>>::get((era, validator, page))
+				.or_else(|| Self::get_committed_page(era, validator, page));
 			others.append(&mut nominators.map(|n| n.others).defensive_unwrap_or_default());
 		}
 
@@ -1248,8 +1510,16 @@ impl<T: Config> EraInfo<T> {
 		defensive_assert!(exposure_pages.len() == expected_page_count, "unexpected page count");
 
 		<ErasStakersOverview<T>>::insert(era, &validator, &exposure_metadata);
+		let commit = ExposureStorageMode::<T>::get() == ExposureStorage::Commitment;
 		exposure_pages.iter().enumerate().for_each(|(page, paged_exposure)| {
-			<ErasStakersPaged<T>>::insert((era, &validator, page as Page), &paged_exposure);
+			let page = page as Page;
+			if commit {
+				let commitment = T::Hashing::hash_of(paged_exposure);
+				<ErasStakersPagedCommitment<T>>::insert((era, &validator, page), commitment);
+				T::ExposureKeeper::keep(era, validator, page, paged_exposure);
+			} else {
+				<ErasStakersPaged<T>>::insert((era, &validator, page), &paged_exposure);
+			}
 		});
 	}
 
@@ -60,6 +60,96 @@ impl Default for ObsoleteReleases {
 #[storage_alias]
 type StorageVersion<T: Config> = StorageValue<Pallet<T>, ObsoleteReleases, ValueQuery>;
 
+/// Multi-block migration pairing every remaining distinct controller account with its stash and
+/// removing the [`Bonded`] indirection.
+///
+/// Controllers are deprecated, but pairs bonded before the deprecation keep a distinct controller
+/// until `set_controller` or [`Call::deprecate_controller_batch`] is called for them. This
+/// migration sweeps [`Bonded`] in bounded steps instead, with the last visited stash as cursor.
+pub mod deprecate_controllers {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		traits::StorageVersion,
+		weights::WeightMeter,
+	};
+
+	const PALLET_MIGRATIONS_ID: &[u8; 26] = b"pallet-staking-controllers";
+
+	/// Moves the ledger of each distinct controller to its stash and removes the [`Bonded`] entry
+	/// of every pair, since a stash is its own controller once its ledger is stored under it.
+	///
+	/// Stashes whose payee is the deprecated [`RewardDestination::Controller`] keep paying their
+	/// former controller through [`RewardDestination::Account`]. The entries of pairs that could
+	/// not be deprecated are kept, so that `restore_ledger` can still repair them. Emits
+	/// [`Event::ControllerBatchDeprecated`] after each step and sets the storage version to 17 once
+	/// done.
+	pub struct LazyDeprecateControllers<T>(core::marker::PhantomData<T>);
+	impl<T: Config> SteppedMigration for LazyDeprecateControllers<T> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<26>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 16, version_to: 17 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None);
+			}
+
+			// the worst case of a single entry is a distinct controller to deprecate.
+			let required = T::WeightInfo::deprecate_controller_batch(1);
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			let mut failures = 0;
+			while meter.can_consume(required) {
+				// entries are removed as they are visited, so a new iterator is needed each time.
+				let mut iter = match &cursor {
+					Some(last) => Bonded::<T>::iter_from(Bonded::<T>::hashed_key_for(last)),
+					None => Bonded::<T>::iter(),
+				};
+				let Some((stash, controller)) = iter.next() else {
+					StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+					cursor = None;
+					break
+				};
+
+				if stash == controller {
+					Bonded::<T>::remove(&stash);
+					meter.consume(T::DbWeight::get().reads_writes(1, 1));
+				} else {
+					if Self::deprecate(&stash, controller).is_err() {
+						failures += 1;
+					}
+					meter.consume(required);
+				}
+				cursor = Some(stash);
+			}
+
+			Pallet::<T>::deposit_event(Event::<T>::ControllerBatchDeprecated { failures });
+			Ok(cursor)
+		}
+	}
+
+	impl<T: Config> LazyDeprecateControllers<T> {
+		fn deprecate(stash: &T::AccountId, controller: T::AccountId) -> Result<(), Error<T>> {
+			Pallet::<T>::ledger(StakingAccount::Stash(stash.clone()))?.set_controller_to_stash()?;
+
+			#[allow(deprecated)]
+			if Payee::<T>::get(stash) == Some(RewardDestination::Controller) {
+				Payee::<T>::insert(stash, RewardDestination::Account(controller));
+			}
+			Ok(())
+		}
+	}
+}
+
 /// Migrating `DisabledValidators` from `Vec<u32>` to `Vec<(u32, OffenceSeverity)>` to track offense
 /// severity for re-enabling purposes.
 pub mod v16 {
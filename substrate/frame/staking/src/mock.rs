@@ -25,14 +25,17 @@ use frame_election_provider_support::{
 use frame_support::{
 	assert_ok, derive_impl, ord_parameter_types, parameter_types,
 	traits::{
-		ConstU64, Currency, EitherOfDiverse, FindAuthor, Get, Hooks, Imbalance, LockableCurrency,
-		OnUnbalanced, OneSessionHandler, WithdrawReasons,
+		ConstU64, Currency, EitherOfDiverse, ExistenceRequirement, FindAuthor, Get, Hooks,
+		Imbalance, LockableCurrency, OnUnbalanced, OneSessionHandler, WithdrawReasons,
 	},
-	weights::constants::RocksDbWeight,
+	weights::{constants::RocksDbWeight, Weight},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_io;
-use sp_runtime::{curve::PiecewiseLinear, testing::UintAuthorityId, traits::Zero, BuildStorage};
+use sp_runtime::{
+	curve::PiecewiseLinear, testing::UintAuthorityId, traits::Zero, BoundedVec, BuildStorage,
+	DispatchError, DispatchResult,
+};
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
 	OnStakingUpdate,
@@ -203,6 +206,8 @@ parameter_types! {
 	pub static HistoryDepth: u32 = 80;
 	pub static MaxExposurePageSize: u32 = 64;
 	pub static MaxUnlockingChunks: u32 = 32;
+	pub static MaxPendingCandidates: u32 = 64;
+	pub static BondActivationDelay: EraIndex = 0;
 	pub static RewardOnUnbalanceWasCalled: bool = false;
 	pub static MaxWinners: u32 = 100;
 	pub static ElectionsBounds: ElectionBounds = ElectionBoundsBuilder::default().build();
@@ -217,6 +222,7 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Test {
 	type ScoreProvider = Staking;
 	type BagThresholds = BagThresholds;
 	type Score = VoteWeight;
+	type MaxScoreRecalculations = frame_support::traits::ConstU32<64>;
 }
 
 pub struct OnChainSeqPhragmen;
@@ -241,6 +247,7 @@ parameter_types! {
 		(BalanceOf<Test>, BTreeMap<EraIndex, BalanceOf<Test>>) =
 		(Zero::zero(), BTreeMap::new());
 	pub static SlashObserver: BTreeMap<AccountId, BalanceOf<Test>> = BTreeMap::new();
+	pub static NominationsObserver: BTreeMap<AccountId, Vec<AccountId>> = BTreeMap::new();
 }
 
 pub struct EventListenerMock;
@@ -256,6 +263,103 @@ impl OnStakingUpdate<AccountId, Balance> for EventListenerMock {
 			map.insert(*pool_account, map.get(pool_account).unwrap_or(&0) + total_slashed)
 		});
 	}
+
+	fn on_nominator_update<MaxNominations: Get<u32>>(
+		who: &AccountId,
+		prev_nominations: BoundedVec<AccountId, MaxNominations>,
+	) {
+		NominationsObserver::mutate(|map| map.insert(*who, prev_nominations.into_inner()));
+	}
+
+	fn on_nominator_remove<MaxNominations: Get<u32>>(
+		who: &AccountId,
+		nominations: BoundedVec<AccountId, MaxNominations>,
+	) {
+		NominationsObserver::mutate(|map| map.insert(*who, nominations.into_inner()));
+	}
+}
+
+parameter_types! {
+	pub static RemotePayouts: Vec<(AccountId, AccountId, Balance)> = vec![];
+	pub static RemotePayoutFails: bool = false;
+	pub static RemotePayoutWeight: Weight = Weight::from_parts(1_000_000, 0);
+}
+
+/// Burns the reward from the stash, as a teleport would, and records the payout.
+pub struct RemotePayoutMock;
+impl RemotePayout<AccountId, Balance> for RemotePayoutMock {
+	fn pay(stash: &AccountId, beneficiary: &AccountId, amount: Balance) -> DispatchResult {
+		let _ = Balances::withdraw(
+			stash,
+			amount,
+			WithdrawReasons::TRANSFER,
+			ExistenceRequirement::KeepAlive,
+		)?;
+		if RemotePayoutFails::get() {
+			return Err(DispatchError::Other("remote payout failed"))
+		}
+		RemotePayouts::mutate(|payouts| payouts.push((*stash, *beneficiary, amount)));
+		Ok(())
+	}
+
+	fn pay_weight() -> Weight {
+		RemotePayoutWeight::get()
+	}
+}
+
+parameter_types! {
+	pub static KeptExposures: Vec<(EraIndex, AccountId, Page, ExposurePage<AccountId, Balance>)> =
+		vec![];
+}
+
+/// Records the exposure pages that are not stored in the pallet.
+pub struct TestExposureKeeper;
+impl ExposureKeeper<AccountId, Balance> for TestExposureKeeper {
+	const CAN_RECALL: bool = true;
+
+	fn keep(
+		era: EraIndex,
+		validator: &AccountId,
+		page: Page,
+		exposure_page: &ExposurePage<AccountId, Balance>,
+	) {
+		KeptExposures::mutate(|kept| kept.push((era, *validator, page, exposure_page.clone())));
+	}
+
+	fn recall(
+		era: EraIndex,
+		validator: &AccountId,
+		page: Page,
+	) -> Option<ExposurePage<AccountId, Balance>> {
+		KeptExposures::get()
+			.into_iter()
+			.find(|(e, v, p, _)| (*e, v, *p) == (era, validator, page))
+			.map(|(.., exposure_page)| exposure_page)
+	}
+}
+
+/// The custom sources of era points in tests.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TestPointsSource {
+	/// May award up to 100 points per era.
+	Availability,
+	/// May award up to 10 points per era.
+	Bridge,
+	/// May not award any points.
+	Unpermitted,
+}
+
+pub struct TestEraPointsReward;
+impl EraPointsReward for TestEraPointsReward {
+	type Source = TestPointsSource;
+
+	fn max_points_per_era(source: &TestPointsSource) -> Option<RewardPoint> {
+		match source {
+			TestPointsSource::Availability => Some(100),
+			TestPointsSource::Bridge => Some(10),
+			TestPointsSource::Unpermitted => None,
+		}
+	}
 }
 
 // Disabling threshold for `UpToLimitDisablingStrategy` and
@@ -287,6 +391,11 @@ impl crate::pallet::pallet::Config for Test {
 	type EventListeners = EventListenerMock;
 	type DisablingStrategy =
 		pallet_staking::UpToLimitWithReEnablingDisablingStrategy<DISABLING_LIMIT_FACTOR>;
+	type RemotePayout = RemotePayoutMock;
+	type EraPointsReward = TestEraPointsReward;
+	type ExposureKeeper = TestExposureKeeper;
+	type MaxPendingCandidates = MaxPendingCandidates;
+	type BondActivationDelay = BondActivationDelay;
 }
 
 pub struct WeightedNominationsQuota<const MAX: u32>;
@@ -800,7 +909,7 @@ pub(crate) fn bond_controller_stash(controller: AccountId, stash: AccountId) ->
 
 // simulates `set_controller` without corrupted ledger checks for testing purposes.
 pub(crate) fn set_controller_no_checks(stash: &AccountId) {
-	let controller = Bonded::<Test>::get(stash).expect("testing stash should be bonded");
+	let controller = Staking::bonded(stash).expect("testing stash should be bonded");
 	let ledger = Ledger::<Test>::get(&controller).expect("testing ledger should exist");
 
 	Ledger::<Test>::remove(&controller);
@@ -810,7 +919,7 @@ pub(crate) fn set_controller_no_checks(stash: &AccountId) {
 
 // simulates `bond_extra` without corrupted ledger checks for testing purposes.
 pub(crate) fn bond_extra_no_checks(stash: &AccountId, amount: Balance) {
-	let controller = Bonded::<Test>::get(stash).expect("bond must exist to bond_extra");
+	let controller = Staking::bonded(stash).expect("bond must exist to bond_extra");
 	let mut ledger = Ledger::<Test>::get(&controller).expect("ledger must exist to bond_extra");
 
 	let new_total = ledger.total + amount;
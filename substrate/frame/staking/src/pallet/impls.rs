@@ -26,6 +26,7 @@ use frame_support::{
 	defensive,
 	dispatch::WithPostDispatchInfo,
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		Defensive, DefensiveSaturating, EstimateNextNewSession, Get, Imbalance, Len, OnUnbalanced,
 		TryCollect, UnixTime,
@@ -39,23 +40,25 @@ use sp_runtime::{
 		Bounded, CheckedAdd, CheckedSub, Convert, One, SaturatedConversion, Saturating,
 		StaticLookup, Zero,
 	},
-	ArithmeticError, Perbill, Percent,
+	ArithmeticError, PerThing, Perbill, Percent,
 };
 use sp_staking::{
 	currency_to_vote::CurrencyToVote,
 	offence::{OffenceDetails, OnOffenceHandler},
-	EraIndex, OnStakingUpdate, Page, SessionIndex, Stake,
+	EraIndex, NominatorBacking, OnStakingUpdate, Page, PendingCandidate, PendingSlash,
+	ProjectedRewards, SessionIndex, Stake, StakeableBreakdown,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, ValidatorPerformance,
 };
 
 use crate::{
 	asset, election_size_tracker::StaticTracker, log, slashing, weights::WeightInfo, ActiveEraInfo,
-	BalanceOf, EraInfo, EraPayout, Exposure, ExposureOf, Forcing, IndividualExposure,
-	LedgerIntegrityState, MaxNominationsOf, MaxWinnersOf, Nominations, NominationsQuota,
-	PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger, ValidatorPrefs,
+	BalanceOf, EraInfo, EraPayout, EraPointsReward, EraPointsSourceOf, EraRewardPoints, Exposure,
+	ExposureOf, ExposurePage, Forcing, IndividualExposure, LedgerIntegrityState, MaxNominationsOf,
+	MaxWinnersOf, Nominations, NominationsQuota, PositiveImbalanceOf, RemotePayout,
+	RewardDestination, RewardPoint, SessionInterface, StakingLedger, ValidatorPrefs,
 };
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec, vec::Vec};
 
 use super::pallet::*;
 
@@ -89,8 +92,9 @@ impl<T: Config> Pallet<T> {
 
 	/// Inspects and returns the corruption state of a ledger and direct bond, if any.
 	///
-	/// Note: all operations in this method access directly the `Bonded` and `Ledger` storage maps
-	/// instead of using the [`StakingLedger`] API since the bond and/or ledger may be corrupted.
+	/// Note: all operations in this method access directly the bonded controller and the `Ledger`
+	/// storage map instead of using the [`StakingLedger`] API since the bond and/or ledger may be
+	/// corrupted.
 	/// It is also meant to check state for direct bonds and may not work as expected for virtual
 	/// bonds.
 	pub(crate) fn inspect_bond_state(
@@ -98,7 +102,7 @@ impl<T: Config> Pallet<T> {
 	) -> Result<LedgerIntegrityState, Error<T>> {
 		let lock = asset::staked::<T>(&stash);
 
-		let controller = <Bonded<T>>::get(stash).ok_or_else(|| {
+		let controller = StakingLedger::<T>::bonded_controller(stash).ok_or_else(|| {
 			if lock == Zero::zero() {
 				Error::<T>::NotStash
 			} else {
@@ -127,12 +131,60 @@ impl<T: Config> Pallet<T> {
 		Self::ledger(Stash(stash.clone())).map(|l| l.active).unwrap_or_default()
 	}
 
-	/// Internal impl of [`Self::slashable_balance_of`] that returns [`VoteWeight`].
+	/// The part of [`Self::slashable_balance_of`] that counts toward elections as of right now.
+	///
+	/// The stake bonded in the last [`Config::BondActivationDelay`] eras does not count yet. Any
+	/// stake unbonded or slashed since is taken from the stake that counts.
+	pub fn activated_balance_of(stash: &T::AccountId) -> BalanceOf<T> {
+		let active = Self::slashable_balance_of(stash);
+		if T::BondActivationDelay::get().is_zero() {
+			return active
+		}
+
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+		let pending = PendingActivation::<T>::get(stash)
+			.into_iter()
+			.filter(|(era, _)| *era > current_era)
+			.fold(BalanceOf::<T>::zero(), |pending, (_, value)| pending.saturating_add(value));
+		active.saturating_sub(pending)
+	}
+
+	/// Keep `value` of stake newly bonded by `stash` out of elections for
+	/// [`Config::BondActivationDelay`] eras.
+	///
+	/// Stake bonded before the first era, e.g. at genesis, counts right away.
+	pub(crate) fn delay_activation(stash: &T::AccountId, value: BalanceOf<T>) {
+		let delay = T::BondActivationDelay::get();
+		if delay.is_zero() || value.is_zero() {
+			return
+		}
+		let Some(current_era) = CurrentEra::<T>::get() else { return };
+
+		let activation_era = current_era.saturating_add(delay);
+		PendingActivation::<T>::mutate(stash, |chunks| {
+			chunks.retain(|(era, _)| *era > current_era);
+			// if there is no room left, the latest chunk takes the new stake, and is activated
+			// together with it.
+			let merge =
+				chunks.is_full() || chunks.last().map_or(false, |(era, _)| *era == activation_era);
+			match chunks.last_mut() {
+				Some((era, pending)) if merge => {
+					*era = activation_era;
+					*pending = pending.saturating_add(value);
+				},
+				_ => {
+					let _ = chunks.try_push((activation_era, value)).defensive();
+				},
+			}
+		});
+	}
+
+	/// Internal impl of [`Self::activated_balance_of`] that returns [`VoteWeight`].
 	pub fn slashable_balance_of_vote_weight(
 		stash: &T::AccountId,
 		issuance: BalanceOf<T>,
 	) -> VoteWeight {
-		T::CurrencyToVote::to_vote(Self::slashable_balance_of(stash), issuance)
+		T::CurrencyToVote::to_vote(Self::activated_balance_of(stash), issuance)
 	}
 
 	/// Returns a closure around `slashable_balance_of_vote_weight` that can be passed around.
@@ -177,6 +229,7 @@ impl<T: Config> Pallet<T> {
 
 		// NOTE: ledger must be updated prior to calling `Self::weight_of`.
 		ledger.update()?;
+		Self::delay_activation(stash, extra);
 		// update this staker in the sorted list, if they exist in it.
 		if T::VoterList::contains(stash) {
 			let _ = T::VoterList::on_update(&stash, Self::weight_of(stash)).defensive();
@@ -251,6 +304,17 @@ impl<T: Config> Pallet<T> {
 		validator_stash: T::AccountId,
 		era: EraIndex,
 		page: Page,
+	) -> DispatchResultWithPostInfo {
+		Self::do_payout_stakers_by_page_with(validator_stash, era, page, None)
+	}
+
+	/// Pay out `page` of the stakers of `validator_stash` in `era`, with the provided
+	/// `exposure_page` if only the hash of the page is stored.
+	pub(super) fn do_payout_stakers_by_page_with(
+		validator_stash: T::AccountId,
+		era: EraIndex,
+		page: Page,
+		exposure_page: Option<ExposurePage<T::AccountId, BalanceOf<T>>>,
 	) -> DispatchResultWithPostInfo {
 		// Validate input data
 		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
@@ -301,10 +365,12 @@ impl<T: Config> Pallet<T> {
 			EraInfo::<T>::set_rewards_as_claimed(era, &stash, page);
 		}
 
-		let exposure = EraInfo::<T>::get_paged_exposure(era, &stash, page).ok_or_else(|| {
-			Error::<T>::InvalidEraToReward
-				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
-		})?;
+		let exposure = EraInfo::<T>::get_paged_exposure_with(era, &stash, page, exposure_page)
+			.map_err(|e| e.with_weight(T::WeightInfo::payout_stakers_alive_staked(0)))?
+			.ok_or_else(|| {
+				Error::<T>::InvalidEraToReward
+					.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+			})?;
 
 		// Input data seems good, no errors allowed after this point
 
@@ -316,29 +382,20 @@ impl<T: Config> Pallet<T> {
 		// which goes to them and each of their nominators.
 
 		let era_reward_points = <ErasRewardPoints<T>>::get(&era);
-		let total_reward_points = era_reward_points.total;
-		let validator_reward_points =
-			era_reward_points.individual.get(&stash).copied().unwrap_or_else(Zero::zero);
+		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
 
 		// Nothing to do if they have no reward points.
-		if validator_reward_points.is_zero() {
+		let Some((validator_total_commission_payout, validator_leftover_payout)) =
+			Self::split_validator_reward(
+				era_payout,
+				&era_reward_points,
+				&stash,
+				validator_commission,
+			)
+		else {
 			return Ok(Some(T::WeightInfo::payout_stakers_alive_staked(0)).into())
-		}
-
-		// This is the fraction of the total reward that the validator and the
-		// nominators will get.
-		let validator_total_reward_part =
-			Perbill::from_rational(validator_reward_points, total_reward_points);
-
-		// This is how much validator + nominators are entitled to.
-		let validator_total_payout = validator_total_reward_part * era_payout;
-
-		let validator_commission = EraInfo::<T>::get_validator_commission(era, &ledger.stash);
-		// total commission validator takes across all nominator pages
-		let validator_total_commission_payout = validator_commission * validator_total_payout;
+		};
 
-		let validator_leftover_payout =
-			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
 		// Now let's calculate how this is split to the validator.
 		let validator_exposure_part = Perbill::from_rational(exposure.own(), exposure.total());
 		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
@@ -353,20 +410,25 @@ impl<T: Config> Pallet<T> {
 			next: EraInfo::<T>::get_next_claimable_page(era, &stash, &ledger),
 		});
 
+		// Track the number of payout ops to nominators. Note:
+		// `WeightInfo::payout_stakers_alive_staked` always assumes at least a validator is paid
+		// out, so we do not need to count their payout op.
+		let mut nominator_payout_count: u32 = 0;
+		// Track the number of payouts to a remote reward destination, validator included.
+		let mut remote_payout_count: u32 = 0;
+
 		let mut total_imbalance = PositiveImbalanceOf::<T>::zero();
 		// We can now make total validator payout:
 		if let Some((imbalance, dest)) =
 			Self::make_payout(&stash, validator_staking_payout + validator_commission_payout)
 		{
+			if matches!(dest, RewardDestination::Remote(_)) {
+				remote_payout_count += 1;
+			}
 			Self::deposit_event(Event::<T>::Rewarded { stash, dest, amount: imbalance.peek() });
 			total_imbalance.subsume(imbalance);
 		}
 
-		// Track the number of payout ops to nominators. Note:
-		// `WeightInfo::payout_stakers_alive_staked` always assumes at least a validator is paid
-		// out, so we do not need to count their payout op.
-		let mut nominator_payout_count: u32 = 0;
-
 		// Lets now calculate how this is split to the nominators.
 		// Reward only the clipped exposures. Note this is not necessarily sorted.
 		for nominator in exposure.others().iter() {
@@ -378,6 +440,9 @@ impl<T: Config> Pallet<T> {
 			if let Some((imbalance, dest)) = Self::make_payout(&nominator.who, nominator_reward) {
 				// Note: this logic does not count payouts for `RewardDestination::None`.
 				nominator_payout_count += 1;
+				if matches!(dest, RewardDestination::Remote(_)) {
+					remote_payout_count += 1;
+				}
 				let e = Event::<T>::Rewarded {
 					stash: nominator.who.clone(),
 					dest,
@@ -391,14 +456,55 @@ impl<T: Config> Pallet<T> {
 		T::Reward::on_unbalanced(total_imbalance);
 		debug_assert!(nominator_payout_count <= T::MaxExposurePageSize::get());
 
-		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
+		Ok(Some(Self::payout_stakers_weight(nominator_payout_count, remote_payout_count)).into())
+	}
+
+	/// The weight of paying out a validator and `nominators` of its nominators, `remote` of them
+	/// (validator included) to a [`RewardDestination::Remote`].
+	pub(crate) fn payout_stakers_weight(nominators: u32, remote: u32) -> Weight {
+		T::WeightInfo::payout_stakers_alive_staked(nominators)
+			.saturating_add(T::RemotePayout::pay_weight().saturating_mul(remote.into()))
+	}
+
+	/// Split the share of `era_payout` that `stash` earned with its reward points into the
+	/// `commission` of the validator and the leftover that is shared by stake between the
+	/// validator and its nominators.
+	///
+	/// Returns `None` if `stash` earned no reward points.
+	pub(crate) fn split_validator_reward(
+		era_payout: BalanceOf<T>,
+		era_reward_points: &EraRewardPoints<T::AccountId>,
+		stash: &T::AccountId,
+		commission: Perbill,
+	) -> Option<(BalanceOf<T>, BalanceOf<T>)> {
+		let validator_reward_points =
+			era_reward_points.individual.get(stash).copied().unwrap_or_else(Zero::zero);
+		if validator_reward_points.is_zero() {
+			return None
+		}
+
+		// This is the fraction of the total reward that the validator and the
+		// nominators will get.
+		let validator_total_reward_part =
+			Perbill::from_rational(validator_reward_points, era_reward_points.total);
+
+		// This is how much validator + nominators are entitled to.
+		let validator_total_payout = validator_total_reward_part * era_payout;
+
+		// total commission validator takes across all nominator pages
+		let validator_total_commission_payout = commission * validator_total_payout;
+
+		let validator_leftover_payout =
+			validator_total_payout.defensive_saturating_sub(validator_total_commission_payout);
+		Some((validator_total_commission_payout, validator_leftover_payout))
 	}
 
 	/// Chill a stash account.
 	pub(crate) fn chill_stash(stash: &T::AccountId) {
 		let chilled_as_validator = Self::do_remove_validator(stash);
 		let chilled_as_nominator = Self::do_remove_nominator(stash);
-		if chilled_as_validator || chilled_as_nominator {
+		let chilled_as_candidate = Self::remove_pending_candidate(stash);
+		if chilled_as_validator || chilled_as_nominator || chilled_as_candidate {
 			Self::deposit_event(Event::<T>::Chilled { stash: stash.clone() });
 		}
 	}
@@ -433,6 +539,21 @@ impl<T: Config> Pallet<T> {
 			RewardDestination::Account(ref dest_account) =>
 				Some(asset::mint_creating::<T>(&dest_account, amount)),
 			RewardDestination::None => None,
+			RewardDestination::Remote(ref beneficiary) => {
+				let maybe_imbalance = asset::mint_existing::<T>(stash, amount);
+				if maybe_imbalance.is_some() {
+					let paid =
+						with_storage_layer(|| T::RemotePayout::pay(stash, beneficiary, amount));
+					if let Err(error) = paid {
+						Self::deposit_event(Event::<T>::RemotePayoutFailed {
+							stash: stash.clone(),
+							amount,
+							error,
+						});
+					}
+				}
+				maybe_imbalance
+			},
 			#[allow(deprecated)]
 			RewardDestination::Controller => Self::bonded(stash)
 					.map(|controller| {
@@ -531,7 +652,9 @@ impl<T: Config> Pallet<T> {
 	/// Start a new era. It does:
 	/// * Increment `active_era.index`,
 	/// * reset `active_era.start`,
-	/// * update `BondedEras` and apply slashes.
+	/// * update `BondedEras` and apply slashes,
+	/// * chill the validators left out of the election for their self-stake,
+	/// * process the candidacy queue.
 	fn start_era(start_session: SessionIndex) {
 		let active_era = ActiveEra::<T>::mutate(|active_era| {
 			let new_index = active_era.as_ref().map(|info| info.index + 1).unwrap_or(0);
@@ -567,6 +690,168 @@ impl<T: Config> Pallet<T> {
 		});
 
 		Self::apply_unapplied_slashes(active_era);
+		Self::chill_below_min_self_stake();
+		Self::process_candidacy_queue(active_era);
+	}
+
+	/// Chill the validators in [`ValidatorsBelowMinSelfStake`] whose self-stake is still below
+	/// [`MinValidatorSelfStake`].
+	fn chill_below_min_self_stake() {
+		let below_count = ValidatorsBelowMinSelfStake::<T>::count();
+		if below_count == 0 {
+			Self::register_weight(T::DbWeight::get().reads(1));
+			return
+		}
+
+		let min_self_stake = MinValidatorSelfStake::<T>::get();
+		let mut chilled = 0u32;
+		// at most the number of targets iterated by an election snapshot.
+		for stash in ValidatorsBelowMinSelfStake::<T>::drain().map(|(stash, _)| stash) {
+			let self_stake = Self::ledger(Stash(stash.clone())).map(|l| l.active);
+			if self_stake.map_or(false, |active| active < min_self_stake) &&
+				Validators::<T>::contains_key(&stash)
+			{
+				Self::chill_stash(&stash);
+				chilled.saturating_inc();
+			}
+		}
+
+		let below_count = below_count as u64;
+		Self::register_weight(
+			T::WeightInfo::chill().saturating_mul(chilled.into()).saturating_add(
+				T::DbWeight::get()
+					.reads_writes(2u64.saturating_add(4 * below_count), 1 + below_count),
+			),
+		);
+	}
+
+	/// The minimum self-stake and approvals a queued candidate needs to become a validator
+	/// candidate in `era`, see [`CandidacyQueueConfig`].
+	///
+	/// Both are zero if the candidacy queue is disabled.
+	pub(crate) fn candidacy_thresholds(era: EraIndex) -> (BalanceOf<T>, BalanceOf<T>) {
+		let Some(params) = CandidacyQueueParams::<T>::get() else {
+			return (Zero::zero(), Zero::zero())
+		};
+
+		let (min_own, min_total) = ErasStakersOverview::<T>::iter_prefix_values(era).fold(
+			(None, None),
+			|(min_own, min_total): (Option<BalanceOf<T>>, Option<BalanceOf<T>>), overview| {
+				(
+					Some(min_own.map_or(overview.own, |own| own.min(overview.own))),
+					Some(min_total.map_or(overview.total, |total| total.min(overview.total))),
+				)
+			},
+		);
+		let min_self_stake = MinValidatorBond::<T>::get().max(MinValidatorSelfStake::<T>::get());
+
+		(
+			(params.self_stake_threshold * min_own.unwrap_or_default()).max(min_self_stake),
+			params.approval_threshold * min_total.unwrap_or_default(),
+		)
+	}
+
+	/// The self-stake and approvals of a queued candidate, or `None` if its approvals were not
+	/// measured by an election snapshot yet.
+	///
+	/// The approvals are the self-stake plus [`PendingCandidateApprovals`].
+	pub(crate) fn candidacy_of(stash: &T::AccountId) -> (BalanceOf<T>, Option<BalanceOf<T>>) {
+		let self_stake = Self::ledger(Stash(stash.clone()))
+			.map(|ledger| ledger.active)
+			.unwrap_or_default();
+		let approvals = PendingCandidateApprovals::<T>::get(stash)
+			.map(|backing| self_stake.saturating_add(backing));
+		(self_stake, approvals)
+	}
+
+	/// Remove `stash` from the candidacy queue, returning whether it was queued.
+	pub(crate) fn remove_pending_candidate(stash: &T::AccountId) -> bool {
+		PendingCandidateApprovals::<T>::remove(stash);
+		PendingCandidates::<T>::take(stash).is_some()
+	}
+
+	/// Measure the approvals of the queued candidates from the `voters` of an election snapshot,
+	/// into [`PendingCandidateApprovals`].
+	fn measure_candidacy_approvals(voters: &[VoterOf<Self>]) {
+		let pending_count = PendingCandidates::<T>::count();
+		if pending_count == 0 {
+			Self::register_weight(T::DbWeight::get().reads(1));
+			return
+		}
+
+		// at most `MaxPendingCandidates`.
+		let mut backing = PendingCandidates::<T>::iter_keys()
+			.map(|stash| (stash, 0u128))
+			.collect::<BTreeMap<_, _>>();
+		for (voter, vote_weight, targets) in voters {
+			for target in targets.iter().filter(|target| *target != voter) {
+				if let Some(stake) = backing.get_mut(target) {
+					*stake = stake.saturating_add((*vote_weight).into());
+				}
+			}
+		}
+
+		let total_issuance = asset::total_issuance::<T>();
+		for (stash, stake) in backing {
+			let stake = T::CurrencyToVote::to_currency(stake, total_issuance);
+			PendingCandidateApprovals::<T>::insert(stash, stake);
+		}
+
+		let pending_count = pending_count as u64;
+		Self::register_weight(
+			T::DbWeight::get().reads_writes(2u64.saturating_add(pending_count), pending_count),
+		);
+	}
+
+	/// Turn the queued candidates whose approvals were measured and that meet the thresholds of
+	/// `era` into validator candidates, and reject the others. The candidates queued after the
+	/// last election snapshot stay queued.
+	///
+	/// If the queue is disabled, all queued candidates become validator candidates.
+	fn process_candidacy_queue(era: EraIndex) {
+		let pending_count = PendingCandidates::<T>::count();
+		if pending_count == 0 {
+			Self::register_weight(T::DbWeight::get().reads(1));
+			return
+		}
+
+		let queue_enabled = CandidacyQueueParams::<T>::exists();
+		let (min_self_stake, min_approvals) = Self::candidacy_thresholds(era);
+		// at most `MaxPendingCandidates`.
+		let pending = PendingCandidates::<T>::iter().collect::<Vec<_>>();
+		let mut processed = 0u32;
+		for (stash, prefs) in pending {
+			let (self_stake, approvals) = Self::candidacy_of(&stash);
+			let approvals = match approvals {
+				Some(approvals) => approvals,
+				None if queue_enabled => continue,
+				None => Zero::zero(),
+			};
+			Self::remove_pending_candidate(&stash);
+			processed.saturating_inc();
+
+			let has_room = MaxValidatorsCount::<T>::get()
+				.map_or(true, |max_validators| Validators::<T>::count() < max_validators);
+			if has_room && self_stake >= min_self_stake && approvals >= min_approvals {
+				Self::do_add_validator(&stash, prefs);
+				Self::deposit_event(Event::<T>::CandidacyActivated { stash });
+			} else {
+				Self::deposit_event(Event::<T>::CandidacyRejected { stash, self_stake, approvals });
+			}
+		}
+
+		// the thresholds read the overview of every validator of `era`.
+		let validators = ErasStakersOverview::<T>::iter_key_prefix(era).count() as u64;
+		Self::register_weight(
+			T::WeightInfo::validate()
+				.saturating_add(T::DbWeight::get().reads_writes(4, 3))
+				.saturating_mul(processed.into())
+				.saturating_add(
+					T::DbWeight::get().reads(
+						3u64.saturating_add(validators).saturating_add(pending_count.into()),
+					),
+				),
+		);
 	}
 
 	/// Compute payout for era.
@@ -580,16 +865,7 @@ impl<T: Config> Pallet<T> {
 			let staked = ErasTotalStake::<T>::get(&active_era.index);
 			let issuance = asset::total_issuance::<T>();
 
-			let (validator_payout, remainder) =
-				T::EraPayout::era_payout(staked, issuance, era_duration);
-
-			let total_payout = validator_payout.saturating_add(remainder);
-			let max_staked_rewards =
-				MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
-
-			// apply cap to validators payout and add difference to remainder.
-			let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
-			let remainder = total_payout.saturating_sub(validator_payout);
+			let (validator_payout, remainder) = Self::era_payout(staked, issuance, era_duration);
 
 			Self::deposit_event(Event::<T>::EraPaid {
 				era_index: active_era.index,
@@ -601,11 +877,110 @@ impl<T: Config> Pallet<T> {
 			<ErasValidatorReward<T>>::insert(&active_era.index, validator_payout);
 			T::RewardRemainder::on_unbalanced(asset::issue::<T>(remainder));
 
+			Self::update_validator_performances(active_era.index);
+
 			// Clear disabled validators.
 			<DisabledValidators<T>>::kill();
 		}
 	}
 
+	/// Update the rolling performance of the validators of `era`, see [`ValidatorPerformance`].
+	///
+	/// Nothing is updated if no era points were earned in `era`.
+	pub(crate) fn update_validator_performances(era: EraIndex) {
+		let era_points = ErasRewardPoints::<T>::get(era);
+		if era_points.total.is_zero() {
+			Self::register_weight(T::DbWeight::get().reads(1));
+			return
+		}
+
+		let validators = ErasStakersOverview::<T>::iter_key_prefix(era).collect::<Vec<_>>();
+		let validators_len = validators.len() as u64;
+		for validator in validators {
+			let points = era_points.individual.get(&validator).copied().unwrap_or_default();
+			// the score of the era is one if the validator earned at least the average points.
+			let points_score =
+				Perbill::from_rational(points as u64 * validators_len, era_points.total as u64);
+
+			ValidatorPerformances::<T>::mutate(&validator, |performance| {
+				let performance = performance.get_or_insert_with(Default::default);
+				// and it is halved by every offence reported in the era.
+				let era_score = points_score *
+					Perbill::from_percent(50)
+						.saturating_pow(performance.pending_offences as usize);
+				performance.score = (PERFORMANCE_SMOOTHING.left_from_one() * performance.score)
+					.saturating_add(PERFORMANCE_SMOOTHING * era_score);
+				performance.last_active_era = era;
+				performance.last_era_points = points;
+				performance.pending_offences = 0;
+			});
+		}
+
+		Self::register_weight(
+			T::DbWeight::get().reads_writes(1 + 2 * validators_len, validators_len),
+		);
+	}
+
+	/// Record an offence reported against `validator` in its [`ValidatorPerformance`].
+	pub(crate) fn note_offence(validator: &T::AccountId) {
+		ValidatorPerformances::<T>::mutate(validator, |performance| {
+			let performance = performance.get_or_insert_with(Default::default);
+			performance.offences.saturating_inc();
+			performance.pending_offences.saturating_inc();
+		});
+	}
+
+	/// The payout to validators and the remainder of an era, capped by [`MaxStakedRewards`].
+	pub(crate) fn era_payout(
+		staked: BalanceOf<T>,
+		issuance: BalanceOf<T>,
+		era_duration: u64,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		let (validator_payout, remainder) =
+			T::EraPayout::era_payout(staked, issuance, era_duration);
+
+		let total_payout = validator_payout.saturating_add(remainder);
+		let max_staked_rewards = MaxStakedRewards::<T>::get().unwrap_or(Percent::from_percent(100));
+
+		// apply cap to validators payout and add difference to remainder.
+		let validator_payout = validator_payout.min(max_staked_rewards * total_payout);
+		(validator_payout, total_payout.saturating_sub(validator_payout))
+	}
+
+	/// The number of eras a chunk of `value` unbonded in `era` has to wait before it can be
+	/// withdrawn.
+	///
+	/// This is [`Config::BondingDuration`], unless the unbonding queue is enabled through
+	/// [`UnbondingQueueParams`]. In that case it grows with the stake unbonded in `era` so far.
+	pub(crate) fn unbonding_duration(era: EraIndex, value: BalanceOf<T>) -> EraIndex {
+		let bonding_duration = T::BondingDuration::get();
+		let Some(params) = UnbondingQueueParams::<T>::get() else { return bonding_duration };
+
+		let exiting = ErasUnbondingTotal::<T>::get(era).saturating_add(value);
+		let capacity = params.max_exit_ratio * ErasTotalStake::<T>::get(era);
+		// saturates to one if `exiting` exceeds `capacity`, or if there is no capacity at all.
+		let demand = Perbill::from_rational(exiting, capacity);
+		let extra = demand * bonding_duration.saturating_sub(params.min_duration);
+
+		params.min_duration.saturating_add(extra).min(bonding_duration)
+	}
+
+	/// The earliest era in which a chunk unbonded in `era` or later unlocks.
+	///
+	/// This is `era + BondingDuration`, unless the unbonding queue handed out a shorter duration
+	/// since, as recorded in [`ErasUnbondingMinDuration`].
+	pub(crate) fn earliest_unlock_era(era: EraIndex) -> EraIndex {
+		let bonding_duration_end = era.saturating_add(T::BondingDuration::get());
+		// a chunk unbonded after `bonding_duration_end` cannot unlock before it.
+		let last_era = CurrentEra::<T>::get().unwrap_or(0).min(bonding_duration_end);
+
+		(era..=last_era)
+			.filter_map(|era| {
+				ErasUnbondingMinDuration::<T>::get(era).map(|duration| era.saturating_add(duration))
+			})
+			.fold(bonding_duration_end, |earliest, unlock_era| earliest.min(unlock_era))
+	}
+
 	/// Plan a new era.
 	///
 	/// * Bump the current era storage (which holds the latest planned era).
@@ -798,6 +1173,9 @@ impl<T: Config> Pallet<T> {
 
 		Self::do_remove_validator(&stash);
 		Self::do_remove_nominator(&stash);
+		Self::remove_pending_candidate(&stash);
+		ValidatorPerformances::<T>::remove(&stash);
+		PendingActivation::<T>::remove(&stash);
 
 		frame_system::Pallet::<T>::dec_consumers(&stash);
 
@@ -818,12 +1196,18 @@ impl<T: Config> Pallet<T> {
 		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ErasStakersPaged<T>>::clear_prefix((era_index,), u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
+		cursor = <ErasStakersPagedCommitment<T>>::clear_prefix((era_index,), u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
 		cursor = <ErasStakersOverview<T>>::clear_prefix(era_index, u32::MAX, None);
 		debug_assert!(cursor.maybe_cursor.is_none());
 
 		<ErasValidatorReward<T>>::remove(era_index);
 		<ErasRewardPoints<T>>::remove(era_index);
+		cursor = <ErasRewardPointsBySource<T>>::clear_prefix(era_index, u32::MAX, None);
+		debug_assert!(cursor.maybe_cursor.is_none());
 		<ErasTotalStake<T>>::remove(era_index);
+		ErasUnbondingTotal::<T>::remove(era_index);
+		ErasUnbondingMinDuration::<T>::remove(era_index);
 		ErasStartSessionIndex::<T>::remove(era_index);
 	}
 
@@ -864,6 +1248,41 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Add reward points to validators on behalf of `source`, one of the sources of
+	/// [`Config::EraPointsReward`].
+	///
+	/// The points are added like with [`Self::reward_by_ids`], until `source` has awarded as many
+	/// points as it may in the active era. The rest are dropped. Returns the number of points
+	/// added.
+	pub fn reward_by_source(
+		source: EraPointsSourceOf<T>,
+		validators_points: impl IntoIterator<Item = (T::AccountId, RewardPoint)>,
+	) -> RewardPoint {
+		let Some(active_era) = ActiveEra::<T>::get() else { return 0 };
+		let Some(max_points) = T::EraPointsReward::max_points_per_era(&source) else { return 0 };
+		let awarded = ErasRewardPointsBySource::<T>::get(active_era.index, &source);
+		let budget = max_points.saturating_sub(awarded);
+		let mut allowance = budget;
+		if allowance == 0 {
+			return 0
+		}
+
+		<ErasRewardPoints<T>>::mutate(active_era.index, |era_rewards| {
+			for (validator, points) in validators_points.into_iter() {
+				let points = points.min(allowance);
+				if points == 0 {
+					continue
+				}
+				*era_rewards.individual.entry(validator).or_default() += points;
+				era_rewards.total += points;
+				allowance -= points;
+			}
+		});
+		let added = budget - allowance;
+		ErasRewardPointsBySource::<T>::insert(active_era.index, source, awarded + added);
+		added
+	}
+
 	/// Helper to set a new `ForceEra` mode.
 	pub(crate) fn set_force_era(mode: Forcing) {
 		log!(info, "Setting force era mode {:?}.", mode);
@@ -1019,6 +1438,8 @@ impl<T: Config> Pallet<T> {
 		let mut all_targets = Vec::<T::AccountId>::with_capacity(final_predicted_len as usize);
 		let mut targets_seen = 0;
 
+		let min_self_stake = MinValidatorSelfStake::<T>::get();
+
 		let mut targets_iter = T::TargetList::iter();
 		while all_targets.len() < final_predicted_len as usize &&
 			targets_seen < (NPOS_MAX_ITERATIONS_COEFFICIENT * final_predicted_len as u32)
@@ -1031,6 +1452,17 @@ impl<T: Config> Pallet<T> {
 				None => break,
 			};
 
+			if !min_self_stake.is_zero() {
+				Self::register_weight(T::DbWeight::get().reads(2));
+				let self_stake = Self::ledger(Stash(target.clone())).map(|l| l.active);
+				if self_stake.map_or(false, |active| active < min_self_stake) {
+					// chilled at the start of the next era, see `chill_below_min_self_stake`.
+					Self::register_weight(T::DbWeight::get().writes(1));
+					ValidatorsBelowMinSelfStake::<T>::insert(target, ());
+					continue
+				}
+			}
+
 			if targets_size_tracker.try_register_target(target.clone(), &bounds).is_err() {
 				// no more space left for the election snapshot, stop iterating.
 				Self::deposit_event(Event::<T>::SnapshotTargetsSizeExceeded {
@@ -1059,13 +1491,22 @@ impl<T: Config> Pallet<T> {
 	/// to `Nominators` or `VoterList` outside of this function is almost certainly
 	/// wrong.
 	pub fn do_add_nominator(who: &T::AccountId, nominations: Nominations<T>) {
-		if !Nominators::<T>::contains_key(who) {
+		let is_nominator = Nominators::<T>::contains_key(who);
+		// nominations which can't be decoded anymore are reported as empty.
+		let prev_nominations = Nominators::<T>::get(who).map(|n| n.targets).unwrap_or_default();
+		if !is_nominator {
 			// maybe update sorted list.
 			let _ = T::VoterList::on_insert(who.clone(), Self::weight_of(who))
 				.defensive_unwrap_or_default();
 		}
 		Nominators::<T>::insert(who, nominations);
 
+		if is_nominator {
+			T::EventListeners::on_nominator_update(who, prev_nominations);
+		} else {
+			T::EventListeners::on_nominator_add(who);
+		}
+
 		debug_assert_eq!(
 			Nominators::<T>::count() + Validators::<T>::count(),
 			T::VoterList::count()
@@ -1082,8 +1523,11 @@ impl<T: Config> Pallet<T> {
 	/// wrong.
 	pub fn do_remove_nominator(who: &T::AccountId) -> bool {
 		let outcome = if Nominators::<T>::contains_key(who) {
+			// nominations which can't be decoded anymore are reported as empty.
+			let nominations = Nominators::<T>::get(who).map(|n| n.targets).unwrap_or_default();
 			Nominators::<T>::remove(who);
 			let _ = T::VoterList::on_remove(who).defensive();
+			T::EventListeners::on_nominator_remove(who, nominations);
 			true
 		} else {
 			false
@@ -1187,6 +1631,146 @@ impl<T: Config> Pallet<T> {
 	pub fn api_pending_rewards(era: EraIndex, account: T::AccountId) -> bool {
 		EraInfo::<T>::pending_rewards(era, &account)
 	}
+
+	/// The era in which all the funds of `stash` could be withdrawn, if it unbonded its whole
+	/// active stake now, or `None` if `stash` is not bonded.
+	pub fn api_estimate_exit_era(stash: T::AccountId) -> Option<EraIndex> {
+		let ledger = Self::ledger(Stash(stash)).ok()?;
+		let current_era = CurrentEra::<T>::get().unwrap_or(0);
+
+		let active_exit_era = (!ledger.active.is_zero()).then(|| {
+			current_era.saturating_add(Self::unbonding_duration(current_era, ledger.active))
+		});
+		let unlocking_exit_era = ledger.unlocking.iter().map(|chunk| chunk.era).max();
+
+		Some(active_exit_era.max(unlocking_exit_era).unwrap_or(current_era))
+	}
+
+	/// The rolling performance of validator `who`, or `None` if it has never been active nor
+	/// reported for an offence.
+	pub fn api_validator_performance(who: T::AccountId) -> Option<ValidatorPerformance> {
+		ValidatorPerformances::<T>::get(who)
+	}
+
+	/// All deferred slashes that have not been applied yet, by the era they are applied in.
+	pub fn api_pending_slashes() -> Vec<PendingSlash<T::AccountId, BalanceOf<T>>> {
+		let mut pending = UnappliedSlashes::<T>::iter().collect::<Vec<_>>();
+		pending.sort_by_key(|(era, _)| *era);
+		pending
+			.into_iter()
+			.flat_map(|(era, slashes)| {
+				slashes
+					.into_iter()
+					.enumerate()
+					.map(move |(index, slash)| slash.to_pending(era, index as u32))
+			})
+			.collect()
+	}
+
+	/// The stashes queued to become validator candidates at the start of the next era.
+	pub fn api_pending_candidates() -> Vec<PendingCandidate<T::AccountId, BalanceOf<T>>> {
+		let era = ActiveEra::<T>::get().map(|info| info.index).unwrap_or(0);
+		let (min_self_stake, min_approvals) = Self::candidacy_thresholds(era);
+		PendingCandidates::<T>::iter_keys()
+			.map(|stash| {
+				let (self_stake, approvals) = Self::candidacy_of(&stash);
+				let meets_thresholds = approvals.map_or(false, |approvals| {
+					self_stake >= min_self_stake && approvals >= min_approvals
+				});
+				let approvals = approvals.unwrap_or(self_stake);
+				PendingCandidate { stash, self_stake, approvals, meets_thresholds }
+			})
+			.collect()
+	}
+
+	/// The reward points awarded in `era` by each source of [`Config::EraPointsReward`] which
+	/// awarded any.
+	pub fn api_era_points_by_source(era: EraIndex) -> Vec<(EraPointsSourceOf<T>, RewardPoint)> {
+		ErasRewardPointsBySource::<T>::iter_prefix(era).collect()
+	}
+
+	/// The breakdown of the balance of `who`, see [`asset::stake_breakdown`].
+	pub fn api_stake_breakdown(who: T::AccountId) -> StakeableBreakdown<BalanceOf<T>> {
+		asset::stake_breakdown::<T>(&who)
+	}
+
+	/// How the stake of nominator `who` is distributed across the validators of the active era.
+	///
+	/// Scans the exposure pages of all validators of the era, since a nominator may back
+	/// validators it no longer nominates.
+	pub fn api_nominator_backing(
+		who: T::AccountId,
+	) -> Option<NominatorBacking<T::AccountId, BalanceOf<T>>> {
+		let era = ActiveEra::<T>::get()?.index;
+		let nominations = Nominators::<T>::get(&who)
+			.map(|nominations| nominations.targets.into_inner())
+			.unwrap_or_default();
+
+		// a nominator shows up in at most one exposure page of each validator.
+		let backing = ErasStakersPaged::<T>::iter_prefix((era,))
+			.filter_map(|((validator, _page), exposure_page)| {
+				exposure_page
+					.others
+					.into_iter()
+					.find(|exposure| exposure.who == who)
+					.map(|exposure| (validator, exposure.value))
+			})
+			.collect();
+
+		Some(NominatorBacking { era, nominations, backing })
+	}
+
+	/// Returns the projected rewards of `account` and its nominators in the active era.
+	///
+	/// Uses the same formula as the era payout and [`Call::payout_stakers`], so that the
+	/// projection only differs from the actual rewards by the reward points that are yet to be
+	/// earned. The era is assumed to last `era_duration` milliseconds, or to end now if `None`.
+	pub fn api_projected_rewards(
+		account: T::AccountId,
+		era_duration: Option<u64>,
+	) -> Option<ProjectedRewards<T::AccountId, BalanceOf<T>>> {
+		let active_era = ActiveEra::<T>::get()?;
+		let era = active_era.index;
+		let era_duration = match era_duration {
+			Some(duration) => duration,
+			None => {
+				let now = T::UnixTime::now().as_millis().saturated_into::<u64>();
+				now.saturating_sub(active_era.start?)
+			},
+		};
+
+		let staked = ErasTotalStake::<T>::get(era);
+		let (era_payout, _) = Self::era_payout(staked, asset::total_issuance::<T>(), era_duration);
+
+		let era_reward_points = ErasRewardPoints::<T>::get(era);
+		let commission = EraInfo::<T>::get_validator_commission(era, &account);
+		let (commission_payout, leftover_payout) =
+			Self::split_validator_reward(era_payout, &era_reward_points, &account, commission)
+				.unwrap_or((Zero::zero(), Zero::zero()));
+
+		let exposure = EraInfo::<T>::get_full_exposure(era, &account);
+		let validator_staking_payout =
+			Perbill::from_rational(exposure.own, exposure.total) * leftover_payout;
+		let nominator_payouts = exposure
+			.others
+			.into_iter()
+			.map(|nominator| {
+				let part = Perbill::from_rational(nominator.value, exposure.total);
+				(nominator.who, part * leftover_payout)
+			})
+			.collect();
+
+		Some(ProjectedRewards {
+			era,
+			era_payout,
+			points: era_reward_points.individual.get(&account).copied().unwrap_or_default(),
+			total_points: era_reward_points.total,
+			commission,
+			commission_payout,
+			validator_payout: validator_staking_payout.saturating_add(commission_payout),
+			nominator_payouts,
+		})
+	}
 }
 
 impl<T: Config> ElectionDataProvider for Pallet<T> {
@@ -1200,8 +1784,14 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 	}
 
 	fn electing_voters(bounds: DataProviderBounds) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		// Only a part of the voters could be reached while the list is rebuilt.
+		if T::VoterList::is_rebuilding() {
+			return Err("Voter list is being rebuilt")
+		}
+
 		// This can never fail -- if `maybe_max_len` is `Some(_)` we handle it.
 		let voters = Self::get_npos_voters(bounds);
+		Self::measure_candidacy_approvals(&voters);
 
 		debug_assert!(!bounds.exhausted(
 			SizeBound(voters.encoded_size() as u32).into(),
@@ -1212,6 +1802,10 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 	}
 
 	fn electable_targets(bounds: DataProviderBounds) -> data_provider::Result<Vec<T::AccountId>> {
+		if T::TargetList::is_rebuilding() {
+			return Err("Target list is being rebuilt")
+		}
+
 		let targets = Self::get_npos_targets(bounds);
 
 		// We can't handle this case yet -- return an error. WIP to improve handling this case in
@@ -1270,7 +1864,6 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		let stake = <BalanceOf<T>>::try_from(weight).unwrap_or_else(|_| {
 			panic!("cannot convert a VoteWeight into BalanceOf, benchmark needs reconfiguring.")
 		});
-		<Bonded<T>>::insert(voter.clone(), voter.clone());
 		<Ledger<T>>::insert(voter.clone(), StakingLedger::<T>::new(voter.clone(), stake));
 
 		Self::do_add_nominator(&voter, Nominations { targets, submitted_in: 0, suppressed: false });
@@ -1279,7 +1872,6 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 	#[cfg(feature = "runtime-benchmarks")]
 	fn add_target(target: T::AccountId) {
 		let stake = MinValidatorBond::<T>::get() * 100u32.into();
-		<Bonded<T>>::insert(target.clone(), target.clone());
 		<Ledger<T>>::insert(target.clone(), StakingLedger::<T>::new(target.clone(), stake));
 		Self::do_add_validator(
 			&target,
@@ -1311,7 +1903,6 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 			let stake: BalanceOf<T> = target_stake
 				.and_then(|w| <BalanceOf<T>>::try_from(w).ok())
 				.unwrap_or_else(|| MinNominatorBond::<T>::get() * 100u32.into());
-			<Bonded<T>>::insert(v.clone(), v.clone());
 			<Ledger<T>>::insert(v.clone(), StakingLedger::<T>::new(v.clone(), stake));
 			Self::do_add_validator(
 				&v,
@@ -1323,7 +1914,6 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 			let stake = <BalanceOf<T>>::try_from(s).unwrap_or_else(|_| {
 				panic!("cannot convert a VoteWeight into BalanceOf, benchmark needs reconfiguring.")
 			});
-			<Bonded<T>>::insert(v.clone(), v.clone());
 			<Ledger<T>>::insert(v.clone(), StakingLedger::<T>::new(v.clone(), stake));
 			Self::do_add_nominator(
 				&v,
@@ -1503,6 +2093,9 @@ where
 				slash_era,
 			});
 
+			Self::note_offence(stash);
+			add_db_reads_writes(1, 1);
+
 			let unapplied = slashing::compute_slash::<T>(slashing::SlashParams {
 				stash,
 				slash: *slash_fraction,
@@ -1578,7 +2171,6 @@ impl<T: Config> ScoreProvider<T::AccountId> for Pallet<T> {
 		ledger.active = active;
 
 		<Ledger<T>>::insert(who, ledger);
-		<Bonded<T>>::insert(who, who);
 
 		// also, we play a trick to make sure that a issuance based-`CurrencyToVote` behaves well:
 		// This will make sure that total issuance is zero, thus the currency to vote will be a 1-1
@@ -1858,6 +2450,13 @@ impl<T: Config> StakingInterface for Pallet<T> {
 		ErasStakersPaged::<T>::iter_prefix((era,)).any(|((validator, _), exposure_page)| {
 			validator == *who || exposure_page.others.iter().any(|i| i.who == *who)
 		})
+			||
+		// look in the committed exposures, a page that cannot be recalled counts as exposed.
+		ErasStakersPagedCommitment::<T>::iter_key_prefix((*era,)).any(|(validator, page)| {
+			validator == *who ||
+				EraInfo::<T>::get_committed_page(*era, &validator, page)
+					.map_or(true, |page| page.others.iter().any(|i| i.who == *who))
+		})
 	}
 	fn status(
 		who: &Self::AccountId,
@@ -1955,6 +2554,7 @@ impl<T: Config> sp_staking::StakingUnchecked for Pallet<T> {
 		let ledger = StakingLedger::<T>::new(keyless_who.clone(), value);
 
 		ledger.bond(RewardDestination::Account(payee.clone()))?;
+		Self::delay_activation(keyless_who, value);
 
 		Ok(())
 	}
@@ -2050,13 +2650,15 @@ impl<T: Config> Pallet<T> {
 	/// * The number of entries in `Payee` and of bonded staking ledgers *must* match.
 	/// * The stash account in the ledger must match that of the bonded account.
 	fn check_payees() -> Result<(), TryRuntimeError> {
-		for (stash, _) in Bonded::<T>::iter() {
-			ensure!(Payee::<T>::get(&stash).is_some(), "bonded ledger does not have payee set");
+		for ledger in Ledger::<T>::iter_values() {
+			ensure!(
+				Payee::<T>::get(&ledger.stash).is_some(),
+				"bonded ledger does not have payee set"
+			);
 		}
 
 		ensure!(
-			(Ledger::<T>::iter().count() == Payee::<T>::iter().count()) &&
-				(Ledger::<T>::iter().count() == Bonded::<T>::iter().count()),
+			Ledger::<T>::iter().count() == Payee::<T>::iter().count(),
 			"number of entries in payee storage items does not match the number of bonded ledgers",
 		);
 
@@ -2088,14 +2690,15 @@ impl<T: Config> Pallet<T> {
 
 	/// Invariants:
 	/// * Stake consistency: ledger.total == ledger.active + sum(ledger.unlocking).
-	/// * The ledger's controller and stash matches the associated `Bonded` tuple.
+	/// * The ledger's controller and stash matches the bonded controller of the stash.
 	/// * Staking locked funds for every bonded stash (non virtual stakers) should be the same as
 	/// its ledger's total.
 	/// * For virtual stakers, locked funds should be zero and payee should be non-stash account.
 	/// * Staking ledger and bond are not corrupted.
 	fn check_ledgers() -> Result<(), TryRuntimeError> {
-		Bonded::<T>::iter()
-			.map(|(stash, ctrl)| {
+		Ledger::<T>::iter()
+			.map(|(ctrl, ledger)| {
+				let stash = ledger.stash;
 				// ensure locks consistency.
 				if VirtualStakers::<T>::contains_key(stash.clone()) {
 					ensure!(
@@ -2103,7 +2706,7 @@ impl<T: Config> Pallet<T> {
 						"virtual stakers should not have any locked balance"
 					);
 					ensure!(
-						<Bonded<T>>::get(stash.clone()).unwrap() == stash.clone(),
+						Self::bonded(&stash) == Some(stash.clone()),
 						"stash and controller should be same"
 					);
 					ensure!(
@@ -2165,7 +2768,6 @@ impl<T: Config> Pallet<T> {
 	/// = exposure.own + exposure.own).
 	/// * Paged exposures metadata (`ErasStakersOverview`) matches the paged exposures state.
 	fn check_paged_exposures() -> Result<(), TryRuntimeError> {
-		use alloc::collections::btree_map::BTreeMap;
 		use sp_staking::PagedExposureMetadata;
 
 		// Sanity check for the paged exposure of the active era.
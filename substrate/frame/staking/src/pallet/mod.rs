@@ -41,7 +41,7 @@ use sp_staking::{
 	offence::OffenceSeverity,
 	EraIndex, Page, SessionIndex,
 	StakingAccount::{self, Controller, Stash},
-	StakingInterface,
+	StakingInterface, ValidatorPerformance,
 };
 
 mod impls;
@@ -50,10 +50,11 @@ pub use impls::*;
 
 use crate::{
 	asset, slashing, weights::WeightInfo, AccountIdLookupOf, ActiveEraInfo, BalanceOf,
-	DisablingStrategy, EraPayout, EraRewardPoints, Exposure, ExposurePage, Forcing,
+	CandidacyQueueConfig, DisablingStrategy, EraPayout, EraPointsReward, EraPointsSourceOf,
+	EraRewardPoints, Exposure, ExposureKeeper, ExposurePage, ExposureStorage, Forcing,
 	LedgerIntegrityState, MaxNominationsOf, NegativeImbalanceOf, Nominations, NominationsQuota,
-	PositiveImbalanceOf, RewardDestination, SessionInterface, StakingLedger, UnappliedSlash,
-	UnlockChunk, ValidatorPrefs,
+	PositiveImbalanceOf, RemotePayout, RewardDestination, RewardPoint, SessionInterface,
+	StakingLedger, UnappliedSlash, UnbondingQueueConfig, UnlockChunk, ValidatorPrefs,
 };
 
 // The speculative number of spans are used as an input of the weight annotation of
@@ -61,6 +62,9 @@ use crate::{
 // account which is not provided as an input. The value set should be conservative but sensible.
 pub(crate) const SPECULATIVE_NUM_SPANS: u32 = 32;
 
+/// The weight of the last era in the rolling score of [`ValidatorPerformances`].
+pub(crate) const PERFORMANCE_SMOOTHING: Perbill = Perbill::from_percent(20);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_election_provider_support::ElectionDataProvider;
@@ -70,7 +74,7 @@ pub mod pallet {
 	use super::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -149,7 +153,7 @@ pub mod pallet {
 		/// HistoryDepth, current_era]`: `ErasStakers`, `ErasStakersClipped`,
 		/// `ErasValidatorPrefs`, `ErasValidatorReward`, `ErasRewardPoints`,
 		/// `ErasTotalStake`, `ErasStartSessionIndex`, `ClaimedRewards`, `ErasStakersPaged`,
-		/// `ErasStakersOverview`.
+		/// `ErasStakersOverview`, `ErasRewardPointsBySource`, `ErasStakersPagedCommitment`.
 		///
 		/// Must be more than the number of eras delayed by session.
 		/// I.e. active era must always be in history. I.e. `active_era >
@@ -297,6 +301,43 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type DisablingStrategy: DisablingStrategy<Self>;
 
+		/// Transfers the rewards of stashes whose reward destination is
+		/// [`RewardDestination::Remote`] to their account on another chain.
+		///
+		/// With `()`, such rewards stay in the stash.
+		#[pallet::no_default_bounds]
+		type RemotePayout: RemotePayout<Self::AccountId, BalanceOf<Self>>;
+
+		/// The sources of era points other than block authoring, and how many points each of
+		/// them may award in an era with [`Pallet::reward_by_source`].
+		///
+		/// With `()`, no source may award era points.
+		#[pallet::no_default_bounds]
+		type EraPointsReward: EraPointsReward;
+
+		/// Keeps the exposure pages of the eras started while the [`ExposureStorageMode`] is
+		/// [`ExposureStorage::Commitment`].
+		///
+		/// The keeper must be able to recall the pages for the exposure checks and the slashes of
+		/// these eras. With `()`, the commitment mode cannot be set.
+		#[pallet::no_default_bounds]
+		type ExposureKeeper: ExposureKeeper<Self::AccountId, BalanceOf<Self>>;
+
+		/// The maximum number of stashes that can be queued in [`PendingCandidates`] at once.
+		///
+		/// This bounds the work of the candidacy queue in the election snapshot and at the start
+		/// of an era.
+		#[pallet::constant]
+		type MaxPendingCandidates: Get<u32>;
+
+		/// The number of eras for which newly bonded stake does not count toward elections, i.e.
+		/// toward the vote weight of its stash and the approvals of the targets it backs.
+		///
+		/// This keeps stake bonded right before an election from swaying it. Zero disables the
+		/// delay.
+		#[pallet::constant]
+		type BondActivationDelay: Get<EraIndex>;
+
 		/// Some parameters of the benchmarking.
 		#[cfg(feature = "std")]
 		type BenchmarkingConfig: BenchmarkingConfig;
@@ -344,6 +385,11 @@ pub mod pallet {
 			type MaxControllersInDeprecationBatch = ConstU32<100>;
 			type EventListeners = ();
 			type DisablingStrategy = crate::UpToLimitDisablingStrategy;
+			type RemotePayout = ();
+			type EraPointsReward = ();
+			type ExposureKeeper = ();
+			type MaxPendingCandidates = ConstU32<64>;
+			type BondActivationDelay = ConstU32<0>;
 			#[cfg(feature = "std")]
 			type BenchmarkingConfig = crate::TestBenchmarkingConfig;
 			type WeightInfo = ();
@@ -365,7 +411,12 @@ pub mod pallet {
 	#[pallet::unbounded]
 	pub type Invulnerables<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
-	/// Map from all locked "stash" accounts to the controller account.
+	/// Map from the "stash" accounts bonded before the controller deprecation to their controller
+	/// account.
+	///
+	/// Stashes bonded since then are their own controller and have no entry here. The remaining
+	/// entries are removed by
+	/// [`crate::migrations::deprecate_controllers::LazyDeprecateControllers`].
 	///
 	/// TWOX-NOTE: SAFE since `AccountId` is a secure hash.
 	#[pallet::storage]
@@ -379,6 +430,22 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MinValidatorBond<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// The minimum self-stake of a validator to be electable.
+	///
+	/// Unlike [`MinValidatorBond`], this is also checked whenever the election snapshot is taken:
+	/// validators whose active bond fell below it, for example because of a slash, are left out
+	/// of the snapshot and chilled at the start of the next era.
+	#[pallet::storage]
+	pub type MinValidatorSelfStake<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The validators that were left out of an election snapshot because their self-stake is below
+	/// [`MinValidatorSelfStake`]. They are chilled at the start of the next era.
+	///
+	/// Bounded by the number of targets an election snapshot iterates.
+	#[pallet::storage]
+	pub type ValidatorsBelowMinSelfStake<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, ()>;
+
 	/// The minimum active nominator stake of the last successful election.
 	#[pallet::storage]
 	pub type MinimumActiveStake<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
@@ -565,6 +632,27 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The hash of each page of exposure of a validator at given era, for the eras started while
+	/// the [`ExposureStorageMode`] is [`ExposureStorage::Commitment`].
+	///
+	/// The pages themselves are not in [`ErasStakersPaged`], and must be provided to pay the
+	/// stakers out. This is cleared after [`Config::HistoryDepth`] eras.
+	#[pallet::storage]
+	pub type ErasStakersPagedCommitment<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, Page>,
+		),
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// How the exposures of the elected validators are stored at the start of an era.
+	#[pallet::storage]
+	pub type ExposureStorageMode<T> = StorageValue<_, ExposureStorage, ValueQuery>;
+
 	/// History of claimed paged rewards by era and validator.
 	///
 	/// This is keyed by era and validator stash which maps to the set of page indexes which have
@@ -613,6 +701,21 @@ pub mod pallet {
 	pub type ErasRewardPoints<T: Config> =
 		StorageMap<_, Twox64Concat, EraIndex, EraRewardPoints<T::AccountId>, ValueQuery>;
 
+	/// The reward points awarded by each source of [`Config::EraPointsReward`] in the last
+	/// [`Config::HistoryDepth`] eras.
+	///
+	/// They are also part of the [`ErasRewardPoints`] of the era.
+	#[pallet::storage]
+	pub type ErasRewardPointsBySource<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Blake2_128Concat,
+		EraPointsSourceOf<T>,
+		RewardPoint,
+		ValueQuery,
+	>;
+
 	/// The total amount staked for the last [`Config::HistoryDepth`] eras.
 	/// If total hasn't been set or has been removed then 0 stake is returned.
 	#[pallet::storage]
@@ -629,6 +732,70 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MaxStakedRewards<T> = StorageValue<_, Percent, OptionQuery>;
 
+	/// The parameters of the demand-based unbonding queue.
+	///
+	/// If `None`, every unbonding chunk waits for [`Config::BondingDuration`] eras.
+	#[pallet::storage]
+	pub type UnbondingQueueParams<T> = StorageValue<_, UnbondingQueueConfig, OptionQuery>;
+
+	/// The parameters of the validator candidacy queue.
+	///
+	/// If `None`, [`Call::validate`] makes the stash a validator candidate right away.
+	#[pallet::storage]
+	pub type CandidacyQueueParams<T> = StorageValue<_, CandidacyQueueConfig, OptionQuery>;
+
+	/// The stashes that applied to be validator candidates while the candidacy queue was enabled,
+	/// with their preferences. They are processed at the start of the era after their approvals
+	/// were measured.
+	///
+	/// Bounded by [`Config::MaxPendingCandidates`].
+	#[pallet::storage]
+	pub type PendingCandidates<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, ValidatorPrefs>;
+
+	/// The stake of the nominators that nominated each queued candidate, as measured by the last
+	/// election snapshot.
+	///
+	/// The candidates that were queued after the last snapshot have no entry yet.
+	#[pallet::storage]
+	pub type PendingCandidateApprovals<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// The total amount that was requested to be unbonded in each of the last
+	/// [`Config::HistoryDepth`] eras, while the unbonding queue was enabled.
+	#[pallet::storage]
+	pub type ErasUnbondingTotal<T: Config> =
+		StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>, ValueQuery>;
+
+	/// The shortest unbonding duration handed out by the unbonding queue in each of the last
+	/// [`Config::HistoryDepth`] eras.
+	///
+	/// Slashes use it to find the unlocking chunks that were unbonded after the offence.
+	#[pallet::storage]
+	pub type ErasUnbondingMinDuration<T> =
+		StorageMap<_, Twox64Concat, EraIndex, EraIndex, OptionQuery>;
+
+	/// The rolling performance of validators, updated at the end of every era from the era points
+	/// they earned and the offences reported against them.
+	///
+	/// It is removed when the stash is reaped.
+	#[pallet::storage]
+	pub type ValidatorPerformances<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, ValidatorPerformance, OptionQuery>;
+
+	/// The stake of a stash that is bonded but does not count toward elections yet, see
+	/// [`Config::BondActivationDelay`], as chunks of the era from which it counts and its value.
+	///
+	/// It is removed when the stash is reaped.
+	#[pallet::storage]
+	pub type PendingActivation<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(EraIndex, BalanceOf<T>), T::MaxUnlockingChunks>,
+		ValueQuery,
+	>;
+
 	/// The percentage of the slash that is distributed to reporters.
 	///
 	/// The rest of the slashed value is handled by the `Slash`.
@@ -858,6 +1025,19 @@ pub mod pallet {
 		ValidatorDisabled { stash: T::AccountId },
 		/// Validator has been re-enabled.
 		ValidatorReenabled { stash: T::AccountId },
+		/// The given fraction of a deferred slash of the validator, to be applied at the start of
+		/// the given era, has been canceled.
+		DeferredSlashCanceled { validator: T::AccountId, era: EraIndex, fraction: Perbill },
+		/// A stash applied to be a validator candidate, and is queued until the next era.
+		CandidacyQueued { stash: T::AccountId, prefs: ValidatorPrefs },
+		/// A queued stash has become a validator candidate.
+		CandidacyActivated { stash: T::AccountId },
+		/// The candidacy of a queued stash has been rejected, as its self-stake or approvals were
+		/// below the thresholds.
+		CandidacyRejected { stash: T::AccountId, self_stake: BalanceOf<T>, approvals: BalanceOf<T> },
+		/// The reward of a stash could not be transferred to its remote reward destination, and
+		/// stays in the stash.
+		RemotePayoutFailed { stash: T::AccountId, amount: BalanceOf<T>, error: DispatchError },
 	}
 
 	#[pallet::error]
@@ -929,6 +1109,18 @@ pub mod pallet {
 		NotEnoughFunds,
 		/// Operation not allowed for virtual stakers.
 		VirtualStakerNotAllowed,
+		/// The unbonding queue parameters are out of bounds.
+		InvalidUnbondingQueueParams,
+		/// The active bond is below `MinValidatorSelfStake`.
+		InsufficientSelfStake,
+		/// Only the hash of this exposure page is stored, the page must be provided.
+		MissingExposurePage,
+		/// The provided exposure page does not match the stored hash, or the page is stored.
+		InvalidExposurePage,
+		/// The exposure pages can only be committed to with a keeper that can recall them.
+		ExposureKeeperCannotRecall,
+		/// The candidacy queue is full.
+		TooManyPendingCandidates,
 	}
 
 	#[pallet::hooks]
@@ -1133,8 +1325,8 @@ pub mod pallet {
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Take the origin account as a stash and lock up `value` of its balance. `controller` will
-		/// be the account that controls it.
+		/// Take the origin account as a stash and lock up `value` of its balance. The stash is its
+		/// own controller: no separate controller is stored in [`Bonded`].
 		///
 		/// `value` must be more than the `minimum_balance` specified by `T::Currency`.
 		///
@@ -1144,11 +1336,10 @@ pub mod pallet {
 		/// ## Complexity
 		/// - Independent of the arguments. Moderate complexity.
 		/// - O(1).
-		/// - Three extra DB entries.
+		/// - Two extra DB entries.
 		///
-		/// NOTE: Two of the storage writes (`Self::bonded`, `Self::payee`) are _never_ cleaned
-		/// unless the `origin` falls below _existential deposit_ (or equal to 0) and gets removed
-		/// as dust.
+		/// NOTE: The `Self::payee` storage write is _never_ cleaned unless the `origin` falls below
+		/// _existential deposit_ (or equal to 0) and gets removed as dust.
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::bond())]
 		pub fn bond(
@@ -1183,6 +1374,7 @@ pub mod pallet {
 			// You're auto-bonded forever, here. We might improve this by only bonding when
 			// you actually validate/nominate and remove once you unbond __everything__.
 			ledger.bond(payee)?;
+			Self::delay_activation(&stash, value);
 
 			Ok(())
 		}
@@ -1277,7 +1469,7 @@ pub mod pallet {
 				let min_active_bond = if Nominators::<T>::contains_key(&stash) {
 					MinNominatorBond::<T>::get()
 				} else if Validators::<T>::contains_key(&stash) {
-					MinValidatorBond::<T>::get()
+					MinValidatorBond::<T>::get().max(MinValidatorSelfStake::<T>::get())
 				} else {
 					Zero::zero()
 				};
@@ -1287,9 +1479,13 @@ pub mod pallet {
 				ensure!(ledger.active >= min_active_bond, Error::<T>::InsufficientBond);
 
 				// Note: in case there is no current era it is fine to bond one era more.
-				let era = CurrentEra::<T>::get()
-					.unwrap_or(0)
-					.defensive_saturating_add(T::BondingDuration::get());
+				let current_era = CurrentEra::<T>::get().unwrap_or(0);
+				let duration = Self::unbonding_duration(current_era, value);
+				let era = current_era
+					.defensive_saturating_add(duration)
+					// Keep `unlocking` sorted by era: a chunk never unlocks before an older one,
+					// even if the unbonding queue got shorter in the meantime.
+					.max(ledger.unlocking.last().map_or(0, |chunk| chunk.era));
 				if let Some(chunk) = ledger.unlocking.last_mut().filter(|chunk| chunk.era == era) {
 					// To keep the chunk count down, we only keep one chunk per era. Since
 					// `unlocking` is a FiFo queue, if a chunk exists for `era` we know that it will
@@ -1301,6 +1497,14 @@ pub mod pallet {
 						.try_push(UnlockChunk { value, era })
 						.map_err(|_| Error::<T>::NoMoreChunks)?;
 				};
+				if UnbondingQueueParams::<T>::exists() {
+					ErasUnbondingTotal::<T>::mutate(current_era, |total| {
+						*total = total.saturating_add(value)
+					});
+					ErasUnbondingMinDuration::<T>::mutate(current_era, |min_duration| {
+						*min_duration = Some(min_duration.map_or(duration, |d| d.min(duration)))
+					});
+				}
 				// NOTE: ledger must be updated prior to calling `Self::weight_of`.
 				ledger.update()?;
 
@@ -1360,6 +1564,10 @@ pub mod pallet {
 		///
 		/// Effects will be felt at the beginning of the next era.
 		///
+		/// If the candidacy queue is enabled, a stash that is not a validator candidate yet is
+		/// queued in [`PendingCandidates`] instead, and only becomes a candidate at the start of
+		/// the next era if it meets the thresholds of [`CandidacyQueueParams`].
+		///
 		/// The dispatch origin for this call must be _Signed_ by the controller, not the stash.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::validate())]
@@ -1369,24 +1577,42 @@ pub mod pallet {
 			let ledger = Self::ledger(Controller(controller))?;
 
 			ensure!(ledger.active >= MinValidatorBond::<T>::get(), Error::<T>::InsufficientBond);
+			ensure!(
+				ledger.active >= MinValidatorSelfStake::<T>::get(),
+				Error::<T>::InsufficientSelfStake
+			);
 			let stash = &ledger.stash;
 
 			// ensure their commission is correct.
 			ensure!(prefs.commission >= MinCommission::<T>::get(), Error::<T>::CommissionTooLow);
 
 			// Only check limits if they are not already a validator.
-			if !Validators::<T>::contains_key(stash) {
+			let is_validator = Validators::<T>::contains_key(stash);
+			if !is_validator && !PendingCandidates::<T>::contains_key(stash) {
 				// If this error is reached, we need to adjust the `MinValidatorBond` and start
 				// calling `chill_other`. Until then, we explicitly block new validators to protect
 				// the runtime.
 				if let Some(max_validators) = MaxValidatorsCount::<T>::get() {
 					ensure!(
-						Validators::<T>::count() < max_validators,
+						Validators::<T>::count().saturating_add(PendingCandidates::<T>::count()) <
+							max_validators,
 						Error::<T>::TooManyValidators
 					);
 				}
 			}
 
+			if !is_validator && CandidacyQueueParams::<T>::exists() {
+				ensure!(
+					PendingCandidates::<T>::contains_key(stash) ||
+						PendingCandidates::<T>::count() < T::MaxPendingCandidates::get(),
+					Error::<T>::TooManyPendingCandidates
+				);
+				Self::do_remove_nominator(stash);
+				PendingCandidates::<T>::insert(stash, prefs.clone());
+				Self::deposit_event(Event::<T>::CandidacyQueued { stash: ledger.stash, prefs });
+				return Ok(())
+			}
+
 			Self::do_remove_nominator(stash);
 			Self::do_add_validator(stash, prefs.clone());
 			Self::deposit_event(Event::<T>::ValidatorPrefsSet { stash: ledger.stash, prefs });
@@ -1462,6 +1688,7 @@ pub mod pallet {
 			};
 
 			Self::do_remove_validator(stash);
+			Self::remove_pending_candidate(stash);
 			Self::do_add_nominator(stash, nominations);
 			Ok(())
 		}
@@ -1769,7 +1996,10 @@ pub mod pallet {
 		///
 		/// If all pages are claimed, it returns an error `InvalidPage`.
 		#[pallet::call_index(18)]
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
+		#[pallet::weight(Pallet::<T>::payout_stakers_weight(
+			T::MaxExposurePageSize::get(),
+			T::MaxExposurePageSize::get().saturating_add(1),
+		))]
 		pub fn payout_stakers(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
@@ -1797,6 +2027,20 @@ pub mod pallet {
 			ensure!(!ledger.unlocking.is_empty(), Error::<T>::NoUnlockChunk);
 
 			let initial_unlocking = ledger.unlocking.len() as u32;
+			// the chunks that may have been unbonded in the current era through the unbonding
+			// queue, the stake rebonded from them no longer counts towards its demand.
+			let current_era = CurrentEra::<T>::get().unwrap_or(0);
+			let maybe_queued_start = ErasUnbondingMinDuration::<T>::get(current_era)
+				.map(|duration| current_era.saturating_add(duration));
+			let queued_value = |unlocking: &[UnlockChunk<BalanceOf<T>>]| {
+				maybe_queued_start.map_or(Zero::zero(), |start| {
+					unlocking
+						.iter()
+						.filter(|chunk| chunk.era >= start)
+						.fold(BalanceOf::<T>::zero(), |sum, chunk| sum.saturating_add(chunk.value))
+				})
+			};
+			let initial_queued = queued_value(&ledger.unlocking);
 			let (ledger, rebonded_value) = ledger.rebond(value);
 			// Last check: the new active amount of ledger must be more than ED.
 			ensure!(
@@ -1809,6 +2053,13 @@ pub mod pallet {
 				amount: rebonded_value,
 			});
 
+			let rebonded_queued = initial_queued.saturating_sub(queued_value(&ledger.unlocking));
+			if !rebonded_queued.is_zero() {
+				ErasUnbondingTotal::<T>::mutate(current_era, |total| {
+					*total = total.saturating_sub(rebonded_queued)
+				});
+			}
+
 			let stash = ledger.stash.clone();
 			let final_unlocking = ledger.unlocking.len();
 
@@ -1922,6 +2173,9 @@ pub mod pallet {
 		///   should be filled in order for the `chill_other` transaction to work.
 		/// * `min_commission`: The minimum amount of commission that each validators must maintain.
 		///   This is checked only upon calling `validate`. Existing validators are not affected.
+		/// * `min_validator_self_stake`: The minimum self-stake of a validator to be electable.
+		///   Validators below it are chilled at the start of the era after the next election
+		///   snapshot.
 		///
 		/// RuntimeOrigin must be Root to call this function.
 		///
@@ -1943,6 +2197,7 @@ pub mod pallet {
 			chill_threshold: ConfigOp<Percent>,
 			min_commission: ConfigOp<Perbill>,
 			max_staked_rewards: ConfigOp<Percent>,
+			min_validator_self_stake: ConfigOp<BalanceOf<T>>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 
@@ -1963,6 +2218,7 @@ pub mod pallet {
 			config_op_exp!(ChillThreshold<T>, chill_threshold);
 			config_op_exp!(MinCommission<T>, min_commission);
 			config_op_exp!(MaxStakedRewards<T>, max_staked_rewards);
+			config_op_exp!(MinValidatorSelfStake<T>, min_validator_self_stake);
 			Ok(())
 		}
 		/// Declare a `controller` to stop participating as either a validator or nominator.
@@ -2109,7 +2365,10 @@ pub mod pallet {
 		/// and so it should not be assumed the highest staker would be on the topmost page and vice
 		/// versa. If rewards are not claimed in [`Config::HistoryDepth`] eras, they are lost.
 		#[pallet::call_index(26)]
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
+		#[pallet::weight(Pallet::<T>::payout_stakers_weight(
+			T::MaxExposurePageSize::get(),
+			T::MaxExposurePageSize::get().saturating_add(1),
+		))]
 		pub fn payout_stakers_by_page(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
@@ -2276,14 +2535,19 @@ pub mod pallet {
 				Ok(LedgerIntegrityState::Ok) | Err(_) => Err(Error::<T>::CannotRestoreLedger),
 			}?;
 
-			// re-bond stash and controller tuple.
-			Bonded::<T>::insert(&stash, &new_controller);
+			// re-bond stash and controller tuple. A stash that is its own controller is bonded by
+			// its ledger alone.
+			if new_controller == stash {
+				Bonded::<T>::remove(&stash);
+			} else {
+				Bonded::<T>::insert(&stash, &new_controller);
+			}
 
 			// resoter ledger state.
 			let mut ledger = StakingLedger::<T>::new(stash.clone(), new_total);
 			ledger.controller = Some(new_controller);
 			ledger.unlocking = maybe_unlocking.unwrap_or_default();
-			ledger.update()?;
+			ledger.store()?;
 
 			ensure!(
 				Self::inspect_bond_state(&stash) == Ok(LedgerIntegrityState::Ok),
@@ -2291,6 +2555,128 @@ pub mod pallet {
 			);
 			Ok(())
 		}
+
+		/// Sets the parameters of the demand-based unbonding queue, or disables it with `None`.
+		///
+		/// `min_duration` cannot exceed [`Config::BondingDuration`], and must be greater than
+		/// [`Config::SlashDeferDuration`] so that deferred slashes still reach unbonding funds.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::set_unbonding_queue_params())]
+		pub fn set_unbonding_queue_params(
+			origin: OriginFor<T>,
+			params: Option<UnbondingQueueConfig>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			if let Some(params) = params {
+				ensure!(
+					params.min_duration <= T::BondingDuration::get() &&
+						params.min_duration > T::SlashDeferDuration::get(),
+					Error::<T>::InvalidUnbondingQueueParams
+				);
+			}
+			UnbondingQueueParams::<T>::set(params);
+			Ok(())
+		}
+
+		/// Cancel a part or the whole of a single deferred slash.
+		///
+		/// Unlike [`Call::cancel_deferred_slash`], the slash must also be identified by its
+		/// `validator`, so that another slash is not canceled if the slashes of the era were
+		/// shifted in the meantime. Every slashed amount, and the payout to the reporters, is
+		/// reduced by `fraction`. The slash is removed if `fraction` is one.
+		///
+		/// Pending slashes and their indices are listed by the `pending_slashes` runtime API.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::cancel_deferred_slash(1))]
+		pub fn cancel_deferred_slash_partially(
+			origin: OriginFor<T>,
+			era: EraIndex,
+			slash_index: u32,
+			validator: T::AccountId,
+			fraction: Perbill,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			UnappliedSlashes::<T>::try_mutate(era, |unapplied| -> DispatchResult {
+				let index = slash_index as usize;
+				ensure!(
+					unapplied.get(index).map_or(false, |slash| slash.validator == validator),
+					Error::<T>::InvalidSlashIndex
+				);
+				if fraction.is_one() {
+					unapplied.remove(index);
+				} else {
+					unapplied[index].cancel(fraction);
+				}
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::DeferredSlashCanceled { validator, era, fraction });
+			Ok(())
+		}
+
+		/// Sets the parameters of the validator candidacy queue, or disables it with `None`.
+		///
+		/// If the queue is disabled, the stashes that are still queued become validator
+		/// candidates at the start of the next era, without any threshold.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::set_candidacy_queue_params())]
+		pub fn set_candidacy_queue_params(
+			origin: OriginFor<T>,
+			params: Option<CandidacyQueueConfig>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			CandidacyQueueParams::<T>::set(params);
+			Ok(())
+		}
+
+		/// Sets how the exposures of the elected validators are stored from the next era on.
+		///
+		/// The exposures of the past eras stay as they were stored. [`ExposureStorage::Commitment`]
+		/// can only be set if [`Config::ExposureKeeper`] can recall the pages it keeps.
+		///
+		/// The dispatch origin must be `T::AdminOrigin`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_exposure_storage())]
+		pub fn set_exposure_storage(origin: OriginFor<T>, mode: ExposureStorage) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			ensure!(
+				mode == ExposureStorage::Full || T::ExposureKeeper::CAN_RECALL,
+				Error::<T>::ExposureKeeperCannotRecall
+			);
+			ExposureStorageMode::<T>::put(mode);
+			Ok(())
+		}
+
+		/// Pay out a page of the stakers behind a validator for an era in which only the hash of
+		/// the exposure pages was stored.
+		///
+		/// Same as [`Call::payout_stakers_by_page`], except that `exposure_page` must be the page
+		/// of exposure, as handed to [`Config::ExposureKeeper`]. It is checked against the hash
+		/// stored in [`ErasStakersPagedCommitment`].
+		///
+		/// The origin of this call must be _Signed_.
+		#[pallet::call_index(35)]
+		#[pallet::weight(Pallet::<T>::payout_stakers_weight(
+			T::MaxExposurePageSize::get(),
+			T::MaxExposurePageSize::get().saturating_add(1),
+		))]
+		pub fn payout_stakers_with_exposure(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			page: Page,
+			exposure_page: ExposurePage<T::AccountId, BalanceOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_payout_stakers_by_page_with(validator_stash, era, page, Some(exposure_page))
+		}
 	}
 }
 
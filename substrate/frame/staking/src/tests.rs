@@ -21,13 +21,13 @@ use super::{ConfigOp, Event, *};
 use crate::{asset, ledger::StakingLedgerInspect};
 use frame_election_provider_support::{
 	bounds::{DataProviderBounds, ElectionBoundsBuilder},
-	ElectionProvider, SortedListProvider, Support,
+	ElectionDataProvider, ElectionProvider, SortedListProvider, Support,
 };
 use frame_support::{
 	assert_noop, assert_ok, assert_storage_noop,
 	dispatch::{extract_actual_weight, GetDispatchInfo, WithPostDispatchInfo},
 	pallet_prelude::*,
-	traits::{Currency, Get, ReservableCurrency},
+	traits::{Currency, Get, LockableCurrency, ReservableCurrency, WithdrawReasons},
 };
 
 use mock::*;
@@ -39,7 +39,7 @@ use sp_runtime::{
 };
 use sp_staking::{
 	offence::{OffenceDetails, OnOffenceHandler},
-	SessionIndex,
+	PendingCandidate, PendingSlash, SessionIndex, StakeableBreakdown, ValidatorPerformance,
 };
 use substrate_test_utils::assert_eq_uvec;
 
@@ -55,7 +55,8 @@ fn set_staking_configs_works() {
 			ConfigOp::Set(20),
 			ConfigOp::Set(Percent::from_percent(75)),
 			ConfigOp::Set(Zero::zero()),
-			ConfigOp::Set(Zero::zero())
+			ConfigOp::Set(Zero::zero()),
+			ConfigOp::Set(2_500)
 		));
 		assert_eq!(MinNominatorBond::<Test>::get(), 1_500);
 		assert_eq!(MinValidatorBond::<Test>::get(), 2_000);
@@ -64,6 +65,7 @@ fn set_staking_configs_works() {
 		assert_eq!(ChillThreshold::<Test>::get(), Some(Percent::from_percent(75)));
 		assert_eq!(MinCommission::<Test>::get(), Perbill::from_percent(0));
 		assert_eq!(MaxStakedRewards::<Test>::get(), Some(Percent::from_percent(0)));
+		assert_eq!(MinValidatorSelfStake::<Test>::get(), 2_500);
 
 		// noop does nothing
 		assert_storage_noop!(assert_ok!(Staking::set_staking_configs(
@@ -74,6 +76,7 @@ fn set_staking_configs_works() {
 			ConfigOp::Noop,
 			ConfigOp::Noop,
 			ConfigOp::Noop,
+			ConfigOp::Noop,
 			ConfigOp::Noop
 		)));
 
@@ -86,6 +89,7 @@ fn set_staking_configs_works() {
 			ConfigOp::Remove,
 			ConfigOp::Remove,
 			ConfigOp::Remove,
+			ConfigOp::Remove,
 			ConfigOp::Remove
 		));
 		assert_eq!(MinNominatorBond::<Test>::get(), 0);
@@ -95,6 +99,7 @@ fn set_staking_configs_works() {
 		assert_eq!(ChillThreshold::<Test>::get(), None);
 		assert_eq!(MinCommission::<Test>::get(), Perbill::from_percent(0));
 		assert_eq!(MaxStakedRewards::<Test>::get(), None);
+		assert_eq!(MinValidatorSelfStake::<Test>::get(), 0);
 	});
 }
 
@@ -1810,6 +1815,7 @@ fn max_staked_rewards_works() {
 			ConfigOp::Noop,
 			ConfigOp::Noop,
 			ConfigOp::Set(Percent::from_percent(max_staked_rewards)),
+			ConfigOp::Noop,
 		));
 
 		assert_eq!(<MaxStakedRewards<Test>>::get(), Some(Percent::from_percent(10)));
@@ -1911,7 +1917,7 @@ fn reap_stash_works() {
 			assert_eq!(Staking::bonded(&11), Some(11));
 
 			assert!(<Ledger<Test>>::contains_key(&11));
-			assert!(<Bonded<Test>>::contains_key(&11));
+			assert!(StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 			assert!(<Validators<Test>>::contains_key(&11));
 			assert!(<Payee<Test>>::contains_key(&11));
 
@@ -1930,7 +1936,7 @@ fn reap_stash_works() {
 
 			// then
 			assert!(!<Ledger<Test>>::contains_key(&11));
-			assert!(!<Bonded<Test>>::contains_key(&11));
+			assert!(!StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 			assert!(!<Validators<Test>>::contains_key(&11));
 			assert!(!<Payee<Test>>::contains_key(&11));
 			// lock is removed.
@@ -1949,7 +1955,7 @@ fn reap_stash_works_with_existential_deposit_zero() {
 			assert_eq!(Staking::bonded(&11), Some(11));
 
 			assert!(<Ledger<Test>>::contains_key(&11));
-			assert!(<Bonded<Test>>::contains_key(&11));
+			assert!(StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 			assert!(<Validators<Test>>::contains_key(&11));
 			assert!(<Payee<Test>>::contains_key(&11));
 
@@ -1968,7 +1974,7 @@ fn reap_stash_works_with_existential_deposit_zero() {
 
 			// then
 			assert!(!<Ledger<Test>>::contains_key(&11));
-			assert!(!<Bonded<Test>>::contains_key(&11));
+			assert!(!StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 			assert!(!<Validators<Test>>::contains_key(&11));
 			assert!(!<Payee<Test>>::contains_key(&11));
 			// lock is removed.
@@ -2410,6 +2416,43 @@ fn add_reward_points_fns_works() {
 	})
 }
 
+#[test]
+fn reward_by_source_is_capped_per_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		let era = active_era();
+
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Bridge, vec![(11, 4), (21, 4)]), 8);
+		// only 2 points are left for this era.
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Bridge, vec![(21, 3), (11, 3)]), 2);
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Bridge, vec![(11, 1)]), 0);
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Unpermitted, vec![(11, 5)]), 0);
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Availability, vec![(11, 5)]), 5);
+
+		// the points add up with the ones awarded by id.
+		Pallet::<Test>::reward_by_ids(vec![(21, 1)]);
+		assert_eq!(
+			ErasRewardPoints::<Test>::get(era),
+			EraRewardPoints { individual: vec![(11, 9), (21, 7)].into_iter().collect(), total: 16 },
+		);
+		let mut by_source = Staking::api_era_points_by_source(era);
+		by_source.sort_by_key(|(_, points)| *points);
+		assert_eq!(
+			by_source,
+			vec![(TestPointsSource::Availability, 5), (TestPointsSource::Bridge, 10)]
+		);
+
+		// the budget is renewed in the next era.
+		mock::start_active_era(era + 1);
+		assert_eq!(Staking::reward_by_source(TestPointsSource::Bridge, vec![(11, 20)]), 10);
+		assert_eq!(ErasRewardPointsBySource::<Test>::get(era, TestPointsSource::Bridge), 10);
+		assert_eq!(ErasRewardPointsBySource::<Test>::get(era + 1, TestPointsSource::Bridge), 10);
+
+		// and the accounting goes away with the era.
+		Staking::clear_era_information(era);
+		assert!(Staking::api_era_points_by_source(era).is_empty());
+	})
+}
+
 #[test]
 fn unbonded_balance_is_not_slashable() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -3221,6 +3264,91 @@ fn staker_cannot_bail_deferred_slash() {
 	})
 }
 
+#[test]
+fn cancel_deferred_slash_partially_works() {
+	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
+		mock::start_active_era(1);
+		let exposure = Staking::eras_stakers(active_era(), &11);
+		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
+		let balance_21 = asset::stakeable_balance::<Test>(&21);
+
+		// both deferred to start of era 4.
+		on_offence_now(
+			&[
+				OffenceDetails { offender: (11, exposure), reporters: vec![] },
+				OffenceDetails {
+					offender: (21, Staking::eras_stakers(active_era(), &21)),
+					reporters: vec![],
+				},
+			],
+			&[Perbill::from_percent(10), Perbill::from_percent(10)],
+		);
+		let pending = Staking::api_pending_slashes();
+		assert_eq!(pending.len(), 2);
+		let PendingSlash { apply_era, index, validator, own, .. } = pending[0].clone();
+		assert_eq!((apply_era, index, validator, own), (4, 0, 11, 100));
+		assert_eq!((pending[1].index, pending[1].validator), (1, 21));
+
+		// the validator must match the slash at the index.
+		assert_noop!(
+			Staking::cancel_deferred_slash_partially(
+				RuntimeOrigin::root(),
+				4,
+				0,
+				21,
+				Perbill::from_percent(50)
+			),
+			Error::<Test>::InvalidSlashIndex
+		);
+		assert_noop!(
+			Staking::cancel_deferred_slash_partially(
+				RuntimeOrigin::signed(11),
+				4,
+				0,
+				11,
+				Perbill::from_percent(50)
+			),
+			BadOrigin
+		);
+
+		// half of the slash of 11 is canceled, the slash of 21 entirely.
+		assert_ok!(Staking::cancel_deferred_slash_partially(
+			RuntimeOrigin::root(),
+			4,
+			0,
+			11,
+			Perbill::from_percent(50)
+		));
+		assert_ok!(Staking::cancel_deferred_slash_partially(
+			RuntimeOrigin::root(),
+			4,
+			1,
+			21,
+			Perbill::one()
+		));
+		assert!(matches!(
+			staking_events_since_last_call().as_slice(),
+			&[
+				..,
+				Event::DeferredSlashCanceled { validator: 11, era: 4, .. },
+				Event::DeferredSlashCanceled { validator: 21, era: 4, .. },
+			]
+		));
+		let pending = Staking::api_pending_slashes();
+		assert_eq!(pending.len(), 1);
+		assert_eq!(pending[0].own, 50);
+
+		mock::start_active_era(4);
+		assert_eq!(asset::stakeable_balance::<Test>(&11), 950);
+		assert_eq!(asset::stakeable_balance::<Test>(&21), balance_21);
+		assert_eq!(
+			asset::stakeable_balance::<Test>(&101),
+			2000 - Perbill::from_percent(50) * (nominated_value / 10)
+		);
+		assert!(Staking::api_pending_slashes().is_empty());
+	})
+}
+
 #[test]
 fn remove_deferred() {
 	ExtBuilder::default().slash_defer_duration(2).build_and_execute(|| {
@@ -5133,6 +5261,22 @@ fn on_finalize_weight_is_nonzero() {
 	})
 }
 
+#[test]
+fn nomination_changes_are_reported_to_event_listeners() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_eq!(Nominators::<Test>::get(101).unwrap().targets, vec![11, 21]);
+		assert!(NominationsObserver::get().is_empty());
+
+		// updating nominations reports the previous ones.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![11]));
+		assert_eq!(NominationsObserver::get().get(&101), Some(&vec![11, 21]));
+
+		// chilling reports the removed ones.
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(101)));
+		assert_eq!(NominationsObserver::get().get(&101), Some(&vec![11]));
+	});
+}
+
 mod election_data_provider {
 	use super::*;
 	use frame_election_provider_support::ElectionDataProvider;
@@ -5172,6 +5316,22 @@ mod election_data_provider {
 		);
 	}
 
+	#[test]
+	fn no_snapshot_while_voter_list_is_rebuilt() {
+		ExtBuilder::default().build_and_execute(|| {
+			assert_ok!(VoterBagsList::start_rebuild(RuntimeOrigin::root()));
+			assert_eq!(
+				<Staking as ElectionDataProvider>::electing_voters(DataProviderBounds::default()),
+				Err("Voter list is being rebuilt")
+			);
+
+			assert_ok!(VoterBagsList::rebuild(RuntimeOrigin::root(), 64));
+			assert_ok!(<Staking as ElectionDataProvider>::electing_voters(
+				DataProviderBounds::default()
+			));
+		});
+	}
+
 	#[test]
 	fn set_minimum_active_stake_is_correct() {
 		ExtBuilder::default()
@@ -5741,6 +5901,7 @@ fn chill_other_works() {
 				ConfigOp::Remove,
 				ConfigOp::Remove,
 				ConfigOp::Noop,
+				ConfigOp::Noop,
 			));
 
 			// Still can't chill these users
@@ -5763,6 +5924,7 @@ fn chill_other_works() {
 				ConfigOp::Noop,
 				ConfigOp::Noop,
 				ConfigOp::Noop,
+				ConfigOp::Noop,
 			));
 
 			// Still can't chill these users
@@ -5785,6 +5947,7 @@ fn chill_other_works() {
 				ConfigOp::Noop,
 				ConfigOp::Noop,
 				ConfigOp::Noop,
+				ConfigOp::Noop,
 			));
 
 			// Still can't chill these users
@@ -5807,6 +5970,7 @@ fn chill_other_works() {
 				ConfigOp::Set(Percent::from_percent(75)),
 				ConfigOp::Noop,
 				ConfigOp::Noop,
+				ConfigOp::Noop,
 			));
 
 			// 16 people total because tests start with 2 active one
@@ -5853,6 +6017,7 @@ fn capped_stakers_works() {
 			ConfigOp::Remove,
 			ConfigOp::Remove,
 			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 
 		// can create `max - validator_count` validators
@@ -5924,6 +6089,7 @@ fn capped_stakers_works() {
 			ConfigOp::Noop,
 			ConfigOp::Noop,
 			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 		assert_ok!(Staking::nominate(RuntimeOrigin::signed(last_nominator), vec![1]));
 		assert_ok!(Staking::validate(
@@ -5960,6 +6126,7 @@ fn min_commission_works() {
 			ConfigOp::Remove,
 			ConfigOp::Set(Perbill::from_percent(10)),
 			ConfigOp::Noop,
+			ConfigOp::Noop,
 		));
 
 		// can't make it less than 10 now
@@ -6120,6 +6287,656 @@ fn api_nominations_quota_works() {
 	})
 }
 
+#[test]
+fn api_stake_breakdown_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		// 41 has 2000 free, of which 1000 is bonded.
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&41, 200));
+		assert_eq!(
+			Staking::api_stake_breakdown(41),
+			StakeableBreakdown {
+				free_to_stake: 800,
+				staked: 1000,
+				reserved: 200,
+				frozen: 1000,
+				existential_deposit: ExistentialDeposit::get(),
+			}
+		);
+
+		// a larger lock overlaps with the staking lock and does not reduce what can be bonded.
+		Balances::set_lock(*b"otherlck", &41, 1500, WithdrawReasons::all());
+		let breakdown = Staking::api_stake_breakdown(41);
+		assert_eq!((breakdown.free_to_stake, breakdown.frozen), (800, 1500));
+
+		// exactly `free_to_stake` can be bonded.
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(41), 800));
+		let breakdown = Staking::api_stake_breakdown(41);
+		assert_eq!((breakdown.free_to_stake, breakdown.staked), (0, 1800));
+	});
+}
+
+#[test]
+fn validator_performance_is_updated_at_era_end() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		// forget the performance of era 0.
+		let _ = ValidatorPerformances::<Test>::clear(u32::MAX, None);
+		assert_eq!(Staking::api_validator_performance(11), None);
+		// a nominator has no performance.
+		assert_eq!(Staking::api_validator_performance(101), None);
+
+		// 11 authors all blocks of era 1, while 21 earns no points and commits an offence.
+		add_slash(&21);
+		assert_eq!(
+			Staking::api_validator_performance(21),
+			Some(ValidatorPerformance { offences: 1, pending_offences: 1, ..Default::default() })
+		);
+
+		mock::start_active_era(2);
+		let performance_11 = Staking::api_validator_performance(11).unwrap();
+		assert_eq!(performance_11.score, Perbill::from_percent(20));
+		assert_eq!(performance_11.last_active_era, 1);
+		assert_eq!(performance_11.offences, 0);
+		assert_eq!(
+			Staking::api_validator_performance(21),
+			Some(ValidatorPerformance {
+				score: Perbill::zero(),
+				last_active_era: 1,
+				last_era_points: 0,
+				offences: 1,
+				pending_offences: 0,
+			})
+		);
+
+		// 11 earns half the average points, 21 more than the average but commits an offence.
+		ErasRewardPoints::<Test>::insert(
+			1,
+			EraRewardPoints { total: 4, individual: vec![(11, 1), (21, 3)].into_iter().collect() },
+		);
+		Staking::note_offence(&21);
+		Staking::update_validator_performances(1);
+
+		assert_eq!(
+			Staking::api_validator_performance(11).unwrap().score,
+			Perbill::from_percent(26)
+		);
+		assert_eq!(
+			Staking::api_validator_performance(21),
+			Some(ValidatorPerformance {
+				score: Perbill::from_percent(10),
+				last_active_era: 1,
+				last_era_points: 3,
+				offences: 2,
+				pending_offences: 0,
+			})
+		);
+	});
+}
+
+#[test]
+fn api_projected_rewards_matches_payout() {
+	ExtBuilder::default().build_and_execute(|| {
+		let commission = Perbill::from_percent(40);
+		<Validators<Test>>::insert(&11, ValidatorPrefs { commission, ..Default::default() });
+		<Payee<Test>>::insert(&11, RewardDestination::Stash);
+		<Payee<Test>>::insert(&101, RewardDestination::Stash);
+
+		mock::start_active_era(1);
+		mock::make_all_reward_payment(0);
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+
+		let projected = Staking::api_projected_rewards(11, Some(reward_time_per_era())).unwrap();
+		assert_eq!(projected.era, 1);
+		assert_eq!((projected.points, projected.total_points), (1, 1));
+		assert_eq!(projected.commission, commission);
+		assert_eq!(
+			projected.era_payout,
+			current_total_payout_for_duration(reward_time_per_era())
+		);
+		assert_eq!(projected.commission_payout, commission * projected.era_payout);
+		// a validator without reward points is projected to earn nothing.
+		assert_eq!(Staking::api_projected_rewards(21, None).unwrap().validator_payout, 0);
+
+		let balance_11 = asset::total_balance::<Test>(&11);
+		let balance_101 = asset::total_balance::<Test>(&101);
+		mock::start_active_era(2);
+		mock::make_all_reward_payment(1);
+
+		assert_eq_error_rate!(
+			asset::total_balance::<Test>(&11),
+			balance_11 + projected.validator_payout,
+			2
+		);
+		assert_eq!(projected.nominator_payouts.len(), 1);
+		assert_eq!(projected.nominator_payouts[0].0, 101);
+		assert_eq_error_rate!(
+			asset::total_balance::<Test>(&101),
+			balance_101 + projected.nominator_payouts[0].1,
+			2
+		);
+	});
+}
+
+#[test]
+fn api_projected_rewards_requires_active_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		ActiveEra::<Test>::kill();
+		assert_eq!(Staking::api_projected_rewards(11, None), None);
+	});
+}
+
+#[test]
+fn api_nominator_backing_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		let backing = Staking::api_nominator_backing(101).unwrap();
+		assert_eq!(backing.era, active_era());
+		assert_eq!(backing.nominations, vec![11, 21]);
+		assert_eq_uvec!(backing.backing, vec![(11, 125), (21, 375)]);
+
+		// new nominations only become effective in the next era.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(101), vec![31]));
+		let backing = Staking::api_nominator_backing(101).unwrap();
+		assert_eq!(backing.nominations, vec![31]);
+		assert_eq_uvec!(backing.backing, vec![(11, 125), (21, 375)]);
+
+		// not a nominator.
+		let backing = Staking::api_nominator_backing(1337).unwrap();
+		assert!(backing.nominations.is_empty() && backing.backing.is_empty());
+
+		ActiveEra::<Test>::kill();
+		assert_eq!(Staking::api_nominator_backing(101), None);
+	});
+}
+
+#[test]
+fn unbonding_queue_scales_with_demand() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let params =
+			UnbondingQueueConfig { min_duration: 1, max_exit_ratio: Perbill::from_percent(10) };
+		assert_ok!(Staking::set_unbonding_queue_params(RuntimeOrigin::root(), Some(params)));
+
+		// when little stake is exiting, unbonding takes `min_duration`.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 1));
+		assert_eq!(
+			Ledger::<Test>::get(&11).unwrap().unlocking.into_inner(),
+			vec![UnlockChunk { value: 1, era: 1 + 1 }]
+		);
+		assert_eq!(ErasUnbondingTotal::<Test>::get(1), 1);
+
+		// once `max_exit_ratio` of the stake is exiting, unbonding takes `BondingDuration`.
+		let capacity = Perbill::from_percent(10) * ErasTotalStake::<Test>::get(1);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(21), capacity));
+		assert_eq!(
+			Ledger::<Test>::get(&21).unwrap().unlocking.into_inner(),
+			vec![UnlockChunk { value: capacity, era: 1 + 3 }]
+		);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 1));
+		assert_eq!(
+			Ledger::<Test>::get(&11).unwrap().unlocking.into_inner(),
+			vec![UnlockChunk { value: 1, era: 1 + 1 }, UnlockChunk { value: 1, era: 1 + 3 }]
+		);
+		assert_eq!(Staking::api_estimate_exit_era(11), Some(1 + 3));
+
+		// demand is tracked per era, but a chunk never unlocks before an older one.
+		mock::start_active_era(2);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 1));
+		assert_eq!(
+			Ledger::<Test>::get(&11).unwrap().unlocking.into_inner(),
+			vec![UnlockChunk { value: 1, era: 1 + 1 }, UnlockChunk { value: 2, era: 1 + 3 }]
+		);
+
+		// when the queue is disabled, unbonding takes `BondingDuration` again.
+		assert_ok!(Staking::set_unbonding_queue_params(RuntimeOrigin::root(), None));
+		assert_eq!(Staking::api_estimate_exit_era(21), Some(2 + 3));
+		assert_eq!(Staking::api_estimate_exit_era(1337), None);
+	});
+}
+
+#[test]
+fn unbonding_queue_slashes_and_rebonds_shortened_chunks() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let params = UnbondingQueueConfig { min_duration: 1, max_exit_ratio: Perbill::one() };
+		assert_ok!(Staking::set_unbonding_queue_params(RuntimeOrigin::root(), Some(params)));
+
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(11), 500));
+		let ledger = Ledger::<Test>::get(&11).unwrap();
+		let unlock_era = ledger.unlocking[0].era;
+		assert!(unlock_era < 1 + BondingDuration::get());
+		assert_eq!(ErasUnbondingMinDuration::<Test>::get(1), Some(unlock_era - 1));
+		assert_eq!(Staking::earliest_unlock_era(1), unlock_era);
+		// no chunk was unbonded in era 2 or later yet.
+		assert_eq!(Staking::earliest_unlock_era(2), 2 + BondingDuration::get());
+
+		// a slash of era 1 reaches the chunk unbonded in era 1, even though it unlocks before
+		// `1 + BondingDuration`.
+		let mut slashed_ledger = ledger.clone();
+		assert_eq!(slashed_ledger.slash(100, 0, 1), 100);
+		assert_eq!(slashed_ledger.active, 450);
+		assert_eq!(
+			slashed_ledger.unlocking.into_inner(),
+			vec![UnlockChunk { value: 450, era: unlock_era }]
+		);
+
+		// the rebonded stake no longer counts towards the demand of the era.
+		assert_eq!(ErasUnbondingTotal::<Test>::get(1), 500);
+		assert_ok!(Staking::rebond(RuntimeOrigin::signed(11), 200));
+		assert_eq!(ErasUnbondingTotal::<Test>::get(1), 300);
+
+		// stake unbonded in a previous era does not lower the demand of the current era.
+		mock::start_active_era(2);
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(21), 100));
+		assert_eq!(ErasUnbondingTotal::<Test>::get(2), 100);
+		assert_ok!(Staking::rebond(RuntimeOrigin::signed(11), 300));
+		assert_eq!(ErasUnbondingTotal::<Test>::get(2), 100);
+	});
+}
+
+#[test]
+fn set_unbonding_queue_params_checks_bounds() {
+	ExtBuilder::default().build_and_execute(|| {
+		let params = |min_duration| UnbondingQueueConfig {
+			min_duration,
+			max_exit_ratio: Perbill::from_percent(10),
+		};
+
+		assert_noop!(
+			Staking::set_unbonding_queue_params(RuntimeOrigin::signed(11), Some(params(1))),
+			BadOrigin
+		);
+		// must not exceed `BondingDuration`.
+		assert_noop!(
+			Staking::set_unbonding_queue_params(RuntimeOrigin::root(), Some(params(4))),
+			Error::<Test>::InvalidUnbondingQueueParams
+		);
+		// must exceed `SlashDeferDuration`.
+		SlashDeferDuration::set(1);
+		assert_noop!(
+			Staking::set_unbonding_queue_params(RuntimeOrigin::root(), Some(params(1))),
+			Error::<Test>::InvalidUnbondingQueueParams
+		);
+		assert_ok!(Staking::set_unbonding_queue_params(RuntimeOrigin::root(), Some(params(2))));
+		assert_eq!(UnbondingQueueParams::<Test>::get(), Some(params(2)));
+	});
+}
+
+#[test]
+fn min_validator_self_stake_is_enforced() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(Staking::set_staking_configs(
+			RuntimeOrigin::root(),
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Noop,
+			ConfigOp::Set(600)
+		));
+
+		// 31 only has 500 bonded, so it cannot (re-)declare its intention to validate.
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(31), ValidatorPrefs::default()),
+			Error::<Test>::InsufficientSelfStake
+		);
+		// and 11 cannot unbond below the floor.
+		assert_noop!(
+			Staking::unbond(RuntimeOrigin::signed(11), 500),
+			Error::<Test>::InsufficientBond
+		);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(41), ValidatorPrefs::default()));
+
+		// 31 is left out of the next election snapshot, but only chilled when the era starts.
+		assert!(Validators::<Test>::contains_key(31));
+		assert!(!Staking::electable_targets(DataProviderBounds::default()).unwrap().contains(&31));
+		assert!(ValidatorsBelowMinSelfStake::<Test>::contains_key(31));
+		assert!(Validators::<Test>::contains_key(31));
+
+		mock::start_active_era(1);
+		assert!(!Validators::<Test>::contains_key(31));
+		assert_eq!(ValidatorsBelowMinSelfStake::<Test>::count(), 0);
+		assert!(staking_events_since_last_call().contains(&Event::Chilled { stash: 31 }));
+		assert!(Validators::<Test>::contains_key(11));
+		assert!(Validators::<Test>::contains_key(41));
+	});
+}
+
+#[test]
+fn candidacy_queue_activates_candidates_above_thresholds() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let params = CandidacyQueueConfig {
+			self_stake_threshold: Perbill::from_percent(80),
+			approval_threshold: Perbill::from_percent(80),
+		};
+		assert_noop!(
+			Staking::set_candidacy_queue_params(RuntimeOrigin::signed(11), Some(params)),
+			BadOrigin
+		);
+		assert_ok!(Staking::set_candidacy_queue_params(RuntimeOrigin::root(), Some(params)));
+
+		// the smallest own stake of the elected validators is 1000, their smallest total backing
+		// 1125 (11).
+		assert_eq!(Staking::candidacy_thresholds(active_era()), (800, 900));
+
+		bond(61, 1000);
+		bond(71, 850);
+		bond(91, 850);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(61), ValidatorPrefs::default()));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(71), ValidatorPrefs::default()));
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(91), ValidatorPrefs::default()));
+		// existing validators are not queued.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(11), ValidatorPrefs::default()));
+		assert_eq!(
+			staking_events_since_last_call(),
+			vec![
+				Event::Bonded { stash: 61, amount: 1000 },
+				Event::Bonded { stash: 71, amount: 850 },
+				Event::Bonded { stash: 91, amount: 850 },
+				Event::CandidacyQueued { stash: 61, prefs: ValidatorPrefs::default() },
+				Event::CandidacyQueued { stash: 71, prefs: ValidatorPrefs::default() },
+				Event::CandidacyQueued { stash: 91, prefs: ValidatorPrefs::default() },
+				Event::ValidatorPrefsSet { stash: 11, prefs: ValidatorPrefs::default() },
+			]
+		);
+		assert!(!Validators::<Test>::contains_key(61));
+		assert_eq!(PendingCandidates::<Test>::count(), 3);
+
+		// the queue is bounded.
+		MaxPendingCandidates::set(3);
+		bond(81, 1000);
+		assert_noop!(
+			Staking::validate(RuntimeOrigin::signed(81), ValidatorPrefs::default()),
+			Error::<Test>::TooManyPendingCandidates
+		);
+
+		// 71 is backed by a nominator, its approvals are measured by the next election snapshot.
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(81), vec![71]));
+		let pending = |stash| {
+			Staking::api_pending_candidates()
+				.into_iter()
+				.find(|c| c.stash == stash)
+				.unwrap()
+		};
+		assert_eq!(
+			pending(71),
+			PendingCandidate {
+				stash: 71,
+				self_stake: 850,
+				approvals: 850,
+				meets_thresholds: false
+			}
+		);
+		assert_ok!(<Staking as ElectionDataProvider>::electing_voters(
+			DataProviderBounds::default()
+		));
+		assert_eq!(PendingCandidateApprovals::<Test>::get(71), Some(1000));
+		assert_eq!(PendingCandidateApprovals::<Test>::get(91), Some(0));
+		assert_eq!(
+			pending(71),
+			PendingCandidate {
+				stash: 71,
+				self_stake: 850,
+				approvals: 1850,
+				meets_thresholds: true
+			}
+		);
+		assert_eq!(
+			pending(91),
+			PendingCandidate {
+				stash: 91,
+				self_stake: 850,
+				approvals: 850,
+				meets_thresholds: false
+			}
+		);
+
+		mock::start_active_era(2);
+		assert!(Validators::<Test>::contains_key(61));
+		assert!(Validators::<Test>::contains_key(71));
+		assert!(!Validators::<Test>::contains_key(91));
+		assert_eq!(PendingCandidates::<Test>::count(), 0);
+		assert_eq!(PendingCandidateApprovals::<Test>::iter().count(), 0);
+		let events = staking_events_since_last_call();
+		assert!(events.contains(&Event::CandidacyActivated { stash: 61 }));
+		assert!(events.contains(&Event::CandidacyActivated { stash: 71 }));
+		assert!(events.contains(&Event::CandidacyRejected {
+			stash: 91,
+			self_stake: 850,
+			approvals: 850
+		}));
+	});
+}
+
+#[test]
+fn candidacy_queue_waits_for_the_approvals_to_be_measured() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let params = CandidacyQueueConfig {
+			self_stake_threshold: Perbill::from_percent(80),
+			approval_threshold: Perbill::from_percent(80),
+		};
+		assert_ok!(Staking::set_candidacy_queue_params(RuntimeOrigin::root(), Some(params)));
+
+		// queued after the election snapshot of era 2 was taken.
+		start_session(5);
+		bond(61, 1000);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(61), ValidatorPrefs::default()));
+		mock::start_active_era(2);
+		assert!(PendingCandidates::<Test>::contains_key(61));
+
+		// it is processed once the snapshot of era 3 measured its approvals.
+		mock::start_active_era(3);
+		assert!(Validators::<Test>::contains_key(61));
+		assert!(!PendingCandidates::<Test>::contains_key(61));
+	});
+}
+
+#[test]
+fn candidacy_queue_is_left_when_chilling_or_disabled() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::start_active_era(1);
+		let params = CandidacyQueueConfig {
+			self_stake_threshold: Perbill::from_percent(100),
+			approval_threshold: Perbill::from_percent(100),
+		};
+		assert_ok!(Staking::set_candidacy_queue_params(RuntimeOrigin::root(), Some(params)));
+
+		bond(61, 500);
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(61), ValidatorPrefs::default()));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(61)));
+		assert!(!PendingCandidates::<Test>::contains_key(61));
+		assert_eq!(*staking_events().last().unwrap(), Event::Chilled { stash: 61 });
+
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(61), ValidatorPrefs::default()));
+		assert_ok!(Staking::nominate(RuntimeOrigin::signed(61), vec![11]));
+		assert!(!PendingCandidates::<Test>::contains_key(61));
+
+		// the queued candidates of a disabled queue are activated without thresholds.
+		assert_ok!(Staking::validate(RuntimeOrigin::signed(61), ValidatorPrefs::default()));
+		assert!(!Nominators::<Test>::contains_key(61));
+		assert_ok!(Staking::set_candidacy_queue_params(RuntimeOrigin::root(), None));
+		mock::start_active_era(2);
+		assert!(Validators::<Test>::contains_key(61));
+	});
+}
+
+#[test]
+fn remote_reward_destination_works() {
+	ExtBuilder::default().nominate(false).build_and_execute(|| {
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Remote(1337)));
+
+		let total_payout_0 = current_total_payout_for_duration(reward_time_per_era());
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		let info = Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0).unwrap();
+		// the remote transfer is charged on top of the payout.
+		assert_eq!(
+			info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::payout_stakers_alive_staked(0) +
+					RemotePayoutWeight::get()
+			)
+		);
+
+		// the reward is paid into the stash, and then transferred to the remote account.
+		assert_eq!(RemotePayouts::get(), vec![(11, 1337, total_payout_0)]);
+		assert_eq!(asset::stakeable_balance::<Test>(&11), 1000);
+		assert!(staking_events().contains(&Event::Rewarded {
+			stash: 11,
+			dest: RewardDestination::Remote(1337),
+			amount: total_payout_0
+		}));
+
+		// if the transfer fails, the reward stays in the stash.
+		RemotePayoutFails::set(true);
+		let total_payout_1 = current_total_payout_for_duration(reward_time_per_era());
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		mock::make_all_reward_payment(1);
+
+		assert_eq!(RemotePayouts::get().len(), 1);
+		assert_eq!(asset::stakeable_balance::<Test>(&11), 1000 + total_payout_1);
+		assert!(staking_events().contains(&Event::RemotePayoutFailed {
+			stash: 11,
+			amount: total_payout_1,
+			error: DispatchError::Other("remote payout failed")
+		}));
+	});
+}
+
+#[test]
+fn remote_validator_payout_is_weighed() {
+	ExtBuilder::default().build_and_execute(|| {
+		// only the validator is paid out remotely, its nominator is not.
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(11), RewardDestination::Remote(1337)));
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+		let info = Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 0).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::payout_stakers_alive_staked(1) +
+					RemotePayoutWeight::get()
+			)
+		);
+		assert_eq!(
+			RemotePayouts::get().iter().map(|(s, b, _)| (*s, *b)).collect::<Vec<_>>(),
+			vec![(11, 1337)]
+		);
+
+		// once the nominator is paid out remotely as well, both transfers are charged.
+		assert_ok!(Staking::set_payee(RuntimeOrigin::signed(101), RewardDestination::Remote(1338)));
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+		let info = Staking::payout_stakers(RuntimeOrigin::signed(1337), 11, 1).unwrap();
+		assert_eq!(
+			info.actual_weight,
+			Some(
+				<Test as Config>::WeightInfo::payout_stakers_alive_staked(1) +
+					RemotePayoutWeight::get() * 2
+			)
+		);
+		assert_eq!(
+			RemotePayouts::get().iter().map(|(s, b, _)| (*s, *b)).collect::<Vec<_>>(),
+			vec![(11, 1337), (11, 1337), (101, 1338)]
+		);
+	});
+}
+
+#[test]
+fn exposure_commitment_mode_works() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			Staking::set_exposure_storage(RuntimeOrigin::signed(11), ExposureStorage::Commitment),
+			BadOrigin
+		);
+		assert_ok!(Staking::set_exposure_storage(
+			RuntimeOrigin::root(),
+			ExposureStorage::Commitment
+		));
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(1);
+
+		// only the hashes of the pages are stored, the pages are handed to the keeper.
+		assert_eq!(ErasStakersPaged::<Test>::iter_prefix_values((1,)).count(), 0);
+		assert!(ErasStakersOverview::<Test>::contains_key(1, 11));
+		let kept = KeptExposures::get();
+		assert_eq!(kept.len(), ErasStakersPagedCommitment::<Test>::iter_prefix((1,)).count());
+		let (_, _, page, exposure_page) = kept
+			.into_iter()
+			.find(|(era, validator, ..)| *era == 1 && *validator == 11)
+			.unwrap();
+		assert_eq!(
+			ErasStakersPagedCommitment::<Test>::get((1, 11, page)),
+			Some(<Test as frame_system::Config>::Hashing::hash_of(&exposure_page))
+		);
+
+		// the exposure checks and the slashes read the pages back from the keeper.
+		assert!(exposure_page.others.iter().any(|i| i.who == 101));
+		assert!(<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&101, &1));
+		assert!(!<Staking as sp_staking::StakingInterface>::is_exposed_in_era(&1337, &1));
+		let full_exposure = EraInfo::<Test>::get_full_exposure(1, &11);
+		assert_eq!(full_exposure.others, exposure_page.others);
+		assert_eq!(Staking::eras_stakers(1, &11), full_exposure);
+
+		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
+		mock::start_active_era(2);
+
+		// the page must be provided, and must match its hash.
+		assert_noop!(
+			Staking::payout_stakers_by_page(RuntimeOrigin::signed(1337), 11, 1, page),
+			Error::<Test>::MissingExposurePage
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+		let mut invalid_page = exposure_page.clone();
+		invalid_page.page_total += 1;
+		assert_noop!(
+			Staking::payout_stakers_with_exposure(
+				RuntimeOrigin::signed(1337),
+				11,
+				1,
+				page,
+				invalid_page
+			),
+			Error::<Test>::InvalidExposurePage
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+		// the pages of the eras before the commitment mode are not accepted.
+		assert_noop!(
+			Staking::payout_stakers_with_exposure(
+				RuntimeOrigin::signed(1337),
+				11,
+				0,
+				page,
+				exposure_page.clone()
+			),
+			Error::<Test>::InvalidExposurePage
+				.with_weight(<Test as Config>::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		let stash_balance = asset::stakeable_balance::<Test>(&11);
+		assert_ok!(Staking::payout_stakers_with_exposure(
+			RuntimeOrigin::signed(1337),
+			11,
+			1,
+			page,
+			exposure_page
+		));
+		assert!(asset::stakeable_balance::<Test>(&11) > stash_balance);
+		assert!(ClaimedRewards::<Test>::get(1, 11).contains(&page));
+
+		// back to the full mode, the pages are stored again.
+		assert_ok!(Staking::set_exposure_storage(RuntimeOrigin::root(), ExposureStorage::Full));
+		mock::start_active_era(3);
+		assert!(ErasStakersPaged::<Test>::iter_prefix_values((3,)).count() > 0);
+		assert_eq!(ErasStakersPagedCommitment::<Test>::iter_prefix((3,)).count(), 0);
+	});
+}
+
 mod sorted_list_provider {
 	use super::*;
 	use frame_election_provider_support::SortedListProvider;
@@ -7085,7 +7902,7 @@ mod staking_interface {
 
 				// empty stash has been reaped
 				assert!(!<Ledger<Test>>::contains_key(&11));
-				assert!(!<Bonded<Test>>::contains_key(&11));
+				assert!(!StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 				assert!(!<Validators<Test>>::contains_key(&11));
 				assert!(!<Payee<Test>>::contains_key(&11));
 				// lock is removed.
@@ -7451,7 +8268,8 @@ mod ledger {
 				RewardDestination::Account(10)
 			));
 
-			assert_eq!(<Bonded<Test>>::get(&10), Some(10));
+			// the stash is its own controller, without a `Bonded` entry.
+			assert_eq!(<Bonded<Test>>::get(&10), None);
 			assert_eq!(
 				StakingLedger::<Test>::paired_account(StakingAccount::Controller(10)),
 				Some(10)
@@ -7484,7 +8302,7 @@ mod ledger {
 			assert!(StakingLedger::<Test>::get(StakingAccount::Stash(42)).is_err());
 
 			// bonded and paired
-			assert_eq!(<Bonded<Test>>::get(&11), Some(11));
+			assert_eq!(Staking::bonded(&11), Some(11));
 
 			match StakingLedger::<Test>::get(StakingAccount::Stash(11)) {
 				Ok(ledger) => {
@@ -7574,7 +8392,8 @@ mod ledger {
 
 			assert_ok!(ledger.clone().bond(reward_dest));
 			assert!(StakingLedger::<Test>::is_bonded(StakingAccount::Stash(42)));
-			assert!(<Bonded<Test>>::get(&42).is_some());
+			assert_eq!(Staking::bonded(&42), Some(42));
+			assert!(<Bonded<Test>>::get(&42).is_none());
 			assert_eq!(<Payee<Test>>::get(&42), Some(reward_dest));
 
 			// cannot bond again.
@@ -7618,7 +8437,7 @@ mod ledger {
 			<Bonded<Test>>::insert(42, 42);
 			assert!(!StakingLedger::<Test>::is_bonded(StakingAccount::Controller(42)));
 
-			assert_eq!(<Bonded<Test>>::get(&11), Some(11));
+			assert_eq!(Staking::bonded(&11), Some(11));
 			assert!(StakingLedger::<Test>::is_bonded(StakingAccount::Stash(11)));
 			assert!(StakingLedger::<Test>::is_bonded(StakingAccount::Controller(11)));
 
@@ -7720,8 +8539,9 @@ mod ledger {
 
 				// Ledger no longer keyed by controller.
 				assert_eq!(Ledger::<Test>::get(ctlr), None);
-				// Bonded now maps to the stash.
-				assert_eq!(Bonded::<Test>::get(stash), Some(stash));
+				// The stash is now its own controller, without a `Bonded` entry.
+				assert_eq!(Bonded::<Test>::get(stash), None);
+				assert_eq!(Staking::bonded(&stash), Some(stash));
 
 				// Ledger is now keyed by stash.
 				let ledger_updated = Ledger::<Test>::get(stash).unwrap();
@@ -7782,8 +8602,8 @@ mod ledger {
 				if unique_pair {
 					assert_eq!(Ledger::<Test>::get(ctlr), None);
 				}
-				// Bonded maps to the stash.
-				assert_eq!(Bonded::<Test>::get(stash), Some(stash));
+				// The stash is bonded to itself.
+				assert_eq!(Staking::bonded(&stash), Some(stash));
 
 				// Ledger is keyed by stash.
 				let ledger_updated = Ledger::<Test>::get(stash).unwrap();
@@ -8745,6 +9565,65 @@ mod migration_tests {
 			assert!(post_upgrade_result.is_ok());
 		});
 	}
+
+	#[test]
+	fn lazy_deprecate_controllers_works() {
+		use frame_support::{migrations::SteppedMigration, weights::WeightMeter};
+		use migrations::deprecate_controllers::LazyDeprecateControllers;
+
+		ExtBuilder::default().try_state(false).build_and_execute(|| {
+			// given three stashes with distinct controllers, one of them paying its controller.
+			for (stash, ctlr) in [(1001, 2001), (1002, 2002), (1003, 2003)] {
+				Ledger::<Test>::insert(
+					ctlr,
+					StakingLedger { controller: None, ..StakingLedger::default_from(stash) },
+				);
+				Bonded::<Test>::insert(stash, ctlr);
+				Payee::<Test>::insert(stash, RewardDestination::Staked);
+			}
+			#[allow(deprecated)]
+			Payee::<Test>::insert(1002, RewardDestination::Controller);
+			// and a stash bonded to itself before the controller deprecation.
+			Bonded::<Test>::insert(11, 11);
+			StorageVersion::new(16).put::<Pallet<Test>>();
+			let _ = staking_events_since_last_call();
+
+			// when stepping with room for a single deprecation at a time.
+			let step_weight = <Test as Config>::WeightInfo::deprecate_controller_batch(1);
+			let mut cursor = None;
+			let mut steps = 0;
+			loop {
+				let mut meter = WeightMeter::with_limit(step_weight);
+				cursor = LazyDeprecateControllers::<Test>::step(cursor, &mut meter).unwrap();
+				steps += 1;
+				if cursor.is_none() {
+					break
+				}
+			}
+
+			// then every pair was migrated over several steps.
+			assert!(steps > 3);
+			for (stash, ctlr) in [(1001, 2001), (1002, 2002), (1003, 2003)] {
+				assert_eq!(Ledger::<Test>::get(ctlr), None);
+				assert_eq!(Staking::bonded(&stash), Some(stash));
+				assert_eq!(Ledger::<Test>::get(stash).unwrap().stash, stash);
+			}
+			// the `Bonded` indirection is gone and the storage version is bumped.
+			assert_eq!(Bonded::<Test>::iter().count(), 0);
+			assert_eq!(Staking::bonded(&11), Some(11));
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(17));
+
+			// and the migration is a no-op once done.
+			let mut meter = WeightMeter::with_limit(step_weight);
+			assert_eq!(LazyDeprecateControllers::<Test>::step(None, &mut meter).unwrap(), None);
+			assert_eq!(meter.consumed(), Weight::zero());
+			// and rewards still go to the former controller.
+			assert_eq!(Payee::<Test>::get(1002), Some(RewardDestination::Account(2002)));
+			assert!(staking_events_since_last_call()
+				.iter()
+				.all(|e| *e == Event::ControllerBatchDeprecated { failures: 0 }));
+		});
+	}
 }
 
 mod getters {
@@ -9081,3 +9960,122 @@ mod getters {
 		});
 	}
 }
+
+mod boost_staking_priority {
+	use super::*;
+	use sp_runtime::traits::DispatchTransaction;
+
+	frame_support::parameter_types! {
+		pub const Boost: u64 = 1_000;
+	}
+
+	fn priority_of(origin: RuntimeOrigin, call: RuntimeCall) -> u64 {
+		let info = call.get_dispatch_info();
+		BoostStakingPriority::<Test, Boost>::new()
+			.validate_only(origin, &call, &info, 0, TransactionSource::External, 0)
+			.unwrap()
+			.0
+			.priority
+	}
+
+	#[test]
+	fn boosts_operational_staking_calls() {
+		ExtBuilder::default().build_and_execute(|| {
+			CurrentEra::<Test>::put(100);
+			let validate: RuntimeCall = Call::validate { prefs: ValidatorPrefs::default() }.into();
+			let chill: RuntimeCall = Call::chill {}.into();
+
+			assert_eq!(priority_of(RuntimeOrigin::signed(11), validate.clone()), 1_000);
+			assert_eq!(priority_of(RuntimeOrigin::signed(11), chill.clone()), 1_000);
+			// accounts that are not stakers are not boosted.
+			assert_eq!(priority_of(RuntimeOrigin::signed(1337), validate.clone()), 0);
+			assert_eq!(priority_of(RuntimeOrigin::signed(1337), chill), 0);
+			// unsigned origins are never boosted.
+			assert_eq!(priority_of(RuntimeOrigin::none(), validate), 0);
+		});
+	}
+
+	#[test]
+	fn boosts_only_expiring_payouts() {
+		ExtBuilder::default().build_and_execute(|| {
+			CurrentEra::<Test>::put(100);
+			let payout = |era| Call::payout_stakers { validator_stash: 11, era }.into();
+
+			// era 20 is the oldest era still within `HistoryDepth`.
+			assert_eq!(priority_of(RuntimeOrigin::signed(101), payout(20)), 1_000);
+			assert_eq!(priority_of(RuntimeOrigin::signed(101), payout(21)), 0);
+			assert_eq!(
+				priority_of(
+					RuntimeOrigin::signed(101),
+					Call::payout_stakers_by_page { validator_stash: 11, era: 20, page: 0 }.into()
+				),
+				1_000
+			);
+			// only stakers are boosted.
+			assert_eq!(priority_of(RuntimeOrigin::signed(1), payout(20)), 0);
+
+			// other calls are left alone.
+			let remark: RuntimeCall = frame_system::Call::remark { remark: vec![] }.into();
+			assert_eq!(priority_of(RuntimeOrigin::signed(101), remark), 0);
+		});
+	}
+}
+
+#[test]
+fn newly_bonded_stake_counts_after_activation_delay() {
+	ExtBuilder::default().build_and_execute(|| {
+		BondActivationDelay::set(2);
+		let voter_weight = |who: AccountId| {
+			Staking::get_npos_voters(DataProviderBounds::default())
+				.into_iter()
+				.find(|(voter, _, _)| *voter == who)
+				.map(|(_, weight, _)| weight)
+		};
+		assert_eq!(current_era(), 0);
+
+		// the stake of a new nominator does not count yet, so it is left out of the snapshot.
+		bond_nominator(61, 500, vec![11]);
+		assert_eq!(Staking::slashable_balance_of(&61), 500);
+		assert_eq!(Staking::activated_balance_of(&61), 0);
+		assert_eq!(voter_weight(61), None);
+		assert_eq!(PendingActivation::<Test>::get(&61).into_inner(), vec![(2, 500)]);
+		// nor does the stake bonded by an existing stash.
+		asset::set_stakeable_balance::<Test>(&11, 2000);
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(11), 500));
+		assert_eq!(Staking::activated_balance_of(&11), 1000);
+
+		start_active_era(1);
+		asset::set_stakeable_balance::<Test>(&61, 1000);
+		assert_ok!(Staking::bond_extra(RuntimeOrigin::signed(61), 100));
+		assert_eq!(PendingActivation::<Test>::get(&61).into_inner(), vec![(2, 500), (3, 100)]);
+		assert_eq!(Staking::activated_balance_of(&61), 0);
+
+		// the first bond counts from era 2 on.
+		start_active_era(2);
+		assert_eq!(Staking::activated_balance_of(&61), 500);
+		assert_eq!(voter_weight(61), Some(500));
+		assert_eq!(Staking::activated_balance_of(&11), 1500);
+
+		// unbonded stake is taken from the stake that counts.
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 200));
+		assert_eq!(Staking::activated_balance_of(&61), 300);
+
+		start_active_era(3);
+		assert_eq!(Staking::activated_balance_of(&61), 400);
+		assert_eq!(voter_weight(61), Some(400));
+	});
+}
+
+#[test]
+fn pending_activation_is_removed_with_stash() {
+	ExtBuilder::default().build_and_execute(|| {
+		BondActivationDelay::set(2);
+		bond_nominator(61, 500, vec![11]);
+		assert!(PendingActivation::<Test>::contains_key(&61));
+		assert_ok!(Staking::chill(RuntimeOrigin::signed(61)));
+		assert_ok!(Staking::unbond(RuntimeOrigin::signed(61), 500));
+		start_active_era(4);
+		assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(61), 0));
+		assert!(!PendingActivation::<Test>::contains_key(&61));
+	});
+}
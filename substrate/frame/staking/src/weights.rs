@@ -83,6 +83,9 @@ pub trait WeightInfo {
 	fn force_apply_min_commission() -> Weight;
 	fn set_min_commission() -> Weight;
 	fn restore_ledger() -> Weight;
+	fn set_exposure_storage() -> Weight;
+	fn set_unbonding_queue_params() -> Weight;
+	fn set_candidacy_queue_params() -> Weight;
 }
 
 /// Weights for `pallet_staking` using the Substrate node and recommended hardware.
@@ -834,6 +837,33 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Staking::ExposureStorageMode` (r:0 w:1)
+	/// Proof: `Staking::ExposureStorageMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_exposure_storage` benchmark is run.
+	fn set_exposure_storage() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::UnbondingQueueParams` (r:0 w:1)
+	/// Proof: `Staking::UnbondingQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_unbonding_queue_params` benchmark is run.
+	fn set_unbonding_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::CandidacyQueueParams` (r:0 w:1)
+	/// Proof: `Staking::CandidacyQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_candidacy_queue_params` benchmark is run.
+	fn set_candidacy_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1584,4 +1614,31 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Staking::ExposureStorageMode` (r:0 w:1)
+	/// Proof: `Staking::ExposureStorageMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_exposure_storage` benchmark is run.
+	fn set_exposure_storage() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::UnbondingQueueParams` (r:0 w:1)
+	/// Proof: `Staking::UnbondingQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_unbonding_queue_params` benchmark is run.
+	fn set_unbonding_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Staking::CandidacyQueueParams` (r:0 w:1)
+	/// Proof: `Staking::CandidacyQueueParams` (`max_values`: Some(1), `max_size`: Some(9), added: 504, mode: `MaxEncodedLen`)
+	///
+	/// Not benchmarked yet: a hand-written placeholder, the same as `set_min_commission` which
+	/// also writes a single small value, until the `set_candidacy_queue_params` benchmark is run.
+	fn set_candidacy_queue_params() -> Weight {
+		Weight::from_parts(3_442_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -17,8 +17,11 @@
 
 use crate::{
 	pallet::{
-		expand::warnings::{weight_constant_warning, weight_witness_warning},
-		parse::{call::CallWeightDef, helper::CallReturnType},
+		expand::warnings::{contains_ident, weight_constant_warning, weight_witness_warning},
+		parse::{
+			call::{CallVariantDef, CallWeightDef},
+			helper::CallReturnType,
+		},
 		Def,
 	},
 	COUNTER,
@@ -84,15 +87,23 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 	}
 
 	let mut fn_weight = Vec::<TokenStream2>::new();
+	// How the weight of each call is computed, for metadata.
+	let mut fn_weight_metadata = Vec::<TokenStream2>::new();
+	let metadata_ir = quote!(#frame_support::__private::metadata_ir);
 	let mut weight_warnings = Vec::new();
 	for method in &methods {
 		match &method.weight {
-			CallWeightDef::DevModeDefault => fn_weight.push(syn::parse_quote!(0)),
+			CallWeightDef::DevModeDefault => {
+				fn_weight.push(syn::parse_quote!(0));
+				fn_weight_metadata
+					.push(quote!(#metadata_ir::CallWeightIR::Fixed { ref_time: 0, proof_size: 0 }));
+			},
 			CallWeightDef::Immediate(e) => {
 				weight_constant_warning(e, def.dev_mode, &mut weight_warnings);
 				weight_witness_warning(method, def.dev_mode, &mut weight_warnings);
 
 				fn_weight.push(e.into_token_stream());
+				fn_weight_metadata.push(weight_metadata(e, method, frame_support));
 			},
 			CallWeightDef::Inherited => {
 				let pallet_weight = def
@@ -107,11 +118,27 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				let t = &pallet_weight.typename;
 				let n = &method.name;
 				fn_weight.push(quote!({ < #t > :: #n ()	}));
+				let function = n.to_string();
+				fn_weight_metadata.push(quote!(#metadata_ir::CallWeightIR::Benchmarked {
+					function: #function,
+					components: #frame_support::__private::vec![],
+					adjusted: false,
+				}));
 			},
 		}
 	}
 	debug_assert_eq!(fn_weight.len(), methods.len());
 
+	// The declared storage access of each call, for metadata.
+	let fn_reads_metadata = methods
+		.iter()
+		.map(|method| method.storage_access.reads.iter().map(last_segment).collect())
+		.collect::<Vec<Vec<_>>>();
+	let fn_writes_metadata = methods
+		.iter()
+		.map(|method| method.storage_access.writes.iter().map(last_segment).collect())
+		.collect::<Vec<Vec<_>>>();
+
 	let fn_doc = methods.iter().map(|method| &method.docs).collect::<Vec<_>>();
 
 	let args_name = methods
@@ -263,6 +290,30 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		});
 
+	// Deprecated calls note their dispatch in `frame_system`, and their weight accounts for it.
+	let (deprecated_call_weight, deprecated_call_note): (Vec<_>, Vec<_>) = methods
+		.iter()
+		.map(|method| {
+			if method.deprecated_call.is_none() {
+				return (quote::quote!(), quote::quote!())
+			}
+			let fn_name = &method.name;
+			(
+				quote::quote!(.saturating_add(<
+					<T as #frame_system::Config>::DbWeight
+						as #frame_support::traits::Get<#frame_support::weights::RuntimeDbWeight>
+				>::get().reads_writes(1, 1))),
+				quote::quote!(
+					#frame_system::Pallet::<T>::note_deprecated_call(
+						<#pallet_ident<#type_use_gen> as #frame_support::traits::PalletInfoAccess>::name(),
+						stringify!(#fn_name),
+					);
+					#[allow(deprecated)]
+				),
+			)
+		})
+		.unzip();
+
 	let deprecation = match crate::deprecation::get_deprecation_enum(
 		&quote::quote! {#frame_support},
 		def.call.as_ref().map(|call| call.attrs.as_ref()).unwrap_or(&[]),
@@ -372,7 +423,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 							>::pays_fee(&__pallet_base_weight, ( #( #args_name, )* ));
 
 							#frame_support::dispatch::DispatchInfo {
-								call_weight: __pallet_weight,
+								call_weight: __pallet_weight #deprecated_call_weight,
 								extension_weight: Default::default(),
 								class: __pallet_class,
 								pays_fee: __pallet_pays_fee,
@@ -450,6 +501,7 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 								#frame_support::__private::sp_tracing::enter_span!(
 									#frame_support::__private::sp_tracing::trace_span!(stringify!(#fn_name))
 								);
+								#deprecated_call_note
 								#maybe_allow_attrs
 								<#pallet_ident<#type_use_gen>>::#fn_name(origin, #( #args_name, )* )
 									.map(Into::into).map_err(Into::into)
@@ -477,8 +529,145 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::__private::metadata_ir::PalletCallMetadataIR  {
 					ty: #frame_support::__private::scale_info::meta_type::<#call_ident<#type_use_gen>>(),
 					deprecation_info: #deprecation,
+					weights: #frame_support::__private::Vec::from([
+						#(
+							#cfg_attrs
+							#frame_support::__private::metadata_ir::CallWeightMetadataIR {
+								name: stringify!(#fn_name),
+								weight: #fn_weight_metadata,
+								reads: #frame_support::__private::vec![ #( #fn_reads_metadata ),* ],
+								writes: #frame_support::__private::vec![ #( #fn_writes_metadata ),* ],
+							},
+						)*
+					]),
 				}
 			}
 		}
 	)
 }
+
+/// The last segment of `path`, e.g. the name of a storage item or a function.
+fn last_segment(path: &syn::Path) -> String {
+	path.segments
+		.last()
+		.map(|segment| segment.ident.to_string())
+		.unwrap_or_default()
+}
+
+/// Expand the `CallWeightIR` describing the weight expression `weight` of `method`.
+///
+/// Integer literals and `Weight::from_parts` of integer literals are `Fixed`. The first call of a
+/// `WeightInfo` function makes the weight `Benchmarked`; it is `adjusted` if the weight is more
+/// than just that call. Anything else is `Custom`.
+fn weight_metadata(
+	weight: &syn::Expr,
+	method: &CallVariantDef,
+	frame_support: &syn::Path,
+) -> TokenStream2 {
+	let metadata_ir = quote!(#frame_support::__private::metadata_ir);
+	let vec = quote!(#frame_support::__private::vec);
+	let params_in = |expr: &syn::Expr| {
+		method
+			.args
+			.iter()
+			.filter(|(_, name, _)| contains_ident(expr.clone(), name))
+			.map(|(_, name, _)| name.to_string())
+			.collect::<Vec<_>>()
+	};
+
+	let weight = strip_expr(weight);
+	if let Some((ref_time, proof_size)) = fixed_weight(weight) {
+		return quote!(#metadata_ir::CallWeightIR::Fixed {
+			ref_time: #ref_time,
+			proof_size: #proof_size,
+		})
+	}
+
+	let mut finder = WeightInfoCall(None);
+	syn::visit::visit_expr(&mut finder, weight);
+	match finder.0 {
+		Some(call) => {
+			let function = match &*call.func {
+				syn::Expr::Path(path) => last_segment(&path.path),
+				_ => unreachable!("`WeightInfoCall` only finds calls of paths; qed"),
+			};
+			let components = call.args.iter().map(|arg| {
+				let params = params_in(arg);
+				quote!(#vec![ #( #params ),* ])
+			});
+			// The visitor finds the outermost call first.
+			let adjusted = !matches!(weight, syn::Expr::Call(c) if is_weight_info_call(c));
+			quote!(#metadata_ir::CallWeightIR::Benchmarked {
+				function: #function,
+				components: #vec![ #( #components ),* ],
+				adjusted: #adjusted,
+			})
+		},
+		None => {
+			let params = params_in(weight);
+			quote!(#metadata_ir::CallWeightIR::Custom { params: #vec![ #( #params ),* ] })
+		},
+	}
+}
+
+/// Strip braces and parentheses around a single expression.
+fn strip_expr(expr: &syn::Expr) -> &syn::Expr {
+	match expr {
+		syn::Expr::Paren(inner) => strip_expr(&inner.expr),
+		syn::Expr::Group(inner) => strip_expr(&inner.expr),
+		syn::Expr::Block(block) => match &block.block.stmts[..] {
+			[syn::Stmt::Expr(inner, None)] => strip_expr(inner),
+			_ => expr,
+		},
+		_ => expr,
+	}
+}
+
+/// The `ref_time` and `proof_size` of a constant weight.
+fn fixed_weight(expr: &syn::Expr) -> Option<(u64, u64)> {
+	let int = |expr: &syn::Expr| match strip_expr(expr) {
+		syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse().ok(),
+		_ => None,
+	};
+
+	match expr {
+		syn::Expr::Call(call) => {
+			let syn::Expr::Path(func) = &*call.func else { return None };
+			let args = call.args.iter().collect::<Vec<_>>();
+			let &[ref_time, proof_size] = &args[..] else { return None };
+			if last_segment(&func.path) != "from_parts" {
+				return None
+			}
+			Some((int(ref_time)?, int(proof_size)?))
+		},
+		_ => int(expr).map(|ref_time| (ref_time, 0)),
+	}
+}
+
+/// Finds the first call of a `WeightInfo` function, e.g. `T::WeightInfo::call(n)` or
+/// `<T as Config>::WeightInfo::call(n)`.
+struct WeightInfoCall(Option<syn::ExprCall>);
+
+impl<'a> syn::visit::Visit<'a> for WeightInfoCall {
+	fn visit_expr_call(&mut self, call: &'a syn::ExprCall) {
+		if self.0.is_some() {
+			return
+		}
+		if is_weight_info_call(call) {
+			self.0 = Some(call.clone());
+			return
+		}
+		syn::visit::visit_expr_call(self, call);
+	}
+}
+
+/// Whether `call` calls a function of a `WeightInfo`.
+fn is_weight_info_call(call: &syn::ExprCall) -> bool {
+	let syn::Expr::Path(func) = &*call.func else { return false };
+	let in_path = func.path.segments.iter().rev().skip(1).any(|s| s.ident == "WeightInfo");
+	let in_qself = func
+		.qself
+		.as_ref()
+		.map_or(false, |qself| qself.ty.to_token_stream().to_string().contains("WeightInfo"));
+	in_path || in_qself
+}
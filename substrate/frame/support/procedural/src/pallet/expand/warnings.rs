@@ -78,7 +78,7 @@ pub(crate) fn weight_constant_warning(
 }
 
 /// Returns whether `expr` contains `ident`.
-fn contains_ident(mut expr: syn::Expr, ident: &syn::Ident) -> bool {
+pub(crate) fn contains_ident(mut expr: syn::Expr, ident: &syn::Ident) -> bool {
 	struct ContainsIdent {
 		ident: syn::Ident,
 		found: bool,
@@ -33,6 +33,12 @@ mod keyword {
 	syn::custom_keyword!(T);
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(feeless_if);
+	syn::custom_keyword!(storage_access);
+	syn::custom_keyword!(reads);
+	syn::custom_keyword!(writes);
+	syn::custom_keyword!(deprecated_call);
+	syn::custom_keyword!(since);
+	syn::custom_keyword!(note);
 }
 
 /// Definition of dispatchables typically `impl<T: Config> Pallet<T> { ... }`
@@ -91,8 +97,12 @@ pub struct CallVariantDef {
 	pub cfg_attrs: Vec<syn::Attribute>,
 	/// The optional `feeless_if` attribute on the `pallet::call`.
 	pub feeless_check: Option<syn::ExprClosure>,
+	/// The storage items declared by the optional `storage_access` attribute, used for metadata.
+	pub storage_access: StorageAccessDef,
 	/// The return type of the call: `DispatchInfo` or `DispatchResultWithPostInfo`.
 	pub return_type: helper::CallReturnType,
+	/// The optional `deprecated_call` attribute on the dispatchable.
+	pub deprecated_call: Option<DeprecatedCallDef>,
 }
 
 /// Attributes for functions in call impl block.
@@ -103,6 +113,95 @@ pub enum FunctionAttr {
 	Weight(syn::Expr),
 	/// Parse for `#[pallet::feeless_if(expr)]`
 	FeelessIf(Span, syn::ExprClosure),
+	/// Parse for `#[pallet::storage_access(reads(path, ..), writes(path, ..))]`
+	StorageAccess(Span, StorageAccessDef),
+	/// Parse for `#[pallet::deprecated_call(since = "version", note = "reason")]`
+	DeprecatedCall(Span, DeprecatedCallDef),
+}
+
+/// The version and reason of the deprecation of a dispatchable.
+#[derive(Clone)]
+pub struct DeprecatedCallDef {
+	/// The version the dispatchable is deprecated since.
+	pub since: syn::LitStr,
+	/// Why the dispatchable is deprecated, and what to use instead.
+	pub note: syn::LitStr,
+}
+
+impl syn::parse::Parse for DeprecatedCallDef {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let (mut since, mut note) = (None, None);
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			let value = if lookahead.peek(keyword::since) {
+				input.parse::<keyword::since>()?;
+				&mut since
+			} else if lookahead.peek(keyword::note) {
+				input.parse::<keyword::note>()?;
+				&mut note
+			} else {
+				return Err(lookahead.error());
+			};
+			input.parse::<syn::Token![=]>()?;
+			let lit = input.parse::<syn::LitStr>()?;
+			if value.replace(lit).is_some() {
+				let msg = "Invalid deprecated_call attribute: `since` and `note` can only be \
+					given once";
+				return Err(syn::Error::new(input.span(), msg));
+			}
+			if !input.is_empty() {
+				input.parse::<syn::Token![,]>()?;
+			}
+		}
+		match (since, note) {
+			(Some(since), Some(note)) => Ok(DeprecatedCallDef { since, note }),
+			_ => {
+				let msg = "Invalid deprecated_call attribute: expected \
+					`deprecated_call(since = \"version\", note = \"reason\")`";
+				Err(syn::Error::new(input.span(), msg))
+			},
+		}
+	}
+}
+
+/// The storage items a dispatchable declares to read and write.
+#[derive(Clone, Default)]
+pub struct StorageAccessDef {
+	/// Storage items read by the dispatchable.
+	pub reads: Vec<syn::Path>,
+	/// Storage items written by the dispatchable.
+	pub writes: Vec<syn::Path>,
+}
+
+impl syn::parse::Parse for StorageAccessDef {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let mut def = StorageAccessDef::default();
+		let (mut has_reads, mut has_writes) = (false, false);
+		while !input.is_empty() {
+			let lookahead = input.lookahead1();
+			let (has, items) = if lookahead.peek(keyword::reads) {
+				input.parse::<keyword::reads>()?;
+				(&mut has_reads, &mut def.reads)
+			} else if lookahead.peek(keyword::writes) {
+				input.parse::<keyword::writes>()?;
+				(&mut has_writes, &mut def.writes)
+			} else {
+				return Err(lookahead.error());
+			};
+			if core::mem::replace(has, true) {
+				let msg = "Invalid storage_access attribute: `reads` and `writes` can only be \
+					given once";
+				return Err(syn::Error::new(input.span(), msg));
+			}
+			let content;
+			syn::parenthesized!(content in input);
+			items.extend(content.parse_terminated(syn::Path::parse_mod_style, syn::Token![,])?);
+			if !input.is_empty() {
+				input.parse::<syn::Token![,]>()?;
+			}
+		}
+		Ok(def)
+	}
 }
 
 impl syn::parse::Parse for FunctionAttr {
@@ -142,6 +241,19 @@ impl syn::parse::Parse for FunctionAttr {
 					err
 				})?,
 			))
+		} else if lookahead.peek(keyword::storage_access) {
+			content.parse::<keyword::storage_access>()?;
+			let access_content;
+			syn::parenthesized!(access_content in content);
+			Ok(FunctionAttr::StorageAccess(access_content.span(), access_content.parse()?))
+		} else if lookahead.peek(keyword::deprecated_call) {
+			content.parse::<keyword::deprecated_call>()?;
+			let deprecation_content;
+			syn::parenthesized!(deprecation_content in content);
+			Ok(FunctionAttr::DeprecatedCall(
+				deprecation_content.span(),
+				deprecation_content.parse()?,
+			))
 		} else {
 			Err(lookahead.error())
 		}
@@ -269,6 +381,8 @@ impl CallDef {
 				let mut call_idx_attrs = vec![];
 				let mut weight_attrs = vec![];
 				let mut feeless_attrs = vec![];
+				let mut storage_access_attrs = vec![];
+				let mut deprecated_call_attrs = vec![];
 				for attr in helper::take_item_pallet_attrs(&mut method.attrs)?.into_iter() {
 					match attr {
 						FunctionAttr::CallIndex(_) => {
@@ -280,6 +394,12 @@ impl CallDef {
 						FunctionAttr::FeelessIf(span, _) => {
 							feeless_attrs.push((span, attr));
 						},
+						FunctionAttr::StorageAccess(span, access) => {
+							storage_access_attrs.push((span, access));
+						},
+						FunctionAttr::DeprecatedCall(span, deprecation) => {
+							deprecated_call_attrs.push((span, deprecation));
+						},
 					}
 				}
 
@@ -435,6 +555,37 @@ impl CallDef {
 					}
 				}
 
+				if storage_access_attrs.len() > 1 {
+					let msg =
+						"Invalid pallet::call, there can only be one storage_access attribute";
+					return Err(syn::Error::new(storage_access_attrs[1].0, msg));
+				}
+				let storage_access =
+					storage_access_attrs.pop().map(|(_, access)| access).unwrap_or_default();
+
+				if deprecated_call_attrs.len() > 1 {
+					let msg =
+						"Invalid pallet::call, there can only be one deprecated_call attribute";
+					return Err(syn::Error::new(deprecated_call_attrs[1].0, msg));
+				}
+				let deprecated_call =
+					deprecated_call_attrs.pop().map(|(_, deprecation)| deprecation);
+				if let Some(DeprecatedCallDef { since, note }) = &deprecated_call {
+					if let Some(attr) =
+						method.attrs.iter().find(|a| a.path().is_ident("deprecated"))
+					{
+						let msg =
+							"Invalid pallet::call, `deprecated` and `pallet::deprecated_call` \
+							attributes cannot be used together";
+						return Err(syn::Error::new(attr.span(), msg));
+					}
+					// The function is deprecated too, which warns its callers and marks the call
+					// as deprecated in the metadata.
+					method
+						.attrs
+						.push(syn::parse_quote!(#[deprecated(since = #since, note = #note)]));
+				}
+
 				methods.push(CallVariantDef {
 					name: method.sig.ident.clone(),
 					weight,
@@ -445,7 +596,9 @@ impl CallDef {
 					attrs: method.attrs.clone(),
 					cfg_attrs,
 					feeless_check,
+					storage_access,
 					return_type,
+					deprecated_call,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
@@ -1902,6 +1902,96 @@ pub mod pallet_macros {
 	/// [`pallet_skip_feeless_payment::CheckIfFeeless`]: ../../pallet_skip_feeless_payment/struct.SkipCheckIfFeeless.html
 	pub use frame_support_procedural::feeless_if;
 
+	/// Declares the storage items a dispatchable reads and writes.
+	///
+	/// Each dispatchable may be annotated with the
+	/// `#[pallet::storage_access(reads(..), writes(..))]` attribute, listing the paths of the
+	/// storage items it accesses. Both `reads` and `writes` are optional.
+	///
+	/// ### Example
+	///
+	/// ```
+	/// #[frame_support::pallet(dev_mode)]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// # 	use frame_system::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[pallet::storage]
+	/// 	pub type Value<T> = StorageValue<_, u32>;
+	///
+	/// 	#[pallet::call]
+	/// 	impl<T: Config> Pallet<T> {
+	/// 		#[pallet::call_index(0)]
+	/// 		#[pallet::storage_access(reads(Value), writes(Value))]
+	/// 		pub fn increment(_: OriginFor<T>) -> DispatchResult {
+	/// 			Value::<T>::mutate(|v| *v = Some(v.unwrap_or_default() + 1));
+	/// 			Ok(())
+	/// 		}
+	/// 	}
+	/// #
+	/// # 	#[pallet::config]
+	/// # 	pub trait Config: frame_system::Config {}
+	/// }
+	/// ```
+	///
+	/// ### Macro expansion
+	///
+	/// The declared storage items are not checked against what the dispatchable actually
+	/// accesses. They are only exposed, together with how the weight of the dispatchable is
+	/// computed, by the `weights` of the `PalletCallMetadataIR` of the pallet, which end up in the
+	/// `call_weights` custom entry of the runtime metadata.
+	pub use frame_support_procedural::storage_access;
+
+	/// Deprecates a dispatchable, and tracks its usage on-chain.
+	///
+	/// Each dispatchable may be annotated with the
+	/// `#[pallet::deprecated_call(since = "version", note = "reason")]` attribute, in place of the
+	/// `#[deprecated]` attribute. Both `since` and `note` are required.
+	///
+	/// ### Example
+	///
+	/// ```
+	/// #[frame_support::pallet(dev_mode)]
+	/// mod pallet {
+	/// # 	use frame_support::pallet_prelude::*;
+	/// # 	use frame_system::pallet_prelude::*;
+	/// #
+	/// 	#[pallet::pallet]
+	/// 	pub struct Pallet<T>(_);
+	///
+	/// 	#[pallet::call]
+	/// 	impl<T: Config> Pallet<T> {
+	/// 		#[pallet::call_index(0)]
+	/// 		#[pallet::deprecated_call(since = "2.0.0", note = "use `new_call` instead")]
+	/// 		pub fn old_call(_: OriginFor<T>) -> DispatchResult {
+	/// 			Ok(())
+	/// 		}
+	///
+	/// 		#[pallet::call_index(1)]
+	/// 		pub fn new_call(_: OriginFor<T>) -> DispatchResult {
+	/// 			Ok(())
+	/// 		}
+	/// 	}
+	/// #
+	/// # 	#[pallet::config]
+	/// # 	pub trait Config: frame_system::Config {}
+	/// }
+	/// ```
+	///
+	/// ### Macro expansion
+	///
+	/// The function of the dispatchable is marked `#[deprecated(since = .., note = ..)]`, which
+	/// warns the code calling it directly and marks the call as deprecated in the metadata.
+	///
+	/// Dispatching the call notes it with `frame_system::Pallet::note_deprecated_call`, which
+	/// counts the dispatches of each deprecated call in the `DeprecatedCallUsage` storage of
+	/// `frame_system`. The weight of the dispatchable is increased by one read and one write
+	/// to account for it.
+	pub use frame_support_procedural::deprecated_call;
+
 	/// Allows defining an error enum that will be returned from the dispatchable when an error
 	/// occurs.
 	///
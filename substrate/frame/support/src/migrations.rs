@@ -24,14 +24,18 @@ use crate::{
 	},
 	weights::{RuntimeDbWeight, Weight, WeightMeter},
 };
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use impl_trait_for_tuples::impl_for_tuples;
-use sp_arithmetic::traits::Bounded;
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::traits::{
+	AtLeast32BitUnsigned, Bounded, UniqueSaturatedFrom, UniqueSaturatedInto,
+};
 use sp_core::Get;
 use sp_io::{hashing::twox_128, storage::clear_prefix, KillStorageResult};
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, RuntimeDebug};
 
 /// Handles storage migration pallet versioning.
 ///
@@ -482,6 +486,94 @@ impl<P: Get<&'static str>, S: Get<&'static str>, DbWeight: Get<RuntimeDbWeight>>
 	}
 }
 
+/// The key under which [`BlockTimeChange::mark_applied`] records the block time that the storage
+/// of a pallet was last converted to:
+///
+/// Twox128([`PalletInfo::name`]) ++ Twox128([`BLOCK_TIME_STORAGE_KEY_POSTFIX`])
+///
+/// [`PalletInfo::name`]: crate::traits::PalletInfo::name
+pub const BLOCK_TIME_STORAGE_KEY_POSTFIX: &[u8] = b":__BLOCK_TIME__:";
+
+/// A change of the block time of a chain, for example from 12s to 6s when enabling async backing.
+///
+/// Pallets use this in migrations that convert block-number based storage, so that it keeps the
+/// same meaning in wall-clock time after the change. All conversions round down.
+///
+/// Such a migration does not change the storage version of the pallet. Instead it should only run
+/// if [`Self::should_apply`] and call [`Self::mark_applied`] afterwards, so that it is skipped if
+/// it is left in the runtime for a later upgrade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockTimeChange {
+	/// The block time before the change, in milliseconds.
+	pub old: u64,
+	/// The block time after the change, in milliseconds.
+	pub new: u64,
+}
+
+impl BlockTimeChange {
+	/// Create the change from the block times returned by `Old` and `New`.
+	pub fn from_get<Old: Get<u64>, New: Get<u64>>() -> Self {
+		Self { old: Old::get(), new: New::get() }
+	}
+
+	/// Whether the change has no effect, either because the block time did not change or because
+	/// one of the block times is zero.
+	pub fn is_noop(&self) -> bool {
+		self.old == self.new || self.old.is_zero() || self.new.is_zero()
+	}
+
+	/// Whether the storage of the pallet `P` should be converted.
+	///
+	/// This is the case if the change is not a no-op and the storage was not converted before, or
+	/// was last converted to the old block time of this change.
+	pub fn should_apply<P: PalletInfoAccess>(&self) -> bool {
+		!self.is_noop() && Self::applied::<P>().map_or(true, |block_time| block_time == self.old)
+	}
+
+	/// The block time that the storage of the pallet `P` was last converted to, if any.
+	pub fn applied<P: PalletInfoAccess>() -> Option<u64> {
+		crate::storage::unhashed::get(&Self::storage_key::<P>())
+	}
+
+	/// Record that the storage of the pallet `P` was converted to the new block time.
+	pub fn mark_applied<P: PalletInfoAccess>(&self) {
+		crate::storage::unhashed::put(&Self::storage_key::<P>(), &self.new)
+	}
+
+	fn storage_key<P: PalletInfoAccess>() -> [u8; 32] {
+		storage_prefix(P::name().as_bytes(), BLOCK_TIME_STORAGE_KEY_POSTFIX)
+	}
+
+	/// Convert a number of blocks to the number of blocks that span the same time with the new
+	/// block time.
+	pub fn scale_blocks<N: AtLeast32BitUnsigned>(&self, blocks: N) -> N {
+		self.scale(blocks, self.old, self.new)
+	}
+
+	/// Convert an amount accrued per block to the amount that accrues in a block with the new
+	/// block time.
+	pub fn scale_per_block<B: AtLeast32BitUnsigned>(&self, amount: B) -> B {
+		self.scale(amount, self.new, self.old)
+	}
+
+	/// Convert the block number `block`, so that its distance to `now` spans the same time with
+	/// the new block time. Blocks that are not in the future are returned unchanged.
+	pub fn scale_future_block<N: AtLeast32BitUnsigned + Copy>(&self, now: N, block: N) -> N {
+		if block <= now {
+			return block
+		}
+		now.saturating_add(self.scale_blocks(block - now))
+	}
+
+	fn scale<N: AtLeast32BitUnsigned>(&self, value: N, numerator: u64, denominator: u64) -> N {
+		if self.is_noop() {
+			return value
+		}
+		let value: u128 = value.unique_saturated_into();
+		N::unique_saturated_from(value.saturating_mul(numerator as u128) / denominator as u128)
+	}
+}
+
 /// A migration that can proceed in multiple steps.
 pub trait SteppedMigration {
 	/// The cursor type that stores the progress (aka. state) of this migration.
@@ -1027,6 +1119,36 @@ impl SteppedMigrations for Tuple {
 	}
 }
 
+/// A pallet whose on-chain storage version was changed by a runtime upgrade.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedPallet {
+	/// The name of the pallet, as configured in the runtime.
+	pub name: String,
+	/// The on-chain storage version before the upgrade.
+	pub from: u16,
+	/// The on-chain storage version after the upgrade.
+	pub to: u16,
+}
+
+/// The report of a dry-run runtime upgrade.
+///
+/// Runtimes return it from the `UpgradeDryRun` runtime API of `frame-try-runtime`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeDryRunReport {
+	/// The weight consumed by all migrations.
+	pub weight: Weight,
+	/// The maximum weight of a block, to compare `weight` against.
+	pub max_block_weight: Weight,
+	/// The pallets whose on-chain storage version changed.
+	pub migrated: Vec<MigratedPallet>,
+	/// The result of the `pre_upgrade`, `post_upgrade` and `try_state` checks.
+	///
+	/// `None` if the runtime was built without the `try-runtime` feature.
+	pub checks: Option<Result<(), String>>,
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1204,4 +1326,23 @@ mod tests {
 			.is_err());
 		});
 	}
+
+	#[test]
+	fn block_time_change_works() {
+		let faster = BlockTimeChange { old: 12_000, new: 6_000 };
+		assert_eq!(faster.scale_blocks(10u32), 20);
+		assert_eq!(faster.scale_per_block(101u64), 50);
+		assert_eq!(faster.scale_future_block(100u32, 110), 120);
+		assert_eq!(faster.scale_future_block(100u32, 90), 90);
+
+		let slower = BlockTimeChange { old: 6_000, new: 12_000 };
+		assert_eq!(slower.scale_blocks(11u32), 5);
+		assert_eq!(slower.scale_per_block(50u64), 100);
+		assert_eq!(slower.scale_future_block(100u32, 110), 105);
+
+		let noop = BlockTimeChange { old: 6_000, new: 0 };
+		assert!(noop.is_noop());
+		assert_eq!(noop.scale_blocks(10u32), 10);
+		assert_eq!(faster.scale_blocks(u32::MAX), u32::MAX);
+	}
 }
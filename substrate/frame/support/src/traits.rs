@@ -77,9 +77,9 @@ pub use randomness::Randomness;
 
 mod metadata;
 pub use metadata::{
-	CallMetadata, CrateVersion, GetCallIndex, GetCallMetadata, GetCallName, GetStorageVersion,
-	NoStorageVersionSet, PalletInfo, PalletInfoAccess, PalletInfoData, PalletsInfoAccess,
-	StorageVersion, STORAGE_VERSION_STORAGE_KEY_POSTFIX,
+	CallMetadata, CrateVersion, DecodedCall, GetCallIndex, GetCallMetadata, GetCallName,
+	GetInnerCalls, GetStorageVersion, NoStorageVersionSet, PalletInfo, PalletInfoAccess,
+	PalletInfoData, PalletsInfoAccess, StorageVersion, STORAGE_VERSION_STORAGE_KEY_POSTFIX,
 };
 
 mod hooks;
@@ -123,7 +123,7 @@ pub use messages::{
 };
 
 mod safe_mode;
-pub use safe_mode::{SafeMode, SafeModeError, SafeModeNotify};
+pub use safe_mode::{SafeMode, SafeModeError, SafeModeNotify, SafeModeTrigger, SafeModeTriggerId};
 
 mod tx_pause;
 pub use tx_pause::{TransactionPause, TransactionPauseError};
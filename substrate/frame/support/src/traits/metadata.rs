@@ -17,10 +17,11 @@
 
 //! Traits for managing information attached to pallets and their constituents.
 
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use codec::{Decode, Encode};
 use core::ops::Add;
 use impl_trait_for_tuples::impl_for_tuples;
+use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
 /// Provides information about the pallet itself and its setup in the runtime.
@@ -130,6 +131,46 @@ pub trait GetCallMetadata {
 	fn get_call_metadata(&self) -> CallMetadata;
 }
 
+/// Gets the calls wrapped in the Call, e.g. the calls of a batch or the call of a proxy.
+pub trait GetInnerCalls<Call> {
+	/// Return the calls wrapped in this Call, in the order they would be dispatched.
+	fn get_inner_calls(&self) -> Vec<&Call>;
+}
+
+/// A Call decoded into a structure that can be shown to whoever is about to sign it.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DecodedCall {
+	/// Name of the pallet to which the function belongs.
+	pub pallet_name: String,
+	/// Name of the function.
+	pub function_name: String,
+	/// Index of the pallet in the runtime.
+	pub pallet_index: u8,
+	/// Index of the function in the pallet.
+	pub call_index: u8,
+	/// SCALE encoded arguments of the function, which can be decoded with the type of the Call in
+	/// the metadata.
+	pub args: Vec<u8>,
+	/// The calls wrapped in this Call, see [`GetInnerCalls`].
+	pub inner_calls: Vec<DecodedCall>,
+}
+
+impl DecodedCall {
+	/// Decode `call` and all the calls wrapped in it, recursively.
+	pub fn new<Call: Encode + GetCallMetadata + GetInnerCalls<Call>>(call: &Call) -> Self {
+		let CallMetadata { function_name, pallet_name } = call.get_call_metadata();
+		let encoded = call.encode();
+		Self {
+			pallet_name: pallet_name.into(),
+			function_name: function_name.into(),
+			pallet_index: encoded.first().copied().unwrap_or_default(),
+			call_index: encoded.get(1).copied().unwrap_or_default(),
+			args: encoded.get(2..).unwrap_or_default().to_vec(),
+			inner_calls: call.get_inner_calls().into_iter().map(Self::new).collect(),
+		}
+	}
+}
+
 /// The version of a crate.
 #[derive(Debug, Eq, PartialEq, Encode, Decode, Clone, Copy, Default)]
 pub struct CrateVersion {
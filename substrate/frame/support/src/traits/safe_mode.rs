@@ -61,6 +61,44 @@ pub enum SafeModeError {
 	Unknown,
 }
 
+/// Identifies a runtime component that automatically puts the runtime into safe mode.
+///
+/// For example `*b"bridge00"` for a bridge that detected an invalid finality proof.
+pub type SafeModeTriggerId = [u8; 8];
+
+/// Allows runtime components to automatically put the runtime into safe mode.
+///
+/// Unlike [`SafeMode`], the duration requested by a trigger is bounded by the implementation and
+/// a trigger can never shorten or exit an already entered safe mode.
+pub trait SafeModeTrigger<BlockNumber> {
+	/// Enter safe mode on behalf of `source` for at most `duration` blocks.
+	///
+	/// If safe mode is already entered, its exit is pushed back to cover `duration` but never
+	/// brought forward.
+	fn trigger(source: SafeModeTriggerId, duration: BlockNumber) -> Result<(), SafeModeError>;
+
+	/// Report a fault on behalf of `source`.
+	///
+	/// Safe mode is triggered for `duration` blocks once `source` reported `threshold` faults.
+	/// The fault counter of `source` is reset whenever it triggers safe mode. Implementations
+	/// may also forget faults after some time, so that only faults in quick succession count.
+	fn report_fault(
+		source: SafeModeTriggerId,
+		threshold: u32,
+		duration: BlockNumber,
+	) -> Result<(), SafeModeError>;
+}
+
+impl<BlockNumber> SafeModeTrigger<BlockNumber> for () {
+	fn trigger(_: SafeModeTriggerId, _: BlockNumber) -> Result<(), SafeModeError> {
+		Ok(())
+	}
+
+	fn report_fault(_: SafeModeTriggerId, _: u32, _: BlockNumber) -> Result<(), SafeModeError> {
+		Ok(())
+	}
+}
+
 /// A trait to notify when the runtime enters or exits safe mode.
 pub trait SafeModeNotify {
 	/// Called when the runtime enters safe mode.
@@ -68,6 +68,11 @@ pub trait LockableCurrency<AccountId>: Currency<AccountId> {
 pub trait InspectLockableCurrency<AccountId>: LockableCurrency<AccountId> {
 	/// Amount of funds locked for `who` associated with `id`.
 	fn balance_locked(id: LockIdentifier, who: &AccountId) -> Self::Balance;
+
+	/// Amount of funds of `who` that are frozen by all locks and freezes together.
+	///
+	/// Locks overlap, so this is the largest of them rather than their sum.
+	fn balance_frozen(who: &AccountId) -> Self::Balance;
 }
 
 /// A vesting schedule over a currency. This allows a particular currency to have vesting limits
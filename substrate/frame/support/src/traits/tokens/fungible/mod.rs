@@ -158,6 +158,7 @@ pub mod hold;
 pub(crate) mod imbalance;
 mod item_of;
 mod regular;
+mod storage_deposit;
 mod union_of;
 
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -184,6 +185,9 @@ pub use regular::{
 use sp_arithmetic::traits::Zero;
 use sp_core::Get;
 use sp_runtime::{traits::Convert, DispatchError};
+pub use storage_deposit::{
+	DepositLedger, DepositPrice, DepositStore, HeldDeposit, ReservedDeposit, StorageDeposit,
+};
 pub use union_of::{NativeFromLeft, NativeOrWithId, UnionOf};
 
 #[cfg(feature = "experimental")]
@@ -0,0 +1,237 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deposits for data kept in storage, held from the account the data is attributed to.
+
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use core::marker::PhantomData;
+use scale_info::TypeInfo;
+use sp_arithmetic::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	Rounding,
+};
+use sp_core::Get;
+use sp_runtime::{traits::Convert, DispatchError, DispatchResult, RuntimeDebug};
+
+use super::MutateHold;
+use crate::{
+	storage::StorageMap,
+	traits::{tokens::Precision::BestEffort, Footprint, ReservableCurrency},
+};
+
+/// The storage footprint attributed to an account and the deposit held for it.
+#[derive(
+	Clone, Copy, Eq, PartialEq, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, Default,
+)]
+pub struct DepositLedger<Balance> {
+	/// The number of items stored.
+	pub count: u64,
+	/// The total size of the items in bytes.
+	pub size: u64,
+	/// The deposit held for the items.
+	pub deposit: Balance,
+}
+
+/// Where the deposits of a [`StorageDeposit`] are kept.
+pub trait DepositStore<AccountId> {
+	/// The balance of a deposit.
+	type Balance: AtLeast32BitUnsigned + Copy + Default;
+
+	/// Take `amount` from the free balance of `who` into the deposit.
+	fn hold(who: &AccountId, amount: Self::Balance) -> DispatchResult;
+
+	/// Return up to `amount` of the deposit of `who` to their free balance, returning the amount
+	/// returned.
+	fn release(who: &AccountId, amount: Self::Balance) -> Result<Self::Balance, DispatchError>;
+}
+
+/// Deposits placed on hold with the `fungible` balance `F` under `R::get()`.
+pub struct HeldDeposit<F, R>(PhantomData<(F, R)>);
+
+impl<A, F: MutateHold<A>, R: Get<F::Reason>> DepositStore<A> for HeldDeposit<F, R> {
+	type Balance = F::Balance;
+
+	fn hold(who: &A, amount: Self::Balance) -> DispatchResult {
+		F::hold(&R::get(), who, amount)
+	}
+
+	fn release(who: &A, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+		F::release(&R::get(), who, amount, BestEffort)
+	}
+}
+
+/// Deposits reserved with the [`ReservableCurrency`] `C`.
+///
+/// For pallets which have not moved their deposits onto holds yet.
+pub struct ReservedDeposit<C>(PhantomData<C>);
+
+impl<A, C: ReservableCurrency<A>> DepositStore<A> for ReservedDeposit<C> {
+	type Balance = C::Balance;
+
+	fn hold(who: &A, amount: Self::Balance) -> DispatchResult {
+		C::reserve(who, amount)
+	}
+
+	fn release(who: &A, amount: Self::Balance) -> Result<Self::Balance, DispatchError> {
+		Ok(amount.saturating_sub(C::unreserve(who, amount)))
+	}
+}
+
+/// The price of a footprint: `Base`, plus `PerItem` for every item and `PerByte` for every byte.
+///
+/// An empty footprint, without any item, is free.
+pub struct DepositPrice<Base, PerItem, PerByte>(PhantomData<(Base, PerItem, PerByte)>);
+
+impl<Balance, Base, PerItem, PerByte> Convert<Footprint, Balance>
+	for DepositPrice<Base, PerItem, PerByte>
+where
+	Balance: AtLeast32BitUnsigned,
+	Base: Get<Balance>,
+	PerItem: Get<Balance>,
+	PerByte: Get<Balance>,
+{
+	fn convert(footprint: Footprint) -> Balance {
+		if footprint.count.is_zero() {
+			return Zero::zero()
+		}
+		Base::get()
+			.saturating_add(
+				PerItem::get().saturating_mul(Balance::unique_saturated_from(footprint.count)),
+			)
+			.saturating_add(
+				PerByte::get().saturating_mul(Balance::unique_saturated_from(footprint.size)),
+			)
+	}
+}
+
+/// Charge and refund deposits for data stored on behalf of an account, priced by `P` and kept in
+/// the [`DepositStore`] `S`, usually [`HeldDeposit`] under a `RuntimeHoldReason` of the pallet.
+///
+/// Unlike [`super::HoldConsideration`], there is no ticket to keep alongside the data.
+///
+/// Pallets which record the deposit of their data themselves use [`Self::update`], which holds
+/// or releases the difference between the recorded deposit and the price of the data. Otherwise,
+/// the total footprint of an account and the deposit held for it are tracked in the ledger `L`, a
+/// storage map declared by the pallet using this helper. New data is then priced by `P`, while a
+/// refund releases the share of the held deposit matching the share of bytes removed. Changing
+/// the price thus never refunds more than was held, and everything is released once nothing is
+/// left.
+pub struct StorageDeposit<A, S, P, L = ()>(PhantomData<fn() -> (A, S, P, L)>);
+
+impl<A, S, P, L> StorageDeposit<A, S, P, L>
+where
+	S: DepositStore<A>,
+	P: Convert<Footprint, S::Balance>,
+{
+	/// The deposit for storing `footprint`.
+	pub fn price(footprint: Footprint) -> S::Balance {
+		P::convert(footprint)
+	}
+
+	/// Update the deposit of data of `who` now measuring `footprint`, for which `deposit` is held.
+	///
+	/// The difference to the price of `footprint` is held or released, and the new deposit
+	/// returned. Everything is released for an empty footprint.
+	pub fn update(
+		who: &A,
+		deposit: S::Balance,
+		footprint: Footprint,
+	) -> Result<S::Balance, DispatchError> {
+		let new_deposit = P::convert(footprint);
+		if new_deposit > deposit {
+			S::hold(who, new_deposit - deposit)?;
+		} else if new_deposit < deposit {
+			S::release(who, deposit - new_deposit)?;
+		}
+		Ok(new_deposit)
+	}
+}
+
+impl<A, S, P, L> StorageDeposit<A, S, P, L>
+where
+	A: FullCodec,
+	S: DepositStore<A>,
+	S::Balance: FullCodec,
+	P: Convert<Footprint, S::Balance>,
+	L: StorageMap<A, DepositLedger<S::Balance>>,
+{
+	/// The ledger of `who`, if anything is stored on their behalf.
+	pub fn ledger(who: &A) -> Option<DepositLedger<S::Balance>> {
+		L::try_get(who).ok()
+	}
+
+	/// The deposit held from `who`.
+	pub fn deposit_of(who: &A) -> S::Balance {
+		Self::ledger(who).map_or(Zero::zero(), |ledger| ledger.deposit)
+	}
+
+	/// Hold the deposit for storing `footprint` more on behalf of `who`, returning the amount.
+	pub fn charge(who: &A, footprint: Footprint) -> Result<S::Balance, DispatchError> {
+		let amount = P::convert(footprint);
+		L::try_mutate_exists(who, |maybe_ledger| {
+			if !amount.is_zero() {
+				S::hold(who, amount)?;
+			}
+			let ledger = maybe_ledger.get_or_insert_with(Default::default);
+			ledger.count.saturating_accrue(footprint.count);
+			ledger.size.saturating_accrue(footprint.size);
+			ledger.deposit.saturating_accrue(amount);
+			Ok(amount)
+		})
+	}
+
+	/// Release the deposit for `footprint` no longer being stored on behalf of `who`, returning
+	/// the amount released.
+	pub fn refund(who: &A, footprint: Footprint) -> Result<S::Balance, DispatchError> {
+		L::try_mutate_exists(who, |maybe_ledger| {
+			let Some(ledger) = maybe_ledger.as_mut() else { return Ok(Zero::zero()) };
+			let count = ledger.count.saturating_sub(footprint.count);
+			let size = ledger.size.saturating_sub(footprint.size);
+			let amount = if count.is_zero() && size.is_zero() {
+				ledger.deposit
+			} else if ledger.size.is_zero() {
+				share(ledger.deposit, ledger.count - count, ledger.count)
+			} else {
+				share(ledger.deposit, ledger.size - size, ledger.size)
+			};
+
+			let released = S::release(who, amount)?;
+			if count.is_zero() && size.is_zero() {
+				*maybe_ledger = None;
+			} else {
+				*ledger = DepositLedger { count, size, deposit: ledger.deposit - amount };
+			}
+			Ok(released)
+		})
+	}
+
+	/// Release the whole deposit of `who`, returning the amount released.
+	pub fn refund_all(who: &A) -> Result<S::Balance, DispatchError> {
+		match Self::ledger(who) {
+			Some(ledger) => Self::refund(who, Footprint { count: ledger.count, size: ledger.size }),
+			None => Ok(Zero::zero()),
+		}
+	}
+}
+
+/// The share `part / total` of `deposit`, rounded down.
+fn share<B: AtLeast32BitUnsigned>(deposit: B, part: u64, total: u64) -> B {
+	let deposit: u128 = deposit.unique_saturated_into();
+	multiply_by_rational_with_rounding(deposit, part.into(), total.into(), Rounding::Down)
+		.map_or(Zero::zero(), B::unique_saturated_from)
+}
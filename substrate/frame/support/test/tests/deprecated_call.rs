@@ -0,0 +1,141 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::{
+	assert_err, assert_ok, derive_impl,
+	dispatch::GetDispatchInfo,
+	pallet_prelude::ConstU32,
+	weights::{constants::RocksDbWeight, RuntimeDbWeight},
+};
+use pallet::*;
+use sp_io::TestExternalities;
+use sp_runtime::traits::Dispatchable;
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		Revert,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(1)]
+		#[pallet::deprecated_call(since = "2.0.0", note = "use `new_call` instead")]
+		pub fn old_call(_origin: OriginFor<T>, revert: bool) -> DispatchResult {
+			ensure!(!revert, Error::<T>::Revert);
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(1)]
+		pub fn new_call(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
+	}
+}
+
+pub type BlockNumber = u32;
+pub type Nonce = u64;
+pub type AccountId = u64;
+pub type Header = sp_runtime::generic::Header<BlockNumber, sp_runtime::traits::BlakeTwo256>;
+pub type UncheckedExtrinsic = sp_runtime::generic::UncheckedExtrinsic<u32, RuntimeCall, (), ()>;
+pub type Block = sp_runtime::generic::Block<Header, UncheckedExtrinsic>;
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = Nonce;
+	type Hash = sp_runtime::testing::H256;
+	type Hashing = sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = sp_runtime::traits::IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type DbWeight = RocksDbWeight;
+	type PalletInfo = PalletInfo;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl Config for Runtime {}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MyPallet: pallet,
+	}
+);
+
+#[test]
+fn deprecated_call_usage_is_counted() {
+	TestExternalities::default().execute_with(|| {
+		let old_call = |revert| RuntimeCall::MyPallet(Call::old_call { revert });
+		let usage = || System::deprecated_call_usage("MyPallet", "old_call");
+		assert_eq!(usage(), 0);
+
+		assert_ok!(old_call(false).dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(usage(), 1);
+		// failed dispatches are counted too.
+		assert_err!(old_call(true).dispatch(RuntimeOrigin::signed(1)), Error::<Runtime>::Revert);
+		assert_ok!(old_call(false).dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(usage(), 3);
+
+		assert_ok!(RuntimeCall::MyPallet(Call::new_call {}).dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(System::deprecated_call_usage("MyPallet", "new_call"), 0);
+	});
+}
+
+#[test]
+fn deprecated_call_weight_accounts_for_usage() {
+	let usage_weight =
+		<RocksDbWeight as frame_support::traits::Get<RuntimeDbWeight>>::get().reads_writes(1, 1);
+	assert_eq!(
+		Call::<Runtime>::old_call { revert: false }.get_dispatch_info().call_weight,
+		Call::<Runtime>::new_call {}.get_dispatch_info().call_weight + usage_weight,
+	);
+}
+
+#[test]
+fn deprecated_call_metadata() {
+	use sp_metadata_ir::{DeprecationInfoIR, DeprecationStatusIR};
+	use std::collections::BTreeMap;
+
+	let pallets = Runtime::metadata_ir().pallets;
+	let calls = pallets.iter().find(|p| p.name == "MyPallet").unwrap().calls.clone().unwrap();
+	assert_eq!(
+		calls.deprecation_info,
+		DeprecationInfoIR::VariantsDeprecated(BTreeMap::from([(
+			codec::Compact(0),
+			DeprecationStatusIR::Deprecated {
+				note: "use `new_call` instead",
+				since: Some("2.0.0")
+			}
+		)])),
+	);
+}
@@ -237,6 +237,7 @@ pub mod pallet {
 		/// call foo_storage_layer doc comment put in metadata
 		#[pallet::call_index(1)]
 		#[pallet::weight({1})]
+		#[pallet::storage_access(reads(Value, Map), writes(Value))]
 		pub fn foo_storage_layer(
 			_origin: OriginFor<T>,
 			#[pallet::compact] foo: u32,
@@ -2001,6 +2002,31 @@ fn metadata_ir_pallet_runtime_docs() {
 	assert_eq!(pallet.docs, expected);
 }
 
+#[test]
+fn metadata_ir_call_weights() {
+	use sp_metadata_ir::CallWeightIR;
+
+	let ir = Runtime::metadata_ir();
+	let calls = ir
+		.pallets
+		.iter()
+		.find(|pallet| pallet.name == "Example")
+		.and_then(|pallet| pallet.calls.as_ref())
+		.expect("Pallet should have calls");
+
+	let names = calls.weights.iter().map(|w| w.name).collect::<Vec<_>>();
+	assert_eq!(names[..3], ["foo", "foo_storage_layer", "foo_index_out_of_order"]);
+
+	let foo = &calls.weights[0];
+	assert_eq!(foo.weight, CallWeightIR::Custom { params: vec!["foo"] });
+	assert!(foo.reads.is_empty() && foo.writes.is_empty());
+
+	let foo_storage_layer = &calls.weights[1];
+	assert_eq!(foo_storage_layer.weight, CallWeightIR::Fixed { ref_time: 1, proof_size: 0 });
+	assert_eq!(foo_storage_layer.reads, vec!["Value", "Map"]);
+	assert_eq!(foo_storage_layer.writes, vec!["Value"]);
+}
+
 #[test]
 fn extrinsic_metadata_ir_types() {
 	let ir = Runtime::metadata_ir().extrinsic;
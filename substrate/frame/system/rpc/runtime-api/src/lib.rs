@@ -23,6 +23,10 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
+
 #[docify::export(AccountNonceApi)]
 sp_api::decl_runtime_apis! {
 	/// The API to query account nonce.
@@ -34,3 +38,51 @@ sp_api::decl_runtime_apis! {
 		fn account_nonce(account: AccountId) -> Nonce;
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// The API to query recent events by topic.
+	pub trait EventTopicsApi<Hash, BlockNumber, EventRecord> where
+		Hash: codec::Codec,
+		BlockNumber: codec::Codec,
+		EventRecord: codec::Codec,
+	{
+		/// Get the events deposited with `topic` during the event retention window of the runtime,
+		/// together with the number of the block they were deposited in, oldest first.
+		fn events_by_topic(topic: Hash) -> Vec<(BlockNumber, EventRecord)>;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the weight left in the current block.
+	pub trait BlockWeightApi<Weights> where
+		Weights: codec::Codec,
+	{
+		/// Get the weight that can still be consumed in the current block, per dispatch class.
+		fn remaining_block_weight() -> Weights;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to decode the call of an extrinsic, e.g. to show it before it is signed.
+	pub trait DecodeCallApi<DecodedCall> where
+		DecodedCall: codec::Codec,
+	{
+		/// Decode the call of the SCALE encoded `extrinsic`, together with the calls wrapped in it,
+		/// such as the calls of a batch, a proxy or a multisig call.
+		///
+		/// Returns `None` if `extrinsic` cannot be decoded.
+		fn decode_extrinsic_call(extrinsic: Vec<u8>) -> Option<DecodedCall>;
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// The API to inspect the extrinsics applied in the block being built, e.g. to dry-run a block.
+	pub trait ExtrinsicOutcomeApi {
+		/// Get the SCALE encoded outcome of the extrinsic at `index` in the block being built, such
+		/// as the weight it consumed, the fee it paid and the events it deposited.
+		///
+		/// The outcome is opaque, so that it can be forwarded by clients that do not know the
+		/// types of the runtime. Its type is documented by the runtime.
+		fn extrinsic_outcome(index: u32) -> Vec<u8>;
+	}
+}
@@ -0,0 +1,524 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+	pallet_prelude::BlockNumberFor, Config, LastRateLimitedCall, RateLimitExpiries,
+	RateLimitExpiriesCursor,
+};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	dispatch::DispatchInfo,
+	pallet_prelude::TransactionSource,
+	traits::{Contains, Get, TypedGet},
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		AsSystemOriginSigner, Convert, DispatchInfoOf, Dispatchable, PostDispatchInfoOf,
+		Saturating, TransactionExtension, ValidateResult, Zero,
+	},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	DispatchResult,
+};
+use sp_weights::Weight;
+
+/// The maximum number of expired rate limits [`prune_rate_limits`] removes in a block.
+const MAX_RATE_LIMIT_PRUNES: u32 = 128;
+
+/// A condition the signer of a transaction must satisfy for the call of the transaction to be
+/// dispatched.
+///
+/// Conditions are checked by the [`CheckCallConditions`] transaction extension. A tuple of
+/// conditions is satisfied if all of its elements are.
+///
+/// Conditions only see the call of the transaction, not the calls it dispatches itself, e.g. the
+/// calls of a `pallet_utility::batch` or a `pallet_proxy::proxy`. Use [`Deny`] to reject such
+/// calls if they contain a guarded call.
+pub trait CallCondition<T: Config> {
+	/// The weight of checking the condition for `call` and recording its use.
+	///
+	/// This should be zero for calls the condition does not apply to.
+	fn weight(call: &T::RuntimeCall) -> Weight;
+
+	/// Check whether `who` may dispatch `call`, without altering any state.
+	fn validate(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError>;
+
+	/// Check whether `who` may dispatch `call` right before it is dispatched, and record the use
+	/// of the condition.
+	fn prepare(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		Self::validate(who, call)
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<T: Config> CallCondition<T> for Tuple {
+	fn weight(call: &T::RuntimeCall) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(<Tuple as CallCondition<T>>::weight(call)); )* );
+		weight
+	}
+
+	fn validate(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		for_tuples!( #( <Tuple as CallCondition<T>>::validate(who, call)?; )* );
+		Ok(())
+	}
+
+	fn prepare(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		for_tuples!( #( <Tuple as CallCondition<T>>::prepare(who, call)?; )* );
+		Ok(())
+	}
+}
+
+/// Only accounts for which `Value` is at least `Min` may dispatch the calls matched by `Filter`.
+///
+/// `Value` could for example return the amount an account has staked. Its cost must be at most
+/// one storage read.
+pub struct MinimumValue<Filter, Value, Min>(PhantomData<(Filter, Value, Min)>);
+
+impl<T, Filter, Value, Min> CallCondition<T> for MinimumValue<Filter, Value, Min>
+where
+	T: Config,
+	Filter: Contains<T::RuntimeCall>,
+	Value: Convert<T::AccountId, Min::Type>,
+	Min: TypedGet,
+	Min::Type: PartialOrd,
+{
+	fn weight(call: &T::RuntimeCall) -> Weight {
+		if Filter::contains(call) {
+			T::DbWeight::get().reads(1)
+		} else {
+			Weight::zero()
+		}
+	}
+
+	fn validate(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		if Filter::contains(call) && Value::convert(who.clone()) < Min::get() {
+			return Err(InvalidTransaction::Call.into())
+		}
+		Ok(())
+	}
+}
+
+/// Every account may dispatch at most one of the calls matched by `Filter` every `Period` blocks.
+///
+/// `Id` identifies the rate limit in [`LastRateLimitedCall`]. Rate limits with the same `Id`
+/// share the same budget. A call counts against the rate limit even if its dispatch fails. The
+/// use of a rate limit is pruned from storage once it expired.
+pub struct RateLimit<Filter, Id, Period>(PhantomData<(Filter, Id, Period)>);
+
+impl<T, Filter, Id, Period> CallCondition<T> for RateLimit<Filter, Id, Period>
+where
+	T: Config,
+	Filter: Contains<T::RuntimeCall>,
+	Id: Get<u32>,
+	Period: Get<BlockNumberFor<T>>,
+{
+	fn weight(call: &T::RuntimeCall) -> Weight {
+		if Filter::contains(call) {
+			// the pruning of the expired rate limit is paid for as well.
+			T::DbWeight::get().reads_writes(4, 5)
+		} else {
+			Weight::zero()
+		}
+	}
+
+	fn validate(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		if !Filter::contains(call) {
+			return Ok(())
+		}
+		if let Some(last) = LastRateLimitedCall::<T>::get(who, Id::get()) {
+			if crate::Pallet::<T>::block_number() < last.saturating_add(Period::get()) {
+				return Err(InvalidTransaction::ExhaustsResources.into())
+			}
+		}
+		Ok(())
+	}
+
+	fn prepare(who: &T::AccountId, call: &T::RuntimeCall) -> Result<(), TransactionValidityError> {
+		<Self as CallCondition<T>>::validate(who, call)?;
+		if Filter::contains(call) && !Period::get().is_zero() {
+			let now = crate::Pallet::<T>::block_number();
+			let expiry = now.saturating_add(Period::get());
+			LastRateLimitedCall::<T>::insert(who, Id::get(), now);
+			RateLimitExpiries::<T>::insert(expiry, (who.clone(), Id::get()), now);
+			RateLimitExpiriesCursor::<T>::mutate(|cursor| {
+				if cursor.is_zero() || expiry < *cursor {
+					*cursor = expiry;
+				}
+			});
+		}
+		Ok(())
+	}
+}
+
+/// No account may dispatch the calls matched by `Filter`.
+///
+/// This is meant for calls that dispatch other calls, which the other conditions do not see. For
+/// example, `Filter` could match the `pallet_utility::batch` and `pallet_proxy::proxy` calls that
+/// contain a call guarded by a [`RateLimit`].
+pub struct Deny<Filter>(PhantomData<Filter>);
+
+impl<T, Filter> CallCondition<T> for Deny<Filter>
+where
+	T: Config,
+	Filter: Contains<T::RuntimeCall>,
+{
+	fn weight(_call: &T::RuntimeCall) -> Weight {
+		Weight::zero()
+	}
+
+	fn validate(
+		_who: &T::AccountId,
+		call: &T::RuntimeCall,
+	) -> Result<(), TransactionValidityError> {
+		if Filter::contains(call) {
+			return Err(InvalidTransaction::Call.into())
+		}
+		Ok(())
+	}
+}
+
+/// Remove the uses of [`RateLimit`]s that expired up to block `now` from [`LastRateLimitedCall`],
+/// at most [`MAX_RATE_LIMIT_PRUNES`] of them, and return the weight consumed.
+pub(crate) fn prune_rate_limits<T: Config>(now: BlockNumberFor<T>) -> Weight {
+	let mut cursor = RateLimitExpiriesCursor::<T>::get();
+	if cursor.is_zero() || cursor > now {
+		return Weight::zero()
+	}
+
+	let mut budget = MAX_RATE_LIMIT_PRUNES;
+	let (mut reads, mut writes) = (0u64, 1u64);
+	while !budget.is_zero() && cursor <= now {
+		let mut pruned = 0u32;
+		for ((who, id), last) in RateLimitExpiries::<T>::drain_prefix(cursor).take(budget as usize)
+		{
+			// the account may have used the rate limit again since.
+			LastRateLimitedCall::<T>::mutate_exists(who, id, |maybe_last| {
+				if *maybe_last == Some(last) {
+					*maybe_last = None;
+				}
+			});
+			pruned.saturating_inc();
+		}
+		reads.saturating_accrue(u64::from(pruned).saturating_mul(2).saturating_add(1));
+		writes.saturating_accrue(u64::from(pruned).saturating_mul(2));
+		budget.saturating_reduce(pruned);
+		// the block may have more expiries to prune.
+		if budget.is_zero() {
+			break
+		}
+		cursor.saturating_inc();
+		// the blocks without expiries are also bounded.
+		budget.saturating_dec();
+	}
+
+	let exhausted = cursor > now && {
+		reads.saturating_inc();
+		RateLimitExpiries::<T>::iter_keys().next().is_none()
+	};
+	if exhausted {
+		RateLimitExpiriesCursor::<T>::kill();
+	} else {
+		RateLimitExpiriesCursor::<T>::put(cursor);
+	}
+	T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Check that the signer of a transaction satisfies the [`CallCondition`]s `C` for its call.
+///
+/// This allows a runtime to declare guards such as a minimum stake or a rate limit for some calls
+/// without implementing them in every pallet. Transactions that are not signed are not checked
+/// and get the weight of the extension refunded.
+#[derive(Encode, Decode, CloneNoBound, EqNoBound, PartialEqNoBound, DefaultNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, C))]
+pub struct CheckCallConditions<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> core::fmt::Debug for CheckCallConditions<T, C> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "CheckCallConditions")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T, C> CheckCallConditions<T, C> {
+	/// Create new `TransactionExtension` to check the call conditions.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+/// Operation to perform from `validate` to `prepare` in [`CheckCallConditions`] transaction
+/// extension.
+#[derive(RuntimeDebugNoBound)]
+pub enum Val<T: Config> {
+	/// Account to check the conditions for.
+	Check(T::AccountId),
+	/// Weight to refund.
+	Refund(Weight),
+}
+
+/// Operation to perform from `prepare` to `post_dispatch_details` in [`CheckCallConditions`]
+/// transaction extension.
+#[derive(RuntimeDebugNoBound)]
+pub enum Pre {
+	/// The transaction extension weight should not be refunded.
+	Checked,
+	/// The transaction extension weight should be refunded.
+	Refund(Weight),
+}
+
+impl<T, C> TransactionExtension<T::RuntimeCall> for CheckCallConditions<T, C>
+where
+	T: Config + Send + Sync,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	<T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+	C: CallCondition<T> + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "CheckCallConditions";
+	type Implicit = ();
+	type Val = Val<T>;
+	type Pre = Pre;
+
+	fn weight(&self, call: &T::RuntimeCall) -> Weight {
+		C::weight(call)
+	}
+
+	fn validate(
+		&self,
+		origin: <T as Config>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, T::RuntimeCall> {
+		let Some(who) = origin.as_system_origin_signer() else {
+			return Ok((Default::default(), Val::Refund(self.weight(call)), origin))
+		};
+		C::validate(who, call)?;
+		Ok((Default::default(), Val::Check(who.clone()), origin))
+	}
+
+	fn prepare(
+		self,
+		val: Self::Val,
+		_origin: &T::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		match val {
+			Val::Check(who) => {
+				C::prepare(&who, call)?;
+				Ok(Pre::Checked)
+			},
+			Val::Refund(weight) => Ok(Pre::Refund(weight)),
+		}
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		_info: &DispatchInfo,
+		_post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+		_result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		match pre {
+			Pre::Checked => Ok(Weight::zero()),
+			Pre::Refund(weight) => Ok(weight),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{new_test_ext, RuntimeCall, System, Test, CALL};
+	use frame_support::{
+		assert_ok,
+		dispatch::GetDispatchInfo,
+		traits::{ConstU32, ConstU64, Everything, Nothing},
+	};
+	use sp_runtime::traits::{ConvertInto, DispatchTransaction};
+
+	type MinimumAccountId = MinimumValue<Everything, ConvertInto, ConstU64<5>>;
+	type Unfiltered = MinimumValue<Nothing, ConvertInto, ConstU64<5>>;
+	type OnePerTenBlocks = RateLimit<Everything, ConstU32<0>, ConstU64<10>>;
+
+	/// A condition that is always satisfied, but still has a weight.
+	struct Heavy;
+	impl CallCondition<Test> for Heavy {
+		fn weight(_: &RuntimeCall) -> Weight {
+			Weight::from_parts(100, 0)
+		}
+
+		fn validate(_: &u64, _: &RuntimeCall) -> Result<(), TransactionValidityError> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn minimum_value_works() {
+		new_test_ext().execute_with(|| {
+			let info = CALL.get_dispatch_info();
+			let ext = CheckCallConditions::<Test, MinimumAccountId>::new();
+			assert_eq!(
+				ext.clone().validate_and_prepare(Some(4).into(), CALL, &info, 0, 0).unwrap_err(),
+				TransactionValidityError::from(InvalidTransaction::Call)
+			);
+			assert_ok!(ext.validate_and_prepare(Some(5).into(), CALL, &info, 0, 0));
+
+			// calls that are not filtered are not checked.
+			assert_ok!(CheckCallConditions::<Test, Unfiltered>::new().validate_and_prepare(
+				Some(4).into(),
+				CALL,
+				&info,
+				0,
+				0
+			));
+		})
+	}
+
+	#[test]
+	fn rate_limit_works() {
+		new_test_ext().execute_with(|| {
+			let info = CALL.get_dispatch_info();
+			let ext = CheckCallConditions::<Test, OnePerTenBlocks>::new();
+			System::set_block_number(1);
+			assert_ok!(ext.clone().validate_and_prepare(Some(1).into(), CALL, &info, 0, 0));
+			assert_eq!(LastRateLimitedCall::<Test>::get(1, 0), Some(1));
+
+			// other accounts have their own budget.
+			assert_ok!(ext.clone().validate_and_prepare(Some(2).into(), CALL, &info, 0, 0));
+
+			System::set_block_number(10);
+			assert_eq!(
+				ext.clone().validate_and_prepare(Some(1).into(), CALL, &info, 0, 0).unwrap_err(),
+				TransactionValidityError::from(InvalidTransaction::ExhaustsResources)
+			);
+
+			System::set_block_number(11);
+			assert_ok!(ext.validate_and_prepare(Some(1).into(), CALL, &info, 0, 0));
+			assert_eq!(LastRateLimitedCall::<Test>::get(1, 0), Some(11));
+		})
+	}
+
+	#[test]
+	fn expired_rate_limits_are_pruned() {
+		new_test_ext().execute_with(|| {
+			let info = CALL.get_dispatch_info();
+			let ext = CheckCallConditions::<Test, OnePerTenBlocks>::new();
+			System::set_block_number(1);
+			assert_ok!(ext.clone().validate_and_prepare(Some(1).into(), CALL, &info, 0, 0));
+			System::set_block_number(5);
+			assert_ok!(ext.clone().validate_and_prepare(Some(2).into(), CALL, &info, 0, 0));
+			assert_eq!(RateLimitExpiriesCursor::<Test>::get(), 11);
+
+			// nothing expired yet.
+			assert_eq!(prune_rate_limits::<Test>(10), Weight::zero());
+
+			// only the rate limit of account 1 expired.
+			assert_eq!(
+				prune_rate_limits::<Test>(11),
+				<Test as Config>::DbWeight::get().reads_writes(4, 3)
+			);
+			assert_eq!(LastRateLimitedCall::<Test>::get(1, 0), None);
+			assert_eq!(LastRateLimitedCall::<Test>::get(2, 0), Some(5));
+			assert_eq!(RateLimitExpiriesCursor::<Test>::get(), 12);
+
+			// account 2 uses the rate limit again in the block it expires.
+			System::set_block_number(15);
+			assert_ok!(ext.validate_and_prepare(Some(2).into(), CALL, &info, 0, 0));
+			prune_rate_limits::<Test>(15);
+			assert_eq!(LastRateLimitedCall::<Test>::get(2, 0), Some(15));
+
+			prune_rate_limits::<Test>(25);
+			assert_eq!(LastRateLimitedCall::<Test>::iter().count(), 0);
+			assert_eq!(RateLimitExpiries::<Test>::iter().count(), 0);
+			assert_eq!(RateLimitExpiriesCursor::<Test>::get(), 0);
+		})
+	}
+
+	#[test]
+	fn deny_works() {
+		new_test_ext().execute_with(|| {
+			let info = CALL.get_dispatch_info();
+			assert_eq!(
+				CheckCallConditions::<Test, Deny<Everything>>::new()
+					.validate_and_prepare(Some(1).into(), CALL, &info, 0, 0)
+					.unwrap_err(),
+				TransactionValidityError::from(InvalidTransaction::Call)
+			);
+			assert_ok!(CheckCallConditions::<Test, Deny<Nothing>>::new().validate_and_prepare(
+				Some(1).into(),
+				CALL,
+				&info,
+				0,
+				0
+			));
+		})
+	}
+
+	#[test]
+	fn all_conditions_of_a_tuple_must_be_satisfied() {
+		new_test_ext().execute_with(|| {
+			let info = CALL.get_dispatch_info();
+			let ext = CheckCallConditions::<Test, (OnePerTenBlocks, MinimumAccountId)>::new();
+			System::set_block_number(1);
+
+			assert_eq!(
+				ext.clone().validate_and_prepare(Some(4).into(), CALL, &info, 0, 0).unwrap_err(),
+				TransactionValidityError::from(InvalidTransaction::Call)
+			);
+			assert_ok!(ext.clone().validate_and_prepare(Some(5).into(), CALL, &info, 0, 0));
+			assert_eq!(
+				ext.validate_and_prepare(Some(5).into(), CALL, &info, 0, 0).unwrap_err(),
+				TransactionValidityError::from(InvalidTransaction::ExhaustsResources)
+			);
+		})
+	}
+
+	#[test]
+	fn unsigned_origin_is_not_checked_and_refunded() {
+		new_test_ext().execute_with(|| {
+			let ext = CheckCallConditions::<Test, (Heavy, MinimumAccountId)>::new();
+			let mut info = CALL.get_dispatch_info();
+			info.extension_weight = ext.weight(CALL);
+			assert!(info.extension_weight != Weight::zero());
+
+			let (pre, _) = ext.validate_and_prepare(None.into(), CALL, &info, 0, 0).unwrap();
+			let mut post_info = frame_support::dispatch::PostDispatchInfo {
+				actual_weight: Some(info.total_weight()),
+				pays_fee: Default::default(),
+			};
+			<CheckCallConditions<Test, (Heavy, MinimumAccountId)> as TransactionExtension<
+				RuntimeCall,
+			>>::post_dispatch(pre, &info, &mut post_info, 0, &Ok(()))
+			.unwrap();
+
+			assert_eq!(post_info.actual_weight, Some(info.call_weight));
+		})
+	}
+}
@@ -91,6 +91,8 @@
 //!     the transaction.
 //!   - [`CheckTxVersion`]: Checks that the transaction version is the same as the one used to sign
 //!     the transaction.
+//!   - [`CheckCallConditions`]: Checks that the signer satisfies the [`CallCondition`]s the runtime
+//!     declared for the call, e.g. a [`MinimumValue`], a [`RateLimit`] or [`Deny`].
 //!
 //! Look up the runtime aggregator file (e.g. `node/runtime`) to see the full list of signed
 //! extensions included in a chain.
@@ -167,6 +169,7 @@ pub mod weights;
 pub mod migrations;
 
 pub use extensions::{
+	check_call_conditions::{CallCondition, CheckCallConditions, Deny, MinimumValue, RateLimit},
 	check_genesis::CheckGenesis, check_mortality::CheckMortality,
 	check_non_zero_sender::CheckNonZeroSender, check_nonce::CheckNonce,
 	check_spec_version::CheckSpecVersion, check_tx_version::CheckTxVersion,
@@ -335,6 +338,8 @@ pub mod pallet {
 			type RuntimeTask = ();
 			type BaseCallFilter = frame_support::traits::Everything;
 			type BlockHashCount = TestBlockHashCount<frame_support::traits::ConstU32<10>>;
+			type EventTopicRetention = ();
+			type MaxEventTopicPrunes = frame_support::traits::ConstU32<128>;
 			type OnSetCode = ();
 			type SingleBlockMigrations = ();
 			type MultiBlockMigrator = ();
@@ -436,6 +441,12 @@ pub mod pallet {
 			/// Using 256 as default.
 			type BlockHashCount = TestBlockHashCount<frame_support::traits::ConstU32<256>>;
 
+			/// Events are not retained after the block they were deposited in.
+			type EventTopicRetention = ();
+
+			/// Prune at most 128 retained events and topics per block.
+			type MaxEventTopicPrunes = frame_support::traits::ConstU32<128>;
+
 			/// The set code logic, just the default since we're not a parachain.
 			type OnSetCode = ();
 			type SingleBlockMigrations = ();
@@ -572,6 +583,22 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type BlockHashCount: Get<BlockNumberFor<Self>>;
 
+		/// Number of blocks for which the events deposited with topics are kept (oldest pruned
+		/// first).
+		///
+		/// Within this window the events can be queried by topic through
+		/// [`Pallet::events_by_topic`]. Zero disables the retention.
+		#[pallet::constant]
+		#[pallet::no_default_bounds]
+		type EventTopicRetention: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of retained events and topics pruned per block.
+		///
+		/// Blocks that fell out of the [`Config::EventTopicRetention`] window, for instance after
+		/// it was lowered, are pruned over as many blocks as needed.
+		#[pallet::constant]
+		type MaxEventTopicPrunes: Get<u32>;
+
 		/// The weight of runtime database operations the runtime can invoke.
 		#[pallet::constant]
 		type DbWeight: Get<RuntimeDbWeight>;
@@ -663,6 +690,11 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::prune_retained_events(n)
+				.saturating_add(extensions::check_call_conditions::prune_rate_limits::<T>(n))
+		}
+
 		#[cfg(feature = "std")]
 		fn integrity_test() {
 			T::BlockWeights::get().validate().expect("The weights are invalid.");
@@ -1014,6 +1046,44 @@ pub mod pallet {
 	pub(super) type EventTopics<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::Hash, Vec<(BlockNumberFor<T>, EventIndex)>, ValueQuery>;
 
+	/// Indices of the events deposited with a topic, per block and topic.
+	///
+	/// Kept for [`Config::EventTopicRetention`] blocks.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub(super) type RetainedEventTopics<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		T::Hash,
+		Vec<EventIndex>,
+		ValueQuery,
+	>;
+
+	/// The events deposited with a topic, per block and event index.
+	///
+	/// Kept for [`Config::EventTopicRetention`] blocks.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub(super) type RetainedEvents<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		EventIndex,
+		EventRecord<T::RuntimeEvent, T::Hash>,
+		OptionQuery,
+	>;
+
+	/// The oldest block whose retained events are not pruned yet.
+	///
+	/// Zero if no events are retained. Whitelisted as it is read at the start of every block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(super) type RetainedEventsCursor<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
 	/// Stores the `spec_version` and `spec_name` of when the last runtime upgrade happened.
 	#[pallet::storage]
 	#[pallet::unbounded]
@@ -1039,6 +1109,49 @@ pub mod pallet {
 	pub(super) type AuthorizedUpgrade<T: Config> =
 		StorageValue<_, CodeUpgradeAuthorization<T>, OptionQuery>;
 
+	/// The block in which an account last dispatched a call guarded by the [`RateLimit`] call
+	/// condition with a given identifier.
+	///
+	/// Entries are pruned once their rate limit expired, see [`RateLimitExpiries`].
+	#[pallet::storage]
+	pub type LastRateLimitedCall<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Twox64Concat,
+		u32,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The entries of [`LastRateLimitedCall`] whose rate limit expires in a given block, with the
+	/// block of the call that started the rate limit.
+	#[pallet::storage]
+	pub(super) type RateLimitExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Blake2_128Concat,
+		(T::AccountId, u32),
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The oldest block of [`RateLimitExpiries`] that is not pruned yet.
+	///
+	/// Zero if there is nothing to prune. Whitelisted as it is read at the start of every block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub(super) type RateLimitExpiriesCursor<T: Config> =
+		StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// The number of times each call deprecated with `#[pallet::deprecated_call]` was
+	/// dispatched, by pallet name and call name.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	pub type DeprecatedCallUsage<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Vec<u8>, Twox64Concat, Vec<u8>, u32, ValueQuery>;
+
 	#[derive(frame_support::DefaultNoBound)]
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
@@ -1757,6 +1870,13 @@ impl<T: Config> Pallet<T> {
 			old_event_count
 		};
 
+		if !topics.is_empty() && !T::EventTopicRetention::get().is_zero() {
+			RetainedEvents::<T>::insert(block_number, event_idx, &event);
+			for topic in topics {
+				RetainedEventTopics::<T>::append(block_number, topic, event_idx);
+			}
+		}
+
 		Events::<T>::append(event);
 
 		for topic in topics {
@@ -1764,6 +1884,73 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Get the events deposited with `topic` during the last [`Config::EventTopicRetention`]
+	/// blocks, together with the number of the block they were deposited in, oldest first.
+	pub fn events_by_topic(
+		topic: &T::Hash,
+	) -> Vec<(BlockNumberFor<T>, EventRecord<T::RuntimeEvent, T::Hash>)> {
+		let now = Self::block_number();
+		let mut block_number =
+			now.saturating_sub(T::EventTopicRetention::get()).saturating_add(One::one());
+		let mut events = Vec::new();
+		while block_number <= now {
+			for event_idx in RetainedEventTopics::<T>::get(block_number, topic) {
+				if let Some(event) = RetainedEvents::<T>::get(block_number, event_idx) {
+					events.push((block_number, event));
+				}
+			}
+			block_number.saturating_inc();
+		}
+		events
+	}
+
+	/// Prune the retained events of the blocks that fell out of the
+	/// [`Config::EventTopicRetention`] window before block `now`, at most
+	/// [`Config::MaxEventTopicPrunes`] of them, and return the weight consumed.
+	fn prune_retained_events(now: BlockNumberFor<T>) -> Weight {
+		let retention = T::EventTopicRetention::get();
+		let mut cursor = RetainedEventsCursor::<T>::get();
+		if cursor.is_zero() {
+			if retention.is_zero() {
+				return Weight::zero()
+			}
+			// nothing was retained before this block.
+			RetainedEventsCursor::<T>::put(now);
+			return T::DbWeight::get().writes(1)
+		}
+
+		let mut budget = T::MaxEventTopicPrunes::get();
+		let (mut reads, mut writes) = (0u64, 1u64);
+		while !budget.is_zero() && cursor.saturating_add(retention) <= now {
+			let topics = RetainedEventTopics::<T>::clear_prefix(cursor, budget, None);
+			budget.saturating_reduce(topics.loops);
+			reads.saturating_accrue(topics.loops.into());
+			writes.saturating_accrue(topics.unique.into());
+			if topics.maybe_cursor.is_some() || budget.is_zero() {
+				break
+			}
+
+			let events = RetainedEvents::<T>::clear_prefix(cursor, budget, None);
+			budget.saturating_reduce(events.loops);
+			reads.saturating_accrue(events.loops.into());
+			writes.saturating_accrue(events.unique.into());
+			if events.maybe_cursor.is_some() {
+				break
+			}
+
+			cursor.saturating_inc();
+			// the empty blocks are also bounded.
+			budget.saturating_dec();
+		}
+
+		if retention.is_zero() && cursor >= now {
+			RetainedEventsCursor::<T>::kill();
+		} else {
+			RetainedEventsCursor::<T>::put(cursor);
+		}
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
 	/// Gets the index of extrinsic that is currently executing.
 	pub fn extrinsic_index() -> Option<u32> {
 		storage::unhashed::get(well_known_keys::EXTRINSIC_INDEX)
@@ -1799,6 +1986,28 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Weight that can still be consumed in the current block by each dispatch class.
+	///
+	/// Mirrors the limits enforced by `CheckWeight`: a class is bounded by its `max_total`, and by
+	/// whatever is left of `max_block` unless it can still dip into its `reserved` pool. A `None`
+	/// limit does not bound the class, so unlimited classes (typically
+	/// [`DispatchClass::Mandatory`]) report [`Weight::MAX`].
+	pub fn remaining_block_weight() -> PerDispatchClass<Weight> {
+		let limits = T::BlockWeights::get();
+		let consumed = BlockWeight::<T>::get();
+		let block_remaining = limits.max_block.saturating_sub(consumed.total());
+		PerDispatchClass::new(|class| {
+			let class_limits = limits.get(class);
+			let class_consumed = *consumed.get(class);
+			let within_block = class_limits.reserved.map_or(Weight::MAX, |reserved| {
+				block_remaining.max(reserved.saturating_sub(class_consumed))
+			});
+			class_limits
+				.max_total
+				.map_or(within_block, |max| max.saturating_sub(class_consumed).min(within_block))
+		})
+	}
+
 	/// Start the execution of a particular block.
 	pub fn initialize(number: &BlockNumberFor<T>, parent_hash: &T::Hash, digest: &generic::Digest) {
 		// populate environment
@@ -1998,6 +2207,22 @@ impl<T: Config> Pallet<T> {
 		let _ = <EventTopics<T>>::clear(u32::max_value(), None);
 	}
 
+	/// Note that the call `call` of the pallet `pallet` was dispatched.
+	///
+	/// Called when dispatching the calls deprecated with `#[pallet::deprecated_call]`, whose weight
+	/// accounts for it, so that runtimes can tell whether they are still used before removing
+	/// them.
+	pub fn note_deprecated_call(pallet: &str, call: &str) {
+		DeprecatedCallUsage::<T>::mutate(pallet.as_bytes(), call.as_bytes(), |count| {
+			*count = count.saturating_add(1)
+		});
+	}
+
+	/// The number of times the deprecated call `call` of the pallet `pallet` was dispatched.
+	pub fn deprecated_call_usage(pallet: &str, call: &str) -> u32 {
+		DeprecatedCallUsage::<T>::get(pallet.as_bytes(), call.as_bytes())
+	}
+
 	/// Assert the given `event` exists.
 	///
 	/// NOTE: Events not registered at the genesis block and quietly omitted.
@@ -69,6 +69,8 @@ parameter_types! {
 
 parameter_types! {
 	pub static Killed: Vec<u64> = vec![];
+	pub static EventTopicRetention: u64 = 0;
+	pub static MaxEventTopicPrunes: u32 = 128;
 }
 
 pub struct RecordKilled;
@@ -94,6 +96,8 @@ impl Config for Test {
 	type Version = Version;
 	type AccountData = u32;
 	type OnKilledAccount = RecordKilled;
+	type EventTopicRetention = EventTopicRetention;
+	type MaxEventTopicPrunes = MaxEventTopicPrunes;
 	type MultiBlockMigrator = MockedMigrator;
 	type Nonce = TypeWithDefault<u64, DefaultNonceProvider>;
 }
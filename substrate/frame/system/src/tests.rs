@@ -19,7 +19,7 @@ use crate::*;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::{Pays, PostDispatchInfo, WithPostDispatchInfo},
-	traits::{OnRuntimeUpgrade, WhitelistedStorageKeys},
+	traits::{Hooks, OnRuntimeUpgrade, WhitelistedStorageKeys},
 };
 use mock::{RuntimeOrigin, *};
 use sp_core::{hexdisplay::HexDisplay, H256};
@@ -570,6 +570,119 @@ fn deposit_event_topics() {
 	});
 }
 
+#[test]
+fn retains_events_by_topic() {
+	new_test_ext().execute_with(|| {
+		EventTopicRetention::set(3);
+		let topic = H256::repeat_byte(1);
+
+		// simulate import of 5 blocks
+		for n in 1..=5 {
+			System::reset_events();
+			System::initialize(&n, &[n as u8 - 1; 32].into(), &Default::default());
+			System::on_initialize(n);
+			System::deposit_event_indexed(&[topic], SysEvent::NewAccount { account: n }.into());
+			// events without topics are not retained.
+			System::deposit_event(SysEvent::KilledAccount { account: n });
+			System::finalize();
+		}
+
+		// the events of the first 2 blocks are pruned.
+		assert_eq!(
+			System::events_by_topic(&topic)
+				.into_iter()
+				.map(|(n, record)| (n, record.event))
+				.collect::<Vec<_>>(),
+			(3..=5)
+				.map(|n| (n, SysEvent::NewAccount { account: n }.into()))
+				.collect::<Vec<(u64, RuntimeEvent)>>(),
+		);
+		assert_eq!(RetainedEvents::<Test>::iter_prefix(2).count(), 0);
+		assert_eq!(RetainedEvents::<Test>::iter_prefix(5).count(), 1);
+		assert!(System::events_by_topic(&H256::repeat_byte(2)).is_empty());
+	});
+}
+
+#[test]
+fn prunes_retained_events_in_bounded_steps() {
+	let topic = H256::repeat_byte(1);
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		EventTopicRetention::set(3);
+		MaxEventTopicPrunes::set(4);
+
+		// 3 events with a topic per block.
+		for n in 1..=3 {
+			System::reset_events();
+			System::initialize(&n, &[n as u8 - 1; 32].into(), &Default::default());
+			System::on_initialize(n);
+			for account in 0..3 {
+				System::deposit_event_indexed(&[topic], SysEvent::NewAccount { account }.into());
+			}
+			System::finalize();
+		}
+		assert_eq!(RetainedEventsCursor::<Test>::get(), 1);
+		assert_eq!(System::events_by_topic(&topic).len(), 9);
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		// lowering the retention does not prune everything at once.
+		EventTopicRetention::set(0);
+		System::reset_events();
+		System::initialize(&4, &[3; 32].into(), &Default::default());
+		// the topic and the 3 events of block 1.
+		assert_eq!(System::on_initialize(4), <Test as Config>::DbWeight::get().reads_writes(4, 5));
+		assert_eq!(RetainedEvents::<Test>::iter_prefix(1).count(), 0);
+		assert_eq!(RetainedEvents::<Test>::iter_prefix(2).count(), 3);
+		assert_eq!(RetainedEventsCursor::<Test>::get(), 2);
+		System::finalize();
+
+		for n in 5..=7 {
+			System::reset_events();
+			System::initialize(&n, &[n as u8 - 1; 32].into(), &Default::default());
+			System::on_initialize(n);
+			System::finalize();
+		}
+		assert_eq!(RetainedEvents::<Test>::iter().count(), 0);
+		assert_eq!(RetainedEventTopics::<Test>::iter().count(), 0);
+		assert!(!RetainedEventsCursor::<Test>::exists());
+	});
+}
+
+#[test]
+fn remaining_block_weight_follows_consumed_weight() {
+	new_test_ext().execute_with(|| {
+		let limits = RuntimeBlockWeights::get();
+		let normal_max = limits.get(DispatchClass::Normal).max_total.unwrap();
+		let operational_reserved = limits.get(DispatchClass::Operational).reserved.unwrap();
+
+		// only the base block weight is consumed.
+		let remaining = System::remaining_block_weight();
+		assert_eq!(*remaining.get(DispatchClass::Normal), normal_max);
+		assert_eq!(
+			*remaining.get(DispatchClass::Operational),
+			limits.max_block - limits.base_block
+		);
+		assert_eq!(*remaining.get(DispatchClass::Mandatory), Weight::MAX);
+
+		// once normal extrinsics fill their share, operational ones are left with their reserve.
+		System::register_extra_weight_unchecked(normal_max, DispatchClass::Normal);
+		let remaining = System::remaining_block_weight();
+		assert_eq!(*remaining.get(DispatchClass::Normal), Weight::zero());
+		assert_eq!(*remaining.get(DispatchClass::Operational), operational_reserved);
+
+		System::register_extra_weight_unchecked(
+			Weight::from_parts(6, 0),
+			DispatchClass::Operational,
+		);
+		assert_eq!(
+			*System::remaining_block_weight().get(DispatchClass::Operational),
+			operational_reserved - Weight::from_parts(6, 0)
+		);
+	});
+}
+
 #[test]
 fn event_util_functions_should_work() {
 	new_test_ext().execute_with(|| {
@@ -190,6 +190,11 @@ pub mod pallet {
 		/// apparatus provides. Your chosen consensus system will generally work with this to
 		/// determine a sensible block time. For example, in the Aura pallet it will be double this
 		/// period on default settings.
+		///
+		/// Nothing in storage depends on this value, but changing the block time of a running chain
+		/// requires migrating the consensus state and any block-number based storage, see
+		/// `pallet_aura::migrations::ChangeSlotDuration` and
+		/// `frame_support::migrations::BlockTimeChange`.
 		#[pallet::constant]
 		type MinimumPeriod: Get<Self::Moment>;
 
@@ -34,6 +34,10 @@
 //! your `construct_runtime` macro, but only include this pallet's [`TransactionExtension`]
 //! ([`ChargeAssetTxPayment`]).
 //!
+//! Signers that hold several assets can use [`ChargeMultiAssetTxPayment`] instead, which takes a
+//! list of assets in order of preference and pays with the first one that can cover the fee,
+//! falling back to the native asset.
+//!
 //! ## Terminology
 //!
 //! - Native Asset or Native Currency: The asset that a chain considers native, as in its default
@@ -48,8 +52,8 @@ use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{DispatchInfo, DispatchResult, PostDispatchInfo},
 	pallet_prelude::TransactionSource,
-	traits::IsType,
-	DefaultNoBound,
+	traits::{ConstU32, IsType},
+	BoundedVec, DefaultNoBound,
 };
 use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
 use scale_info::TypeInfo;
@@ -428,3 +432,140 @@ where
 		}
 	}
 }
+
+/// The maximum number of assets in the preference list of [`ChargeMultiAssetTxPayment`].
+pub const MAX_FEE_ASSETS: u32 = 8;
+
+/// Require payment for transaction inclusion in one of several assets and optionally include a tip
+/// to gain additional priority in the queue.
+///
+/// The assets are tried in the order of preference given by the signer, and the native currency
+/// is tried last. The fee is charged in the first of them that can pay for it, just like
+/// [`ChargeAssetTxPayment`] would, i.e. an asset fee is converted by
+/// [`Config::OnChargeAssetTransaction`], any excess is refunded after dispatch and
+/// [`Event::AssetTxFeePaid`] is deposited.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeMultiAssetTxPayment<T: Config> {
+	#[codec(compact)]
+	tip: BalanceOf<T>,
+	assets: BoundedVec<T::AssetId, ConstU32<MAX_FEE_ASSETS>>,
+}
+
+impl<T: Config> ChargeMultiAssetTxPayment<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	/// Utility constructor. Used only in client/factory code.
+	pub fn from(
+		tip: BalanceOf<T>,
+		assets: BoundedVec<T::AssetId, ConstU32<MAX_FEE_ASSETS>>,
+	) -> Self {
+		Self { tip, assets }
+	}
+
+	/// The extension charging the fee in `asset_id`, or in the native currency on `None`.
+	fn charge_in(&self, asset_id: Option<T::AssetId>) -> ChargeAssetTxPayment<T> {
+		ChargeAssetTxPayment { tip: self.tip, asset_id }
+	}
+
+	/// Returns the first of the preferred assets that `who` can pay `fee` with, or `None` if only
+	/// the native currency can.
+	fn select_asset(
+		&self,
+		who: &T::AccountId,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		fee: BalanceOf<T>,
+	) -> Result<Option<T::AssetId>, TransactionValidityError> {
+		self.assets
+			.iter()
+			.cloned()
+			.map(Some)
+			.chain(core::iter::once(None))
+			.find(|asset_id| {
+				self.charge_in(asset_id.clone()).can_withdraw_fee(who, call, info, fee).is_ok()
+			})
+			.ok_or_else(|| InvalidTransaction::Payment.into())
+	}
+}
+
+impl<T: Config> core::fmt::Debug for ChargeMultiAssetTxPayment<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "ChargeMultiAssetTxPayment<{:?}, {:?}>", self.tip, self.assets.encode())
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config> TransactionExtension<T::RuntimeCall> for ChargeMultiAssetTxPayment<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	BalanceOf<T>: Send + Sync + From<u64>,
+	T::AssetId: Send + Sync,
+	<T::RuntimeCall as Dispatchable>::RuntimeOrigin: AsSystemOriginSigner<T::AccountId> + Clone,
+{
+	const IDENTIFIER: &'static str = "ChargeMultiAssetTxPayment";
+	type Implicit = ();
+	/// The selected asset, `None` for the native currency, and the info of the charge.
+	type Val = (Option<T::AssetId>, Val<T>);
+	type Pre = Pre<T>;
+
+	fn weight(&self, _: &T::RuntimeCall) -> Weight {
+		// All the preferred assets might be tried before falling back to the native currency.
+		<T as Config>::WeightInfo::charge_asset_tx_payment_asset()
+			.saturating_mul(self.assets.len() as u64)
+			.saturating_add(<T as Config>::WeightInfo::charge_asset_tx_payment_native())
+	}
+
+	fn validate(
+		&self,
+		origin: <T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> ValidateResult<Self::Val, T::RuntimeCall> {
+		let Some(who) = origin.as_system_origin_signer() else {
+			return Ok((ValidTransaction::default(), (None, Val::NoCharge), origin))
+		};
+		// Non-mutating call of `compute_fee` to calculate the fee used in the transaction priority.
+		let fee = pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, self.tip);
+		let asset_id = self.select_asset(&who, call, info, fee)?;
+		let priority = ChargeTransactionPayment::<T>::get_priority(info, len, self.tip, fee);
+		let validity = ValidTransaction { priority, ..Default::default() };
+		let val = Val::Charge { tip: self.tip, who: who.clone(), fee };
+		Ok((validity, (asset_id, val), origin))
+	}
+
+	fn prepare(
+		self,
+		(asset_id, val): Self::Val,
+		origin: &<T::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		// The weight of trying the assets before the selected one is not refunded, as it was
+		// spent in `validate`.
+		match self.charge_in(asset_id).prepare(val, origin, call, info, len)? {
+			Pre::NoCharge { .. } => Ok(Pre::NoCharge { refund: self.weight(call) }),
+			pre => Ok(pre),
+		}
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		ChargeAssetTxPayment::<T>::post_dispatch_details(pre, info, post_info, len, result)
+	}
+}
@@ -924,3 +924,155 @@ fn no_fee_and_no_weight_for_other_origins() {
 		assert_eq!(post_info.actual_weight, Some(info.call_weight));
 	})
 }
+
+#[test]
+fn multi_asset_payment_uses_first_asset_that_can_pay() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			// create two assets, only the second one has a pool.
+			let (no_pool_asset, pool_asset) = (1, 2);
+			for asset_id in [no_pool_asset, pool_asset] {
+				assert_ok!(Assets::force_create(
+					RuntimeOrigin::root(),
+					asset_id.into(),
+					42,   /* owner */
+					true, /* is_sufficient */
+					2,    /* min_balance */
+				));
+			}
+			setup_lp(pool_asset, balance_factor);
+
+			// mint into an account without native balance.
+			let caller = 333;
+			let balance = 1000;
+			for asset_id in [no_pool_asset, pool_asset] {
+				assert_ok!(Assets::mint_into(asset_id.into(), &caller, balance));
+			}
+			assert_eq!(Balances::free_balance(caller), 0);
+
+			let len = 10;
+			let fee_in_native = base_weight + 5 + len as u64;
+			let fee_in_asset = AssetConversion::quote_price_tokens_for_exact_tokens(
+				NativeOrWithId::WithId(pool_asset),
+				NativeOrWithId::Native,
+				fee_in_native,
+				true,
+			)
+			.unwrap();
+
+			let assets = |ids: Vec<u32>| -> BoundedVec<_, ConstU32<MAX_FEE_ASSETS>> {
+				ids.into_iter()
+					.map(NativeOrWithId::WithId)
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap()
+			};
+			let ext = ChargeMultiAssetTxPayment::<Runtime>::from(0, assets(vec![no_pool_asset]));
+			assert!(ext
+				.validate_and_prepare(
+					Some(caller).into(),
+					CALL,
+					&info_from_weight(WEIGHT_5),
+					len,
+					0
+				)
+				.is_err());
+
+			let ext = ChargeMultiAssetTxPayment::<Runtime>::from(
+				0,
+				assets(vec![no_pool_asset, pool_asset]),
+			);
+			// both assets might be tried before the native currency.
+			assert_eq!(
+				ext.weight(CALL),
+				<Runtime as Config>::WeightInfo::charge_asset_tx_payment_asset()
+					.saturating_mul(2)
+					.saturating_add(
+						<Runtime as Config>::WeightInfo::charge_asset_tx_payment_native()
+					)
+			);
+			let (pre, _) = ext
+				.validate_and_prepare(
+					Some(caller).into(),
+					CALL,
+					&info_from_weight(WEIGHT_5),
+					len,
+					0,
+				)
+				.unwrap();
+			assert_eq!(Assets::balance(no_pool_asset, caller), balance);
+			assert_eq!(Assets::balance(pool_asset, caller), balance - fee_in_asset);
+
+			assert_ok!(ChargeMultiAssetTxPayment::<Runtime>::post_dispatch_details(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+			));
+			System::assert_last_event(RuntimeEvent::AssetTxPayment(Event::AssetTxFeePaid {
+				who: caller,
+				actual_fee: fee_in_asset,
+				tip: 0,
+				asset_id: NativeOrWithId::WithId(pool_asset),
+			}));
+			assert_eq!(Assets::balance(pool_asset, caller), balance - fee_in_asset);
+			assert_eq!(FeeUnbalancedAmount::get(), fee_in_native);
+		});
+}
+
+#[test]
+fn multi_asset_payment_falls_back_to_native() {
+	let base_weight = 5;
+	let balance_factor = 100;
+	ExtBuilder::default()
+		.balance_factor(balance_factor)
+		.base_weight(Weight::from_parts(base_weight, 0))
+		.build()
+		.execute_with(|| {
+			// the caller does not hold the preferred asset.
+			let asset_id = 1;
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id.into(),
+				42,   /* owner */
+				true, /* is_sufficient */
+				2,    /* min_balance */
+			));
+			setup_lp(asset_id, balance_factor);
+
+			let caller = 1;
+			let len = 10;
+			let ext = ChargeMultiAssetTxPayment::<Runtime>::from(
+				0,
+				vec![NativeOrWithId::WithId(asset_id)].try_into().unwrap(),
+			);
+			let (pre, _) = ext
+				.validate_and_prepare(
+					Some(caller).into(),
+					CALL,
+					&info_from_weight(WEIGHT_5),
+					len,
+					0,
+				)
+				.unwrap();
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - 5 - 5 - 10);
+			assert_eq!(Assets::balance(asset_id, caller), 0);
+
+			assert_ok!(ChargeMultiAssetTxPayment::<Runtime>::post_dispatch_details(
+				pre,
+				&info_from_weight(WEIGHT_5),
+				&default_post_info(),
+				len,
+				&Ok(()),
+			));
+			assert_eq!(Balances::free_balance(caller), 10 * balance_factor - 5 - 5 - 10);
+		});
+}
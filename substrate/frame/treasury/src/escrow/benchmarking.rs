@@ -0,0 +1,174 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Treasury escrow benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::{Pallet as TreasuryEscrow, *};
+
+use alloc::vec::Vec;
+use frame_benchmarking::{
+	v1::{account, BenchmarkError},
+	v2::*,
+};
+use frame_support::traits::{EnsureOrigin, Hooks};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn fund_treasury<T: Config>() {
+	let value = T::Currency::minimum_balance().saturating_mul(1_000_000_000u32.into());
+	T::Currency::set_balance(&T::TreasuryAccount::get(), value);
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+// The milestones of an agreement, as many as allowed, with the first deadline at `first_deadline`.
+fn milestones<T: Config>(
+	first_deadline: BlockNumberFor<T>,
+) -> BoundedVec<MilestoneOf<T>, T::MaxMilestones> {
+	let amount = T::Currency::minimum_balance().saturating_mul(10u32.into());
+	(0..T::MaxMilestones::get())
+		.map(|i| Milestone { amount, deadline: first_deadline.saturating_add(i.into()) })
+		.collect::<Vec<_>>()
+		.try_into()
+		.expect("as many milestones as allowed; qed")
+}
+
+// Create an agreement with its first deadline at `first_deadline`.
+fn create_agreement<T: Config>(
+	seed: u32,
+	first_deadline: BlockNumberFor<T>,
+) -> Result<(AgreementIndex, T::AccountId, T::AccountId), BenchmarkError> {
+	let origin =
+		T::CreateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let beneficiary: T::AccountId = account("beneficiary", seed, SEED);
+	let curator: T::AccountId = account("curator", seed, SEED);
+	let index = AgreementCount::<T>::get();
+	TreasuryEscrow::<T>::create(
+		origin,
+		T::Lookup::unlookup(beneficiary.clone()),
+		T::Lookup::unlookup(curator.clone()),
+		milestones::<T>(first_deadline),
+	)?;
+	Ok((index, beneficiary, curator))
+}
+
+fn next_deadline<T: Config>() -> BlockNumberFor<T> {
+	frame_system::Pallet::<T>::block_number().saturating_add(10u32.into())
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// This benchmark is short-circuited if `CreateOrigin` cannot provide a successful origin, in
+	/// which case `create` is un-callable and can use weight=0.
+	#[benchmark]
+	fn create() -> Result<(), BenchmarkError> {
+		fund_treasury::<T>();
+		let origin =
+			T::CreateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		let curator: T::AccountId = account("curator", 0, SEED);
+		let milestones = milestones::<T>(next_deadline::<T>());
+		let amount = milestones.iter().fold(Zero::zero(), |total: BalanceOf<T>, milestone| {
+			total.saturating_add(milestone.amount)
+		});
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			T::Lookup::unlookup(beneficiary.clone()),
+			T::Lookup::unlookup(curator.clone()),
+			milestones,
+		);
+
+		assert_last_event::<T>(
+			Event::AgreementCreated { index: 0, beneficiary, curator, amount }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_milestone() -> Result<(), BenchmarkError> {
+		fund_treasury::<T>();
+		let (index, beneficiary, curator) = create_agreement::<T>(0, next_deadline::<T>())?;
+		TreasuryEscrow::<T>::approve_milestone(RawOrigin::Signed(beneficiary).into(), index)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(curator), index);
+
+		assert!(Agreements::<T>::get(index).map_or(true, |a| a.next_milestone == 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispute() -> Result<(), BenchmarkError> {
+		fund_treasury::<T>();
+		let (index, _, curator) = create_agreement::<T>(0, next_deadline::<T>())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(curator.clone()), index);
+
+		assert_last_event::<T>(Event::AgreementDisputed { index, who: curator }.into());
+		Ok(())
+	}
+
+	/// This benchmark is short-circuited if `DisputeOrigin` cannot provide a successful origin, in
+	/// which case `resolve_dispute` is un-callable and can use weight=0.
+	#[benchmark]
+	fn resolve_dispute() -> Result<(), BenchmarkError> {
+		fund_treasury::<T>();
+		let (index, _, curator) = create_agreement::<T>(0, next_deadline::<T>())?;
+		TreasuryEscrow::<T>::dispute(RawOrigin::Signed(curator).into(), index)?;
+		let origin =
+			T::DisputeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, index, DisputeResolution::Release);
+
+		assert!(Agreements::<T>::get(index).map_or(true, |a| a.next_milestone == 1));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize(n: Linear<0, 100>) -> Result<(), BenchmarkError> {
+		fund_treasury::<T>();
+		let deadline = next_deadline::<T>();
+		for i in 0..n {
+			create_agreement::<T>(i, deadline)?;
+		}
+
+		#[block]
+		{
+			TreasuryEscrow::<T>::on_initialize(deadline);
+		}
+
+		assert_eq!(Agreements::<T>::iter().count(), 0);
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(
+		TreasuryEscrow,
+		crate::tests::ExtBuilder::default().build(),
+		crate::tests::Test
+	);
+}
@@ -0,0 +1,543 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An opt-in escrow for treasury-funded work, released in milestones.
+//!
+//! An escrow agreement is created by [`Config::CreateOrigin`] for a beneficiary and a curator,
+//! with a list of milestones, each with an amount and a deadline. The total amount of the
+//! agreement is held on the treasury account with [`HoldReason::Escrow`] rather than transferred
+//! to another account, so that it stays in the treasury until it is released.
+//!
+//! A milestone is released to the beneficiary once both the beneficiary and the curator approved
+//! it with `approve_milestone`. Milestones are released in order. If the current milestone of an
+//! agreement is not released before its deadline, the remaining amount of the agreement is
+//! refunded to the treasury at the start of the deadline block.
+//!
+//! Either party may `dispute` an agreement. This suspends approvals and the deadline until
+//! [`Config::DisputeOrigin`] resolves the dispute with `resolve_dispute`, by releasing the current
+//! milestone, refunding the remaining amount or dismissing the dispute.
+
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{
+		fungible::{Inspect, Mutate, MutateHold},
+		tokens::{Fortitude::Polite, Precision, Restriction::Free},
+		Get,
+	},
+	BoundedVec,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{CheckedAdd, Saturating, StaticLookup, Zero},
+	ArithmeticError, RuntimeDebug,
+};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Index of an escrow agreement.
+pub type AgreementIndex = u32;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+pub type MilestoneOf<T> = Milestone<BalanceOf<T>, BlockNumberFor<T>>;
+pub type AgreementOf<T> = Agreement<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+	<T as Config>::MaxMilestones,
+>;
+
+/// A milestone of an escrow agreement.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Milestone<Balance, BlockNumber> {
+	/// The amount released to the beneficiary for the milestone.
+	pub amount: Balance,
+	/// The block before which the milestone has to be released.
+	pub deadline: BlockNumber,
+}
+
+/// The approvals of the current milestone of an escrow agreement.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Approvals {
+	/// Whether the beneficiary approved the milestone.
+	pub beneficiary: bool,
+	/// Whether the curator approved the milestone.
+	pub curator: bool,
+}
+
+/// An escrow agreement, holding treasury funds until its milestones are released.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxMilestones))]
+pub struct Agreement<AccountId, Balance, BlockNumber, MaxMilestones>
+where
+	MaxMilestones: Get<u32>,
+{
+	/// The account the milestones are released to.
+	pub beneficiary: AccountId,
+	/// The account overseeing the work on behalf of the treasury.
+	pub curator: AccountId,
+	/// The milestones of the agreement, in the order they are released.
+	pub milestones: BoundedVec<Milestone<Balance, BlockNumber>, MaxMilestones>,
+	/// The index of the current milestone, i.e. the number of milestones released so far.
+	pub next_milestone: u32,
+	/// The approvals of the current milestone.
+	pub approvals: Approvals,
+	/// Whether the agreement is disputed and waits for a resolution.
+	pub disputed: bool,
+}
+
+impl<AccountId, Balance, BlockNumber, MaxMilestones>
+	Agreement<AccountId, Balance, BlockNumber, MaxMilestones>
+where
+	Balance: Saturating + Zero + Copy,
+	MaxMilestones: Get<u32>,
+{
+	/// The current milestone, if any is left.
+	pub fn current(&self) -> Option<&Milestone<Balance, BlockNumber>> {
+		self.milestones.get(self.next_milestone as usize)
+	}
+
+	/// The amount of the milestones which are still to be released.
+	pub fn remaining(&self) -> Balance {
+		self.milestones
+			.iter()
+			.skip(self.next_milestone as usize)
+			.fold(Zero::zero(), |total, milestone| total.saturating_add(milestone.amount))
+	}
+}
+
+/// How a dispute over an escrow agreement is resolved.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum DisputeResolution {
+	/// Release the current milestone to the beneficiary, and carry on with the next one.
+	Release,
+	/// Refund the remaining amount of the agreement to the treasury.
+	Refund,
+	/// Carry on with the current milestone, as if there was no dispute.
+	Dismiss,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The currency of the treasury, in which agreements are paid out.
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The account of the treasury, on which the funds of the agreements are held.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Origin from which agreements can be created, returning the maximum total amount of an
+		/// agreement.
+		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = BalanceOf<Self>>;
+
+		/// Origin from which disputes are resolved.
+		type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of milestones of an agreement.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// A reason for the escrow pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The treasury funds are held for an escrow agreement.
+		#[codec(index = 0)]
+		Escrow,
+	}
+
+	/// The count of agreements that have been made.
+	#[pallet::storage]
+	pub type AgreementCount<T> = StorageValue<_, AgreementIndex, ValueQuery>;
+
+	/// Agreements which still have milestones to be released.
+	// Hasher: Twox safe since `AgreementIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type Agreements<T: Config> =
+		StorageMap<_, Twox64Concat, AgreementIndex, AgreementOf<T>, OptionQuery>;
+
+	/// The agreements whose current milestone expires at the start of a block.
+	///
+	/// Disputed agreements are not present.
+	#[pallet::storage]
+	pub type Deadlines<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		AgreementIndex,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A new agreement has been created, and its amount held on the treasury account.
+		AgreementCreated {
+			index: AgreementIndex,
+			beneficiary: T::AccountId,
+			curator: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A party of an agreement approved its current milestone.
+		MilestoneApproved { index: AgreementIndex, milestone: u32, who: T::AccountId },
+		/// A milestone has been released to the beneficiary.
+		MilestoneReleased { index: AgreementIndex, milestone: u32, amount: BalanceOf<T> },
+		/// All the milestones of an agreement have been released.
+		AgreementCompleted { index: AgreementIndex },
+		/// The remaining amount of an agreement has been refunded to the treasury.
+		AgreementRefunded { index: AgreementIndex, amount: BalanceOf<T> },
+		/// A party disputed an agreement.
+		AgreementDisputed { index: AgreementIndex, who: T::AccountId },
+		/// A dispute over an agreement has been resolved.
+		DisputeResolved { index: AgreementIndex, resolution: DisputeResolution },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The total amount of the agreement is more than the origin is allowed to spend.
+		InsufficientPermission,
+		/// An agreement needs at least one milestone.
+		NoMilestones,
+		/// The deadlines of the milestones must be in the future, in increasing order.
+		InvalidDeadlines,
+		/// The beneficiary of an agreement can not be its curator.
+		CuratorIsBeneficiary,
+		/// There is no agreement with the given index.
+		UnknownAgreement,
+		/// The caller is neither the beneficiary nor the curator of the agreement.
+		NotParty,
+		/// The caller already approved the current milestone.
+		AlreadyApproved,
+		/// The agreement is disputed.
+		Disputed,
+		/// The agreement is not disputed.
+		NotDisputed,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Refund the agreements whose current milestone expires at `now`.
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut expired = 0u32;
+			for (index, ()) in Deadlines::<T>::drain_prefix(now) {
+				if let Some(agreement) = Agreements::<T>::take(index) {
+					Self::refund(index, &agreement);
+				}
+				expired.saturating_inc();
+			}
+			T::WeightInfo::on_initialize(expired)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create an agreement, releasing treasury funds to `beneficiary` in milestones.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::CreateOrigin`] with the `Success` value being at least the total
+		/// amount of the milestones.
+		///
+		/// ## Details
+		///
+		/// The total amount is held on the treasury account until the milestones are released or
+		/// refunded. Each milestone is released once approved by both `beneficiary` and `curator`,
+		/// and the rest of the agreement is refunded if a milestone is not released before its
+		/// deadline.
+		///
+		/// ### Parameters
+		/// - `beneficiary`: The account the milestones are released to.
+		/// - `curator`: The account approving the milestones on behalf of the treasury.
+		/// - `milestones`: The amount and deadline of each milestone, in the order they are
+		///   released.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::AgreementCreated`] if successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::create())]
+		pub fn create(
+			origin: OriginFor<T>,
+			beneficiary: AccountIdLookupOf<T>,
+			curator: AccountIdLookupOf<T>,
+			milestones: BoundedVec<MilestoneOf<T>, T::MaxMilestones>,
+		) -> DispatchResult {
+			let max_amount = T::CreateOrigin::ensure_origin(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+			let curator = T::Lookup::lookup(curator)?;
+			ensure!(beneficiary != curator, Error::<T>::CuratorIsBeneficiary);
+
+			let first = milestones.first().ok_or(Error::<T>::NoMilestones)?;
+			ensure!(
+				first.deadline > frame_system::Pallet::<T>::block_number() &&
+					milestones.windows(2).all(|pair| pair[0].deadline < pair[1].deadline),
+				Error::<T>::InvalidDeadlines
+			);
+			let amount =
+				milestones.iter().try_fold(BalanceOf::<T>::zero(), |total, milestone| {
+					total.checked_add(&milestone.amount).ok_or(ArithmeticError::Overflow)
+				})?;
+			ensure!(amount <= max_amount, Error::<T>::InsufficientPermission);
+
+			T::Currency::hold(&HoldReason::Escrow.into(), &T::TreasuryAccount::get(), amount)?;
+
+			let index = AgreementCount::<T>::get();
+			Deadlines::<T>::insert(first.deadline, index, ());
+			Agreements::<T>::insert(
+				index,
+				Agreement {
+					beneficiary: beneficiary.clone(),
+					curator: curator.clone(),
+					milestones,
+					next_milestone: 0,
+					approvals: Approvals::default(),
+					disputed: false,
+				},
+			);
+			AgreementCount::<T>::put(index + 1);
+
+			Self::deposit_event(Event::AgreementCreated { index, beneficiary, curator, amount });
+			Ok(())
+		}
+
+		/// Approve the current milestone of an agreement.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed by the beneficiary or the curator of the agreement.
+		///
+		/// ## Details
+		///
+		/// The milestone is released to the beneficiary once approved by both of them.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the agreement.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneApproved`] if successful, followed by
+		/// [`Event::MilestoneReleased`] if the milestone is released.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::approve_milestone())]
+		pub fn approve_milestone(origin: OriginFor<T>, index: AgreementIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut agreement = Agreements::<T>::get(index).ok_or(Error::<T>::UnknownAgreement)?;
+			ensure!(!agreement.disputed, Error::<T>::Disputed);
+
+			let approvals = &mut agreement.approvals;
+			let mut approved = false;
+			if who == agreement.beneficiary && !approvals.beneficiary {
+				approvals.beneficiary = true;
+				approved = true;
+			}
+			if who == agreement.curator && !approvals.curator {
+				approvals.curator = true;
+				approved = true;
+			}
+			if !approved {
+				return if who == agreement.beneficiary || who == agreement.curator {
+					Err(Error::<T>::AlreadyApproved.into())
+				} else {
+					Err(Error::<T>::NotParty.into())
+				}
+			}
+
+			Self::deposit_event(Event::MilestoneApproved {
+				index,
+				milestone: agreement.next_milestone,
+				who,
+			});
+			if agreement.approvals.beneficiary && agreement.approvals.curator {
+				Self::release(index, agreement)
+			} else {
+				Agreements::<T>::insert(index, agreement);
+				Ok(())
+			}
+		}
+
+		/// Dispute an agreement, escalating it to [`Config::DisputeOrigin`].
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed by the beneficiary or the curator of the agreement.
+		///
+		/// ## Details
+		///
+		/// Until the dispute is resolved, the current milestone can not be approved and its
+		/// deadline does not apply.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the agreement.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::AgreementDisputed`] if successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::dispute())]
+		pub fn dispute(origin: OriginFor<T>, index: AgreementIndex) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut agreement = Agreements::<T>::get(index).ok_or(Error::<T>::UnknownAgreement)?;
+			ensure!(who == agreement.beneficiary || who == agreement.curator, Error::<T>::NotParty);
+			ensure!(!agreement.disputed, Error::<T>::Disputed);
+
+			if let Some(milestone) = agreement.current() {
+				Deadlines::<T>::remove(milestone.deadline, index);
+			}
+			agreement.disputed = true;
+			Agreements::<T>::insert(index, agreement);
+
+			Self::deposit_event(Event::AgreementDisputed { index, who });
+			Ok(())
+		}
+
+		/// Resolve a dispute over an agreement.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::DisputeOrigin`].
+		///
+		/// ## Details
+		///
+		/// If the deadline of the milestone to carry on with has passed in the meantime, the
+		/// remaining amount of the agreement is refunded right away.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the agreement.
+		/// - `resolution`: Whether to release the current milestone, refund the remaining amount or
+		///   dismiss the dispute.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::DisputeResolved`] if successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			index: AgreementIndex,
+			resolution: DisputeResolution,
+		) -> DispatchResult {
+			T::DisputeOrigin::ensure_origin(origin)?;
+			let mut agreement = Agreements::<T>::get(index).ok_or(Error::<T>::UnknownAgreement)?;
+			ensure!(agreement.disputed, Error::<T>::NotDisputed);
+
+			agreement.disputed = false;
+			Self::deposit_event(Event::DisputeResolved { index, resolution });
+			match resolution {
+				DisputeResolution::Release => Self::release(index, agreement),
+				DisputeResolution::Refund => {
+					Agreements::<T>::remove(index);
+					Self::refund(index, &agreement);
+					Ok(())
+				},
+				DisputeResolution::Dismiss => {
+					Self::carry_on(index, agreement);
+					Ok(())
+				},
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Release the current milestone of `agreement` to its beneficiary, and carry on with the next
+	/// one, if any.
+	fn release(index: AgreementIndex, mut agreement: AgreementOf<T>) -> DispatchResult {
+		let milestone = agreement.current().cloned().ok_or(Error::<T>::UnknownAgreement)?;
+		T::Currency::transfer_on_hold(
+			&HoldReason::Escrow.into(),
+			&T::TreasuryAccount::get(),
+			&agreement.beneficiary,
+			milestone.amount,
+			Precision::Exact,
+			Free,
+			Polite,
+		)?;
+		Deadlines::<T>::remove(milestone.deadline, index);
+		Self::deposit_event(Event::MilestoneReleased {
+			index,
+			milestone: agreement.next_milestone,
+			amount: milestone.amount,
+		});
+
+		agreement.next_milestone.saturating_inc();
+		agreement.approvals = Approvals::default();
+		if agreement.current().is_some() {
+			Self::carry_on(index, agreement);
+		} else {
+			Agreements::<T>::remove(index);
+			Self::deposit_event(Event::AgreementCompleted { index });
+		}
+		Ok(())
+	}
+
+	/// Refund the remaining amount of `agreement` to the treasury.
+	///
+	/// The agreement must already be removed from storage.
+	fn refund(index: AgreementIndex, agreement: &AgreementOf<T>) {
+		let amount = T::Currency::release(
+			&HoldReason::Escrow.into(),
+			&T::TreasuryAccount::get(),
+			agreement.remaining(),
+			Precision::BestEffort,
+		)
+		.unwrap_or_else(|_| Zero::zero());
+		Self::deposit_event(Event::AgreementRefunded { index, amount });
+	}
+
+	/// Carry on with the current milestone of `agreement`, or refund it if its deadline has
+	/// already passed.
+	fn carry_on(index: AgreementIndex, agreement: AgreementOf<T>) {
+		match agreement.current() {
+			Some(milestone) if milestone.deadline > frame_system::Pallet::<T>::block_number() => {
+				Deadlines::<T>::insert(milestone.deadline, index, ());
+				Agreements::<T>::insert(index, agreement);
+			},
+			_ => {
+				Agreements::<T>::remove(index);
+				Self::refund(index, &agreement);
+			},
+		}
+	}
+}
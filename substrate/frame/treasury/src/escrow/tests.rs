@@ -0,0 +1,258 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Treasury escrow tests.
+
+use super::*;
+use crate::tests::{Balances, ExtBuilder, RuntimeOrigin, System, Test, Treasury, TreasuryEscrow};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungible::InspectHold, Currency, OnInitialize},
+};
+use sp_runtime::{traits::BadOrigin, DispatchResult};
+
+const BENEFICIARY: u128 = 6;
+const CURATOR: u128 = 7;
+
+fn milestones(
+	milestones: &[(u64, u64)],
+) -> BoundedVec<MilestoneOf<Test>, <Test as Config>::MaxMilestones> {
+	milestones
+		.iter()
+		.map(|&(amount, deadline)| Milestone { amount, deadline })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn create(origin: RuntimeOrigin, m: &[(u64, u64)]) -> DispatchResult {
+	TreasuryEscrow::create(origin, BENEFICIARY, CURATOR, milestones(m))
+}
+
+fn held() -> u64 {
+	Balances::balance_on_hold(&HoldReason::Escrow.into(), &Treasury::account_id())
+}
+
+fn go_to_block(n: u64) {
+	while System::block_number() < n {
+		let next = System::block_number() + 1;
+		System::set_block_number(next);
+		TreasuryEscrow::on_initialize(next);
+	}
+}
+
+fn approve(index: AgreementIndex) {
+	assert_ok!(TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(BENEFICIARY), index));
+	assert_ok!(TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(CURATOR), index));
+}
+
+#[test]
+fn create_holds_treasury_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+
+		assert_noop!(create(RuntimeOrigin::signed(1), &[(30, 5)]), BadOrigin);
+		assert_noop!(
+			create(RuntimeOrigin::signed(13), &[(30, 5), (30, 10)]),
+			Error::<Test>::InsufficientPermission
+		);
+		assert_noop!(create(RuntimeOrigin::root(), &[]), Error::<Test>::NoMilestones);
+		assert_noop!(create(RuntimeOrigin::root(), &[(30, 1)]), Error::<Test>::InvalidDeadlines);
+		assert_noop!(
+			create(RuntimeOrigin::root(), &[(30, 5), (40, 5)]),
+			Error::<Test>::InvalidDeadlines
+		);
+		assert_noop!(
+			TreasuryEscrow::create(RuntimeOrigin::root(), CURATOR, CURATOR, milestones(&[(30, 5)])),
+			Error::<Test>::CuratorIsBeneficiary
+		);
+		assert!(create(RuntimeOrigin::root(), &[(60, 5), (41, 10)]).is_err());
+
+		assert_ok!(create(RuntimeOrigin::signed(13), &[(30, 5), (20, 10)]));
+		System::assert_last_event(
+			Event::<Test>::AgreementCreated {
+				index: 0,
+				beneficiary: BENEFICIARY,
+				curator: CURATOR,
+				amount: 50,
+			}
+			.into(),
+		);
+		// the funds stay in the treasury account, but not in the pot.
+		assert_eq!(held(), 50);
+		assert_eq!(Balances::free_balance(Treasury::account_id()), 51);
+		assert_eq!(Treasury::pot(), 50);
+		assert_eq!(Deadlines::<Test>::iter().collect::<Vec<_>>(), vec![(5, 0, ())]);
+	});
+}
+
+#[test]
+fn milestones_are_released_with_both_approvals() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(create(RuntimeOrigin::root(), &[(30, 5), (40, 10)]));
+
+		assert_noop!(
+			TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotParty
+		);
+		assert_noop!(
+			TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(CURATOR), 1),
+			Error::<Test>::UnknownAgreement
+		);
+		assert_ok!(TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(BENEFICIARY), 0));
+		assert_noop!(
+			TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(BENEFICIARY), 0),
+			Error::<Test>::AlreadyApproved
+		);
+		assert_eq!(Balances::free_balance(BENEFICIARY), 0);
+
+		assert_ok!(TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(CURATOR), 0));
+		System::assert_last_event(
+			Event::<Test>::MilestoneReleased { index: 0, milestone: 0, amount: 30 }.into(),
+		);
+		assert_eq!(Balances::free_balance(BENEFICIARY), 30);
+		assert_eq!(held(), 40);
+		let agreement = Agreements::<Test>::get(0).unwrap();
+		assert_eq!((agreement.next_milestone, agreement.approvals), (1, Approvals::default()));
+		assert_eq!(Deadlines::<Test>::iter().collect::<Vec<_>>(), vec![(10, 0, ())]);
+
+		// the first deadline does not apply anymore.
+		go_to_block(5);
+		approve(0);
+		System::assert_last_event(Event::<Test>::AgreementCompleted { index: 0 }.into());
+		assert_eq!(Balances::free_balance(BENEFICIARY), 70);
+		assert_eq!(held(), 0);
+		assert!(Agreements::<Test>::get(0).is_none());
+		assert_eq!(Deadlines::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn expired_agreements_are_refunded() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(create(RuntimeOrigin::root(), &[(30, 5), (40, 10)]));
+		assert_ok!(create(RuntimeOrigin::root(), &[(10, 8)]));
+		approve(0);
+
+		go_to_block(9);
+		System::assert_last_event(Event::<Test>::AgreementRefunded { index: 1, amount: 10 }.into());
+		assert_eq!(held(), 40);
+
+		// expires at the start of the deadline block.
+		go_to_block(10);
+		System::assert_last_event(Event::<Test>::AgreementRefunded { index: 0, amount: 40 }.into());
+		assert_noop!(
+			TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(BENEFICIARY), 0),
+			Error::<Test>::UnknownAgreement
+		);
+		assert_eq!(held(), 0);
+		assert_eq!(Treasury::pot(), 70);
+		assert_eq!(Balances::free_balance(BENEFICIARY), 30);
+	});
+}
+
+#[test]
+fn disputes_are_resolved_by_governance() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(create(RuntimeOrigin::root(), &[(30, 5), (40, 10), (20, 20)]));
+
+		assert_noop!(
+			TreasuryEscrow::resolve_dispute(RuntimeOrigin::root(), 0, DisputeResolution::Release),
+			Error::<Test>::NotDisputed
+		);
+		assert_noop!(TreasuryEscrow::dispute(RuntimeOrigin::signed(3), 0), Error::<Test>::NotParty);
+		assert_ok!(TreasuryEscrow::dispute(RuntimeOrigin::signed(BENEFICIARY), 0));
+		System::assert_last_event(
+			Event::<Test>::AgreementDisputed { index: 0, who: BENEFICIARY }.into(),
+		);
+		assert_noop!(
+			TreasuryEscrow::dispute(RuntimeOrigin::signed(CURATOR), 0),
+			Error::<Test>::Disputed
+		);
+		assert_noop!(
+			TreasuryEscrow::approve_milestone(RuntimeOrigin::signed(CURATOR), 0),
+			Error::<Test>::Disputed
+		);
+
+		// the deadline is suspended, and governance releases the milestone.
+		go_to_block(6);
+		assert_noop!(
+			TreasuryEscrow::resolve_dispute(
+				RuntimeOrigin::signed(CURATOR),
+				0,
+				DisputeResolution::Release
+			),
+			BadOrigin
+		);
+		assert_ok!(TreasuryEscrow::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeResolution::Release
+		));
+		assert_eq!(Balances::free_balance(BENEFICIARY), 30);
+		assert_eq!(Deadlines::<Test>::iter().collect::<Vec<_>>(), vec![(10, 0, ())]);
+
+		// a dismissed dispute carries on with the current milestone.
+		assert_ok!(TreasuryEscrow::dispute(RuntimeOrigin::signed(CURATOR), 0));
+		assert_ok!(TreasuryEscrow::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeResolution::Dismiss
+		));
+		System::assert_last_event(
+			Event::<Test>::DisputeResolved { index: 0, resolution: DisputeResolution::Dismiss }
+				.into(),
+		);
+		approve(0);
+		assert_eq!(Balances::free_balance(BENEFICIARY), 70);
+
+		// or the remaining amount is refunded.
+		assert_ok!(TreasuryEscrow::dispute(RuntimeOrigin::signed(CURATOR), 0));
+		assert_ok!(TreasuryEscrow::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeResolution::Refund
+		));
+		System::assert_last_event(Event::<Test>::AgreementRefunded { index: 0, amount: 20 }.into());
+		assert!(Agreements::<Test>::get(0).is_none());
+		assert_eq!(held(), 0);
+		assert_eq!(Deadlines::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn dismissing_a_dispute_after_the_deadline_refunds() {
+	ExtBuilder::default().build().execute_with(|| {
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		assert_ok!(create(RuntimeOrigin::root(), &[(30, 5)]));
+		assert_ok!(TreasuryEscrow::dispute(RuntimeOrigin::signed(CURATOR), 0));
+
+		go_to_block(5);
+		assert!(Agreements::<Test>::get(0).is_some());
+		assert_ok!(TreasuryEscrow::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			DisputeResolution::Dismiss
+		));
+		System::assert_last_event(Event::<Test>::AgreementRefunded { index: 0, amount: 30 }.into());
+		assert!(Agreements::<Test>::get(0).is_none());
+		assert_eq!(held(), 0);
+	});
+}
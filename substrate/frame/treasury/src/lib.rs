@@ -69,11 +69,21 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! A spend may also be paid out in milestones with the `spend_in_milestones` dispatchable. Each
+//! milestone becomes a regular spend, claimable with `payout`, once it is approved by
+//! [`pallet::Config::MilestoneApproveOrigin`] with `approve_milestone`. Milestones are approved in
+//! order, and the remaining ones can be voided with `void_milestone_spend`.
+//!
+//! The opt-in [`escrow`] pallet holds treasury funds for some work instead, and releases them in
+//! milestones approved by both a beneficiary and a curator.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod escrow;
 pub mod migration;
+pub mod runtime_api;
 #[cfg(test)]
 mod tests;
 pub mod weights;
@@ -87,7 +97,7 @@ extern crate alloc;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use alloc::{boxed::Box, collections::btree_map::BTreeMap};
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec::Vec};
 use sp_runtime::{
 	traits::{
 		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
@@ -196,6 +206,41 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// Index of an approved treasury spend paid out in milestones.
+pub type MilestoneSpendIndex = u32;
+
+/// Info regarding an approved treasury spend paid out in milestones.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxMilestones))]
+pub struct MilestoneSpendStatus<AssetKind, AssetBalance, Beneficiary, MaxMilestones>
+where
+	MaxMilestones: Get<u32>,
+{
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The asset amount of each milestone, in the order they are paid out.
+	milestones: BoundedVec<AssetBalance, MaxMilestones>,
+	/// The number of milestones approved so far.
+	approved: u32,
+}
+
+/// The milestones of a treasury spend which are still to be approved.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PendingMilestones<AssetKind, AssetBalance, Beneficiary> {
+	/// The index of the spend paid out in milestones.
+	pub index: MilestoneSpendIndex,
+	/// The kind of asset to be spent.
+	pub asset_kind: AssetKind,
+	/// The beneficiary of the spend.
+	pub beneficiary: Beneficiary,
+	/// The number of the next milestone to be approved, starting from zero.
+	pub next_milestone: u32,
+	/// The asset amount of each milestone still to be approved, in order.
+	pub amounts: Vec<AssetBalance>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -217,6 +262,9 @@ pub mod pallet {
 		/// Origin from which rejections must come.
 		type RejectOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin from which the milestones of a spend must be approved.
+		type MilestoneApproveOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -281,6 +329,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self, I>>;
 
+		/// The maximum number of milestones a spend may be paid out in.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -343,6 +395,22 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The count of spends paid out in milestones that have been made.
+	#[pallet::storage]
+	pub(crate) type MilestoneSpendCount<T, I = ()> =
+		StorageValue<_, MilestoneSpendIndex, ValueQuery>;
+
+	/// Spends paid out in milestones which still have milestones to be approved.
+	// Hasher: Twox safe since `MilestoneSpendIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type MilestoneSpends<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		MilestoneSpendIndex,
+		MilestoneSpendStatus<T::AssetKind, AssetBalanceOf<T, I>, T::Beneficiary, T::MaxMilestones>,
+		OptionQuery,
+	>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub(crate) type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T, I>, OptionQuery>;
@@ -405,6 +473,18 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new spend paid out in milestones has been approved.
+		MilestoneSpendApproved {
+			index: MilestoneSpendIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			milestones: u32,
+		},
+		/// A milestone was approved and can be claimed as the spend `spend_index`.
+		MilestoneApproved { index: MilestoneSpendIndex, milestone: u32, spend_index: SpendIndex },
+		/// The remaining milestones of a spend were voided.
+		MilestoneSpendVoided { index: MilestoneSpendIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -433,6 +513,8 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// A spend paid out in milestones needs at least one milestone.
+		NoMilestones,
 	}
 
 	#[pallet::hooks]
@@ -674,28 +756,7 @@ pub mod pallet {
 			})
 			.unwrap_or(Ok(()))?;
 
-			let index = SpendCount::<T, I>::get();
-			Spends::<T, I>::insert(
-				index,
-				SpendStatus {
-					asset_kind: *asset_kind.clone(),
-					amount,
-					beneficiary: beneficiary.clone(),
-					valid_from,
-					expire_at,
-					status: PaymentState::Pending,
-				},
-			);
-			SpendCount::<T, I>::put(index + 1);
-
-			Self::deposit_event(Event::AssetSpendApproved {
-				index,
-				asset_kind: *asset_kind,
-				amount,
-				beneficiary,
-				valid_from,
-				expire_at,
-			});
+			Self::insert_spend(*asset_kind, amount, beneficiary, valid_from, expire_at);
 			Ok(())
 		}
 
@@ -829,6 +890,174 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds, paid out in milestones.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the sum of
+		/// `milestones` of `asset_kind` in the native asset. The amount of `asset_kind` is
+		/// converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Nothing is claimable until the first milestone is approved with `approve_milestone`.
+		/// Each approved milestone becomes a spend which must be claimed using the `payout`
+		/// dispatchable within the [`Config::PayoutPeriod`].
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `milestones`: The amount of each milestone, in the order they are paid out.
+		/// - `beneficiary`: The beneficiary of the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneSpendApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend())]
+		pub fn spend_in_milestones(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			milestones: BoundedVec<AssetBalanceOf<T, I>, T::MaxMilestones>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!milestones.is_empty(), Error::<T, I>::NoMilestones);
+
+			let amount = milestones
+				.iter()
+				.try_fold(AssetBalanceOf::<T, I>::zero(), |total, m| total.checked_add(m))
+				.ok_or(Error::<T, I>::InsufficientPermission)?;
+			let native_amount =
+				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
+					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+
+			let index = MilestoneSpendCount::<T, I>::get();
+			let milestones_len = milestones.len() as u32;
+			MilestoneSpends::<T, I>::insert(
+				index,
+				MilestoneSpendStatus {
+					asset_kind: *asset_kind.clone(),
+					beneficiary: beneficiary.clone(),
+					milestones,
+					approved: 0,
+				},
+			);
+			MilestoneSpendCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::MilestoneSpendApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				milestones: milestones_len,
+			});
+			Ok(())
+		}
+
+		/// Approve the next milestone of a spend paid out in milestones.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::MilestoneApproveOrigin`].
+		///
+		/// ## Details
+		///
+		/// The milestone becomes a spend claimable immediately using the `payout` dispatchable
+		/// within the [`Config::PayoutPeriod`]. Once the last milestone is approved, the spend
+		/// paid out in milestones is removed from storage.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the spend paid out in milestones.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneApproved`] and [`Event::AssetSpendApproved`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(
+			T::WeightInfo::spend().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn approve_milestone(
+			origin: OriginFor<T>,
+			index: MilestoneSpendIndex,
+		) -> DispatchResult {
+			T::MilestoneApproveOrigin::ensure_origin(origin)?;
+			let mut status =
+				MilestoneSpends::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let milestone = status.approved;
+			let amount = *status
+				.milestones
+				.get(milestone as usize)
+				.ok_or(Error::<T, I>::InvalidIndex)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let expire_at = now.saturating_add(T::PayoutPeriod::get());
+			let spend_index = Self::insert_spend(
+				status.asset_kind.clone(),
+				amount,
+				status.beneficiary.clone(),
+				now,
+				expire_at,
+			);
+
+			status.approved += 1;
+			if status.approved as usize >= status.milestones.len() {
+				MilestoneSpends::<T, I>::remove(index);
+			} else {
+				MilestoneSpends::<T, I>::insert(index, status);
+			}
+
+			Self::deposit_event(Event::MilestoneApproved { index, milestone, spend_index });
+			Ok(())
+		}
+
+		/// Void the remaining milestones of a spend paid out in milestones.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// Milestones already approved are not affected, and may be voided with `void_spend`.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the spend paid out in milestones.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneSpendVoided`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::void_spend())]
+		pub fn void_milestone_spend(
+			origin: OriginFor<T>,
+			index: MilestoneSpendIndex,
+		) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			MilestoneSpends::<T, I>::take(index).ok_or(Error::<T, I>::InvalidIndex)?;
+
+			Self::deposit_event(Event::<T, I>::MilestoneSpendVoided { index });
+			Ok(())
+		}
 	}
 }
 
@@ -889,6 +1118,53 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Approvals::<T, I>::get()
 	}
 
+	/// Record an approved spend, returning its index.
+	fn insert_spend(
+		asset_kind: T::AssetKind,
+		amount: AssetBalanceOf<T, I>,
+		beneficiary: T::Beneficiary,
+		valid_from: BlockNumberFor<T, I>,
+		expire_at: BlockNumberFor<T, I>,
+	) -> SpendIndex {
+		let index = SpendCount::<T, I>::get();
+		Spends::<T, I>::insert(
+			index,
+			SpendStatus {
+				asset_kind: asset_kind.clone(),
+				amount,
+				beneficiary: beneficiary.clone(),
+				valid_from,
+				expire_at,
+				status: PaymentState::Pending,
+			},
+		);
+		SpendCount::<T, I>::put(index + 1);
+
+		Self::deposit_event(Event::AssetSpendApproved {
+			index,
+			asset_kind,
+			amount,
+			beneficiary,
+			valid_from,
+			expire_at,
+		});
+		index
+	}
+
+	/// The spends paid out in milestones which still have milestones to be approved.
+	pub fn pending_milestones(
+	) -> Vec<PendingMilestones<T::AssetKind, AssetBalanceOf<T, I>, T::Beneficiary>> {
+		MilestoneSpends::<T, I>::iter()
+			.map(|(index, status)| PendingMilestones {
+				index,
+				asset_kind: status.asset_kind,
+				beneficiary: status.beneficiary,
+				next_milestone: status.approved,
+				amounts: status.milestones.into_iter().skip(status.approved as usize).collect(),
+			})
+			.collect()
+	}
+
 	/// Spend some money! returns number of approvals before spend.
 	pub fn spend_funds(
 		spend_periods_passed: BlockNumberFor<T, I>,
@@ -993,6 +1269,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_milestone_spends()?;
 
 		Ok(())
 	}
@@ -1066,6 +1343,30 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// ## Invariants of milestone spend storage items
+	///
+	/// 1. Each entry in [`MilestoneSpends`] should be saved under a key strictly less than current
+	/// [`MilestoneSpendCount`].
+	/// 2. Each entry in [`MilestoneSpends`] should have fewer milestones approved than it has
+	/// milestones.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_milestone_spends() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_count = MilestoneSpendCount::<T, I>::get();
+		MilestoneSpends::<T, I>::iter().try_for_each(|(index, status)| -> DispatchResult {
+			ensure!(
+				current_count > index,
+				"`MilestoneSpendCount` should by strictly greater than any MilestoneSpendIndex used as a key for `MilestoneSpends`."
+			);
+			ensure!(
+				(status.approved as usize) < status.milestones.len(),
+				"Spends with all milestones approved should be removed from `MilestoneSpends`."
+			);
+			Ok(())
+		})?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Pallet<T, I> {
@@ -50,6 +50,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances,
 		Treasury: treasury,
 		Utility: pallet_utility,
+		TreasuryEscrow: treasury::escrow,
 	}
 );
 
@@ -179,6 +180,7 @@ impl Config for Test {
 	type PalletId = TreasuryPalletId;
 	type Currency = pallet_balances::Pallet<Test>;
 	type RejectOrigin = frame_system::EnsureRoot<u128>;
+	type MilestoneApproveOrigin = frame_system::EnsureRoot<u128>;
 	type RuntimeEvent = RuntimeEvent;
 	type SpendPeriod = ConstU64<2>;
 	type Burn = Burn;
@@ -193,11 +195,23 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 }
 
+impl crate::escrow::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type TreasuryAccount = TreasuryAccount;
+	type CreateOrigin = TestSpendOrigin;
+	type DisputeOrigin = frame_system::EnsureRoot<u128>;
+	type MaxMilestones = ConstU32<4>;
+	type WeightInfo = ();
+}
+
 pub struct ExtBuilder {}
 
 impl Default for ExtBuilder {
@@ -1010,3 +1024,97 @@ fn multiple_spend_periods_work() {
 		assert_eq!(LastSpendPeriod::<Test>::get(), Some(8));
 	});
 }
+
+#[test]
+fn spend_in_milestones_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// approve a `5` coins spend of asset `1` to beneficiary `6`, paid out in two milestones.
+		assert_ok!(Treasury::spend_in_milestones(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			vec![2, 3].try_into().unwrap(),
+			Box::new(6),
+		));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneSpendApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 5,
+				beneficiary: 6,
+				milestones: 2,
+			}
+			.into(),
+		);
+		// nothing can be claimed before a milestone is approved.
+		assert_eq!(SpendCount::<Test, _>::get(), 0);
+		assert_noop!(Treasury::approve_milestone(RuntimeOrigin::signed(1), 0), BadOrigin);
+
+		assert_ok!(Treasury::approve_milestone(RuntimeOrigin::root(), 0));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneApproved { index: 0, milestone: 0, spend_index: 0 }.into(),
+		);
+		assert_eq!(
+			Treasury::pending_milestones(),
+			vec![PendingMilestones {
+				index: 0,
+				asset_kind: 1,
+				beneficiary: 6,
+				next_milestone: 1,
+				amounts: vec![3],
+			}]
+		);
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 2);
+
+		// approving the last milestone completes the spend.
+		assert_ok!(Treasury::approve_milestone(RuntimeOrigin::root(), 0));
+		assert!(Treasury::pending_milestones().is_empty());
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 1));
+		assert_eq!(paid(6, 1), 5);
+		assert_noop!(
+			Treasury::approve_milestone(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
+#[test]
+fn spend_in_milestones_checks_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			Treasury::spend_in_milestones(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				Default::default(),
+				Box::new(6),
+			),
+			Error::<Test, _>::NoMilestones
+		);
+		// the origin must be allowed to spend the sum of all milestones.
+		assert_noop!(
+			Treasury::spend_in_milestones(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				vec![3, 3].try_into().unwrap(),
+				Box::new(6),
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+
+		assert_ok!(Treasury::spend_in_milestones(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			vec![2, 3].try_into().unwrap(),
+			Box::new(6),
+		));
+		assert_noop!(Treasury::void_milestone_spend(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_ok!(Treasury::void_milestone_spend(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::MilestoneSpendVoided { index: 0 }.into());
+		assert_noop!(
+			Treasury::approve_milestone(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
@@ -24,3 +24,20 @@ pub mod inner;
 
 #[cfg(feature = "try-runtime")]
 pub use inner::*;
+
+pub use frame_support::migrations::{MigratedPallet, UpgradeDryRunReport};
+
+sp_api::decl_runtime_apis! {
+	/// Runtime api to dry-run the upgrade to this runtime.
+	///
+	/// In contrast to `TryRuntime`, this api is meant to be available in production runtimes, so
+	/// that the upgrade to a proposed runtime can be verified against the state of a live chain.
+	pub trait UpgradeDryRun {
+		/// Execute all migrations of this runtime against the current state and report the
+		/// outcome.
+		///
+		/// This should do EXACTLY the same operations as the runtime would have done in the case
+		/// of a runtime upgrade. The state changes are expected to be discarded by the caller.
+		fn dry_run_upgrade() -> UpgradeDryRunReport;
+	}
+}
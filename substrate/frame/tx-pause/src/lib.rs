@@ -236,6 +236,11 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Return all calls that are explicitly paused.
+	pub fn paused_calls() -> Vec<RuntimeCallNameOf<T>> {
+		PausedCalls::<T>::iter_keys().collect()
+	}
+
 	/// Return whether this call is paused.
 	pub fn is_paused(full_name: &RuntimeCallNameOf<T>) -> bool {
 		if T::WhitelistedCalls::contains(full_name) {
@@ -329,3 +334,14 @@ impl<T: Config> From<Error<T>> for frame_support::traits::TransactionPauseError
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to query the calls paused by the tx-pause pallet.
+	pub trait TxPauseApi<CallName>
+	where
+		CallName: codec::Codec,
+	{
+		/// All calls that are explicitly paused.
+		fn paused_calls() -> Vec<CallName>;
+	}
+}
@@ -87,6 +87,23 @@ fn can_unpause_specific_call() {
 	});
 }
 
+#[test]
+fn paused_calls_lists_paused_calls() {
+	new_test_ext().execute_with(|| {
+		assert!(TxPause::paused_calls().is_empty());
+
+		let transfer = full_name::<Test>(b"Balances", b"transfer_allow_death");
+		assert_ok!(TxPause::pause(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			transfer.clone()
+		));
+		assert_eq!(TxPause::paused_calls(), vec![transfer.clone()]);
+
+		assert_ok!(TxPause::unpause(RuntimeOrigin::signed(mock::UnpauseOrigin::get()), transfer));
+		assert!(TxPause::paused_calls().is_empty());
+	});
+}
+
 #[test]
 fn can_filter_balance_in_batch_when_paused() {
 	new_test_ext().execute_with(|| {
@@ -48,6 +48,8 @@
 //!
 //! #### For pseudonymal dispatch
 //! * `as_derivative` - Dispatch a call from a derivative signed origin.
+//! * `dispatch_as_fallible` - Dispatch multiple calls, each from the sender's origin or from one of
+//!   its derivative signed origins.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -62,7 +64,7 @@ use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
-	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+	traits::{GetInnerCalls, IsSubType, OriginTrait, UnfilteredDispatchable},
 };
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
@@ -120,6 +122,9 @@ pub mod pallet {
 		ItemFailed { error: DispatchError },
 		/// A call was dispatched.
 		DispatchedAs { result: DispatchResult },
+		/// An item of `dispatch_as_fallible` was dispatched from the caller, or from its
+		/// `derivative` account if any.
+		ItemDispatchedAs { index: u32, derivative: Option<u16>, result: DispatchResult },
 	}
 
 	// Align the call size to 1KB. As we are currently compiling the runtime for native/wasm
@@ -183,7 +188,7 @@ pub mod pallet {
 		/// event is deposited.
 		#[pallet::call_index(0)]
 		#[pallet::weight({
-			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(calls.iter());
 			let dispatch_weight = dispatch_weight.saturating_add(T::WeightInfo::batch(calls.len() as u32));
 			(dispatch_weight, dispatch_class)
 		})]
@@ -292,7 +297,7 @@ pub mod pallet {
 		/// - O(C) where C is the number of calls to be batched.
 		#[pallet::call_index(2)]
 		#[pallet::weight({
-			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(calls.iter());
 			let dispatch_weight = dispatch_weight.saturating_add(T::WeightInfo::batch_all(calls.len() as u32));
 			(dispatch_weight, dispatch_class)
 		})]
@@ -388,7 +393,7 @@ pub mod pallet {
 		/// - O(C) where C is the number of calls to be batched.
 		#[pallet::call_index(4)]
 		#[pallet::weight({
-			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(&calls);
+			let (dispatch_weight, dispatch_class) = Pallet::<T>::weight_and_dispatch_class(calls.iter());
 			let dispatch_weight = dispatch_weight.saturating_add(T::WeightInfo::force_batch(calls.len() as u32));
 			(dispatch_weight, dispatch_class)
 		})]
@@ -454,14 +459,92 @@ pub mod pallet {
 			let res = call.dispatch_bypass_filter(frame_system::RawOrigin::Root.into());
 			res.map(|_| ()).map_err(|e| e.error)
 		}
+
+		/// Send a batch of dispatch calls, each from the sender or from one of its derivative
+		/// accounts.
+		///
+		/// - `calls`: The calls to be dispatched, each paired with the index of the derivative
+		///   account to dispatch it from (see [`Pallet::derivative_account_id`]), or `None` to
+		///   dispatch it from the sender. The number of call must not exceed the constant:
+		///   `batched_calls_limit` (available in constant metadata).
+		/// - `atomic`: Whether the whole batch should be reverted if any of the calls failed, as in
+		///   `batch_all`. Otherwise all calls are dispatched regardless of errors, as in
+		///   `force_batch`.
+		///
+		/// Filter from origin are passed along to every call, as in `as_derivative`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// An `ItemDispatchedAs` event is deposited for every dispatched call, with its result.
+		/// It is followed by `BatchCompleted`, or `BatchCompletedWithErrors` if some of the calls
+		/// failed and the batch is not atomic.
+		///
+		/// ## Complexity
+		/// - O(C) where C is the number of calls to be batched.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let (dispatch_weight, dispatch_class) =
+				Pallet::<T>::weight_and_dispatch_class(calls.iter().map(|(_, call)| call));
+			let dispatch_weight = dispatch_weight
+				.saturating_add(Pallet::<T>::dispatch_as_fallible_weight(calls.len() as u32));
+			(dispatch_weight, dispatch_class)
+		})]
+		pub fn dispatch_as_fallible(
+			origin: OriginFor<T>,
+			calls: Vec<(Option<u16>, <T as Config>::RuntimeCall)>,
+			atomic: bool,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			let calls_len = calls.len();
+			ensure!(calls_len <= Self::batched_calls_limit() as usize, Error::<T>::TooManyCalls);
+
+			// Track the actual weight of each of the batch calls.
+			let mut weight = Weight::zero();
+			// Track failed dispatch occur.
+			let mut has_error: bool = false;
+			for (index, (derivative, call)) in calls.into_iter().enumerate() {
+				let info = call.get_dispatch_info();
+				let mut item_origin = origin.clone();
+				if let Some(derivative) = derivative {
+					let pseudonym = Self::derivative_account_id(who.clone(), derivative);
+					item_origin.set_caller_from(frame_system::RawOrigin::Signed(pseudonym));
+				}
+				let result = call.dispatch(item_origin);
+				// Add the weight of this call.
+				weight = weight.saturating_add(extract_actual_weight(&result, &info));
+				if atomic {
+					if let Err(mut err) = result {
+						// Take the weight of this function itself into account.
+						let base_weight =
+							Self::dispatch_as_fallible_weight(index.saturating_add(1) as u32);
+						// Return the actual used weight + base_weight of this call.
+						err.post_info = Some(base_weight.saturating_add(weight)).into();
+						return Err(err)
+					}
+				}
+				has_error |= result.is_err();
+				Self::deposit_event(Event::ItemDispatchedAs {
+					index: index as u32,
+					derivative,
+					result: result.map(|_| ()).map_err(|e| e.error),
+				});
+			}
+			if has_error {
+				Self::deposit_event(Event::BatchCompletedWithErrors);
+			} else {
+				Self::deposit_event(Event::BatchCompleted);
+			}
+			let base_weight = Self::dispatch_as_fallible_weight(calls_len as u32);
+			Ok(Some(base_weight.saturating_add(weight)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Get the accumulated `weight` and the dispatch class for the given `calls`.
-		fn weight_and_dispatch_class(
-			calls: &[<T as Config>::RuntimeCall],
+		fn weight_and_dispatch_class<'a>(
+			calls: impl Iterator<Item = &'a <T as Config>::RuntimeCall>,
 		) -> (Weight, DispatchClass) {
-			let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info());
+			let dispatch_infos = calls.map(|call| call.get_dispatch_info());
 			let (dispatch_weight, dispatch_class) = dispatch_infos.fold(
 				(Weight::zero(), DispatchClass::Operational),
 				|(total_weight, dispatch_class): (Weight, DispatchClass), di| {
@@ -475,6 +558,16 @@ pub mod pallet {
 
 			(dispatch_weight, dispatch_class)
 		}
+
+		/// The weight of `dispatch_as_fallible` for `c` calls, without the weight of the calls.
+		///
+		/// Every call is accounted as if dispatched through `as_derivative`.
+		fn dispatch_as_fallible_weight(c: u32) -> Weight {
+			let per_call = T::WeightInfo::as_derivative()
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			T::WeightInfo::batch_all(c).saturating_add(per_call.saturating_mul(c.into()))
+		}
 	}
 }
 
@@ -494,3 +587,18 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 }
+
+impl<T: Config> GetInnerCalls<<T as Config>::RuntimeCall> for Call<T> {
+	fn get_inner_calls(&self) -> Vec<&<T as Config>::RuntimeCall> {
+		match self {
+			Call::batch { calls } | Call::batch_all { calls } | Call::force_batch { calls } =>
+				calls.iter().collect(),
+			Call::dispatch_as_fallible { calls, .. } =>
+				calls.iter().map(|(_, call)| call).collect(),
+			Call::as_derivative { call, .. } |
+			Call::dispatch_as { call, .. } |
+			Call::with_weight { call, .. } => Vec::from([&**call]),
+			_ => Vec::new(),
+		}
+	}
+}
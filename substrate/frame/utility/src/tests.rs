@@ -26,7 +26,7 @@ use frame_support::{
 	assert_err_ignore_postinfo, assert_noop, assert_ok, derive_impl,
 	dispatch::{DispatchErrorWithPostInfo, Pays},
 	parameter_types, storage,
-	traits::{ConstU64, Contains},
+	traits::{ConstU64, Contains, PalletInfoAccess},
 	weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -228,6 +228,15 @@ impl Config for Test {
 type ExampleCall = example::Call<Test>;
 type UtilityCall = crate::Call<Test>;
 
+impl GetInnerCalls<RuntimeCall> for RuntimeCall {
+	fn get_inner_calls(&self) -> Vec<&RuntimeCall> {
+		match self {
+			RuntimeCall::Utility(call) => call.get_inner_calls(),
+			_ => Vec::new(),
+		}
+	}
+}
+
 use frame_system::Call as SystemCall;
 use pallet_balances::Call as BalancesCall;
 use pallet_root_testing::Call as RootTestingCall;
@@ -739,6 +748,102 @@ fn force_batch_works() {
 	});
 }
 
+#[test]
+fn dispatch_as_fallible_works() {
+	new_test_ext().execute_with(|| {
+		let sub_1_0 = Utility::derivative_account_id(1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), sub_1_0, 5));
+		assert_ok!(Utility::dispatch_as_fallible(
+			RuntimeOrigin::signed(1),
+			vec![
+				(Some(0), call_transfer(2, 3)),
+				(Some(1), call_transfer(2, 3)),
+				(None, call_transfer(2, 4)),
+			],
+			false,
+		));
+		System::assert_has_event(
+			utility::Event::ItemDispatchedAs { index: 0, derivative: Some(0), result: Ok(()) }
+				.into(),
+		);
+		System::assert_has_event(
+			utility::Event::ItemDispatchedAs {
+				index: 1,
+				derivative: Some(1),
+				result: Err(TokenError::FundsUnavailable.into()),
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			utility::Event::ItemDispatchedAs { index: 2, derivative: None, result: Ok(()) }.into(),
+		);
+		System::assert_last_event(utility::Event::BatchCompletedWithErrors.into());
+		assert_eq!(Balances::free_balance(sub_1_0), 2);
+		assert_eq!(Balances::free_balance(1), 1);
+		assert_eq!(Balances::free_balance(2), 17);
+
+		// calls are still subject to the filters of the origin.
+		assert_ok!(Utility::dispatch_as_fallible(
+			RuntimeOrigin::signed(1),
+			vec![(
+				Some(0),
+				RuntimeCall::Balances(BalancesCall::transfer_keep_alive { dest: 2, value: 1 })
+			)],
+			false,
+		));
+		System::assert_has_event(
+			utility::Event::ItemDispatchedAs {
+				index: 0,
+				derivative: Some(0),
+				result: Err(frame_system::Error::<Test>::CallFiltered.into()),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Utility::dispatch_as_fallible(RuntimeOrigin::root(), vec![], false),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn dispatch_as_fallible_atomic_reverts() {
+	new_test_ext().execute_with(|| {
+		let sub_1_0 = Utility::derivative_account_id(1, 0);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), sub_1_0, 5));
+		assert_noop!(
+			Utility::dispatch_as_fallible(
+				RuntimeOrigin::signed(1),
+				vec![(Some(0), call_transfer(2, 3)), (Some(1), call_transfer(2, 3))],
+				true,
+			),
+			DispatchErrorWithPostInfo {
+				post_info: PostDispatchInfo {
+					actual_weight: Some(
+						<Test as Config>::WeightInfo::batch_all(2) +
+							(<Test as Config>::WeightInfo::as_derivative() +
+								<Test as frame_system::Config>::DbWeight::get()
+									.reads_writes(1, 1)) * 2 +
+							call_transfer(2, 3).get_dispatch_info().call_weight * 2
+					),
+					pays_fee: Pays::Yes
+				},
+				error: TokenError::FundsUnavailable.into(),
+			}
+		);
+
+		assert_ok!(Utility::dispatch_as_fallible(
+			RuntimeOrigin::signed(1),
+			vec![(Some(0), call_transfer(2, 3)), (None, call_transfer(2, 3))],
+			true,
+		));
+		System::assert_last_event(utility::Event::BatchCompleted.into());
+		assert_eq!(Balances::free_balance(sub_1_0), 2);
+		assert_eq!(Balances::free_balance(2), 16);
+	});
+}
+
 #[test]
 fn none_origin_does_not_work() {
 	new_test_ext().execute_with(|| {
@@ -914,3 +1019,35 @@ fn with_weight_works() {
 		);
 	})
 }
+
+#[test]
+fn decoded_call_includes_inner_calls() {
+	let call = RuntimeCall::Utility(UtilityCall::batch {
+		calls: vec![
+			call_transfer(2, 1),
+			RuntimeCall::Utility(UtilityCall::as_derivative {
+				index: 0,
+				call: Box::new(call_transfer(3, 2)),
+			}),
+		],
+	});
+
+	let decoded = frame_support::traits::DecodedCall::new(&call);
+
+	assert_eq!(decoded.pallet_name, "Utility");
+	assert_eq!(decoded.function_name, "batch");
+	assert_eq!(decoded.pallet_index, <Utility as PalletInfoAccess>::index() as u8);
+	assert_eq!(decoded.call_index, 0);
+	assert_eq!(decoded.inner_calls.len(), 2);
+
+	let transfer = &decoded.inner_calls[0];
+	assert_eq!(transfer.function_name, "transfer_allow_death");
+	assert_eq!(transfer.args, call_transfer(2, 1).encode()[2..].to_vec());
+	assert!(transfer.inner_calls.is_empty());
+
+	let derivative = &decoded.inner_calls[1];
+	assert_eq!(derivative.function_name, "as_derivative");
+	assert_eq!(derivative.inner_calls.len(), 1);
+	assert_eq!(derivative.inner_calls[0].pallet_name, "Balances");
+	assert_eq!(derivative.inner_calls[0].args, call_transfer(3, 2).encode()[2..].to_vec());
+}
@@ -99,6 +99,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -182,6 +183,9 @@ pub mod pallet {
 		/// Provider for the block number.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// Origin allowed to revoke revocable vesting schedules.
+		type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
 	}
@@ -231,7 +235,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -269,6 +273,13 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A revocable vesting schedule of `account` was revoked and the `unvested` amount was
+		/// transferred to `destination`.
+		VestingRevoked {
+			account: T::AccountId,
+			destination: T::AccountId,
+			unvested: BalanceOf<T>,
+		},
 	}
 
 	/// Error for the vesting pallet.
@@ -285,6 +296,10 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// The vesting schedule is not revocable.
+		NotRevocable,
+		/// The vesting schedule is revocable or has not reached its cliff and cannot be merged.
+		ScheduleNotMergeable,
 	}
 
 	#[pallet::call]
@@ -464,6 +479,51 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Revoke a revocable vesting schedule, transferring the funds that have not vested yet
+		/// to `destination`. Funds that already vested stay with `target`.
+		///
+		/// The dispatch origin for this call must be `RevokeOrigin`.
+		///
+		/// - `target`: An account that has a revocable vesting schedule.
+		/// - `schedule_index`: The index of the vesting schedule to revoke.
+		/// - `destination`: The account receiving the unvested funds.
+		///
+		/// Emits `VestingRevoked`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::force_remove_vesting_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn revoke_vesting_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+			destination: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			T::RevokeOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(target)?;
+			let destination = T::Lookup::lookup(destination)?;
+
+			let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+			let schedule = *schedules
+				.get(schedule_index as usize)
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			ensure!(schedule.is_revocable(), Error::<T>::NotRevocable);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let unvested = schedule.locked_at::<T::BlockNumberToBalance>(now);
+
+			let remove_action = VestingAction::Remove { index: schedule_index as usize };
+			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+			Self::write_vesting(&who, schedules)?;
+			Self::write_lock(&who, locked_now);
+
+			T::Currency::transfer(&who, &destination, unvested, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::<T>::VestingRevoked { account: who, destination, unvested });
+			Ok(())
+		}
 	}
 }
 
@@ -538,29 +598,54 @@ impl<T: Config> Pallet<T> {
 		};
 
 		// Check we can add to this account prior to any storage writes.
-		Self::can_add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		)?;
+		ensure!(
+			(Vesting::<T>::decode_len(target).unwrap_or_default() as u32) <
+				T::MAX_VESTING_SCHEDULES,
+			Error::<T>::AtMaxVestingSchedules
+		);
 
 		T::Currency::transfer(source, target, schedule.locked(), ExistenceRequirement::AllowDeath)?;
 
 		// We can't let this fail because the currency transfer has already happened.
 		// Must be successful as it has been checked before.
 		// Better to return error on failure anyway.
-		let res = Self::add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::do_add_vesting_schedule(target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
 	}
 
+	/// Add `schedule` to the schedules of `who` and update the lock accordingly.
+	///
+	/// Is a no-op if the amount to be vested is zero.
+	fn do_add_vesting_schedule(
+		who: &T::AccountId,
+		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+	) -> DispatchResult {
+		if schedule.locked().is_zero() {
+			return Ok(())
+		}
+
+		// Check for `per_block` or `locked` of 0.
+		if !schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		};
+
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
 	/// Iterate through the schedules to track the current locked amount and
 	/// filter out completed and specified schedules.
 	///
@@ -653,13 +738,18 @@ impl<T: Config> Pallet<T> {
 				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
 				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
 
+				let now = T::BlockNumberProvider::current_block_number();
+				ensure!(
+					schedule1.is_mergeable(now) && schedule2.is_mergeable(now),
+					Error::<T>::ScheduleNotMergeable
+				);
+
 				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
 				// Thus we know below that we can push the new merged schedule without error
 				// (assuming initial state was valid).
 				let (mut schedules, mut locked_now) =
 					Self::report_schedule_updates(schedules.to_vec(), action);
 
-				let now = T::BlockNumberProvider::current_block_number();
 				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
 					// Merging created a new schedule so we:
 					// 1) need to add it to the accounts vesting schedule collection,
@@ -723,29 +813,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(())
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		};
-
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::do_add_vesting_schedule(who, VestingInfo::new(locked, per_block, starting_block))
 	}
 
 	/// Ensure we can call `add_vesting_schedule` without error. This should always
@@ -41,7 +41,7 @@ pub mod v1 {
 	pub fn migrate<T: Config>() -> Weight {
 		let mut reads_writes = 0;
 
-		Vesting::<T>::translate::<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
+		Vesting::<T>::translate::<v2::OldVestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
 			|_key, vesting_info| {
 				reads_writes += 1;
 				let v: Option<
@@ -49,7 +49,7 @@ pub mod v1 {
 						VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
 						MaxVestingSchedulesGet<T>,
 					>,
-				> = vec![vesting_info].try_into().ok();
+				> = vec![vesting_info.upgrade()].try_into().ok();
 
 				if v.is_none() {
 					log::warn!(
@@ -94,3 +94,216 @@ pub mod v1 {
 		Ok(())
 	}
 }
+
+// Migration adding the cliff and revocable flag to vesting schedules.
+pub mod v2 {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	/// Layout of `VestingInfo` prior to the addition of `cliff` and `revocable`.
+	#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+	pub struct OldVestingInfo<Balance, BlockNumber> {
+		pub locked: Balance,
+		pub per_block: Balance,
+		pub starting_block: BlockNumber,
+	}
+
+	impl<Balance, BlockNumber> OldVestingInfo<Balance, BlockNumber>
+	where
+		Balance: AtLeast32BitUnsigned + Copy,
+		BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
+	{
+		/// Convert into a schedule without cliff that cannot be revoked.
+		pub fn upgrade(self) -> VestingInfo<Balance, BlockNumber> {
+			VestingInfo::new(self.locked, self.per_block, self.starting_block)
+		}
+	}
+
+	/// Migrate the `Vesting` storage to the `VestingInfo` layout with `cliff` and `revocable`.
+	///
+	/// Existing schedules get no cliff and are not revocable. It only runs while the storage
+	/// version is `V1`, so it does nothing if it is still in the runtime in a later upgrade.
+	pub struct MigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				log::info!(
+					target: "runtime::vesting",
+					"migration: Vesting storage version v2 migration should be removed."
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Vesting::<T>::translate::<
+				BoundedVec<
+					OldVestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+					MaxVestingSchedulesGet<T>,
+				>,
+				_,
+			>(|_key, schedules| {
+				translated.saturating_inc();
+				let schedules: Vec<_> = schedules.into_iter().map(|s| s.upgrade()).collect();
+				// Same bound as before, cannot fail.
+				schedules.try_into().ok()
+			});
+			StorageVersion::<T>::put(Releases::V2);
+
+			log::info!(
+				target: "runtime::vesting",
+				"migration: Vesting storage version v2 migrated {} accounts.",
+				translated,
+			);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let count = Vesting::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let count: u32 = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+			ensure!(StorageVersion::<T>::get() == Releases::V2, "Storage version not updated");
+			ensure!(
+				Vesting::<T>::iter().count() as u32 == count,
+				"Vesting schedules were lost during the migration"
+			);
+			Ok(())
+		}
+	}
+}
+
+// Migration adjusting vesting schedules to a change of the block time.
+pub mod block_time {
+	use super::*;
+	use frame_support::{migrations::BlockTimeChange, traits::OnRuntimeUpgrade};
+
+	/// Rescale the vesting schedules after the block time changed from `OldBlockTime` to
+	/// `NewBlockTime`, both in milliseconds, so that funds keep unlocking at the same pace.
+	///
+	/// Schedules that are vesting already are restarted at the current block with the amount that
+	/// is still locked. For the other ones the distance of `starting_block` and `cliff` to the
+	/// current block is converted to the new block time. `per_block` is scaled accordingly.
+	///
+	/// This is not needed if `BlockNumberProvider` is not affected by the change, e.g. when it
+	/// returns the relay chain block number. It does not touch the storage version, but records
+	/// the new block time with [`BlockTimeChange::mark_applied`]. It does nothing if it is still
+	/// in the runtime in a later upgrade, and should be removed then.
+	pub struct ChangeBlockTime<T, OldBlockTime, NewBlockTime>(
+		PhantomData<(T, OldBlockTime, NewBlockTime)>,
+	);
+
+	impl<T: Config, OldBlockTime: Get<u64>, NewBlockTime: Get<u64>> OnRuntimeUpgrade
+		for ChangeBlockTime<T, OldBlockTime, NewBlockTime>
+	{
+		fn on_runtime_upgrade() -> Weight {
+			let change = BlockTimeChange::from_get::<OldBlockTime, NewBlockTime>();
+			if !change.should_apply::<Pallet<T>>() {
+				log::warn!(
+					target: "runtime::vesting",
+					"migration: Block time did not change or was applied already, ChangeBlockTime \
+					should be removed."
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut translated = 0u64;
+			Vesting::<T>::translate::<
+				BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+				_,
+			>(|_key, schedules| {
+				translated.saturating_inc();
+				let schedules: Vec<_> = schedules
+					.into_iter()
+					.filter_map(|s| rescale_schedule::<T>(s, now, &change))
+					.collect();
+				// Never more schedules than before, cannot fail.
+				let schedules: BoundedVec<_, _> = schedules.try_into().ok()?;
+				(!schedules.is_empty()).then_some(schedules)
+			});
+			change.mark_applied::<Pallet<T>>();
+
+			log::info!(
+				target: "runtime::vesting",
+				"migration: Rescaled vesting schedules of {} accounts from {}ms to {}ms blocks.",
+				translated,
+				change.old,
+				change.new,
+			);
+
+			T::DbWeight::get().reads_writes(translated + 1, translated + 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let now = T::BlockNumberProvider::current_block_number();
+			let locked: Vec<(T::AccountId, BalanceOf<T>)> = Vesting::<T>::iter()
+				.map(|(who, schedules)| (who, locked_at::<T>(&schedules, now)))
+				.collect();
+			Ok(locked.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let locked: Vec<(T::AccountId, BalanceOf<T>)> = Decode::decode(&mut &state[..])
+				.map_err(|_| "Failed to decode the pre-upgrade state")?;
+			let now = T::BlockNumberProvider::current_block_number();
+			for (who, before) in locked {
+				let after = Vesting::<T>::get(&who)
+					.map_or(Zero::zero(), |schedules| locked_at::<T>(&schedules, now));
+				ensure!(after == before, "The amount locked now changed during the migration");
+			}
+			Ok(())
+		}
+	}
+
+	/// Convert a single schedule, returning `None` if nothing is left to vest.
+	fn rescale_schedule<T: Config>(
+		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		now: BlockNumberFor<T>,
+		change: &BlockTimeChange,
+	) -> Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+		let per_block = change.scale_per_block(schedule.raw_per_block());
+		let vesting =
+			schedule.starting_block() < now && schedule.cliff().map_or(true, |c| c <= now);
+
+		let rescaled = if vesting {
+			let locked = schedule.locked_at::<T::BlockNumberToBalance>(now);
+			if locked.is_zero() {
+				return None
+			}
+			VestingInfo::new(locked, per_block, now)
+		} else {
+			// Blocks before a pending cliff keep counting, so move the start back as well.
+			let starting_block = if schedule.starting_block() < now {
+				now.saturating_sub(change.scale_blocks(now - schedule.starting_block()))
+			} else {
+				change.scale_future_block(now, schedule.starting_block())
+			};
+			let rescaled = VestingInfo::new(schedule.locked(), per_block, starting_block);
+			match schedule.cliff() {
+				Some(cliff) => rescaled.with_cliff(change.scale_future_block(now, cliff)),
+				None => rescaled,
+			}
+		};
+
+		Some(if schedule.is_revocable() { rescaled.with_revocable() } else { rescaled })
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn locked_at<T: Config>(
+		schedules: &[VestingInfo<BalanceOf<T>, BlockNumberFor<T>>],
+		now: BlockNumberFor<T>,
+	) -> BalanceOf<T> {
+		schedules.iter().fold(Zero::zero(), |total, s| {
+			total.saturating_add(s.locked_at::<T::BlockNumberToBalance>(now))
+		})
+	}
+}
@@ -1049,9 +1049,9 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
 	});
 }
 
@@ -1236,3 +1236,174 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn cliff_locks_everything_until_reached() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Vesting over 20 blocks starting at block 10, nothing unlocked before block 20.
+		let sched = VestingInfo::new(ED * 20, ED, 10).with_cliff(20);
+		assert_eq!(sched.ending_block_as_balance::<Identity>(), 30);
+		// The cliff ends the schedule if it is past the linear end.
+		let short_sched = VestingInfo::new(ED, ED, 10).with_cliff(20);
+		assert_eq!(short_sched.ending_block_as_balance::<Identity>(), 20);
+
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![sched]);
+
+		System::set_block_number(19);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 20));
+
+		// Everything that vested since the start is unlocked at the cliff.
+		System::set_block_number(20);
+		assert_eq!(Vesting::vesting_balance(&4), Some(ED * 10));
+		assert_ok!(Vesting::vest(Some(4).into()));
+		System::assert_last_event(Event::VestingUpdated { account: 4, unvested: ED * 10 }.into());
+
+		System::set_block_number(30);
+		vest_and_assert_no_vesting::<Test>(4);
+	});
+}
+
+#[test]
+fn revoke_vesting_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let sched = VestingInfo::new(ED * 20, ED, 10).with_cliff(20).with_revocable();
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, sched));
+		assert_eq!(Balances::free_balance(&4), ED * 60);
+
+		// Only `RevokeOrigin` can revoke.
+		assert_noop!(Vesting::revoke_vesting_schedule(Some(4).into(), 4, 0, 13), BadOrigin);
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 4, 1, 13),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		// After the cliff, the vested part stays with the account.
+		System::set_block_number(25);
+		assert_ok!(Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 4, 0, 13));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, destination: 13, unvested: ED * 5 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&4), ED * 55);
+		assert_eq!(Balances::free_balance(&13), ED * 10004);
+		assert_eq!(VestingStorage::<Test>::get(&4), None);
+		assert_eq!(Vesting::vesting_balance(&4), None);
+		// The remaining balance is transferable.
+		assert_ok!(Balances::transfer_allow_death(Some(4).into(), 3, ED * 54));
+	});
+}
+
+#[test]
+fn revoke_vesting_schedule_keeps_other_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		// Account 2 vests 20 * ED over blocks 10 to 30 from genesis.
+		let revocable = VestingInfo::new(ED * 2, ED, 10).with_revocable();
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, revocable));
+
+		// Schedules that are not revocable cannot be revoked.
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 2, 0, 13),
+			Error::<Test>::NotRevocable
+		);
+
+		assert_ok!(Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 2, 1, 13));
+		let genesis_sched = VestingInfo::new(ED * 20, ED, 10);
+		assert_eq!(VestingStorage::<Test>::get(&2).unwrap(), vec![genesis_sched]);
+		assert_eq!(Vesting::vesting_balance(&2), Some(ED * 20));
+		assert_eq!(Balances::free_balance(&13), ED * 10001);
+	});
+}
+
+#[test]
+fn merge_schedules_rejects_revocable_and_cliff_schedules() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let cliff = VestingInfo::new(ED * 2, ED, 10).with_cliff(15);
+		let revocable = VestingInfo::new(ED * 2, ED, 10).with_revocable();
+		// Account 2 already has a schedule from genesis at index 0.
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, cliff));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 2, revocable));
+
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 0, 1),
+			Error::<Test>::ScheduleNotMergeable
+		);
+		assert_noop!(
+			Vesting::merge_schedules(Some(2).into(), 0, 2),
+			Error::<Test>::ScheduleNotMergeable
+		);
+
+		// Once the cliff is reached the schedule can be merged.
+		System::set_block_number(15);
+		assert_ok!(Vesting::merge_schedules(Some(2).into(), 0, 1));
+	});
+}
+
+#[test]
+fn migrate_to_v2_works() {
+	use crate::migrations::v2::{MigrateToV2, OldVestingInfo};
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let _ = VestingStorage::<Test>::clear(u32::MAX, None);
+		let old = OldVestingInfo::<u64, u64> { locked: ED * 5, per_block: ED, starting_block: 10 };
+		frame_support::storage::unhashed::put(
+			&VestingStorage::<Test>::hashed_key_for(&4),
+			&vec![old, old],
+		);
+		StorageVersion::<Test>::put(Releases::V1);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+		let new = VestingInfo::new(ED * 5, ED, 10);
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![new, new]);
+	});
+}
+
+#[test]
+fn change_block_time_works() {
+	use crate::migrations::block_time::ChangeBlockTime;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	frame_support::parameter_types! {
+		pub const Old: u64 = 12_000;
+		pub const New: u64 = 6_000;
+	}
+
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let _ = VestingStorage::<Test>::clear(u32::MAX, None);
+		System::set_block_number(20);
+		// Vesting since block 10, `ED * 10` of `ED * 20` still locked.
+		let vesting = VestingInfo::new(ED * 20, ED, 10);
+		// Starts 10 blocks from now.
+		let future = VestingInfo::new(ED * 20, ED * 2, 30).with_revocable();
+		// Started, but nothing unlocked before the cliff 4 blocks from now.
+		let cliff = VestingInfo::new(ED * 20, ED * 2, 16).with_cliff(24);
+		// Fully vested.
+		let done = VestingInfo::new(ED * 5, ED, 10);
+		VestingStorage::<Test>::insert(
+			4,
+			BoundedVec::try_from(vec![vesting, future, cliff, done]).unwrap(),
+		);
+
+		ChangeBlockTime::<Test, Old, New>::on_runtime_upgrade();
+
+		let schedules = VestingStorage::<Test>::get(&4).unwrap();
+		assert_eq!(
+			schedules.to_vec(),
+			vec![
+				VestingInfo::new(ED * 10, ED / 2, 20),
+				VestingInfo::new(ED * 20, ED, 40).with_revocable(),
+				VestingInfo::new(ED * 20, ED, 12).with_cliff(28),
+			]
+		);
+		// Everything ends at the same time in the new blocks.
+		let ends: Vec<u64> =
+			schedules.iter().map(|s| s.ending_block_as_balance::<Identity>()).collect();
+		assert_eq!(ends, vec![40, 60, 32]);
+
+		// Leaving the migration in the runtime does not apply it again.
+		ChangeBlockTime::<Test, Old, New>::on_runtime_upgrade();
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), schedules);
+	});
+}
@@ -28,6 +28,11 @@ pub struct VestingInfo<Balance, BlockNumber> {
 	per_block: Balance,
 	/// Starting block for unlocking(vesting).
 	starting_block: BlockNumber,
+	/// Block before which nothing is unlocked, if any. Once reached, everything that vested since
+	/// `starting_block` is unlocked at once.
+	cliff: Option<BlockNumber>,
+	/// Whether the unvested funds may be taken back by `RevokeOrigin`.
+	revocable: bool,
 }
 
 impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
@@ -41,7 +46,19 @@ where
 		per_block: Balance,
 		starting_block: BlockNumber,
 	) -> VestingInfo<Balance, BlockNumber> {
-		VestingInfo { locked, per_block, starting_block }
+		VestingInfo { locked, per_block, starting_block, cliff: None, revocable: false }
+	}
+
+	/// Set the block before which nothing is unlocked.
+	pub fn with_cliff(mut self, cliff: BlockNumber) -> Self {
+		self.cliff = Some(cliff);
+		self
+	}
+
+	/// Make the schedule revocable by `RevokeOrigin`.
+	pub fn with_revocable(mut self) -> Self {
+		self.revocable = true;
+		self
 	}
 
 	/// Validate parameters for `VestingInfo`. Note that this does not check
@@ -73,11 +90,31 @@ where
 		self.starting_block
 	}
 
+	/// Block before which nothing is unlocked, if any.
+	pub fn cliff(&self) -> Option<BlockNumber> {
+		self.cliff
+	}
+
+	/// Whether the unvested funds may be taken back by `RevokeOrigin`.
+	pub fn is_revocable(&self) -> bool {
+		self.revocable
+	}
+
+	/// Whether the schedule may be merged with another one at block `n`. Revocable schedules and
+	/// schedules before their cliff cannot be merged, as the merged schedule would lose these.
+	pub(crate) fn is_mergeable(&self, n: BlockNumber) -> bool {
+		!self.revocable && self.cliff.map_or(true, |cliff| n >= cliff)
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
 		&self,
 		n: BlockNumber,
 	) -> Balance {
+		// Nothing is unlocked before the cliff.
+		if self.cliff.map_or(false, |cliff| n < cliff) {
+			return self.locked
+		}
 		// Number of blocks that count toward vesting;
 		// saturating to 0 when n < starting_block.
 		let vested_block_count = n.saturating_sub(self.starting_block);
@@ -109,6 +146,10 @@ where
 				}
 		};
 
-		starting_block.saturating_add(duration)
+		let ending_block = starting_block.saturating_add(duration);
+		match self.cliff {
+			Some(cliff) => ending_block.max(BlockNumberToBalance::convert(cliff)),
+			None => ending_block,
+		}
 	}
 }
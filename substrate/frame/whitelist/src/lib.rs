@@ -120,6 +120,7 @@ pub mod pallet {
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::whitelist_call())]
+		#[pallet::storage_access(reads(WhitelistedCall), writes(WhitelistedCall))]
 		pub fn whitelist_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
 
@@ -138,6 +139,7 @@ pub mod pallet {
 
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::remove_whitelisted_call())]
+		#[pallet::storage_access(reads(WhitelistedCall), writes(WhitelistedCall))]
 		pub fn remove_whitelisted_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
 
@@ -155,6 +157,7 @@ pub mod pallet {
 			T::WeightInfo::dispatch_whitelisted_call(*call_encoded_len)
 				.saturating_add(*call_weight_witness)
 		)]
+		#[pallet::storage_access(reads(WhitelistedCall), writes(WhitelistedCall))]
 		pub fn dispatch_whitelisted_call(
 			origin: OriginFor<T>,
 			call_hash: T::Hash,
@@ -197,6 +200,7 @@ pub mod pallet {
 			T::WeightInfo::dispatch_whitelisted_call_with_preimage(call_len)
 				.saturating_add(call_weight)
 		})]
+		#[pallet::storage_access(reads(WhitelistedCall), writes(WhitelistedCall))]
 		pub fn dispatch_whitelisted_call_with_preimage(
 			origin: OriginFor<T>,
 			call: Box<<T as Config>::RuntimeCall>,
@@ -223,3 +223,50 @@ fn test_whitelist_call_and_execute_decode_consumes_all() {
 		);
 	});
 }
+
+#[test]
+fn call_weights_metadata() {
+	use sp_metadata_ir::CallWeightIR;
+
+	let weights = Whitelist::call_functions().weights;
+	let names = weights.iter().map(|w| w.name).collect::<Vec<_>>();
+	assert_eq!(
+		names,
+		[
+			"whitelist_call",
+			"remove_whitelisted_call",
+			"dispatch_whitelisted_call",
+			"dispatch_whitelisted_call_with_preimage"
+		]
+	);
+	assert!(weights
+		.iter()
+		.all(|w| w.reads == ["WhitelistedCall"] && w.writes == ["WhitelistedCall"]));
+
+	assert_eq!(
+		weights[0].weight,
+		CallWeightIR::Benchmarked {
+			function: "whitelist_call",
+			components: vec![],
+			adjusted: false
+		}
+	);
+	// The witness is added on top of the benchmarked weight.
+	assert_eq!(
+		weights[2].weight,
+		CallWeightIR::Benchmarked {
+			function: "dispatch_whitelisted_call",
+			components: vec![vec!["call_encoded_len"]],
+			adjusted: true
+		}
+	);
+	// The length of the call is not a parameter of the call.
+	assert_eq!(
+		weights[3].weight,
+		CallWeightIR::Benchmarked {
+			function: "dispatch_whitelisted_call_with_preimage",
+			components: vec![vec![]],
+			adjusted: true
+		}
+	);
+}
@@ -146,6 +146,32 @@ pub struct ScheduledChange<N> {
 	pub delay: N,
 }
 
+/// A change of authority set that was signaled and is not enacted yet.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingChange<N> {
+	/// The block number at which the change was signaled.
+	pub scheduled_at: N,
+	/// The change, enacted `delay` blocks after `scheduled_at`.
+	pub change: ScheduledChange<N>,
+	/// If defined the change is forced, and the new authorities vote on top of the given median
+	/// last finalized block number.
+	pub forced: Option<N>,
+}
+
+/// The state of the authority set handoffs, used to diagnose stalled finality.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct AuthoritySetDiagnostics<N> {
+	/// The id of the current authority set.
+	pub set_id: SetId,
+	/// The change of authority set that is pending, if any.
+	pub pending_change: Option<PendingChange<N>>,
+	/// The block number from which another forced change can be signaled, if any.
+	pub next_forced: Option<N>,
+	/// The `(delay, median last finalized block number)` of the forced change that was noted to
+	/// be signaled at the start of the next session because finality is stalled, if any.
+	pub stalled: Option<(N, N)>,
+}
+
 /// An consensus log item for GRANDPA.
 #[derive(Decode, Encode, PartialEq, Eq, Clone, RuntimeDebug)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -544,5 +570,10 @@ sp_api::decl_runtime_apis! {
 
 		/// Get current GRANDPA authority set id.
 		fn current_set_id() -> SetId;
+
+		/// Get the state of the authority set handoffs: the pending change, when the next forced
+		/// change can be signaled, and the forced change noted because finality is stalled.
+		#[api_version(4)]
+		fn authority_set_diagnostics() -> AuthoritySetDiagnostics<NumberFor<Block>>;
 	}
 }
@@ -93,6 +93,16 @@ impl From<StorageKind> for u32 {
 	}
 }
 
+/// Additional parameters of an offchain http request, passed SCALE encoded as the `meta` of
+/// [`Externalities::http_request_start`].
+///
+/// An empty or undecodable `meta` stands for the default parameters.
+#[derive(Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, Encode, Decode)]
+pub struct HttpRequestMeta {
+	/// Decode the body of the response while it is read if it is gzip encoded.
+	pub decode_gzip: bool,
+}
+
 /// Opaque type for offchain http requests.
 #[derive(
 	Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, Encode, Decode, PassByInner,
@@ -469,6 +469,15 @@ sp_api::decl_runtime_apis! {
 		/// same position in both the `leaves` vector and the `leaf_indices` vector contained in the [LeafProof]
 		fn verify_proof_stateless(root: Hash, leaves: Vec<EncodableOpaqueLeaf>, proof: LeafProof<Hash>)
 			-> Result<(), Error>;
+
+		/// Generate a proof that the MMR at `prev_block_number` is an ancestor of the MMR at
+		/// `best_known_block_number`. If `best_known_block_number` is `None`, use the current MMR
+		/// state.
+		#[api_version(3)]
+		fn generate_ancestry_proof(
+			prev_block_number: BlockNumber,
+			best_known_block_number: Option<BlockNumber>
+		) -> Result<AncestryProof<Hash>, Error>;
 	}
 }
 
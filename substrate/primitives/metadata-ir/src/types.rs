@@ -15,11 +15,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use codec::{Compact, Encode};
+use codec::{Compact, Decode, Encode};
 use scale_info::{
 	form::{Form, MetaForm, PortableForm},
-	prelude::{collections::BTreeMap, vec::Vec},
-	IntoPortable, Registry,
+	meta_type,
+	prelude::{collections::BTreeMap, string::String, vec::Vec},
+	IntoPortable, MetaType, Registry, TypeInfo,
 };
 
 /// The intermediate representation for the runtime metadata.
@@ -372,6 +373,8 @@ pub struct PalletCallMetadataIR<T: Form = MetaForm> {
 	pub ty: T::Type,
 	/// Deprecation status of the pallet call
 	pub deprecation_info: DeprecationInfoIR<T>,
+	/// Declared weight and storage access of each call.
+	pub weights: Vec<CallWeightMetadataIR<T>>,
 }
 
 impl IntoPortable for PalletCallMetadataIR {
@@ -381,10 +384,187 @@ impl IntoPortable for PalletCallMetadataIR {
 		PalletCallMetadataIR {
 			ty: registry.register_type(&self.ty),
 			deprecation_info: self.deprecation_info.into_portable(registry),
+			weights: registry.map_into_portable(self.weights),
 		}
 	}
 }
 
+/// Metadata about the declared weight and storage access of a call.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub struct CallWeightMetadataIR<T: Form = MetaForm> {
+	/// Name of the call.
+	pub name: T::String,
+	/// How the weight of the call is computed, derived from its `#[pallet::weight]` attribute
+	/// or the weight function inherited from `#[pallet::call(weight = ..)]`.
+	pub weight: CallWeightIR<T>,
+	/// Names of the storage items the call declares to read.
+	pub reads: Vec<T::String>,
+	/// Names of the storage items the call declares to write.
+	pub writes: Vec<T::String>,
+}
+
+impl IntoPortable for CallWeightMetadataIR {
+	type Output = CallWeightMetadataIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		CallWeightMetadataIR {
+			name: self.name.into_portable(registry),
+			weight: self.weight.into_portable(registry),
+			reads: registry.map_into_portable(self.reads),
+			writes: registry.map_into_portable(self.writes),
+		}
+	}
+}
+
+/// How the weight of a call is computed.
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub enum CallWeightIR<T: Form = MetaForm> {
+	/// A constant weight.
+	Fixed {
+		/// The `ref_time` of the weight.
+		ref_time: u64,
+		/// The `proof_size` of the weight.
+		proof_size: u64,
+	},
+	/// A benchmarked function of the `WeightInfo` of the pallet.
+	Benchmarked {
+		/// Name of the `WeightInfo` function.
+		function: T::String,
+		/// For each argument of the function, the call parameters it is computed from.
+		components: Vec<Vec<T::String>>,
+		/// Whether other terms, such as a weight witness, are added to the benchmarked weight.
+		adjusted: bool,
+	},
+	/// Any other weight expression.
+	Custom {
+		/// The call parameters the weight is computed from.
+		params: Vec<T::String>,
+	},
+}
+
+impl IntoPortable for CallWeightIR {
+	type Output = CallWeightIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		match self {
+			Self::Fixed { ref_time, proof_size } => CallWeightIR::Fixed { ref_time, proof_size },
+			Self::Benchmarked { function, components, adjusted } => CallWeightIR::Benchmarked {
+				function: function.into_portable(registry),
+				components: components
+					.into_iter()
+					.map(|params| registry.map_into_portable(params))
+					.collect(),
+				adjusted,
+			},
+			Self::Custom { params } =>
+				CallWeightIR::Custom { params: registry.map_into_portable(params) },
+		}
+	}
+}
+
+/// Key of the custom metadata entry holding the [`PalletCallWeights`] of all pallets.
+pub const CALL_WEIGHTS_CUSTOM_KEY: &str = "call_weights";
+
+/// The declared weight and storage access of the calls of a pallet.
+///
+/// The call metadata of V15 and later has no room for these, so they are exposed as a
+/// `Vec<PalletCallWeights>` under [`CALL_WEIGHTS_CUSTOM_KEY`] in the custom metadata.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub struct PalletCallWeights {
+	/// Pallet name.
+	pub pallet: String,
+	/// Pallet index.
+	pub index: u8,
+	/// Declared weight and storage access of each call of the pallet.
+	pub calls: Vec<CallWeight>,
+}
+
+/// The declared weight and storage access of a call, see [`CallWeightMetadataIR`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub struct CallWeight {
+	/// Name of the call.
+	pub name: String,
+	/// How the weight of the call is computed.
+	pub weight: WeightFormula,
+	/// Names of the storage items the call declares to read.
+	pub reads: Vec<String>,
+	/// Names of the storage items the call declares to write.
+	pub writes: Vec<String>,
+}
+
+/// How the weight of a call is computed, see [`CallWeightIR`].
+#[derive(Clone, PartialEq, Eq, Encode, Decode, Debug, TypeInfo)]
+pub enum WeightFormula {
+	/// A constant weight.
+	Fixed {
+		/// The `ref_time` of the weight.
+		ref_time: u64,
+		/// The `proof_size` of the weight.
+		proof_size: u64,
+	},
+	/// A benchmarked function of the `WeightInfo` of the pallet.
+	Benchmarked {
+		/// Name of the `WeightInfo` function.
+		function: String,
+		/// For each argument of the function, the call parameters it is computed from.
+		components: Vec<Vec<String>>,
+		/// Whether other terms, such as a weight witness, are added to the benchmarked weight.
+		adjusted: bool,
+	},
+	/// Any other weight expression.
+	Custom {
+		/// The call parameters the weight is computed from.
+		params: Vec<String>,
+	},
+}
+
+impl From<&CallWeightIR> for WeightFormula {
+	fn from(weight: &CallWeightIR) -> Self {
+		let strings = |items: &Vec<&'static str>| -> Vec<String> {
+			items.iter().map(|item| (*item).into()).collect()
+		};
+		match weight {
+			CallWeightIR::Fixed { ref_time, proof_size } =>
+				Self::Fixed { ref_time: *ref_time, proof_size: *proof_size },
+			CallWeightIR::Benchmarked { function, components, adjusted } => Self::Benchmarked {
+				function: (*function).into(),
+				components: components.iter().map(strings).collect(),
+				adjusted: *adjusted,
+			},
+			CallWeightIR::Custom { params } => Self::Custom { params: strings(params) },
+		}
+	}
+}
+
+impl MetadataIR {
+	/// The type and value of the [`CALL_WEIGHTS_CUSTOM_KEY`] custom metadata entry, if any pallet
+	/// has calls.
+	pub(crate) fn call_weights(&self) -> Option<(MetaType, Vec<u8>)> {
+		let pallets = self
+			.pallets
+			.iter()
+			.filter_map(|pallet| {
+				let calls = pallet.calls.as_ref()?;
+				let calls = calls
+					.weights
+					.iter()
+					.map(|call| CallWeight {
+						name: call.name.into(),
+						weight: (&call.weight).into(),
+						reads: call.reads.iter().map(|item| (*item).into()).collect(),
+						writes: call.writes.iter().map(|item| (*item).into()).collect(),
+					})
+					.collect();
+				Some(PalletCallWeights { pallet: pallet.name.into(), index: pallet.index, calls })
+			})
+			.collect::<Vec<_>>();
+		if pallets.is_empty() {
+			return None;
+		}
+		Some((meta_type::<Vec<PalletCallWeights>>(), pallets.encode()))
+	}
+}
+
 /// Metadata about the pallet Event type.
 #[derive(Clone, PartialEq, Eq, Encode, Debug)]
 pub struct PalletEventMetadataIR<T: Form = MetaForm> {
@@ -20,7 +20,7 @@
 use crate::{
 	DeprecationInfoIR, DeprecationStatusIR, OuterEnumsIR, PalletAssociatedTypeMetadataIR,
 	PalletCallMetadataIR, PalletConstantMetadataIR, PalletErrorMetadataIR, PalletEventMetadataIR,
-	PalletStorageMetadataIR, StorageEntryMetadataIR,
+	PalletStorageMetadataIR, StorageEntryMetadataIR, CALL_WEIGHTS_CUSTOM_KEY,
 };
 
 use super::types::{
@@ -29,23 +29,27 @@ use super::types::{
 };
 
 use frame_metadata::v16::{
-	CustomMetadata, DeprecationInfo, DeprecationStatus, ExtrinsicMetadata, OuterEnums,
-	PalletAssociatedTypeMetadata, PalletCallMetadata, PalletConstantMetadata, PalletErrorMetadata,
-	PalletEventMetadata, PalletMetadata, PalletStorageMetadata, RuntimeApiMetadata,
-	RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata, RuntimeMetadataV16,
-	StorageEntryMetadata, TransactionExtensionMetadata,
+	CustomMetadata, CustomValueMetadata, DeprecationInfo, DeprecationStatus, ExtrinsicMetadata,
+	OuterEnums, PalletAssociatedTypeMetadata, PalletCallMetadata, PalletConstantMetadata,
+	PalletErrorMetadata, PalletEventMetadata, PalletMetadata, PalletStorageMetadata,
+	RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata,
+	RuntimeMetadataV16, StorageEntryMetadata, TransactionExtensionMetadata,
 };
 
 impl From<MetadataIR> for RuntimeMetadataV16 {
 	fn from(ir: MetadataIR) -> Self {
+		// Substrate only collects the call weights as custom metadata.
+		let custom = ir
+			.call_weights()
+			.map(|(ty, value)| (CALL_WEIGHTS_CUSTOM_KEY, CustomValueMetadata { ty, value }))
+			.into_iter()
+			.collect();
 		RuntimeMetadataV16::new(
 			ir.pallets.into_iter().map(Into::into).collect(),
 			ir.extrinsic.into(),
 			ir.apis.into_iter().map(Into::into).collect(),
 			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V16 easily.
-			CustomMetadata { map: Default::default() },
+			CustomMetadata { map: custom },
 		)
 	}
 }
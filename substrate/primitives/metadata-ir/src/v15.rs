@@ -17,7 +17,7 @@
 
 //! Convert the IR to V15 metadata.
 
-use crate::OuterEnumsIR;
+use crate::{OuterEnumsIR, CALL_WEIGHTS_CUSTOM_KEY};
 
 use super::types::{
 	ExtrinsicMetadataIR, MetadataIR, PalletMetadataIR, RuntimeApiMetadataIR,
@@ -25,22 +25,26 @@ use super::types::{
 };
 
 use frame_metadata::v15::{
-	CustomMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata, RuntimeApiMetadata,
-	RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata, RuntimeMetadataV15,
-	SignedExtensionMetadata,
+	CustomMetadata, CustomValueMetadata, ExtrinsicMetadata, OuterEnums, PalletMetadata,
+	RuntimeApiMetadata, RuntimeApiMethodMetadata, RuntimeApiMethodParamMetadata,
+	RuntimeMetadataV15, SignedExtensionMetadata,
 };
 
 impl From<MetadataIR> for RuntimeMetadataV15 {
 	fn from(ir: MetadataIR) -> Self {
+		// Substrate only collects the call weights as custom metadata.
+		let custom = ir
+			.call_weights()
+			.map(|(ty, value)| (CALL_WEIGHTS_CUSTOM_KEY, CustomValueMetadata { ty, value }))
+			.into_iter()
+			.collect();
 		RuntimeMetadataV15::new(
 			ir.pallets.into_iter().map(Into::into).collect(),
 			ir.extrinsic.into(),
 			ir.ty,
 			ir.apis.into_iter().map(Into::into).collect(),
 			ir.outer_enums.into(),
-			// Substrate does not collect yet the custom metadata fields.
-			// This allows us to extend the V15 easily.
-			CustomMetadata { map: Default::default() },
+			CustomMetadata { map: custom },
 		)
 	}
 }
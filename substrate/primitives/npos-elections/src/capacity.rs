@@ -0,0 +1,142 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capacity limits of the targets.
+//!
+//! The capacity of a target is the maximum backing it may receive in a solution, e.g. a cap on the
+//! stake behind a validator. A target without capacity may receive any backing.
+//!
+//! - [`enforce_capacity`] bends the outcome of an election algorithm to respect the capacity of all
+//!   the targets. See [`crate::seq_phragmen_capped`].
+//! - [`check_capacity`] checks that a solution respects the capacity of all the targets.
+
+use crate::{CandidatePtr, Error, ExtendedBalance, IdentifierT, Support, Voter};
+use alloc::{rc::Rc, vec::Vec};
+use sp_arithmetic::traits::{Bounded, Zero};
+
+/// Make the backing of each elected candidate fit into its `capacity`.
+///
+/// This is done in two steps:
+///
+/// 1. The excess backing that each voter gives to a candidate is moved to the other elected
+///    candidates of that voter, as far as their own capacity allows.
+/// 2. If a candidate is still above its capacity, its voters are left out, the ones with the least
+///    stake first, until it is not. All the edges of these voters are removed, so they do not show
+///    up in the assignments anymore.
+///
+/// Returns the number of voters that are left out.
+///
+/// The edge weights of the remaining voters still sum up to their budget. Note that an elected
+/// candidate might end up with no backing, if all of its voters are left out.
+pub fn enforce_capacity<AccountId: IdentifierT>(
+	voters: &mut Vec<Voter<AccountId>>,
+	capacity: impl Fn(&AccountId) -> Option<ExtendedBalance>,
+) -> usize {
+	let excess_of = |c_ptr: &CandidatePtr<AccountId>| -> ExtendedBalance {
+		let candidate = c_ptr.borrow();
+		capacity(&candidate.who)
+			.map_or(Zero::zero(), |cap| candidate.backed_stake.saturating_sub(cap))
+	};
+	let room_of = |c_ptr: &CandidatePtr<AccountId>| -> ExtendedBalance {
+		let candidate = c_ptr.borrow();
+		if !candidate.elected {
+			return Zero::zero()
+		}
+		capacity(&candidate.who)
+			.map_or(Bounded::max_value(), |cap| cap.saturating_sub(candidate.backed_stake))
+	};
+
+	// step 1: move the excess to the other elected candidates of each voter.
+	for voter in voters.iter_mut() {
+		for i in 0..voter.edges.len() {
+			let mut excess = excess_of(&voter.edges[i].candidate).min(voter.edges[i].weight);
+			for j in 0..voter.edges.len() {
+				if excess.is_zero() {
+					break
+				}
+				if i == j {
+					continue
+				}
+				let moved = room_of(&voter.edges[j].candidate).min(excess);
+				if moved.is_zero() {
+					continue
+				}
+
+				voter.edges[i].weight = voter.edges[i].weight.saturating_sub(moved);
+				voter.edges[j].weight = voter.edges[j].weight.saturating_add(moved);
+				let mut from = voter.edges[i].candidate.borrow_mut();
+				let mut to = voter.edges[j].candidate.borrow_mut();
+				from.backed_stake = from.backed_stake.saturating_sub(moved);
+				to.backed_stake = to.backed_stake.saturating_add(moved);
+				excess = excess.saturating_sub(moved);
+			}
+		}
+	}
+
+	// step 2: leave out the voters of the candidates that are still above their capacity.
+	let mut over_capacity: Vec<CandidatePtr<AccountId>> = Vec::new();
+	for edge in voters.iter().flat_map(|v| v.edges.iter()) {
+		if !excess_of(&edge.candidate).is_zero() &&
+			!over_capacity.iter().any(|c_ptr| Rc::ptr_eq(c_ptr, &edge.candidate))
+		{
+			over_capacity.push(Rc::clone(&edge.candidate));
+		}
+	}
+
+	let mut left_out = 0;
+	for c_ptr in over_capacity {
+		let who = c_ptr.borrow().who.clone();
+		let mut backers = voters
+			.iter()
+			.enumerate()
+			.filter(|(_, v)| v.votes_for(&who))
+			.map(|(index, v)| (v.budget, index))
+			.collect::<Vec<_>>();
+		backers.sort();
+
+		for (_, index) in backers {
+			if excess_of(&c_ptr).is_zero() {
+				break
+			}
+			for edge in core::mem::take(&mut voters[index].edges) {
+				let mut candidate = edge.candidate.borrow_mut();
+				candidate.backed_stake = candidate.backed_stake.saturating_sub(edge.weight);
+			}
+			left_out += 1;
+		}
+	}
+
+	left_out
+}
+
+/// Check that the total backing of each target in `supports` fits into its `capacity`.
+///
+/// ### Errors
+///
+/// Returns [`Error::TargetCapacityExceeded`] for the first target that is above its capacity.
+pub fn check_capacity<AccountId>(
+	supports: &[(AccountId, Support<AccountId>)],
+	capacity: impl Fn(&AccountId) -> Option<ExtendedBalance>,
+) -> Result<(), Error> {
+	supports.iter().try_for_each(|(who, support)| {
+		if capacity(who).map_or(false, |cap| support.total > cap) {
+			Err(Error::TargetCapacityExceeded)
+		} else {
+			Ok(())
+		}
+	})
+}
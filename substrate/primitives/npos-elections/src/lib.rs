@@ -24,6 +24,8 @@
 //!   similar to that of the MMS algorithm.
 //! - [`balance`]: Implements the star balancing algorithm. This iterative process can push a
 //!   solution toward being more "balanced", which in turn can increase its score.
+//! - [`seq_phragmen_capped`]: Same as [`seq_phragmen`], but the backing of each winner is kept
+//!   within its capacity, e.g. a cap on the stake behind a validator. See [`capacity`].
 //!
 //! ### Terminology
 //!
@@ -92,6 +94,7 @@ mod tests;
 
 mod assignments;
 pub mod balancing;
+pub mod capacity;
 pub mod helpers;
 pub mod node;
 pub mod phragmen;
@@ -102,6 +105,7 @@ pub mod traits;
 
 pub use assignments::{Assignment, StakedAssignment};
 pub use balancing::*;
+pub use capacity::*;
 pub use helpers::*;
 pub use phragmen::*;
 pub use phragmms::*;
@@ -127,6 +131,8 @@ pub enum Error {
 	InvalidSupportEdge,
 	/// The number of voters is bigger than the `MaxVoters` bound.
 	TooManyVoters,
+	/// The backing of a target is above its capacity.
+	TargetCapacityExceeded,
 }
 
 /// A type which is used in the API of this crate as a numeric weight of a vote, most often the
@@ -21,8 +21,8 @@
 //! to the Maximin problem.
 
 use crate::{
-	balancing, setup_inputs, BalancingConfig, CandidatePtr, ElectionResult, ExtendedBalance,
-	IdentifierT, PerThing128, VoteWeight, Voter,
+	balancing, capacity, setup_inputs, BalancingConfig, CandidatePtr, ElectionResult,
+	ExtendedBalance, IdentifierT, PerThing128, VoteWeight, Voter,
 };
 use alloc::vec::Vec;
 use sp_arithmetic::{
@@ -72,6 +72,27 @@ pub fn seq_phragmen<AccountId: IdentifierT, P: PerThing128>(
 	candidates: Vec<AccountId>,
 	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
 	balancing: Option<BalancingConfig>,
+) -> Result<ElectionResult<AccountId, P>, crate::Error> {
+	seq_phragmen_capped(to_elect, candidates, voters, |_: &AccountId| None, balancing)
+}
+
+/// Same as [`seq_phragmen`], except that the backing of each winner is kept within its
+/// `capacity`. Targets for which `capacity` returns `None` may receive any backing.
+///
+/// Once elected, and balanced if `balancing` is `Some(_)`, the excess backing of the winners is
+/// moved to the other winners of their voters, as far as their capacity allows. The voters whose
+/// stake still does not fit are left out of the assignments, the ones with the least stake first.
+/// See [`capacity::enforce_capacity`] for more info.
+///
+/// Note that a winner might end up with no backing, if all of its voters are left out, and that
+/// the conversion of the edge weights into `P` might round the backing of a winner slightly above
+/// its capacity. Use [`capacity::check_capacity`] on the final supports.
+pub fn seq_phragmen_capped<AccountId: IdentifierT, P: PerThing128>(
+	to_elect: usize,
+	candidates: Vec<AccountId>,
+	voters: Vec<(AccountId, VoteWeight, impl IntoIterator<Item = AccountId>)>,
+	capacity: impl Fn(&AccountId) -> Option<ExtendedBalance>,
+	balancing: Option<BalancingConfig>,
 ) -> Result<ElectionResult<AccountId, P>, crate::Error> {
 	let (candidates, voters) = setup_inputs(candidates, voters);
 
@@ -83,6 +104,8 @@ pub fn seq_phragmen<AccountId: IdentifierT, P: PerThing128>(
 		let _iters = balancing::balance::<AccountId>(&mut voters, config);
 	}
 
+	let _left_out = capacity::enforce_capacity::<AccountId>(&mut voters, capacity);
+
 	let mut winners = candidates
 		.into_iter()
 		.filter(|c_ptr| c_ptr.borrow().elected)
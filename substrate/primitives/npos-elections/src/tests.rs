@@ -18,8 +18,9 @@
 //! Tests for npos-elections.
 
 use crate::{
-	balancing, helpers::*, mock::*, seq_phragmen, seq_phragmen_core, setup_inputs, to_support_map,
-	Assignment, BalancingConfig, ElectionResult, ExtendedBalance, StakedAssignment, Support, Voter,
+	balancing, check_capacity, helpers::*, mock::*, seq_phragmen, seq_phragmen_capped,
+	seq_phragmen_core, setup_inputs, to_support_map, to_supports, Assignment, BalancingConfig,
+	ElectionResult, Error, ExtendedBalance, StakedAssignment, Support, Voter,
 };
 use sp_arithmetic::{PerU16, Perbill, Percent, Permill};
 use substrate_test_utils::assert_eq_uvec;
@@ -333,6 +334,78 @@ fn phragmen_poc_works_with_balancing() {
 	);
 }
 
+#[test]
+fn phragmen_capped_moves_excess_backing() {
+	let candidates = vec![1, 2, 3];
+	let voters = vec![(10, vec![1, 2]), (20, vec![1, 3]), (30, vec![2, 3])];
+
+	let stake_of = create_stake_of(&[(10, 10), (20, 20), (30, 30)]);
+	let ElectionResult::<_, Perbill> { winners, assignments } = seq_phragmen_capped(
+		2,
+		candidates,
+		voters
+			.iter()
+			.map(|(ref v, ref vs)| (*v, stake_of(v), vs.clone()))
+			.collect::<Vec<_>>(),
+		|_| Some(30),
+		None,
+	)
+	.unwrap();
+
+	// without capacity, the winners are backed by 25 and 35.
+	assert_eq_uvec!(winners, vec![(2, 30), (3, 30)]);
+	assert_eq_uvec!(
+		assignments,
+		vec![
+			Assignment { who: 10u64, distribution: vec![(2, Perbill::from_percent(100))] },
+			Assignment { who: 20, distribution: vec![(3, Perbill::from_percent(100))] },
+			Assignment {
+				who: 30,
+				distribution: vec![
+					(2, Perbill::from_parts(666666666)),
+					(3, Perbill::from_parts(333333334)),
+				],
+			},
+		]
+	);
+
+	let staked = assignment_ratio_to_staked(assignments, &stake_of);
+	assert_eq!(check_capacity(&to_supports::<AccountId>(&staked), |_| Some(30)), Ok(()));
+	assert_eq!(
+		check_capacity(&to_supports::<AccountId>(&staked), |_| Some(29)),
+		Err(Error::TargetCapacityExceeded)
+	);
+}
+
+#[test]
+fn phragmen_capped_leaves_out_voters() {
+	let candidates = vec![1, 2, 3];
+	let voters = vec![(10, vec![1, 2]), (20, vec![1, 3]), (30, vec![2, 3])];
+
+	let stake_of = create_stake_of(&[(10, 10), (20, 20), (30, 30)]);
+	let ElectionResult::<_, Perbill> { winners, assignments } = seq_phragmen_capped(
+		2,
+		candidates,
+		voters
+			.iter()
+			.map(|(ref v, ref vs)| (*v, stake_of(v), vs.clone()))
+			.collect::<Vec<_>>(),
+		|who| if *who == 1 { None } else { Some(20) },
+		None,
+	)
+	.unwrap();
+
+	// the excess cannot be moved, 10 and 20 are left out as they have the least stake.
+	assert_eq_uvec!(winners, vec![(2, 15), (3, 15)]);
+	assert_eq!(
+		assignments,
+		vec![Assignment {
+			who: 30,
+			distribution: vec![(2, Perbill::from_percent(50)), (3, Perbill::from_percent(50))],
+		}]
+	);
+}
+
 #[test]
 fn phragmen_poc_2_works() {
 	let candidates = vec![10, 20, 30];
@@ -47,11 +47,18 @@
 //! assert_eq!(body.clone().collect::<Vec<_>>(), b"1234".to_vec());
 //! assert_eq!(body.error(), &None);
 //! ```
+//!
+//! Large bodies can be read in chunks with [`ResponseBody::read`] instead, so that they never
+//! have to be kept in memory entirely. Requests built with [`Request::decode_gzip`] have their
+//! gzip encoded responses decoded by the node. Nodes may limit the size of the responses they
+//! read.
 
 use alloc::{str, vec, vec::Vec};
+use codec::Encode;
 use sp_core::{
 	offchain::{
-		HttpError, HttpRequestId as RequestId, HttpRequestStatus as RequestStatus, Timestamp,
+		HttpError, HttpRequestId as RequestId, HttpRequestMeta, HttpRequestStatus as RequestStatus,
+		Timestamp,
 	},
 	RuntimeDebug,
 };
@@ -133,6 +140,8 @@ pub struct Request<'a, T = Vec<&'static [u8]>> {
 	pub deadline: Option<Timestamp>,
 	/// Request list of headers.
 	headers: Vec<header::Header>,
+	/// Whether the node decodes a gzip encoded response.
+	decode_gzip: bool,
 }
 
 impl<T: Default> Default for Request<'static, T> {
@@ -143,6 +152,7 @@ impl<T: Default> Default for Request<'static, T> {
 			headers: Vec::new(),
 			body: Default::default(),
 			deadline: None,
+			decode_gzip: false,
 		}
 	}
 }
@@ -159,7 +169,14 @@ impl<'a, T> Request<'a, T> {
 	pub fn post(url: &'a str, body: T) -> Self {
 		let req: Request = Request::default();
 
-		Request { url, body, method: Method::Post, headers: req.headers, deadline: req.deadline }
+		Request {
+			url,
+			body,
+			method: Method::Post,
+			headers: req.headers,
+			deadline: req.deadline,
+			decode_gzip: req.decode_gzip,
+		}
 	}
 }
 
@@ -198,6 +215,15 @@ impl<'a, T: Default> Request<'a, T> {
 		self.deadline = Some(deadline);
 		self
 	}
+
+	/// Ask the node to decode the response if it is gzip encoded.
+	///
+	/// The response is then read decoded, without its `Content-Encoding` and `Content-Length`
+	/// headers. Use together with an `Accept-Encoding: gzip` header.
+	pub fn decode_gzip(mut self) -> Self {
+		self.decode_gzip = true;
+		self
+	}
 }
 
 impl<'a, I: AsRef<[u8]>, T: IntoIterator<Item = I>> Request<'a, T> {
@@ -206,10 +232,15 @@ impl<'a, I: AsRef<[u8]>, T: IntoIterator<Item = I>> Request<'a, T> {
 	/// Err is returned in case the deadline is reached
 	/// or the request timeouts.
 	pub fn send(self) -> Result<PendingRequest, HttpError> {
-		let meta = &[];
+		// default parameters are left empty, for nodes that don't know about them
+		let meta = if self.decode_gzip {
+			HttpRequestMeta { decode_gzip: true }.encode()
+		} else {
+			Vec::new()
+		};
 
 		// start an http request.
-		let id = sp_io::offchain::http_request_start(self.method.as_ref(), self.url, meta)
+		let id = sp_io::offchain::http_request_start(self.method.as_ref(), self.url, &meta)
 			.map_err(|_| HttpError::IoError)?;
 
 		// add custom headers
@@ -395,6 +426,35 @@ impl ResponseBody {
 	pub fn error(&self) -> &Option<HttpError> {
 		&self.error
 	}
+
+	/// Read the next part of the body into `buffer`, and return its length.
+	///
+	/// Unlike iterating the body byte by byte, this reads as much as `buffer` can hold at once.
+	/// Returns `Ok(0)` once the whole body has been read. Errors are the same as the ones
+	/// returned by [`Self::error`] when iterating.
+	pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, HttpError> {
+		if let Some(error) = self.error {
+			return Err(error)
+		}
+
+		// first return what has been buffered while iterating.
+		if let Some(filled_up_to) = self.filled_up_to {
+			let len = (filled_up_to - self.position).min(buffer.len());
+			buffer[..len].copy_from_slice(&self.buffer[self.position..self.position + len]);
+			self.position += len;
+			if self.position == filled_up_to {
+				self.filled_up_to = None;
+			}
+			return Ok(len)
+		}
+
+		sp_io::offchain::http_response_read_body(self.id, buffer, self.deadline)
+			.map(|size| size as usize)
+			.map_err(|error| {
+				self.error = Some(error);
+				error
+			})
+	}
 }
 
 impl Iterator for ResponseBody {
@@ -565,6 +625,78 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn should_read_response_in_chunks() {
+		let (offchain, state) = testing::TestOffchainExt::new();
+		let mut t = TestExternalities::default();
+		t.register_extension(OffchainWorkerExt::new(offchain));
+
+		t.execute_with(|| {
+			let request: Request = Request::get("http://localhost:1234");
+			let pending = request.send().unwrap();
+			state.write().fulfill_pending_request(
+				0,
+				testing::PendingRequest {
+					method: "GET".into(),
+					uri: "http://localhost:1234".into(),
+					sent: true,
+					..Default::default()
+				},
+				(0..=255u8).cycle().take(10_000).collect::<Vec<_>>(),
+				None,
+			);
+
+			let response = pending.wait().unwrap();
+			let mut body = response.body();
+
+			// bytes buffered while iterating are read first.
+			assert_eq!(body.next(), Some(0));
+			let mut buffer = [0; 1000];
+			assert_eq!(body.read(&mut buffer), Ok(1000));
+			assert_eq!(buffer[..3], [1, 2, 3]);
+
+			let mut read = 1001;
+			loop {
+				match body.read(&mut buffer).unwrap() {
+					0 => break,
+					n => read += n,
+				}
+			}
+			assert_eq!(read, 10_000);
+			assert_eq!(body.error(), &None);
+		})
+	}
+
+	#[test]
+	fn should_ask_the_node_to_decode_gzip() {
+		let (offchain, state) = testing::TestOffchainExt::new();
+		let mut t = TestExternalities::default();
+		t.register_extension(OffchainWorkerExt::new(offchain));
+
+		t.execute_with(|| {
+			let request: Request = Request::get("http://localhost:1234");
+			let pending =
+				request.add_header("Accept-Encoding", "gzip").decode_gzip().send().unwrap();
+			// make sure it's sent correctly
+			state.write().fulfill_pending_request(
+				0,
+				testing::PendingRequest {
+					method: "GET".into(),
+					uri: "http://localhost:1234".into(),
+					meta: HttpRequestMeta { decode_gzip: true }.encode(),
+					headers: vec![("Accept-Encoding".into(), "gzip".into())],
+					sent: true,
+					..Default::default()
+				},
+				b"1234".to_vec(),
+				None,
+			);
+
+			let response = pending.wait().unwrap();
+			assert_eq!(response.body().collect::<Vec<_>>(), b"1234".to_vec());
+		})
+	}
+
 	#[test]
 	fn should_send_a_post_request() {
 		let (offchain, state) = testing::TestOffchainExt::new();
@@ -28,8 +28,8 @@ use codec::{Decode, Encode, FullCodec, HasCompact, MaxEncodedLen};
 use core::ops::Sub;
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, Zero},
-	DispatchError, DispatchResult, Perbill, RuntimeDebug, Saturating,
+	traits::{AtLeast32BitUnsigned, Get, Zero},
+	BoundedVec, DispatchError, DispatchResult, Perbill, RuntimeDebug, Saturating,
 };
 
 pub mod offence;
@@ -44,6 +44,9 @@ pub type EraIndex = u32;
 
 /// Type for identifying a page.
 pub type Page = u32;
+
+/// The targets of a nominator, bounded by `MaxNominations`.
+pub type BoundedNominations<AccountId, MaxNominations> = BoundedVec<AccountId, MaxNominations>;
 /// Representation of a staking account, which may be a stash or controller account.
 ///
 /// Note: once the controller is completely deprecated, this enum can also be deprecated in favor of
@@ -115,13 +118,24 @@ pub trait OnStakingUpdate<AccountId, Balance> {
 	/// Note that this is not fired when a nominator changes their stake. For that,
 	/// `on_stake_update` should be used, followed by querying whether `who` was a validator or a
 	/// nominator.
-	fn on_nominator_update(_who: &AccountId, _prev_nominations: Vec<AccountId>) {}
+	///
+	/// `MaxNominations` bounds the number of nominations of the staking system and can be used in
+	/// weight formulas.
+	fn on_nominator_update<MaxNominations: Get<u32>>(
+		_who: &AccountId,
+		_prev_nominations: BoundedNominations<AccountId, MaxNominations>,
+	) {
+	}
 
 	/// Fired when someone removes their intention to nominate, either due to chill or validating.
 	///
 	/// The set of nominations at the time of removal is provided as it can no longer be fetched in
 	/// any way.
-	fn on_nominator_remove(_who: &AccountId, _nominations: Vec<AccountId>) {}
+	fn on_nominator_remove<MaxNominations: Get<u32>>(
+		_who: &AccountId,
+		_nominations: BoundedNominations<AccountId, MaxNominations>,
+	) {
+	}
 
 	/// Fired when someone sets their intention to validate.
 	///
@@ -463,6 +477,115 @@ pub struct PagedExposureMetadata<Balance: HasCompact + codec::MaxEncodedLen> {
 	pub page_count: Page,
 }
 
+/// The projected reward of a validator and its nominators in an era.
+///
+/// Computed with the same formula that is used to pay out the era, based on the reward points
+/// earned so far.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProjectedRewards<AccountId, Balance> {
+	/// The era the rewards are projected for.
+	pub era: EraIndex,
+	/// The total payout to the validators of the era, after inflation and any cap.
+	pub era_payout: Balance,
+	/// The reward points earned by the validator so far.
+	pub points: u32,
+	/// The reward points earned by all validators so far.
+	pub total_points: u32,
+	/// The commission of the validator in the era.
+	pub commission: Perbill,
+	/// The part of `validator_payout` that is paid as commission.
+	pub commission_payout: Balance,
+	/// The reward of the validator, including its commission.
+	pub validator_payout: Balance,
+	/// The reward of each nominator backing the validator.
+	pub nominator_payouts: Vec<(AccountId, Balance)>,
+}
+
+/// Breakdown of the balance of an account from the point of view of staking.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct StakeableBreakdown<Balance> {
+	/// The free balance that is not staked yet, i.e. the most that can still be bonded.
+	pub free_to_stake: Balance,
+	/// The balance that is already staked.
+	pub staked: Balance,
+	/// The balance that is reserved or held elsewhere, and cannot be staked.
+	pub reserved: Balance,
+	/// The balance that is frozen by locks and freezes, including the staking lock.
+	///
+	/// Locks overlap, so this overlaps with `staked` and `free_to_stake` and does not reduce what
+	/// can be bonded.
+	pub frozen: Balance,
+	/// The existential deposit. A new bond must be at least this much.
+	pub existential_deposit: Balance,
+}
+
+/// A slash that has been computed, but is deferred so that it can still be canceled.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingSlash<AccountId, Balance> {
+	/// The era at the start of which the slash is applied, unless it is canceled before.
+	pub apply_era: EraIndex,
+	/// The index of the slash among the slashes applied in `apply_era`.
+	pub index: u32,
+	/// The offending validator.
+	pub validator: AccountId,
+	/// The slash of the validator's own stake.
+	pub own: Balance,
+	/// The total slash of its nominators.
+	pub others: Balance,
+	/// The payout to the reporters of the offence.
+	pub payout: Balance,
+}
+
+/// A stash queued to become a validator candidate at the start of the next era.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingCandidate<AccountId, Balance> {
+	/// The queued stash.
+	pub stash: AccountId,
+	/// Its active bond.
+	pub self_stake: Balance,
+	/// Its self-stake plus the stake of the nominators that backed it in the last election
+	/// snapshot.
+	pub approvals: Balance,
+	/// Whether it would become a validator candidate if the next era started now.
+	pub meets_thresholds: bool,
+}
+
+/// Rolling performance record of a validator.
+#[derive(
+	PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct ValidatorPerformance {
+	/// The rolling score of the validator.
+	///
+	/// It is a moving average of the scores of the eras the validator was active in. A validator
+	/// scores one in an era if it earned at least the average era points of the validators of that
+	/// era, and each offence reported against it in the era halves that score.
+	pub score: Perbill,
+	/// The last era the validator was active in.
+	pub last_active_era: EraIndex,
+	/// The era points earned by the validator in [`Self::last_active_era`].
+	pub last_era_points: u32,
+	/// The number of offences reported against the validator, e.g. missed heartbeats or lost
+	/// disputes.
+	pub offences: u32,
+	/// The number of offences reported since the score was last updated.
+	pub pending_offences: u32,
+}
+
+/// How the stake of a nominator is distributed across the validators of an era, next to its
+/// nominations.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct NominatorBacking<AccountId, Balance> {
+	/// The era of the exposures.
+	pub era: EraIndex,
+	/// The current nominations of the nominator.
+	pub nominations: Vec<AccountId>,
+	/// The validators that the nominator backs in `era`, with the stake backing each of them.
+	///
+	/// Nominations missing from here were not effective in `era`.
+	pub backing: Vec<(AccountId, Balance)>,
+}
+
 /// A type that belongs only in the context of an `Agent`.
 ///
 /// `Agent` is someone that manages delegated funds from [`Delegator`] accounts. It can
@@ -532,6 +532,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_system_rpc_runtime_api::ExtrinsicOutcomeApi<Block> for Runtime {
+		fn extrinsic_outcome(index: u32) -> Vec<u8> {
+			System::read_events_no_consensus()
+				.map(|record| *record)
+				.filter(|record| record.phase == frame_system::Phase::ApplyExtrinsic(index))
+				.collect::<Vec<_>>()
+				.encode()
+		}
+	}
+
 	impl self::TestAPI<Block> for Runtime {
 		fn balance_of(id: AccountId) -> u64 {
 			Balances::free_balance(id)
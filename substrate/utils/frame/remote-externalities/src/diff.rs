@@ -0,0 +1,481 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Storage Diff
+//!
+//! A structured diff between two states of the top trie, typically taken before and after
+//! `on_runtime_upgrade` or a single migration was executed on top of [`crate::Builder`]'s
+//! externalities.
+//!
+//! Changed keys are grouped per pallet and per storage item by their `twox_128` prefixes. Once
+//! [`StorageDiff::with_metadata`] is called, pallets and storage items are named and values of
+//! known storage items are decoded, otherwise everything is reported as raw hex. Child tries are
+//! not part of the diff.
+
+use codec::{Compact, Decode};
+use frame_metadata::{
+	v14::{PalletStorageMetadata, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, TypeDefPrimitive};
+use sp_core::hexdisplay::HexDisplay;
+use sp_crypto_hashing::twox_128;
+use sp_state_machine::{Backend, TestExternalities};
+use std::{collections::BTreeMap, fmt};
+
+/// Length of the prefix of a storage item, `twox_128(pallet) ++ twox_128(item)`.
+const ITEM_PREFIX_LEN: usize = 32;
+
+/// The change of a single key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyDiff {
+	/// The full storage key.
+	pub key: Vec<u8>,
+	/// The value before the change, `None` if the key was added.
+	pub pre: Option<Vec<u8>>,
+	/// The value after the change, `None` if the key was removed.
+	pub post: Option<Vec<u8>>,
+	/// The decoded value before the change, if the metadata permits decoding it.
+	pub decoded_pre: Option<String>,
+	/// The decoded value after the change, if the metadata permits decoding it.
+	pub decoded_post: Option<String>,
+}
+
+impl KeyDiff {
+	fn new(key: Vec<u8>, pre: Option<Vec<u8>>, post: Option<Vec<u8>>) -> Self {
+		Self { key, pre, post, decoded_pre: None, decoded_post: None }
+	}
+}
+
+/// All changed keys of a single storage item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDiff {
+	/// `twox_128(item)`.
+	pub prefix: [u8; 16],
+	/// The name of the storage item, if known.
+	pub name: Option<String>,
+	/// The changed keys, sorted.
+	pub keys: Vec<KeyDiff>,
+}
+
+/// All changed storage items of a single pallet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PalletDiff {
+	/// `twox_128(pallet)`.
+	pub prefix: [u8; 16],
+	/// The storage prefix of the pallet, if known.
+	pub name: Option<String>,
+	/// The changed storage items, sorted by prefix.
+	pub items: Vec<ItemDiff>,
+}
+
+/// A structured diff between two states.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageDiff {
+	/// The changes grouped by pallet, sorted by prefix.
+	pub pallets: Vec<PalletDiff>,
+	/// Changed keys too short to belong to a storage item, such as `:code`.
+	pub other: Vec<KeyDiff>,
+}
+
+impl StorageDiff {
+	/// Compute the diff between the `pre` and `post` key-value pairs.
+	pub fn new(
+		pre: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+		post: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+	) -> Self {
+		let mut pre = pre.into_iter().collect::<BTreeMap<_, _>>();
+		let mut changes = Vec::new();
+		for (key, value) in post {
+			match pre.remove(&key) {
+				Some(old) if old == value => {},
+				old => changes.push(KeyDiff::new(key, old, Some(value))),
+			}
+		}
+		changes.extend(pre.into_iter().map(|(key, old)| KeyDiff::new(key, Some(old), None)));
+		changes.sort_by(|a, b| a.key.cmp(&b.key));
+
+		let mut diff = Self::default();
+		for change in changes {
+			if change.key.len() < ITEM_PREFIX_LEN {
+				diff.other.push(change);
+				continue
+			}
+
+			let pallet: [u8; 16] = change.key[..16].try_into().expect("checked length above; qed");
+			let item: [u8; 16] =
+				change.key[16..ITEM_PREFIX_LEN].try_into().expect("checked length above; qed");
+
+			if diff.pallets.last().map_or(true, |p| p.prefix != pallet) {
+				diff.pallets.push(PalletDiff { prefix: pallet, name: None, items: Vec::new() });
+			}
+			let items = &mut diff.pallets.last_mut().expect("pushed above if missing; qed").items;
+			if items.last().map_or(true, |i| i.prefix != item) {
+				items.push(ItemDiff { prefix: item, name: None, keys: Vec::new() });
+			}
+			items.last_mut().expect("pushed above if missing; qed").keys.push(change);
+		}
+
+		diff
+	}
+
+	/// Compute the diff between the top tries of `pre` and `post`, including their pending
+	/// changes.
+	pub fn from_externalities<H>(
+		pre: &mut TestExternalities<H>,
+		post: &mut TestExternalities<H>,
+	) -> Self
+	where
+		H: sp_core::Hasher,
+		H::Out: Ord + 'static + codec::Codec,
+	{
+		Self::new(pairs(pre), pairs(post))
+	}
+
+	/// Execute `f` on `ext` and return its result together with the diff of the top trie of `ext`
+	/// caused by it.
+	///
+	/// This is meant to wrap the execution of `on_runtime_upgrade` or a single migration.
+	pub fn from_execution<H, R>(ext: &mut TestExternalities<H>, f: impl FnOnce() -> R) -> (R, Self)
+	where
+		H: sp_core::Hasher,
+		H::Out: Ord + 'static + codec::Codec,
+	{
+		let pre = pairs(ext);
+		let result = ext.execute_with(f);
+		(result, Self::new(pre, pairs(ext)))
+	}
+
+	/// Whether nothing changed.
+	pub fn is_empty(&self) -> bool {
+		self.pallets.is_empty() && self.other.is_empty()
+	}
+
+	/// The number of changed keys.
+	pub fn len(&self) -> usize {
+		self.pallets
+			.iter()
+			.flat_map(|p| p.items.iter())
+			.map(|i| i.keys.len())
+			.sum::<usize>() +
+			self.other.len()
+	}
+
+	/// Name the pallets and storage items known to `metadata` and decode their values.
+	///
+	/// Only metadata V14 and V15 are supported, other versions leave the diff untouched.
+	pub fn with_metadata(mut self, metadata: &RuntimeMetadataPrefixed) -> Self {
+		match &metadata.1 {
+			RuntimeMetadata::V14(m) =>
+				self.annotate(&m.types, m.pallets.iter().filter_map(|p| p.storage.as_ref())),
+			RuntimeMetadata::V15(m) =>
+				self.annotate(&m.types, m.pallets.iter().filter_map(|p| p.storage.as_ref())),
+			_ => log::warn!(
+				target: crate::LOG_TARGET,
+				"unsupported metadata version {}, storage diff stays undecoded",
+				metadata.0,
+			),
+		}
+		self
+	}
+
+	fn annotate<'a>(
+		&mut self,
+		types: &PortableRegistry,
+		storages: impl Iterator<Item = &'a PalletStorageMetadata<PortableForm>>,
+	) {
+		let storages = storages
+			.map(|storage| (twox_128(storage.prefix.as_bytes()), storage))
+			.collect::<BTreeMap<_, _>>();
+
+		for pallet in self.pallets.iter_mut() {
+			let Some(storage) = storages.get(&pallet.prefix) else { continue };
+			pallet.name = Some(storage.prefix.clone());
+
+			for item in pallet.items.iter_mut() {
+				let Some(entry) =
+					storage.entries.iter().find(|e| twox_128(e.name.as_bytes()) == item.prefix)
+				else {
+					continue
+				};
+				item.name = Some(entry.name.clone());
+
+				let value_ty = match &entry.ty {
+					StorageEntryType::Plain(ty) => ty.id,
+					StorageEntryType::Map { value, .. } => value.id,
+				};
+				for key in item.keys.iter_mut() {
+					key.decoded_pre = key.pre.as_ref().and_then(|v| decode(types, value_ty, v));
+					key.decoded_post = key.post.as_ref().and_then(|v| decode(types, value_ty, v));
+				}
+			}
+		}
+	}
+}
+
+impl fmt::Display for StorageDiff {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		fn name_or_hex(name: &Option<String>, prefix: &[u8]) -> String {
+			name.clone().unwrap_or_else(|| format!("0x{}", HexDisplay::from(&prefix)))
+		}
+
+		for pallet in &self.pallets {
+			writeln!(f, "{}", name_or_hex(&pallet.name, &pallet.prefix))?;
+			for item in &pallet.items {
+				writeln!(f, "  {}", name_or_hex(&item.name, &item.prefix))?;
+				for key in &item.keys {
+					writeln!(f, "    {}", key)?;
+				}
+			}
+		}
+		if !self.other.is_empty() {
+			writeln!(f, "other")?;
+			for key in &self.other {
+				writeln!(f, "    {}", key)?;
+			}
+		}
+
+		Ok(())
+	}
+}
+
+impl fmt::Display for KeyDiff {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let show = |raw: &Option<Vec<u8>>, decoded: &Option<String>| match (decoded, raw) {
+			(Some(decoded), _) => decoded.clone(),
+			(None, Some(raw)) => format!("0x{}", HexDisplay::from(raw)),
+			(None, None) => String::new(),
+		};
+		let key = HexDisplay::from(&self.key);
+
+		match (&self.pre, &self.post) {
+			(None, _) => write!(f, "+ 0x{}: {}", key, show(&self.post, &self.decoded_post)),
+			(_, None) => write!(f, "- 0x{}: {}", key, show(&self.pre, &self.decoded_pre)),
+			_ => write!(
+				f,
+				"~ 0x{}: {} -> {}",
+				key,
+				show(&self.pre, &self.decoded_pre),
+				show(&self.post, &self.decoded_post),
+			),
+		}
+	}
+}
+
+/// All key-value pairs of the top trie of `ext`.
+fn pairs<H>(ext: &mut TestExternalities<H>) -> Vec<(Vec<u8>, Vec<u8>)>
+where
+	H: sp_core::Hasher,
+	H::Out: Ord + 'static + codec::Codec,
+{
+	ext.as_backend()
+		.pairs(Default::default())
+		.expect("iterating the in-memory backend does not fail; qed")
+		.filter_map(|pair| pair.ok())
+		.collect()
+}
+
+/// Decode `value` as type `ty`, if it is fully consumed.
+fn decode(types: &PortableRegistry, ty: u32, mut value: &[u8]) -> Option<String> {
+	let decoded = decode_type(types, ty, &mut value)?;
+	value.is_empty().then_some(decoded)
+}
+
+fn decode_type(types: &PortableRegistry, ty: u32, input: &mut &[u8]) -> Option<String> {
+	let fields = |input: &mut &[u8], fields: &[scale_info::Field<PortableForm>]| {
+		let named = fields.iter().all(|f| f.name.is_some());
+		let values = fields
+			.iter()
+			.map(|field| {
+				let value = decode_type(types, field.ty.id, input)?;
+				Some(match &field.name {
+					Some(name) => format!("{}: {}", name, value),
+					None => value,
+				})
+			})
+			.collect::<Option<Vec<_>>>()?;
+		Some(match (fields.is_empty(), named) {
+			(true, _) => String::new(),
+			(false, true) => format!(" {{ {} }}", values.join(", ")),
+			(false, false) => format!("({})", values.join(", ")),
+		})
+	};
+	let items = |input: &mut &[u8], ty: u32, len: usize| {
+		if is_u8(types, ty) {
+			let bytes = input.get(..len)?;
+			*input = &input[len..];
+			return Some(format!("0x{}", HexDisplay::from(&bytes)))
+		}
+		let values = (0..len).map(|_| decode_type(types, ty, input)).collect::<Option<Vec<_>>>()?;
+		Some(format!("[{}]", values.join(", ")))
+	};
+
+	match &types.resolve(ty)?.type_def {
+		TypeDef::Composite(composite) => {
+			let decoded = fields(input, &composite.fields)?;
+			Some(decoded.trim_start().to_string())
+		},
+		TypeDef::Variant(variant) => {
+			let index = u8::decode(input).ok()?;
+			let variant = variant.variants.iter().find(|v| v.index == index)?;
+			Some(format!("{}{}", variant.name, fields(input, &variant.fields)?))
+		},
+		TypeDef::Sequence(sequence) => {
+			let len = Compact::<u32>::decode(input).ok()?.0;
+			items(input, sequence.type_param.id, len as usize)
+		},
+		TypeDef::Array(array) => items(input, array.type_param.id, array.len as usize),
+		TypeDef::Tuple(tuple) => {
+			let values = tuple
+				.fields
+				.iter()
+				.map(|field| decode_type(types, field.id, input))
+				.collect::<Option<Vec<_>>>()?;
+			Some(format!("({})", values.join(", ")))
+		},
+		TypeDef::Primitive(primitive) => decode_primitive(primitive, input),
+		TypeDef::Compact(_) => Some(Compact::<u128>::decode(input).ok()?.0.to_string()),
+		TypeDef::BitSequence(_) => None,
+	}
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Option<String> {
+	fn display<T: Decode + ToString>(input: &mut &[u8]) -> Option<String> {
+		T::decode(input).ok().map(|v| v.to_string())
+	}
+
+	match primitive {
+		TypeDefPrimitive::Bool => display::<bool>(input),
+		TypeDefPrimitive::Char => char::from_u32(u32::decode(input).ok()?).map(|c| c.to_string()),
+		TypeDefPrimitive::Str => display::<String>(input),
+		TypeDefPrimitive::U8 => display::<u8>(input),
+		TypeDefPrimitive::U16 => display::<u16>(input),
+		TypeDefPrimitive::U32 => display::<u32>(input),
+		TypeDefPrimitive::U64 => display::<u64>(input),
+		TypeDefPrimitive::U128 => display::<u128>(input),
+		TypeDefPrimitive::I8 => display::<i8>(input),
+		TypeDefPrimitive::I16 => display::<i16>(input),
+		TypeDefPrimitive::I32 => display::<i32>(input),
+		TypeDefPrimitive::I64 => display::<i64>(input),
+		TypeDefPrimitive::I128 => display::<i128>(input),
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 =>
+			<[u8; 32]>::decode(input).ok().map(|v| format!("0x{}", HexDisplay::from(&v))),
+	}
+}
+
+fn is_u8(types: &PortableRegistry, ty: u32) -> bool {
+	types
+		.resolve(ty)
+		.map_or(false, |t| matches!(t.type_def, TypeDef::Primitive(TypeDefPrimitive::U8)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use scale_info::{meta_type, Registry, TypeInfo};
+
+	fn key(pallet: &str, item: &str, suffix: &[u8]) -> Vec<u8> {
+		[&twox_128(pallet.as_bytes())[..], &twox_128(item.as_bytes())[..], suffix].concat()
+	}
+
+	#[test]
+	fn groups_changes_per_pallet_and_item() {
+		let pre = vec![
+			(key("Staking", "Ledger", b"a"), vec![1]),
+			(key("Staking", "Ledger", b"b"), vec![2]),
+			(key("Staking", "Bonded", b"a"), vec![3]),
+			(b":code".to_vec(), vec![4]),
+		];
+		let post = vec![
+			(key("Staking", "Ledger", b"a"), vec![1]),
+			(key("Staking", "Ledger", b"b"), vec![5]),
+			(key("Balances", "Account", b"a"), vec![6]),
+			(b":code".to_vec(), vec![7]),
+		];
+
+		let diff = StorageDiff::new(pre, post);
+
+		assert_eq!(diff.len(), 4);
+		assert_eq!(diff.pallets.len(), 2);
+		let staking = diff
+			.pallets
+			.iter()
+			.find(|p| p.prefix == twox_128(b"Staking"))
+			.expect("staking changed");
+		assert_eq!(staking.items.len(), 2);
+		assert_eq!(diff.other, vec![KeyDiff::new(b":code".to_vec(), Some(vec![4]), Some(vec![7]))]);
+		assert!(StorageDiff::new(unchanged(), unchanged()).is_empty());
+	}
+
+	#[test]
+	fn diffs_execution() {
+		let mut ext = TestExternalities::<sp_core::Blake2Hasher>::default();
+		ext.insert(key("System", "Number", &[]), 1u32.encode());
+		ext.insert(key("System", "Digest", &[]), vec![0]);
+
+		let (result, diff) = StorageDiff::from_execution(&mut ext, || {
+			sp_io::storage::set(&key("System", "Number", &[]), &2u32.encode());
+			sp_io::storage::clear(&key("System", "Digest", &[]));
+			7
+		});
+
+		let mut expected = vec![
+			KeyDiff::new(key("System", "Digest", &[]), Some(vec![0]), None),
+			KeyDiff::new(key("System", "Number", &[]), Some(1u32.encode()), Some(2u32.encode())),
+		];
+		expected.sort_by(|a, b| a.key.cmp(&b.key));
+
+		assert_eq!(result, 7);
+		assert_eq!(diff.pallets.len(), 1);
+		assert_eq!(
+			diff.pallets[0]
+				.items
+				.iter()
+				.flat_map(|i| i.keys.iter())
+				.cloned()
+				.collect::<Vec<_>>(),
+			expected,
+		);
+	}
+
+	fn unchanged() -> Vec<(Vec<u8>, Vec<u8>)> {
+		vec![(key("System", "Number", &[]), 1u32.encode())]
+	}
+
+	#[test]
+	fn decodes_values() {
+		#[derive(Encode, TypeInfo)]
+		struct Ledger {
+			stash: [u8; 4],
+			total: u128,
+			unlocking: Vec<(u32, u64)>,
+		}
+
+		let mut registry = Registry::new();
+		let ty = registry.register_type(&meta_type::<Ledger>()).id;
+		let types: PortableRegistry = registry.into();
+
+		let value = Ledger { stash: [1, 2, 3, 4], total: 10, unlocking: vec![(1, 2)] }.encode();
+		assert_eq!(
+			decode(&types, ty, &value).as_deref(),
+			Some("{ stash: 0x01020304, total: 10, unlocking: [(1, 2)] }"),
+		);
+		// trailing bytes are not a valid value.
+		assert_eq!(decode(&types, ty, &[value, vec![0]].concat()), None);
+	}
+}
@@ -27,14 +27,15 @@ use jsonrpsee::{
 	Extensions,
 };
 
-use sc_transaction_pool_api::{InPoolTransaction, TransactionPool};
-use sp_api::ApiExt;
+use sc_transaction_pool_api::{InPoolTransaction, TransactionPool, TxHash};
+use serde::{Deserialize, Serialize};
+use sp_api::{ApiExt, Core};
 use sp_block_builder::BlockBuilder;
 use sp_blockchain::HeaderBackend;
 use sp_core::{hexdisplay::HexDisplay, Bytes};
-use sp_runtime::{legacy, traits};
+use sp_runtime::{legacy, traits, TransactionOutcome};
 
-pub use frame_system_rpc_runtime_api::AccountNonceApi;
+pub use frame_system_rpc_runtime_api::{AccountNonceApi, ExtrinsicOutcomeApi};
 
 /// System RPC methods.
 #[docify::export]
@@ -53,6 +54,31 @@ pub trait SystemApi<BlockHash, AccountId, Nonce> {
 	async fn dry_run(&self, extrinsic: Bytes, at: Option<BlockHash>) -> RpcResult<Bytes>;
 }
 
+/// Block dry-run RPC methods.
+#[rpc(client, server)]
+pub trait BlockDryRunApi<BlockHash, TxHash> {
+	/// Dry run a block built out of the ready transactions of the pool on top of a given block,
+	/// without proposing it. Returns the dry run of each transaction, in pool order.
+	///
+	/// Inherents are not applied and the block is not finalized.
+	#[method(name = "system_dryRunBlock", with_extensions)]
+	async fn dry_run_block(&self, at: Option<BlockHash>)
+		-> RpcResult<Vec<ExtrinsicDryRun<TxHash>>>;
+}
+
+/// The dry run of a transaction of the pool in a candidate block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtrinsicDryRun<TxHash> {
+	/// Hash of the transaction in the pool.
+	pub hash: TxHash,
+	/// SCALE encoded `ApplyExtrinsicResult` of the transaction.
+	pub result: Bytes,
+	/// SCALE encoded outcome of the transaction, as returned by [`ExtrinsicOutcomeApi`], e.g. its
+	/// weight, fee and events. `None` if the transaction would not be included in the block.
+	pub outcome: Option<Bytes>,
+}
+
 /// Error type of this RPC api.
 pub enum Error {
 	/// The transaction was not decodable.
@@ -176,6 +202,100 @@ where
 	}
 }
 
+/// An implementation of the block dry-run RPC methods on full client.
+pub struct BlockDryRun<P: TransactionPool, C, B> {
+	client: Arc<C>,
+	pool: Arc<P>,
+	_marker: std::marker::PhantomData<B>,
+}
+
+impl<P: TransactionPool, C, B> BlockDryRun<P, C, B> {
+	/// Create new `BlockDryRun` given client and transaction pool.
+	pub fn new(client: Arc<C>, pool: Arc<P>) -> Self {
+		Self { client, pool, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<P, C, Block> BlockDryRunApiServer<<Block as traits::Block>::Hash, TxHash<P>>
+	for BlockDryRun<P, C, Block>
+where
+	C: sp_api::ProvideRuntimeApi<Block>,
+	C: HeaderBackend<Block>,
+	C: Send + Sync + 'static,
+	C::Api: BlockBuilder<Block>,
+	C::Api: ExtrinsicOutcomeApi<Block>,
+	P: TransactionPool<Block = Block> + 'static,
+	Block: traits::Block,
+{
+	async fn dry_run_block(
+		&self,
+		ext: &Extensions,
+		at: Option<<Block as traits::Block>::Hash>,
+	) -> RpcResult<Vec<ExtrinsicDryRun<TxHash<P>>>> {
+		sc_rpc_api::check_if_safe(ext)?;
+
+		let runtime_error = |e: sp_api::ApiError| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to dry run block.",
+				Some(e.to_string()),
+			)
+		};
+
+		let parent_hash = at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash);
+		let parent_number = self.client.number(parent_hash).ok().flatten().ok_or_else(|| {
+			ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to dry run block.",
+				Some(format!("Could not find block `{:?}`.", parent_hash)),
+			)
+		})?;
+		let header = <<Block as traits::Block>::Header as traits::Header>::new(
+			parent_number + traits::One::one(),
+			Default::default(),
+			Default::default(),
+			parent_hash,
+			Default::default(),
+		);
+
+		let mut api = self.client.runtime_api();
+		api.initialize_block(parent_hash, &header).map_err(runtime_error)?;
+
+		let mut dry_runs = Vec::new();
+		let mut index = 0u32;
+		for tx in self.pool.ready() {
+			// Like the block builder, only keep the changes of the transactions that would be
+			// included in the block.
+			let result = api.execute_in_transaction(|api| {
+				match api.apply_extrinsic(parent_hash, (**tx.data()).clone()) {
+					Ok(Ok(outcome)) => TransactionOutcome::Commit(Ok(Ok(outcome))),
+					res => TransactionOutcome::Rollback(res),
+				}
+			});
+			let result = result.map_err(runtime_error)?;
+
+			let outcome = if result.is_ok() {
+				let outcome = api.extrinsic_outcome(parent_hash, index).map_err(runtime_error)?;
+				index += 1;
+				Some(outcome.into())
+			} else {
+				None
+			};
+
+			dry_runs.push(ExtrinsicDryRun {
+				hash: tx.hash().clone(),
+				result: result.encode().into(),
+				outcome,
+			});
+		}
+
+		Ok(dry_runs)
+	}
+}
+
 /// Adjust account nonce from state, so that tx with the nonce will be
 /// placed after all ready txpool transactions.
 fn adjust_nonce<P, AccountId, Nonce>(pool: &P, account: AccountId, nonce: Nonce) -> Nonce
@@ -374,4 +494,80 @@ mod tests {
 		let apply_res: ApplyExtrinsicResult = Decode::decode(&mut bytes.as_ref()).unwrap();
 		assert_eq!(apply_res, Err(TransactionValidityError::Invalid(InvalidTransaction::Future)));
 	}
+
+	#[tokio::test]
+	async fn dry_run_block_should_deny_unsafe() {
+		sp_tracing::try_init_simple();
+
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner,
+			client.clone(),
+		));
+
+		let dry_run = BlockDryRun::new(client, pool);
+
+		// when
+		let res = dry_run.dry_run_block(&deny_unsafe(), None).await;
+		assert_matches!(res, Err(e) => {
+			assert!(e.message().contains("RPC call is unsafe to be called externally"));
+		});
+	}
+
+	#[tokio::test]
+	async fn dry_run_block_should_work() {
+		sp_tracing::try_init_simple();
+
+		// given
+		let client = Arc::new(substrate_test_runtime_client::new());
+		let spawner = sp_core::testing::TaskExecutor::new();
+		let pool = Arc::from(BasicPool::new_full(
+			Default::default(),
+			true.into(),
+			None,
+			spawner,
+			client.clone(),
+		));
+
+		let source = sp_runtime::transaction_validity::TransactionSource::External;
+		let new_transaction = |nonce: u64| {
+			Transfer {
+				from: Sr25519Keyring::Alice.into(),
+				to: Sr25519Keyring::Bob.into(),
+				amount: 5,
+				nonce,
+			}
+			.into_unchecked_extrinsic()
+		};
+		let hash_of_block0 = client.info().genesis_hash;
+		let ext0 = new_transaction(0);
+		let hash0 = block_on(pool.submit_one(hash_of_block0, source, ext0)).unwrap();
+		let ext1 = new_transaction(1);
+		let hash1 = block_on(pool.submit_one(hash_of_block0, source, ext1)).unwrap();
+
+		let dry_run = BlockDryRun::new(client.clone(), pool);
+
+		// when
+		let dry_runs =
+			dry_run.dry_run_block(&allow_unsafe(), None).await.expect("Call is successful");
+
+		// then
+		assert_eq!(
+			dry_runs.iter().map(|dry_run| dry_run.hash).collect::<Vec<_>>(),
+			vec![hash0, hash1]
+		);
+		for dry_run in dry_runs {
+			let apply_res: ApplyExtrinsicResult =
+				Decode::decode(&mut dry_run.result.as_ref()).unwrap();
+			assert_eq!(apply_res, Ok(Ok(())));
+			assert!(dry_run.outcome.is_some_and(|outcome| !outcome.is_empty()));
+		}
+		// nothing was imported.
+		assert_eq!(client.info().best_hash, hash_of_block0);
+	}
 }
@@ -298,6 +298,7 @@ pub async fn start_parachain_node(
 				network_provider: Arc::new(network.clone()),
 				is_validator: parachain_config.role.is_authority(),
 				enable_http_requests: false,
+				http_response_size_limit: parachain_config.offchain_worker.http_response_size_limit,
 				custom_extensions: move |_| vec![],
 			})?;
 		task_manager.spawn_handle().spawn(
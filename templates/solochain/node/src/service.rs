@@ -195,6 +195,7 @@ pub fn new_full<
 				)),
 				network_provider: Arc::new(network.clone()),
 				enable_http_requests: true,
+				http_response_size_limit: config.offchain_worker.http_response_size_limit,
 				custom_extensions: |_| vec![],
 			})?;
 		task_manager.spawn_handle().spawn(
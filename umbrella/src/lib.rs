@@ -345,6 +345,10 @@ pub use pallet_babe;
 #[cfg(feature = "pallet-bags-list")]
 pub use pallet_bags_list;
 
+/// Runtime API for the bags-list FRAME pallet.
+#[cfg(feature = "pallet-bags-list-runtime-api")]
+pub use pallet_bags_list_runtime_api;
+
 /// FRAME pallet to manage balances.
 #[cfg(feature = "pallet-balances")]
 pub use pallet_balances;
@@ -1188,6 +1192,10 @@ pub use snowbridge_core;
 #[cfg(feature = "snowbridge-ethereum")]
 pub use snowbridge_ethereum;
 
+/// Snowbridge Inbound Queue Runtime API.
+#[cfg(feature = "snowbridge-inbound-queue-runtime-api")]
+pub use snowbridge_inbound_queue_runtime_api;
+
 /// Snowbridge Outbound Queue Merkle Tree.
 #[cfg(feature = "snowbridge-outbound-queue-merkle-tree")]
 pub use snowbridge_outbound_queue_merkle_tree;